@@ -0,0 +1,555 @@
+//! CIE 1931 chromaticity diagram
+//!
+//! Computes the (x, y) chromaticity, CCT and Duv of a spectral power
+//! distribution and plots it on the CIE 1931 diagram, together with the
+//! spectrum locus, the Planckian locus and CCT isotherms.
+//!
+//! CCT and Duv follow the CIE 1960 (u, v) definition: the nearest point on the
+//! Planckian locus gives the CCT, the distance to it the Duv (positive above
+//! the locus, i.e. greenish; negative below, i.e. pinkish).
+
+use crate::spectral::SpectralTheme;
+use crate::tm30::{spd_to_xyz, CIE_X, CIE_Y, CIE_Z, WAVELENGTHS};
+use crate::types::SpectralDistribution;
+
+/// Second radiation constant in m⋅K
+const C2: f64 = 1.4388e-2;
+
+/// CCT search range in K
+const CCT_MIN: f64 = 1000.0;
+const CCT_MAX: f64 = 25000.0;
+
+/// Isotherms drawn across the Planckian locus, in K
+const ISOTHERM_CCTS: [f64; 8] = [
+    2000.0, 2500.0, 3000.0, 3500.0, 4000.0, 5000.0, 6500.0, 10000.0,
+];
+
+/// Half length of the isotherms in Duv
+const ISOTHERM_DUV: f64 = 0.02;
+
+/// Wavelengths labelled on the spectrum locus, in nm
+const LABELLED_WAVELENGTHS: [f64; 12] = [
+    460.0, 470.0, 480.0, 490.0, 500.0, 510.0, 520.0, 540.0, 560.0, 580.0, 600.0, 620.0,
+];
+
+/// Chromaticity of a light source
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Chromaticity {
+    /// CIE 1931 x
+    pub x: f64,
+    /// CIE 1931 y
+    pub y: f64,
+    /// CIE 1960 u
+    pub u: f64,
+    /// CIE 1960 v
+    pub v: f64,
+    /// Correlated color temperature in K
+    pub cct: f64,
+    /// Distance from the Planckian locus in (u, v), positive above it
+    pub duv: f64,
+}
+
+impl Chromaticity {
+    /// Chromaticity of a spectral power distribution
+    ///
+    /// Returns `None` when the SPD has no power in the visible range.
+    pub fn from_spd(spd: &SpectralDistribution) -> Option<Self> {
+        if !spd.values.iter().any(|&v| v > 0.0) {
+            return None;
+        }
+        let (x, y, z) = spd_to_xyz(spd);
+        let sum = x + y + z;
+        if x + z <= 0.0 || !sum.is_finite() {
+            return None;
+        }
+        Some(Self::from_xy(x / sum, y / sum))
+    }
+
+    /// Chromaticity of CIE 1931 (x, y) coordinates
+    pub fn from_xy(x: f64, y: f64) -> Self {
+        let (u, v) = xy_to_uv(x, y);
+        let (cct, duv) = cct_duv(u, v);
+        Self {
+            x,
+            y,
+            u,
+            v,
+            cct,
+            duv,
+        }
+    }
+}
+
+/// An isotherm line crossing the Planckian locus
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Isotherm {
+    /// Color temperature in K
+    pub cct: f64,
+    /// (x, y) end below the locus (negative Duv)
+    pub start: (f64, f64),
+    /// (x, y) end above the locus (positive Duv)
+    pub end: (f64, f64),
+}
+
+/// CIE 1931 chromaticity diagram data with SVG generation
+#[derive(Debug, Clone)]
+pub struct ChromaticityDiagram {
+    /// Chromaticity of the plotted source
+    pub chromaticity: Option<Chromaticity>,
+    /// Spectrum locus as (wavelength, x, y), 380–700 nm
+    pub spectrum_locus: Vec<(f64, f64, f64)>,
+    /// Planckian locus as (CCT, x, y), 1000–25000 K
+    pub planckian_locus: Vec<(f64, f64, f64)>,
+    /// CCT isotherms
+    pub isotherms: Vec<Isotherm>,
+}
+
+impl ChromaticityDiagram {
+    /// Create the diagram for a spectral power distribution
+    pub fn from_spectral(spd: &SpectralDistribution) -> Self {
+        Self::new(Chromaticity::from_spd(spd))
+    }
+
+    /// Create the diagram for a known chromaticity
+    pub fn from_chromaticity(chromaticity: Chromaticity) -> Self {
+        Self::new(Some(chromaticity))
+    }
+
+    fn new(chromaticity: Option<Chromaticity>) -> Self {
+        let spectrum_locus = WAVELENGTHS
+            .iter()
+            .enumerate()
+            .take_while(|(_, &wl)| wl <= 700.0)
+            .map(|(i, &wl)| {
+                let sum = CIE_X[i] + CIE_Y[i] + CIE_Z[i];
+                (wl, CIE_X[i] / sum, CIE_Y[i] / sum)
+            })
+            .collect();
+
+        // Even mired steps space the locus points evenly
+        let mired_max = 1e6 / CCT_MIN;
+        let mired_min = 1e6 / CCT_MAX;
+        let planckian_locus = (0..=48)
+            .map(|i| {
+                let cct = 1e6 / (mired_max - (mired_max - mired_min) * i as f64 / 48.0);
+                let (x, y) = planck_xy(cct);
+                (cct, x, y)
+            })
+            .collect();
+
+        let isotherms = ISOTHERM_CCTS
+            .iter()
+            .map(|&cct| {
+                let (u, v) = planck_uv(cct);
+                let (nu, nv) = locus_normal(cct);
+                Isotherm {
+                    cct,
+                    start: uv_to_xy(u - ISOTHERM_DUV * nu, v - ISOTHERM_DUV * nv),
+                    end: uv_to_xy(u + ISOTHERM_DUV * nu, v + ISOTHERM_DUV * nv),
+                }
+            })
+            .collect();
+
+        Self {
+            chromaticity,
+            spectrum_locus,
+            planckian_locus,
+            isotherms,
+        }
+    }
+
+    /// Generate SVG string
+    pub fn to_svg(&self, width: f64, height: f64, theme: &SpectralTheme) -> String {
+        let margin_left = 55.0;
+        let margin_top = 40.0;
+        let plot_width = width - margin_left - 20.0;
+        let plot_height = height - margin_top - 45.0;
+        let scale = (plot_width / 0.8).min(plot_height / 0.9);
+        let origin_x = margin_left;
+        let origin_y = margin_top + 0.9 * scale;
+        let to_screen = |x: f64, y: f64| (origin_x + x * scale, origin_y - y * scale);
+
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {width} {height}" width="{width}" height="{height}">
+  <rect width="{width}" height="{height}" fill="{}"/>
+"#,
+            theme.background
+        );
+
+        // Grid and tick labels
+        for i in 0..=8 {
+            let value = i as f64 * 0.1;
+            let (sx, _) = to_screen(value, 0.0);
+            svg.push_str(&format!(
+                r#"  <line x1="{sx:.1}" y1="{:.1}" x2="{sx:.1}" y2="{origin_y:.1}" stroke="{}" stroke-width="1"/>
+  <text x="{sx:.1}" y="{:.1}" fill="{}" font-size="10" font-family="{}" text-anchor="middle">{value:.1}</text>
+"#,
+                margin_top, theme.grid, origin_y + 14.0, theme.foreground, theme.font_family
+            ));
+        }
+        for i in 0..=9 {
+            let value = i as f64 * 0.1;
+            let (_, sy) = to_screen(0.0, value);
+            svg.push_str(&format!(
+                r#"  <line x1="{origin_x:.1}" y1="{sy:.1}" x2="{:.1}" y2="{sy:.1}" stroke="{}" stroke-width="1"/>
+  <text x="{:.1}" y="{:.1}" fill="{}" font-size="10" font-family="{}" text-anchor="end">{value:.1}</text>
+"#,
+                origin_x + 0.8 * scale,
+                theme.grid,
+                origin_x - 6.0,
+                sy + 3.5,
+                theme.foreground,
+                theme.font_family
+            ));
+        }
+
+        // Horseshoe: wedges from the equal-energy point to the boundary, each
+        // shaded from white to the chromaticity color of its outer edge
+        let (_, last_x, last_y) = self.spectrum_locus[self.spectrum_locus.len() - 1];
+        let (_, first_x, first_y) = self.spectrum_locus[0];
+        let mut boundary: Vec<(f64, f64)> = self
+            .spectrum_locus
+            .iter()
+            .map(|&(_, x, y)| (x, y))
+            .collect();
+        for i in 1..10 {
+            let t = i as f64 / 10.0;
+            boundary.push((
+                last_x + (first_x - last_x) * t,
+                last_y + (first_y - last_y) * t,
+            ));
+        }
+
+        let white = (1.0 / 3.0, 1.0 / 3.0);
+        let (wx, wy) = to_screen(white.0, white.1);
+        let white_color = xy_to_hex(white.0, white.1);
+        svg.push_str("  <defs>\n");
+        for i in 0..boundary.len() {
+            let (ax, ay) = boundary[i];
+            let (bx, by) = boundary[(i + 1) % boundary.len()];
+            let (mx, my) = ((ax + bx) / 2.0, (ay + by) / 2.0);
+            let (sx, sy) = to_screen(mx, my);
+            svg.push_str(&format!(
+                r#"    <linearGradient id="cie-wedge-{i}" gradientUnits="userSpaceOnUse" x1="{wx:.1}" y1="{wy:.1}" x2="{sx:.1}" y2="{sy:.1}"><stop offset="0" stop-color="{white_color}"/><stop offset="1" stop-color="{}"/></linearGradient>
+"#,
+                xy_to_hex(mx, my)
+            ));
+        }
+        svg.push_str("  </defs>\n  <g class=\"cie-gamut\">\n");
+        for i in 0..boundary.len() {
+            let (ax, ay) = to_screen(boundary[i].0, boundary[i].1);
+            let next = boundary[(i + 1) % boundary.len()];
+            let (bx, by) = to_screen(next.0, next.1);
+            svg.push_str(&format!(
+                r#"    <path d="M {wx:.1} {wy:.1} L {ax:.1} {ay:.1} L {bx:.1} {by:.1} Z" fill="url(#cie-wedge-{i})" stroke="url(#cie-wedge-{i})" stroke-width="0.6"/>
+"#
+            ));
+        }
+        svg.push_str("  </g>\n");
+
+        // Spectrum locus outline with the purple line
+        let outline: Vec<String> = self
+            .spectrum_locus
+            .iter()
+            .map(|&(_, x, y)| {
+                let (sx, sy) = to_screen(x, y);
+                format!("{sx:.1},{sy:.1}")
+            })
+            .collect();
+        svg.push_str(&format!(
+            r#"  <polygon points="{}" fill="none" stroke="{}" stroke-width="1.2"/>
+"#,
+            outline.join(" "),
+            theme.foreground
+        ));
+
+        // Wavelength labels, pushed outward from the white point
+        for &(wl, x, y) in &self.spectrum_locus {
+            if !LABELLED_WAVELENGTHS.contains(&wl) {
+                continue;
+            }
+            let (sx, sy) = to_screen(x, y);
+            let (dx, dy) = (sx - wx, sy - wy);
+            let len = (dx * dx + dy * dy).sqrt().max(1e-9);
+            let (nx, ny) = (dx / len, dy / len);
+            svg.push_str(&format!(
+                r#"  <line x1="{sx:.1}" y1="{sy:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="1"/>
+  <text x="{:.1}" y="{:.1}" fill="{}" font-size="9" font-family="{}" text-anchor="{}">{wl:.0}</text>
+"#,
+                sx + nx * 5.0,
+                sy + ny * 5.0,
+                theme.foreground,
+                sx + nx * 9.0,
+                sy + ny * 9.0 + 3.0,
+                theme.foreground,
+                theme.font_family,
+                if nx >= 0.0 { "start" } else { "end" }
+            ));
+        }
+
+        // Planckian locus
+        let locus: Vec<String> = self
+            .planckian_locus
+            .iter()
+            .map(|&(_, x, y)| {
+                let (sx, sy) = to_screen(x, y);
+                format!("{sx:.1},{sy:.1}")
+            })
+            .collect();
+        svg.push_str(&format!(
+            r#"  <polyline class="planckian-locus" points="{}" fill="none" stroke="{}" stroke-width="1.5"/>
+"#,
+            locus.join(" "),
+            theme.foreground
+        ));
+
+        // Isotherms, labelled at their lower end
+        for isotherm in &self.isotherms {
+            let (x1, y1) = to_screen(isotherm.start.0, isotherm.start.1);
+            let (x2, y2) = to_screen(isotherm.end.0, isotherm.end.1);
+            svg.push_str(&format!(
+                r#"  <line class="isotherm" x1="{x1:.1}" y1="{y1:.1}" x2="{x2:.1}" y2="{y2:.1}" stroke="{}" stroke-width="1" opacity="0.7"/>
+  <text x="{:.1}" y="{:.1}" fill="{}" font-size="8" font-family="{}" text-anchor="middle">{:.0}K</text>
+"#,
+                theme.foreground,
+                x1,
+                y1 + 9.0,
+                theme.foreground,
+                theme.font_family,
+                isotherm.cct
+            ));
+        }
+
+        // The source and its values
+        if let Some(c) = &self.chromaticity {
+            let (sx, sy) = to_screen(c.x, c.y);
+            svg.push_str(&format!(
+                r#"  <circle class="chromaticity-point" cx="{sx:.1}" cy="{sy:.1}" r="4" fill="{}" stroke="{}" stroke-width="2"/>
+"#,
+                theme.background, theme.stroke
+            ));
+
+            let (bx, by) = to_screen(0.48, 0.88);
+            let lines = [
+                format!("x = {:.4}   y = {:.4}", c.x, c.y),
+                format!("u = {:.4}   v = {:.4}", c.u, c.v),
+                format!("CCT = {:.0} K", c.cct),
+                format!("Duv = {:+.4}", c.duv),
+            ];
+            svg.push_str(&format!(
+                r#"  <rect x="{bx:.1}" y="{by:.1}" width="{:.1}" height="{:.1}" fill="{}" stroke="{}" stroke-width="1" rx="4"/>
+"#,
+                0.3 * scale,
+                16.0 * lines.len() as f64 + 10.0,
+                theme.background,
+                theme.grid
+            ));
+            for (i, line) in lines.iter().enumerate() {
+                svg.push_str(&format!(
+                    r#"  <text x="{:.1}" y="{:.1}" fill="{}" font-size="11" font-family="{}">{line}</text>
+"#,
+                    bx + 8.0,
+                    by + 18.0 + 16.0 * i as f64,
+                    theme.foreground,
+                    theme.font_family
+                ));
+            }
+        }
+
+        // Axis titles and title
+        svg.push_str(&format!(
+            r#"  <text x="{:.1}" y="{:.1}" fill="{}" font-size="12" font-family="{}" text-anchor="middle">x</text>
+  <text x="15" y="{:.1}" fill="{}" font-size="12" font-family="{}" text-anchor="middle">y</text>
+  <text x="{:.1}" y="22" fill="{}" font-size="14" font-family="{}" font-weight="bold" text-anchor="middle">CIE 1931 Chromaticity</text>
+"#,
+            origin_x + 0.4 * scale,
+            height - 10.0,
+            theme.foreground,
+            theme.font_family,
+            margin_top + 0.45 * scale,
+            theme.foreground,
+            theme.font_family,
+            width / 2.0,
+            theme.foreground,
+            theme.font_family
+        ));
+
+        svg.push_str("</svg>");
+        svg
+    }
+}
+
+/// CIE 1931 (x, y) to CIE 1960 (u, v)
+fn xy_to_uv(x: f64, y: f64) -> (f64, f64) {
+    let d = -2.0 * x + 12.0 * y + 3.0;
+    (4.0 * x / d, 6.0 * y / d)
+}
+
+/// CIE 1960 (u, v) to CIE 1931 (x, y)
+fn uv_to_xy(u: f64, v: f64) -> (f64, f64) {
+    let d = 2.0 * u - 8.0 * v + 4.0;
+    (3.0 * u / d, 2.0 * v / d)
+}
+
+/// Chromaticity of a blackbody radiator
+fn planck_xy(cct: f64) -> (f64, f64) {
+    let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+    for (i, &wl) in WAVELENGTHS.iter().enumerate() {
+        let m = wl * 1e-9;
+        let power = 1.0 / (m.powi(5) * ((C2 / (m * cct)).exp() - 1.0));
+        x += power * CIE_X[i];
+        y += power * CIE_Y[i];
+        z += power * CIE_Z[i];
+    }
+    let sum = x + y + z;
+    (x / sum, y / sum)
+}
+
+fn planck_uv(cct: f64) -> (f64, f64) {
+    let (x, y) = planck_xy(cct);
+    xy_to_uv(x, y)
+}
+
+/// Unit normal of the Planckian locus in (u, v), pointing to positive Duv
+fn locus_normal(cct: f64) -> (f64, f64) {
+    let (u1, v1) = planck_uv(cct * 0.99);
+    let (u2, v2) = planck_uv(cct * 1.01);
+    let (du, dv) = (u2 - u1, v2 - v1);
+    let len = (du * du + dv * dv).sqrt();
+    let (nu, nv) = (-dv / len, du / len);
+    if nv < 0.0 {
+        (-nu, -nv)
+    } else {
+        (nu, nv)
+    }
+}
+
+/// CCT and Duv from the nearest point of the Planckian locus
+fn cct_duv(u: f64, v: f64) -> (f64, f64) {
+    let distance = |mired: f64| {
+        let (pu, pv) = planck_uv(1e6 / mired);
+        ((u - pu).powi(2) + (v - pv).powi(2)).sqrt()
+    };
+
+    // Coarse scan in 1 mired steps, then narrow down around the best step
+    let mired_min = 1e6 / CCT_MAX;
+    let mired_max = 1e6 / CCT_MIN;
+    let mut best = mired_min;
+    let mut best_distance = f64::INFINITY;
+    let mut mired = mired_min;
+    while mired <= mired_max {
+        let d = distance(mired);
+        if d < best_distance {
+            best = mired;
+            best_distance = d;
+        }
+        mired += 1.0;
+    }
+
+    let (mut lo, mut hi) = ((best - 1.0).max(mired_min), (best + 1.0).min(mired_max));
+    for _ in 0..40 {
+        let a = lo + (hi - lo) / 3.0;
+        let b = hi - (hi - lo) / 3.0;
+        if distance(a) < distance(b) {
+            hi = b;
+        } else {
+            lo = a;
+        }
+    }
+    let mired = (lo + hi) / 2.0;
+    let cct = 1e6 / mired;
+    let (_, pv) = planck_uv(cct);
+    let duv = distance(mired);
+    (cct, if v < pv { -duv } else { duv })
+}
+
+/// Display color of a chromaticity: linear sRGB desaturated into gamut and
+/// scaled to full brightness
+fn xy_to_hex(x: f64, y: f64) -> String {
+    let big_x = x / y.max(1e-9);
+    let big_z = (1.0 - x - y) / y.max(1e-9);
+    let mut rgb = [
+        3.2406 * big_x - 1.5372 - 0.4986 * big_z,
+        -0.9689 * big_x + 1.8758 + 0.0415 * big_z,
+        0.0557 * big_x - 0.2040 + 1.0570 * big_z,
+    ];
+    let min = rgb.iter().copied().fold(f64::INFINITY, f64::min);
+    if min < 0.0 {
+        rgb.iter_mut().for_each(|c| *c -= min);
+    }
+    let max = rgb.iter().copied().fold(0.0, f64::max).max(1e-9);
+    let channel = |c: f64| {
+        let c = c / max;
+        let encoded = if c <= 0.003_130_8 {
+            12.92 * c
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        };
+        (encoded.clamp(0.0, 1.0) * 255.0).round() as u8
+    };
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        channel(rgb[0]),
+        channel(rgb[1]),
+        channel(rgb[2])
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spectral::synthesize_spectrum;
+    use crate::types::SpectralUnits;
+
+    #[test]
+    fn test_chromaticity_of_planckian_points() {
+        // 3000 K blackbody
+        let c = Chromaticity::from_xy(0.4369, 0.4041);
+        assert!((c.cct - 3000.0).abs() < 15.0, "cct {}", c.cct);
+        assert!(c.duv.abs() < 0.001, "duv {}", c.duv);
+
+        // Off the locus: greenish above, pinkish below
+        assert!(Chromaticity::from_xy(0.4369, 0.4200).duv > 0.005);
+        assert!(Chromaticity::from_xy(0.4369, 0.3880).duv < -0.005);
+
+        // Equal-energy spectrum sits at the center of the diagram
+        let spd = SpectralDistribution {
+            wavelengths: vec![380.0, 780.0],
+            values: vec![1.0, 1.0],
+            units: SpectralUnits::Relative,
+            start_wavelength: None,
+            wavelength_interval: None,
+        };
+        let e = Chromaticity::from_spd(&spd).unwrap();
+        assert!((e.x - 1.0 / 3.0).abs() < 0.002);
+        assert!((e.y - 1.0 / 3.0).abs() < 0.002);
+        assert!((e.cct - 5455.0).abs() < 30.0, "cct {}", e.cct);
+    }
+
+    #[test]
+    fn test_chromaticity_diagram_svg() {
+        let spd = synthesize_spectrum(4000.0, Some(90.0));
+        let diagram = ChromaticityDiagram::from_spectral(&spd);
+        let c = diagram.chromaticity.unwrap();
+        assert!(c.cct > 3000.0 && c.cct < 5500.0, "cct {}", c.cct);
+        assert_eq!(diagram.spectrum_locus.len(), 65);
+        assert_eq!(diagram.isotherms.len(), ISOTHERM_CCTS.len());
+
+        let svg = diagram.to_svg(600.0, 600.0, &SpectralTheme::light());
+        assert!(svg.contains("CIE 1931 Chromaticity"));
+        assert!(svg.contains(r#"class="chromaticity-point""#));
+        assert!(svg.contains(&format!("CCT = {:.0} K", c.cct)));
+        assert_eq!(svg.matches(r#"class="isotherm""#).count(), 8);
+
+        let empty = SpectralDistribution {
+            wavelengths: vec![],
+            values: vec![],
+            units: SpectralUnits::Relative,
+            start_wavelength: None,
+            wavelength_interval: None,
+        };
+        let svg =
+            ChromaticityDiagram::from_spectral(&empty).to_svg(600.0, 600.0, &SpectralTheme::dark());
+        assert!(!svg.contains("chromaticity-point"));
+    }
+}
@@ -6,7 +6,10 @@
 use crate::types::*;
 
 #[cfg(feature = "eulumdat")]
-use eulumdat::{Eulumdat, LampSet, Symmetry as EulumdatSymmetry, TypeIndicator};
+use eulumdat::{
+    Eulumdat, LampSet, NumericField, PhotometricCenter, RoundingPolicy,
+    Symmetry as EulumdatSymmetry, TypeIndicator,
+};
 
 #[cfg(feature = "eulumdat")]
 impl From<&Eulumdat> for LuminaireOpticalData {
@@ -77,7 +80,11 @@ impl From<&Eulumdat> for LuminaireOpticalData {
                             None
                         },
                     },
-                    position: None,
+                    position: ldt.photometric_center.map(|center| Position3D {
+                        x: center.x,
+                        y: center.y,
+                        z: center.z,
+                    }),
                 }],
                 mounting: None,
                 num_emitters: Some(ldt.lamp_sets.iter().map(|ls| ls.num_lamps as u32).sum()),
@@ -289,6 +296,10 @@ impl From<&LuminaireOpticalData> for Eulumdat {
             if let Some(opening) = luminaire.luminous_openings.first() {
                 ldt.luminous_area_length = opening.dimensions.length;
                 ldt.luminous_area_width = opening.dimensions.width.unwrap_or(0.0);
+                ldt.photometric_center = opening
+                    .position
+                    .as_ref()
+                    .map(|pos| PhotometricCenter::new(pos.x, pos.y, pos.z));
             }
         }
 
@@ -628,6 +639,37 @@ impl LuminaireOpticalData {
     pub fn to_eulumdat(&self) -> Eulumdat {
         self.into()
     }
+
+    /// Round emitter values to the precision of an export rounding policy.
+    ///
+    /// Call before writing XML or JSON; the writers output every stored
+    /// digit. Intensities are rounded in the units of the distribution.
+    pub fn apply_rounding(&mut self, policy: &RoundingPolicy) {
+        let round = |field, value: &mut f64| *value = policy.round(field, *value);
+        for emitter in &mut self.emitters {
+            for lumens in [&mut emitter.rated_lumens, &mut emitter.measured_lumens]
+                .into_iter()
+                .flatten()
+            {
+                round(NumericField::Flux, lumens);
+            }
+            if let Some(watts) = &mut emitter.input_watts {
+                round(NumericField::Wattage, watts);
+            }
+            if let Some(dist) = &mut emitter.intensity_distribution {
+                for angle in dist
+                    .horizontal_angles
+                    .iter_mut()
+                    .chain(dist.vertical_angles.iter_mut())
+                {
+                    round(NumericField::Angle, angle);
+                }
+                for value in dist.intensities.iter_mut().flatten() {
+                    round(NumericField::Intensity, value);
+                }
+            }
+        }
+    }
 }
 
 #[cfg(all(test, feature = "eulumdat"))]
@@ -693,6 +735,43 @@ mod tests {
         assert_eq!(cri_to_group(85.0), "1B");
         assert_eq!(cri_to_group(75.0), "2A");
     }
+
+    #[test]
+    fn test_apply_rounding_conserves_flux() {
+        let ldt = Eulumdat::parse(include_str!(
+            "../../eulumdat-wasm/templates/road_luminaire.ldt"
+        ))
+        .unwrap();
+        let original = LuminaireOpticalData::from_eulumdat(&ldt);
+        let mut doc = original.clone();
+        doc.apply_rounding(&RoundingPolicy::new(2).with_precision(NumericField::Intensity, 0));
+
+        let dist = doc.emitters[0].intensity_distribution.as_ref().unwrap();
+        assert!(dist.intensities.iter().flatten().all(|v| *v == v.round()));
+
+        let flux = |doc: &LuminaireOpticalData| {
+            eulumdat::PhotometricCalculations::integrate_flux(&doc.to_eulumdat()).luminaire_flux
+        };
+        let (before, after) = (flux(&original), flux(&doc));
+        assert!(
+            (after - before).abs() / before < 0.005,
+            "{after} vs {before}"
+        );
+    }
+
+    #[test]
+    fn test_photometric_center_roundtrip() {
+        let mut ldt = Eulumdat::parse(include_str!(
+            "../../eulumdat-wasm/templates/road_luminaire.ldt"
+        ))
+        .unwrap();
+        let doc = LuminaireOpticalData::from_eulumdat(&ldt);
+        assert_eq!(doc.to_eulumdat().photometric_center, None);
+
+        ldt.photometric_center = Some(PhotometricCenter::new(120.0, 0.0, -35.0));
+        let doc = LuminaireOpticalData::from_eulumdat(&ldt);
+        assert_eq!(doc.to_eulumdat().photometric_center, ldt.photometric_center);
+    }
 }
 
 // ============================================================================
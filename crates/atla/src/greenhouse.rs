@@ -26,6 +26,12 @@ pub struct GreenhouseLabels {
     pub vegetative: String,
     /// "Seedling" growth stage
     pub seedling: String,
+    /// "Uniformity" label
+    pub uniformity: String,
+    /// "Coverage" label (area above the PPFD threshold)
+    pub coverage: String,
+    /// "Photoperiod" label
+    pub photoperiod: String,
 }
 
 impl Default for GreenhouseLabels {
@@ -40,6 +46,9 @@ impl Default for GreenhouseLabels {
             flowering: "Flowering".to_string(),
             vegetative: "Veg".to_string(),
             seedling: "Seedling".to_string(),
+            uniformity: "Uniformity".to_string(),
+            coverage: "Coverage".to_string(),
+            photoperiod: "Photoperiod".to_string(),
         }
     }
 }
@@ -57,6 +66,9 @@ impl GreenhouseLabels {
             flowering: "Blüte".to_string(),
             vegetative: "Wachstum".to_string(),
             seedling: "Sämling".to_string(),
+            uniformity: "Gleichmäßigkeit".to_string(),
+            coverage: "Abdeckung".to_string(),
+            photoperiod: "Photoperiode".to_string(),
         }
     }
 
@@ -72,6 +84,9 @@ impl GreenhouseLabels {
             flowering: "开花".to_string(),
             vegetative: "营养".to_string(),
             seedling: "幼苗".to_string(),
+            uniformity: "均匀度".to_string(),
+            coverage: "覆盖".to_string(),
+            photoperiod: "光周期".to_string(),
         }
     }
 
@@ -87,6 +102,9 @@ impl GreenhouseLabels {
             flowering: "Floraison".to_string(),
             vegetative: "Croissance".to_string(),
             seedling: "Semis".to_string(),
+            uniformity: "Uniformité".to_string(),
+            coverage: "Couverture".to_string(),
+            photoperiod: "Photopériode".to_string(),
         }
     }
 
@@ -102,6 +120,9 @@ impl GreenhouseLabels {
             flowering: "Fioritura".to_string(),
             vegetative: "Crescita".to_string(),
             seedling: "Piantina".to_string(),
+            uniformity: "Uniformità".to_string(),
+            coverage: "Copertura".to_string(),
+            photoperiod: "Fotoperiodo".to_string(),
         }
     }
 
@@ -117,6 +138,9 @@ impl GreenhouseLabels {
             flowering: "Цветение".to_string(),
             vegetative: "Рост".to_string(),
             seedling: "Рассада".to_string(),
+            uniformity: "Равномерность".to_string(),
+            coverage: "Покрытие".to_string(),
+            photoperiod: "Фотопериод".to_string(),
         }
     }
 
@@ -132,6 +156,9 @@ impl GreenhouseLabels {
             flowering: "Floración".to_string(),
             vegetative: "Crecimiento".to_string(),
             seedling: "Plántula".to_string(),
+            uniformity: "Uniformidad".to_string(),
+            coverage: "Cobertura".to_string(),
+            photoperiod: "Fotoperiodo".to_string(),
         }
     }
 
@@ -147,6 +174,9 @@ impl GreenhouseLabels {
             flowering: "Floração".to_string(),
             vegetative: "Crescimento".to_string(),
             seedling: "Muda".to_string(),
+            uniformity: "Uniformidade".to_string(),
+            coverage: "Cobertura".to_string(),
+            photoperiod: "Fotoperíodo".to_string(),
         }
     }
 }
@@ -204,12 +234,28 @@ impl GreenhouseTheme {
     }
 }
 
+/// Default photoperiod for the daily light integral in hours
+pub const DEFAULT_PHOTOPERIOD_HOURS: f64 = 12.0;
+
+/// Default PPFD threshold for the coverage area in µmol/m²/s
+pub const DEFAULT_PPFD_THRESHOLD: f64 = 200.0;
+
 /// PPFD at a specific distance
 #[derive(Debug, Clone)]
 pub struct PpfdAtDistance {
     pub distance_m: f64,
     pub ppfd: f64,       // µmol/m²/s
     pub coverage_m: f64, // beam diameter at this distance
+    /// Lowest PPFD within the beam footprint (µmol/m²/s)
+    pub min_ppfd: f64,
+    /// Area-weighted average PPFD within the beam footprint (µmol/m²/s)
+    pub avg_ppfd: f64,
+    /// PPFD uniformity min / avg within the beam footprint
+    pub uniformity: f64,
+    /// Canopy area receiving at least the PPFD threshold (m²)
+    pub area_above_threshold_m2: f64,
+    /// Daily light integral of the average PPFD over the photoperiod (mol/m²/d)
+    pub dli: f64,
 }
 
 /// Greenhouse PPFD diagram data
@@ -227,6 +273,13 @@ pub struct GreenhouseDiagram {
     pub beam_angle: f64,
     /// Recommended mounting heights for different growth stages
     pub recommendations: Vec<(String, f64, f64)>, // (stage, min_height, max_height)
+    /// Photoperiod for the daily light integral in hours
+    pub photoperiod_hours: f64,
+    /// PPFD threshold for the coverage area in µmol/m²/s
+    pub ppfd_threshold: f64,
+    /// Photon intensity profile `(gamma°, µmol/s/sr)` of the luminaire,
+    /// rotationally symmetric and scaled to the PPF
+    pub photon_intensity: Vec<(f64, f64)>,
 }
 
 impl GreenhouseDiagram {
@@ -275,6 +328,11 @@ impl GreenhouseDiagram {
                     distance_m: d,
                     ppfd,
                     coverage_m: coverage,
+                    min_ppfd: 0.0,
+                    avg_ppfd: 0.0,
+                    uniformity: 0.0,
+                    area_above_threshold_m2: 0.0,
+                    dli: 0.0,
                 }
             })
             .collect();
@@ -286,13 +344,116 @@ impl GreenhouseDiagram {
             ("Flowering".to_string(), 0.3, 0.5),
         ];
 
-        Self {
+        // Relative intensity of the first C-plane, Lambertian without data
+        let relative: Vec<(f64, f64)> = emitter
+            .and_then(|e| e.intensity_distribution.as_ref())
+            .filter(|dist| !dist.intensities.is_empty())
+            .map(|dist| {
+                dist.vertical_angles
+                    .iter()
+                    .copied()
+                    .zip(dist.intensities[0].iter().copied())
+                    .collect()
+            })
+            .unwrap_or_else(|| {
+                (0..=18)
+                    .map(|i| {
+                        let gamma = i as f64 * 5.0;
+                        (gamma, gamma.to_radians().cos())
+                    })
+                    .collect()
+            });
+        let photon_intensity = scale_to_flux(&relative, ppf);
+
+        let mut diagram = Self {
             ppf,
             watts,
             efficacy,
             ppfd_levels,
             beam_angle,
             recommendations,
+            photoperiod_hours: DEFAULT_PHOTOPERIOD_HOURS,
+            ppfd_threshold: DEFAULT_PPFD_THRESHOLD,
+            photon_intensity,
+        };
+        diagram.update_metrics();
+        diagram
+    }
+
+    /// Set the photoperiod for the daily light integral
+    pub fn with_photoperiod(mut self, hours: f64) -> Self {
+        self.photoperiod_hours = hours.clamp(0.0, 24.0);
+        self.update_metrics();
+        self
+    }
+
+    /// Set the PPFD threshold for the coverage area
+    pub fn with_ppfd_threshold(mut self, threshold: f64) -> Self {
+        self.ppfd_threshold = threshold.max(0.0);
+        self.update_metrics();
+        self
+    }
+
+    /// PPFD on a horizontal canopy at `distance` below the luminaire and
+    /// `radius` off its axis (µmol/m²/s)
+    pub fn ppfd_at(&self, distance: f64, radius: f64) -> f64 {
+        if distance <= 0.0 {
+            return 0.0;
+        }
+        let gamma = radius.atan2(distance);
+        let cos_g = gamma.cos();
+        interpolate(&self.photon_intensity, gamma.to_degrees()) * cos_g.powi(3)
+            / (distance * distance)
+    }
+
+    /// Recompute uniformity, coverage area and DLI for every distance
+    fn update_metrics(&mut self) {
+        const RINGS: usize = 50;
+        // Coverage is searched out to 85° from nadir
+        let max_gamma = self
+            .photon_intensity
+            .last()
+            .map_or(0.0, |&(g, _)| g)
+            .min(85.0);
+
+        for i in 0..self.ppfd_levels.len() {
+            let d = self.ppfd_levels[i].distance_m;
+
+            // Footprint: the beam circle, sampled in rings of equal width
+            let footprint = self.ppfd_levels[i].coverage_m / 2.0;
+            let mut min_ppfd = self.ppfd_at(d, footprint);
+            let (mut weighted, mut area) = (0.0, 0.0);
+            for ring in 0..RINGS {
+                let r = (ring as f64 + 0.5) / RINGS as f64 * footprint;
+                let ppfd = self.ppfd_at(d, r);
+                min_ppfd = min_ppfd.min(ppfd);
+                weighted += ppfd * r;
+                area += r;
+            }
+            let avg_ppfd = if area > 0.0 {
+                weighted / area
+            } else {
+                self.ppfd_at(d, 0.0)
+            };
+
+            let reach = d * max_gamma.to_radians().tan();
+            let dr = reach / (4 * RINGS) as f64;
+            let area_above_threshold_m2: f64 = (0..4 * RINGS)
+                .map(|ring| (ring as f64 + 0.5) * dr)
+                .filter(|&r| self.ppfd_at(d, r) >= self.ppfd_threshold)
+                .map(|r| 2.0 * std::f64::consts::PI * r * dr)
+                .sum();
+
+            let level = &mut self.ppfd_levels[i];
+            level.min_ppfd = min_ppfd;
+            level.avg_ppfd = avg_ppfd;
+            level.uniformity = if avg_ppfd > 0.0 {
+                min_ppfd / avg_ppfd
+            } else {
+                0.0
+            };
+            level.area_above_threshold_m2 = area_above_threshold_m2;
+            level.dli = avg_ppfd * self.photoperiod_hours * 3600.0 / 1e6;
         }
     }
 
@@ -455,9 +616,23 @@ impl GreenhouseDiagram {
                     "  <text x=\"{}\" y=\"{}\" fill=\"{}\" font-size=\"11\" font-family=\"{}\" font-weight=\"bold\">{:.0}</text>\n",
                     margin + plot_width + 5.0, y + 4.0, ppfd_color, theme.font_family, level.ppfd
                 ));
+
+                // Uniformity, coverage area and DLI above the line
+                svg.push_str(&format!(
+                    "  <text x=\"{}\" y=\"{}\" fill=\"{}\" font-size=\"9\" font-family=\"{}\" text-anchor=\"end\" paint-order=\"stroke\" stroke=\"{}\" stroke-width=\"3\">U₀ {:.2} · {:.1} m² · DLI {:.1}</text>\n",
+                    margin + plot_width - 4.0, y - 3.0, theme.foreground, theme.font_family, theme.background,
+                    level.uniformity, level.area_above_threshold_m2, level.dli
+                ));
             }
         }
 
+        // Key for the per-distance metrics
+        svg.push_str(&format!(
+            "  <text x=\"{}\" y=\"{}\" fill=\"{}\" font-size=\"9\" font-family=\"{}\">U₀: {} min/avg · m²: {} ≥ {:.0} {} · DLI: mol/m²/d, {} {:.0} h</text>\n",
+            margin, greenhouse_y - 8.0, theme.foreground, theme.font_family,
+            labels.uniformity, labels.coverage, self.ppfd_threshold, labels.unit, labels.photoperiod, self.photoperiod_hours
+        ));
+
         // Unit label
         svg.push_str(&format!(
             "  <text x=\"{}\" y=\"{}\" fill=\"{}\" font-size=\"9\" font-family=\"{}\" text-anchor=\"end\">{}</text>\n",
@@ -526,6 +701,37 @@ impl GreenhouseDiagram {
     }
 }
 
+/// Scale a relative intensity profile `(gamma°, value)` so that the
+/// rotationally symmetric distribution emits `flux` in total
+fn scale_to_flux(profile: &[(f64, f64)], flux: f64) -> Vec<(f64, f64)> {
+    let relative_flux: f64 = profile
+        .windows(2)
+        .map(|w| {
+            let (g0, i0) = (w[0].0.to_radians(), w[0].1);
+            let (g1, i1) = (w[1].0.to_radians(), w[1].1);
+            std::f64::consts::PI * (i0 * g0.sin() + i1 * g1.sin()) * (g1 - g0)
+        })
+        .sum();
+    let factor = if relative_flux > 0.0 {
+        flux / relative_flux
+    } else {
+        0.0
+    };
+    profile.iter().map(|&(g, i)| (g, i * factor)).collect()
+}
+
+/// Linear interpolation in a profile sorted by angle, zero beyond its end
+fn interpolate(profile: &[(f64, f64)], gamma: f64) -> f64 {
+    match profile.iter().position(|&(g, _)| g >= gamma) {
+        Some(0) => profile[0].1,
+        Some(i) => {
+            let ((g0, i0), (g1, i1)) = (profile[i - 1], profile[i]);
+            i0 + (i1 - i0) * (gamma - g0) / (g1 - g0)
+        }
+        None => 0.0,
+    }
+}
+
 /// Estimate PPF conversion factor from spectral distribution
 fn estimate_ppf_factor(spd: &SpectralDistribution) -> f64 {
     // Weight spectrum by PAR region (400-700nm)
@@ -596,5 +802,52 @@ mod tests {
         assert!(svg.contains("<svg"));
         assert!(svg.contains("PPFD"));
         assert!(svg.contains("µmol"));
+        assert!(svg.contains("DLI"));
+    }
+
+    #[test]
+    fn test_greenhouse_uniformity_coverage_and_dli() {
+        let doc = LuminaireOpticalData::new();
+        let diagram = GreenhouseDiagram::from_atla(&doc);
+
+        // Lambertian fallback: the photon flux below the luminaire is the PPF
+        let flux: f64 = (0..2000)
+            .map(|i| {
+                let r = (i as f64 + 0.5) * 0.05;
+                diagram.ppfd_at(1.0, r) * 2.0 * std::f64::consts::PI * r * 0.05
+            })
+            .sum();
+        assert!(
+            (flux / diagram.ppf - 1.0).abs() < 0.05,
+            "{flux} vs {}",
+            diagram.ppf
+        );
+
+        for level in &diagram.ppfd_levels {
+            assert!(level.uniformity > 0.0 && level.uniformity <= 1.0);
+            assert!(level.min_ppfd <= level.avg_ppfd);
+            let dli = level.avg_ppfd * 12.0 * 3600.0 / 1e6;
+            assert!((level.dli - dli).abs() < 1e-9);
+        }
+        // A lower light level farther away spreads over a larger area
+        let near = &diagram.ppfd_levels[0];
+        let far = diagram.ppfd_levels.last().unwrap();
+        assert!(near.avg_ppfd > far.avg_ppfd);
+
+        let longer = diagram.clone().with_photoperiod(18.0);
+        assert!((longer.ppfd_levels[0].dli - near.dli * 1.5).abs() < 1e-9);
+
+        let area_at = |threshold: f64| {
+            let diagram = diagram.clone().with_ppfd_threshold(threshold);
+            diagram.ppfd_levels.last().unwrap().area_above_threshold_m2
+        };
+        let covered = area_at(far.avg_ppfd);
+        assert!(covered > 0.0);
+        assert!(area_at(far.avg_ppfd * 2.0) < covered);
+        let none = diagram.with_ppfd_threshold(1e9);
+        assert!(none
+            .ppfd_levels
+            .iter()
+            .all(|l| l.area_above_threshold_m2 == 0.0));
     }
 }
@@ -13,6 +13,8 @@
 //! - **Multiple intensity metrics** - Luminous, radiant, photon, and spectral
 //! - **Data provenance** - Track whether data is measured or simulated
 //! - **Color metrics** - CCT, CRI (Ra, R9), and TM-30 (Rf, Rg)
+//! - **Chromaticity** - CIE 1931 (x, y), CCT and Duv from the SPD
+//! - **Mesopic photometry** - CIE 191 adaptation multipliers from the S/P ratio
 //! - **Extensible** - Custom data fields for application-specific needs
 //! - **TM-33-23 support** - Symmetry types, multipliers, angular spectral/color data
 //!
@@ -76,9 +78,11 @@
 //! - `eulumdat` - Conversion to/from EULUMDAT format
 
 pub mod bim;
+pub mod chromaticity;
 pub mod error;
 pub mod greenhouse;
 pub mod labels;
+pub mod mesopic;
 pub mod spectral;
 pub mod tm30;
 pub mod types;
@@ -101,9 +105,11 @@ pub use bim::{
     BimParameters, ElectricalPhase, EmergencyType, HousingShape, LedDriveType, MountingType,
     VoltageType,
 };
+pub use chromaticity::{Chromaticity, ChromaticityDiagram};
 pub use error::{AtlaError, Result};
 pub use greenhouse::{GreenhouseDiagram, GreenhouseLabels, GreenhouseTheme};
 pub use labels::SpectralLabels;
+pub use mesopic::{mesopic_factors, mesopic_table, MesopicFactors};
 pub use spectral::{
     synthesize_spectrum, SpectralDiagram, SpectralMetrics, SpectralSvgLabels, SpectralTheme,
 };
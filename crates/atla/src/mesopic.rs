@@ -0,0 +1,201 @@
+//! CIE 191:2010 Mesopic Photometry
+//!
+//! Implements the CIE recommended system for mesopic photometry (the MES2 model).
+//! Given a photopic adaptation luminance and the S/P ratio of the light source,
+//! the mesopic luminance is found iteratively and expressed as a multiplier on
+//! the photopic values.
+//!
+//! Because the multiplier only depends on the adaptation state and the spectrum,
+//! the same factor applies to luminance and illuminance computed for that source.
+//!
+//! Reference: CIE 191:2010 "Recommended System for Mesopic Photometry Based on
+//! Visual Performance"
+
+use crate::types::Emitter;
+
+/// Upper limit of the mesopic range (cd/m²); above this vision is fully photopic
+pub const MESOPIC_UPPER_LIMIT: f64 = 5.0;
+
+/// Lower limit of the mesopic range (cd/m²); below this vision is fully scotopic
+pub const MESOPIC_LOWER_LIMIT: f64 = 0.005;
+
+/// Ratio of the maxima of V'(λ) and V(λ) luminous efficacies (683 / 1699)
+const V_PRIME_LAMBDA_0: f64 = 683.0 / 1699.0;
+
+/// MES2 model coefficient `a` in `m = a + b·log10(L_mes)`
+const MES2_A: f64 = 0.7670;
+
+/// MES2 model coefficient `b` in `m = a + b·log10(L_mes)`
+const MES2_B: f64 = 0.3334;
+
+/// Adaptation luminances (cd/m²) tabulated by [`mesopic_table`]
+pub const STANDARD_ADAPTATION_LUMINANCES: [f64; 8] = [0.01, 0.1, 0.3, 0.5, 1.0, 1.5, 2.0, 5.0];
+
+/// Result of a CIE 191 mesopic adaptation calculation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MesopicFactors {
+    /// Photopic adaptation luminance the calculation was done for (cd/m²)
+    pub adaptation_luminance: f64,
+    /// Scotopic-to-photopic ratio of the light source
+    pub sp_ratio: f64,
+    /// Adaptation coefficient m (0 = scotopic, 1 = photopic)
+    pub adaptation_coefficient: f64,
+    /// Mesopic luminance at the adaptation point (cd/m²)
+    pub mesopic_luminance: f64,
+    /// Multiplier to convert photopic luminance to effective mesopic luminance
+    pub luminance_multiplier: f64,
+    /// Multiplier to convert photopic illuminance to effective mesopic illuminance
+    pub illuminance_multiplier: f64,
+}
+
+impl MesopicFactors {
+    /// Apply the luminance multiplier to a photopic luminance (cd/m²)
+    pub fn effective_luminance(&self, photopic: f64) -> f64 {
+        photopic * self.luminance_multiplier
+    }
+
+    /// Apply the illuminance multiplier to a photopic illuminance (lx)
+    pub fn effective_illuminance(&self, photopic: f64) -> f64 {
+        photopic * self.illuminance_multiplier
+    }
+}
+
+/// Calculate CIE 191 mesopic factors for a photopic adaptation luminance
+///
+/// `adaptation_luminance` is the photopic luminance (cd/m²) the observer is adapted to,
+/// `sp_ratio` the scotopic-to-photopic ratio of the source. Returns factors of 1.0
+/// above the mesopic range; below it the scotopic luminance is used.
+///
+/// # Example
+/// ```rust
+/// use atla::mesopic::mesopic_factors;
+///
+/// // Cool-white LED (S/P 2.0) at a typical road luminance of 0.5 cd/m²
+/// let f = mesopic_factors(0.5, 2.0);
+/// assert!(f.luminance_multiplier > 1.0);
+///
+/// // Fully photopic: no correction
+/// assert_eq!(mesopic_factors(10.0, 2.0).luminance_multiplier, 1.0);
+/// ```
+pub fn mesopic_factors(adaptation_luminance: f64, sp_ratio: f64) -> MesopicFactors {
+    let l_p = adaptation_luminance.max(0.0);
+    let sp = sp_ratio.max(0.0);
+    let l_s = l_p * sp;
+
+    let (m, l_mes) = if l_p >= MESOPIC_UPPER_LIMIT {
+        (1.0, l_p)
+    } else if l_s <= MESOPIC_LOWER_LIMIT || l_p <= 0.0 {
+        // At m = 0 the mesopic luminance reduces to the scotopic one
+        (0.0, l_s)
+    } else {
+        // Iterate m_n = a + b·log10(L_mes(m_{n-1})) starting at m_0 = 0.5
+        let mut m = 0.5_f64;
+        let mut l_mes = l_p;
+        for _ in 0..100 {
+            l_mes =
+                (m * l_p + (1.0 - m) * l_s * V_PRIME_LAMBDA_0) / (m + (1.0 - m) * V_PRIME_LAMBDA_0);
+            let next = (MES2_A + MES2_B * l_mes.max(f64::MIN_POSITIVE).log10()).clamp(0.0, 1.0);
+            if (next - m).abs() < 1e-9 {
+                m = next;
+                break;
+            }
+            m = next;
+        }
+        (m, l_mes)
+    };
+
+    let multiplier = if l_p > 0.0 { l_mes / l_p } else { 1.0 };
+
+    MesopicFactors {
+        adaptation_luminance: l_p,
+        sp_ratio: sp,
+        adaptation_coefficient: m,
+        mesopic_luminance: l_mes,
+        luminance_multiplier: multiplier,
+        illuminance_multiplier: multiplier,
+    }
+}
+
+/// Calculate mesopic factors at the [`STANDARD_ADAPTATION_LUMINANCES`]
+pub fn mesopic_table(sp_ratio: f64) -> Vec<MesopicFactors> {
+    STANDARD_ADAPTATION_LUMINANCES
+        .iter()
+        .map(|&l| mesopic_factors(l, sp_ratio))
+        .collect()
+}
+
+impl Emitter {
+    /// Mesopic factors for this emitter's S/P ratio at the given adaptation luminance
+    ///
+    /// Returns `None` if the emitter has no S/P ratio.
+    pub fn mesopic_factors(&self, adaptation_luminance: f64) -> Option<MesopicFactors> {
+        self.sp_ratio
+            .map(|sp| mesopic_factors(adaptation_luminance, sp))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_photopic_range_is_unity() {
+        let f = mesopic_factors(5.0, 1.5);
+        assert_eq!(f.adaptation_coefficient, 1.0);
+        assert_eq!(f.luminance_multiplier, 1.0);
+    }
+
+    #[test]
+    fn test_sp_ratio_one_is_unity() {
+        // V(λ) and V'(λ) weighted luminances coincide when S/P = 1
+        let f = mesopic_factors(0.5, 1.0);
+        assert_relative_eq!(f.luminance_multiplier, 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_mes2_reference_values() {
+        // Cool-white LED, S/P 2.25 at L_p = 0.1 cd/m² → L_mes ≈ 0.138
+        let f = mesopic_factors(0.1, 2.25);
+        assert_relative_eq!(f.mesopic_luminance, 0.138, epsilon = 0.005);
+
+        // High-pressure sodium, S/P 0.65 at 1.0 cd/m² → L_mes ≈ 0.960
+        let f = mesopic_factors(1.0, 0.65);
+        assert_relative_eq!(f.mesopic_luminance, 0.960, epsilon = 0.005);
+    }
+
+    #[test]
+    fn test_multiplier_grows_with_darkness() {
+        let table = mesopic_table(2.0);
+        assert_eq!(table.len(), STANDARD_ADAPTATION_LUMINANCES.len());
+        for pair in table.windows(2) {
+            assert!(pair[0].luminance_multiplier >= pair[1].luminance_multiplier);
+        }
+    }
+
+    #[test]
+    fn test_continuous_at_lower_limit() {
+        // L_s = L_p · S/P crosses the lower limit at L_p = 0.0025 for S/P 2.0
+        let below = mesopic_factors(0.0025 * 0.999, 2.0);
+        let above = mesopic_factors(0.0025 * 1.001, 2.0);
+        assert_eq!(below.adaptation_coefficient, 0.0);
+        assert_relative_eq!(below.luminance_multiplier, 2.0, epsilon = 1e-9);
+        assert_relative_eq!(
+            below.luminance_multiplier,
+            above.luminance_multiplier,
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn test_emitter_mesopic_factors() {
+        let mut emitter = Emitter::default();
+        assert!(emitter.mesopic_factors(0.5).is_none());
+        emitter.sp_ratio = Some(2.0);
+        let f = emitter.mesopic_factors(0.5).unwrap();
+        assert_relative_eq!(
+            f.effective_illuminance(10.0),
+            10.0 * f.illuminance_multiplier
+        );
+    }
+}
@@ -75,7 +75,7 @@ impl Tm30Theme {
 // ============================================================================
 
 /// CIE 1931 2° x̄(λ) color matching function
-const CIE_X: [f64; 81] = [
+pub(crate) const CIE_X: [f64; 81] = [
     0.001368, 0.002236, 0.004243, 0.007650, 0.014310, 0.023190, 0.043510, 0.077630, 0.134380,
     0.214770, 0.283900, 0.328500, 0.348280, 0.348060, 0.336200, 0.318700, 0.290800, 0.251100,
     0.195360, 0.142100, 0.095640, 0.058010, 0.032010, 0.014700, 0.004900, 0.002400, 0.009300,
@@ -88,7 +88,7 @@ const CIE_X: [f64; 81] = [
 ];
 
 /// CIE 1931 2° ȳ(λ) color matching function
-const CIE_Y: [f64; 81] = [
+pub(crate) const CIE_Y: [f64; 81] = [
     0.000039, 0.000064, 0.000120, 0.000217, 0.000396, 0.000640, 0.001210, 0.002180, 0.004000,
     0.007300, 0.011600, 0.016840, 0.023000, 0.029800, 0.038000, 0.048000, 0.060000, 0.073900,
     0.090980, 0.112600, 0.139020, 0.169300, 0.208020, 0.258600, 0.323000, 0.407300, 0.503000,
@@ -101,7 +101,7 @@ const CIE_Y: [f64; 81] = [
 ];
 
 /// CIE 1931 2° z̄(λ) color matching function
-const CIE_Z: [f64; 81] = [
+pub(crate) const CIE_Z: [f64; 81] = [
     0.006450, 0.010550, 0.020050, 0.036210, 0.067850, 0.110200, 0.207400, 0.371300, 0.645600,
     1.039050, 1.385600, 1.622960, 1.747060, 1.782600, 1.772110, 1.744100, 1.669200, 1.528100,
     1.287640, 1.041900, 0.812950, 0.616200, 0.465180, 0.353300, 0.272000, 0.212300, 0.158200,
@@ -114,7 +114,7 @@ const CIE_Z: [f64; 81] = [
 ];
 
 /// Wavelengths for CMF data (380-780nm at 5nm)
-const WAVELENGTHS: [f64; 81] = [
+pub(crate) const WAVELENGTHS: [f64; 81] = [
     380.0, 385.0, 390.0, 395.0, 400.0, 405.0, 410.0, 415.0, 420.0, 425.0, 430.0, 435.0, 440.0,
     445.0, 450.0, 455.0, 460.0, 465.0, 470.0, 475.0, 480.0, 485.0, 490.0, 495.0, 500.0, 505.0,
     510.0, 515.0, 520.0, 525.0, 530.0, 535.0, 540.0, 545.0, 550.0, 555.0, 560.0, 565.0, 570.0,
@@ -146,7 +146,7 @@ use ces_data::*;
 // ============================================================================
 
 /// Calculate XYZ tristimulus values from SPD
-fn spd_to_xyz(spd: &SpectralDistribution) -> (f64, f64, f64) {
+pub(crate) fn spd_to_xyz(spd: &SpectralDistribution) -> (f64, f64, f64) {
     let mut x = 0.0;
     let mut y = 0.0;
     let mut z = 0.0;
@@ -520,138 +520,138 @@ fn polygon_area(points: &[(f64, f64)]) -> f64 {
 // SVG Generation
 // ============================================================================
 
+/// IES TM-30 hue bin colors (bins 1-16)
+const HUE_BIN_COLORS: [&str; 16] = [
+    "#e62828", "#e74b4b", "#fb812e", "#ffb529", "#cbca46", "#7eb94c", "#41c06d", "#009c7c",
+    "#16bcb0", "#00a4bf", "#0085c3", "#3b62aa", "#4568ae", "#6a4e85", "#9d69a1", "#a74f81",
+];
+
 impl Tm30Result {
-    /// Generate Color Vector Graphic (CVG) as SVG
+    /// Color vectors normalized to the reference, as (test x, test y) per bin
+    ///
+    /// As in the standard TM-30 graphic, each reference bin centroid sits on
+    /// the unit circle at the bin's center hue; the test centroid is offset
+    /// from it by the chroma-relative test − reference difference. A point
+    /// outside the circle means increased saturation in that hue.
+    pub fn normalized_vectors(&self) -> [(f64, f64); 16] {
+        let mut vectors = [(0.0, 0.0); 16];
+        for (i, &(test_a, test_b, ref_a, ref_b)) in self.color_vectors.iter().enumerate() {
+            let hue = (i as f64 * 22.5).to_radians();
+            let ref_chroma = (ref_a * ref_a + ref_b * ref_b).sqrt();
+            vectors[i] = if ref_chroma > 0.0 {
+                (
+                    hue.cos() + (test_a - ref_a) / ref_chroma,
+                    hue.sin() + (test_b - ref_b) / ref_chroma,
+                )
+            } else {
+                (hue.cos(), hue.sin())
+            };
+        }
+        vectors
+    }
+
+    /// Generate the TM-30 Color Vector Graphic (CVG) as SVG
+    ///
+    /// Standard layout: hue bin sectors behind a unit reference circle, the
+    /// normalized test polygon and per-bin arrows from reference to test.
     pub fn to_svg(&self, width: f64, height: f64, theme: &Tm30Theme) -> String {
         let margin = 60.0;
         let plot_size = (width - 2.0 * margin).min(height - 2.0 * margin);
         let center_x = width / 2.0;
         let center_y = height / 2.0;
-        let scale = plot_size / 100.0; // Scale factor for a'/b' coordinates
+        // The plot spans ±1.5 reference radii
+        let radius = plot_size / 2.0;
+        let scale = radius / 1.5;
+        let to_screen = |x: f64, y: f64| (center_x + x * scale, center_y - y * scale);
 
         let mut svg = format!(
             r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {width} {height}" width="{width}" height="{height}">
   <rect width="{width}" height="{height}" fill="{bg}"/>
-  <defs>
-    <clipPath id="cvg-clip">
-      <circle cx="{cx}" cy="{cy}" r="{r}"/>
-    </clipPath>
-  </defs>
 "#,
             width = width,
             height = height,
             bg = theme.background,
-            cx = center_x,
-            cy = center_y,
-            r = plot_size / 2.0,
         );
 
-        // Grid circles
-        for r in [20.0, 40.0] {
+        // Hue bin sectors, 22.5° each centered on the bin hue
+        for (i, color) in HUE_BIN_COLORS.iter().enumerate() {
+            let start = (i as f64 * 22.5 - 11.25).to_radians();
+            let end = (i as f64 * 22.5 + 11.25).to_radians();
+            let (x1, y1) = to_screen(1.5 * start.cos(), 1.5 * start.sin());
+            let (x2, y2) = to_screen(1.5 * end.cos(), 1.5 * end.sin());
             svg.push_str(&format!(
-                r#"  <circle cx="{}" cy="{}" r="{}" fill="none" stroke="{}" stroke-width="1" stroke-dasharray="4,4"/>"#,
-                center_x, center_y, r * scale, theme.grid_color
+                r#"  <path class="tm30-bin" d="M {center_x:.1} {center_y:.1} L {x1:.1} {y1:.1} A {radius:.1} {radius:.1} 0 0 0 {x2:.1} {y2:.1} Z" fill="{color}" fill-opacity="0.35"/>"#
             ));
             svg.push('\n');
         }
 
-        // Outer circle (reference boundary)
-        svg.push_str(&format!(
-            r#"  <circle cx="{}" cy="{}" r="{}" fill="none" stroke="{}" stroke-width="2"/>"#,
-            center_x,
-            center_y,
-            plot_size / 2.0,
-            theme.grid_color
-        ));
-        svg.push('\n');
-
-        // Axis lines
-        svg.push_str(&format!(
-            r#"  <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="1"/>"#,
-            center_x - plot_size / 2.0,
-            center_y,
-            center_x + plot_size / 2.0,
-            center_y,
-            theme.grid_color
-        ));
-        svg.push('\n');
-        svg.push_str(&format!(
-            r#"  <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="1"/>"#,
-            center_x,
-            center_y - plot_size / 2.0,
-            center_x,
-            center_y + plot_size / 2.0,
-            theme.grid_color
-        ));
-        svg.push('\n');
-
-        // Hue bin colors for visualization
-        let hue_colors = [
-            "#e74c3c", "#e67e22", "#f39c12", "#f1c40f", // Red -> Yellow
-            "#2ecc71", "#1abc9c", "#16a085", "#3498db", // Green -> Cyan
-            "#2980b9", "#9b59b6", "#8e44ad", "#c0392b", // Blue -> Purple
-            "#d35400", "#e74c3c", "#c0392b", "#922b21", // Continuation
-        ];
-
-        // Draw reference polygon (gray)
-        let mut ref_path = String::from("M ");
-        for (i, &(_, _, ref_a, ref_b)) in self.color_vectors.iter().enumerate() {
-            let x = center_x + ref_a * scale;
-            let y = center_y - ref_b * scale; // Flip Y
-            if i == 0 {
-                ref_path.push_str(&format!("{:.1} {:.1}", x, y));
-            } else {
-                ref_path.push_str(&format!(" L {:.1} {:.1}", x, y));
-            }
+        // Chroma grid at ±20 % and the reference circle
+        for r in [0.8, 1.2] {
+            svg.push_str(&format!(
+                r#"  <circle cx="{center_x}" cy="{center_y}" r="{:.1}" fill="none" stroke="{}" stroke-width="1" stroke-dasharray="4,4"/>"#,
+                r * scale,
+                theme.grid_color
+            ));
+            svg.push('\n');
         }
-        ref_path.push_str(" Z");
         svg.push_str(&format!(
-            r#"  <path d="{}" fill="none" stroke="{}" stroke-width="2" stroke-dasharray="6,3"/>"#,
-            ref_path, theme.reference_color
+            r#"  <circle cx="{center_x}" cy="{center_y}" r="{:.1}" fill="none" stroke="{}" stroke-width="2"/>"#,
+            scale, theme.reference_color
         ));
         svg.push('\n');
 
-        // Draw test polygon (colored)
-        let mut test_path = String::from("M ");
-        for (i, &(test_a, test_b, _, _)) in self.color_vectors.iter().enumerate() {
-            let x = center_x + test_a * scale;
-            let y = center_y - test_b * scale;
-            if i == 0 {
-                test_path.push_str(&format!("{:.1} {:.1}", x, y));
-            } else {
-                test_path.push_str(&format!(" L {:.1} {:.1}", x, y));
-            }
-        }
-        test_path.push_str(" Z");
+        // Normalized test polygon
+        let vectors = self.normalized_vectors();
+        let points: Vec<String> = vectors
+            .iter()
+            .map(|&(x, y)| {
+                let (sx, sy) = to_screen(x, y);
+                format!("{sx:.1},{sy:.1}")
+            })
+            .collect();
         svg.push_str(&format!(
-            r#"  <path d="{}" fill="{}20" stroke="{}" stroke-width="2"/>"#,
-            test_path, theme.test_color, theme.test_color
+            r#"  <polygon points="{}" fill="none" stroke="{}" stroke-width="2"/>"#,
+            points.join(" "),
+            theme.test_color
         ));
         svg.push('\n');
 
-        // Draw color vectors (arrows from reference to test)
-        for (i, &(test_a, test_b, ref_a, ref_b)) in self.color_vectors.iter().enumerate() {
-            let x1 = center_x + ref_a * scale;
-            let y1 = center_y - ref_b * scale;
-            let x2 = center_x + test_a * scale;
-            let y2 = center_y - test_b * scale;
-
-            // Vector line
+        // Color vectors from the reference circle to the test centroids
+        for (i, &(x, y)) in vectors.iter().enumerate() {
+            let hue = (i as f64 * 22.5).to_radians();
+            let (x1, y1) = to_screen(hue.cos(), hue.sin());
+            let (x2, y2) = to_screen(x, y);
             svg.push_str(&format!(
-                r#"  <line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="2"/>"#,
-                x1, y1, x2, y2, hue_colors[i]
+                r#"  <line class="tm30-vector" x1="{x1:.1}" y1="{y1:.1}" x2="{x2:.1}" y2="{y2:.1}" stroke="{}" stroke-width="2"/>"#,
+                HUE_BIN_COLORS[i]
             ));
             svg.push('\n');
 
-            // Test point
-            svg.push_str(&format!(
-                r#"  <circle cx="{:.1}" cy="{:.1}" r="4" fill="{}" stroke="{}" stroke-width="1"/>"#,
-                x2, y2, hue_colors[i], theme.background
-            ));
+            // Arrow head
+            let angle = (y2 - y1).atan2(x2 - x1);
+            if (x2 - x1).hypot(y2 - y1) > 3.0 {
+                let (ax, ay) = (
+                    x2 - 8.0 * (angle - 0.4).cos(),
+                    y2 - 8.0 * (angle - 0.4).sin(),
+                );
+                let (bx, by) = (
+                    x2 - 8.0 * (angle + 0.4).cos(),
+                    y2 - 8.0 * (angle + 0.4).sin(),
+                );
+                svg.push_str(&format!(
+                    r#"  <path d="M {x2:.1} {y2:.1} L {ax:.1} {ay:.1} L {bx:.1} {by:.1} Z" fill="{}"/>"#,
+                    HUE_BIN_COLORS[i]
+                ));
+            } else {
+                svg.push_str(&format!(
+                    r#"  <circle cx="{x2:.1}" cy="{y2:.1}" r="3" fill="{}"/>"#,
+                    HUE_BIN_COLORS[i]
+                ));
+            }
             svg.push('\n');
         }
 
-        // Title and metrics
+        // Title
         svg.push_str(&format!(
             r#"  <text x="{}" y="25" fill="{}" font-size="16" font-family="{}" font-weight="bold" text-anchor="middle">TM-30 Color Vector Graphic</text>"#,
             center_x, theme.foreground, theme.font_family
@@ -688,8 +688,12 @@ impl Tm30Result {
 
         // Legend
         svg.push_str(&format!(
-            r#"  <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="2" stroke-dasharray="6,3"/>"#,
-            width - 120.0, 20.0, width - 90.0, 20.0, theme.reference_color
+            r#"  <line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="2"/>"#,
+            width - 120.0,
+            20.0,
+            width - 90.0,
+            20.0,
+            theme.reference_color
         ));
         svg.push_str(&format!(
             r#"  <text x="{}" y="24" fill="{}" font-size="11" font-family="{}">Reference</text>"#,
@@ -729,11 +733,6 @@ impl Tm30Result {
         let plot_height = height - margin_top - margin_bottom;
         let bar_width = plot_width / 16.0 - 4.0;
 
-        let hue_colors = [
-            "#e74c3c", "#e67e22", "#f39c12", "#f1c40f", "#2ecc71", "#27ae60", "#1abc9c", "#16a085",
-            "#3498db", "#2980b9", "#9b59b6", "#8e44ad", "#c0392b", "#d35400", "#e74c3c", "#922b21",
-        ];
-
         let mut svg = format!(
             r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {width} {height}" width="{width}" height="{height}">
   <rect width="{width}" height="{height}" fill="{bg}"/>
@@ -777,7 +776,7 @@ impl Tm30Result {
 
             svg.push_str(&format!(
                 r#"  <rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="{}" rx="2"/>"#,
-                x, y, bar_width, bar_height, hue_colors[i]
+                x, y, bar_width, bar_height, HUE_BIN_COLORS[i]
             ));
             svg.push('\n');
 
@@ -880,4 +879,18 @@ mod tests {
         assert_eq!(tm30.rf_hue.len(), 16);
         assert_eq!(tm30.color_vectors.len(), 16);
     }
+    #[test]
+    fn test_cvg_normalized_vectors() {
+        // A blackbody renders like its own reference: vectors stay on the circle
+        let tm30 = calculate_tm30(&planckian_spd(3000.0)).unwrap();
+        for (x, y) in tm30.normalized_vectors() {
+            assert!((x.hypot(y) - 1.0).abs() < 0.05, "|v| = {}", x.hypot(y));
+        }
+
+        let svg = tm30.to_svg(500.0, 500.0, &Tm30Theme::dark());
+        assert_eq!(svg.matches(r#"class="tm30-bin""#).count(), 16);
+        assert_eq!(svg.matches(r#"class="tm30-vector""#).count(), 16);
+        assert!(svg.contains(&format!("Rf = {:.0}", tm30.rf)));
+        assert!(svg.contains(HUE_BIN_COLORS[15]));
+    }
 }
@@ -5,12 +5,12 @@
 //! stays in `eulumdat-bevy` while the `photometric/` module is extracted.
 
 use crate::photometric::{parse_color_temperature, parse_cri, PhotometricData};
-use eulumdat::Eulumdat;
+use eulumdat::{Eulumdat, Interpolation};
 
 impl PhotometricData for Eulumdat {
     fn sample(&self, c_angle: f64, g_angle: f64) -> f64 {
-        // Eulumdat::sample already handles symmetry expansion and interpolation
-        Eulumdat::sample(self, c_angle, g_angle)
+        // Bicubic avoids faceting of the photometric solid on coarse grids
+        Eulumdat::sample_with(self, c_angle, g_angle, Interpolation::Bicubic)
     }
 
     fn max_intensity(&self) -> f64 {
@@ -38,6 +38,12 @@ impl PhotometricData for Eulumdat {
         )
     }
 
+    fn photometric_center(&self) -> (f32, f32, f32) {
+        // Photometric frame (z up) to Bevy (y up), mm to meters
+        let (x, y, z) = Eulumdat::photometric_center(self).to_meters();
+        (x as f32, z as f32, y as f32)
+    }
+
     fn color_temperature(&self) -> Option<f32> {
         self.lamp_sets
             .first()
@@ -134,10 +134,19 @@ pub fn run_on_canvas(canvas_selector: &str) {
 }
 
 /// Run the 3D viewer as a native window (desktop).
+///
+/// A `.scene.toml` path as the first argument opens that scene file.
 #[cfg(all(not(target_arch = "wasm32"), feature = "viewer"))]
 pub fn run_native() {
     use bevy::prelude::*;
 
+    let plugin = match std::env::args().nth(1) {
+        Some(path) if path.ends_with(viewer::scene_file::SCENE_FILE_EXTENSION) => {
+            viewer::EulumdatViewerPlugin::with_scene_file(path)
+        }
+        _ => viewer::EulumdatViewerPlugin::default(),
+    };
+
     App::new()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
@@ -147,7 +156,7 @@ pub fn run_native() {
             }),
             ..default()
         }))
-        .add_plugins(viewer::EulumdatViewerPlugin::default())
+        .add_plugins(plugin)
         .run();
 }
 
@@ -65,6 +65,15 @@ pub trait PhotometricData: Send + Sync + Clone + Debug + 'static {
     /// For cylindrical luminaires, width may be 0 and length is the diameter.
     fn dimensions(&self) -> (f32, f32, f32);
 
+    /// Photometric center relative to the geometric center in meters.
+    ///
+    /// Given in the luminaire frame (+X = C0, +Y = up, +Z = C90). Lights and
+    /// the photometric solid are placed here instead of at the center of
+    /// the luminaire model. Defaults to the geometric center.
+    fn photometric_center(&self) -> (f32, f32, f32) {
+        (0.0, 0.0, 0.0)
+    }
+
     /// Color temperature in Kelvin (None if unknown).
     ///
     /// Common values: 2700K (warm), 4000K (neutral), 6500K (cool)
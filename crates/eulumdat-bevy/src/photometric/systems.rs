@@ -145,6 +145,9 @@ fn spawn_lights_for_entity<T: PhotometricData>(
     // Get luminaire dimensions
     let (_, _, _height) = data.dimensions();
 
+    // Lights emit from the photometric center, the model stays centered
+    let origin = position + rotation * Vec3::from(data.photometric_center());
+
     // Calculate light direction based on luminaire rotation
     // Default up direction is +Y, apply luminaire rotation
     let up_direction = rotation * Vec3::Y;
@@ -159,7 +162,7 @@ fn spawn_lights_for_entity<T: PhotometricData>(
             shadow_maps_enabled: false,
             ..default()
         },
-        Transform::from_translation(position),
+        Transform::from_translation(origin),
         BevyLightMarker::<T>::new(parent_entity),
     ));
 
@@ -167,7 +170,7 @@ fn spawn_lights_for_entity<T: PhotometricData>(
     // For asymmetric luminaires (like road lights), we create multiple angled spots
     // that follow the luminaire's rotation to illuminate in the correct direction
     if downward_fraction > 0.1 {
-        let spot_pos = position;
+        let spot_pos = origin;
 
         // Get the luminaire's local axes (rotated by parent transform)
         // After 90° Y rotation for road scene:
@@ -243,7 +246,7 @@ fn spawn_lights_for_entity<T: PhotometricData>(
 
     // Spawn upward spot light (if significant upward flux)
     if upward_fraction > 0.1 {
-        let target = position + up_direction * 10.0;
+        let target = origin + up_direction * 10.0;
         let forward = rotation * Vec3::Z;
         let up_hint = if forward.dot(up_direction).abs() > 0.99 {
             rotation * Vec3::X
@@ -262,7 +265,7 @@ fn spawn_lights_for_entity<T: PhotometricData>(
                 shadow_maps_enabled: light.shadow_maps_enabled,
                 ..default()
             },
-            Transform::from_translation(position).looking_at(target, up_hint),
+            Transform::from_translation(origin).looking_at(target, up_hint),
             BevyLightMarker::<T>::new(parent_entity),
         ));
     }
@@ -289,7 +292,7 @@ fn spawn_lights_for_entity<T: PhotometricData>(
         commands.spawn((
             Mesh3d(meshes.add(mesh)),
             MeshMaterial3d(materials.add(material)),
-            Transform::from_translation(position - Vec3::Y * 0.1).with_rotation(rotation),
+            Transform::from_translation(origin).with_rotation(rotation),
             PhotometricSolid::<T>::new(parent_entity),
         ));
     }
@@ -0,0 +1,283 @@
+//! Sun and daylight reference for outdoor scenes.
+//!
+//! A simple solar position model (declination and hour angle, no equation
+//! of time) drives a directional "sun" light and the sky ambient, so road
+//! and area scenes can be viewed at dusk, at night, or next to daylight to
+//! judge how much the luminaires actually contribute.
+//!
+//! # Controls
+//!
+//! - `N`: Toggle the daylight reference
+//! - `J` / `K`: Move the time of day back/forward (±0.25 h)
+
+use super::{SceneType, ViewerSettings};
+use bevy::prelude::*;
+
+/// Time step for the `J` / `K` keys in hours.
+const HOUR_STEP: f32 = 0.25;
+
+/// Ambient brightness without daylight (matches the scene default).
+const NIGHT_AMBIENT: f32 = 50.0;
+
+/// Plugin for the sun light in outdoor scenes.
+pub struct DaylightPlugin;
+
+impl Plugin for DaylightPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Daylight>()
+            .add_systems(Startup, spawn_sun)
+            .add_systems(Update, (daylight_controls_system, update_sun).chain());
+    }
+}
+
+/// Sun position above the horizon.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SunPosition {
+    /// Elevation above the horizon in degrees (negative below)
+    pub elevation: f32,
+    /// Azimuth in degrees, clockwise from north (90 = east)
+    pub azimuth: f32,
+}
+
+impl SunPosition {
+    /// Sun position for a day of the year (1-365), local solar time in
+    /// hours (12 = solar noon) and latitude in degrees (north positive).
+    pub fn compute(day_of_year: u32, solar_hour: f32, latitude: f32) -> Self {
+        let declination = 23.44f32.to_radians()
+            * (360.0 / 365.0 * (284.0 + day_of_year as f32))
+                .to_radians()
+                .sin();
+        let hour_angle = (15.0 * (solar_hour - 12.0)).to_radians();
+        let lat = latitude.to_radians();
+
+        let sin_elevation =
+            lat.sin() * declination.sin() + lat.cos() * declination.cos() * hour_angle.cos();
+        let elevation = sin_elevation.clamp(-1.0, 1.0).asin();
+
+        // Measured from south, positive towards west; shift to north-based
+        let azimuth = hour_angle
+            .sin()
+            .atan2(hour_angle.cos() * lat.sin() - declination.tan() * lat.cos())
+            .to_degrees()
+            + 180.0;
+
+        Self {
+            elevation: elevation.to_degrees(),
+            azimuth: azimuth.rem_euclid(360.0),
+        }
+    }
+
+    /// Unit vector pointing towards the sun in scene coordinates
+    /// (north = -Z, east = +X, up = +Y).
+    pub fn direction(&self) -> Vec3 {
+        let (el, az) = (self.elevation.to_radians(), self.azimuth.to_radians());
+        Vec3::new(az.sin() * el.cos(), el.sin(), -az.cos() * el.cos())
+    }
+
+    /// Direct sunlight on a surface facing the sun in lux.
+    ///
+    /// Clear-sky extinction over the air mass; zero below the horizon.
+    pub fn direct_illuminance(&self) -> f32 {
+        let sin_el = self.elevation.to_radians().sin();
+        if sin_el <= 0.0 {
+            return 0.0;
+        }
+        127_500.0 * (-0.21 / sin_el).exp()
+    }
+
+    /// Diffuse sky illuminance on a horizontal surface in lux.
+    ///
+    /// Falls off log-linearly through twilight (about 400 lx at sunset,
+    /// 3 lx at the end of civil twilight) down to starlight.
+    pub fn sky_illuminance(&self) -> f32 {
+        if self.elevation >= 0.0 {
+            400.0 + 20_000.0 * self.elevation.to_radians().sin()
+        } else {
+            (400.0 * 10f32.powf(self.elevation * 2.12 / 6.0)).max(0.001)
+        }
+    }
+
+    /// Total daylight on a horizontal surface in lux.
+    pub fn horizontal_illuminance(&self) -> f32 {
+        self.direct_illuminance() * self.elevation.to_radians().sin().max(0.0)
+            + self.sky_illuminance()
+    }
+}
+
+/// Daylight reference settings.
+#[derive(Resource, Clone)]
+pub struct Daylight {
+    /// Whether the sun and sky contribute to outdoor scenes
+    pub enabled: bool,
+    /// Day of the year (1-365)
+    pub day_of_year: u32,
+    /// Local solar time in hours (12 = solar noon)
+    pub solar_hour: f32,
+    /// Site latitude in degrees (north positive)
+    pub latitude: f32,
+    /// Factor from lux to the renderer's light units.
+    ///
+    /// The photometric lights are not in physical units, so this keeps
+    /// daylight and luminaires in a viewable balance.
+    pub render_scale: f32,
+}
+
+impl Default for Daylight {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            day_of_year: 172, // June solstice
+            solar_hour: 21.0, // Dusk in summer at mid latitudes
+            latitude: 48.0,
+            render_scale: 0.1,
+        }
+    }
+}
+
+impl Daylight {
+    /// Current sun position.
+    pub fn sun_position(&self) -> SunPosition {
+        SunPosition::compute(self.day_of_year, self.solar_hour, self.latitude)
+    }
+
+    /// Share of the luminaires in the total horizontal illuminance (0-1)
+    /// for a point receiving `luminaire_lux` from the installation.
+    pub fn luminaire_share(&self, luminaire_lux: f32) -> f32 {
+        let daylight = if self.enabled {
+            self.sun_position().horizontal_illuminance()
+        } else {
+            0.0
+        };
+        let total = luminaire_lux.max(0.0) + daylight;
+        if total > 0.0 {
+            luminaire_lux.max(0.0) / total
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Marker component for the sun light.
+#[derive(Component)]
+pub struct SunLight;
+
+/// Whether the daylight reference applies to a scene type.
+fn is_outdoor(scene_type: SceneType) -> bool {
+    matches!(
+        scene_type,
+        SceneType::Road | SceneType::Parking | SceneType::Outdoor | SceneType::DesignerExterior
+    )
+}
+
+fn spawn_sun(mut commands: Commands) {
+    commands.spawn((
+        DirectionalLight {
+            illuminance: 0.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::default(),
+        Visibility::Hidden,
+        SunLight,
+    ));
+}
+
+/// Keyboard controls for the daylight reference.
+pub fn daylight_controls_system(
+    mut daylight: ResMut<Daylight>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyN) {
+        daylight.enabled = !daylight.enabled;
+    }
+    if keyboard.just_pressed(KeyCode::KeyJ) {
+        daylight.solar_hour = (daylight.solar_hour - HOUR_STEP).rem_euclid(24.0);
+    }
+    if keyboard.just_pressed(KeyCode::KeyK) {
+        daylight.solar_hour = (daylight.solar_hour + HOUR_STEP).rem_euclid(24.0);
+    }
+}
+
+/// Point the sun light and set sun and sky brightness for the current time.
+///
+/// Also reruns when the scene rebuild resets the ambient light.
+fn update_sun(
+    daylight: Res<Daylight>,
+    settings: Res<ViewerSettings>,
+    ambient: Option<ResMut<bevy::light::GlobalAmbientLight>>,
+    mut suns: Query<(&mut DirectionalLight, &mut Transform, &mut Visibility), With<SunLight>>,
+) {
+    let ambient_reset = ambient.as_ref().is_some_and(|a| a.is_changed());
+    if !daylight.is_changed() && !settings.is_changed() && !ambient_reset {
+        return;
+    }
+
+    let active = daylight.enabled && is_outdoor(settings.scene_type);
+    let sun = daylight.sun_position();
+
+    for (mut light, mut transform, mut visibility) in &mut suns {
+        if active && sun.elevation > 0.0 {
+            light.illuminance = sun.direct_illuminance() * daylight.render_scale;
+            transform.rotation = Quat::from_rotation_arc(Vec3::NEG_Z, -sun.direction());
+            *visibility = Visibility::Visible;
+        } else {
+            light.illuminance = 0.0;
+            *visibility = Visibility::Hidden;
+        }
+    }
+
+    if let Some(mut ambient) = ambient {
+        let brightness = if active {
+            NIGHT_AMBIENT + sun.sky_illuminance() * daylight.render_scale
+        } else {
+            NIGHT_AMBIENT
+        };
+        if ambient.brightness != brightness {
+            ambient.brightness = brightness;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sun_position() {
+        // Equinox at solar noon: elevation = 90° - latitude, due south
+        let noon = SunPosition::compute(80, 12.0, 48.0);
+        assert!((noon.elevation - 42.0).abs() < 1.0, "{noon:?}");
+        assert!((noon.azimuth - 180.0).abs() < 0.1, "{noon:?}");
+
+        // Morning sun rises in the east, midnight is below the horizon
+        let morning = SunPosition::compute(80, 8.0, 48.0);
+        assert!(morning.azimuth > 90.0 && morning.azimuth < 180.0);
+        assert!(SunPosition::compute(172, 0.0, 48.0).elevation < 0.0);
+
+        let dir = noon.direction();
+        assert!((dir.length() - 1.0).abs() < 1e-5);
+        assert!(dir.z > 0.0 && dir.y > 0.0); // south = +Z
+    }
+
+    #[test]
+    fn test_dusk_transition() {
+        let illuminance = |hour| SunPosition::compute(172, hour, 48.0).horizontal_illuminance();
+        assert!(illuminance(12.0) > 50_000.0);
+        assert!(illuminance(19.0) > illuminance(20.5));
+        assert!(illuminance(20.5) > illuminance(22.0));
+        assert!(illuminance(0.0) < 1.0);
+
+        let daylight = Daylight {
+            enabled: true,
+            solar_hour: 23.5,
+            ..default()
+        };
+        // 10 lx from the installation dominates at night
+        assert!(daylight.luminaire_share(10.0) > 0.9);
+        let noon = Daylight {
+            solar_hour: 12.0,
+            ..daylight
+        };
+        assert!(noon.luminaire_share(10.0) < 0.01);
+    }
+}
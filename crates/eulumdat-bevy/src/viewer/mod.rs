@@ -6,6 +6,8 @@
 //! # Features
 //!
 //! - Pre-built demo scenes (Room, Road, Parking, Outdoor)
+//! - Sun/daylight reference for outdoor scenes
+//! - Scene description files (`.scene.toml`) for sharing sessions
 //! - First-person camera controller
 //! - Keyboard controls for toggling visualizations
 //! - Optional localStorage sync for WASM hot-reload
@@ -26,10 +28,12 @@
 
 pub mod camera;
 pub mod controls;
+pub mod daylight;
 pub mod designer_scenes;
 #[cfg(feature = "egui-ui")]
 pub mod egui_panel;
 pub mod plugin;
+pub mod scene_file;
 pub mod scenes;
 pub mod wasm_sync;
 
@@ -37,7 +41,9 @@ pub use camera::{CameraPlugin, FirstPersonCamera};
 pub use controls::{
     calculate_all_luminaire_transforms, calculate_light_position, LuminaireTransform,
 };
+pub use daylight::{Daylight, DaylightPlugin, SunLight, SunPosition};
 pub use plugin::EulumdatViewerPlugin;
+pub use scene_file::{CameraBookmark, SceneFile, SceneFileError, SceneSession};
 pub use scenes::{SceneGeometry, ScenePlugin, SceneType};
 pub use wasm_sync::{
     load_default_ldt, load_from_local_storage, poll_viewer_settings_changes, DesignerTimestamp,
@@ -2,6 +2,8 @@
 //!
 //! This plugin provides a complete 3D viewer with:
 //! - Demo scenes (Room, Road, Parking, Outdoor)
+//! - Sun/daylight reference for outdoor scenes
+//! - Scene description files for reproducible sessions
 //! - First-person camera
 //! - Keyboard controls
 //! - Optional localStorage sync for WASM
@@ -10,6 +12,8 @@ use super::camera::CameraPlugin;
 use super::controls::{
     calculate_all_luminaire_transforms, sync_viewer_to_lights, viewer_controls_system,
 };
+use super::daylight::DaylightPlugin;
+use super::scene_file::{apply_initial_bookmark, scene_file_controls_system, SceneSession};
 use super::scenes::ScenePlugin;
 use super::wasm_sync::{
     load_default_ldt, DesignerTimestamp, LdtTimestamp, ViewerSettingsTimestamp,
@@ -19,6 +23,7 @@ use crate::eulumdat_impl::EulumdatLightBundle;
 use crate::photometric::PhotometricPlugin;
 use bevy::prelude::*;
 use eulumdat::Eulumdat;
+use std::path::PathBuf;
 
 /// Full demo application plugin for the Eulumdat 3D viewer.
 ///
@@ -26,6 +31,7 @@ use eulumdat::Eulumdat;
 /// - [`PhotometricPlugin`] for photometric lighting
 /// - [`CameraPlugin`] for first-person camera
 /// - [`ScenePlugin`] for demo scene geometry
+/// - [`DaylightPlugin`] for the sun in outdoor scenes
 /// - Keyboard controls (P/L/H/1-4)
 /// - Optional localStorage sync for WASM hot-reload
 ///
@@ -50,6 +56,9 @@ pub struct EulumdatViewerPlugin {
     /// Enable localStorage polling for hot-reload (WASM only, requires `wasm-sync` feature).
     /// Default: true when `wasm-sync` feature is enabled, false otherwise.
     pub enable_local_storage_sync: bool,
+    /// Scene description file to open at startup (optional).
+    /// Overrides `initial_ldt` when the file names a luminaire.
+    pub scene_file: Option<PathBuf>,
 }
 
 impl Default for EulumdatViewerPlugin {
@@ -58,6 +67,7 @@ impl Default for EulumdatViewerPlugin {
             initial_ldt: None,
             enable_keyboard_controls: true,
             enable_local_storage_sync: cfg!(feature = "wasm-sync"),
+            scene_file: None,
         }
     }
 }
@@ -74,6 +84,15 @@ impl EulumdatViewerPlugin {
             initial_ldt: Some(ldt),
             enable_keyboard_controls: true,
             enable_local_storage_sync: cfg!(feature = "wasm-sync"),
+            scene_file: None,
+        }
+    }
+
+    /// Create a plugin that opens a `.scene.toml` file.
+    pub fn with_scene_file(path: impl Into<PathBuf>) -> Self {
+        Self {
+            scene_file: Some(path.into()),
+            ..Self::default()
         }
     }
 }
@@ -84,14 +103,23 @@ impl Plugin for EulumdatViewerPlugin {
         app.add_plugins(PhotometricPlugin::<Eulumdat>::new());
 
         // Add viewer-specific plugins
-        app.add_plugins((CameraPlugin, ScenePlugin));
+        app.add_plugins((CameraPlugin, ScenePlugin, DaylightPlugin));
 
-        // Insert viewer settings
-        let settings = ViewerSettings {
+        // Insert viewer settings, applying the scene file if one is given
+        let mut settings = ViewerSettings {
             ldt_data: self.initial_ldt.clone(),
             ..default()
         };
+        let session = match &self.scene_file {
+            Some(path) => SceneSession::open(path, &mut settings).unwrap_or_else(|e| {
+                warn!("Failed to open scene file: {e}");
+                SceneSession::default()
+            }),
+            None => SceneSession::default(),
+        };
         app.insert_resource(settings);
+        app.insert_resource(session);
+        app.add_systems(PostStartup, apply_initial_bookmark);
         app.insert_resource(LdtTimestamp::default());
         app.insert_resource(ViewerSettingsTimestamp::default());
         app.insert_resource(DesignerTimestamp::default());
@@ -101,7 +129,7 @@ impl Plugin for EulumdatViewerPlugin {
 
         // Add keyboard controls if enabled
         if self.enable_keyboard_controls {
-            app.add_systems(Update, viewer_controls_system);
+            app.add_systems(Update, (viewer_controls_system, scene_file_controls_system));
         }
 
         // Add localStorage polling if feature is enabled
@@ -0,0 +1,440 @@
+//! Scene description files (`.scene.toml`) for reproducible viewer sessions.
+//!
+//! A scene file captures everything needed to reopen a design review
+//! identically: scene type and dimensions, the luminaire (as a path to the
+//! LDT/IES file or embedded), the exterior layout, camera bookmarks and
+//! display toggles.
+//!
+//! ```toml
+//! [scene]
+//! scene_type = "road"
+//! mounting_height = 8.0
+//! num_lanes = 2
+//!
+//! [display]
+//! show_shadows = true
+//!
+//! [luminaire]
+//! ldt = "road_luminaire.ldt"
+//!
+//! [[cameras]]
+//! name = "Driver view"
+//! position = [1.75, 1.2, -5.0]
+//! yaw = 3.14
+//! pitch = 0.05
+//! ```
+//!
+//! Relative LDT paths are resolved against the scene file's directory.
+//!
+//! # Controls
+//!
+//! - `B`: Jump to the next camera bookmark
+//! - `Shift+B`: Bookmark the current camera
+//! - `Ctrl+S`: Save the session to the scene file it was opened from
+
+use super::camera::FirstPersonCamera;
+use super::{SceneType, ViewerSettings};
+use bevy::prelude::*;
+use eulumdat::area::LuminairePlace;
+use eulumdat::Eulumdat;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Conventional extension of scene description files.
+pub const SCENE_FILE_EXTENSION: &str = ".scene.toml";
+
+/// Errors from loading or saving a scene file.
+#[derive(Debug)]
+pub enum SceneFileError {
+    /// Reading or writing a file failed.
+    Io(PathBuf, std::io::Error),
+    /// The scene file is not valid TOML or has unknown values.
+    Parse(toml::de::Error),
+    /// The scene could not be serialized.
+    Serialize(toml::ser::Error),
+    /// The referenced luminaire file could not be parsed.
+    Luminaire(PathBuf, String),
+}
+
+impl fmt::Display for SceneFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, e) => write!(f, "{}: {e}", path.display()),
+            Self::Parse(e) => write!(f, "invalid scene file: {e}"),
+            Self::Serialize(e) => write!(f, "cannot serialize scene: {e}"),
+            Self::Luminaire(path, e) => write!(f, "{}: {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for SceneFileError {}
+
+/// Scene type and dimensions (`[scene]`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SceneSection {
+    pub scene_type: SceneType,
+    pub room_width: f32,
+    pub room_length: f32,
+    pub room_height: f32,
+    pub mounting_height: f32,
+    pub pendulum_length: f32,
+    pub luminaire_tilt: f32,
+    pub lane_width: f32,
+    pub num_lanes: u32,
+    pub sidewalk_width: f32,
+    pub pole_spacing: f32,
+}
+
+impl Default for SceneSection {
+    fn default() -> Self {
+        Self::from_settings(&ViewerSettings::default())
+    }
+}
+
+impl SceneSection {
+    fn from_settings(settings: &ViewerSettings) -> Self {
+        Self {
+            scene_type: settings.scene_type,
+            room_width: settings.room_width,
+            room_length: settings.room_length,
+            room_height: settings.room_height,
+            mounting_height: settings.mounting_height,
+            pendulum_length: settings.pendulum_length,
+            luminaire_tilt: settings.luminaire_tilt,
+            lane_width: settings.lane_width,
+            num_lanes: settings.num_lanes,
+            sidewalk_width: settings.sidewalk_width,
+            pole_spacing: settings.pole_spacing,
+        }
+    }
+}
+
+/// Visualization toggles (`[display]`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplaySection {
+    pub show_luminaire: bool,
+    pub show_photometric_solid: bool,
+    pub show_shadows: bool,
+    pub show_light_cones: bool,
+    pub show_cavities: bool,
+}
+
+impl Default for DisplaySection {
+    fn default() -> Self {
+        Self::from_settings(&ViewerSettings::default())
+    }
+}
+
+impl DisplaySection {
+    fn from_settings(settings: &ViewerSettings) -> Self {
+        Self {
+            show_luminaire: settings.show_luminaire,
+            show_photometric_solid: settings.show_photometric_solid,
+            show_shadows: settings.show_shadows,
+            show_light_cones: settings.show_light_cones,
+            show_cavities: settings.show_cavities,
+        }
+    }
+}
+
+/// Luminaire and layout (`[luminaire]`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LuminaireSection {
+    /// Path to the LDT or IES file, relative to the scene file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldt: Option<PathBuf>,
+    /// Embedded LDT content, used when no path is given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ldt_content: Option<String>,
+    /// Exterior designer placements
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub placements: Vec<LuminairePlace>,
+}
+
+/// A named camera position (`[[cameras]]`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CameraBookmark {
+    pub name: String,
+    /// Camera position in meters
+    pub position: [f32; 3],
+    /// Yaw angle in radians
+    pub yaw: f32,
+    /// Pitch angle in radians
+    pub pitch: f32,
+}
+
+impl CameraBookmark {
+    /// Camera transform for this bookmark.
+    pub fn transform(&self) -> Transform {
+        Transform::from_translation(Vec3::from_array(self.position))
+            .with_rotation(Quat::from_euler(EulerRot::YXZ, self.yaw, self.pitch, 0.0))
+    }
+}
+
+/// Contents of a `.scene.toml` file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SceneFile {
+    pub scene: SceneSection,
+    pub display: DisplaySection,
+    pub luminaire: LuminaireSection,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cameras: Vec<CameraBookmark>,
+}
+
+impl SceneFile {
+    /// Capture the current viewer state.
+    ///
+    /// With `ldt_path` the luminaire is referenced by path, otherwise the
+    /// current LDT data is embedded so the file is self-contained.
+    pub fn from_settings(
+        settings: &ViewerSettings,
+        ldt_path: Option<PathBuf>,
+        cameras: Vec<CameraBookmark>,
+    ) -> Self {
+        let ldt_content = match ldt_path {
+            Some(_) => None,
+            None => settings.ldt_data.as_ref().map(Eulumdat::to_ldt),
+        };
+        Self {
+            scene: SceneSection::from_settings(settings),
+            display: DisplaySection::from_settings(settings),
+            luminaire: LuminaireSection {
+                ldt: ldt_path,
+                ldt_content,
+                placements: settings.area_placements.clone(),
+            },
+            cameras,
+        }
+    }
+
+    /// Parse a scene file from TOML.
+    pub fn from_toml_str(content: &str) -> Result<Self, SceneFileError> {
+        toml::from_str(content).map_err(SceneFileError::Parse)
+    }
+
+    /// Serialize the scene file to TOML.
+    pub fn to_toml_string(&self) -> Result<String, SceneFileError> {
+        toml::to_string_pretty(self).map_err(SceneFileError::Serialize)
+    }
+
+    /// Read a scene file from disk.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SceneFileError> {
+        let path = path.as_ref();
+        let content =
+            std::fs::read_to_string(path).map_err(|e| SceneFileError::Io(path.to_path_buf(), e))?;
+        Self::from_toml_str(&content)
+    }
+
+    /// Write the scene file to disk.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SceneFileError> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_toml_string()?)
+            .map_err(|e| SceneFileError::Io(path.to_path_buf(), e))
+    }
+
+    /// Load the luminaire, resolving a relative path against `base_dir`.
+    ///
+    /// Returns `None` if the file names no luminaire.
+    pub fn load_luminaire(&self, base_dir: &Path) -> Result<Option<Eulumdat>, SceneFileError> {
+        if let Some(ldt) = &self.luminaire.ldt {
+            let path = base_dir.join(ldt);
+            return Eulumdat::from_file(&path)
+                .map(Some)
+                .map_err(|e| SceneFileError::Luminaire(path, e.to_string()));
+        }
+        self.luminaire
+            .ldt_content
+            .as_deref()
+            .map(|content| {
+                Eulumdat::parse(content).map_err(|e| {
+                    SceneFileError::Luminaire(PathBuf::from("ldt_content"), e.to_string())
+                })
+            })
+            .transpose()
+    }
+
+    /// Apply scene, display and layout values to the viewer settings.
+    ///
+    /// The luminaire is loaded separately with [`Self::load_luminaire`].
+    pub fn apply(&self, settings: &mut ViewerSettings) {
+        let scene = &self.scene;
+        settings.scene_type = scene.scene_type;
+        settings.room_width = scene.room_width;
+        settings.room_length = scene.room_length;
+        settings.room_height = scene.room_height;
+        settings.mounting_height = scene.mounting_height;
+        settings.pendulum_length = scene.pendulum_length;
+        settings.luminaire_tilt = scene.luminaire_tilt;
+        settings.lane_width = scene.lane_width;
+        settings.num_lanes = scene.num_lanes;
+        settings.sidewalk_width = scene.sidewalk_width;
+        settings.pole_spacing = scene.pole_spacing;
+
+        let display = &self.display;
+        settings.show_luminaire = display.show_luminaire;
+        settings.show_photometric_solid = display.show_photometric_solid;
+        settings.show_shadows = display.show_shadows;
+        settings.show_light_cones = display.show_light_cones;
+        settings.show_cavities = display.show_cavities;
+
+        settings.area_placements = self.luminaire.placements.clone();
+    }
+}
+
+/// The scene file of the current session and its camera bookmarks.
+#[derive(Resource, Default)]
+pub struct SceneSession {
+    /// File the session was opened from and is saved to
+    pub path: Option<PathBuf>,
+    /// Luminaire path as written in the scene file
+    pub ldt_path: Option<PathBuf>,
+    /// Camera bookmarks
+    pub cameras: Vec<CameraBookmark>,
+    /// Index of the bookmark shown last
+    pub current_camera: usize,
+}
+
+impl SceneSession {
+    /// Open a scene file: apply it to `settings` and load its luminaire.
+    pub fn open(
+        path: impl AsRef<Path>,
+        settings: &mut ViewerSettings,
+    ) -> Result<Self, SceneFileError> {
+        let path = path.as_ref();
+        let file = SceneFile::load(path)?;
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+
+        file.apply(settings);
+        if let Some(ldt) = file.load_luminaire(base_dir)? {
+            settings.ldt_data = Some(ldt);
+        }
+
+        Ok(Self {
+            path: Some(path.to_path_buf()),
+            ldt_path: file.luminaire.ldt,
+            cameras: file.cameras,
+            current_camera: 0,
+        })
+    }
+
+    /// Write the session back to its scene file.
+    pub fn save(&self, settings: &ViewerSettings) -> Result<(), SceneFileError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        SceneFile::from_settings(settings, self.ldt_path.clone(), self.cameras.clone()).save(path)
+    }
+}
+
+/// Move the camera to the first bookmark of an opened scene file.
+pub fn apply_initial_bookmark(
+    session: Res<SceneSession>,
+    mut cameras: Query<(&mut Transform, &mut FirstPersonCamera)>,
+) {
+    if let Some(bookmark) = session.cameras.first() {
+        for (mut transform, mut camera) in &mut cameras {
+            *transform = bookmark.transform();
+            camera.yaw = bookmark.yaw;
+            camera.pitch = bookmark.pitch;
+        }
+    }
+}
+
+/// Keyboard controls for bookmarks and saving.
+pub fn scene_file_controls_system(
+    mut session: ResMut<SceneSession>,
+    settings: Res<ViewerSettings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut cameras: Query<(&mut Transform, &mut FirstPersonCamera)>,
+) {
+    let shift = keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let ctrl = keyboard.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+
+    if keyboard.just_pressed(KeyCode::KeyB) && shift {
+        if let Some((transform, camera)) = cameras.iter().next() {
+            let name = format!("View {}", session.cameras.len() + 1);
+            session.cameras.push(CameraBookmark {
+                name,
+                position: transform.translation.to_array(),
+                yaw: camera.yaw,
+                pitch: camera.pitch,
+            });
+        }
+    } else if keyboard.just_pressed(KeyCode::KeyB) && !session.cameras.is_empty() {
+        let next = (session.current_camera + 1) % session.cameras.len();
+        session.current_camera = next;
+        let bookmark = &session.cameras[next];
+        for (mut transform, mut camera) in &mut cameras {
+            *transform = bookmark.transform();
+            camera.yaw = bookmark.yaw;
+            camera.pitch = bookmark.pitch;
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyS) && ctrl {
+        if let Err(e) = session.save(&settings) {
+            warn!("Failed to save scene file: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scene_file_roundtrip() {
+        let settings = ViewerSettings {
+            scene_type: SceneType::Road,
+            mounting_height: 10.0,
+            num_lanes: 3,
+            show_shadows: true,
+            ..Default::default()
+        };
+        let bookmark = CameraBookmark {
+            name: "Driver view".to_string(),
+            position: [1.75, 1.2, -5.0],
+            yaw: 3.1,
+            pitch: 0.05,
+        };
+        let file =
+            SceneFile::from_settings(&settings, Some(PathBuf::from("road.ldt")), vec![bookmark]);
+
+        let toml = file.to_toml_string().unwrap();
+        assert!(toml.contains("scene_type = \"road\""));
+        let reopened = SceneFile::from_toml_str(&toml).unwrap();
+        assert_eq!(reopened, file);
+
+        let mut applied = ViewerSettings::default();
+        reopened.apply(&mut applied);
+        assert_eq!(applied.scene_type, SceneType::Road);
+        assert_eq!(applied.mounting_height, 10.0);
+        assert_eq!(applied.num_lanes, 3);
+        assert!(applied.show_shadows);
+    }
+
+    #[test]
+    fn test_partial_scene_file_uses_defaults() {
+        let file = SceneFile::from_toml_str(
+            "[scene]\nscene_type = \"parking\"\n\n[luminaire]\nldt_content = \"\"\n",
+        )
+        .unwrap();
+        assert_eq!(file.scene.scene_type, SceneType::Parking);
+        assert_eq!(file.scene.lane_width, ViewerSettings::default().lane_width);
+        assert!(file.cameras.is_empty());
+        // Embedded content that is not an LDT file is reported
+        assert!(file.load_luminaire(Path::new(".")).is_err());
+
+        assert!(SceneFile::from_toml_str("[scene]\nscene_type = \"cave\"\n").is_err());
+    }
+}
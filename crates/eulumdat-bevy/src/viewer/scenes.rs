@@ -60,7 +60,8 @@ impl Plugin for ScenePlugin {
 }
 
 /// Scene type for demo scenes.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SceneType {
     /// Indoor room scene (4×5×2.8m)
     #[default]
@@ -6,9 +6,18 @@ use std::path::PathBuf;
 #[derive(Parser)]
 #[command(name = "eulumdat")]
 #[command(author, version, about = "EULUMDAT/IES photometric file tool", long_about = None)]
+#[command(arg_required_else_help = true)]
 pub struct Cli {
+    /// Print the man page (troff) and exit, e.g. `eulumdat --man > eulumdat.1`
+    #[arg(long)]
+    pub man: bool,
+
+    /// Print machine-readable JSON (info, validate, calc, bug, summary, ugr, compare, gldf, stats)
+    #[arg(long, global = true)]
+    pub json: bool,
+
     #[command(subcommand)]
-    pub command: Commands,
+    pub command: Option<Commands>,
 }
 
 #[derive(Subcommand)]
@@ -35,15 +44,32 @@ pub enum Commands {
         /// Use strict validation (fail on warnings)
         #[arg(short, long)]
         strict: bool,
+
+        /// Exit with code 3 on warnings or 4 on strict validation errors
+        #[arg(long, value_enum)]
+        fail_on: Option<FailOnArg>,
     },
 
     /// Convert between photometric formats (LDT, IES, ATLA XML/JSON)
     Convert {
-        /// Input file (.ldt, .ies, .xml, or .json)
+        /// Input file (.ldt, .ies, .xml, or .json), or - for stdin
         input: PathBuf,
 
-        /// Output file (.ldt, .ies, .xml, or .json)
-        output: PathBuf,
+        /// Output file (.ldt, .ies, .xml, or .json), or - for stdout
+        #[arg(required_unless_present = "output_file")]
+        output: Option<PathBuf>,
+
+        /// Output file, instead of the second argument
+        #[arg(short = 'o', long = "output", conflicts_with = "output")]
+        output_file: Option<PathBuf>,
+
+        /// Input format, instead of the input extension (guessed for stdin)
+        #[arg(long, value_enum)]
+        from: Option<FileFormatArg>,
+
+        /// Output format, instead of the output extension (needed for stdout)
+        #[arg(long, value_enum)]
+        to: Option<FileFormatArg>,
 
         /// Output compact format (no indentation) for XML/JSON
         #[arg(short, long)]
@@ -54,14 +80,217 @@ pub enum Commands {
         /// Use -90 when converting LDT→IES.
         #[arg(short, long, default_value = "0.0")]
         rotate: f64,
+
+        /// What to do with fields the output format cannot hold
+        #[arg(long, value_enum, default_value = "lenient")]
+        policy: LossPolicyArg,
+
+        /// Convert again whenever the input file changes
+        #[arg(short, long)]
+        watch: bool,
+    },
+
+    /// Apply safe fixes for validation warnings and print what changed
+    Fix {
+        /// Input file (.ldt or .ies)
+        file: PathBuf,
+
+        /// Write to this file (.ldt or .ies) instead of overwriting the input
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Only print the changes, without writing a file
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Record the repairs in the file (LDT report field, IES [_REPAIRS] keyword)
+        #[arg(long)]
+        annotate: bool,
+    },
+
+    /// Create a photometric file from a built-in luminaire template
+    New {
+        /// Template to start from
+        #[arg(short, long, value_enum)]
+        template: TemplateArg,
+
+        /// Output file (.ldt or .ies)
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Luminaire name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Total luminous flux of the lamps in lumens
+        #[arg(long)]
+        flux: Option<f64>,
+
+        /// Wattage including ballast in watts
+        #[arg(long)]
+        wattage: Option<f64>,
+
+        /// Beam angle in degrees (50 % of maximum); the distribution is stretched to match
+        #[arg(long)]
+        beam_angle: Option<f64>,
+
+        /// Luminaire length in mm
+        #[arg(long)]
+        length: Option<f64>,
+
+        /// Luminaire width in mm (0 for round luminaires)
+        #[arg(long)]
+        width: Option<f64>,
+
+        /// Luminaire height in mm
+        #[arg(long)]
+        height: Option<f64>,
+
+        /// Overwrite an existing output file
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Edit header fields of an LDT or IES file in place
+    Set {
+        /// Input file (.ldt or .ies)
+        file: PathBuf,
+
+        /// Write to this file (.ldt or .ies) instead of overwriting the input
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Luminaire name
+        #[arg(long)]
+        luminaire_name: Option<String>,
+
+        /// Luminaire (catalog) number
+        #[arg(long)]
+        luminaire_number: Option<String>,
+
+        /// Manufacturer (LDT identification line, IES [MANUFAC])
+        #[arg(long)]
+        manufacturer: Option<String>,
+
+        /// Measurement report number
+        #[arg(long)]
+        report_number: Option<String>,
+
+        /// Date/user field
+        #[arg(long)]
+        date_user: Option<String>,
+
+        /// Total luminous flux of the first lamp set in lumens
+        #[arg(long)]
+        flux: Option<f64>,
+
+        /// Wattage including ballast of the first lamp set in watts
+        #[arg(long)]
+        wattage: Option<f64>,
+
+        /// Lamp type of the first lamp set
+        #[arg(long)]
+        lamp_type: Option<String>,
+    },
+
+    /// Strip identifying metadata (manufacturer, numbers, dates) and keep the photometry
+    Anonymize {
+        /// Input file (.ldt or .ies)
+        file: PathBuf,
+
+        /// File to write (.ldt or .ies); its name replaces the file name
+        /// field, so it must differ from the input
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Replacement luminaire name
+        #[arg(long, default_value = "Luminaire")]
+        name: String,
+
+        /// Also clear the lamp types, which may name the product
+        #[arg(long)]
+        lamp_types: bool,
+    },
+
+    /// Resample onto a regular C/γ grid and report the interpolation error
+    Resample {
+        /// Input file (.ldt or .ies)
+        input: PathBuf,
+
+        /// Distance between C-planes in degrees
+        #[arg(long, default_value = "15.0")]
+        c_step: f64,
+
+        /// Distance between gamma angles in degrees
+        #[arg(long, default_value = "5.0")]
+        g_step: f64,
+
+        /// Output file (.ldt or .ies); without it only the report is printed
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Rotate the C-plane reference, e.g. to fix a wrong goniophotometer orientation
+    Rotate {
+        /// Input file (.ldt or .ies)
+        file: PathBuf,
+
+        /// Write to this file (.ldt or .ies) instead of overwriting the input
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Rotation in degrees (positive = counter-clockwise seen from above)
+        #[arg(long, allow_hyphen_values = true)]
+        degrees: f64,
+    },
+
+    /// Mirror the distribution, e.g. to fix a reversed C-plane direction
+    Mirror {
+        /// Input file (.ldt or .ies)
+        file: PathBuf,
+
+        /// Write to this file (.ldt or .ies) instead of overwriting the input
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Mirror plane
+        #[arg(long, value_enum, default_value = "c0-c180")]
+        plane: MirrorPlaneArg,
+    },
+
+    /// Scale lumens, wattage and absolute intensities for a dimmed or boosted variant
+    Scale {
+        /// Input file (.ldt or .ies)
+        file: PathBuf,
+
+        /// Write to this file (.ldt or .ies) instead of overwriting the input
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Scale factor for flux and wattage (e.g. 0.8 for 80 %)
+        #[arg(
+            long,
+            conflicts_with = "target_flux",
+            required_unless_present = "target_flux"
+        )]
+        factor: Option<f64>,
+
+        /// Scale to this total luminous flux in lumens instead of a factor
+        #[arg(long)]
+        target_flux: Option<f64>,
     },
 
     /// Generate SVG diagram
     Diagram {
-        /// Input file (.ldt, .ies, .xml, or .json for ATLA)
+        /// Input file (.ldt, .ies, .xml, or .json for ATLA), or - for stdin
         input: PathBuf,
 
-        /// Output SVG file
+        /// Input format, instead of the input extension (guessed for stdin)
+        #[arg(long, value_enum)]
+        from: Option<FileFormatArg>,
+
+        /// Output file (.svg, .png for a bitmap, .dxf for polar/isolux CAD export,
+        /// or .html for the intensity table); stdout if not given or -
         #[arg(short, long)]
         output: Option<PathBuf>,
 
@@ -73,6 +302,11 @@ pub enum Commands {
         #[arg(short, long)]
         dark: bool,
 
+        /// JSON theme file (colors, fonts, line weights, logo); missing
+        /// fields come from the light theme
+        #[arg(long, conflicts_with = "dark")]
+        theme: Option<PathBuf>,
+
         /// Width in pixels
         #[arg(short = 'W', long, default_value = "500")]
         width: f64,
@@ -85,17 +319,53 @@ pub enum Commands {
         #[arg(short = 'm', long, default_value = "3.0")]
         mounting_height: f64,
 
-        /// Tilt angle in degrees (for isolux diagram, 0=down, 90=horizontal)
+        /// Several mounting heights for the cone diagram, e.g. 2,3,4
+        #[arg(long, value_delimiter = ',')]
+        heights: Vec<f64>,
+
+        /// Tilt angle in degrees (for isolux/footprint diagrams, 0=down, 90=horizontal)
         #[arg(long, default_value = "0.0")]
         tilt: f64,
 
-        /// Use logarithmic Y-axis (for floodlight-vh diagram)
+        /// Throw distance to the aiming point in meters (footprint diagram)
+        #[arg(long, default_value = "10.0")]
+        throw: f64,
+
+        /// Use logarithmic scale (floodlight-vh Y-axis, heatmap colors)
         #[arg(long)]
         log_scale: bool,
 
+        /// Color map of the heatmap diagram
+        #[arg(long, value_enum, default_value = "classic")]
+        colormap: ColorMapArg,
+
+        /// Radial scale of the polar diagram (sqrt or log for narrow beams)
+        #[arg(long, value_enum, default_value = "linear")]
+        radial_scale: RadialScaleArg,
+
+        /// Annotate batwing peaks, valley depth and asymmetry (cartesian diagram)
+        #[arg(long)]
+        batwing: bool,
+
         /// Unit system for isolux/cone labels (lx/fc, m/ft)
         #[arg(short = 'U', long, value_enum, default_value = "metric")]
         units: UnitArg,
+
+        /// Isolux rendering style
+        #[arg(long, value_enum, default_value = "heatmap")]
+        isolux_style: IsoluxStyle,
+
+        /// Half-width of the isolux ground area in meters
+        #[arg(long, default_value = "20.0")]
+        area: f64,
+
+        /// Tell curves apart by dash pattern and hatching, not only color
+        #[arg(long)]
+        patterns: bool,
+
+        /// Regenerate the diagram whenever the input file changes
+        #[arg(short, long, requires = "output")]
+        watch: bool,
     },
 
     /// Calculate BUG rating (outdoor luminaires)
@@ -132,6 +402,54 @@ pub enum Commands {
         /// Overwrite existing files
         #[arg(long)]
         overwrite: bool,
+
+        /// Number of files converted in parallel (default: one per CPU core)
+        #[arg(short, long, default_value = "0", hide_default_value = true)]
+        jobs: usize,
+
+        /// Exit with code 3 on skipped files or 4 on failed files
+        #[arg(long, value_enum)]
+        fail_on: Option<FailOnArg>,
+    },
+
+    /// Render PNG polar thumbnails for all photometric files in a directory
+    Thumbnail {
+        /// Input directory containing .ldt or .ies files
+        input_dir: PathBuf,
+
+        /// Output directory (defaults to input directory); thumbnails are named <file>.png
+        #[arg(short, long)]
+        output_dir: Option<PathBuf>,
+
+        /// Edge length in pixels
+        #[arg(short, long, default_value = "256")]
+        size: u32,
+
+        /// Process subdirectories recursively
+        #[arg(short, long)]
+        recursive: bool,
+
+        /// Overwrite existing files
+        #[arg(long)]
+        overwrite: bool,
+
+        /// Use dark theme
+        #[arg(long)]
+        dark: bool,
+
+        /// Number of files rendered in parallel (default: one per CPU core)
+        #[arg(short, long, default_value = "0", hide_default_value = true)]
+        jobs: usize,
+    },
+
+    /// Aggregate statistics over a directory (symmetry, flux, efficacy, validation)
+    Stats {
+        /// Input directory containing .ldt or .ies files
+        input_dir: PathBuf,
+
+        /// Process subdirectories recursively
+        #[arg(short, long)]
+        recursive: bool,
     },
 
     /// Display photometric summary with calculated values
@@ -148,11 +466,224 @@ pub enum Commands {
         output: Option<PathBuf>,
     },
 
-    /// Export GLDF-compatible photometric data
-    Gldf {
+    /// Print the UGR table for the standard CIE 117 rooms
+    Ugr {
+        /// Input file (.ldt or .ies)
+        file: PathBuf,
+
+        /// Output format
+        #[arg(short = 'f', long, value_enum, default_value = "text")]
+        format: UgrFormat,
+
+        /// Output to file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Print utilization factors by room index (or the IES CU table)
+    Uf {
+        /// Input file (.ldt or .ies)
+        file: PathBuf,
+
+        /// Output format
+        #[arg(short = 'f', long, value_enum, default_value = "text")]
+        format: UfFormat,
+
+        /// Output to file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Room indices k, e.g. 0.6,1,2.5 (default: 0.6 to 5)
+        #[arg(long, value_delimiter = ',')]
+        room_indices: Vec<f64>,
+
+        /// Ceiling/wall/floor reflectances in percent, e.g. 70/50/20,50/30/10
+        #[arg(long, value_delimiter = ',', value_parser = parse_reflectances)]
+        reflectances: Vec<(u8, u8, u8)>,
+
+        /// Print the IES CU table by room cavity ratio instead
+        #[arg(long, conflicts_with_all = ["room_indices", "reflectances"])]
+        cu: bool,
+    },
+
+    /// Compute the workplane illuminance of a regular luminaire layout
+    Illuminance {
+        /// Input file (.ldt or .ies)
+        file: PathBuf,
+
+        /// Room width (x) in meters
+        #[arg(long)]
+        width: f64,
+
+        /// Room depth (y) in meters
+        #[arg(long)]
+        depth: f64,
+
+        /// Mounting height above the floor in meters
+        #[arg(short = 'm', long, default_value = "3.0")]
+        mounting_height: f64,
+
+        /// Workplane height above the floor in meters
+        #[arg(long, default_value = "0.8")]
+        workplane: f64,
+
+        /// Luminaire rows along the depth
+        #[arg(long, default_value = "2", conflicts_with = "spacing")]
+        rows: usize,
+
+        /// Luminaire columns along the width
+        #[arg(long, default_value = "2", conflicts_with = "spacing")]
+        cols: usize,
+
+        /// Luminaire spacing in meters, instead of --rows and --cols
+        #[arg(long)]
+        spacing: Option<f64>,
+
+        /// Calculation points per axis
+        #[arg(long, default_value = "40")]
+        grid: usize,
+
+        /// Maintenance factor applied to the lamp flux
+        #[arg(long, default_value = "1.0")]
+        maintenance_factor: f64,
+
+        /// Write an isolux SVG of the workplane to this file
+        #[arg(long)]
+        svg: Option<PathBuf>,
+
+        /// Use dark theme for the SVG
+        #[arg(long)]
+        dark: bool,
+    },
+
+    /// Export the 3D photometric solid as a mesh (glTF, GLB, OBJ or STL)
+    Mesh {
         /// Input file (.ldt or .ies)
         file: PathBuf,
 
+        /// Output file; the format follows the extension unless --format is given
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Mesh format
+        #[arg(short = 'f', long, value_enum)]
+        format: Option<MeshFormatArg>,
+
+        /// Radius of the peak intensity in model units
+        #[arg(short, long, default_value = "1.0")]
+        scale: f32,
+
+        /// Distance between C-planes in degrees
+        #[arg(long, default_value = "5.0")]
+        c_step: f64,
+
+        /// Distance between γ angles in degrees
+        #[arg(long, default_value = "5.0")]
+        g_step: f64,
+
+        /// Adaptive steps within this many vertices, dense where the intensity
+        /// changes fast, instead of --c-step and --g-step
+        #[arg(long, conflicts_with_all = ["c_step", "g_step"])]
+        vertex_budget: Option<usize>,
+    },
+
+    /// EN 13201 road lighting check: luminance, illuminance and achieved classes
+    Road {
+        /// Input file (.ldt or .ies)
+        file: PathBuf,
+
+        /// Number of lanes
+        #[arg(long, default_value = "2")]
+        lanes: usize,
+
+        /// Lane width in meters
+        #[arg(long, default_value = "3.5")]
+        lane_width: f64,
+
+        /// Pole spacing in meters
+        #[arg(long, default_value = "30.0")]
+        spacing: f64,
+
+        /// Mounting height in meters
+        #[arg(short = 'm', long, default_value = "8.0")]
+        mounting_height: f64,
+
+        /// Distance of the luminaire from the road edge in meters, positive over the road
+        #[arg(long, default_value = "1.0", allow_hyphen_values = true)]
+        overhang: f64,
+
+        /// Pole arrangement
+        #[arg(long, value_enum, default_value = "single-sided")]
+        arrangement: RoadArrangementArg,
+
+        /// Average luminance coefficient q0 of the road surface (0.07 for CIE R3)
+        #[arg(long, default_value = "0.07")]
+        q0: f64,
+
+        /// Maintenance factor applied to all values
+        #[arg(long, default_value = "1.0")]
+        maintenance_factor: f64,
+
+        /// Target lighting class (M1–M6, C0–C5, P1–P6); fails if a C or P
+        /// class is not met, M classes are only estimated
+        #[arg(long)]
+        class: Option<eulumdat::road::RoadClass>,
+
+        /// Write a luminance plan SVG to this file
+        #[arg(long)]
+        svg: Option<PathBuf>,
+
+        /// Use dark theme for the SVG
+        #[arg(long)]
+        dark: bool,
+    },
+
+    /// Spectral power distribution: measured from an ATLA file, or synthesized
+    /// from CCT and CRI; prints a table or writes SVG/CSV
+    Spectrum {
+        /// Input file (.xml or .json for ATLA, or .ldt/.ies for the lamp CCT)
+        #[arg(required_unless_present = "cct", conflicts_with = "cct")]
+        file: Option<PathBuf>,
+
+        /// Synthesize an LED spectrum of this color temperature in K
+        #[arg(long)]
+        cct: Option<f64>,
+
+        /// Color rendering index Ra of the synthesized spectrum
+        #[arg(long, requires = "cct")]
+        cri: Option<f64>,
+
+        /// Output file (.svg diagram or .csv values); table on stdout if not given
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Wavelength step of the printed table in nm (default: every value)
+        #[arg(long)]
+        step: Option<f64>,
+
+        /// Use dark theme for the SVG
+        #[arg(short, long)]
+        dark: bool,
+
+        /// SVG width in pixels
+        #[arg(short = 'W', long, default_value = "600")]
+        width: f64,
+
+        /// SVG height in pixels
+        #[arg(short = 'H', long, default_value = "400")]
+        height: f64,
+    },
+
+    /// Export GLDF-compatible photometric data, or pack and unpack GLDF containers
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+    Gldf {
+        #[command(subcommand)]
+        container: Option<GldfCommands>,
+
+        /// Input file (.ldt or .ies)
+        #[arg(required = true)]
+        file: Option<PathBuf>,
+
         /// Output JSON file (stdout if not specified)
         #[arg(short, long)]
         output: Option<PathBuf>,
@@ -215,23 +746,37 @@ pub enum Commands {
         compact: bool,
     },
 
-    /// Compare two photometric files side-by-side
+    /// Compare two photometric files side-by-side, or all files in a directory
     Compare {
-        /// First input file (.ldt, .ies, .xml, or .json)
+        /// First input file (.ldt, .ies, .xml, or .json), or a directory of
+        /// .ldt/.ies files for a catalog overview
         file_a: PathBuf,
 
         /// Second input file (.ldt, .ies, .xml, or .json)
-        file_b: PathBuf,
+        file_b: Option<PathBuf>,
 
         /// Output format for comparison table
         #[arg(short = 'f', long, value_enum, default_value = "text")]
         format: CompareFormat,
 
+        /// Sort the catalog overview by this column
+        #[arg(long, value_enum, default_value = "name")]
+        sort: OverviewColumnArg,
+
+        /// Sort the catalog overview in descending order
+        #[arg(long)]
+        desc: bool,
+
+        /// Scan the directory recursively
+        #[arg(short, long)]
+        recursive: bool,
+
         /// Generate overlay diagram SVG
         #[arg(short = 'd', long, value_enum)]
         diagram: Option<CompareDiagramType>,
 
-        /// Output file for SVG diagram (stdout if not specified)
+        /// Output file for SVG diagram, or for the catalog overview table
+        /// (stdout if not specified)
         #[arg(short, long)]
         output: Option<PathBuf>,
 
@@ -243,17 +788,22 @@ pub enum Commands {
         #[arg(long)]
         significant_only: bool,
 
+        /// Exit with code 3 on minor or moderate differences (5–30%) or 4 on
+        /// major ones (> 30%); two files only
+        #[arg(long, value_enum)]
+        fail_on: Option<FailOnArg>,
+
         /// Unit system for dimension metrics (mm/in)
         #[arg(short = 'U', long, value_enum, default_value = "metric")]
         units: UnitArg,
     },
 
-    /// Generate photometric report (Typst source or PDF)
+    /// Generate photometric report (Typst source or PDF) or one-page datasheet (HTML, SVG, PDF)
     Report {
         /// Input file (.ldt, .ies, .xml, or .json)
         input: PathBuf,
 
-        /// Output file (.typ for Typst source, .pdf for PDF)
+        /// Output file (.typ for Typst source, .pdf for PDF, .html or .svg for the datasheet)
         output: PathBuf,
 
         /// Paper size
@@ -275,6 +825,11 @@ pub enum Commands {
         /// Include full candela tabulation (like Photometric Toolbox)
         #[arg(long)]
         candela_table: bool,
+
+        /// Datasheet template (TOML: company, footer, colors, panels); makes
+        /// .typ and .pdf output a one-page datasheet
+        #[arg(short, long, conflicts_with_all = ["compact", "cu_table", "ugr_table", "candela_table"])]
+        template: Option<PathBuf>,
     },
 
     /// Interpolate between photometric files at different LED operating points
@@ -325,6 +880,67 @@ pub enum Commands {
         #[arg(long)]
         overwrite: bool,
     },
+
+    /// Index a photometric library into a JSON catalog
+    ///
+    /// Records name, manufacturer, flux, power, beam and field angle and the
+    /// path of every .ldt and .ies file below the directory, with a PNG polar
+    /// thumbnail in <index>.thumbs/. Running again only parses new and
+    /// changed files, and an interrupted run resumes.
+    Index {
+        /// Library directory, searched recursively
+        dir: PathBuf,
+
+        /// Index file to write or update
+        #[arg(short, long, default_value = "catalog.json")]
+        output: PathBuf,
+
+        /// Parse every file again instead of reusing unchanged entries
+        #[arg(long)]
+        rebuild: bool,
+
+        /// Edge length of the thumbnails in pixels
+        #[arg(long, default_value = "128")]
+        thumbnail_size: u32,
+
+        /// Skip rendering thumbnails
+        #[arg(long)]
+        no_thumbnails: bool,
+    },
+
+    /// Browse a file in the terminal: info, polar plot, intensities, validation
+    Tui {
+        /// Input file (.ldt, .ies, .xml, or .json)
+        file: PathBuf,
+    },
+
+    /// Serve convert, diagram, validate and summary over HTTP
+    ///
+    /// POST a file to /convert, /diagram, /validate or /summary; query
+    /// parameters follow the long options of the commands, e.g.
+    /// /convert?to=ies or /diagram?type=cartesian&dark
+    Serve {
+        /// Port to listen on
+        #[arg(short, long, default_value = "8080")]
+        port: u16,
+
+        /// Address to bind; 0.0.0.0 for all interfaces
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+    },
+
+    /// Generate shell completion scripts
+    ///
+    /// Examples:
+    ///   eulumdat completions bash > ~/.local/share/bash-completion/completions/eulumdat
+    ///   eulumdat completions zsh > ~/.zfunc/_eulumdat
+    ///   eulumdat completions fish > ~/.config/fish/completions/eulumdat.fish
+    ///   eulumdat completions powershell >> $PROFILE
+    Completions {
+        /// Target shell
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
@@ -345,6 +961,8 @@ pub enum DiagramType {
     Lcs,
     /// Spectral power distribution (requires ATLA input with spectral data)
     Spectral,
+    /// TM-30 color vector graphic with Rf/Rg (requires ATLA input with spectral data)
+    Tm30,
     /// Greenhouse PPFD diagram (horticultural lighting)
     Greenhouse,
     /// Floodlight V-H Cartesian diagram (Type B coordinates)
@@ -353,6 +971,90 @@ pub enum DiagramType {
     Isolux,
     /// Isocandela contour plot (equal-intensity lines)
     Isocandela,
+    /// Beam and field footprint on the aimed-at plane (throw distance and tilt)
+    Footprint,
+    /// Luminance vs viewing angle against the EN 12464-1 limits
+    Glare,
+    /// Heat-colored C/γ intensity table (.html, or one SVG/PNG per page)
+    Table,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum TemplateArg {
+    /// LED office downlight with vertical axis symmetry
+    Downlight,
+    /// T16 linear fluorescent with bilateral symmetry
+    Fluorescent,
+    /// Spotlight with an asymmetric distribution
+    Projector,
+    /// Street light with forward throw
+    Road,
+    /// Decorative floor uplight
+    Uplight,
+    /// Batwing distribution for uniform illuminance
+    Batwing,
+    /// Narrow beam spotlight
+    Spotlight,
+    /// Wide beam floodlight
+    Flood,
+}
+
+/// GLDF container operations
+#[derive(Subcommand)]
+pub enum GldfCommands {
+    /// Pack product.xml and the files it lists into a .gldf container
+    Pack {
+        /// Product definition (product.xml)
+        product: PathBuf,
+
+        /// Files or directories holding the listed files (default: the
+        /// directory of product.xml)
+        inputs: Vec<PathBuf>,
+
+        /// Output .gldf file
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Overwrite an existing output file
+        #[arg(long)]
+        overwrite: bool,
+    },
+
+    /// Extract a .gldf container and check its file references
+    Unpack {
+        /// Input .gldf file
+        archive: PathBuf,
+
+        /// Output directory (default: the archive name without extension)
+        #[arg(short, long)]
+        output_dir: Option<PathBuf>,
+
+        /// Overwrite existing files
+        #[arg(long)]
+        overwrite: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum MeshFormatArg {
+    /// glTF 2.0 JSON with embedded buffer and intensity vertex colors
+    Gltf,
+    /// Binary glTF 2.0 with intensity vertex colors
+    Glb,
+    /// Wavefront OBJ
+    Obj,
+    /// Binary STL (Z up) for CAD and 3D printing
+    Stl,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum RoadArrangementArg {
+    /// One row along the near road edge
+    SingleSided,
+    /// Two rows facing each other
+    Opposite,
+    /// Two rows, the far row offset by half the spacing
+    Staggered,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
@@ -363,6 +1065,16 @@ pub enum OutputFormat {
     Ies,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum MirrorPlaneArg {
+    /// Mirror across C0-C180 (swaps C90 and C270)
+    #[value(name = "c0-c180")]
+    C0C180,
+    /// Mirror across C90-C270 (swaps C0 and C180)
+    #[value(name = "c90-c270")]
+    C90C270,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
 pub enum SummaryFormat {
     /// Human-readable text report
@@ -371,6 +1083,45 @@ pub enum SummaryFormat {
     Compact,
     /// JSON output
     Json,
+    /// JSON Schema of the JSON output
+    JsonSchema,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum UgrFormat {
+    /// Text table with the 4H×8H, 70/50/20 row marked
+    Text,
+    /// One line per room size and reflectance combination
+    Csv,
+    /// JSON with the full table and the 4H×8H, 70/50/20 values
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum UfFormat {
+    /// Text table, one column per reflectance combination
+    Text,
+    /// One line per room and reflectance combination
+    Csv,
+}
+
+/// Parse `ceiling/wall/floor` reflectances in percent, e.g. `70/50/20`
+fn parse_reflectances(value: &str) -> Result<(u8, u8, u8), String> {
+    let parts: Vec<u8> = value
+        .split('/')
+        .map(|part| {
+            part.trim()
+                .parse::<u8>()
+                .map_err(|e| format!("{part}: {e}"))
+        })
+        .collect::<Result<_, _>>()?;
+    match parts[..] {
+        [ceiling, wall, floor] if parts.iter().all(|&p| p <= 100) => Ok((ceiling, wall, floor)),
+        [_, _, _] => Err("reflectances must be 0-100".to_string()),
+        _ => Err(format!(
+            "expected ceiling/wall/floor, e.g. 70/50/20, got {value}"
+        )),
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
@@ -385,6 +1136,8 @@ pub enum CalcType {
     ZonalLumens,
     /// Coefficient of Utilization (CU) table
     CuTable,
+    /// Coefficient of Utilization (CU) table, classic IES RCR method
+    CuTableRcr,
     /// Unified Glare Rating (UGR) table
     UgrTable,
     /// Candela tabulation
@@ -417,6 +1170,29 @@ pub enum ConversionPolicyArg {
     Compatible,
 }
 
+/// Findings that make `validate`, `compare` and `batch` fail
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum FailOnArg {
+    /// Fail on warnings and errors
+    Warning,
+    /// Fail on errors only
+    Error,
+    /// Never fail because of findings
+    None,
+}
+
+/// Handling of lossy mappings in `convert`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum LossPolicyArg {
+    /// Refuse conversions that drop data
+    Strict,
+    /// Convert and list the dropped fields at the end
+    #[default]
+    Lenient,
+    /// Convert without checking for dropped fields
+    Silent,
+}
+
 /// Paper size for report generation
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
 pub enum PaperSize {
@@ -429,6 +1205,31 @@ pub enum PaperSize {
     A3,
 }
 
+/// Photometric file format, for input and output without an extension
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum FileFormatArg {
+    /// EULUMDAT
+    Ldt,
+    /// IES LM-63
+    Ies,
+    /// ATLA XML
+    Xml,
+    /// ATLA JSON
+    Json,
+}
+
+impl FileFormatArg {
+    /// File extension of the format
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Ldt => "ldt",
+            Self::Ies => "ies",
+            Self::Xml => "xml",
+            Self::Json => "json",
+        }
+    }
+}
+
 /// Output format for the compare command
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
 pub enum CompareFormat {
@@ -439,6 +1240,45 @@ pub enum CompareFormat {
     Json,
     /// CSV output
     Csv,
+    /// Markdown table
+    Markdown,
+}
+
+/// Sort column of the catalog overview
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum OverviewColumnArg {
+    /// File name
+    Name,
+    /// Luminaire flux
+    Flux,
+    /// System power
+    Wattage,
+    /// Luminaire efficacy
+    Efficacy,
+    /// Beam angle
+    Beam,
+    /// Field angle
+    Field,
+    /// UGR 4H×8H, 70/50/20
+    Ugr,
+    /// BUG rating
+    Bug,
+}
+
+impl OverviewColumnArg {
+    /// Convert to the core library's `OverviewColumn`.
+    pub fn to_overview_column(self) -> eulumdat::OverviewColumn {
+        match self {
+            Self::Name => eulumdat::OverviewColumn::Name,
+            Self::Flux => eulumdat::OverviewColumn::Flux,
+            Self::Wattage => eulumdat::OverviewColumn::Wattage,
+            Self::Efficacy => eulumdat::OverviewColumn::Efficacy,
+            Self::Beam => eulumdat::OverviewColumn::BeamAngle,
+            Self::Field => eulumdat::OverviewColumn::FieldAngle,
+            Self::Ugr => eulumdat::OverviewColumn::Ugr,
+            Self::Bug => eulumdat::OverviewColumn::Bug,
+        }
+    }
 }
 
 /// Diagram type for compare overlay
@@ -450,6 +1290,69 @@ pub enum CompareDiagramType {
     Cartesian,
 }
 
+/// Rendering style of the isolux diagram
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum IsoluxStyle {
+    /// Continuous heatmap with contour overlay
+    #[default]
+    Heatmap,
+    /// Discrete colored contour bands
+    Aec,
+    /// Labelled contour lines with scale bar, as on luminaire datasheets
+    Datasheet,
+}
+
+/// Color map for heatmap diagrams
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ColorMapArg {
+    /// Blue -> cyan -> green -> yellow -> red
+    #[default]
+    Classic,
+    /// Perceptually uniform purple -> teal -> yellow
+    Viridis,
+    /// Perceptually uniform black -> purple -> orange -> yellow
+    Inferno,
+    /// Black to white, for monochrome printing
+    Grayscale,
+}
+
+impl ColorMapArg {
+    /// Convert to the core library's `ColorMap`.
+    pub fn to_color_map(self) -> eulumdat::diagram::ColorMap {
+        use eulumdat::diagram::ColorMap;
+        match self {
+            Self::Classic => ColorMap::Classic,
+            Self::Viridis => ColorMap::Viridis,
+            Self::Inferno => ColorMap::Inferno,
+            Self::Grayscale => ColorMap::Grayscale,
+        }
+    }
+}
+
+/// Radial scale of the polar diagram
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum RadialScaleArg {
+    /// Radius proportional to intensity
+    #[default]
+    Linear,
+    /// Radius proportional to the square root of intensity
+    Sqrt,
+    /// Three decades below the maximum
+    Log,
+}
+
+impl RadialScaleArg {
+    /// Convert to the core library's `RadialScale`.
+    pub fn to_radial_scale(self) -> eulumdat::diagram::RadialScale {
+        use eulumdat::diagram::RadialScale;
+        match self {
+            Self::Linear => RadialScale::Linear,
+            Self::Sqrt => RadialScale::SquareRoot,
+            Self::Log => RadialScale::Logarithmic,
+        }
+    }
+}
+
 /// Unit system for output display
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
 pub enum UnitArg {
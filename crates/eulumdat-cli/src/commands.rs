@@ -2,63 +2,318 @@
 
 use anyhow::{Context, Result};
 use atla::LuminaireOpticalData;
+use eulumdat::road::{RoadClass, RoadGrid, RoadLayout};
 use eulumdat::{
+    area::{self, AreaSvg, LuminairePlace},
     batch::{self, BatchInput, ConversionFormat},
-    diagram::{CartesianDiagram, PolarDiagram, SvgTheme},
-    BugDiagram, Eulumdat, GldfPhotometricData, IesExporter, IesParser, PhotometricCalculations,
-    PhotometricComparison, PhotometricSummary, Significance,
+    diagram::{CartesianDiagram, PolarDiagram, RoadDiagram, RoadQuantity, SvgTheme},
+    BugDiagram, CatalogOverview, Eulumdat, GldfPhotometricData, IesExportOptions, IesExporter,
+    IesParser, MirrorPlane, OverviewColumn, OverviewRow, PhotometricCalculations,
+    PhotometricComparison, PhotometricSummary, RepairReport, Significance, SummaryRecord, UfTable,
+    UF_REFLECTANCES, UF_ROOM_INDICES,
 };
-use std::path::PathBuf;
+use eulumdat_photweb::PhotometricWeb;
+use std::path::{Path, PathBuf};
 
 use crate::cli::{
-    AtlaSchemaType, CalcType, CompareDiagramType, CompareFormat, ConversionPolicyArg, DiagramType,
-    OutputFormat, SummaryFormat,
+    AtlaSchemaType, CalcType, Cli, CompareDiagramType, CompareFormat, ConversionPolicyArg,
+    DiagramType, FailOnArg, FileFormatArg, IsoluxStyle, LossPolicyArg, MirrorPlaneArg,
+    OutputFormat, RoadArrangementArg, SummaryFormat, UfFormat, UgrFormat,
 };
+use crate::exit;
+use crate::json;
+use crate::loss;
+use crate::template::ReportTemplate;
 use std::fs;
+use std::io::{Read, Write};
 
-pub fn load_file(path: &PathBuf) -> Result<Eulumdat> {
-    let ext = path
-        .extension()
+pub fn load_file(path: impl AsRef<Path>) -> Result<Eulumdat> {
+    load_input(path.as_ref(), None)
+}
+
+/// Load a file, or stdin for `-`; `from` overrides the format
+pub fn load_input(path: &Path, from: Option<FileFormatArg>) -> Result<Eulumdat> {
+    let content = read_input(path)?;
+    parse_content(&content, &input_format(path, from, &content), 0.0)
+}
+
+/// Whether `path` is `-`, standing for stdin or stdout
+pub fn is_stdio(path: &Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+/// Read a file, or stdin for `-`.
+///
+/// Like the library's file parsers, input that is not UTF-8 is read as
+/// ISO-8859-1 (Latin-1).
+fn read_input(path: &Path) -> Result<String> {
+    let bytes = if is_stdio(path) {
+        let mut bytes = Vec::new();
+        std::io::stdin()
+            .read_to_end(&mut bytes)
+            .context("Failed to read stdin")?;
+        bytes
+    } else {
+        fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?
+    };
+    Ok(decode_text(bytes))
+}
+
+/// Text of a file, ISO-8859-1 if it is not UTF-8
+pub fn decode_text(bytes: Vec<u8>) -> String {
+    String::from_utf8(bytes).unwrap_or_else(|e| e.into_bytes().iter().map(|&b| b as char).collect())
+}
+
+/// Write to a file, or stdout for `-`
+fn write_output(path: &Path, content: &str) -> Result<()> {
+    if is_stdio(path) {
+        std::io::stdout()
+            .write_all(content.as_bytes())
+            .context("Failed to write stdout")
+    } else {
+        fs::write(path, content).context("Failed to write output file")
+    }
+}
+
+/// Name of a path in messages, `-` shown as stdin or stdout
+fn display_path(path: &Path, stdio: &str) -> String {
+    if is_stdio(path) {
+        stdio.to_string()
+    } else {
+        path.display().to_string()
+    }
+}
+
+/// Lowercase extension of `path`
+fn extension(path: &Path) -> String {
+    path.extension()
         .and_then(|e| e.to_str())
         .unwrap_or("")
-        .to_lowercase();
+        .to_lowercase()
+}
+
+/// Input format: `from` if given, else the extension, else for stdin
+/// guessed from the content
+fn input_format(path: &Path, from: Option<FileFormatArg>, content: &str) -> String {
+    if let Some(format) = from {
+        return format.extension().to_string();
+    }
+    if !is_stdio(path) {
+        return extension(path);
+    }
+    sniff_format(content).to_string()
+}
+
+/// Guess the format (ldt, ies, xml or json) of file content
+pub fn sniff_format(content: &str) -> &'static str {
+    let trimmed = content.trim_start();
+    if trimmed.starts_with('<') {
+        "xml"
+    } else if trimmed.starts_with('{') {
+        "json"
+    } else if trimmed.starts_with("IES")
+        || content
+            .lines()
+            .take(64)
+            .any(|l| l.trim_start().starts_with("TILT="))
+    {
+        "ies"
+    } else {
+        "ldt"
+    }
+}
 
-    match ext.as_str() {
-        "ldt" => Eulumdat::from_file(path).context("Failed to parse LDT file"),
-        "ies" => IesParser::parse_file(path).context("Failed to parse IES file"),
-        "xml" | "json" => {
-            // Parse ATLA format and convert to Eulumdat
-            let atla_doc = atla::parse_file(path).context("Failed to parse ATLA file")?;
-            Ok(atla_doc.to_eulumdat())
+/// Parse `content` as `format` (ldt, ies, xml or json), rotating IES
+/// C-planes by `ies_rotation` degrees on import
+pub fn parse_content(content: &str, format: &str, ies_rotation: f64) -> Result<Eulumdat> {
+    match format {
+        "ldt" => Eulumdat::parse(content).context("Failed to parse LDT file"),
+        "ies" => {
+            let opts = eulumdat::IesImportOptions {
+                rotate_c_planes: ies_rotation,
+                ..Default::default()
+            };
+            IesParser::parse_with_options(content, &opts).context("Failed to parse IES file")
+        }
+        "xml" | "json" => Ok(parse_atla_content(content, format)?.to_eulumdat()),
+        _ => {
+            anyhow::bail!("Unknown file extension: .{format} (expected .ldt, .ies, .xml, or .json)")
+        }
+    }
+}
+
+/// Parse `content` as an ATLA document; LDT and IES are converted
+pub fn parse_atla_content(content: &str, format: &str) -> Result<LuminaireOpticalData> {
+    match format {
+        "xml" | "json" => atla::parse(content).context("Failed to parse ATLA file"),
+        "ldt" | "ies" => Ok(LuminaireOpticalData::from_eulumdat(&parse_content(
+            content, format, 0.0,
+        )?)),
+        _ => {
+            anyhow::bail!("Unknown file extension: .{format} (expected .ldt, .ies, .xml, or .json)")
         }
-        _ => anyhow::bail!("Unknown file extension: .{ext} (expected .ldt, .ies, .xml, or .json)"),
     }
 }
 
-/// Load file as ATLA document (preserves spectral data)
-pub fn load_atla(path: &PathBuf) -> Result<LuminaireOpticalData> {
+/// Lowercase extension of an output file that must be LDT or IES
+pub fn ldt_or_ies_extension(path: &Path) -> Result<String> {
     let ext = path
         .extension()
         .and_then(|e| e.to_str())
         .unwrap_or("")
         .to_lowercase();
+    if !matches!(ext.as_str(), "ldt" | "ies") {
+        anyhow::bail!("Unknown output extension: .{ext} (expected .ldt or .ies)");
+    }
+    Ok(ext)
+}
+
+/// Write `ldt` as LDT or IES, by the extension from [`ldt_or_ies_extension`]
+pub fn save_ldt_or_ies(ldt: &Eulumdat, path: &Path, ext: &str) -> Result<()> {
+    let content = match ext {
+        "ldt" => ldt.to_ldt(),
+        _ => IesExporter::export(ldt),
+    };
+    fs::write(path, content).context("Failed to write output file")
+}
+
+/// Output file of a command that edits `input`: `output`, else `input`
+/// itself unless it is an IES file.
+///
+/// IES files are written by [`IesExporter`], which rewrites the header as
+/// LM-63-2019 and keeps only the keywords it maps, so an IES input is not
+/// overwritten; the changes are listed instead. With `output`, an IES to
+/// IES rewrite lists them as a warning.
+pub fn edit_output<'a>(input: &'a Path, output: Option<&'a Path>) -> Result<&'a Path> {
+    let rewrites_ies = extension(input) == "ies"
+        && output.is_none_or(|path| ldt_or_ies_extension(path).is_ok_and(|ext| ext == "ies"));
+    let changes = if rewrites_ies {
+        ies_rewrite_changes(input)?
+    } else {
+        Vec::new()
+    };
 
-    match ext.as_str() {
-        "xml" | "json" => atla::parse_file(path).context("Failed to parse ATLA file"),
-        "ldt" => {
-            let ldt = Eulumdat::from_file(path).context("Failed to parse LDT file")?;
-            Ok(LuminaireOpticalData::from_eulumdat(&ldt))
+    match output {
+        Some(path) => {
+            if !changes.is_empty() {
+                eprintln!("Warning: re-exporting {} as IES", input.display());
+                for change in &changes {
+                    eprintln!("  {change}");
+                }
+            }
+            Ok(path)
         }
-        "ies" => {
-            let ldt = IesParser::parse_file(path).context("Failed to parse IES file")?;
-            Ok(LuminaireOpticalData::from_eulumdat(&ldt))
+        None if rewrites_ies => {
+            let mut message = format!(
+                "Not overwriting {}: it would be re-exported as a new IES file",
+                input.display()
+            );
+            for change in &changes {
+                message.push_str("\n  ");
+                message.push_str(change);
+            }
+            message.push_str("\nUse -o to write the result to another file");
+            anyhow::bail!(message)
+        }
+        None => Ok(input),
+    }
+}
+
+/// What re-exporting the IES file `input` changes besides the edit: the
+/// header version and the keywords the exporter drops, adds or rewrites
+fn ies_rewrite_changes(input: &Path) -> Result<Vec<String>> {
+    let content = read_input(input)?;
+    let source = IesParser::parse_to_ies_data(&content)?;
+    let exported =
+        IesParser::parse_to_ies_data(&IesExporter::export(&IesParser::parse(&content)?))?;
+
+    let mut changes = Vec::new();
+    if source.version != exported.version {
+        changes.push(format!(
+            "header {} becomes {}",
+            source.version_string, exported.version_string
+        ));
+    }
+    let mut keywords: Vec<&String> = source
+        .keywords
+        .keys()
+        .chain(exported.keywords.keys())
+        .collect();
+    keywords.sort();
+    keywords.dedup();
+    for keyword in keywords {
+        match (source.keywords.get(keyword), exported.keywords.get(keyword)) {
+            (Some(_), None) => changes.push(format!("[{keyword}] is dropped")),
+            (None, Some(value)) => changes.push(format!("[{keyword}] {value} is added")),
+            (Some(before), Some(after)) if before != after => {
+                changes.push(format!("[{keyword}] {before} becomes {after}"))
+            }
+            _ => {}
+        }
+    }
+    Ok(changes)
+}
+
+/// Replace the value of `keyword` in IES `content`, dropping its `[MORE]`
+/// continuation lines, or add it before `TILT=` if the file has none.
+/// Every other line is kept as it is.
+fn set_ies_keyword(content: &str, keyword: &str, value: &str) -> String {
+    let tag = format!("[{keyword}]");
+    let is_tag = |line: &str, tag: &str| {
+        line.trim_start()
+            .get(..tag.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(tag))
+    };
+    let replacement = format!("{tag} {value}");
+
+    let mut lines: Vec<&str> = Vec::new();
+    let mut found = false;
+    let mut in_header = true;
+    let mut skip_more = false;
+    for line in content.lines() {
+        if in_header {
+            let upper = line.trim_start().to_uppercase();
+            if upper.starts_with("TILT=") || upper.starts_with("TILT ") {
+                in_header = false;
+                if !found {
+                    lines.push(&replacement);
+                    found = true;
+                }
+            } else if skip_more && is_tag(line, "[MORE]") {
+                continue;
+            } else if !found && is_tag(line, &tag) {
+                lines.push(&replacement);
+                found = true;
+                skip_more = true;
+                continue;
+            }
+            skip_more = false;
         }
-        _ => anyhow::bail!("Unknown file extension: .{ext} (expected .ldt, .ies, .xml, or .json)"),
+        lines.push(line);
     }
+
+    let newline = if content.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let mut result = lines.join(newline);
+    if content.ends_with('\n') {
+        result.push_str(newline);
+    }
+    result
 }
 
-pub fn info(file: &PathBuf, verbose: bool, units: eulumdat::UnitSystem) -> Result<()> {
+pub fn info(
+    file: &PathBuf,
+    verbose: bool,
+    units: eulumdat::UnitSystem,
+    as_json: bool,
+) -> Result<()> {
     let ldt = load_file(file)?;
+    if as_json {
+        return json::print("info", file, json::Info::new(&ldt, verbose));
+    }
 
     println!("File: {}", file.display());
     println!();
@@ -126,74 +381,153 @@ pub fn info(file: &PathBuf, verbose: bool, units: eulumdat::UnitSystem) -> Resul
     Ok(())
 }
 
-pub fn validate(file: &PathBuf, strict: bool) -> Result<()> {
+pub fn validate(
+    file: &PathBuf,
+    strict: bool,
+    fail_on: Option<FailOnArg>,
+    as_json: bool,
+) -> Result<()> {
     let ldt = load_file(file)?;
     let warnings = ldt.validate();
+    // --fail-on rates the strict validation errors too
+    let errors = if strict || fail_on.is_some() {
+        ldt.validate_strict().err().unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let (warning_count, error_count) = (warnings.len(), errors.len());
+    let error_lines: Vec<_> = errors
+        .iter()
+        .map(|e| format!("[{}] {}", e.code, e.message))
+        .collect();
 
-    if warnings.is_empty() {
+    if as_json {
+        json::print(
+            "validate",
+            file,
+            json::Validation {
+                valid: warnings.is_empty() && errors.is_empty(),
+                warnings,
+                errors,
+            },
+        )?;
+    } else if warnings.is_empty() && errors.is_empty() {
         println!("✓ {} is valid", file.display());
-        return Ok(());
-    }
-
-    println!("Validation results for {}:", file.display());
-    println!();
+    } else {
+        println!("Validation results for {}:", file.display());
+        println!();
 
-    for warning in &warnings {
-        println!("[{}] {}", warning.code, warning.message);
-    }
+        for warning in &warnings {
+            println!("[{}] {}", warning.code, warning.message);
+        }
 
-    println!();
-    println!("Found {} warning(s)", warnings.len());
+        println!();
+        println!("Found {} warning(s)", warnings.len());
 
-    if strict {
-        ldt.validate_strict().map_err(|errors| {
-            let msgs: Vec<_> = errors
-                .iter()
-                .map(|e| format!("[{}] {}", e.code, e.message))
-                .collect();
-            anyhow::anyhow!("Strict validation failed:\n{}", msgs.join("\n"))
-        })?;
+        if fail_on.is_some() && error_count > 0 {
+            println!();
+            for line in &error_lines {
+                println!("{line}");
+            }
+            println!();
+            println!("Found {error_count} error(s)");
+        }
     }
 
-    Ok(())
+    match fail_on {
+        Some(fail_on) => exit::check(fail_on, warning_count, error_count, ("warning", "error")),
+        None if error_count == 0 => Ok(()),
+        None if as_json => {
+            anyhow::bail!("Strict validation failed with {error_count} error(s)")
+        }
+        None => anyhow::bail!("Strict validation failed:\n{}", error_lines.join("\n")),
+    }
 }
 
-pub fn convert(input: &PathBuf, output: &PathBuf, compact: bool, rotate: f64) -> Result<()> {
-    let in_ext = input
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("")
-        .to_lowercase();
+/// Convert between LDT, IES and ATLA XML/JSON; `-` reads stdin or writes stdout
+pub fn convert(
+    input: &Path,
+    output: &Path,
+    from: Option<FileFormatArg>,
+    to: Option<FileFormatArg>,
+    compact: bool,
+    rotate: f64,
+    policy: LossPolicyArg,
+) -> Result<()> {
+    let source = read_input(input)?;
+    let in_ext = input_format(input, from, &source);
+    let out_ext = match to {
+        Some(format) => format.extension().to_string(),
+        None if is_stdio(output) => {
+            anyhow::bail!("Writing to stdout needs --to (ldt, ies, xml, or json)")
+        }
+        None => extension(output),
+    };
 
-    let out_ext = output
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("")
-        .to_lowercase();
+    let losses = match policy {
+        LossPolicyArg::Silent => Vec::new(),
+        _ => loss::losses(&source, &in_ext, &out_ext)?,
+    };
+    if policy == LossPolicyArg::Strict && !losses.is_empty() {
+        anyhow::bail!(
+            "{}\nRefusing a lossy conversion with --policy strict",
+            loss::report(&losses)
+        );
+    }
 
-    // Helper: load file with optional C-plane rotation on IES import
-    let load_with_rotation = |path: &PathBuf, rotation: f64| -> Result<Eulumdat> {
-        let ext = path
-            .extension()
-            .and_then(|e| e.to_str())
-            .unwrap_or("")
-            .to_lowercase();
-        match ext.as_str() {
-            "ies" if rotation.abs() > 0.001 => {
-                let opts = eulumdat::IesImportOptions {
-                    rotate_c_planes: rotation,
-                };
-                IesParser::parse_file_with_options(path, &opts).context("Failed to parse IES file")
-            }
-            _ => load_file(path),
-        }
+    let content = convert_content(&source, &in_ext, &out_ext, compact, rotate)?;
+    write_output(output, &content)?;
+
+    let in_ext_upper = in_ext.to_uppercase();
+    let out_ext_upper = out_ext.to_uppercase();
+    let mut notes = Vec::new();
+    if compact && (out_ext == "xml" || out_ext == "json") {
+        notes.push("compact");
+    }
+    if rotate.abs() > 0.001 {
+        notes.push("C-planes rotated");
+    }
+    let format_note = if notes.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", notes.join(", "))
     };
 
-    // Load the source data
-    let content = match (in_ext.as_str(), out_ext.as_str()) {
+    let message = format!(
+        "Converted {} → {} ({} → {}){}",
+        display_path(input, "stdin"),
+        display_path(output, "stdout"),
+        in_ext_upper,
+        out_ext_upper,
+        format_note
+    );
+    let message = if losses.is_empty() {
+        message
+    } else {
+        format!("{message}\n{}", loss::report(&losses))
+    };
+    // Keep stdout clean for the converted file
+    if is_stdio(output) {
+        eprintln!("{message}");
+    } else {
+        println!("{message}");
+    }
+
+    Ok(())
+}
+
+/// Convert `source` from `in_ext` to `out_ext` (ldt, ies, xml or json)
+pub fn convert_content(
+    source: &str,
+    in_ext: &str,
+    out_ext: &str,
+    compact: bool,
+    rotate: f64,
+) -> Result<String> {
+    let content = match (in_ext, out_ext) {
         // ATLA input -> ATLA output (direct conversion)
         ("xml" | "json", "xml") => {
-            let atla_doc = atla::parse_file(input).context("Failed to parse ATLA file")?;
+            let atla_doc = parse_atla_content(source, in_ext)?;
             if compact {
                 atla::xml::write_compact(&atla_doc).context("Failed to write ATLA XML")?
             } else {
@@ -201,7 +535,7 @@ pub fn convert(input: &PathBuf, output: &PathBuf, compact: bool, rotate: f64) ->
             }
         }
         ("xml" | "json", "json") => {
-            let atla_doc = atla::parse_file(input).context("Failed to parse ATLA file")?;
+            let atla_doc = parse_atla_content(source, in_ext)?;
             if compact {
                 atla::json::write_compact(&atla_doc).context("Failed to write ATLA JSON")?
             } else {
@@ -210,7 +544,7 @@ pub fn convert(input: &PathBuf, output: &PathBuf, compact: bool, rotate: f64) ->
         }
         // LDT/IES input -> ATLA output
         ("ldt" | "ies", "xml") => {
-            let ldt = load_with_rotation(input, rotate)?;
+            let ldt = parse_content(source, in_ext, rotate)?;
             let atla_doc = atla::LuminaireOpticalData::from_eulumdat(&ldt);
             if compact {
                 atla::xml::write_compact(&atla_doc).context("Failed to write ATLA XML")?
@@ -219,7 +553,7 @@ pub fn convert(input: &PathBuf, output: &PathBuf, compact: bool, rotate: f64) ->
             }
         }
         ("ldt" | "ies", "json") => {
-            let ldt = load_with_rotation(input, rotate)?;
+            let ldt = parse_content(source, in_ext, rotate)?;
             let atla_doc = atla::LuminaireOpticalData::from_eulumdat(&ldt);
             if compact {
                 atla::json::write_compact(&atla_doc).context("Failed to write ATLA JSON")?
@@ -229,12 +563,12 @@ pub fn convert(input: &PathBuf, output: &PathBuf, compact: bool, rotate: f64) ->
         }
         // Any input -> LDT output (via Eulumdat)
         (_, "ldt") => {
-            let ldt = load_with_rotation(input, rotate)?;
+            let ldt = parse_content(source, in_ext, rotate)?;
             ldt.to_ldt()
         }
         // Any input -> IES output (rotation applied on export for LDT sources)
         (_, "ies") => {
-            let ldt = load_file(input)?;
+            let ldt = parse_content(source, in_ext, 0.0)?;
             let opts = eulumdat::IesExportOptions {
                 rotate_c_planes: rotate,
                 ..Default::default()
@@ -245,97 +579,648 @@ pub fn convert(input: &PathBuf, output: &PathBuf, compact: bool, rotate: f64) ->
             "Unknown output extension: .{out_ext} (expected .ldt, .ies, .xml, or .json)"
         ),
     };
+    Ok(content)
+}
 
-    std::fs::write(output, &content).context("Failed to write output file")?;
+/// Rotate the C-planes by `degrees` and write the file back, or to `output`
+pub fn rotate(input: &PathBuf, output: Option<&PathBuf>, degrees: f64) -> Result<()> {
+    let out_ext = ldt_or_ies_extension(output.unwrap_or(input))?;
+    let out_path = edit_output(input, output.map(PathBuf::as_path))?;
 
-    let in_ext_upper = in_ext.to_uppercase();
-    let out_ext_upper = out_ext.to_uppercase();
-    let mut notes = Vec::new();
-    if compact && (out_ext == "xml" || out_ext == "json") {
-        notes.push("compact");
+    let mut ldt = load_file(input)?;
+    let symmetry = ldt.symmetry;
+    ldt.rotate_c_planes(degrees);
+    save_ldt_or_ies(&ldt, out_path, &out_ext)?;
+
+    println!("Rotated C-planes by {}° → {}", degrees, out_path.display());
+    if ldt.symmetry != symmetry {
+        println!("  Symmetry:  {:?} → {:?}", symmetry, ldt.symmetry);
     }
-    if rotate.abs() > 0.001 {
-        notes.push("C-planes rotated");
+
+    Ok(())
+}
+
+/// Mirror the distribution across `plane` and write the file back, or to `output`
+pub fn mirror(input: &PathBuf, output: Option<&PathBuf>, plane: MirrorPlaneArg) -> Result<()> {
+    let out_ext = ldt_or_ies_extension(output.unwrap_or(input))?;
+    let out_path = edit_output(input, output.map(PathBuf::as_path))?;
+    let (plane, label) = match plane {
+        MirrorPlaneArg::C0C180 => (MirrorPlane::C0C180, "C0-C180"),
+        MirrorPlaneArg::C90C270 => (MirrorPlane::C90C270, "C90-C270"),
+    };
+
+    let mut ldt = load_file(input)?;
+    let symmetry = ldt.symmetry;
+    ldt.mirror(plane);
+    save_ldt_or_ies(&ldt, out_path, &out_ext)?;
+
+    println!("Mirrored across {} → {}", label, out_path.display());
+    if ldt.symmetry != symmetry {
+        println!("  Symmetry:  {:?} → {:?}", symmetry, ldt.symmetry);
     }
-    let format_note = if notes.is_empty() {
-        String::new()
-    } else {
-        format!(" [{}]", notes.join(", "))
+
+    Ok(())
+}
+
+/// Scale lamp lumens and wattage by `factor`, or to `target_flux`, and
+/// write the file back, or to `output`
+pub fn scale(
+    input: &PathBuf,
+    output: Option<&PathBuf>,
+    factor: Option<f64>,
+    target_flux: Option<f64>,
+) -> Result<()> {
+    let out_ext = ldt_or_ies_extension(output.unwrap_or(input))?;
+    let out_path = edit_output(input, output.map(PathBuf::as_path))?;
+
+    let mut ldt = load_file(input)?;
+    let flux_before = ldt.total_luminous_flux();
+    let wattage_before = ldt.total_wattage();
+
+    let factor = match (factor, target_flux) {
+        (Some(factor), _) => factor,
+        (None, Some(target)) => {
+            if flux_before <= 0.0 {
+                anyhow::bail!("The file has no lamp flux to scale to --target-flux");
+            }
+            target / flux_before
+        }
+        (None, None) => anyhow::bail!("Use --factor or --target-flux"),
     };
+    if !factor.is_finite() || factor <= 0.0 {
+        anyhow::bail!("The scale factor must be positive (got {factor})");
+    }
+    ldt.scale_flux(factor);
 
+    save_ldt_or_ies(&ldt, out_path, &out_ext)?;
+
+    println!("Scaled by {:.4} → {}", factor, out_path.display());
     println!(
-        "Converted {} → {} ({} → {}){}",
-        input.display(),
-        output.display(),
-        in_ext_upper,
-        out_ext_upper,
-        format_note
+        "  Flux:      {:.0} lm → {:.0} lm",
+        flux_before,
+        ldt.total_luminous_flux()
+    );
+    println!(
+        "  Wattage:   {:.1} W → {:.1} W",
+        wattage_before,
+        ldt.total_wattage()
+    );
+    println!(
+        "  I_max:     {:.0} cd → {:.0} cd",
+        ldt.max_intensity() * flux_before / 1000.0,
+        ldt.max_intensity() * ldt.total_luminous_flux() / 1000.0
+    );
+
+    Ok(())
+}
+
+/// Resample onto a regular grid, report the error at the original angles
+/// and write the result to `output`
+pub fn resample(input: &PathBuf, c_step: f64, g_step: f64, output: Option<&PathBuf>) -> Result<()> {
+    if c_step <= 0.0 || g_step <= 0.0 {
+        anyhow::bail!("--c-step and --g-step must be positive");
+    }
+    let out_ext = output.map(|path| ldt_or_ies_extension(path)).transpose()?;
+    if let Some(path) = output {
+        edit_output(input, Some(path))?;
+    }
+
+    let original = load_file(input)?;
+    let mut resampled = original.clone();
+    resampled.resample(c_step, g_step);
+    let error = resampled.deviation_from(&original);
+    let flux_before = PhotometricCalculations::integrate_flux(&original).luminaire_flux;
+    let flux_after = PhotometricCalculations::integrate_flux(&resampled).luminaire_flux;
+
+    println!("Resampled {}", input.display());
+    println!(
+        "  Grid:      {} × {} → {} × {} (C × γ)",
+        original.num_c_planes,
+        original.g_angles.len(),
+        resampled.num_c_planes,
+        resampled.g_angles.len()
+    );
+    println!(
+        "  Stored:    {} → {} C-planes ({:?} → {:?})",
+        original.intensities.len(),
+        resampled.intensities.len(),
+        original.symmetry,
+        resampled.symmetry
+    );
+    println!("Interpolation error at the original angles:");
+    println!(
+        "  Max:       {:.2} cd/klm ({:.2}% of I_max) at C{}° γ{}°",
+        error.max, error.max_percent, error.c_angle, error.g_angle
     );
+    println!("  RMS:       {:.2} cd/klm", error.rms);
+    if flux_before > 0.0 {
+        println!(
+            "  Flux:      {:+.2}%",
+            (flux_after - flux_before) / flux_before * 100.0
+        );
+    }
+
+    if let (Some(out_path), Some(ext)) = (output, out_ext) {
+        save_ldt_or_ies(&resampled, out_path, &ext)?;
+        println!("Wrote {}", out_path.display());
+    }
 
     Ok(())
 }
 
+/// Edit header fields and write the file back, or to `output`.
+///
+/// The text fields of an IES file are edited in its keyword lines, leaving
+/// the rest of the file as it is; `--flux` and `--wattage` re-export it.
 #[allow(clippy::too_many_arguments)]
-pub fn diagram(
+pub fn set(
     input: &PathBuf,
     output: Option<&PathBuf>,
-    diagram_type: DiagramType,
-    dark: bool,
-    width: f64,
-    height: f64,
-    mounting_height: f64,
-    tilt: f64,
-    log_scale: bool,
-    units: eulumdat::UnitSystem,
+    luminaire_name: Option<String>,
+    luminaire_number: Option<String>,
+    manufacturer: Option<String>,
+    report_number: Option<String>,
+    date_user: Option<String>,
+    flux: Option<f64>,
+    wattage: Option<f64>,
+    lamp_type: Option<String>,
 ) -> Result<()> {
-    use eulumdat::diagram::*;
-
-    let theme = if dark {
-        SvgTheme::dark()
+    let out_ext = ldt_or_ies_extension(output.unwrap_or(input))?;
+    let edit_keywords =
+        extension(input) == "ies" && out_ext == "ies" && flux.is_none() && wattage.is_none();
+    let out_path = if edit_keywords {
+        output.unwrap_or(input).as_path()
     } else {
-        SvgTheme::light()
+        edit_output(input, output.map(PathBuf::as_path))?
     };
 
-    let svg = match diagram_type {
-        DiagramType::Polar => {
-            let ldt = load_file(input)?;
-            let diagram = PolarDiagram::from_eulumdat(&ldt);
-            diagram.to_svg(width, height, &theme)
-        }
-        DiagramType::Butterfly => {
-            let ldt = load_file(input)?;
-            let diagram = ButterflyDiagram::from_eulumdat(&ldt, width, height, 60.0);
-            diagram.to_svg(width, height, &theme)
-        }
-        DiagramType::Cartesian => {
-            let ldt = load_file(input)?;
-            let diagram = CartesianDiagram::from_eulumdat(&ldt, width, height, 8);
-            diagram.to_svg(width, height, &theme)
+    let mut ldt = load_file(input)?;
+    let mut changed = Vec::new();
+    let mut keywords = Vec::new();
+
+    let text_fields = [
+        (
+            "luminaire name",
+            "LUMINAIRE",
+            luminaire_name,
+            &mut ldt.luminaire_name,
+        ),
+        (
+            "luminaire number",
+            "LUMCAT",
+            luminaire_number,
+            &mut ldt.luminaire_number,
+        ),
+        (
+            "manufacturer",
+            "MANUFAC",
+            manufacturer,
+            &mut ldt.identification,
+        ),
+        (
+            "report number",
+            "TEST",
+            report_number,
+            &mut ldt.measurement_report_number,
+        ),
+        ("date/user", "ISSUEDATE", date_user, &mut ldt.date_user),
+    ];
+    for (label, keyword, value, field) in text_fields {
+        if let Some(value) = value {
+            keywords.push((keyword, value.clone()));
+            *field = value;
+            changed.push(label);
         }
-        DiagramType::Heatmap => {
-            let ldt = load_file(input)?;
-            let diagram = HeatmapDiagram::from_eulumdat(&ldt, width, height);
-            diagram.to_svg(width, height, &theme)
+    }
+
+    if flux.is_some() || wattage.is_some() || lamp_type.is_some() {
+        let lamp_set = ldt
+            .lamp_sets
+            .first_mut()
+            .context("The file has no lamp set for --flux, --wattage or --lamp-type")?;
+        if let Some(flux) = flux {
+            lamp_set.total_luminous_flux = flux;
+            changed.push("flux");
         }
-        DiagramType::Cone => {
-            let ldt = load_file(input)?;
-            let diagram = ConeDiagram::from_eulumdat(&ldt, mounting_height);
-            diagram.to_svg_with_units(width, height, &theme, &ConeDiagramLabels::default(), units)
+        if let Some(wattage) = wattage {
+            lamp_set.wattage_with_ballast = wattage;
+            changed.push("wattage");
         }
-        DiagramType::BeamAngle => {
-            let ldt = load_file(input)?;
-            let diagram = PolarDiagram::from_eulumdat(&ldt);
-            let analysis = PhotometricCalculations::beam_field_analysis(&ldt);
-            let show_both = analysis.is_batwing;
-            diagram.to_svg_with_beam_field_angles(width, height, &theme, &analysis, show_both)
+        if let Some(lamp_type) = lamp_type {
+            keywords.push(("LAMP", lamp_type.clone()));
+            lamp_set.lamp_type = lamp_type;
+            changed.push("lamp type");
         }
-        DiagramType::Lcs => {
-            let ldt = load_file(input)?;
+    }
+
+    if changed.is_empty() {
+        anyhow::bail!("Nothing to set (use e.g. --luminaire-name, --manufacturer or --flux)");
+    }
+
+    if edit_keywords {
+        let bytes =
+            fs::read(input).with_context(|| format!("Failed to read {}", input.display()))?;
+        let utf8 = std::str::from_utf8(&bytes).is_ok();
+        let mut content = decode_text(bytes);
+        for (keyword, value) in &keywords {
+            content = set_ies_keyword(&content, keyword, value);
+        }
+        // Keep the encoding of the input, ISO-8859-1 if it was not UTF-8
+        let bytes: Vec<u8> = if utf8 {
+            content.into_bytes()
+        } else {
+            content
+                .chars()
+                .map(|c| u8::try_from(c).unwrap_or(b'?'))
+                .collect()
+        };
+        fs::write(out_path, bytes).context("Failed to write output file")?;
+    } else {
+        save_ldt_or_ies(&ldt, out_path, &out_ext)?;
+    }
+
+    println!("Set {} → {}", changed.join(", "), out_path.display());
+
+    Ok(())
+}
+
+/// Clear identifying metadata, replace the luminaire name with `name` and
+/// write the result to `output`.
+///
+/// The file name field becomes the name of `output`. Writing back to the
+/// input is refused, as its file name would still identify the product.
+pub fn anonymize(input: &Path, output: &Path, name: &str, lamp_types: bool) -> Result<()> {
+    let out_ext = ldt_or_ies_extension(output)?;
+    if output == input
+        || fs::canonicalize(output).is_ok_and(|path| fs::canonicalize(input).ok() == Some(path))
+    {
+        anyhow::bail!(
+            "Not anonymizing {} in place: its file name would be kept; use -o with another name",
+            input.display()
+        );
+    }
+    let out_path = edit_output(input, Some(output))?;
+
+    let mut ldt = load_file(input)?;
+    // The file name field names the new file, not the original one
+    let file_name = out_path
+        .file_name()
+        .map(|f| f.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let mut fields = vec![
+        ("manufacturer", &mut ldt.identification, String::new()),
+        ("luminaire name", &mut ldt.luminaire_name, name.to_string()),
+        ("luminaire number", &mut ldt.luminaire_number, String::new()),
+        (
+            "report number",
+            &mut ldt.measurement_report_number,
+            String::new(),
+        ),
+        ("date/user", &mut ldt.date_user, String::new()),
+        ("file name", &mut ldt.file_name, file_name),
+    ];
+    if lamp_types {
+        for lamp_set in &mut ldt.lamp_sets {
+            fields.push(("lamp type", &mut lamp_set.lamp_type, String::new()));
+        }
+    }
+
+    let mut changed = Vec::new();
+    for (label, field, value) in fields {
+        if *field != value {
+            *field = value;
+            if !changed.contains(&label) {
+                changed.push(label);
+            }
+        }
+    }
+
+    save_ldt_or_ies(&ldt, out_path, &out_ext)?;
+
+    if changed.is_empty() {
+        println!("Nothing to anonymize → {}", out_path.display());
+    } else {
+        println!("Anonymized {} → {}", changed.join(", "), out_path.display());
+    }
+
+    Ok(())
+}
+
+/// Apply the safe corrections of [`RepairReport::fix_validation`], print
+/// them and write the file back, or to `output`.
+///
+/// Fixed are plane counts, intensity rows of the wrong length, negative
+/// intensities, LOR and DFF (out of range or disagreeing with the
+/// intensities), direct ratios outside 0–1, a non-positive conversion
+/// factor and over-long text fields. Warnings without a safe correction,
+/// such as unsorted angles, are only listed.
+pub fn fix(input: &Path, output: Option<&Path>, dry_run: bool, annotate: bool) -> Result<()> {
+    let out_ext = ldt_or_ies_extension(output.unwrap_or(input))?;
+
+    let mut ldt = load_file(input)?;
+    let report = RepairReport::fix_validation(&mut ldt);
+
+    if report.is_empty() {
+        println!("Nothing to fix in {}", input.display());
+    } else {
+        println!("Fixes for {}:", input.display());
+        for repair in &report.repairs {
+            println!("  {repair}");
+        }
+    }
+
+    let remaining = ldt.validate();
+    if !remaining.is_empty() {
+        println!();
+        println!("Not fixed automatically:");
+        for warning in &remaining {
+            println!("  [{}] {}", warning.code, warning.message);
+        }
+    }
+
+    if dry_run || (report.is_empty() && output.is_none()) {
+        return Ok(());
+    }
+    let out_path = edit_output(input, output)?;
+
+    let content = if out_ext == "ldt" {
+        if annotate {
+            report.annotate_ldt(&mut ldt);
+        }
+        ldt.to_ldt()
+    } else {
+        let options = IesExportOptions {
+            repair_report: (annotate && !report.is_empty()).then(|| report.clone()),
+            ..Default::default()
+        };
+        IesExporter::export_with_options(&ldt, &options)
+    };
+    fs::write(out_path, content).context("Failed to write output file")?;
+
+    println!();
+    println!("Applied {} fix(es) → {}", report.len(), out_path.display());
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn diagram(
+    input: &Path,
+    from: Option<FileFormatArg>,
+    output: Option<&PathBuf>,
+    diagram_type: DiagramType,
+    dark: bool,
+    theme_file: Option<&PathBuf>,
+    width: f64,
+    height: f64,
+    mounting_height: f64,
+    heights: &[f64],
+    tilt: f64,
+    throw: f64,
+    log_scale: bool,
+    color_map: eulumdat::diagram::ColorMap,
+    radial_scale: eulumdat::diagram::RadialScale,
+    batwing: bool,
+    units: eulumdat::UnitSystem,
+    isolux_style: IsoluxStyle,
+    area: f64,
+    patterns: bool,
+) -> Result<()> {
+    use eulumdat::diagram::*;
+
+    // Read once, so stdin works for every diagram type
+    let source = read_input(input)?;
+    let format = input_format(input, from, &source);
+    let load = || parse_content(&source, &format, 0.0);
+    // `-o -` is the same as no output file
+    let output = output.filter(|path| !is_stdio(path));
+
+    let theme = if let Some(path) = theme_file {
+        let json = fs::read_to_string(path).context("Failed to read theme file")?;
+        serde_json::from_str::<SvgTheme>(&json).context("Invalid theme file")?
+    } else if dark {
+        SvgTheme::dark()
+    } else {
+        SvgTheme::light()
+    };
+    // An imperial theme file stays imperial without repeating --units
+    let mut theme = match units {
+        eulumdat::UnitSystem::Imperial => theme.with_units(units),
+        eulumdat::UnitSystem::Metric => theme,
+    };
+    theme.curve_patterns |= patterns;
+
+    let is_dxf = output.is_some_and(|path| {
+        path.extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("dxf"))
+    });
+    if let (true, Some(out_path)) = (is_dxf, output) {
+        let dxf = match diagram_type {
+            DiagramType::Polar => PolarDiagram::from_eulumdat(&load()?)
+                .with_radial_scale(radial_scale)
+                .to_dxf(),
+            DiagramType::Isolux => {
+                let ldt = load()?;
+                let params = IsoluxParams {
+                    mounting_height,
+                    tilt_angle: tilt,
+                    area_half_width: area,
+                    area_half_depth: area,
+                    grid_resolution: 80,
+                };
+                IsoluxDiagram::from_eulumdat_with_units(&ldt, width, height, params, theme.units)
+                    .to_dxf()
+            }
+            _ => anyhow::bail!("DXF output is only available for polar and isolux diagrams"),
+        };
+        std::fs::write(out_path, dxf).context("Failed to write DXF file")?;
+        println!(
+            "Generated {:?} diagram: {}",
+            diagram_type,
+            out_path.display()
+        );
+        return Ok(());
+    }
+
+    // The intensity table paginates: HTML in one file, SVG/PNG one file per page
+    if diagram_type == DiagramType::Table {
+        let table = IntensityTable::from_eulumdat(&load()?).with_color_map(color_map);
+        let Some(out_path) = output else {
+            println!("{}", table.to_html(&theme, 12));
+            return Ok(());
+        };
+        let ext = out_path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("svg")
+            .to_lowercase();
+        if ext == "html" || ext == "htm" {
+            std::fs::write(out_path, table.to_html(&theme, 12))
+                .context("Failed to write HTML file")?;
+            println!("Generated intensity table: {}", out_path.display());
+            return Ok(());
+        }
+        let stem = out_path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("table");
+        let pages = table.to_svg_pages(width, height, &theme);
+        for (i, page) in pages.iter().enumerate() {
+            let path = if i == 0 {
+                out_path.clone()
+            } else {
+                out_path.with_file_name(format!("{stem}-{}.{ext}", i + 1))
+            };
+            let page = add_svg_title(
+                &theme.apply_styling(page),
+                "Luminous intensity table (cd/klm)",
+                &format!("Page {} of {}", i + 1, pages.len()),
+            );
+            if ext == "png" {
+                let png = eulumdat::diagram::svg_to_png(&page, 1.0)?;
+                std::fs::write(&path, png).context("Failed to write PNG file")?;
+            } else {
+                std::fs::write(&path, page).context("Failed to write SVG file")?;
+            }
+            println!("Generated intensity table page: {}", path.display());
+        }
+        return Ok(());
+    }
+
+    let options = DiagramOptions {
+        width,
+        height,
+        mounting_height,
+        heights,
+        tilt,
+        throw,
+        log_scale,
+        color_map,
+        radial_scale,
+        batwing,
+        isolux_style,
+        area,
+    };
+    let svg = diagram_svg(&source, &format, diagram_type, dark, &theme, options)?;
+
+    if let Some(out_path) = output {
+        let is_png = out_path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("png"));
+        if is_png {
+            let png = eulumdat::diagram::svg_to_png(&svg, 1.0)?;
+            std::fs::write(out_path, png).context("Failed to write PNG file")?;
+        } else {
+            std::fs::write(out_path, &svg).context("Failed to write SVG file")?;
+        }
+        println!(
+            "Generated {:?} diagram: {}",
+            diagram_type,
+            out_path.display()
+        );
+    } else {
+        println!("{svg}");
+    }
+
+    Ok(())
+}
+
+/// Options of [`diagram_svg`]; see the `diagram` command for their meaning
+#[derive(Clone)]
+pub struct DiagramOptions<'a> {
+    pub width: f64,
+    pub height: f64,
+    pub mounting_height: f64,
+    pub heights: &'a [f64],
+    pub tilt: f64,
+    pub throw: f64,
+    pub log_scale: bool,
+    pub color_map: eulumdat::diagram::ColorMap,
+    pub radial_scale: eulumdat::diagram::RadialScale,
+    pub batwing: bool,
+    pub isolux_style: IsoluxStyle,
+    pub area: f64,
+}
+
+/// Render `source` in `format` (ldt, ies, xml or json) as an SVG diagram.
+///
+/// The intensity table is paginated and not available here.
+pub fn diagram_svg(
+    source: &str,
+    format: &str,
+    diagram_type: DiagramType,
+    dark: bool,
+    theme: &SvgTheme,
+    options: DiagramOptions,
+) -> Result<String> {
+    use clap::ValueEnum;
+    use eulumdat::diagram::*;
+
+    let DiagramOptions {
+        width,
+        height,
+        mounting_height,
+        heights,
+        tilt,
+        throw,
+        log_scale,
+        color_map,
+        radial_scale,
+        batwing,
+        isolux_style,
+        area,
+    } = options;
+    let load = || parse_content(source, format, 0.0);
+    // As ATLA document (preserves spectral data)
+    let load_atla = || parse_atla_content(source, format);
+
+    let svg = match diagram_type {
+        DiagramType::Polar => {
+            let ldt = load()?;
+            let diagram = PolarDiagram::from_eulumdat(&ldt).with_radial_scale(radial_scale);
+            diagram.to_svg(width, height, theme)
+        }
+        DiagramType::Butterfly => {
+            let ldt = load()?;
+            let diagram = ButterflyDiagram::from_eulumdat(&ldt, width, height, 60.0);
+            diagram.to_svg(width, height, theme)
+        }
+        DiagramType::Cartesian => {
+            let ldt = load()?;
+            let mut diagram = CartesianDiagram::from_eulumdat(&ldt, width, height, 8);
+            if batwing {
+                diagram = diagram.with_batwing_annotations(&ldt);
+            }
+            diagram.to_svg(width, height, theme)
+        }
+        DiagramType::Heatmap => {
+            let ldt = load()?;
+            let diagram = HeatmapDiagram::from_eulumdat(&ldt, width, height)
+                .with_color_map(color_map)
+                .with_log_scale(log_scale);
+            diagram.to_svg(width, height, theme)
+        }
+        DiagramType::Cone => {
+            let ldt = load()?;
+            let diagram =
+                ConeDiagram::from_eulumdat(&ldt, mounting_height).with_heights(&ldt, heights);
+            diagram.to_svg(width, height, theme)
+        }
+        DiagramType::BeamAngle => {
+            let ldt = load()?;
+            let diagram = PolarDiagram::from_eulumdat(&ldt);
+            let analysis = PhotometricCalculations::beam_field_analysis(&ldt);
+            let show_both = analysis.is_batwing;
+            diagram.to_svg_with_beam_field_angles(width, height, theme, &analysis, show_both)
+        }
+        DiagramType::Lcs => {
+            let ldt = load()?;
             let diagram = BugDiagram::from_eulumdat(&ldt);
-            diagram.to_lcs_svg(width, height, &theme)
+            diagram.to_lcs_svg(width, height, theme)
         }
         DiagramType::Spectral => {
-            let atla_doc = load_atla(input)?;
+            let atla_doc = load_atla()?;
             let atla_theme = if dark {
                 atla::spectral::SpectralTheme::dark()
             } else {
@@ -365,8 +1250,25 @@ pub fn diagram(
                 anyhow::bail!("No emitter data found in file. Spectral diagram requires emitter with spectral distribution or CCT.")
             }
         }
+        DiagramType::Tm30 => {
+            let atla_doc = load_atla()?;
+            let tm30_theme = if dark {
+                atla::tm30::Tm30Theme::dark()
+            } else {
+                atla::tm30::Tm30Theme::light()
+            };
+            let spd = atla_doc
+                .emitters
+                .iter()
+                .filter_map(|e| e.spectral_distribution.as_ref())
+                .next()
+                .context("No spectral data found in file. TM-30 requires a measured spectral distribution.")?;
+            let tm30 = atla::tm30::calculate_tm30(spd)
+                .context("Spectral data must cover at least 400-700 nm for TM-30")?;
+            tm30.to_svg(width, height, &tm30_theme)
+        }
         DiagramType::Greenhouse => {
-            let atla_doc = load_atla(input)?;
+            let atla_doc = load_atla()?;
             let gh_theme = if dark {
                 atla::greenhouse::GreenhouseTheme::dark()
             } else {
@@ -379,243 +1281,726 @@ pub fn diagram(
             diagram.to_svg(width, height, &gh_theme)
         }
         DiagramType::FloodlightVh => {
-            let ldt = load_file(input)?;
+            let ldt = load()?;
             let y_scale = if log_scale {
                 YScale::Logarithmic
             } else {
                 YScale::Linear
             };
             let diagram = FloodlightCartesianDiagram::from_eulumdat(&ldt, width, height, y_scale);
-            diagram.to_svg(width, height, &theme)
+            diagram.to_svg(width, height, theme)
         }
         DiagramType::Isolux => {
-            let ldt = load_file(input)?;
+            let ldt = load()?;
             let params = IsoluxParams {
                 mounting_height,
                 tilt_angle: tilt,
-                area_half_width: 20.0,
-                area_half_depth: 20.0,
+                area_half_width: area,
+                area_half_depth: area,
                 grid_resolution: 80,
             };
             let diagram =
-                IsoluxDiagram::from_eulumdat_with_units(&ldt, width, height, params, units);
-            diagram.to_svg_with_units(width, height, &theme, units)
+                IsoluxDiagram::from_eulumdat_with_units(&ldt, width, height, params, theme.units);
+            match isolux_style {
+                IsoluxStyle::Heatmap => diagram.to_svg(width, height, theme),
+                IsoluxStyle::Aec => diagram.to_svg_aec(width, height, theme, theme.units),
+                IsoluxStyle::Datasheet => {
+                    diagram.to_svg_datasheet(width, height, theme, theme.units)
+                }
+            }
+        }
+        DiagramType::Isocandela => {
+            let ldt = load()?;
+            let diagram = IsocandelaDiagram::from_eulumdat(&ldt, width, height);
+            diagram.to_svg(width, height, theme)
+        }
+        DiagramType::Footprint => {
+            let ldt = load()?;
+            let params = FootprintParams {
+                throw_distance: throw,
+                tilt_angle: tilt,
+                grid_resolution: 100,
+            };
+            let diagram = FootprintDiagram::from_eulumdat(&ldt, width, height, params);
+            diagram.to_svg(width, height, theme)
+        }
+        DiagramType::Table => {
+            anyhow::bail!("The intensity table is paginated; use the diagram command")
+        }
+        DiagramType::Glare => {
+            let ldt = load()?;
+            let diagram = GlareDiagram::from_eulumdat(&ldt);
+            diagram.to_svg(width, height, theme)
+        }
+    };
+    // Polar, cartesian and heatmap apply font/line scale and logo themselves
+    // and carry their own title
+    let svg = match diagram_type {
+        DiagramType::Polar | DiagramType::Cartesian | DiagramType::Heatmap => svg,
+        _ => {
+            let title = diagram_type
+                .to_possible_value()
+                .and_then(|value| value.get_help().map(|help| help.to_string()))
+                .unwrap_or_default();
+            add_svg_title(&theme.apply_styling(&svg), &title, "")
+        }
+    };
+    Ok(svg)
+}
+
+pub fn bug(file: &PathBuf, svg: Option<&PathBuf>, dark: bool, as_json: bool) -> Result<()> {
+    let ldt = load_file(file)?;
+    let bug = BugDiagram::from_eulumdat(&ldt);
+
+    if as_json {
+        if let Some(out_path) = svg {
+            write_bug_svg(&bug, out_path, dark)?;
+        }
+        return json::print(
+            "bug",
+            file,
+            json::Bug {
+                rating: bug.rating,
+                rating_text: bug.rating.to_string(),
+                zones_lm: bug.zones,
+            },
+        );
+    }
+
+    println!("BUG Rating for {}:", file.display());
+    println!();
+    println!("Rating: {}", bug.rating);
+    println!();
+    println!("=== Zone Lumens ===");
+    println!(
+        "Backlight:  BL={:.1}  BM={:.1}  BH={:.1}  BVH={:.1}",
+        bug.zones.bl, bug.zones.bm, bug.zones.bh, bug.zones.bvh
+    );
+    println!(
+        "Forward:    FL={:.1}  FM={:.1}  FH={:.1}  FVH={:.1}",
+        bug.zones.fl, bug.zones.fm, bug.zones.fh, bug.zones.fvh
+    );
+    println!("Uplight:    UL={:.1}  UH={:.1}", bug.zones.ul, bug.zones.uh);
+
+    if let Some(out_path) = svg {
+        write_bug_svg(&bug, out_path, dark)?;
+        println!();
+        println!("Generated BUG diagram: {}", out_path.display());
+    }
+
+    Ok(())
+}
+
+fn write_bug_svg(bug: &BugDiagram, path: &Path, dark: bool) -> Result<()> {
+    let theme = if dark {
+        SvgTheme::dark()
+    } else {
+        SvgTheme::light()
+    };
+    let svg_content = bug.to_svg(400.0, 350.0, &theme);
+    std::fs::write(path, &svg_content).context("Failed to write SVG file")
+}
+
+/// Convert all .ldt and .ies files of a directory on `jobs` threads (0 for
+/// one per CPU core), with a progress bar and per-file timing
+pub fn batch(
+    input_dir: &PathBuf,
+    output_dir: Option<&PathBuf>,
+    format: OutputFormat,
+    recursive: bool,
+    overwrite: bool,
+    jobs: usize,
+    fail_on: Option<FailOnArg>,
+) -> Result<()> {
+    use rayon::prelude::*;
+    use std::time::{Duration, Instant};
+
+    if !input_dir.is_dir() {
+        anyhow::bail!("Input path is not a directory: {}", input_dir.display());
+    }
+
+    let output_dir = output_dir.unwrap_or(input_dir);
+    fs::create_dir_all(output_dir).context("Failed to create output directory")?;
+
+    let paths = photometric_files(input_dir, recursive);
+    if paths.is_empty() {
+        println!("No .ldt or .ies files found in {}", input_dir.display());
+        return Ok(());
+    }
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .context("Failed to start worker threads")?;
+    println!(
+        "Converting {} files to {} format on {} threads...",
+        paths.len(),
+        match format {
+            OutputFormat::Ldt => "LDT",
+            OutputFormat::Ies => "IES",
+        },
+        pool.current_num_threads()
+    );
+    println!();
+
+    let conversion_format = match format {
+        OutputFormat::Ldt => ConversionFormat::Ldt,
+        OutputFormat::Ies => ConversionFormat::Ies,
+    };
+
+    /// Outcome of one file
+    enum Outcome {
+        Converted,
+        Skipped,
+        Failed,
+    }
+
+    let progress = crate::progress::Progress::new(paths.len());
+    let started = Instant::now();
+    let results: Vec<(&PathBuf, Duration, Outcome)> = pool.install(|| {
+        paths
+            .par_iter()
+            .map(|path| {
+                let file_started = Instant::now();
+                let result = batch_file(
+                    path,
+                    input_dir,
+                    output_dir,
+                    conversion_format,
+                    recursive,
+                    overwrite,
+                );
+                let elapsed = file_started.elapsed();
+                let name = path.strip_prefix(input_dir).unwrap_or(path).display();
+                let outcome = match result {
+                    Ok(Some(out_path)) => {
+                        progress.finish(
+                            &format!(
+                                "✓ {name} → {} ({:.1} ms)",
+                                out_path.display(),
+                                elapsed.as_secs_f64() * 1000.0
+                            ),
+                            false,
+                        );
+                        Outcome::Converted
+                    }
+                    Ok(None) => {
+                        progress.finish(
+                            &format!("✗ {name}: Output already exists (use --overwrite)"),
+                            true,
+                        );
+                        Outcome::Skipped
+                    }
+                    Err(e) => {
+                        progress.finish(&format!("✗ {name}: {e:#}"), true);
+                        Outcome::Failed
+                    }
+                };
+                (path, elapsed, outcome)
+            })
+            .collect()
+    });
+    progress.done();
+    let total_time = started.elapsed();
+
+    let count = |f: fn(&Outcome) -> bool| results.iter().filter(|(_, _, o)| f(o)).count();
+    let successful = count(|o| matches!(o, Outcome::Converted));
+    let skipped = count(|o| matches!(o, Outcome::Skipped));
+    let failed = count(|o| matches!(o, Outcome::Failed));
+
+    println!();
+    println!("=== Batch Conversion Summary ===");
+    println!("Total:      {}", results.len());
+    println!("Successful: {successful}");
+    if skipped > 0 {
+        println!("Skipped:    {skipped}");
+    }
+    println!("Failed:     {failed}");
+    println!(
+        "Time:       {:.2} s ({:.0} files/s)",
+        total_time.as_secs_f64(),
+        results.len() as f64 / total_time.as_secs_f64().max(1e-3)
+    );
+    let mut slowest: Vec<_> = results.iter().map(|(path, time, _)| (path, time)).collect();
+    slowest.sort_by(|a, b| b.1.cmp(a.1));
+    if results.len() > 1 {
+        println!("Slowest:");
+        for (path, time) in slowest.iter().take(3) {
+            println!(
+                "  {:>8.1} ms  {}",
+                time.as_secs_f64() * 1000.0,
+                path.strip_prefix(input_dir).unwrap_or(path).display()
+            );
+        }
+    }
+
+    match fail_on {
+        Some(fail_on) => exit::check(fail_on, skipped, failed, ("skipped file", "failed file")),
+        None if failed > 0 => anyhow::bail!("{failed} file(s) failed to convert"),
+        None => Ok(()),
+    }
+}
+
+/// The .ldt and .ies files in `dir`, and with `recursive` its subdirectories
+pub fn photometric_files(dir: &Path, recursive: bool) -> Vec<PathBuf> {
+    let walker = if recursive {
+        walkdir::WalkDir::new(dir)
+    } else {
+        walkdir::WalkDir::new(dir).max_depth(1)
+    };
+    walker
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|entry| {
+            entry.file_type().is_file() && matches!(extension(entry.path()).as_str(), "ldt" | "ies")
+        })
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+/// Convert one file of [`batch`]; `None` if the output exists and
+/// `overwrite` is not set
+fn batch_file(
+    path: &Path,
+    input_dir: &Path,
+    output_dir: &Path,
+    format: ConversionFormat,
+    recursive: bool,
+    overwrite: bool,
+) -> Result<Option<PathBuf>> {
+    let input = BatchInput {
+        name: path.file_name().unwrap().to_string_lossy().to_string(),
+        content: read_input(path)?,
+        format: None, // Auto-detect
+    };
+    let output = batch::convert_one(&input, format);
+    if let Some(error) = output.error {
+        anyhow::bail!(error);
+    }
+    let content = output.content.unwrap_or_default();
+
+    // Preserve subdirectory structure if recursive
+    let mut out_dir = output_dir.to_path_buf();
+    if recursive {
+        if let Some(parent) = path.strip_prefix(input_dir).ok().and_then(Path::parent) {
+            out_dir = output_dir.join(parent);
+            fs::create_dir_all(&out_dir).context("Failed to create output directory")?;
+        }
+    }
+    let out_path = out_dir.join(&output.output_name);
+
+    if out_path.exists() && !overwrite {
+        return Ok(None);
+    }
+    fs::write(&out_path, content)
+        .with_context(|| format!("Failed to write {}", out_path.display()))?;
+    Ok(Some(out_path))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn thumbnail(
+    input_dir: &Path,
+    output_dir: Option<&Path>,
+    size: u32,
+    recursive: bool,
+    overwrite: bool,
+    dark: bool,
+    jobs: usize,
+) -> Result<()> {
+    use rayon::prelude::*;
+    use std::time::Instant;
+
+    if !input_dir.is_dir() {
+        anyhow::bail!("Input path is not a directory: {}", input_dir.display());
+    }
+    if !(16..=2048).contains(&size) {
+        anyhow::bail!("Thumbnail size must be between 16 and 2048 pixels");
+    }
+    let output_dir = output_dir.unwrap_or(input_dir);
+    fs::create_dir_all(output_dir).context("Failed to create output directory")?;
+
+    let paths = photometric_files(input_dir, recursive);
+    if paths.is_empty() {
+        println!("No .ldt or .ies files found in {}", input_dir.display());
+        return Ok(());
+    }
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .context("Failed to start worker threads")?;
+    let theme = if dark {
+        SvgTheme::dark()
+    } else {
+        SvgTheme::light()
+    };
+
+    let progress = crate::progress::Progress::new(paths.len());
+    let started = Instant::now();
+    // Some(true) written, Some(false) skipped, None failed
+    let results: Vec<Option<bool>> = pool.install(|| {
+        paths
+            .par_iter()
+            .map(|path| {
+                let name = path.strip_prefix(input_dir).unwrap_or(path);
+                // Keep the extension, so luminaire.ldt and luminaire.ies get their own thumbnail
+                let mut out_path = output_dir.join(name);
+                out_path.as_mut_os_string().push(".png");
+                if out_path.exists() && !overwrite {
+                    progress.finish(
+                        &format!(
+                            "✗ {}: Output already exists (use --overwrite)",
+                            name.display()
+                        ),
+                        true,
+                    );
+                    return Some(false);
+                }
+                let result = load_file(path).and_then(|ldt| {
+                    let svg = PolarDiagram::render_thumbnail_svg(&ldt, size as f64, &theme);
+                    let png = eulumdat::diagram::svg_to_png(&svg, 1.0)?;
+                    if let Some(parent) = out_path.parent() {
+                        fs::create_dir_all(parent).context("Failed to create output directory")?;
+                    }
+                    fs::write(&out_path, png)
+                        .with_context(|| format!("Failed to write {}", out_path.display()))
+                });
+                match result {
+                    Ok(()) => {
+                        progress.finish(
+                            &format!("✓ {} → {}", name.display(), out_path.display()),
+                            false,
+                        );
+                        Some(true)
+                    }
+                    Err(e) => {
+                        progress.finish(&format!("✗ {}: {e:#}", name.display()), true);
+                        None
+                    }
+                }
+            })
+            .collect()
+    });
+    progress.done();
+
+    let written = results.iter().filter(|r| **r == Some(true)).count();
+    let skipped = results.iter().filter(|r| **r == Some(false)).count();
+    let failed = results.iter().filter(|r| r.is_none()).count();
+    println!();
+    println!(
+        "{written} thumbnail(s) of {size}×{size} px in {:.2} s, {skipped} skipped, {failed} failed",
+        started.elapsed().as_secs_f64()
+    );
+    if failed > 0 {
+        anyhow::bail!("{failed} file(s) could not be rendered");
+    }
+    Ok(())
+}
+
+pub fn summary(file: &PathBuf, format: SummaryFormat, output: Option<&PathBuf>) -> Result<()> {
+    let ldt = load_file(file)?;
+    let summary = PhotometricSummary::from_eulumdat(&ldt);
+
+    let content = match format {
+        SummaryFormat::Text => {
+            let mut s = format!("File: {}\n\n", file.display());
+            s.push_str(&summary.to_text());
+            s
+        }
+        SummaryFormat::Compact => summary.to_compact(),
+        SummaryFormat::Json => serde_json::to_string_pretty(&summary.to_record())
+            .context("Failed to serialize summary")?,
+        SummaryFormat::JsonSchema => SummaryRecord::JSON_SCHEMA.to_string(),
+    };
+
+    if let Some(out_path) = output {
+        std::fs::write(out_path, &content).context("Failed to write output file")?;
+        println!("Summary written to: {}", out_path.display());
+    } else {
+        println!("{content}");
+    }
+
+    Ok(())
+}
+
+pub fn ugr(file: &PathBuf, format: UgrFormat, output: Option<&PathBuf>) -> Result<()> {
+    let ldt = load_file(file)?;
+    let table = PhotometricCalculations::ugr_table(&ldt);
+
+    let content = match format {
+        UgrFormat::Text => format!("File: {}\n\n{}", file.display(), table.to_text()),
+        UgrFormat::Csv => table.to_csv(),
+        UgrFormat::Json => {
+            let mut value =
+                serde_json::to_value(&table).context("Failed to serialize UGR table")?;
+            value["standard_room"] = serde_json::to_value(table.standard_room())
+                .context("Failed to serialize UGR table")?;
+            serde_json::to_string_pretty(&value).context("Failed to serialize UGR table")?
+        }
+    };
+
+    if let Some(out_path) = output {
+        std::fs::write(out_path, &content).context("Failed to write output file")?;
+        println!("UGR table written to: {}", out_path.display());
+    } else {
+        println!("{content}");
+    }
+
+    Ok(())
+}
+
+pub fn uf(
+    file: &PathBuf,
+    format: UfFormat,
+    output: Option<&PathBuf>,
+    room_indices: &[f64],
+    reflectances: &[(u8, u8, u8)],
+    cu: bool,
+) -> Result<()> {
+    let ldt = load_file(file)?;
+
+    let content = if cu {
+        let table = PhotometricCalculations::cu_table_rcr(&ldt);
+        match format {
+            UfFormat::Text => table.to_text(),
+            UfFormat::Csv => table.to_csv(),
+        }
+    } else {
+        let room_indices = if room_indices.is_empty() {
+            &UF_ROOM_INDICES[..]
+        } else {
+            room_indices
+        };
+        let reflectances = if reflectances.is_empty() {
+            &UF_REFLECTANCES[..]
+        } else {
+            reflectances
+        };
+        let table = UfTable::calculate_for(&ldt, room_indices, reflectances);
+        if table.room_indices.is_empty() {
+            anyhow::bail!("Room indices must be positive");
         }
-        DiagramType::Isocandela => {
-            let ldt = load_file(input)?;
-            let diagram = IsocandelaDiagram::from_eulumdat(&ldt, width, height);
-            diagram.to_svg(width, height, &theme)
+        match format {
+            UfFormat::Text => table.to_text(),
+            UfFormat::Csv => table.to_csv(),
         }
     };
 
     if let Some(out_path) = output {
-        std::fs::write(out_path, &svg).context("Failed to write SVG file")?;
-        println!(
-            "Generated {:?} diagram: {}",
-            diagram_type,
-            out_path.display()
-        );
+        std::fs::write(out_path, &content).context("Failed to write output file")?;
+        println!("Table written to: {}", out_path.display());
     } else {
-        println!("{svg}");
+        print!("{content}");
     }
 
     Ok(())
 }
 
-pub fn bug(file: &PathBuf, svg: Option<&PathBuf>, dark: bool) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+pub fn illuminance(
+    file: &Path,
+    width: f64,
+    depth: f64,
+    mounting_height: f64,
+    workplane: f64,
+    rows: usize,
+    cols: usize,
+    spacing: Option<f64>,
+    grid: usize,
+    maintenance_factor: f64,
+    svg: Option<&Path>,
+    dark: bool,
+) -> Result<()> {
+    if width <= 0.0 || depth <= 0.0 {
+        anyhow::bail!("Room width and depth must be positive");
+    }
+    if mounting_height <= workplane {
+        anyhow::bail!("Mounting height must be above the workplane ({workplane} m)");
+    }
+    if grid < 2 {
+        anyhow::bail!("The grid needs at least 2 points per axis");
+    }
+    let (rows, cols) = match spacing {
+        Some(spacing) if spacing > 0.0 => (
+            ((depth / spacing).round() as usize).max(1),
+            ((width / spacing).round() as usize).max(1),
+        ),
+        Some(_) => anyhow::bail!("Spacing must be positive"),
+        None if rows == 0 || cols == 0 => anyhow::bail!("Rows and columns must be at least 1"),
+        None => (rows, cols),
+    };
+
     let ldt = load_file(file)?;
-    let bug = BugDiagram::from_eulumdat(&ldt);
+    // Heights are measured from the workplane, which the area grid treats as ground
+    let height = mounting_height - workplane;
+    let placements: Vec<LuminairePlace> =
+        area::layout::generate_pole_positions(rows, cols, width, depth)
+            .into_iter()
+            .enumerate()
+            .map(|(id, (x, y))| LuminairePlace::simple(id, x, y, height))
+            .collect();
+    let result =
+        area::compute_area_illuminance(&ldt, &placements, width, depth, grid, maintenance_factor);
 
-    println!("BUG Rating for {}:", file.display());
-    println!();
-    println!("Rating: {}", bug.rating);
-    println!();
-    println!("=== Zone Lumens ===");
-    println!(
-        "Backlight:  BL={:.1}  BM={:.1}  BH={:.1}  BVH={:.1}",
-        bug.zones.bl, bug.zones.bm, bug.zones.bh, bug.zones.bvh
-    );
+    println!("Room:        {width:.2} × {depth:.2} m, workplane at {workplane:.2} m");
     println!(
-        "Forward:    FL={:.1}  FM={:.1}  FH={:.1}  FVH={:.1}",
-        bug.zones.fl, bug.zones.fm, bug.zones.fh, bug.zones.fvh
+        "Layout:      {rows} × {cols} = {} luminaires at {mounting_height:.2} m (spacing {:.2} × {:.2} m)",
+        placements.len(),
+        width / cols as f64,
+        depth / rows as f64
     );
-    println!("Uplight:    UL={:.1}  UH={:.1}", bug.zones.ul, bug.zones.uh);
+    println!("Grid:        {grid} × {grid} points");
+    println!();
+    println!("Average:     {:.0} lx", result.avg_lux);
+    println!("Minimum:     {:.0} lx", result.min_lux);
+    println!("Maximum:     {:.0} lx", result.max_lux);
+    println!("U0 min/avg:  {:.2}", result.uniformity_min_avg);
+    println!("Ud min/max:  {:.2}", result.uniformity_min_max);
 
-    if let Some(out_path) = svg {
+    if let Some(path) = svg {
         let theme = if dark {
             SvgTheme::dark()
         } else {
             SvgTheme::light()
         };
-        let svg_content = bug.to_svg(400.0, 350.0, &theme);
-        std::fs::write(out_path, &svg_content).context("Failed to write SVG file")?;
+        let svg_width = 600.0;
+        let svg_height = (svg_width * depth / width).clamp(300.0, 1200.0);
+        let content = AreaSvg::iso_view(
+            &result,
+            svg_width,
+            svg_height,
+            &theme,
+            eulumdat::UnitSystem::Metric,
+        );
+        fs::write(path, content).context("Failed to write SVG file")?;
         println!();
-        println!("Generated BUG diagram: {}", out_path.display());
+        println!("Isolux diagram written to {}", path.display());
     }
 
     Ok(())
 }
 
-pub fn batch(
-    input_dir: &PathBuf,
-    output_dir: Option<&PathBuf>,
-    format: OutputFormat,
-    recursive: bool,
-    overwrite: bool,
+/// EN 13201 road lighting check of a straight road
+#[allow(clippy::too_many_arguments)]
+pub fn road(
+    file: &Path,
+    lanes: usize,
+    lane_width: f64,
+    spacing: f64,
+    mounting_height: f64,
+    overhang: f64,
+    arrangement: RoadArrangementArg,
+    q0: f64,
+    maintenance_factor: f64,
+    target: Option<RoadClass>,
+    svg: Option<&Path>,
+    dark: bool,
 ) -> Result<()> {
-    use std::fs;
-
-    if !input_dir.is_dir() {
-        anyhow::bail!("Input path is not a directory: {}", input_dir.display());
+    if lanes == 0 {
+        anyhow::bail!("The road needs at least one lane");
     }
-
-    let output_dir = output_dir.unwrap_or(input_dir);
-    fs::create_dir_all(output_dir).context("Failed to create output directory")?;
-
-    // Collect input files
-    let mut batch_inputs = Vec::new();
-    let walker = if recursive {
-        walkdir::WalkDir::new(input_dir)
-    } else {
-        walkdir::WalkDir::new(input_dir).max_depth(1)
-    };
-
-    for entry in walker.into_iter().filter_map(|e| e.ok()) {
-        if !entry.file_type().is_file() {
-            continue;
-        }
-
-        let path = entry.path();
-        let ext = path
-            .extension()
-            .and_then(|e| e.to_str())
-            .unwrap_or("")
-            .to_lowercase();
-
-        if ext != "ldt" && ext != "ies" {
-            continue;
-        }
-
-        let content = fs::read_to_string(path)
-            .with_context(|| format!("Failed to read {}", path.display()))?;
-
-        batch_inputs.push((
-            path.to_owned(),
-            BatchInput {
-                name: path.file_name().unwrap().to_string_lossy().to_string(),
-                content,
-                format: None, // Auto-detect
-            },
-        ));
+    if lane_width <= 0.0 || spacing <= 0.0 || mounting_height <= 0.0 {
+        anyhow::bail!("Lane width, spacing and mounting height must be positive");
     }
-
-    if batch_inputs.is_empty() {
-        println!("No .ldt or .ies files found in {}", input_dir.display());
-        return Ok(());
+    if !(0.0..=1.0).contains(&maintenance_factor) || maintenance_factor == 0.0 {
+        anyhow::bail!("Maintenance factor must be in (0, 1]");
     }
+    let arrangement = match arrangement {
+        RoadArrangementArg::SingleSided => eulumdat::road::RoadArrangement::SingleSided,
+        RoadArrangementArg::Opposite => eulumdat::road::RoadArrangement::Opposite,
+        RoadArrangementArg::Staggered => eulumdat::road::RoadArrangement::Staggered,
+    };
+    let layout = RoadLayout {
+        lanes,
+        lane_width,
+        pole_spacing: spacing,
+        mounting_height,
+        overhang,
+        arrangement,
+        q0,
+    };
+
+    let ldt = load_file(file)?;
+    let grid = RoadGrid::compute(&ldt, &layout).maintained(maintenance_factor);
 
-    println!("Found {} files to convert", batch_inputs.len());
     println!(
-        "Converting to {} format...",
-        match format {
-            OutputFormat::Ldt => "LDT",
-            OutputFormat::Ies => "IES",
-        }
+        "Road:        {lanes} × {lane_width:.2} m lanes ({:.2} m), q0 {q0:.3}",
+        layout.road_width()
+    );
+    println!(
+        "Layout:      {arrangement:?}, spacing {spacing:.1} m, height {mounting_height:.1} m, overhang {overhang:.2} m"
+    );
+    println!(
+        "Grid:        {} × {} points, maintenance factor {maintenance_factor:.2}",
+        grid.x.len(),
+        grid.y.len()
     );
     println!();
+    println!("Lav:         {:.2} cd/m²", grid.average_luminance());
+    println!("Uo (L):      {:.2}", grid.luminance_uniformity());
+    println!("Ul:          {:.2}", grid.longitudinal_uniformity());
+    println!("Eav:         {:.1} lx", grid.average_illuminance());
+    println!("Emin:        {:.1} lx", grid.min_illuminance());
+    println!("Uo (E):      {:.2}", grid.illuminance_uniformity());
 
-    // Batch convert
-    let conversion_format = match format {
-        OutputFormat::Ldt => ConversionFormat::Ldt,
-        OutputFormat::Ies => ConversionFormat::Ies,
-    };
-
-    let inputs: Vec<_> = batch_inputs
-        .iter()
-        .map(|(_, input)| input.clone())
-        .collect();
-    let (outputs, stats) = batch::batch_convert_with_stats(&inputs, conversion_format);
-
-    // Write outputs
-    for (output, (original_path, _)) in outputs.iter().zip(batch_inputs.iter()) {
-        if let Some(error) = &output.error {
-            eprintln!("✗ {}: {}", output.input_name, error);
-            continue;
-        }
-
-        let content = output.content.as_ref().unwrap();
-        let mut out_path = output_dir.join(&output.output_name);
-
-        // Preserve subdirectory structure if recursive
-        if recursive {
-            if let Ok(rel_path) = original_path.strip_prefix(input_dir) {
-                if let Some(parent) = rel_path.parent() {
-                    let out_subdir = output_dir.join(parent);
-                    fs::create_dir_all(&out_subdir)?;
-                    out_path = out_subdir.join(&output.output_name);
-                }
-            }
-        }
-
-        if out_path.exists() && !overwrite {
-            eprintln!("✗ {}: Already exists (use --overwrite)", out_path.display());
-            continue;
+    println!();
+    for (series, first) in [
+        ("M", RoadClass::M1),
+        ("C", RoadClass::C0),
+        ("P", RoadClass::P1),
+    ] {
+        let achieved = grid
+            .achieved_class(first)
+            .map_or("none".to_string(), |c| c.to_string());
+        if first.is_approximate() {
+            println!("{series} classes:   {achieved} (approximate: L = q0 · E, TI not checked)");
+        } else {
+            println!("{series} classes:   {achieved}");
         }
-
-        fs::write(&out_path, content)
-            .with_context(|| format!("Failed to write {}", out_path.display()))?;
-
-        println!("✓ {} → {}", output.input_name, out_path.display());
     }
 
-    println!();
-    println!("=== Batch Conversion Summary ===");
-    println!("Total:      {}", stats.total);
-    println!("Successful: {}", stats.successful);
-    println!("Failed:     {}", stats.failed);
-
-    if stats.failed > 0 {
-        anyhow::bail!("{} file(s) failed to convert", stats.failed);
+    if let Some(path) = svg {
+        let theme = if dark {
+            SvgTheme::dark()
+        } else {
+            SvgTheme::light()
+        };
+        let (svg_width, svg_height) = (800.0, 400.0);
+        let content = RoadDiagram::from_grid(&grid, svg_width, svg_height, RoadQuantity::Luminance)
+            .to_svg(svg_width, svg_height, &theme);
+        fs::write(path, content).context("Failed to write SVG file")?;
+        println!();
+        println!("Luminance plan written to {}", path.display());
     }
 
-    Ok(())
-}
-
-pub fn summary(file: &PathBuf, format: SummaryFormat, output: Option<&PathBuf>) -> Result<()> {
-    let ldt = load_file(file)?;
-    let summary = PhotometricSummary::from_eulumdat(&ldt);
-
-    let content = match format {
-        SummaryFormat::Text => {
-            let mut s = format!("File: {}\n\n", file.display());
-            s.push_str(&summary.to_text());
-            s
-        }
-        SummaryFormat::Compact => summary.to_compact(),
-        SummaryFormat::Json => {
-            let kv = summary.to_key_value();
-            let mut json = String::from("{\n");
-            for (i, (key, value)) in kv.iter().enumerate() {
-                let comma = if i < kv.len() - 1 { "," } else { "" };
-                // Try to parse as number for proper JSON formatting
-                if let Ok(num) = value.parse::<f64>() {
-                    json.push_str(&format!("  \"{}\": {}{}\n", key, num, comma));
-                } else {
-                    json.push_str(&format!("  \"{}\": \"{}\"{}\n", key, value, comma));
-                }
-            }
-            json.push('}');
-            json
-        }
+    let Some(target) = target else {
+        return Ok(());
     };
-
-    if let Some(out_path) = output {
-        std::fs::write(out_path, &content).context("Failed to write output file")?;
-        println!("Summary written to: {}", out_path.display());
+    println!();
+    if target.is_approximate() {
+        // Luminance from the diffuse approximation, without TI and R_EI: an
+        // estimate that neither passes nor fails the command
+        println!("Target {target}, approximate (L = q0 · E; TI and R_EI not checked):");
     } else {
-        println!("{content}");
+        println!("Target {target}:");
+    }
+    for check in target.check(&grid) {
+        let verdict = match (target.is_approximate(), check.passed()) {
+            (false, true) => "PASS",
+            (false, false) => "FAIL",
+            (true, true) => "reached (approx.)",
+            (true, false) => "missed (approx.)",
+        };
+        println!(
+            "  {:<5} {:>8.2} {:<6} required ≥ {:.2}  {verdict}",
+            check.quantity, check.achieved, check.unit, check.required,
+        );
+    }
+    if target.is_approximate() {
+        println!("Not judged: M classes need r-table luminance and the threshold increment");
+    } else if !target.is_met_by(&grid) {
+        anyhow::bail!("Target class {target} not met");
     }
-
     Ok(())
 }
 
@@ -661,8 +2046,11 @@ pub fn gldf(file: &PathBuf, output: Option<&PathBuf>, pretty: bool) -> Result<()
     Ok(())
 }
 
-pub fn calc(file: &PathBuf, calc_type: CalcType) -> Result<()> {
+pub fn calc(file: &PathBuf, calc_type: CalcType, as_json: bool) -> Result<()> {
     let ldt = load_file(file)?;
+    if as_json {
+        return calc_json(file, &ldt, calc_type);
+    }
 
     println!("Photometric calculations for: {}", file.display());
     println!();
@@ -677,6 +2065,12 @@ pub fn calc(file: &PathBuf, calc_type: CalcType) -> Result<()> {
             println!("N4 (90-180°, ULOR):    {:.1}%", codes.n4);
             println!("N5 (90-120°):          {:.1}%", codes.n5);
             println!();
+            println!("FC1 (0-41.4° / down):  {:.1}%", codes.fc1);
+            println!("FC2 (0-60° / down):    {:.1}%", codes.fc2);
+            println!("FC3 (0-75.5° / down):  {:.1}%", codes.fc3);
+            println!("FC4 (downward):        {:.1}%", codes.fc4);
+            println!("FC5 (flux fraction):   {:.1}%", codes.fc5);
+            println!();
             println!("CIE Flux Code: {}", codes);
         }
         CalcType::BeamAngles => {
@@ -747,6 +2141,10 @@ pub fn calc(file: &PathBuf, calc_type: CalcType) -> Result<()> {
             let cu = PhotometricCalculations::cu_table(&ldt);
             println!("{}", cu.to_text());
         }
+        CalcType::CuTableRcr => {
+            let cu = PhotometricCalculations::cu_table_rcr(&ldt);
+            println!("{}", cu.to_text());
+        }
         CalcType::UgrTable => {
             let ugr = PhotometricCalculations::ugr_table(&ldt);
             println!("{}", ugr.to_text());
@@ -779,6 +2177,93 @@ pub fn calc(file: &PathBuf, calc_type: CalcType) -> Result<()> {
     Ok(())
 }
 
+/// `calc` with `--json`: the result of the calculation as a JSON document
+fn calc_json(file: &Path, ldt: &Eulumdat, calc_type: CalcType) -> Result<()> {
+    use clap::ValueEnum;
+
+    fn print<T: serde::Serialize>(file: &Path, calculation: &str, result: T) -> Result<()> {
+        json::print(
+            "calc",
+            file,
+            json::Calc {
+                calculation,
+                result,
+            },
+        )
+    }
+
+    let name = calc_type
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default();
+    match calc_type {
+        CalcType::CieCodes => print(file, &name, PhotometricCalculations::cie_flux_codes(ldt)),
+        CalcType::BeamAngles => print(
+            file,
+            &name,
+            json::BeamAngles {
+                beam_angle_deg: PhotometricCalculations::beam_angle(ldt),
+                field_angle_deg: PhotometricCalculations::field_angle(ldt),
+                cut_off_angle_deg: PhotometricCalculations::cut_off_angle(ldt),
+                beam_c0_deg: PhotometricCalculations::beam_angle_for_plane(ldt, 0.0),
+                beam_c90_deg: PhotometricCalculations::beam_angle_for_plane(ldt, 90.0),
+                field_c0_deg: PhotometricCalculations::field_angle_for_plane(ldt, 0.0),
+                field_c90_deg: PhotometricCalculations::field_angle_for_plane(ldt, 90.0),
+            },
+        ),
+        CalcType::Spacing => {
+            let (spacing_c0, spacing_c90) = PhotometricCalculations::spacing_criteria(ldt);
+            let (sc_0_180, sc_90_270, sc_diagonal) =
+                PhotometricCalculations::spacing_criteria_ies(ldt);
+            print(
+                file,
+                &name,
+                json::Spacing {
+                    spacing_c0,
+                    spacing_c90,
+                    sc_0_180,
+                    sc_90_270,
+                    sc_diagonal,
+                    photometric_code: PhotometricCalculations::photometric_code(ldt),
+                },
+            )
+        }
+        CalcType::ZonalLumens => {
+            let zones = PhotometricCalculations::zonal_lumens_30deg(ldt);
+            print(
+                file,
+                &name,
+                json::Zonal {
+                    downward_percent: zones.downward_total(),
+                    upward_percent: zones.upward_total(),
+                    zones_percent: zones,
+                    within_40_percent: PhotometricCalculations::downward_flux(ldt, 40.0),
+                    within_60_percent: PhotometricCalculations::downward_flux(ldt, 60.0),
+                    within_90_percent: PhotometricCalculations::downward_flux(ldt, 90.0),
+                },
+            )
+        }
+        CalcType::CuTable => print(file, &name, PhotometricCalculations::cu_table(ldt)),
+        CalcType::CuTableRcr => print(file, &name, PhotometricCalculations::cu_table_rcr(ldt)),
+        CalcType::UgrTable => print(file, &name, PhotometricCalculations::ugr_table(ldt)),
+        CalcType::CandelaTable => print(
+            file,
+            &name,
+            PhotometricCalculations::candela_tabulation(ldt),
+        ),
+        CalcType::Nema => print(
+            file,
+            &name,
+            PhotometricCalculations::nema_classification(ldt),
+        ),
+        CalcType::All => print(
+            file,
+            &name,
+            PhotometricSummary::from_eulumdat(ldt).to_record(),
+        ),
+    }
+}
+
 pub fn validate_atla(
     file: &PathBuf,
     schema: Option<&PathBuf>,
@@ -1118,6 +2603,7 @@ pub fn atla_convert(
 }
 
 /// Generate a photometric report
+#[allow(clippy::too_many_arguments)]
 pub fn report(
     input: &PathBuf,
     output: &PathBuf,
@@ -1126,10 +2612,26 @@ pub fn report(
     cu_table: bool,
     ugr_table: bool,
     candela_table: bool,
+    template: Option<&PathBuf>,
 ) -> Result<()> {
     use eulumdat_typst::{ReportGenerator, ReportOptions, ReportSection};
 
     let ldt = load_file(input)?;
+
+    let out_ext = output
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("pdf")
+        .to_lowercase();
+    if template.is_some() || matches!(out_ext.as_str(), "html" | "svg") {
+        if compact || cu_table || ugr_table || candela_table {
+            anyhow::bail!(
+                "--compact, --cu-table, --ugr-table and --candela-table apply to the full report (.typ or .pdf without --template)"
+            );
+        }
+        return datasheet(&ldt, output, &out_ext, paper, template);
+    }
+
     let generator = ReportGenerator::new(&ldt);
 
     let mut sections = if compact {
@@ -1162,12 +2664,6 @@ pub fn report(
         language: "en".to_string(),
     };
 
-    let out_ext = output
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("pdf")
-        .to_lowercase();
-
     match out_ext.as_str() {
         "typ" => {
             // Generate Typst source
@@ -1199,6 +2695,52 @@ pub fn report(
     Ok(())
 }
 
+/// One-page datasheet from the datasheet composer, styled by `template`
+fn datasheet(
+    ldt: &Eulumdat,
+    output: &PathBuf,
+    out_ext: &str,
+    paper: crate::cli::PaperSize,
+    template: Option<&PathBuf>,
+) -> Result<()> {
+    use eulumdat_typst::{generate_datasheet_typst, ReportGenerator};
+
+    let template = template
+        .map(|path| ReportTemplate::load(path))
+        .transpose()?
+        .unwrap_or_default();
+    // Page size in SVG units (96 per inch)
+    let (width, height, paper_size) = match paper {
+        crate::cli::PaperSize::A4 => (793.7, 1122.5, eulumdat_typst::PaperSize::A4),
+        crate::cli::PaperSize::Letter => (816.0, 1056.0, eulumdat_typst::PaperSize::Letter),
+        crate::cli::PaperSize::A3 => (1122.5, 1587.4, eulumdat_typst::PaperSize::A3),
+    };
+    let composer = template.composer(width, height)?;
+    let theme = template.theme()?;
+
+    match out_ext {
+        "html" => fs::write(output, composer.to_html(ldt, &theme)),
+        "svg" => fs::write(output, composer.to_svg(ldt, &theme)),
+        "typ" => fs::write(
+            output,
+            generate_datasheet_typst(&composer.to_svg(ldt, &theme), paper_size),
+        ),
+        "pdf" => {
+            let pdf =
+                ReportGenerator::generate_datasheet_pdf(&composer.to_svg(ldt, &theme), paper_size)
+                    .context("Failed to generate PDF")?;
+            fs::write(output, pdf)
+        }
+        _ => {
+            anyhow::bail!("Unknown output format: .{out_ext} (expected .html, .svg, .typ or .pdf)")
+        }
+    }
+    .context("Failed to write datasheet")?;
+
+    println!("Datasheet written to: {}", output.display());
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn compare(
     file_a: &PathBuf,
@@ -1209,6 +2751,7 @@ pub fn compare(
     dark: bool,
     significant_only: bool,
     units: eulumdat::UnitSystem,
+    fail_on: Option<FailOnArg>,
 ) -> Result<()> {
     let ldt_a = load_file(file_a)?;
     let ldt_b = load_file(file_b)?;
@@ -1226,6 +2769,8 @@ pub fn compare(
 
     let comparison =
         PhotometricComparison::from_eulumdat_with_units(&ldt_a, &ldt_b, &label_a, &label_b, units);
+    // Over the whole distribution rather than the summary metrics
+    let web_diff = PhotometricWeb::from(&ldt_a).compare(&PhotometricWeb::from(&ldt_b));
 
     // Print comparison table
     match format {
@@ -1265,6 +2810,14 @@ pub fn compare(
             } else {
                 print!("{}", comparison.to_text());
             }
+            println!(
+                "Intensity difference: RMS {:.2}%, max {:.2}% at C{:.1} G{:.1}; flux {:+.2}%",
+                web_diff.rms_relative * 100.0,
+                web_diff.max_relative * 100.0,
+                web_diff.max_at.0,
+                web_diff.max_at.1,
+                web_diff.flux_delta_relative() * 100.0,
+            );
         }
         CompareFormat::Json => {
             // Build a simple JSON output
@@ -1287,12 +2840,23 @@ pub fn compare(
                     m.name, m.key, m.unit, m.value_a, m.value_b, m.delta, m.delta_percent, m.significance, comma
                 );
             }
-            println!("  ]");
+            println!("  ],");
+            println!(
+                "  \"intensity_difference\": {{\"rms_relative\":{:.6},\"max_relative\":{:.6},\"max_c\":{:.1},\"max_g\":{:.1},\"flux_delta_relative\":{:.6}}}",
+                web_diff.rms_relative,
+                web_diff.max_relative,
+                web_diff.max_at.0,
+                web_diff.max_at.1,
+                web_diff.flux_delta_relative(),
+            );
             println!("}}");
         }
         CompareFormat::Csv => {
             print!("{}", comparison.to_csv());
         }
+        CompareFormat::Markdown => {
+            print!("{}", comparison.to_markdown());
+        }
     }
 
     // Generate overlay diagram if requested
@@ -1328,6 +2892,84 @@ pub fn compare(
         }
     }
 
+    let Some(fail_on) = fail_on else {
+        return Ok(());
+    };
+    let count = |levels: &[Significance]| {
+        comparison
+            .metrics
+            .iter()
+            .filter(|m| levels.contains(&m.significance))
+            .count()
+    };
+    exit::check(
+        fail_on,
+        count(&[Significance::Minor, Significance::Moderate]),
+        count(&[Significance::Major]),
+        ("minor or moderate difference", "major difference"),
+    )
+}
+
+/// Overview table of all .ldt/.ies files in a directory
+pub fn compare_catalog(
+    dir: &Path,
+    format: CompareFormat,
+    sort: OverviewColumn,
+    descending: bool,
+    recursive: bool,
+    output: Option<&Path>,
+) -> Result<()> {
+    let walker = if recursive {
+        walkdir::WalkDir::new(dir)
+    } else {
+        walkdir::WalkDir::new(dir).max_depth(1)
+    };
+
+    let mut rows = Vec::new();
+    for entry in walker.into_iter().filter_map(|e| e.ok()) {
+        let path = entry.path();
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        if !entry.file_type().is_file() || (ext != "ldt" && ext != "ies") {
+            continue;
+        }
+
+        match load_file(path) {
+            Ok(ldt) => {
+                let label = path.strip_prefix(dir).unwrap_or(path).display().to_string();
+                rows.push(OverviewRow::from_eulumdat(&ldt, &label));
+            }
+            Err(e) => eprintln!("Skipping {}: {e:#}", path.display()),
+        }
+    }
+    if rows.is_empty() {
+        anyhow::bail!("No .ldt or .ies files found in {}", dir.display());
+    }
+
+    let mut overview = CatalogOverview::new(rows);
+    overview.sort_by(sort, descending);
+
+    let table = match format {
+        CompareFormat::Text => overview.to_text(),
+        CompareFormat::Json => serde_json::to_string_pretty(&overview.rows)? + "\n",
+        CompareFormat::Csv => overview.to_csv(),
+        CompareFormat::Markdown => overview.to_markdown(),
+    };
+
+    if let Some(path) = output {
+        fs::write(path, &table).context("Failed to write output file")?;
+        eprintln!(
+            "Overview of {} files written to: {}",
+            overview.rows.len(),
+            path.display()
+        );
+    } else {
+        print!("{table}");
+    }
+
     Ok(())
 }
 
@@ -1471,3 +3113,22 @@ pub fn interpolate(
     );
     Ok(())
 }
+
+/// Print completions for `shell` to stdout
+pub fn completions(shell: clap_complete::Shell) -> Result<()> {
+    use clap::CommandFactory;
+
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, &mut std::io::stdout());
+    Ok(())
+}
+
+/// Print the man page to stdout
+pub fn man_page() -> Result<()> {
+    use clap::CommandFactory;
+
+    clap_mangen::Man::new(Cli::command())
+        .render(&mut std::io::stdout())
+        .context("Failed to write man page")
+}
@@ -0,0 +1,61 @@
+//! Exit codes of `--fail-on` for CI pipelines
+//!
+//! | Code | Meaning                                             |
+//! |------|-----------------------------------------------------|
+//! | 0    | Success, or findings below the threshold            |
+//! | 1    | The command failed (unreadable file, parse error)   |
+//! | 2    | Invalid command line                                |
+//! | 3    | Warnings with `--fail-on warning`                   |
+//! | 4    | Errors with `--fail-on warning` or `--fail-on error` |
+
+use crate::cli::FailOnArg;
+
+/// Exit code for warnings
+pub const WARNINGS: i32 = 3;
+
+/// Exit code for errors
+pub const ERRORS: i32 = 4;
+
+/// Findings that reached the `--fail-on` threshold
+///
+/// Returned as an error so commands keep their `Result<()>`; `main` turns it
+/// into the exit code instead of printing it as a failure.
+#[derive(Debug)]
+pub struct Findings {
+    pub code: i32,
+    pub message: String,
+}
+
+impl std::fmt::Display for Findings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Findings {}
+
+/// Fail with the exit code of the most severe finding at or above `fail_on`
+///
+/// `what` names the findings, e.g. `("warning", "error")` or
+/// `("skipped file", "failed file")`.
+pub fn check(
+    fail_on: FailOnArg,
+    warnings: usize,
+    errors: usize,
+    (warning, error): (&str, &str),
+) -> anyhow::Result<()> {
+    let plural =
+        |count: usize, noun: &str| format!("{count} {noun}{}", if count == 1 { "" } else { "s" });
+    let findings = match fail_on {
+        FailOnArg::Warning | FailOnArg::Error if errors > 0 => Findings {
+            code: ERRORS,
+            message: format!("Failed with {}", plural(errors, error)),
+        },
+        FailOnArg::Warning if warnings > 0 => Findings {
+            code: WARNINGS,
+            message: format!("Failed with {}", plural(warnings, warning)),
+        },
+        _ => return Ok(()),
+    };
+    Err(findings.into())
+}
@@ -0,0 +1,345 @@
+//! GLDF containers for `gldf pack` and `gldf unpack`
+//!
+//! A .gldf file is a ZIP archive with `product.xml` at the root and every
+//! local file of its `<Files>` list in the folder of its content type
+//! (`ldc/`, `image/`, `geo/`, `doc/`, ...). The product definition refers to
+//! these files by `fileId`, which is what the reference check verifies.
+
+use anyhow::{Context, Result};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::commands::{decode_text, parse_content};
+
+/// Name of the product definition at the archive root
+const PRODUCT_XML: &str = "product.xml";
+
+/// A `<File>` of the product definition
+struct FileEntry {
+    id: String,
+    content_type: String,
+    /// File name, or the URL for `type="url"`
+    name: String,
+    is_url: bool,
+}
+
+impl FileEntry {
+    /// Path inside the archive, `None` for URLs
+    fn archive_path(&self) -> Option<String> {
+        (!self.is_url).then(|| format!("{}/{}", folder(&self.content_type), self.name))
+    }
+}
+
+/// Archive folder of a GLDF content type, e.g. `ldc/eulumdat` → `ldc`
+fn folder(content_type: &str) -> &'static str {
+    match content_type.split('/').next().unwrap_or_default() {
+        "ldc" => "ldc",
+        "image" => "image",
+        "geo" => "geo",
+        "document" => "doc",
+        "spectrum" => "spectrum",
+        "sensor" => "sensor",
+        "symbol" => "symbol",
+        _ => "other",
+    }
+}
+
+/// Files and `fileId` references of a product definition
+struct ProductDefinition {
+    files: Vec<FileEntry>,
+    references: BTreeSet<String>,
+}
+
+impl ProductDefinition {
+    fn parse(xml: &str) -> Result<Self> {
+        let mut reader = Reader::from_str(xml);
+        let mut files = Vec::new();
+        let mut references = BTreeSet::new();
+        loop {
+            let event = reader.read_event().context("Invalid product XML")?;
+            let (element, has_text) = match &event {
+                Event::Start(e) => (e, true),
+                Event::Empty(e) => (e, false),
+                Event::Eof => break,
+                _ => continue,
+            };
+            let mut id = None;
+            let mut content_type = String::new();
+            let mut is_url = false;
+            for attr in element.attributes().flatten() {
+                let value = attr
+                    .unescape_value()
+                    .context("Invalid attribute in product XML")?;
+                match attr.key.as_ref() {
+                    b"fileId" => {
+                        references.insert(value.into_owned());
+                    }
+                    b"id" => id = Some(value.into_owned()),
+                    b"contentType" => content_type = value.into_owned(),
+                    b"type" => is_url = value == "url",
+                    _ => {}
+                }
+            }
+            if element.name().as_ref() == b"File" && has_text {
+                let end = element.to_end().into_owned();
+                let text = reader
+                    .read_text(end.name())
+                    .context("Invalid <File> in product XML")?;
+                let name = quick_xml::escape::unescape(&text)
+                    .context("Invalid <File> in product XML")?
+                    .trim()
+                    .to_string();
+                files.push(FileEntry {
+                    id: id.unwrap_or_default(),
+                    content_type,
+                    name,
+                    is_url,
+                });
+            }
+        }
+        Ok(Self { files, references })
+    }
+
+    /// Errors and warnings of the internal references; `exists` tells
+    /// whether a local file is available
+    fn check(&self, exists: impl Fn(&FileEntry) -> bool) -> Check {
+        let mut check = Check::default();
+        let mut ids = BTreeSet::new();
+        for file in &self.files {
+            if file.id.is_empty() {
+                check.errors.push(format!("<File> {} has no id", file.name));
+            } else if !ids.insert(file.id.as_str()) {
+                check.errors.push(format!("Duplicate file id {}", file.id));
+            }
+            if !file.is_url && !exists(file) {
+                check.errors.push(format!(
+                    "File {} ({}) is missing",
+                    file.name,
+                    file.archive_path().unwrap_or_default()
+                ));
+            }
+            if !self.references.contains(&file.id) {
+                check.warnings.push(format!(
+                    "File {} ({}) is never referenced",
+                    file.id, file.name
+                ));
+            }
+        }
+        for reference in &self.references {
+            if !ids.contains(reference.as_str()) {
+                check
+                    .errors
+                    .push(format!("fileId {reference} has no <File> definition"));
+            }
+        }
+        check
+    }
+}
+
+#[derive(Default)]
+struct Check {
+    errors: Vec<String>,
+    warnings: Vec<String>,
+}
+
+impl Check {
+    fn print(&self) {
+        for warning in &self.warnings {
+            println!("  warning: {warning}");
+        }
+        for error in &self.errors {
+            println!("  error:   {error}");
+        }
+    }
+}
+
+/// Pack `product` and the files it lists, found in `inputs`, into `output`
+pub fn pack(product: &Path, inputs: &[PathBuf], output: &Path, overwrite: bool) -> Result<()> {
+    if output.exists() && !overwrite {
+        anyhow::bail!("{} already exists (use --overwrite)", output.display());
+    }
+    let xml = fs::read_to_string(product)
+        .with_context(|| format!("Failed to read {}", product.display()))?;
+    let definition = ProductDefinition::parse(&xml)?;
+
+    // Without inputs, look next to the product definition
+    let default_input = [product
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."))
+        .to_path_buf()];
+    let inputs = if inputs.is_empty() {
+        &default_input[..]
+    } else {
+        inputs
+    };
+    let mut found: BTreeMap<String, Vec<PathBuf>> = BTreeMap::new();
+    for input in inputs {
+        if !input.exists() {
+            anyhow::bail!("Input not found: {}", input.display());
+        }
+        for entry in walkdir::WalkDir::new(input)
+            .into_iter()
+            .filter_map(|e| e.ok())
+        {
+            if entry.file_type().is_file() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                found.entry(name).or_default().push(entry.into_path());
+            }
+        }
+    }
+
+    let mut check = definition.check(|file| found.contains_key(&file.name));
+    for file in definition.files.iter().filter(|f| !f.is_url) {
+        match found.get(&file.name).map(Vec::as_slice) {
+            Some([_, _, ..]) => check.errors.push(format!(
+                "File {} found more than once in the inputs",
+                file.name
+            )),
+            Some([path]) => {
+                if let Some(error) = photometry_error(file, &fs::read(path)?) {
+                    check.errors.push(error);
+                }
+            }
+            _ => {}
+        }
+    }
+    println!("Checking {}", product.display());
+    check.print();
+    if !check.errors.is_empty() {
+        anyhow::bail!(
+            "{} has {} broken reference(s), nothing written",
+            product.display(),
+            check.errors.len()
+        );
+    }
+
+    let out = fs::File::create(output)
+        .with_context(|| format!("Failed to create {}", output.display()))?;
+    let mut zip = zip::ZipWriter::new(out);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+    zip.start_file(PRODUCT_XML, options)?;
+    zip.write_all(xml.as_bytes())?;
+    let mut written = BTreeSet::new();
+    for file in &definition.files {
+        let Some(archive_path) = file.archive_path() else {
+            continue;
+        };
+        // Several <File> entries may share one file
+        if !written.insert(archive_path.clone()) {
+            continue;
+        }
+        let content = fs::read(&found[&file.name][0])?;
+        zip.start_file(archive_path.as_str(), options)?;
+        zip.write_all(&content)?;
+        println!("  {archive_path}");
+    }
+    zip.finish().context("Failed to write GLDF archive")?;
+
+    println!(
+        "Packed {} file(s) → {}",
+        written.len() + 1,
+        output.display()
+    );
+    Ok(())
+}
+
+/// Extract `archive` into `output_dir` and check its references
+pub fn unpack(archive: &Path, output_dir: &Path, overwrite: bool) -> Result<()> {
+    let file =
+        fs::File::open(archive).with_context(|| format!("Failed to open {}", archive.display()))?;
+    let mut zip = zip::ZipArchive::new(file).context("Not a GLDF (ZIP) archive")?;
+
+    let mut xml = String::new();
+    zip.by_name(PRODUCT_XML)
+        .context("Archive has no product.xml")?
+        .read_to_string(&mut xml)
+        .context("Failed to read product.xml")?;
+    let definition = ProductDefinition::parse(&xml)?;
+
+    fs::create_dir_all(output_dir).context("Failed to create output directory")?;
+    let mut entries = BTreeSet::new();
+    let mut contents = BTreeMap::new();
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i)?;
+        if entry.is_dir() {
+            continue;
+        }
+        // Refuse paths escaping the output directory
+        let Some(relative) = entry.enclosed_name() else {
+            anyhow::bail!("Unsafe path in archive: {}", entry.name());
+        };
+        let target = output_dir.join(&relative);
+        if target.exists() && !overwrite {
+            anyhow::bail!("{} already exists (use --overwrite)", target.display());
+        }
+        let mut content = Vec::new();
+        entry.read_to_end(&mut content)?;
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&target, &content)
+            .with_context(|| format!("Failed to write {}", target.display()))?;
+        let name = entry.name().to_string();
+        println!("  {name}");
+        entries.insert(name.clone());
+        contents.insert(name, content);
+    }
+
+    let mut check = definition.check(|file| {
+        file.archive_path()
+            .is_some_and(|path| entries.contains(&path))
+    });
+    let listed: BTreeSet<String> = definition
+        .files
+        .iter()
+        .filter_map(FileEntry::archive_path)
+        .collect();
+    for entry in &entries {
+        if entry != PRODUCT_XML && !listed.contains(entry) && !entry.starts_with("meta-information")
+        {
+            check
+                .warnings
+                .push(format!("{entry} is not listed in product.xml"));
+        }
+    }
+    for file in &definition.files {
+        if let Some(content) = file.archive_path().and_then(|path| contents.get(&path)) {
+            if let Some(error) = photometry_error(file, content) {
+                check.errors.push(error);
+            }
+        }
+    }
+
+    println!(
+        "Unpacked {} file(s) → {}",
+        entries.len(),
+        output_dir.display()
+    );
+    check.print();
+    if !check.errors.is_empty() {
+        anyhow::bail!(
+            "{} has {} broken reference(s)",
+            archive.display(),
+            check.errors.len()
+        );
+    }
+    Ok(())
+}
+
+/// Parse error of an LDT or IES file of the container
+fn photometry_error(file: &FileEntry, content: &[u8]) -> Option<String> {
+    let format = match file.content_type.as_str() {
+        "ldc/eulumdat" => "ldt",
+        "ldc/ies" => "ies",
+        _ => return None,
+    };
+    parse_content(&decode_text(content.to_vec()), format, 0.0)
+        .err()
+        .map(|e| format!("File {} does not parse: {e:#}", file.name))
+}
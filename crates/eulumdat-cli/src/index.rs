@@ -0,0 +1,308 @@
+//! Catalog index of the `index` command
+//!
+//! The index is a JSON file with one entry per photometric file. Entries
+//! remember the modification time and size of their file, so a later run
+//! only parses new and changed files. The index is saved every
+//! [`CHECKPOINT_INTERVAL`] files, which lets an interrupted run of a large
+//! library resume where it stopped.
+//!
+//! A PNG polar thumbnail of every file is kept in a directory next to the
+//! index (`catalog.thumbs/` for `catalog.json`), for library views such as
+//! the one of the desktop app.
+
+use anyhow::{Context, Result};
+use eulumdat::diagram::{svg_to_png, PolarDiagram, SvgTheme};
+use eulumdat::{Eulumdat, PhotometricCalculations};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::commands::load_file;
+
+/// Current value of `schema_version`; bumped on incompatible changes
+const SCHEMA_VERSION: u32 = 1;
+
+/// Files parsed between two saves of the index
+const CHECKPOINT_INTERVAL: usize = 200;
+
+#[derive(Serialize, Deserialize)]
+struct CatalogIndex {
+    schema_version: u32,
+    /// Absolute path of the indexed directory
+    root: String,
+    /// Seconds since the Unix epoch
+    updated: u64,
+    entries: Vec<IndexEntry>,
+}
+
+/// One photometric file; all metrics are zero if it failed to parse
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct IndexEntry {
+    /// Path relative to the root, with `/` separators
+    path: String,
+    /// Modification time in seconds since the Unix epoch
+    modified: u64,
+    /// File size in bytes
+    size: u64,
+    luminaire_name: String,
+    luminaire_number: String,
+    manufacturer: String,
+    /// Lamp flux (lm)
+    lamp_flux_lm: f64,
+    /// Luminaire flux: lamp flux × LOR (lm)
+    flux_lm: f64,
+    wattage_w: f64,
+    efficacy_lm_w: f64,
+    /// Beam angle, IES definition (degrees)
+    beam_angle_deg: f64,
+    /// Field angle, IES definition (degrees)
+    field_angle_deg: f64,
+    /// PNG polar thumbnail, relative to the directory of the index file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thumbnail: Option<String>,
+    /// Parse error, if the file could not be read
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Thumbnail directory of an index and the edge length of its PNGs
+struct Thumbnails {
+    /// Directory of the index file, which thumbnail paths are relative to
+    base: PathBuf,
+    /// Name of the thumbnail directory inside `base`
+    dir_name: String,
+    size: u32,
+}
+
+impl Thumbnails {
+    fn new(output: &Path, size: u32) -> Self {
+        let stem = output
+            .file_stem()
+            .map_or("catalog".into(), |stem| stem.to_string_lossy());
+        Self {
+            base: output.parent().unwrap_or(Path::new("")).to_path_buf(),
+            dir_name: format!("{stem}.thumbs"),
+            size,
+        }
+    }
+
+    /// Render the thumbnail of the file at `relative` and return its path
+    fn render(&self, ldt: &Eulumdat, relative: &str) -> Result<String> {
+        // Keep the extension, so luminaire.ldt and luminaire.ies get their own thumbnail
+        let thumbnail = format!("{}/{relative}.png", self.dir_name);
+        let path = self.base.join(&thumbnail);
+        let svg = PolarDiagram::render_thumbnail_svg(ldt, self.size as f64, &SvgTheme::light());
+        let png = svg_to_png(&svg, 1.0)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).context("Failed to create thumbnail directory")?;
+        }
+        fs::write(&path, png).with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(thumbnail)
+    }
+
+    /// Whether `entry` needs no new thumbnail
+    fn is_current(&self, entry: &IndexEntry) -> bool {
+        entry.error.is_some()
+            || entry
+                .thumbnail
+                .as_ref()
+                .is_some_and(|thumbnail| self.base.join(thumbnail).is_file())
+    }
+
+    fn remove(&self, entry: &IndexEntry) {
+        if let Some(thumbnail) = &entry.thumbnail {
+            let _ = fs::remove_file(self.base.join(thumbnail));
+        }
+    }
+}
+
+impl IndexEntry {
+    fn parse(
+        path: &Path,
+        relative: String,
+        modified: u64,
+        size: u64,
+        thumbnails: Option<&Thumbnails>,
+    ) -> Self {
+        let mut entry = Self {
+            path: relative,
+            modified,
+            size,
+            ..Default::default()
+        };
+        match load_file(path) {
+            Ok(ldt) => {
+                let lamp_flux = ldt.total_luminous_flux();
+                entry.luminaire_name = ldt.luminaire_name.trim().to_string();
+                entry.luminaire_number = ldt.luminaire_number.trim().to_string();
+                entry.manufacturer = ldt.identification.trim().to_string();
+                entry.lamp_flux_lm = lamp_flux;
+                entry.flux_lm = lamp_flux * ldt.light_output_ratio / 100.0;
+                entry.wattage_w = ldt.total_wattage();
+                entry.efficacy_lm_w = ldt.luminous_efficacy();
+                entry.beam_angle_deg = PhotometricCalculations::beam_angle(&ldt);
+                entry.field_angle_deg = PhotometricCalculations::field_angle(&ldt);
+                if let Some(thumbnails) = thumbnails {
+                    match thumbnails.render(&ldt, &entry.path) {
+                        Ok(thumbnail) => entry.thumbnail = Some(thumbnail),
+                        Err(e) => eprintln!("No thumbnail for {}: {e:#}", entry.path),
+                    }
+                }
+            }
+            Err(e) => entry.error = Some(format!("{e:#}")),
+        }
+        entry
+    }
+}
+
+/// Index the .ldt and .ies files below `dir` into `output`.
+///
+/// Entries of an existing index for the same directory are kept for files
+/// whose modification time and size are unchanged, unless `rebuild` is set.
+/// Thumbnails of `thumbnail_size` pixels are rendered unless it is `None`.
+pub fn index(dir: &Path, output: &Path, rebuild: bool, thumbnail_size: Option<u32>) -> Result<()> {
+    if thumbnail_size.is_some_and(|size| !(16..=2048).contains(&size)) {
+        anyhow::bail!("Thumbnail size must be between 16 and 2048 pixels");
+    }
+    let thumbnails = thumbnail_size.map(|size| Thumbnails::new(output, size));
+    let root = fs::canonicalize(dir)
+        .with_context(|| format!("Failed to read directory {}", dir.display()))?;
+    let root_name = root.display().to_string();
+
+    let mut previous: HashMap<String, IndexEntry> = HashMap::new();
+    if !rebuild && output.exists() {
+        match load(output) {
+            Ok(index) if index.root == root_name => {
+                previous = index
+                    .entries
+                    .into_iter()
+                    .map(|entry| (entry.path.clone(), entry))
+                    .collect();
+            }
+            Ok(index) => eprintln!(
+                "{} indexes {}, rebuilding for {root_name}",
+                output.display(),
+                index.root
+            ),
+            Err(e) => eprintln!("Rebuilding {}: {e:#}", output.display()),
+        }
+    }
+
+    let mut index = CatalogIndex {
+        schema_version: SCHEMA_VERSION,
+        root: root_name,
+        updated: 0,
+        entries: Vec::new(),
+    };
+    let (mut added, mut changed, mut unchanged) = (0, 0, 0);
+    let mut parsed_since_save = 0;
+
+    for entry in walkdir::WalkDir::new(&root)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|e| e.ok())
+    {
+        let path = entry.path();
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_lowercase();
+        if !entry.file_type().is_file() || (ext != "ldt" && ext != "ies") {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |duration| duration.as_secs());
+        let size = metadata.len();
+        let relative = path
+            .strip_prefix(&root)
+            .unwrap_or(path)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        match previous.remove(&relative) {
+            Some(old)
+                if old.modified == modified
+                    && old.size == size
+                    && thumbnails.as_ref().is_none_or(|t| t.is_current(&old)) =>
+            {
+                unchanged += 1;
+                index.entries.push(old);
+                continue;
+            }
+            Some(_) => changed += 1,
+            None => added += 1,
+        }
+        index.entries.push(IndexEntry::parse(
+            path,
+            relative,
+            modified,
+            size,
+            thumbnails.as_ref(),
+        ));
+
+        parsed_since_save += 1;
+        if parsed_since_save == CHECKPOINT_INTERVAL {
+            // Files not reached yet keep their old entries until the next save
+            save(&mut index, previous.values(), output)?;
+            parsed_since_save = 0;
+        }
+    }
+
+    let removed = previous.len();
+    save(&mut index, [].iter(), output)?;
+    if let Some(thumbnails) = &thumbnails {
+        previous.values().for_each(|entry| thumbnails.remove(entry));
+    }
+
+    let failed = index.entries.iter().filter(|e| e.error.is_some()).count();
+    println!(
+        "Indexed {} files into {}: {added} new, {changed} changed, {unchanged} unchanged, {removed} removed",
+        index.entries.len(),
+        output.display()
+    );
+    if failed > 0 {
+        println!("{failed} file(s) could not be parsed; see the error field of their entries");
+    }
+    Ok(())
+}
+
+fn load(path: &Path) -> Result<CatalogIndex> {
+    let json = fs::read_to_string(path).context("Failed to read index")?;
+    let index: CatalogIndex = serde_json::from_str(&json).context("Invalid index file")?;
+    if index.schema_version != SCHEMA_VERSION {
+        anyhow::bail!("Unsupported index schema version {}", index.schema_version);
+    }
+    Ok(index)
+}
+
+/// Write `index` plus the `pending` entries of the previous run, through a
+/// temporary file so an interruption never leaves a truncated index
+fn save<'a>(
+    index: &mut CatalogIndex,
+    pending: impl Iterator<Item = &'a IndexEntry>,
+    path: &Path,
+) -> Result<()> {
+    index.updated = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let done = index.entries.len();
+    index.entries.extend(pending.cloned());
+    let json = serde_json::to_string_pretty(&*index).context("Failed to serialize index");
+    index.entries.truncate(done);
+
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, json? + "\n").context("Failed to write index")?;
+    fs::rename(&tmp, path).context("Failed to write index")
+}
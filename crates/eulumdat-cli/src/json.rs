@@ -0,0 +1,158 @@
+//! Structured output of the global `--json` flag
+//!
+//! Every document is one JSON object with `schema_version`, `command` and
+//! `file`, followed by the fields of the command. The layout is described by
+//! `schema/cli-output.schema.json`; units are part of the field names.
+
+use anyhow::{Context, Result};
+use eulumdat::{
+    BugRating, Eulumdat, LampSet, Symmetry, TypeIndicator, ValidationError, ValidationWarning,
+    ZonalLumens30, ZoneLumens,
+};
+use serde::Serialize;
+use std::path::Path;
+
+/// Current value of `schema_version`; bumped on incompatible changes
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct Document<'a, T: Serialize> {
+    schema_version: u32,
+    command: &'a str,
+    file: String,
+    #[serde(flatten)]
+    data: T,
+}
+
+/// Print the output of `command` for `file` as pretty JSON on stdout
+pub fn print<T: Serialize>(command: &str, file: &Path, data: T) -> Result<()> {
+    let document = Document {
+        schema_version: SCHEMA_VERSION,
+        command,
+        file: file.display().to_string(),
+        data,
+    };
+    let json = serde_json::to_string_pretty(&document).context("Failed to serialize JSON")?;
+    println!("{json}");
+    Ok(())
+}
+
+/// `info`: header, lamp data and calculated values
+#[derive(Serialize)]
+pub struct Info<'a> {
+    pub luminaire_name: &'a str,
+    pub luminaire_number: &'a str,
+    pub manufacturer: &'a str,
+    pub date: &'a str,
+    pub length_mm: f64,
+    pub width_mm: f64,
+    pub height_mm: f64,
+    pub type_indicator: TypeIndicator,
+    pub symmetry: Symmetry,
+    pub c_plane_count: usize,
+    pub c_plane_spacing_deg: f64,
+    pub g_angle_count: usize,
+    pub g_angle_spacing_deg: f64,
+    pub lamp_sets: &'a [LampSet],
+    pub total_flux_lm: f64,
+    pub total_wattage_w: f64,
+    pub efficacy_lm_w: f64,
+    pub max_intensity_cd_klm: f64,
+    pub dff_percent: f64,
+    pub lor_percent: f64,
+    /// Angles and intensities, with `--verbose` only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub c_angles: Option<&'a [f64]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub g_angles: Option<&'a [f64]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intensities_cd_klm: Option<&'a [Vec<f64>]>,
+}
+
+impl<'a> Info<'a> {
+    pub fn new(ldt: &'a Eulumdat, verbose: bool) -> Self {
+        Self {
+            luminaire_name: &ldt.luminaire_name,
+            luminaire_number: &ldt.luminaire_number,
+            manufacturer: &ldt.identification,
+            date: &ldt.date_user,
+            length_mm: ldt.length,
+            width_mm: ldt.width,
+            height_mm: ldt.height,
+            type_indicator: ldt.type_indicator,
+            symmetry: ldt.symmetry,
+            c_plane_count: ldt.c_angles.len(),
+            c_plane_spacing_deg: ldt.c_plane_distance,
+            g_angle_count: ldt.g_angles.len(),
+            g_angle_spacing_deg: ldt.g_plane_distance,
+            lamp_sets: &ldt.lamp_sets,
+            total_flux_lm: ldt.total_luminous_flux(),
+            total_wattage_w: ldt.total_wattage(),
+            efficacy_lm_w: ldt.luminous_efficacy(),
+            max_intensity_cd_klm: ldt.max_intensity(),
+            dff_percent: ldt.downward_flux_fraction,
+            lor_percent: ldt.light_output_ratio,
+            c_angles: verbose.then_some(ldt.c_angles.as_slice()),
+            g_angles: verbose.then_some(ldt.g_angles.as_slice()),
+            intensities_cd_klm: verbose.then_some(ldt.intensities.as_slice()),
+        }
+    }
+}
+
+/// `validate`: warnings, and with `--strict` the errors
+#[derive(Serialize)]
+pub struct Validation {
+    pub valid: bool,
+    pub warnings: Vec<ValidationWarning>,
+    pub errors: Vec<ValidationError>,
+}
+
+/// `bug`: BUG rating and zone lumens
+#[derive(Serialize)]
+pub struct Bug {
+    pub rating: BugRating,
+    /// Rating as text, e.g. "B1 U0 G1"
+    pub rating_text: String,
+    pub zones_lm: ZoneLumens,
+}
+
+/// `calc`: the calculation name and its result
+#[derive(Serialize)]
+pub struct Calc<'a, T: Serialize> {
+    pub calculation: &'a str,
+    pub result: T,
+}
+
+/// Result of `calc -t beam-angles`
+#[derive(Serialize)]
+pub struct BeamAngles {
+    pub beam_angle_deg: f64,
+    pub field_angle_deg: f64,
+    pub cut_off_angle_deg: f64,
+    pub beam_c0_deg: f64,
+    pub beam_c90_deg: f64,
+    pub field_c0_deg: f64,
+    pub field_c90_deg: f64,
+}
+
+/// Result of `calc -t spacing`
+#[derive(Serialize)]
+pub struct Spacing {
+    pub spacing_c0: f64,
+    pub spacing_c90: f64,
+    pub sc_0_180: f64,
+    pub sc_90_270: f64,
+    pub sc_diagonal: f64,
+    pub photometric_code: String,
+}
+
+/// Result of `calc -t zonal-lumens`
+#[derive(Serialize)]
+pub struct Zonal {
+    pub zones_percent: ZonalLumens30,
+    pub downward_percent: f64,
+    pub upward_percent: f64,
+    pub within_40_percent: f64,
+    pub within_60_percent: f64,
+    pub within_90_percent: f64,
+}
@@ -0,0 +1,211 @@
+//! Loss report of the `convert` command
+//!
+//! Lists the fields a conversion cannot carry over: ATLA data without an LDT
+//! equivalent (spectra, further emitters, equipment, ...) and LDT data that
+//! IES has no place for (several lamp sets, color, luminous area, ...).
+//! Values recomputed on import, like direct ratios, are not reported.
+
+use anyhow::Result;
+use atla::LuminaireOpticalData;
+use eulumdat::Eulumdat;
+
+use crate::commands::{parse_atla_content, parse_content};
+
+/// A field, or group of fields, dropped or merged by a conversion
+pub struct Loss {
+    /// Field path in the source, e.g. `emitters[0].spectral_distribution`
+    pub field: String,
+    /// What happens to it
+    pub detail: String,
+}
+
+impl Loss {
+    fn new(field: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Losses of converting `source` from `in_ext` to `out_ext`
+pub fn losses(source: &str, in_ext: &str, out_ext: &str) -> Result<Vec<Loss>> {
+    let losses = match (in_ext, out_ext) {
+        ("xml" | "json", "ldt") => atla_to_eulumdat(&parse_atla_content(source, in_ext)?),
+        ("xml" | "json", "ies") => {
+            let doc = parse_atla_content(source, in_ext)?;
+            let mut losses = atla_to_eulumdat(&doc);
+            losses.extend(eulumdat_to_ies(&doc.to_eulumdat()));
+            losses
+        }
+        ("ldt", "ies") => eulumdat_to_ies(&parse_content(source, in_ext, 0.0)?),
+        ("ldt" | "ies", "xml" | "json") => eulumdat_to_atla(&parse_content(source, in_ext, 0.0)?),
+        _ => Vec::new(),
+    };
+    Ok(losses)
+}
+
+/// Format `losses` as the report printed at the end of a conversion
+pub fn report(losses: &[Loss]) -> String {
+    let width = losses.iter().map(|l| l.field.len()).max().unwrap_or(0);
+    let mut out = format!(
+        "Lossy conversion ({} field{}):",
+        losses.len(),
+        if losses.len() == 1 { "" } else { "s" }
+    );
+    for loss in losses {
+        out.push_str(&format!("\n  {:<width$}  {}", loss.field, loss.detail));
+    }
+    out
+}
+
+fn atla_to_eulumdat(doc: &LuminaireOpticalData) -> Vec<Loss> {
+    let mut losses = Vec::new();
+    let header = &doc.header;
+    for (field, present) in [
+        (
+            "header.gtin",
+            header.gtin.is_some() || header.gtin_int.is_some(),
+        ),
+        ("header.uuid", header.uuid.is_some()),
+        (
+            "header.references",
+            header.reference.is_some() || !header.references.is_empty(),
+        ),
+        ("header.more_info_uri", header.more_info_uri.is_some()),
+        // Written from the manufacturer by the LDT to ATLA conversion
+        (
+            "header.laboratory",
+            header.laboratory.is_some() && header.laboratory != header.manufacturer,
+        ),
+    ] {
+        if present {
+            losses.push(Loss::new(field, "dropped, no LDT field"));
+        }
+    }
+
+    if let Some(luminaire) = &doc.luminaire {
+        if luminaire.luminous_openings.len() > 1 {
+            losses.push(Loss::new(
+                "luminaire.luminous_openings",
+                format!(
+                    "{} openings, only the first becomes the luminous area",
+                    luminaire.luminous_openings.len()
+                ),
+            ));
+        }
+        if luminaire.mounting.is_some() {
+            losses.push(Loss::new("luminaire.mounting", "dropped, no LDT field"));
+        }
+    }
+    if doc.equipment.is_some() {
+        losses.push(Loss::new("equipment", "dropped, no LDT field"));
+    }
+
+    let mut distributions = 0;
+    for (i, emitter) in doc.emitters.iter().enumerate() {
+        let field = |name: &str| format!("emitters[{i}].{name}");
+        if emitter.intensity_distribution.is_some() {
+            distributions += 1;
+            if distributions > 1 {
+                losses.push(Loss::new(
+                    field("intensity_distribution"),
+                    "dropped, LDT keeps the first distribution only",
+                ));
+            }
+        }
+        if let Some(spd) = &emitter.spectral_distribution {
+            losses.push(Loss::new(
+                field("spectral_distribution"),
+                format!("{} spectral values dropped", spd.values.len()),
+            ));
+        }
+        if emitter.angular_spectral.is_some() {
+            losses.push(Loss::new(
+                field("angular_spectral"),
+                "dropped, LDT has no spectral data",
+            ));
+        }
+        if let Some(cr) = &emitter.color_rendering {
+            if cr.r9.is_some() || cr.rf.is_some() || cr.rg.is_some() {
+                losses.push(Loss::new(
+                    field("color_rendering"),
+                    "only Ra kept, as a color rendering group",
+                ));
+            }
+        }
+        for (name, present) in [
+            ("catalog_number", emitter.catalog_number.is_some()),
+            ("power_factor", emitter.power_factor.is_some()),
+            ("ballast_factor", emitter.ballast_factor.is_some()),
+            ("duv", emitter.duv.is_some()),
+            ("sp_ratio", emitter.sp_ratio.is_some()),
+        ] {
+            if present {
+                losses.push(Loss::new(field(name), "dropped, no LDT field"));
+            }
+        }
+    }
+
+    if doc.custom_data.is_some() || !doc.custom_data_items.is_empty() {
+        losses.push(Loss::new("custom_data", "dropped, no LDT field"));
+    }
+    losses
+}
+
+fn eulumdat_to_ies(ldt: &Eulumdat) -> Vec<Loss> {
+    let mut losses = Vec::new();
+    if ldt.lamp_sets.len() > 1 {
+        losses.push(Loss::new(
+            "lamp_sets",
+            format!(
+                "{} lamp sets merged into one, [LAMP] names the first",
+                ldt.lamp_sets.len()
+            ),
+        ));
+    }
+    for (i, lamp_set) in ldt.lamp_sets.iter().enumerate() {
+        if !lamp_set.color_appearance.is_empty() {
+            losses.push(Loss::new(
+                format!("lamp_sets[{i}].color_appearance"),
+                "dropped, no IES field",
+            ));
+        }
+        if !lamp_set.color_rendering_group.is_empty() {
+            losses.push(Loss::new(
+                format!("lamp_sets[{i}].color_rendering_group"),
+                "dropped, no IES field",
+            ));
+        }
+    }
+    if ldt.luminous_area_length > 0.0 || ldt.luminous_area_width > 0.0 {
+        losses.push(Loss::new(
+            "luminous_area",
+            "dropped, IES dimensions are the luminaire's",
+        ));
+    }
+    if ldt.tilt_angle != 0.0 {
+        losses.push(Loss::new(
+            "tilt_angle",
+            format!("{}° dropped, no IES field", ldt.tilt_angle),
+        ));
+    }
+    losses
+}
+
+fn eulumdat_to_atla(ldt: &Eulumdat) -> Vec<Loss> {
+    let mut losses = Vec::new();
+    if ldt.light_output_ratio > 0.0 && (ldt.light_output_ratio - 100.0).abs() > 0.05 {
+        losses.push(Loss::new(
+            "light_output_ratio",
+            format!("{:.1}% dropped, reads back as 100%", ldt.light_output_ratio),
+        ));
+    }
+    if ldt.tilt_angle != 0.0 {
+        losses.push(Loss::new(
+            "tilt_angle",
+            format!("{}° dropped, no ATLA field", ldt.tilt_angle),
+        ));
+    }
+    losses
+}
@@ -4,76 +4,423 @@
 
 mod cli;
 mod commands;
+mod exit;
+mod gldf;
+mod index;
+mod json;
+mod loss;
+mod mesh;
+mod progress;
+mod scaffold;
+mod serve;
+mod spectrum;
+mod stats;
+mod template;
+mod tui;
+mod watch;
 
 use anyhow::Result;
-use clap::Parser;
+use clap::{CommandFactory, Parser};
 
-use cli::{Cli, Commands};
+use cli::{Cli, Commands, CompareFormat, GldfCommands, SummaryFormat, UgrFormat};
 
 fn main() -> Result<()> {
+    // Findings of --fail-on have exit codes of their own
+    run().or_else(|e| match e.downcast::<exit::Findings>() {
+        Ok(findings) => {
+            eprintln!("{findings}");
+            std::process::exit(findings.code)
+        }
+        Err(e) => Err(e),
+    })
+}
+
+fn run() -> Result<()> {
     let cli = Cli::parse();
+    if cli.man {
+        return commands::man_page();
+    }
+    // Only global flags, e.g. `eulumdat --json`
+    let Some(mut command) = cli.command else {
+        Cli::command().print_help()?;
+        return Ok(());
+    };
+
+    let json = cli.json;
+    if json
+        && !matches!(
+            command,
+            Commands::Info { .. }
+                | Commands::Validate { .. }
+                | Commands::Calc { .. }
+                | Commands::Bug { .. }
+                | Commands::Summary { .. }
+                | Commands::Ugr { .. }
+                | Commands::Compare { .. }
+                | Commands::Gldf {
+                    container: None,
+                    ..
+                }
+                | Commands::Stats { .. }
+        )
+    {
+        anyhow::bail!("--json is not supported by this command");
+    }
+
+    // Commands with a JSON format of their own
+    match &mut command {
+        Commands::Summary { format, .. } if json => *format = SummaryFormat::Json,
+        Commands::Ugr { format, .. } if json => *format = UgrFormat::Json,
+        Commands::Compare { format, .. } if json => *format = CompareFormat::Json,
+        _ => {}
+    }
 
-    match cli.command {
+    match command {
         Commands::Info {
             file,
             verbose,
             units,
-        } => commands::info(&file, verbose, units.to_unit_system()),
-        Commands::Validate { file, strict } => commands::validate(&file, strict),
+        } => commands::info(&file, verbose, units.to_unit_system(), json),
+        Commands::Validate {
+            file,
+            strict,
+            fail_on,
+        } => commands::validate(&file, strict, fail_on, json),
         Commands::Convert {
             input,
             output,
+            output_file,
+            from,
+            to,
             compact,
             rotate,
-        } => commands::convert(&input, &output, compact, rotate),
-        Commands::Diagram {
+            policy,
+            watch,
+        } => {
+            // clap requires one of the two
+            let output = output.or(output_file).unwrap_or_default();
+            let convert = || commands::convert(&input, &output, from, to, compact, rotate, policy);
+            if watch {
+                watch::watch(&input, convert)
+            } else {
+                convert()
+            }
+        }
+        Commands::Rotate {
+            file,
+            output,
+            degrees,
+        } => commands::rotate(&file, output.as_ref(), degrees),
+        Commands::Mirror {
+            file,
+            output,
+            plane,
+        } => commands::mirror(&file, output.as_ref(), plane),
+        Commands::Scale {
+            file,
+            output,
+            factor,
+            target_flux,
+        } => commands::scale(&file, output.as_ref(), factor, target_flux),
+        Commands::Resample {
             input,
+            c_step,
+            g_step,
             output,
-            diagram_type,
-            dark,
+        } => commands::resample(&input, c_step, g_step, output.as_ref()),
+        Commands::Fix {
+            file,
+            output,
+            dry_run,
+            annotate,
+        } => commands::fix(&file, output.as_deref(), dry_run, annotate),
+        Commands::New {
+            template,
+            output,
+            name,
+            flux,
+            wattage,
+            beam_angle,
+            length,
             width,
             height,
-            mounting_height,
-            tilt,
-            log_scale,
-            units,
-        } => commands::diagram(
-            &input,
+            force,
+        } => scaffold::new_file(
+            template,
+            &output,
+            scaffold::Overrides {
+                name,
+                flux,
+                wattage,
+                beam_angle,
+                length,
+                width,
+                height,
+            },
+            force,
+        ),
+        Commands::Anonymize {
+            file,
+            output,
+            name,
+            lamp_types,
+        } => commands::anonymize(&file, &output, &name, lamp_types),
+        Commands::Set {
+            file,
+            output,
+            luminaire_name,
+            luminaire_number,
+            manufacturer,
+            report_number,
+            date_user,
+            flux,
+            wattage,
+            lamp_type,
+        } => commands::set(
+            &file,
             output.as_ref(),
+            luminaire_name,
+            luminaire_number,
+            manufacturer,
+            report_number,
+            date_user,
+            flux,
+            wattage,
+            lamp_type,
+        ),
+        Commands::Diagram {
+            input,
+            from,
+            output,
             diagram_type,
             dark,
+            theme,
             width,
             height,
             mounting_height,
+            heights,
             tilt,
+            throw,
             log_scale,
-            units.to_unit_system(),
+            colormap,
+            radial_scale,
+            batwing,
+            units,
+            isolux_style,
+            area,
+            patterns,
+            watch,
+        } => {
+            let diagram = || {
+                commands::diagram(
+                    &input,
+                    from,
+                    output.as_ref(),
+                    diagram_type,
+                    dark,
+                    theme.as_ref(),
+                    width,
+                    height,
+                    mounting_height,
+                    &heights,
+                    tilt,
+                    throw,
+                    log_scale,
+                    colormap.to_color_map(),
+                    radial_scale.to_radial_scale(),
+                    batwing,
+                    units.to_unit_system(),
+                    isolux_style,
+                    area,
+                    patterns,
+                )
+            };
+            if watch {
+                watch::watch(&input, diagram)
+            } else {
+                diagram()
+            }
+        }
+        Commands::Bug { file, svg, dark } => commands::bug(&file, svg.as_ref(), dark, json),
+        Commands::Thumbnail {
+            input_dir,
+            output_dir,
+            size,
+            recursive,
+            overwrite,
+            dark,
+            jobs,
+        } => commands::thumbnail(
+            &input_dir,
+            output_dir.as_deref(),
+            size,
+            recursive,
+            overwrite,
+            dark,
+            jobs,
         ),
-        Commands::Bug { file, svg, dark } => commands::bug(&file, svg.as_ref(), dark),
+        Commands::Stats {
+            input_dir,
+            recursive,
+        } => stats::stats(&input_dir, recursive, json),
         Commands::Batch {
             input_dir,
             output_dir,
             format,
             recursive,
             overwrite,
+            jobs,
+            fail_on,
         } => commands::batch(
             &input_dir,
             output_dir.as_ref(),
             format,
             recursive,
             overwrite,
+            jobs,
+            fail_on,
         ),
         Commands::Summary {
             file,
             format,
             output,
         } => commands::summary(&file, format, output.as_ref()),
+        Commands::Ugr {
+            file,
+            format,
+            output,
+        } => commands::ugr(&file, format, output.as_ref()),
+        Commands::Uf {
+            file,
+            format,
+            output,
+            room_indices,
+            reflectances,
+            cu,
+        } => commands::uf(
+            &file,
+            format,
+            output.as_ref(),
+            &room_indices,
+            &reflectances,
+            cu,
+        ),
+        Commands::Illuminance {
+            file,
+            width,
+            depth,
+            mounting_height,
+            workplane,
+            rows,
+            cols,
+            spacing,
+            grid,
+            maintenance_factor,
+            svg,
+            dark,
+        } => commands::illuminance(
+            &file,
+            width,
+            depth,
+            mounting_height,
+            workplane,
+            rows,
+            cols,
+            spacing,
+            grid,
+            maintenance_factor,
+            svg.as_deref(),
+            dark,
+        ),
+        Commands::Mesh {
+            file,
+            output,
+            format,
+            scale,
+            c_step,
+            g_step,
+            vertex_budget,
+        } => mesh::mesh(&file, &output, format, scale, c_step, g_step, vertex_budget),
+        Commands::Road {
+            file,
+            lanes,
+            lane_width,
+            spacing,
+            mounting_height,
+            overhang,
+            arrangement,
+            q0,
+            maintenance_factor,
+            class,
+            svg,
+            dark,
+        } => commands::road(
+            &file,
+            lanes,
+            lane_width,
+            spacing,
+            mounting_height,
+            overhang,
+            arrangement,
+            q0,
+            maintenance_factor,
+            class,
+            svg.as_deref(),
+            dark,
+        ),
+        Commands::Spectrum {
+            file,
+            cct,
+            cri,
+            output,
+            step,
+            dark,
+            width,
+            height,
+        } => spectrum::spectrum(
+            file.as_deref(),
+            cct,
+            cri,
+            output.as_deref(),
+            step,
+            dark,
+            width,
+            height,
+        ),
+        Commands::Gldf {
+            container:
+                Some(GldfCommands::Pack {
+                    product,
+                    inputs,
+                    output,
+                    overwrite,
+                }),
+            ..
+        } => gldf::pack(&product, &inputs, &output, overwrite),
+        Commands::Gldf {
+            container:
+                Some(GldfCommands::Unpack {
+                    archive,
+                    output_dir,
+                    overwrite,
+                }),
+            ..
+        } => {
+            let output_dir = output_dir.unwrap_or_else(|| archive.with_extension(""));
+            gldf::unpack(&archive, &output_dir, overwrite)
+        }
         Commands::Gldf {
             file,
             output,
             pretty,
-        } => commands::gldf(&file, output.as_ref(), pretty),
-        Commands::Calc { file, calc_type } => commands::calc(&file, calc_type),
+            ..
+        } => commands::gldf(
+            // clap requires the file without a subcommand
+            &file.unwrap_or_default(),
+            output.as_ref(),
+            pretty,
+        ),
+        Commands::Calc { file, calc_type } => commands::calc(&file, calc_type, json),
         Commands::ValidateAtla {
             file,
             schema,
@@ -88,6 +435,32 @@ fn main() -> Result<()> {
             verbose,
             compact,
         } => commands::atla_convert(&input, &output, target, policy, verbose, compact),
+        Commands::Compare {
+            file_a,
+            file_b,
+            format,
+            sort,
+            desc,
+            recursive,
+            diagram,
+            output,
+            fail_on,
+            ..
+        } if file_a.is_dir() => {
+            if file_b.is_some() || diagram.is_some() || fail_on.is_some() {
+                anyhow::bail!(
+                    "A directory overview takes no second file, no diagram and no --fail-on"
+                );
+            }
+            commands::compare_catalog(
+                &file_a,
+                format,
+                sort.to_overview_column(),
+                desc,
+                recursive,
+                output.as_deref(),
+            )
+        }
         Commands::Compare {
             file_a,
             file_b,
@@ -97,15 +470,18 @@ fn main() -> Result<()> {
             dark,
             significant_only,
             units,
+            fail_on,
+            ..
         } => commands::compare(
             &file_a,
-            &file_b,
+            &file_b.ok_or_else(|| anyhow::anyhow!("Missing second file to compare"))?,
             format,
             diagram,
             output.as_ref(),
             dark,
             significant_only,
             units.to_unit_system(),
+            fail_on,
         ),
         Commands::Report {
             input,
@@ -115,6 +491,7 @@ fn main() -> Result<()> {
             cu_table,
             ugr_table,
             candela_table,
+            template,
         } => commands::report(
             &input,
             &output,
@@ -123,6 +500,7 @@ fn main() -> Result<()> {
             cu_table,
             ugr_table,
             candela_table,
+            template.as_ref(),
         ),
         Commands::Interpolate {
             inputs,
@@ -145,5 +523,20 @@ fn main() -> Result<()> {
             &param_name,
             overwrite,
         ),
+        Commands::Index {
+            dir,
+            output,
+            rebuild,
+            thumbnail_size,
+            no_thumbnails,
+        } => index::index(
+            &dir,
+            &output,
+            rebuild,
+            (!no_thumbnails).then_some(thumbnail_size),
+        ),
+        Commands::Tui { file } => tui::run(&file),
+        Commands::Serve { port, host } => serve::serve(&host, port),
+        Commands::Completions { shell } => commands::completions(shell),
     }
 }
@@ -0,0 +1,80 @@
+//! Export of the photometric solid by the `mesh` command
+//!
+//! The solid comes from `eulumdat_photweb::LdcMesh`: Y is up, the nadir points
+//! to -Y and the distance from the origin is the normalized intensity times
+//! the scale.
+
+use anyhow::{Context, Result};
+use eulumdat_photweb::{LdcMesh, PhotometricWeb};
+use std::fs;
+use std::path::Path;
+
+use crate::cli::MeshFormatArg;
+use crate::commands::load_file;
+
+/// Write the photometric solid of `input` to `output`
+#[allow(clippy::too_many_arguments)]
+pub fn mesh(
+    input: &Path,
+    output: &Path,
+    format: Option<MeshFormatArg>,
+    scale: f32,
+    c_step: f64,
+    g_step: f64,
+    vertex_budget: Option<usize>,
+) -> Result<()> {
+    if !(0.5..=90.0).contains(&c_step) || !(0.5..=90.0).contains(&g_step) {
+        anyhow::bail!("Angle steps must be between 0.5° and 90°");
+    }
+    if vertex_budget.is_some_and(|budget| !(15..=1_000_000).contains(&budget)) {
+        anyhow::bail!("Vertex budget must be between 15 and 1000000");
+    }
+    if scale <= 0.0 || !scale.is_finite() {
+        anyhow::bail!("Scale must be positive");
+    }
+    let format = match format {
+        Some(format) => format,
+        None => format_from_extension(output)?,
+    };
+
+    let ldt = load_file(input)?;
+    let web = PhotometricWeb::from(&ldt);
+    let mesh = match vertex_budget {
+        Some(budget) => LdcMesh::from_photweb_adaptive(&web, budget, scale),
+        None => LdcMesh::from_photweb(&web, c_step, g_step, scale),
+    };
+
+    let bytes = match format {
+        MeshFormatArg::Gltf => mesh.to_gltf_json().into_bytes(),
+        MeshFormatArg::Glb => mesh.to_gltf(),
+        MeshFormatArg::Obj => mesh.to_obj().into_bytes(),
+        MeshFormatArg::Stl => mesh.to_stl(),
+    };
+    fs::write(output, bytes)
+        .with_context(|| format!("Failed to write file: {}", output.display()))?;
+
+    println!(
+        "Wrote {} ({} vertices, {} triangles)",
+        output.display(),
+        mesh.vertex_count(),
+        mesh.triangle_count()
+    );
+    Ok(())
+}
+
+fn format_from_extension(path: &Path) -> Result<MeshFormatArg> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase);
+    match extension.as_deref() {
+        Some("gltf") => Ok(MeshFormatArg::Gltf),
+        Some("glb") => Ok(MeshFormatArg::Glb),
+        Some("obj") => Ok(MeshFormatArg::Obj),
+        Some("stl") => Ok(MeshFormatArg::Stl),
+        _ => anyhow::bail!(
+            "Cannot infer mesh format from {}; use .gltf, .glb, .obj or .stl or pass --format",
+            path.display()
+        ),
+    }
+}
@@ -0,0 +1,71 @@
+//! Progress bar for long-running commands
+//!
+//! Drawn on stderr, and only when stderr is a terminal, so logs and pipes
+//! get the plain per-file lines. Safe to share between worker threads.
+
+use std::io::{IsTerminal, Write};
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Width of the bar in characters
+const BAR_WIDTH: usize = 30;
+
+pub struct Progress {
+    total: usize,
+    enabled: bool,
+    started: Instant,
+    /// Finished items; the lock also keeps lines and bar from interleaving
+    done: Mutex<usize>,
+}
+
+impl Progress {
+    pub fn new(total: usize) -> Self {
+        Self {
+            total,
+            enabled: std::io::stderr().is_terminal(),
+            started: Instant::now(),
+            done: Mutex::new(0),
+        }
+    }
+
+    /// Count one finished item and print its `line`, above the bar
+    pub fn finish(&self, line: &str, error: bool) {
+        let mut done = self.done.lock().unwrap_or_else(|e| e.into_inner());
+        *done += 1;
+        self.clear();
+        if error {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+        if self.enabled {
+            self.draw(*done);
+        }
+    }
+
+    /// Remove the bar before the final output
+    pub fn done(&self) {
+        let _lock = self.done.lock().unwrap_or_else(|e| e.into_inner());
+        self.clear();
+    }
+
+    fn clear(&self) {
+        if self.enabled {
+            eprint!("\r\x1b[2K");
+        }
+    }
+
+    fn draw(&self, done: usize) {
+        let fraction = done as f64 / self.total.max(1) as f64;
+        let filled = (fraction * BAR_WIDTH as f64).round() as usize;
+        let rate = done as f64 / self.started.elapsed().as_secs_f64().max(1e-3);
+        eprint!(
+            "[{}{}] {done}/{} ({:.0}%, {rate:.0} files/s)",
+            "#".repeat(filled),
+            " ".repeat(BAR_WIDTH - filled),
+            self.total,
+            fraction * 100.0
+        );
+        let _ = std::io::stderr().flush();
+    }
+}
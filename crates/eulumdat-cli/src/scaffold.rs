@@ -0,0 +1,168 @@
+//! Luminaire templates of the `new` command
+//!
+//! The templates are files the web and desktop editors offer, embedded at
+//! compile time. Overrides are applied on top, and the intensities are
+//! rescaled so that the declared LOR and DFF match the distribution.
+
+use anyhow::{Context, Result};
+use eulumdat::PhotometricCalculations;
+use std::path::Path;
+
+use crate::cli::TemplateArg;
+use crate::commands::{ldt_or_ies_extension, parse_content, save_ldt_or_ies};
+
+/// Values replacing those of the template
+pub struct Overrides {
+    pub name: Option<String>,
+    pub flux: Option<f64>,
+    pub wattage: Option<f64>,
+    pub beam_angle: Option<f64>,
+    pub length: Option<f64>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+}
+
+/// Content and format (file extension) of a template
+fn template_content(template: TemplateArg) -> (&'static str, &'static str) {
+    match template {
+        TemplateArg::Downlight => (
+            include_str!("../../eulumdat-wasm/templates/tm-32-24_office_downlight_bim.xml"),
+            "xml",
+        ),
+        TemplateArg::Fluorescent => (
+            include_str!("../../eulumdat-wasm/templates/fluorescent_luminaire.ldt"),
+            "ldt",
+        ),
+        TemplateArg::Projector => (
+            include_str!("../../eulumdat-wasm/templates/projector.ldt"),
+            "ldt",
+        ),
+        TemplateArg::Road => (
+            include_str!("../../eulumdat-wasm/templates/road_luminaire.ldt"),
+            "ldt",
+        ),
+        TemplateArg::Uplight => (
+            include_str!("../../eulumdat-wasm/templates/floor_uplight.ldt"),
+            "ldt",
+        ),
+        TemplateArg::Batwing => (
+            include_str!("../../eulumdat-wasm/templates/wiki-batwing.ldt"),
+            "ldt",
+        ),
+        TemplateArg::Spotlight => (
+            include_str!("../../eulumdat-wasm/templates/wiki-spotlight.ldt"),
+            "ldt",
+        ),
+        TemplateArg::Flood => (
+            include_str!("../../eulumdat-wasm/templates/wiki-flood.ldt"),
+            "ldt",
+        ),
+    }
+}
+
+/// Write `template` with `overrides` applied to `output`
+pub fn new_file(
+    template: TemplateArg,
+    output: &Path,
+    overrides: Overrides,
+    force: bool,
+) -> Result<()> {
+    let ext = ldt_or_ies_extension(output)?;
+    if output.exists() && !force {
+        anyhow::bail!(
+            "{} already exists (use --force to overwrite)",
+            output.display()
+        );
+    }
+
+    let (content, format) = template_content(template);
+    let mut ldt = parse_content(content, format, 0.0).context("Invalid template")?;
+
+    if let Some(beam_angle) = overrides.beam_angle {
+        let current = PhotometricCalculations::beam_angle(&ldt);
+        if beam_angle <= 0.0 || beam_angle >= 180.0 {
+            anyhow::bail!("Beam angle must be between 0 and 180 degrees");
+        }
+        if ldt.downward_flux_fraction < 50.0 {
+            anyhow::bail!("--beam-angle needs a mainly downward distribution");
+        }
+        if current <= 0.0 {
+            anyhow::bail!("The template has no beam angle to scale");
+        }
+        ldt.scale_beam(beam_angle / current);
+    }
+
+    let flux = PhotometricCalculations::integrate_flux(&ldt);
+    if flux.lor > 0.0 {
+        // Keep the declared LOR, and the DFF that goes with the distribution
+        if ldt.light_output_ratio <= 0.0 {
+            ldt.light_output_ratio = (flux.lor * 10.0).round() / 10.0;
+        }
+        let scale = ldt.light_output_ratio / flux.lor;
+        for value in ldt.intensities.iter_mut().flatten() {
+            *value *= scale;
+        }
+        ldt.downward_flux_fraction = (flux.dff * 10.0).round() / 10.0;
+    }
+
+    if let Some(name) = overrides.name {
+        ldt.luminaire_name = name;
+    }
+    for (value, field) in [
+        (overrides.length, &mut ldt.length),
+        (overrides.width, &mut ldt.width),
+        (overrides.height, &mut ldt.height),
+    ] {
+        if let Some(value) = value {
+            if value < 0.0 {
+                anyhow::bail!("Dimensions must not be negative");
+            }
+            *field = value;
+        }
+    }
+    // The luminous area has to fit into the luminaire
+    ldt.luminous_area_length = ldt.luminous_area_length.min(ldt.length);
+    ldt.luminous_area_width = ldt.luminous_area_width.min(ldt.width);
+
+    if overrides.flux.is_some() || overrides.wattage.is_some() {
+        let lamp_set = ldt
+            .lamp_sets
+            .first_mut()
+            .context("The template has no lamp set")?;
+        if let Some(flux) = overrides.flux {
+            lamp_set.total_luminous_flux = flux;
+        }
+        if let Some(wattage) = overrides.wattage {
+            lamp_set.wattage_with_ballast = wattage;
+        }
+    }
+    ldt.file_name = output
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    save_ldt_or_ies(&ldt, output, &ext)?;
+
+    println!(
+        "Created {} from the {template:?} template",
+        output.display()
+    );
+    print!(
+        "Flux {:.0} lm, {:.1} W, LOR {}%",
+        ldt.total_luminous_flux(),
+        ldt.total_wattage(),
+        ldt.light_output_ratio
+    );
+    if ldt.downward_flux_fraction >= 50.0 {
+        print!(
+            ", beam angle {:.1}°",
+            PhotometricCalculations::beam_angle(&ldt)
+        );
+    }
+    println!();
+    for warning in ldt.validate() {
+        println!("[{}] {}", warning.code, warning.message);
+    }
+
+    Ok(())
+}
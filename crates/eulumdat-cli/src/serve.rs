@@ -0,0 +1,404 @@
+//! HTTP server of the `serve` command
+//!
+//! Every endpoint takes a photometric file (LDT, IES or ATLA XML/JSON) as
+//! the POST body. The format is taken from the `from` query parameter or
+//! guessed from the content; the other parameters mirror the long options
+//! of the matching CLI command:
+//!
+//! | Endpoint         | Parameters                                  | Response            |
+//! |------------------|---------------------------------------------|---------------------|
+//! | `POST /convert`  | `to` (required), `compact`, `rotate`        | converted file      |
+//! | `POST /diagram`  | `type`, `format=svg\|png`, `dark`, `width`, … | SVG or PNG          |
+//! | `POST /validate` | `strict`                                    | JSON, as `validate --json` without the envelope |
+//! | `POST /summary`  | –                                           | JSON, as `summary --format json` |
+//!
+//! Diagram `width` and `height` must be between 16 and 4096 pixels.
+//! Errors are answered with a 4xx status and a plain text message.
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use eulumdat::diagram::SvgTheme;
+use eulumdat::PhotometricSummary;
+use std::io::Read;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use std::thread;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::cli::{ColorMapArg, DiagramType, FileFormatArg, IsoluxStyle, RadialScaleArg, UnitArg};
+use crate::commands::{self, DiagramOptions};
+use crate::json;
+
+/// Largest accepted request body; photometric files are far smaller
+const MAX_BODY: u64 = 16 * 1024 * 1024;
+
+/// Accepted diagram width and height in pixels; a PNG needs 4 bytes per pixel
+const DIAGRAM_SIZE: RangeInclusive<f64> = 16.0..=4096.0;
+
+/// Serve the endpoints on `host:port` until interrupted
+pub fn serve(host: &str, port: u16) -> Result<()> {
+    let server = Server::http((host, port))
+        .map_err(|e| anyhow::anyhow!("Failed to listen on {host}:{port}: {e}"))?;
+    eprintln!("Listening on http://{host}:{port} (Ctrl+C to stop)");
+    eprintln!("Endpoints: POST /convert, /diagram, /validate, /summary");
+
+    let workers = thread::available_parallelism().map_or(4, |n| n.get());
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                while let Ok(request) = server.recv() {
+                    handle(request);
+                }
+            });
+        }
+    });
+    Ok(())
+}
+
+/// A response before it is sent
+struct Reply {
+    status: u16,
+    content_type: &'static str,
+    body: Vec<u8>,
+}
+
+impl Reply {
+    fn ok(content_type: &'static str, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status: 200,
+            content_type,
+            body: body.into(),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        let mut body = message.into().into_bytes();
+        body.push(b'\n');
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            body,
+        }
+    }
+
+    fn json(data: &impl serde::Serialize) -> Result<Self> {
+        let json = serde_json::to_string_pretty(data).context("Failed to serialize JSON")?;
+        Ok(Self::ok("application/json", json))
+    }
+}
+
+fn handle(mut request: Request) {
+    let url = request.url().to_string();
+    let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+    let query = Query::parse(query);
+
+    let reply = if !matches!(path, "/convert" | "/diagram" | "/validate" | "/summary") {
+        Reply::error(404, format!("Unknown endpoint {path}"))
+    } else if *request.method() != Method::Post {
+        Reply::error(405, "Use POST with the photometric file as the body")
+    } else {
+        match read_body(&mut request) {
+            Ok(body) => {
+                route(path, &query, &body).unwrap_or_else(|e| Reply::error(400, format!("{e:#}")))
+            }
+            Err(reply) => reply,
+        }
+    };
+
+    eprintln!("{} {url} → {}", request.method(), reply.status);
+    let header = Header::from_bytes("Content-Type", reply.content_type)
+        .expect("content types are valid header values");
+    let response = Response::from_data(reply.body)
+        .with_status_code(reply.status)
+        .with_header(header);
+    if let Err(e) = request.respond(response) {
+        eprintln!("Failed to send response: {e}");
+    }
+}
+
+/// The request body as text
+fn read_body(request: &mut Request) -> std::result::Result<String, Reply> {
+    let mut bytes = Vec::new();
+    request
+        .as_reader()
+        .take(MAX_BODY + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|e| Reply::error(400, format!("Failed to read request body: {e}")))?;
+    if bytes.len() as u64 > MAX_BODY {
+        return Err(Reply::error(413, "Request body too large"));
+    }
+    if bytes.is_empty() {
+        return Err(Reply::error(
+            400,
+            "Empty request body, expected a photometric file",
+        ));
+    }
+    Ok(commands::decode_text(bytes))
+}
+
+fn route(path: &str, query: &Query, body: &str) -> Result<Reply> {
+    let format = match query.value_enum::<FileFormatArg>("from")? {
+        Some(from) => from.extension(),
+        None => commands::sniff_format(body),
+    };
+    match path {
+        "/convert" => convert(query, body, format),
+        "/diagram" => diagram(query, body, format),
+        "/validate" => {
+            let ldt = commands::parse_content(body, format, 0.0)?;
+            let warnings = ldt.validate();
+            let errors = if query.flag("strict")? {
+                ldt.validate_strict().err().unwrap_or_default()
+            } else {
+                Vec::new()
+            };
+            Reply::json(&json::Validation {
+                valid: warnings.is_empty() && errors.is_empty(),
+                warnings,
+                errors,
+            })
+        }
+        _ => {
+            let ldt = commands::parse_content(body, format, 0.0)?;
+            Reply::json(&PhotometricSummary::from_eulumdat(&ldt).to_record())
+        }
+    }
+}
+
+fn convert(query: &Query, body: &str, format: &str) -> Result<Reply> {
+    let to = query
+        .value_enum::<FileFormatArg>("to")?
+        .context("Missing query parameter to (ldt, ies, xml, or json)")?;
+    let content = commands::convert_content(
+        body,
+        format,
+        to.extension(),
+        query.flag("compact")?,
+        query.value("rotate", 0.0)?,
+    )?;
+    let content_type = match to {
+        FileFormatArg::Ldt | FileFormatArg::Ies => "text/plain; charset=utf-8",
+        FileFormatArg::Xml => "application/xml",
+        FileFormatArg::Json => "application/json",
+    };
+    Ok(Reply::ok(content_type, content))
+}
+
+fn diagram(query: &Query, body: &str, format: &str) -> Result<Reply> {
+    let diagram_type = query.value_enum("type")?.unwrap_or(DiagramType::Polar);
+    let dark = query.flag("dark")?;
+    let units = query
+        .value_enum("units")?
+        .unwrap_or(UnitArg::Metric)
+        .to_unit_system();
+    let mut theme = if dark {
+        SvgTheme::dark()
+    } else {
+        SvgTheme::light()
+    }
+    .with_units(units);
+    theme.curve_patterns = query.flag("patterns")?;
+
+    let heights = match query.get("heights") {
+        Some(list) => list
+            .split(',')
+            .map(|h| h.trim().parse().context("Invalid value of heights"))
+            .collect::<Result<Vec<f64>>>()?,
+        None => Vec::new(),
+    };
+    let options = DiagramOptions {
+        width: diagram_size(query, "width")?,
+        height: diagram_size(query, "height")?,
+        mounting_height: query.value("mounting-height", 3.0)?,
+        heights: &heights,
+        tilt: query.value("tilt", 0.0)?,
+        throw: query.value("throw", 10.0)?,
+        log_scale: query.flag("log-scale")?,
+        color_map: query
+            .value_enum("colormap")?
+            .unwrap_or(ColorMapArg::Classic)
+            .to_color_map(),
+        radial_scale: query
+            .value_enum("radial-scale")?
+            .unwrap_or(RadialScaleArg::Linear)
+            .to_radial_scale(),
+        batwing: query.flag("batwing")?,
+        isolux_style: query
+            .value_enum("isolux-style")?
+            .unwrap_or(IsoluxStyle::Heatmap),
+        area: query.value("area", 20.0)?,
+    };
+    let svg = commands::diagram_svg(body, format, diagram_type, dark, &theme, options)?;
+
+    match query.get("format").unwrap_or("svg") {
+        "svg" => Ok(Reply::ok("image/svg+xml", svg)),
+        "png" => Ok(Reply::ok(
+            "image/png",
+            eulumdat::diagram::svg_to_png(&svg, 1.0)?,
+        )),
+        other => anyhow::bail!("Unknown format {other} (expected svg or png)"),
+    }
+}
+
+/// Width or height of a diagram, 500 pixels if not given
+fn diagram_size(query: &Query, name: &str) -> Result<f64> {
+    let size = query.value(name, 500.0)?;
+    if !DIAGRAM_SIZE.contains(&size) {
+        anyhow::bail!(
+            "Invalid value of {name}: {size} (expected {} to {} pixels)",
+            DIAGRAM_SIZE.start(),
+            DIAGRAM_SIZE.end()
+        );
+    }
+    Ok(size)
+}
+
+/// Decoded query string parameters
+struct Query(Vec<(String, String)>);
+
+impl Query {
+    fn parse(query: &str) -> Self {
+        Self(
+            query
+                .split('&')
+                .filter(|pair| !pair.is_empty())
+                .map(|pair| {
+                    let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+                    (percent_decode(name), percent_decode(value))
+                })
+                .collect(),
+        )
+    }
+
+    /// The first value of `name`
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// A switch: present without a value, `true`/`1` or `false`/`0`
+    fn flag(&self, name: &str) -> Result<bool> {
+        match self.get(name) {
+            None | Some("false" | "0") => Ok(false),
+            Some("" | "true" | "1") => Ok(true),
+            Some(other) => {
+                anyhow::bail!("Invalid value of {name}: {other} (expected true or false)")
+            }
+        }
+    }
+
+    fn value<T: FromStr>(&self, name: &str, default: T) -> Result<T> {
+        match self.get(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid value of {name}: {value}")),
+            None => Ok(default),
+        }
+    }
+
+    /// A value as accepted by the CLI option of the same name
+    fn value_enum<T: ValueEnum>(&self, name: &str) -> Result<Option<T>> {
+        self.get(name)
+            .map(|value| {
+                T::from_str(value, true)
+                    .map_err(|e| anyhow::anyhow!("Invalid value of {name}: {e}"))
+            })
+            .transpose()
+    }
+}
+
+/// Decode `%XX` escapes and `+` for spaces; a `%` without two hex digits
+/// is kept as is
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => match (hex_digit(bytes.get(i + 1)), hex_digit(bytes.get(i + 2))) {
+                (Some(high), Some(low)) => {
+                    decoded.push(high << 4 | low);
+                    i += 2;
+                }
+                _ => decoded.push(b'%'),
+            },
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn hex_digit(byte: Option<&u8>) -> Option<u8> {
+    char::from(*byte?).to_digit(16).map(|digit| digit as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("a+b"), "a b");
+        assert_eq!(percent_decode("a%2Bb%2fc"), "a+b/c");
+        assert_eq!(percent_decode("%C3%A4"), "ä");
+        // Bad escapes stay as they are
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%4"), "%4");
+        assert_eq!(percent_decode("%zz1"), "%zz1");
+        assert_eq!(percent_decode("%+1"), "% 1");
+        assert_eq!(percent_decode("%%41"), "%A");
+    }
+
+    #[test]
+    fn test_query_parse() {
+        let query = Query::parse("type=polar&dark&name=a+b%26c&&width=300&width=400&=x");
+        assert_eq!(query.get("type"), Some("polar"));
+        assert_eq!(query.get("dark"), Some(""));
+        assert_eq!(query.get("name"), Some("a b&c"));
+        // Repeated keys: the first wins
+        assert_eq!(query.get("width"), Some("300"));
+        assert_eq!(query.get(""), Some("x"));
+        assert_eq!(query.get("height"), None);
+        assert!(Query::parse("").0.is_empty());
+    }
+
+    #[test]
+    fn test_query_values() {
+        let query = Query::parse("dark&patterns=0&rotate=%2D90&tilt=abc&strict=maybe&type=POLAR");
+        assert!(query.flag("dark").unwrap());
+        assert!(!query.flag("patterns").unwrap());
+        assert!(!query.flag("batwing").unwrap());
+        assert!(query.flag("strict").is_err());
+
+        assert_eq!(query.value("rotate", 0.0).unwrap(), -90.0);
+        assert_eq!(query.value("throw", 10.0).unwrap(), 10.0);
+        assert!(query.value("tilt", 0.0).is_err());
+
+        assert_eq!(
+            query.value_enum::<DiagramType>("type").unwrap(),
+            Some(DiagramType::Polar)
+        );
+        assert!(query.value_enum::<DiagramType>("tilt").is_err());
+    }
+
+    #[test]
+    fn test_diagram_size_limits() {
+        let size = |query: &str| diagram_size(&Query::parse(query), "width");
+        assert_eq!(size("").unwrap(), 500.0);
+        assert_eq!(size("width=16").unwrap(), 16.0);
+        assert_eq!(size("width=4096").unwrap(), 4096.0);
+        for rejected in [
+            "width=40000",
+            "width=15",
+            "width=-500",
+            "width=NaN",
+            "width=inf",
+        ] {
+            assert!(size(rejected).is_err(), "{rejected}");
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Spectral power distribution of the `spectrum` command
+//!
+//! The SPD is either measured, taken from the first emitter of an ATLA file
+//! that has one, or synthesized by `atla::synthesize_spectrum` from a CCT and
+//! color rendering index, given on the command line or read from the file.
+
+use anyhow::{Context, Result};
+use atla::{
+    Chromaticity, SpectralDiagram, SpectralDistribution, SpectralMetrics, SpectralTheme,
+    SpectralUnits,
+};
+use std::fs;
+use std::path::Path;
+
+use crate::commands::{decode_text, parse_atla_content};
+
+/// Print the SPD of `file`, or of `cct`/`cri`, and write it as SVG or CSV
+#[allow(clippy::too_many_arguments)]
+pub fn spectrum(
+    file: Option<&Path>,
+    cct: Option<f64>,
+    cri: Option<f64>,
+    output: Option<&Path>,
+    step: Option<f64>,
+    dark: bool,
+    width: f64,
+    height: f64,
+) -> Result<()> {
+    let (source, spd) = match (file, cct) {
+        (_, Some(cct)) => {
+            if !(1000.0..=25000.0).contains(&cct) {
+                anyhow::bail!("CCT must be between 1000 K and 25000 K");
+            }
+            if cri.is_some_and(|ra| !(0.0..=100.0).contains(&ra)) {
+                anyhow::bail!("CRI must be between 0 and 100");
+            }
+            (synthesized(cct, cri), atla::synthesize_spectrum(cct, cri))
+        }
+        (Some(file), None) => from_file(file)?,
+        // clap requires one of them
+        (None, None) => anyhow::bail!("Give an input file or --cct"),
+    };
+    let samples = samples(&spd);
+    if samples.is_empty() {
+        anyhow::bail!("The spectral distribution has no values");
+    }
+    // Explicit wavelengths for the diagram and metrics
+    let spd = SpectralDistribution {
+        wavelengths: samples.iter().map(|&(wl, _)| wl).collect(),
+        values: samples.iter().map(|&(_, v)| v).collect(),
+        units: spd.units,
+        start_wavelength: None,
+        wavelength_interval: None,
+    };
+
+    match output {
+        Some(output) => {
+            let extension = output
+                .extension()
+                .and_then(|e| e.to_str())
+                .map(str::to_lowercase);
+            let content = match extension.as_deref() {
+                Some("svg") => {
+                    let theme = if dark {
+                        SpectralTheme::dark()
+                    } else {
+                        SpectralTheme::light()
+                    };
+                    SpectralDiagram::from_spectral(&spd).to_svg(width, height, &theme)
+                }
+                Some("csv") => csv(&spd),
+                _ => anyhow::bail!(
+                    "Cannot write a spectrum to {}; use .svg or .csv",
+                    output.display()
+                ),
+            };
+            fs::write(output, content)
+                .with_context(|| format!("Failed to write file: {}", output.display()))?;
+            println!("{source} → {}", output.display());
+        }
+        None => print_table(&source, &spd, step),
+    }
+    Ok(())
+}
+
+fn synthesized(cct: f64, cri: Option<f64>) -> String {
+    match cri {
+        Some(ra) => format!("Synthesized from {cct:.0} K, Ra {ra:.0}"),
+        None => format!("Synthesized from {cct:.0} K"),
+    }
+}
+
+/// Measured SPD of the first emitter that has one, else the synthesized SPD
+/// of the first emitter with a CCT
+fn from_file(file: &Path) -> Result<(String, SpectralDistribution)> {
+    let bytes =
+        fs::read(file).with_context(|| format!("Failed to read file: {}", file.display()))?;
+    let format = file
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+    let doc = parse_atla_content(&decode_text(bytes), &format)?;
+
+    if let Some((i, spd)) = doc
+        .emitters
+        .iter()
+        .enumerate()
+        .find_map(|(i, e)| e.spectral_distribution.as_ref().map(|spd| (i, spd)))
+    {
+        return Ok((format!("Measured, emitter {i}"), spd.clone()));
+    }
+    let (i, emitter) = doc
+        .emitters
+        .iter()
+        .enumerate()
+        .find(|(_, e)| e.cct.is_some())
+        .context("No spectral distribution or CCT in the file; pass --cct instead")?;
+    let cct = emitter.cct.unwrap_or_default();
+    let cri = emitter.color_rendering.as_ref().and_then(|cr| cr.ra);
+    Ok((
+        format!("{}, emitter {i}", synthesized(cct, cri)),
+        atla::synthesize_spectrum(cct, cri),
+    ))
+}
+
+/// (wavelength, value) pairs, with wavelengths from the start and interval
+/// when the SPD has no explicit list
+fn samples(spd: &SpectralDistribution) -> Vec<(f64, f64)> {
+    if !spd.wavelengths.is_empty() {
+        return spd
+            .wavelengths
+            .iter()
+            .copied()
+            .zip(spd.values.iter().copied())
+            .collect();
+    }
+    match (spd.start_wavelength, spd.wavelength_interval) {
+        (Some(start), Some(interval)) => spd
+            .values
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| (start + i as f64 * interval, v))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn unit_label(units: &SpectralUnits) -> &'static str {
+    match units {
+        SpectralUnits::WattsPerNanometer => "W/nm",
+        SpectralUnits::Relative => "relative",
+    }
+}
+
+fn csv(spd: &SpectralDistribution) -> String {
+    let column = match spd.units {
+        SpectralUnits::WattsPerNanometer => "w_per_nm",
+        SpectralUnits::Relative => "relative",
+    };
+    let mut out = format!("wavelength_nm,{column}\n");
+    for (wl, value) in spd.wavelengths.iter().zip(&spd.values) {
+        out.push_str(&format!("{wl},{value}\n"));
+    }
+    out
+}
+
+fn print_table(source: &str, spd: &SpectralDistribution, step: Option<f64>) {
+    let metrics = SpectralMetrics::from_spd(spd);
+    println!("Spectrum");
+    println!("========");
+    println!("Source:          {source}");
+    println!(
+        "Range:           {:.0}–{:.0} nm ({} values, {})",
+        metrics.wavelength_min,
+        metrics.wavelength_max,
+        spd.values.len(),
+        unit_label(&spd.units)
+    );
+    println!("Peak:            {:.0} nm", metrics.peak_wavelength);
+    if let Some(c) = Chromaticity::from_spd(spd) {
+        println!("CCT:             {:.0} K (Duv {:+.4})", c.cct, c.duv);
+        println!("CIE 1931 x, y:   {:.4}, {:.4}", c.x, c.y);
+    }
+    println!(
+        "PAR:             {:.1}% (blue {:.1}%, green {:.1}%, red {:.1}%)",
+        metrics.par_percent,
+        metrics.blue_par_percent,
+        metrics.green_par_percent,
+        metrics.red_par_percent
+    );
+    if let Some(ratio) = metrics.r_fr_ratio {
+        println!("R:FR:            {ratio:.2}");
+    }
+    if let Some(hazard) = metrics.hazard_level() {
+        println!("Hazard:          {hazard}");
+    }
+
+    let peak = spd.values.iter().copied().fold(0.0_f64, f64::max);
+    let mut next = f64::MIN;
+    println!();
+    println!("{:>8}  {:>12}", "λ (nm)", unit_label(&spd.units));
+    for (&wl, &value) in spd.wavelengths.iter().zip(&spd.values) {
+        if wl + 1e-9 < next {
+            continue;
+        }
+        next = wl + step.unwrap_or(0.0);
+        // Bar of up to 40 characters relative to the peak
+        let bar = if peak > 0.0 {
+            "█".repeat((value / peak * 40.0).round().max(0.0) as usize)
+        } else {
+            String::new()
+        };
+        println!("{wl:>8.1}  {value:>12.4}  {bar}");
+    }
+}
@@ -0,0 +1,272 @@
+//! Aggregate statistics of the `stats` command
+//!
+//! Meant for QA of incoming supplier data: how many files parse, which
+//! symmetry types and flux ranges they cover, how efficient the luminaires
+//! are and which validation warnings come up how often.
+
+use anyhow::Result;
+use eulumdat::Eulumdat;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::commands::{load_file, photometric_files};
+use crate::json;
+
+/// Upper edges of the luminaire flux histogram bins in lumens; the last bin
+/// is open-ended
+const FLUX_BIN_EDGES: [f64; 7] = [500.0, 1000.0, 2000.0, 5000.0, 10000.0, 20000.0, 50000.0];
+
+/// Percentiles reported for the luminaire efficacy
+const PERCENTILES: [u8; 5] = [10, 25, 50, 75, 90];
+
+#[derive(Serialize)]
+struct Stats {
+    file_count: usize,
+    parsed_count: usize,
+    parse_failures: Vec<ParseFailure>,
+    /// Files per symmetry type
+    by_symmetry: BTreeMap<String, usize>,
+    flux_histogram_lm: Vec<HistogramBin>,
+    /// Luminaire flux per watt, over files with a wattage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    efficacy_lm_w: Option<Distribution>,
+    files_with_warnings: usize,
+    /// Most frequent first
+    warnings: Vec<WarningCount>,
+}
+
+#[derive(Serialize)]
+struct ParseFailure {
+    path: String,
+    error: String,
+}
+
+#[derive(Serialize)]
+struct HistogramBin {
+    min: f64,
+    /// `None` for the open-ended last bin
+    max: Option<f64>,
+    count: usize,
+}
+
+#[derive(Serialize)]
+struct Distribution {
+    count: usize,
+    min: f64,
+    max: f64,
+    /// `p10`, `p25`, `p50`, `p75` and `p90`
+    percentiles: BTreeMap<String, f64>,
+}
+
+#[derive(Serialize)]
+struct WarningCount {
+    code: &'static str,
+    /// Files with this warning
+    files: usize,
+    percent: f64,
+    /// Message of the first file with the warning
+    example: String,
+}
+
+/// Print statistics over the .ldt and .ies files in `dir`
+pub fn stats(dir: &Path, recursive: bool, as_json: bool) -> Result<()> {
+    if !dir.is_dir() {
+        anyhow::bail!("Input path is not a directory: {}", dir.display());
+    }
+    let paths = photometric_files(dir, recursive);
+    let loaded: Vec<(&Path, Result<Eulumdat>)> = paths
+        .par_iter()
+        .map(|path| (path.as_path(), load_file(path)))
+        .collect();
+
+    let mut parse_failures = Vec::new();
+    let mut files = Vec::new();
+    for (path, result) in loaded {
+        match result {
+            Ok(ldt) => files.push(ldt),
+            Err(e) => parse_failures.push(ParseFailure {
+                path: path.strip_prefix(dir).unwrap_or(path).display().to_string(),
+                error: format!("{e:#}"),
+            }),
+        }
+    }
+
+    let stats = Stats::collect(paths.len(), parse_failures, &files);
+    if as_json {
+        json::print("stats", dir, stats)
+    } else {
+        stats.print(dir);
+        Ok(())
+    }
+}
+
+impl Stats {
+    fn collect(file_count: usize, parse_failures: Vec<ParseFailure>, files: &[Eulumdat]) -> Self {
+        let mut by_symmetry = BTreeMap::new();
+        let mut flux_histogram_lm: Vec<HistogramBin> = std::iter::once(0.0)
+            .chain(FLUX_BIN_EDGES)
+            .enumerate()
+            .map(|(i, min)| HistogramBin {
+                min,
+                max: FLUX_BIN_EDGES.get(i).copied(),
+                count: 0,
+            })
+            .collect();
+        let mut efficacies = Vec::new();
+        let mut warnings: BTreeMap<&'static str, WarningCount> = BTreeMap::new();
+        let mut files_with_warnings = 0;
+
+        for ldt in files {
+            *by_symmetry
+                .entry(format!("{:?}", ldt.symmetry))
+                .or_insert(0) += 1;
+
+            let flux = ldt.total_luminous_flux() * ldt.light_output_ratio / 100.0;
+            let bin = FLUX_BIN_EDGES
+                .iter()
+                .position(|&edge| flux < edge)
+                .unwrap_or(FLUX_BIN_EDGES.len());
+            flux_histogram_lm[bin].count += 1;
+            let wattage = ldt.total_wattage();
+            if wattage > 0.0 {
+                efficacies.push(flux / wattage);
+            }
+
+            let file_warnings = ldt.validate();
+            if !file_warnings.is_empty() {
+                files_with_warnings += 1;
+            }
+            let mut seen = Vec::new();
+            for warning in file_warnings {
+                // Count files, not warnings (e.g. one W028 per lamp set)
+                if seen.contains(&warning.code) {
+                    continue;
+                }
+                seen.push(warning.code);
+                warnings
+                    .entry(warning.code)
+                    .or_insert_with(|| WarningCount {
+                        code: warning.code,
+                        files: 0,
+                        percent: 0.0,
+                        example: warning.message,
+                    })
+                    .files += 1;
+            }
+        }
+
+        let mut warnings: Vec<WarningCount> = warnings.into_values().collect();
+        for warning in &mut warnings {
+            warning.percent = 100.0 * warning.files as f64 / files.len() as f64;
+        }
+        warnings.sort_by(|a, b| b.files.cmp(&a.files).then(a.code.cmp(b.code)));
+
+        Self {
+            file_count,
+            parsed_count: files.len(),
+            parse_failures,
+            by_symmetry,
+            flux_histogram_lm,
+            efficacy_lm_w: Distribution::new(efficacies),
+            files_with_warnings,
+            warnings,
+        }
+    }
+
+    fn print(&self, dir: &Path) {
+        println!("Statistics for {}", dir.display());
+        println!();
+        println!("Files:          {}", self.file_count);
+        println!("Parsed:         {}", self.parsed_count);
+        println!("Parse failures: {}", self.parse_failures.len());
+        for failure in &self.parse_failures {
+            println!("  {}: {}", failure.path, failure.error);
+        }
+        if self.parsed_count == 0 {
+            return;
+        }
+
+        println!();
+        println!("=== Symmetry ===");
+        for (symmetry, count) in &self.by_symmetry {
+            println!("{symmetry:<14} {count:>6}");
+        }
+
+        println!();
+        println!("=== Luminaire Flux ===");
+        let largest = self
+            .flux_histogram_lm
+            .iter()
+            .map(|bin| bin.count)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        for bin in &self.flux_histogram_lm {
+            let range = match bin.max {
+                Some(max) => format!("{:.0}–{:.0} lm", bin.min, max),
+                None => format!("≥ {:.0} lm", bin.min),
+            };
+            println!(
+                "{range:<16} {:>6}  {}",
+                bin.count,
+                "#".repeat((bin.count * 40).div_ceil(largest))
+            );
+        }
+
+        println!();
+        println!("=== Luminaire Efficacy (lm/W) ===");
+        match &self.efficacy_lm_w {
+            Some(efficacy) => {
+                println!("Files:          {}", efficacy.count);
+                println!("Min:            {:.1}", efficacy.min);
+                for (name, value) in &efficacy.percentiles {
+                    println!("{:<15} {value:.1}", format!("{}:", name.to_uppercase()));
+                }
+                println!("Max:            {:.1}", efficacy.max);
+            }
+            None => println!("No file has a wattage"),
+        }
+
+        println!();
+        println!("=== Validation ===");
+        println!(
+            "Files with warnings: {} of {} ({:.1}%)",
+            self.files_with_warnings,
+            self.parsed_count,
+            100.0 * self.files_with_warnings as f64 / self.parsed_count as f64
+        );
+        for warning in &self.warnings {
+            println!(
+                "  [{}] {:>5} files ({:>5.1}%)  e.g. {}",
+                warning.code, warning.files, warning.percent, warning.example
+            );
+        }
+    }
+}
+
+impl Distribution {
+    fn new(mut values: Vec<f64>) -> Option<Self> {
+        values.retain(|v| v.is_finite());
+        values.sort_by(f64::total_cmp);
+        let (&min, &max) = (values.first()?, values.last()?);
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&p| (format!("p{p}"), percentile(&values, p as f64 / 100.0)))
+            .collect();
+        Some(Self {
+            count: values.len(),
+            min,
+            max,
+            percentiles,
+        })
+    }
+}
+
+/// Linear interpolation between the closest ranks of sorted `values`
+fn percentile(values: &[f64], fraction: f64) -> f64 {
+    let rank = fraction * (values.len() - 1) as f64;
+    let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+    values[low] + (values[high] - values[low]) * (rank - low as f64)
+}
@@ -0,0 +1,127 @@
+//! Datasheet templates for the report command
+//!
+//! A template is a TOML file with company details, colors and the panel
+//! layout of the one-page datasheet. All keys are optional:
+//!
+//! ```toml
+//! company = "ACME Lighting"
+//! title = "Product datasheet"
+//! footer = "ACME Lighting GmbH · www.acme.example"
+//! accent = "#e30613"
+//! dark = false
+//! theme = "acme-theme.json"   # SvgTheme JSON, relative to the template
+//! mounting_height = 3.0
+//! columns = 2
+//! rows = 3
+//! panels = ["polar", "cartesian", "cone", "bug", "summary:2"]
+//! ```
+
+use anyhow::{Context, Result};
+use eulumdat::diagram::{
+    DatasheetBranding, DatasheetCell, DatasheetComposer, DatasheetPanel, SvgTheme,
+};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReportTemplate {
+    /// Company name in the title block
+    pub company: String,
+    /// Document title (default "Photometric datasheet")
+    pub title: String,
+    /// Footer line
+    pub footer: String,
+    /// Color of the title rule
+    pub accent: String,
+    /// Use the dark theme
+    pub dark: bool,
+    /// SvgTheme JSON file, relative to the template
+    pub theme: Option<PathBuf>,
+    /// Mounting height for the cone diagram in meters
+    pub mounting_height: Option<f64>,
+    /// Grid size
+    pub columns: Option<usize>,
+    pub rows: Option<usize>,
+    /// Panels in row-major order: polar, cartesian, cone, bug or summary,
+    /// with `:<columns>` to span several columns
+    pub panels: Vec<String>,
+    /// Directory of the template file
+    #[serde(skip)]
+    base_dir: PathBuf,
+}
+
+impl ReportTemplate {
+    /// Read a template from a TOML file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).context("Failed to read template file")?;
+        let mut template: Self = toml::from_str(&content).context("Invalid template file")?;
+        template.base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        Ok(template)
+    }
+
+    /// Diagram theme: the theme file if given, else light or dark
+    pub fn theme(&self) -> Result<SvgTheme> {
+        match &self.theme {
+            Some(path) => {
+                let json = fs::read_to_string(self.base_dir.join(path))
+                    .context("Failed to read theme file")?;
+                serde_json::from_str(&json).context("Invalid theme file")
+            }
+            None if self.dark => Ok(SvgTheme::dark()),
+            None => Ok(SvgTheme::light()),
+        }
+    }
+
+    /// Datasheet composer for a page of `width` × `height` SVG units
+    pub fn composer(&self, width: f64, height: f64) -> Result<DatasheetComposer> {
+        let defaults = DatasheetComposer::new();
+        let mut composer = defaults
+            .clone()
+            .with_page_size(width, height)
+            .with_grid(
+                self.columns.unwrap_or(defaults.columns),
+                self.rows.unwrap_or(defaults.rows),
+            )
+            .with_mounting_height(self.mounting_height.unwrap_or(defaults.mounting_height))
+            .with_branding(DatasheetBranding {
+                company: self.company.clone(),
+                title: self.title.clone(),
+                footer: self.footer.clone(),
+                accent: self.accent.clone(),
+            });
+        if !self.panels.is_empty() {
+            composer.cells = self
+                .panels
+                .iter()
+                .map(|entry| parse_panel(entry))
+                .collect::<Result<_>>()?;
+        }
+        Ok(composer)
+    }
+}
+
+/// Parse a panel entry such as `polar` or `summary:2`
+fn parse_panel(entry: &str) -> Result<DatasheetCell> {
+    let (name, span) = match entry.split_once(':') {
+        Some((name, span)) => (
+            name,
+            span.trim()
+                .parse()
+                .with_context(|| format!("Invalid column span in panel \"{entry}\""))?,
+        ),
+        None => (entry, 1),
+    };
+    let panel = match name.trim().to_lowercase().as_str() {
+        "polar" => DatasheetPanel::Polar,
+        "cartesian" => DatasheetPanel::Cartesian,
+        "cone" => DatasheetPanel::Cone,
+        "bug" => DatasheetPanel::Bug,
+        "summary" => DatasheetPanel::Summary,
+        other => anyhow::bail!(
+            "Unknown panel \"{other}\" (expected polar, cartesian, cone, bug or summary)"
+        ),
+    };
+    Ok(DatasheetCell::spanning(panel, span))
+}
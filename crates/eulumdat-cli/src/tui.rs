@@ -0,0 +1,357 @@
+//! Terminal viewer for the `tui` command
+//!
+//! Tabs for the file header, a braille polar plot, the intensity table and
+//! the validation results, for a quick look over SSH where there is no GUI.
+
+use anyhow::Result;
+use eulumdat::diagram::PolarDiagram;
+use eulumdat::{Eulumdat, PhotometricSummary, ValidationError, ValidationWarning};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::symbols::Marker;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::canvas::{Canvas, Circle, Line as CanvasLine};
+use ratatui::widgets::{Block, Paragraph, Row, Table, Tabs};
+use ratatui::{DefaultTerminal, Frame};
+use std::path::Path;
+
+use crate::commands::load_file;
+
+const TABS: [&str; 4] = ["Info", "Polar", "Intensities", "Validation"];
+const INFO: usize = 0;
+const POLAR: usize = 1;
+const INTENSITIES: usize = 2;
+const VALIDATION: usize = 3;
+
+/// Width of an intensity table column in cells
+const COLUMN_WIDTH: u16 = 8;
+
+/// Open `file` in the terminal viewer until the user quits
+pub fn run(file: &Path) -> Result<()> {
+    let ldt = load_file(file)?;
+    let mut viewer = Viewer::new(file, ldt);
+    let mut terminal = ratatui::init();
+    let result = viewer.run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+struct Viewer {
+    title: String,
+    ldt: Eulumdat,
+    summary: PhotometricSummary,
+    polar: PolarDiagram,
+    warnings: Vec<ValidationWarning>,
+    errors: Vec<ValidationError>,
+    tab: usize,
+    /// First visible line (info, validation) or gamma row (intensities)
+    scroll: usize,
+    /// First visible C-plane of the intensity table
+    column: usize,
+    quit: bool,
+}
+
+impl Viewer {
+    fn new(file: &Path, ldt: Eulumdat) -> Self {
+        Self {
+            title: file.display().to_string(),
+            summary: PhotometricSummary::from_eulumdat(&ldt),
+            polar: PolarDiagram::from_eulumdat(&ldt),
+            warnings: ldt.validate(),
+            errors: ldt.validate_strict().err().unwrap_or_default(),
+            ldt,
+            tab: INFO,
+            scroll: 0,
+            column: 0,
+            quit: false,
+        }
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        while !self.quit {
+            terminal.draw(|frame| self.draw(frame))?;
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    self.handle_key(key.code);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Tab => self.select_tab((self.tab + 1) % TABS.len()),
+            KeyCode::BackTab => self.select_tab((self.tab + TABS.len() - 1) % TABS.len()),
+            KeyCode::Char(c @ '1'..='4') => self.select_tab(c as usize - '1' as usize),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll += 1,
+            KeyCode::Up | KeyCode::Char('k') => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::PageDown => self.scroll += 10,
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            KeyCode::Home => self.scroll = 0,
+            KeyCode::Right | KeyCode::Char('l') => self.column += 1,
+            KeyCode::Left | KeyCode::Char('h') => self.column = self.column.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    fn select_tab(&mut self, tab: usize) {
+        if tab != self.tab {
+            self.tab = tab;
+            self.scroll = 0;
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [tabs, body, help] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        frame.render_widget(
+            Tabs::new(
+                TABS.iter()
+                    .enumerate()
+                    .map(|(i, name)| format!("{} {name}", i + 1)),
+            )
+            .select(self.tab)
+            .highlight_style(Style::new().bold().reversed())
+            .block(Block::bordered().title(format!(" {} ", self.title))),
+            tabs,
+        );
+
+        match self.tab {
+            INFO => self.draw_info(frame, body),
+            POLAR => self.draw_polar(frame, body),
+            INTENSITIES => self.draw_intensities(frame, body),
+            VALIDATION => self.draw_validation(frame, body),
+            _ => unreachable!(),
+        }
+
+        let keys = match self.tab {
+            INTENSITIES => "Tab/1-4 tabs · ↑↓ γ rows · ←→ C-planes · q quit",
+            POLAR => "Tab/1-4 tabs · q quit",
+            _ => "Tab/1-4 tabs · ↑↓ scroll · q quit",
+        };
+        frame.render_widget(Paragraph::new(keys).dark_gray(), help);
+    }
+
+    /// Clamp the scroll position so the last line stays on screen
+    fn clamp_scroll(&mut self, lines: usize, height: u16) -> u16 {
+        self.scroll = self.scroll.min(lines.saturating_sub(height as usize));
+        self.scroll as u16
+    }
+
+    fn draw_info(&mut self, frame: &mut Frame, area: Rect) {
+        let ldt = &self.ldt;
+        let s = &self.summary;
+        let field = |name: &str, value: String| {
+            Line::from(vec![
+                Span::from(format!("{name:<20}")).bold(),
+                Span::from(value),
+            ])
+        };
+        let heading = |text: &str| Line::from(text.to_string()).cyan().bold();
+
+        let mut lines = vec![
+            heading("Luminaire"),
+            field("Name", ldt.luminaire_name.clone()),
+            field("Number", ldt.luminaire_number.clone()),
+            field("Manufacturer", ldt.identification.clone()),
+            field("Date", ldt.date_user.clone()),
+            field(
+                "Dimensions (mm)",
+                format!("{} × {} × {}", ldt.length, ldt.width, ldt.height),
+            ),
+            Line::default(),
+            heading("Photometric data"),
+            field("Type", format!("{:?}", ldt.type_indicator)),
+            field("Symmetry", format!("{:?}", ldt.symmetry)),
+            field(
+                "Grid",
+                format!(
+                    "{} C-planes × {} γ angles",
+                    ldt.c_angles.len(),
+                    ldt.g_angles.len()
+                ),
+            ),
+            field("Max intensity", format!("{:.1} cd/klm", s.max_intensity)),
+            field(
+                "Beam / field angle",
+                format!("{:.1}° / {:.1}°", s.beam_angle, s.field_angle),
+            ),
+            field("CIE flux code", s.cie_flux_codes.to_string()),
+            field(
+                "LOR (down / up)",
+                format!("{:.1}% ({:.1}% / {:.1}%)", s.lor, s.dlor, s.ulor),
+            ),
+            Line::default(),
+            heading("Lamps"),
+        ];
+        for (i, lamp) in ldt.lamp_sets.iter().enumerate() {
+            lines.push(field(
+                &format!("Set {}", i + 1),
+                format!(
+                    "{} × {}, {:.0} lm, {:.1} W, {} / {}",
+                    lamp.num_lamps,
+                    lamp.lamp_type,
+                    lamp.total_luminous_flux,
+                    lamp.wattage_with_ballast,
+                    lamp.color_appearance,
+                    lamp.color_rendering_group
+                ),
+            ));
+        }
+        lines.extend([
+            field("Lamp flux", format!("{:.0} lm", s.total_lamp_flux)),
+            field("Power", format!("{:.1} W", s.total_wattage)),
+            field(
+                "Efficacy",
+                format!("{:.1} lm/W (luminaire)", s.luminaire_efficacy),
+            ),
+        ]);
+
+        let block = Block::bordered();
+        let scroll = self.clamp_scroll(lines.len(), block.inner(area).height);
+        frame.render_widget(Paragraph::new(lines).block(block).scroll((scroll, 0)), area);
+    }
+
+    fn draw_polar(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered().title(Line::from(vec![
+            Span::from(" C0-C180 ").cyan(),
+            Span::from(" C90-C270 ").red(),
+            Span::from(format!(
+                " cd/klm, max {:.0} ",
+                self.polar.scale.max_intensity
+            )),
+        ]));
+        let inner = block.inner(area);
+        let scale = self.polar.scale.scale_max.max(1.0);
+
+        // Braille dots are about square, so widen whichever axis has room
+        let ratio = f64::from(inner.width) / (2.0 * f64::from(inner.height.max(1)));
+        let (x_max, y_max) = if ratio >= 1.0 {
+            (scale * ratio, scale)
+        } else {
+            (scale, scale / ratio)
+        };
+
+        let polar = &self.polar;
+        let canvas = Canvas::default()
+            .block(block)
+            .marker(Marker::Braille)
+            .x_bounds([-x_max, x_max])
+            .y_bounds([-y_max, y_max])
+            .paint(move |ctx| {
+                for &radius in &polar.scale.grid_values {
+                    ctx.draw(&Circle {
+                        x: 0.0,
+                        y: 0.0,
+                        radius,
+                        color: Color::DarkGray,
+                    });
+                }
+                ctx.draw(&CanvasLine::new(-scale, 0.0, scale, 0.0, Color::DarkGray));
+                ctx.draw(&CanvasLine::new(0.0, -scale, 0.0, scale, Color::DarkGray));
+                ctx.layer();
+
+                for (curve, color) in [
+                    (&polar.c0_c180_curve, Color::Cyan),
+                    (&polar.c90_c270_curve, Color::Red),
+                ] {
+                    // Nadir (γ 0°) points down
+                    for pair in curve.points.windows(2) {
+                        ctx.draw(&CanvasLine::new(
+                            pair[0].x, -pair[0].y, pair[1].x, -pair[1].y, color,
+                        ));
+                    }
+                }
+
+                if let Some(&outer) = polar.scale.grid_values.last() {
+                    ctx.print(2.0, -outer, format!("{outer:.0}").dark_gray());
+                }
+            });
+        frame.render_widget(canvas, area);
+    }
+
+    fn draw_intensities(&mut self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered().title(" Intensity (cd/klm), stored C-planes ");
+        let inner = block.inner(area);
+
+        let planes = self.ldt.intensities.len().min(self.ldt.c_angles.len());
+        let visible_columns = (inner.width.saturating_sub(COLUMN_WIDTH) / COLUMN_WIDTH).max(1);
+        self.column = self
+            .column
+            .min(planes.saturating_sub(visible_columns as usize));
+        // One line for the header
+        let scroll = self.clamp_scroll(self.ldt.g_angles.len(), inner.height.saturating_sub(1));
+        let ldt = &self.ldt;
+        let columns = self.column..(self.column + visible_columns as usize).min(planes);
+        let max = self.summary.max_intensity;
+
+        let header = Row::new(
+            std::iter::once("γ \\ C".to_string())
+                .chain(columns.clone().map(|c| format!("{}°", ldt.c_angles[c]))),
+        )
+        .bold()
+        .cyan();
+        let rows = ldt
+            .g_angles
+            .iter()
+            .enumerate()
+            .skip(scroll as usize)
+            .map(|(g, gamma)| {
+                let mut cells = vec![Span::from(format!("{gamma}°")).bold()];
+                for c in columns.clone() {
+                    let value = ldt.intensities[c].get(g).copied().unwrap_or(0.0);
+                    let text = Span::from(format!("{value:>7.1}"));
+                    cells.push(if max > 0.0 && value >= max {
+                        text.yellow().add_modifier(Modifier::BOLD)
+                    } else {
+                        text
+                    });
+                }
+                Row::new(cells)
+            });
+
+        let widths = vec![Constraint::Length(COLUMN_WIDTH); columns.len() + 1];
+        frame.render_widget(Table::new(rows, widths).header(header).block(block), area);
+    }
+
+    fn draw_validation(&mut self, frame: &mut Frame, area: Rect) {
+        let mut lines = Vec::new();
+        if self.warnings.is_empty() && self.errors.is_empty() {
+            lines.push(Line::from("✓ No validation warnings").green());
+        }
+        for error in &self.errors {
+            lines.push(Line::from(vec![
+                Span::from(format!("[{}] ", error.code)).red().bold(),
+                Span::from(error.message.clone()),
+            ]));
+        }
+        for warning in &self.warnings {
+            lines.push(Line::from(vec![
+                Span::from(format!("[{}] ", warning.code)).yellow().bold(),
+                Span::from(warning.message.clone()),
+            ]));
+        }
+
+        let block = Block::bordered().title(format!(
+            " {} error(s), {} warning(s) ",
+            self.errors.len(),
+            self.warnings.len()
+        ));
+        let scroll = self.clamp_scroll(lines.len(), block.inner(area).height);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(ratatui::widgets::Wrap { trim: false })
+                .scroll((scroll, 0)),
+            area,
+        );
+    }
+}
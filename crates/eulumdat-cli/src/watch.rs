@@ -0,0 +1,57 @@
+//! Watch mode for the diagram and convert commands
+//!
+//! Polls the modification time of the input file instead of using OS file
+//! events: editors and lab exports often replace a file rather than write
+//! to it, which event watchers report inconsistently across platforms.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Interval between modification time checks
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Run `task` once, then again whenever `input` changes, until interrupted.
+///
+/// Errors of `task` are reported and watching continues, so a half-saved
+/// file does not end the session.
+pub fn watch(input: &Path, mut task: impl FnMut() -> Result<()>) -> Result<()> {
+    if crate::commands::is_stdio(input) {
+        anyhow::bail!("Cannot watch stdin");
+    }
+    let mut last = stamp(input).context("Failed to read input file")?;
+    run(&mut task);
+    eprintln!("Watching {} for changes (Ctrl+C to stop)", input.display());
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        // The file may be missing for a moment while an editor replaces it
+        let Ok(current) = stamp(input) else {
+            continue;
+        };
+        if current == last {
+            continue;
+        }
+
+        // Wait until the writer is done
+        thread::sleep(POLL_INTERVAL);
+        last = stamp(input).unwrap_or(current);
+        eprintln!("\n{} changed", input.display());
+        run(&mut task);
+    }
+}
+
+/// Modification time and size; the size catches writes within one
+/// timestamp tick
+fn stamp(path: &Path) -> std::io::Result<(SystemTime, u64)> {
+    let metadata = fs::metadata(path)?;
+    Ok((metadata.modified()?, metadata.len()))
+}
+
+fn run(task: &mut impl FnMut() -> Result<()>) {
+    if let Err(e) = task() {
+        eprintln!("Error: {e:#}");
+    }
+}
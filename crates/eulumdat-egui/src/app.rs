@@ -3,7 +3,7 @@
 use atla::LuminaireOpticalData;
 use eframe::egui::{self, Color32, DragValue, Margin, RichText, Rounding, TextureHandle, Vec2};
 use eulumdat::compare::{PhotometricComparison, Significance};
-use eulumdat::diagram::{CartesianDiagram, ConeDiagram, PolarDiagram};
+use eulumdat::diagram::{CartesianDiagram, ConeDiagram, PolarDiagram, RadialScale};
 use eulumdat::{Eulumdat, IesExporter, PhotometricCalculations};
 use eulumdat_i18n::{Language, Locale};
 use std::path::PathBuf;
@@ -11,13 +11,13 @@ use std::path::PathBuf;
 use crate::diagram::Butterfly3DRenderer;
 use crate::templates::{self, Template};
 use crate::ui::{
-    diagram_panel::{generate_svg_with_height, DiagramParams},
+    diagram_panel::{generate_svg_with_height, DiagramParams, NativeDiagramCache},
     render_info_panel, render_main_tab_bar, render_sub_tab_bar,
     tabs::{
         render_dimensions_tab, render_general_tab, render_intensity_tab, render_lamps_tab,
         render_optical_tab, render_validation_tab, IntensityTabState,
     },
-    DiagramType, MainTab, SubTab,
+    DiagramType, Library, MainTab, MeasureState, SubTab,
 };
 
 /// Compare display mode
@@ -80,10 +80,16 @@ pub struct EulumdatApp {
     pub error: Option<String>,
     /// Use dark theme for diagrams
     pub dark_theme: bool,
-    /// Cached texture for current diagram
+    /// Cached texture for the current diagram, if drawn from SVG
     texture: Option<TextureHandle>,
-    /// Whether texture needs refresh
+    /// Whether the current diagram (texture or native) needs a refresh
     texture_dirty: bool,
+    /// Polar, cartesian or heatmap diagram drawn with native widgets
+    native_diagram: NativeDiagramCache,
+    /// Pinned measurement markers on the current diagram
+    measure: MeasureState,
+    /// Opened catalog index, shown in the library panel
+    library: Option<Library>,
     /// Show info panel
     pub show_info: bool,
     /// Current main tab
@@ -102,8 +108,18 @@ pub struct EulumdatApp {
     pub tilt_angle: f64,
     /// Area size for isolux diagram (meters, half-width)
     pub area_size: f64,
+    /// Throw distance for the beam footprint diagram (meters)
+    pub throw_distance: f64,
     /// Log scale for floodlight diagram
     pub log_scale: bool,
+    /// Datasheet style (labelled contour lines) for isolux diagram
+    pub isolux_datasheet: bool,
+    /// Units of cone and isolux annotations (m/lx or ft/fc)
+    pub units: eulumdat::UnitSystem,
+    /// Radial scale of the polar diagram
+    pub radial_scale: RadialScale,
+    /// Batwing callouts on the cartesian diagram
+    pub batwing_callouts: bool,
     /// Selected C-plane for per-plane diagrams (None = all)
     pub selected_c_plane: Option<f64>,
     /// Compare file B
@@ -141,6 +157,9 @@ impl EulumdatApp {
             dark_theme: false,
             texture: None,
             texture_dirty: true,
+            native_diagram: NativeDiagramCache::default(),
+            measure: MeasureState::default(),
+            library: None,
             show_info: true,
             main_tab: MainTab::Diagrams,
             sub_tab: SubTab::Polar,
@@ -150,7 +169,12 @@ impl EulumdatApp {
             greenhouse_height: 2.0,
             tilt_angle: 0.0,
             area_size: 20.0,
+            throw_distance: 10.0,
             log_scale: false,
+            isolux_datasheet: false,
+            units: eulumdat::UnitSystem::Metric,
+            radial_scale: RadialScale::Linear,
+            batwing_callouts: false,
             selected_c_plane: None,
             compare_ldt: None,
             compare_file_name: String::new(),
@@ -179,6 +203,7 @@ impl EulumdatApp {
         self.atla_doc = None;
         self.texture = None;
         self.texture_dirty = true;
+        self.measure.clear();
 
         let content = match std::fs::read(&path) {
             Ok(bytes) => {
@@ -304,14 +329,18 @@ impl EulumdatApp {
 
         match self.sub_tab {
             SubTab::Polar => {
-                let diagram = eulumdat::diagram::PolarDiagram::from_eulumdat(ldt);
+                let diagram = eulumdat::diagram::PolarDiagram::from_eulumdat(ldt)
+                    .with_radial_scale(self.radial_scale);
                 let summary = eulumdat::PhotometricSummary::from_eulumdat(ldt);
                 let theme = self.svg_theme();
                 Some(diagram.to_svg_with_summary(800.0, 800.0, &theme, &summary))
             }
             SubTab::Cartesian => {
-                let diagram =
+                let mut diagram =
                     eulumdat::diagram::CartesianDiagram::from_eulumdat(ldt, 800.0, 600.0, 8);
+                if self.batwing_callouts {
+                    diagram = diagram.with_batwing_annotations(ldt);
+                }
                 let summary = eulumdat::PhotometricSummary::from_eulumdat(ldt);
                 let theme = self.svg_theme();
                 Some(diagram.to_svg_with_summary(800.0, 600.0, &theme, &summary))
@@ -345,6 +374,17 @@ impl EulumdatApp {
                 let theme = self.svg_theme();
                 Some(diagram.to_svg(800.0, 600.0, &theme))
             }
+            SubTab::Footprint => {
+                let params = eulumdat::diagram::FootprintParams {
+                    throw_distance: self.throw_distance,
+                    tilt_angle: self.tilt_angle,
+                    grid_resolution: 100,
+                };
+                let diagram =
+                    eulumdat::diagram::FootprintDiagram::from_eulumdat(ldt, 800.0, 640.0, params);
+                let theme = self.svg_theme();
+                Some(diagram.to_svg(800.0, 640.0, &theme))
+            }
             SubTab::Spectral => {
                 let theme = if self.dark_theme {
                     atla::spectral::SpectralTheme::dark_with_locale(&self.locale)
@@ -373,6 +413,40 @@ impl EulumdatApp {
                     None
                 }
             }
+            SubTab::Chromaticity => {
+                let theme = if self.dark_theme {
+                    atla::spectral::SpectralTheme::dark_with_locale(&self.locale)
+                } else {
+                    atla::spectral::SpectralTheme::light_with_locale(&self.locale)
+                };
+                let spd = atla
+                    .emitters
+                    .iter()
+                    .filter_map(|e| e.spectral_distribution.clone())
+                    .next()
+                    .or_else(|| {
+                        let emitter = atla.emitters.first()?;
+                        let cri = emitter.color_rendering.as_ref().and_then(|cr| cr.ra);
+                        Some(atla::spectral::synthesize_spectrum(emitter.cct?, cri))
+                    })?;
+                let diagram = atla::chromaticity::ChromaticityDiagram::from_spectral(&spd);
+                Some(diagram.to_svg(780.0, 800.0, &theme))
+            }
+            SubTab::Tm30 => {
+                let theme = if self.dark_theme {
+                    atla::tm30::Tm30Theme::dark()
+                } else {
+                    atla::tm30::Tm30Theme::light()
+                };
+                // TM-30 needs measured spectral data
+                let spd = atla
+                    .emitters
+                    .iter()
+                    .filter_map(|e| e.spectral_distribution.as_ref())
+                    .next()?;
+                let tm30 = atla::tm30::calculate_tm30(spd)?;
+                Some(tm30.to_svg(800.0, 800.0, &theme))
+            }
             SubTab::Greenhouse => {
                 let theme = if self.dark_theme {
                     atla::greenhouse::GreenhouseTheme::dark()
@@ -395,6 +469,11 @@ impl EulumdatApp {
                 let theme = self.svg_theme();
                 Some(diagram.to_lcs_svg(800.0, 504.0, &theme))
             }
+            SubTab::Glare => {
+                let diagram = eulumdat::diagram::GlareDiagram::from_eulumdat(ldt);
+                let theme = self.svg_theme();
+                Some(diagram.to_svg(800.0, 800.0, &theme))
+            }
             _ => None,
         }
     }
@@ -405,6 +484,20 @@ impl EulumdatApp {
         } else {
             eulumdat::diagram::SvgTheme::light_with_locale(&self.locale)
         }
+        .with_units(self.units)
+    }
+
+    /// Toggle between metric and imperial diagram annotations
+    fn units_checkbox(&mut self, ui: &mut egui::Ui) {
+        let mut imperial = self.units == eulumdat::UnitSystem::Imperial;
+        if ui.checkbox(&mut imperial, "Imperial (ft, fc)").changed() {
+            self.units = if imperial {
+                eulumdat::UnitSystem::Imperial
+            } else {
+                eulumdat::UnitSystem::Metric
+            };
+            self.texture_dirty = true;
+        }
     }
 
     /// Export as IES
@@ -433,6 +526,18 @@ impl EulumdatApp {
         }
     }
 
+    fn open_library_dialog(&mut self) {
+        if let Some(path) = rfd::FileDialog::new()
+            .add_filter("Catalog index", &["json"])
+            .pick_file()
+        {
+            match Library::open(&path) {
+                Ok(library) => self.library = Some(library),
+                Err(e) => self.error = Some(format!("{e:#}")),
+            }
+        }
+    }
+
     /// Render the welcome/empty state
     fn render_welcome(&mut self, ui: &mut egui::Ui) {
         let available = ui.available_size();
@@ -586,21 +691,57 @@ impl EulumdatApp {
             return;
         }
 
-        // For other diagrams, render SVG to texture
         let available_size = ui.available_size();
         let size = available_size.min_elem() * 0.95;
 
-        if self.texture_dirty || self.texture.is_none() {
-            let params = DiagramParams {
-                mounting_height: self.mounting_height,
-                tilt_angle: self.tilt_angle,
-                area_size: self.area_size,
-                log_scale: self.log_scale,
-                c_plane: self.selected_c_plane,
+        let params = DiagramParams {
+            mounting_height: self.mounting_height,
+            tilt_angle: self.tilt_angle,
+            area_size: self.area_size,
+            throw_distance: self.throw_distance,
+            log_scale: self.log_scale,
+            isolux_datasheet: self.isolux_datasheet,
+            units: self.units,
+            radial_scale: self.radial_scale,
+            batwing: self.batwing_callouts,
+            c_plane: self.selected_c_plane,
+        };
+
+        let diagram_type = self.sub_tab_to_diagram_type();
+
+        // Polar, cartesian and heatmap are drawn natively from a cached diagram
+        if let Some(diagram) = self.native_diagram.get(
+            ldt,
+            diagram_type,
+            &params,
+            available_size,
+            self.texture_dirty,
+        ) {
+            self.texture = None;
+            self.texture_dirty = false;
+            let theme = if self.dark_theme {
+                eulumdat_ui::Theme::dark()
+            } else {
+                eulumdat_ui::Theme::light()
             };
+            ui.vertical_centered(|ui| {
+                let response = diagram.show(ui, ldt, &theme);
+                crate::ui::measure::show_measure_overlay(
+                    ui,
+                    response,
+                    ldt,
+                    diagram,
+                    &mut self.measure,
+                );
+            });
+            return;
+        }
+
+        // Other diagrams are rendered from SVG to a texture
+        if self.texture_dirty || self.texture.is_none() {
             if let Some(svg) = generate_svg_with_height(
                 ldt,
-                self.sub_tab_to_diagram_type(),
+                diagram_type,
                 size as f64,
                 size as f64,
                 self.dark_theme,
@@ -669,9 +810,13 @@ impl EulumdatApp {
             SubTab::Cone => DiagramType::Cone,
             SubTab::BugRating => DiagramType::Bug,
             SubTab::Lcs => DiagramType::Lcs,
+            SubTab::Glare => DiagramType::Glare,
             SubTab::Spectral => DiagramType::Spectral,
+            SubTab::Chromaticity => DiagramType::Chromaticity,
+            SubTab::Tm30 => DiagramType::Tm30,
             SubTab::Greenhouse => DiagramType::Greenhouse,
             SubTab::Isolux => DiagramType::Isolux,
+            SubTab::Footprint => DiagramType::Footprint,
             SubTab::Isocandela => DiagramType::Isocandela,
             SubTab::Floodlight => DiagramType::Floodlight,
             _ => DiagramType::Polar,
@@ -993,7 +1138,12 @@ impl EulumdatApp {
                     mounting_height: self.mounting_height,
                     tilt_angle: self.tilt_angle,
                     area_size: self.area_size,
+                    throw_distance: self.throw_distance,
                     log_scale: self.log_scale,
+                    isolux_datasheet: self.isolux_datasheet,
+                    units: self.units,
+                    radial_scale: self.radial_scale,
+                    batwing: self.batwing_callouts,
                     c_plane: None,
                 };
 
@@ -1345,6 +1495,7 @@ impl eframe::App for EulumdatApp {
         let templates_label = self.locale.ui.header.templates.clone();
         let export_label = self.locale.ui.tabs.export.clone();
         let export_svg_label = self.locale.ui.file.export_svg.clone();
+        let export_png_label = self.locale.ui.header.export_png.clone();
         let export_ies_label = self.locale.ui.file.export_ies.clone();
         let export_ldt_label = self.locale.ui.file.export_ldt.clone();
         let close_label = self.locale.ui.actions.close.clone();
@@ -1360,6 +1511,10 @@ impl eframe::App for EulumdatApp {
                         self.open_file_dialog();
                         ui.close_menu();
                     }
+                    if ui.button("Open Library...").clicked() {
+                        self.open_library_dialog();
+                        ui.close_menu();
+                    }
 
                     ui.menu_button(&templates_label, |ui| {
                         for template in templates::all_templates() {
@@ -1386,6 +1541,25 @@ impl eframe::App for EulumdatApp {
                                 }
                                 ui.close_menu();
                             }
+                            if ui.button(&export_png_label).clicked() {
+                                if let Some(svg) = self.generate_current_svg() {
+                                    if let Some(path) = rfd::FileDialog::new()
+                                        .add_filter("PNG", &["png"])
+                                        .set_file_name("diagram.png")
+                                        .save_file()
+                                    {
+                                        // 2x for HiDPI, sRGB-tagged so colors match the app
+                                        let options = crate::render::RasterOptions::natural()
+                                            .with_scale_factor(2.0);
+                                        if let Ok(png) =
+                                            crate::render::render_svg_to_png(&svg, &options)
+                                        {
+                                            let _ = std::fs::write(path, png);
+                                        }
+                                    }
+                                }
+                                ui.close_menu();
+                            }
                             if ui.button(&export_ies_label).clicked() {
                                 if let Some(ies) = self.export_ies() {
                                     if let Some(path) = rfd::FileDialog::new()
@@ -1496,6 +1670,7 @@ impl eframe::App for EulumdatApp {
                             {
                                 self.texture_dirty = true;
                             }
+                            self.units_checkbox(ui);
                         } else if self.sub_tab == SubTab::Greenhouse {
                             ui.separator();
                             ui.label(&self.locale.diagram.greenhouse.max_height);
@@ -1548,6 +1723,78 @@ impl eframe::App for EulumdatApp {
                             {
                                 self.texture_dirty = true;
                             }
+                            if ui
+                                .checkbox(&mut self.isolux_datasheet, "Datasheet style")
+                                .changed()
+                            {
+                                self.texture_dirty = true;
+                            }
+                            self.units_checkbox(ui);
+                        } else if self.sub_tab == SubTab::Footprint {
+                            ui.separator();
+                            ui.label("Throw:");
+                            if ui
+                                .add(
+                                    DragValue::new(&mut self.throw_distance)
+                                        .speed(0.1)
+                                        .range(1.0..=200.0)
+                                        .suffix(" m"),
+                                )
+                                .changed()
+                            {
+                                self.texture_dirty = true;
+                            }
+                            ui.label("Tilt:");
+                            if ui
+                                .add(
+                                    DragValue::new(&mut self.tilt_angle)
+                                        .speed(0.5)
+                                        .range(0.0..=80.0)
+                                        .suffix("°"),
+                                )
+                                .changed()
+                            {
+                                self.texture_dirty = true;
+                            }
+                            self.units_checkbox(ui);
+                        } else if self.sub_tab == SubTab::Cartesian {
+                            ui.separator();
+                            if ui
+                                .checkbox(&mut self.batwing_callouts, "Batwing callouts")
+                                .changed()
+                            {
+                                self.texture_dirty = true;
+                            }
+                        } else if self.sub_tab == SubTab::Polar {
+                            ui.separator();
+                            ui.label("Scale:");
+                            let old_scale = self.radial_scale;
+                            egui::ComboBox::from_id_salt("radial_scale")
+                                .selected_text(match self.radial_scale {
+                                    RadialScale::Linear => "Linear",
+                                    RadialScale::SquareRoot => "Square root",
+                                    RadialScale::Logarithmic => "Logarithmic",
+                                })
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(
+                                        &mut self.radial_scale,
+                                        RadialScale::Linear,
+                                        "Linear",
+                                    );
+                                    ui.selectable_value(
+                                        &mut self.radial_scale,
+                                        RadialScale::SquareRoot,
+                                        "Square root",
+                                    );
+                                    ui.selectable_value(
+                                        &mut self.radial_scale,
+                                        RadialScale::Logarithmic,
+                                        "Logarithmic",
+                                    );
+                                });
+                            if old_scale != self.radial_scale {
+                                self.texture_dirty = true;
+                            }
                         } else if self.sub_tab == SubTab::Floodlight {
                             ui.separator();
                             if ui.checkbox(&mut self.log_scale, "Log scale").changed() {
@@ -1653,6 +1900,34 @@ impl eframe::App for EulumdatApp {
                 });
             });
 
+        // Library panel (left side)
+        let mut library_file = None;
+        let mut close_library = false;
+        if let Some(library) = &mut self.library {
+            egui::SidePanel::left("library_panel")
+                .default_width(300.0)
+                .frame(
+                    egui::Frame::none()
+                        .fill(Color32::from_rgb(248, 250, 252))
+                        .inner_margin(Margin::same(12.0)),
+                )
+                .show(ctx, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new("Library").size(16.0).strong());
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            close_library = ui.small_button("x").clicked();
+                        });
+                    });
+                    library_file = library.show(ui);
+                });
+        }
+        if close_library {
+            self.library = None;
+        }
+        if let Some(path) = library_file {
+            self.load_file(path);
+        }
+
         // Info panel (right side)
         if self.show_info && self.eulumdat.is_some() {
             egui::SidePanel::right("info_panel")
@@ -1713,12 +1988,16 @@ impl eframe::App for EulumdatApp {
                     | SubTab::Heatmap
                     | SubTab::Cone
                     | SubTab::Isolux
+                    | SubTab::Footprint
                     | SubTab::Isocandela
                     | SubTab::Floodlight
                     | SubTab::Spectral
+                    | SubTab::Chromaticity
+                    | SubTab::Tm30
                     | SubTab::Greenhouse
                     | SubTab::BugRating
-                    | SubTab::Lcs => {
+                    | SubTab::Lcs
+                    | SubTab::Glare => {
                         self.render_diagram(ui);
                     }
 
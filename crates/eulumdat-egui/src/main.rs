@@ -16,27 +16,14 @@ mod ui;
 fn load_icon() -> Option<egui::IconData> {
     let svg_data = include_str!(concat!(env!("OUT_DIR"), "/icon.svg"));
 
-    // Parse SVG and render to pixels
-    let options = resvg::usvg::Options::default();
-    let tree = resvg::usvg::Tree::from_str(svg_data, &options).ok()?;
-
-    let size = 64u32;
-    let mut pixmap = resvg::tiny_skia::Pixmap::new(size, size)?;
-
-    // Fill with transparent background
-    pixmap.fill(resvg::tiny_skia::Color::TRANSPARENT);
-
-    // Calculate scale to fit
-    let tree_size = tree.size();
-    let scale = (size as f32 / tree_size.width()).min(size as f32 / tree_size.height());
-
-    let transform = resvg::tiny_skia::Transform::from_scale(scale, scale);
-    resvg::render(&tree, transform, &mut pixmap.as_mut());
+    // Render with a transparent background, fitted to 64x64
+    let options = render::RasterOptions::new(64, 64).transparent();
+    let pixmap = render::rasterize_svg(svg_data, &options).ok()?;
 
     Some(egui::IconData {
-        rgba: pixmap.take(),
-        width: size,
-        height: size,
+        rgba: render::pixmap_to_rgba(&pixmap),
+        width: pixmap.width(),
+        height: pixmap.height(),
     })
 }
 
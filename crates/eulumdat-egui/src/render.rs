@@ -14,30 +14,81 @@ fn get_fontdb() -> &'static fontdb::Database {
     })
 }
 
-/// Render an SVG string to RGBA pixels
-pub fn render_svg_to_rgba(
-    svg: &str,
-    max_width: u32,
-    max_height: u32,
-) -> Result<(Vec<u8>, u32, u32), String> {
+/// Background of a rasterized SVG.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Background {
+    /// Opaque background color (sRGB)
+    Solid(u8, u8, u8),
+    /// Keep the SVG's own background; uncovered pixels stay transparent
+    Transparent,
+}
+
+/// Options for rasterizing an SVG.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RasterOptions {
+    /// Maximum width of the 1x image in pixels (0 = SVG size)
+    pub max_width: u32,
+    /// Maximum height of the 1x image in pixels (0 = SVG size)
+    pub max_height: u32,
+    /// Device pixel ratio (2.0/3.0 for HiDPI embedding)
+    pub scale_factor: f32,
+    /// Background fill
+    pub background: Background,
+}
+
+impl RasterOptions {
+    /// Fit within `max_width` × `max_height` at 1x on white.
+    pub fn new(max_width: u32, max_height: u32) -> Self {
+        Self {
+            max_width,
+            max_height,
+            scale_factor: 1.0,
+            background: Background::Solid(255, 255, 255),
+        }
+    }
+
+    /// Keep the SVG's own size at 1x on white.
+    pub fn natural() -> Self {
+        Self::new(0, 0)
+    }
+
+    /// Render at a device pixel ratio.
+    pub fn with_scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Render with a transparent background.
+    pub fn transparent(mut self) -> Self {
+        self.background = Background::Transparent;
+        self
+    }
+}
+
+/// Rasterize an SVG string into a premultiplied pixmap.
+pub fn rasterize_svg(svg: &str, options: &RasterOptions) -> Result<Pixmap, String> {
     // Parse SVG with font database for text rendering
-    let options = Options {
+    let usvg_options = Options {
         fontdb: std::sync::Arc::new(get_fontdb().clone()),
         ..Default::default()
     };
-    let tree = Tree::from_str(svg, &options).map_err(|e| e.to_string())?;
+    let tree = Tree::from_str(svg, &usvg_options).map_err(|e| e.to_string())?;
 
     // Get original size
     let size = tree.size();
     let orig_width = size.width();
     let orig_height = size.height();
 
-    // Calculate scale to fit within max dimensions while preserving aspect ratio
-    // Use 2x resolution for crisp text rendering (like Retina displays)
-    let scale_x = max_width as f32 / orig_width;
-    let scale_y = max_height as f32 / orig_height;
-    let base_scale = scale_x.min(scale_y);
-    let scale = (base_scale * 2.0).min(4.0); // Render at 2x for crisp text, max 4x
+    // Fit within max dimensions while preserving aspect ratio (upscaling at
+    // most 2x), then apply the device pixel ratio
+    let base_scale = if options.max_width == 0 || options.max_height == 0 {
+        1.0
+    } else {
+        let scale_x = options.max_width as f32 / orig_width;
+        let scale_y = options.max_height as f32 / orig_height;
+        scale_x.min(scale_y).min(2.0)
+    };
+    let scale = base_scale * options.scale_factor.clamp(0.25, 4.0);
 
     let final_width = (orig_width * scale).ceil() as u32;
     let final_height = (orig_height * scale).ceil() as u32;
@@ -46,15 +97,58 @@ pub fn render_svg_to_rgba(
     let mut pixmap = Pixmap::new(final_width, final_height)
         .ok_or_else(|| "Failed to create pixmap".to_string())?;
 
-    // Fill with white background
-    pixmap.fill(resvg::tiny_skia::Color::WHITE);
+    match options.background {
+        Background::Solid(r, g, b) => {
+            pixmap.fill(resvg::tiny_skia::Color::from_rgba8(r, g, b, 255));
+        }
+        Background::Transparent => pixmap.fill(resvg::tiny_skia::Color::TRANSPARENT),
+    }
 
     // Render SVG
     let transform = resvg::tiny_skia::Transform::from_scale(scale, scale);
     resvg::render(&tree, transform, &mut pixmap.as_mut());
 
-    // Return RGBA pixels
-    Ok((pixmap.take(), final_width, final_height))
+    Ok(pixmap)
+}
+
+/// Straight-alpha sRGB RGBA pixels of a pixmap.
+///
+/// tiny-skia stores premultiplied colors; consumers such as egui textures
+/// and window icons expect straight alpha, otherwise anti-aliased and
+/// transparent edges come out too dark.
+pub fn pixmap_to_rgba(pixmap: &Pixmap) -> Vec<u8> {
+    pixmap
+        .pixels()
+        .iter()
+        .flat_map(|p| {
+            let c = p.demultiply();
+            [c.red(), c.green(), c.blue(), c.alpha()]
+        })
+        .collect()
+}
+
+/// Render an SVG string to RGBA pixels
+pub fn render_svg_to_rgba(
+    svg: &str,
+    max_width: u32,
+    max_height: u32,
+) -> Result<(Vec<u8>, u32, u32), String> {
+    // Use 2x resolution for crisp text rendering (like Retina displays)
+    let options = RasterOptions::new(max_width, max_height).with_scale_factor(2.0);
+    let pixmap = rasterize_svg(svg, &options)?;
+    Ok((pixmap_to_rgba(&pixmap), pixmap.width(), pixmap.height()))
+}
+
+/// Render an SVG string to an sRGB-tagged PNG.
+///
+/// The PNG records the scale factor as pixel density, so a 2x export
+/// embeds at the same physical size as the 1x diagram.
+pub fn render_svg_to_png(svg: &str, options: &RasterOptions) -> Result<Vec<u8>, String> {
+    let png = rasterize_svg(svg, options)?
+        .encode_png()
+        .map_err(|e| e.to_string())?;
+    eulumdat::diagram::tag_srgb_png(&png, options.scale_factor as f64)
+        .ok_or_else(|| "Failed to tag PNG".to_string())
 }
 
 /// Convert RGBA to egui ColorImage
@@ -1,12 +1,14 @@
 //! Diagram panel rendering
 
-use eframe::egui::{self, Color32, Sense, TextureHandle, Ui};
+use eframe::egui::{self, Color32, Response, Sense, TextureHandle, Ui, Vec2};
 use eulumdat::diagram::{
-    ButterflyDiagram, CartesianDiagram, ConeDiagram, FloodlightCartesianDiagram, HeatmapDiagram,
-    IsocandelaDiagram, IsoluxDiagram, IsoluxParams, PolarDiagram, SvgTheme, YScale,
+    ButterflyDiagram, CartesianDiagram, ConeDiagram, FloodlightCartesianDiagram, FootprintDiagram,
+    FootprintParams, GlareDiagram, HeatmapDiagram, IsocandelaDiagram, IsoluxDiagram, IsoluxParams,
+    PolarDiagram, RadialScale, SvgTheme, YScale,
 };
-use eulumdat::{BugDiagram, Eulumdat, PhotometricCalculations, PhotometricSummary};
+use eulumdat::{BugDiagram, Eulumdat, PhotometricCalculations, PhotometricSummary, UnitSystem};
 use eulumdat_i18n::Locale;
+use eulumdat_ui::{CartesianWidget, HeatmapWidget, PolarWidget, Theme};
 
 use crate::diagram::Butterfly3DRenderer;
 use crate::render::{render_svg_to_rgba, rgba_to_color_image};
@@ -21,13 +23,17 @@ pub enum DiagramType {
     Heatmap,
     Bug,
     Lcs,
+    Glare,
     Cone,
     BeamAngle,
     Isolux,
+    Footprint,
     Isocandela,
     Floodlight,
     // ATLA-specific types (handled separately)
     Spectral,
+    Chromaticity,
+    Tm30,
     Greenhouse,
 }
 
@@ -41,12 +47,16 @@ impl DiagramType {
             DiagramType::Heatmap => "Heatmap",
             DiagramType::Bug => "BUG",
             DiagramType::Lcs => "LCS",
+            DiagramType::Glare => "Glare",
             DiagramType::Cone => "Cone",
             DiagramType::BeamAngle => "Beam",
             DiagramType::Isolux => "Isolux",
+            DiagramType::Footprint => "Footprint",
             DiagramType::Isocandela => "Isocandela",
             DiagramType::Floodlight => "Floodlight",
             DiagramType::Spectral => "Spectral",
+            DiagramType::Chromaticity => "Chromaticity",
+            DiagramType::Tm30 => "TM-30",
             DiagramType::Greenhouse => "Greenhouse",
         }
     }
@@ -60,9 +70,11 @@ impl DiagramType {
             DiagramType::Heatmap,
             DiagramType::Bug,
             DiagramType::Lcs,
+            DiagramType::Glare,
             DiagramType::Cone,
             DiagramType::BeamAngle,
             DiagramType::Isolux,
+            DiagramType::Footprint,
             DiagramType::Isocandela,
             DiagramType::Floodlight,
         ]
@@ -74,7 +86,12 @@ pub struct DiagramParams {
     pub mounting_height: f64,
     pub tilt_angle: f64,
     pub area_size: f64,
+    pub throw_distance: f64,
     pub log_scale: bool,
+    pub isolux_datasheet: bool,
+    pub units: UnitSystem,
+    pub radial_scale: RadialScale,
+    pub batwing: bool,
     pub c_plane: Option<f64>,
 }
 
@@ -84,12 +101,115 @@ impl Default for DiagramParams {
             mounting_height: 3.0,
             tilt_angle: 0.0,
             area_size: 20.0,
+            throw_distance: 10.0,
             log_scale: false,
+            isolux_datasheet: false,
+            units: UnitSystem::Metric,
+            radial_scale: RadialScale::Linear,
+            batwing: false,
             c_plane: None,
         }
     }
 }
 
+/// Diagram drawn with the native `eulumdat-ui` widgets instead of an SVG
+/// texture
+pub enum NativeDiagram {
+    Polar(PolarDiagram),
+    Cartesian(CartesianDiagram),
+    Heatmap(HeatmapDiagram),
+}
+
+impl NativeDiagram {
+    /// Build the diagram for a widget of `size` (from the widget's
+    /// `size_for`); `None` for types drawn from SVG
+    pub fn build(
+        ldt: &Eulumdat,
+        diagram_type: DiagramType,
+        params: &DiagramParams,
+        size: Vec2,
+    ) -> Option<Self> {
+        let (width, height) = (size.x as f64, size.y as f64);
+        Some(match diagram_type {
+            DiagramType::Polar => {
+                let polar = match params.c_plane {
+                    Some(cp) => PolarDiagram::from_eulumdat_for_plane(ldt, cp),
+                    None => PolarDiagram::from_eulumdat(ldt),
+                };
+                Self::Polar(polar.with_radial_scale(params.radial_scale))
+            }
+            DiagramType::Cartesian => {
+                let mut cartesian = match params.c_plane {
+                    Some(cp) => CartesianDiagram::from_eulumdat_for_plane(ldt, cp, width, height),
+                    None => CartesianDiagram::from_eulumdat(ldt, width, height, 8),
+                };
+                if params.batwing {
+                    cartesian = cartesian.with_batwing_annotations(ldt);
+                }
+                Self::Cartesian(cartesian)
+            }
+            DiagramType::Heatmap => {
+                Self::Heatmap(HeatmapDiagram::from_eulumdat(ldt, width, height))
+            }
+            _ => return None,
+        })
+    }
+
+    /// Widget size the diagram of `diagram_type` is built for; zero for the
+    /// size-independent polar diagram, `None` for types drawn from SVG
+    fn widget_size(diagram_type: DiagramType, available: Vec2) -> Option<Vec2> {
+        match diagram_type {
+            DiagramType::Polar => Some(Vec2::ZERO),
+            DiagramType::Cartesian => Some(CartesianWidget::size_for(available)),
+            DiagramType::Heatmap => Some(HeatmapWidget::size_for(available)),
+            _ => None,
+        }
+    }
+
+    /// Draw the diagram and return the response of its plot area
+    pub fn show(&self, ui: &mut Ui, ldt: &Eulumdat, theme: &Theme) -> Response {
+        match self {
+            Self::Polar(polar) => PolarWidget::show_diagram(ui, ldt, polar, theme),
+            Self::Cartesian(cartesian) => CartesianWidget::show_diagram(ui, ldt, cartesian, theme),
+            Self::Heatmap(heatmap) => HeatmapWidget::show_diagram(ui, heatmap, theme),
+        }
+    }
+}
+
+/// Native diagram kept between frames until the data, the parameters or
+/// the widget size change
+#[derive(Default)]
+pub struct NativeDiagramCache {
+    /// Diagram type and widget size (whole points) of `diagram`
+    key: Option<(DiagramType, [u32; 2])>,
+    diagram: Option<NativeDiagram>,
+}
+
+impl NativeDiagramCache {
+    /// The native diagram of `diagram_type` for the `available` space,
+    /// rebuilt if `dirty` or the type or size changed; `None` (and the cache
+    /// emptied) for types drawn from SVG
+    pub fn get(
+        &mut self,
+        ldt: &Eulumdat,
+        diagram_type: DiagramType,
+        params: &DiagramParams,
+        available: Vec2,
+        dirty: bool,
+    ) -> Option<&NativeDiagram> {
+        let Some(size) = NativeDiagram::widget_size(diagram_type, available) else {
+            *self = Self::default();
+            return None;
+        };
+        let key = (diagram_type, [size.x.round() as u32, size.y.round() as u32]);
+        if dirty || self.key != Some(key) {
+            self.diagram = NativeDiagram::build(ldt, diagram_type, params, size);
+            self.key = Some(key);
+        }
+        self.diagram.as_ref()
+    }
+}
+
 /// Generate SVG for a diagram type
 pub fn generate_svg(
     ldt: &Eulumdat,
@@ -126,29 +246,32 @@ pub fn generate_svg_with_height(
         SvgTheme::dark_with_locale(locale)
     } else {
         SvgTheme::light_with_locale(locale)
-    };
+    }
+    .with_units(params.units);
 
     let summary = PhotometricSummary::from_eulumdat(ldt);
 
     Some(match diagram_type {
         DiagramType::Polar => {
             if let Some(cp) = params.c_plane {
-                let polar = PolarDiagram::from_eulumdat_for_plane(ldt, cp);
+                let polar = PolarDiagram::from_eulumdat_for_plane(ldt, cp)
+                    .with_radial_scale(params.radial_scale);
                 polar.to_svg_with_summary(width, height, &theme, &summary)
             } else {
-                let polar = PolarDiagram::from_eulumdat(ldt);
+                let polar = PolarDiagram::from_eulumdat(ldt).with_radial_scale(params.radial_scale);
                 polar.to_svg_with_summary(width, height, &theme, &summary)
             }
         }
         DiagramType::Cartesian => {
-            if let Some(cp) = params.c_plane {
-                let cartesian =
-                    CartesianDiagram::from_eulumdat_for_plane(ldt, cp, width, height * 0.75);
-                cartesian.to_svg_with_summary(width, height * 0.75, &theme, &summary)
+            let mut cartesian = if let Some(cp) = params.c_plane {
+                CartesianDiagram::from_eulumdat_for_plane(ldt, cp, width, height * 0.75)
             } else {
-                let cartesian = CartesianDiagram::from_eulumdat(ldt, width, height * 0.75, 8);
-                cartesian.to_svg_with_summary(width, height * 0.75, &theme, &summary)
+                CartesianDiagram::from_eulumdat(ldt, width, height * 0.75, 8)
+            };
+            if params.batwing {
+                cartesian = cartesian.with_batwing_annotations(ldt);
             }
+            cartesian.to_svg_with_summary(width, height * 0.75, &theme, &summary)
         }
         DiagramType::Butterfly | DiagramType::Butterfly3D => {
             let butterfly = ButterflyDiagram::from_eulumdat(ldt, width, height * 0.8, 60.0);
@@ -166,6 +289,7 @@ pub fn generate_svg_with_height(
             let bug = BugDiagram::from_eulumdat(ldt);
             bug.to_lcs_svg(width, height * 0.75, &theme)
         }
+        DiagramType::Glare => GlareDiagram::from_eulumdat(ldt).to_svg(width, height, &theme),
         DiagramType::Cone => {
             if let Some(cp) = params.c_plane {
                 let cone = ConeDiagram::from_eulumdat_for_plane(ldt, mounting_height, cp);
@@ -189,8 +313,28 @@ pub fn generate_svg_with_height(
                 area_half_depth: params.area_size,
                 grid_resolution: 60,
             };
-            let diagram = IsoluxDiagram::from_eulumdat(ldt, width, height, isolux_params);
-            diagram.to_svg(width, height, &theme)
+            let diagram = IsoluxDiagram::from_eulumdat_with_units(
+                ldt,
+                width,
+                height,
+                isolux_params,
+                params.units,
+            );
+            if params.isolux_datasheet {
+                diagram.to_svg_datasheet(width, height, &theme, params.units)
+            } else {
+                diagram.to_svg(width, height, &theme)
+            }
+        }
+        DiagramType::Footprint => {
+            let footprint_params = FootprintParams {
+                throw_distance: params.throw_distance,
+                tilt_angle: params.tilt_angle,
+                grid_resolution: 80,
+            };
+            let diagram =
+                FootprintDiagram::from_eulumdat(ldt, width, height * 0.85, footprint_params);
+            diagram.to_svg(width, height * 0.85, &theme)
         }
         DiagramType::Isocandela => {
             let diagram = IsocandelaDiagram::from_eulumdat(ldt, width, height * 0.85);
@@ -207,7 +351,10 @@ pub fn generate_svg_with_height(
             diagram.to_svg(width, height * 0.75, &theme)
         }
         // ATLA-specific types are handled separately via generate_current_svg
-        DiagramType::Spectral | DiagramType::Greenhouse => return None,
+        DiagramType::Spectral
+        | DiagramType::Chromaticity
+        | DiagramType::Tm30
+        | DiagramType::Greenhouse => return None,
     })
 }
 
@@ -0,0 +1,204 @@
+//! Library view of a catalog index
+//!
+//! Reads the JSON index written by `eulumdat index` and lists its entries
+//! with their PNG polar thumbnails. Thumbnails are loaded when their row
+//! first scrolls into view, so large libraries open instantly.
+
+use anyhow::{Context, Result};
+use eframe::egui::{self, Color32, ColorImage, RichText, TextureHandle, TextureOptions, Vec2};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Edge length of the thumbnails in the list
+const THUMBNAIL_SIZE: f32 = 64.0;
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct CatalogIndex {
+    root: String,
+    entries: Vec<LibraryEntry>,
+}
+
+/// The fields of an index entry the view shows
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct LibraryEntry {
+    path: String,
+    luminaire_name: String,
+    manufacturer: String,
+    flux_lm: f64,
+    wattage_w: f64,
+    beam_angle_deg: f64,
+    thumbnail: Option<String>,
+    error: Option<String>,
+}
+
+/// An opened catalog index
+pub struct Library {
+    /// Directory of the index file; thumbnail paths are relative to it
+    base: PathBuf,
+    /// Indexed directory; entry paths are relative to it
+    root: PathBuf,
+    entries: Vec<LibraryEntry>,
+    /// Search text and the indices of the entries matching it
+    filter: String,
+    visible: Vec<usize>,
+    /// Loaded thumbnails by entry index; `None` if missing or unreadable
+    textures: HashMap<usize, Option<TextureHandle>>,
+}
+
+impl Library {
+    /// Read the catalog index at `path`
+    pub fn open(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path).context("Failed to read index")?;
+        let index: CatalogIndex = serde_json::from_str(&json).context("Invalid index file")?;
+        Ok(Self {
+            base: path.parent().unwrap_or(Path::new("")).to_path_buf(),
+            root: PathBuf::from(index.root),
+            visible: (0..index.entries.len()).collect(),
+            entries: index.entries,
+            filter: String::new(),
+            textures: HashMap::new(),
+        })
+    }
+
+    /// Show the list; returns the file of a clicked entry
+    pub fn show(&mut self, ui: &mut egui::Ui) -> Option<PathBuf> {
+        ui.label(RichText::new(self.root.display().to_string()).strong());
+        ui.label(
+            RichText::new(format!(
+                "{} of {} files",
+                self.visible.len(),
+                self.entries.len()
+            ))
+            .size(11.0)
+            .color(Color32::GRAY),
+        );
+        if ui
+            .add(egui::TextEdit::singleline(&mut self.filter).hint_text("Search name or path"))
+            .changed()
+        {
+            self.apply_filter();
+        }
+        ui.separator();
+
+        let mut clicked = None;
+        let row_height = THUMBNAIL_SIZE + ui.spacing().item_spacing.y;
+        egui::ScrollArea::vertical().auto_shrink(false).show_rows(
+            ui,
+            row_height,
+            self.visible.len(),
+            |ui, rows| {
+                for row in rows {
+                    let index = self.visible[row];
+                    if self.show_entry(ui, index) {
+                        clicked = Some(self.root.join(&self.entries[index].path));
+                    }
+                }
+            },
+        );
+        clicked
+    }
+
+    /// One row: thumbnail, name, manufacturer and key metrics
+    fn show_entry(&mut self, ui: &mut egui::Ui, index: usize) -> bool {
+        let texture = self.texture(ui.ctx(), index);
+        let entry = &self.entries[index];
+        let response = ui
+            .horizontal(|ui| {
+                ui.set_height(THUMBNAIL_SIZE);
+                let size = Vec2::splat(THUMBNAIL_SIZE);
+                match texture {
+                    Some(texture) => {
+                        ui.add(egui::Image::new((texture.id(), size)));
+                    }
+                    None => {
+                        let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+                        ui.painter()
+                            .rect_filled(rect, 4.0, Color32::from_rgb(241, 245, 249));
+                    }
+                }
+                ui.vertical(|ui| {
+                    let name = if entry.luminaire_name.is_empty() {
+                        &entry.path
+                    } else {
+                        &entry.luminaire_name
+                    };
+                    ui.label(RichText::new(name).strong());
+                    if let Some(error) = &entry.error {
+                        ui.label(
+                            RichText::new(error)
+                                .size(11.0)
+                                .color(Color32::from_rgb(185, 28, 28)),
+                        );
+                    } else {
+                        if !entry.manufacturer.is_empty() {
+                            ui.label(RichText::new(&entry.manufacturer).size(11.0));
+                        }
+                        ui.label(
+                            RichText::new(format!(
+                                "{:.0} lm · {:.1} W · {:.0}°",
+                                entry.flux_lm, entry.wattage_w, entry.beam_angle_deg
+                            ))
+                            .size(11.0)
+                            .color(Color32::GRAY),
+                        );
+                    }
+                });
+            })
+            .response;
+
+        let response = ui
+            .interact(
+                response.rect,
+                ui.id().with(("library_entry", index)),
+                egui::Sense::click(),
+            )
+            .on_hover_text(&entry.path);
+        if response.hovered() {
+            ui.painter().rect_stroke(
+                response.rect,
+                4.0,
+                egui::Stroke::new(1.0, Color32::from_rgb(59, 130, 246)),
+            );
+        }
+        response.clicked()
+    }
+
+    /// Thumbnail texture of an entry, loaded on first use
+    fn texture(&mut self, ctx: &egui::Context, index: usize) -> Option<TextureHandle> {
+        let entry = &self.entries[index];
+        let base = &self.base;
+        self.textures
+            .entry(index)
+            .or_insert_with(|| {
+                let path = base.join(entry.thumbnail.as_ref()?);
+                let rgba = image::open(path).ok()?.to_rgba8();
+                let size = [rgba.width() as usize, rgba.height() as usize];
+                let image = ColorImage::from_rgba_unmultiplied(size, rgba.as_raw());
+                Some(ctx.load_texture(
+                    format!("library_thumbnail_{index}"),
+                    image,
+                    TextureOptions::LINEAR,
+                ))
+            })
+            .clone()
+    }
+
+    fn apply_filter(&mut self) {
+        let filter = self.filter.to_lowercase();
+        self.visible = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| {
+                filter.is_empty()
+                    || entry.luminaire_name.to_lowercase().contains(&filter)
+                    || entry.manufacturer.to_lowercase().contains(&filter)
+                    || entry.path.to_lowercase().contains(&filter)
+            })
+            .map(|(index, _)| index)
+            .collect();
+    }
+}
@@ -0,0 +1,234 @@
+//! Pinned measurement markers on the native diagrams
+//!
+//! The polar, cartesian and heatmap widgets show the hover readout
+//! themselves; clicking pins it as a marker. Pointer positions are mapped
+//! with the layouts the widgets draw with, on the cached diagram. Markers
+//! keep the angles and value rather than a position, so they survive
+//! resizing.
+
+use eframe::egui::{self, Color32, FontId, Pos2, Rect, Response, Stroke, Ui};
+use eulumdat::Eulumdat;
+use eulumdat_ui::layout::{heatmap_cell_at, CartesianLayout, PolarLayout};
+
+use super::diagram_panel::{DiagramType, NativeDiagram};
+/// A single measurement at a diagram position
+#[derive(Debug, Clone, PartialEq)]
+pub struct Readout {
+    /// C-plane angle in degrees
+    pub c_angle: f64,
+    /// Gamma angle in degrees
+    pub gamma: f64,
+    /// Intensity in cd/klm
+    pub intensity: f64,
+    /// Intensity in cd (using the total lamp flux)
+    pub candela: f64,
+}
+
+impl Readout {
+    /// Short label for markers
+    pub fn short_label(&self) -> String {
+        format!(
+            "C{:.0} γ{:.0}° {:.0}",
+            self.c_angle, self.gamma, self.intensity
+        )
+    }
+}
+
+/// Pinned measurement marker
+#[derive(Debug, Clone)]
+pub struct MeasureMarker {
+    /// Diagram the marker belongs to
+    pub diagram_type: DiagramType,
+    /// Readout at the time the marker was pinned; also places the marker
+    pub readout: Readout,
+}
+
+/// Measurement state kept by the application
+#[derive(Debug, Clone, Default)]
+pub struct MeasureState {
+    /// Pinned markers (click to add, right-click to clear)
+    pub markers: Vec<MeasureMarker>,
+}
+
+impl MeasureState {
+    /// Remove all pinned markers
+    pub fn clear(&mut self) {
+        self.markers.clear();
+    }
+}
+
+impl NativeDiagram {
+    /// Type of the diagram, for matching markers
+    fn diagram_type(&self) -> DiagramType {
+        match self {
+            Self::Polar(_) => DiagramType::Polar,
+            Self::Cartesian(_) => DiagramType::Cartesian,
+            Self::Heatmap(_) => DiagramType::Heatmap,
+        }
+    }
+
+    /// C-planes on the right and left half of the polar diagram
+    fn polar_planes(polar: &eulumdat::diagram::PolarDiagram) -> (Vec<f64>, Vec<f64>) {
+        let mut right = vec![polar.c0_c180_curve.c_angle];
+        if polar.show_c90_c270() {
+            right.push(polar.c90_c270_curve.c_angle);
+        }
+        let left = right.iter().map(|c| (c + 180.0) % 360.0).collect();
+        (right, left)
+    }
+
+    /// Readout at a screen position on the diagram shown in `rect`
+    ///
+    /// Polar and cartesian readouts take the plane whose curve is closest to
+    /// the pointer.
+    pub fn readout_at(&self, ldt: &Eulumdat, rect: Rect, pos: Pos2) -> Option<Readout> {
+        let flux_factor = ldt.total_luminous_flux() / 1000.0;
+        let nearest = |planes: Vec<f64>, gamma: f64, pointer_value: f64| {
+            planes
+                .into_iter()
+                .map(|c| (c, ldt.sample(c, gamma)))
+                .min_by(|a, b| {
+                    (a.1 - pointer_value)
+                        .abs()
+                        .total_cmp(&(b.1 - pointer_value).abs())
+                })
+                .map(|(c_angle, intensity)| Readout {
+                    c_angle,
+                    gamma,
+                    intensity,
+                    candela: intensity * flux_factor,
+                })
+        };
+
+        match self {
+            Self::Polar(polar) => {
+                let (gamma, pointer_value, right) =
+                    PolarLayout::for_diagram(rect, polar).invert(pos)?;
+                let (right_planes, left_planes) = Self::polar_planes(polar);
+                let planes = if right { right_planes } else { left_planes };
+                nearest(planes, gamma, pointer_value)
+            }
+            Self::Cartesian(cartesian) => {
+                let (gamma, pointer_value) = CartesianLayout::new(rect, cartesian).invert(pos)?;
+                let planes = cartesian.curves.iter().map(|curve| curve.c_angle).collect();
+                nearest(planes, gamma, pointer_value)
+            }
+            Self::Heatmap(heatmap) => heatmap_cell_at(heatmap, rect, pos).map(|cell| Readout {
+                c_angle: cell.c_angle,
+                gamma: cell.g_angle,
+                intensity: cell.intensity,
+                candela: cell.candela,
+            }),
+        }
+    }
+
+    /// Screen position of a readout on the diagram shown in `rect`, `None`
+    /// if its plane is not shown
+    fn marker_position(&self, rect: Rect, readout: &Readout) -> Option<Pos2> {
+        let same = |a: f64, b: f64| (a - b).abs() < 1e-6;
+        match self {
+            Self::Polar(polar) => {
+                let layout = PolarLayout::for_diagram(rect, polar);
+                let (right, left) = Self::polar_planes(polar);
+                let pos = layout.point(readout.gamma, readout.intensity);
+                if right.iter().any(|&c| same(c, readout.c_angle)) {
+                    Some(pos)
+                } else if left.iter().any(|&c| same(c, readout.c_angle)) {
+                    Some(Pos2::new(2.0 * layout.center.x - pos.x, pos.y))
+                } else {
+                    None
+                }
+            }
+            Self::Cartesian(cartesian) => cartesian
+                .curves
+                .iter()
+                .any(|curve| same(curve.c_angle, readout.c_angle))
+                .then(|| {
+                    CartesianLayout::new(rect, cartesian).point(readout.gamma, readout.intensity)
+                }),
+            Self::Heatmap(heatmap) => heatmap
+                .cells
+                .iter()
+                .find(|cell| {
+                    same(cell.c_angle, readout.c_angle) && same(cell.g_angle, readout.gamma)
+                })
+                .map(|cell| {
+                    rect.min
+                        + egui::vec2(
+                            (cell.x + cell.width / 2.0) as f32,
+                            (cell.y + cell.height / 2.0) as f32,
+                        )
+                }),
+        }
+    }
+}
+
+/// Pin readouts on clicks and draw the pinned markers over a native diagram
+///
+/// `response` must be the plot response returned by the diagram widget.
+pub fn show_measure_overlay(
+    ui: &mut Ui,
+    response: Response,
+    ldt: &Eulumdat,
+    diagram: &NativeDiagram,
+    state: &mut MeasureState,
+) {
+    let rect = response.rect;
+    let diagram_type = diagram.diagram_type();
+
+    if response.secondary_clicked() {
+        state.clear();
+    } else if response.clicked() {
+        if let Some(pos) = response.interact_pointer_pos() {
+            if let Some(readout) = diagram.readout_at(ldt, rect, pos) {
+                state.markers.push(MeasureMarker {
+                    diagram_type,
+                    readout,
+                });
+            }
+        }
+    }
+
+    let painter = ui.painter_at(rect);
+    let marker_color = Color32::from_rgb(234, 88, 12);
+    for marker in state
+        .markers
+        .iter()
+        .filter(|m| m.diagram_type == diagram_type)
+    {
+        let Some(pos) = diagram.marker_position(rect, &marker.readout) else {
+            continue;
+        };
+        painter.circle_filled(pos, 4.0, marker_color);
+        painter.circle_stroke(pos, 6.0, Stroke::new(1.0, Color32::WHITE));
+        draw_label(
+            &painter,
+            rect,
+            pos,
+            &marker.readout.short_label(),
+            marker_color,
+        );
+    }
+
+    ui.label(
+        egui::RichText::new("Click to pin a reading, right-click to clear")
+            .small()
+            .color(Color32::GRAY),
+    );
+}
+
+/// Draw a marker label on a colored background, kept inside `clip`
+fn draw_label(painter: &egui::Painter, clip: Rect, pos: Pos2, text: &str, color: Color32) {
+    let galley =
+        painter.layout_no_wrap(text.to_string(), FontId::proportional(11.0), Color32::WHITE);
+    let mut label_rect =
+        Rect::from_min_size(pos + egui::vec2(8.0, -galley.size().y - 4.0), galley.size());
+    if label_rect.max.x > clip.max.x {
+        label_rect = label_rect.translate(egui::vec2(-label_rect.width() - 16.0, 0.0));
+    }
+    if label_rect.min.y < clip.min.y {
+        label_rect = label_rect.translate(egui::vec2(0.0, galley.size().y + 8.0));
+    }
+    painter.rect_filled(label_rect.expand(2.0), 3.0, color);
+    painter.galley(label_rect.min, galley, Color32::WHITE);
+}
@@ -2,8 +2,12 @@
 
 pub mod diagram_panel;
 mod info_panel;
+pub mod library;
+pub mod measure;
 pub mod tabs;
 
 pub use diagram_panel::DiagramType;
 pub use info_panel::render_info_panel;
+pub use library::Library;
+pub use measure::MeasureState;
 pub use tabs::{render_main_tab_bar, render_sub_tab_bar, MainTab, SubTab};
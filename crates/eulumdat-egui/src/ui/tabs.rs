@@ -58,13 +58,17 @@ pub enum SubTab {
     Heatmap,
     Cone,
     Isolux,
+    Footprint,
     Isocandela,
     Floodlight,
     // Analysis group
     Spectral,
+    Chromaticity,
+    Tm30,
     Greenhouse,
     BugRating,
     Lcs,
+    Glare,
     Bim,
     // Compare group (single)
     ComparePanel,
@@ -87,12 +91,16 @@ impl SubTab {
             SubTab::Heatmap => "Heatmap",
             SubTab::Cone => "Cone",
             SubTab::Isolux => "Isolux",
+            SubTab::Footprint => "Footprint",
             SubTab::Isocandela => "Isocandela",
             SubTab::Floodlight => "Floodlight",
             SubTab::Spectral => "Spectral",
+            SubTab::Chromaticity => "Chromaticity",
+            SubTab::Tm30 => "TM-30",
             SubTab::Greenhouse => "Greenhouse",
             SubTab::BugRating => "BUG Rating",
             SubTab::Lcs => "LCS",
+            SubTab::Glare => "Glare",
             SubTab::Bim => "BIM",
             SubTab::ComparePanel => "Compare",
             SubTab::ValidationPanel => "Validation",
@@ -113,12 +121,16 @@ impl SubTab {
             | SubTab::Heatmap
             | SubTab::Cone
             | SubTab::Isolux
+            | SubTab::Footprint
             | SubTab::Isocandela
             | SubTab::Floodlight => MainTab::Diagrams,
             SubTab::Spectral
+            | SubTab::Chromaticity
+            | SubTab::Tm30
             | SubTab::Greenhouse
             | SubTab::BugRating
             | SubTab::Lcs
+            | SubTab::Glare
             | SubTab::Bim => MainTab::Analysis,
             SubTab::ComparePanel => MainTab::Compare,
             SubTab::ValidationPanel => MainTab::Validation,
@@ -155,14 +167,18 @@ impl SubTab {
                 SubTab::Heatmap,
                 SubTab::Cone,
                 SubTab::Isolux,
+                SubTab::Footprint,
                 SubTab::Isocandela,
                 SubTab::Floodlight,
             ],
             MainTab::Analysis => &[
                 SubTab::Spectral,
+                SubTab::Chromaticity,
+                SubTab::Tm30,
                 SubTab::Greenhouse,
                 SubTab::BugRating,
                 SubTab::Lcs,
+                SubTab::Glare,
                 SubTab::Bim,
             ],
             MainTab::Compare => &[SubTab::ComparePanel],
@@ -181,12 +197,16 @@ impl SubTab {
                 | SubTab::Heatmap
                 | SubTab::Cone
                 | SubTab::Isolux
+                | SubTab::Footprint
                 | SubTab::Isocandela
                 | SubTab::Floodlight
                 | SubTab::Spectral
+                | SubTab::Chromaticity
+                | SubTab::Tm30
                 | SubTab::Greenhouse
                 | SubTab::BugRating
                 | SubTab::Lcs
+                | SubTab::Glare
         )
     }
 }
@@ -7,6 +7,7 @@ use eulumdat::diagram::{
 use eulumdat::{PhotometricCalculations, PhotometricSummary, SymmetryHandler};
 use eulumdat_i18n::{Language as CoreLanguage, Locale};
 
+use crate::error::EulumdatError;
 use crate::types::{to_core_eulumdat, Eulumdat, Symmetry};
 
 /// Supported UI languages for localization
@@ -414,6 +415,15 @@ pub fn generate_heatmap_diagram(ldt: &Eulumdat, width: f64, height: f64) -> Heat
     }
 }
 
+/// Render a diagram SVG (any of the `generate_*_svg` results) to PNG
+///
+/// One SVG unit becomes `scale_factor` pixels; the PNG is tagged as sRGB.
+#[uniffi::export]
+pub fn render_svg_to_png(svg: String, scale_factor: f32) -> Result<Vec<u8>, EulumdatError> {
+    eulumdat::diagram::svg_to_png(&svg, scale_factor)
+        .map_err(|e| EulumdatError::ExportError(e.to_string()))
+}
+
 /// Generate polar diagram as SVG string
 #[uniffi::export]
 pub fn generate_polar_svg(ldt: &Eulumdat, width: f64, height: f64, theme: SvgThemeType) -> String {
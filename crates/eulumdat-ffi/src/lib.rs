@@ -62,7 +62,7 @@ pub use diagram::{
     generate_heatmap_svg_localized, generate_isocandela_svg, generate_isocandela_svg_localized,
     generate_isolux_svg, generate_isolux_svg_localized, generate_polar_diagram,
     generate_polar_overlay_svg, generate_polar_svg, generate_polar_svg_for_plane,
-    generate_polar_svg_localized, get_expanded_c_angles, has_c_plane_variation,
+    generate_polar_svg_localized, get_expanded_c_angles, has_c_plane_variation, render_svg_to_png,
     ButterflyDiagramData, ButterflyWing, CPlaneDirection, CartesianCurve, CartesianDiagramData,
     CartesianPoint, Color, DiagramScale, HeatmapCell, HeatmapDiagramData, Language, LegendEntry,
     Point2D, PolarCurve, PolarDiagramData, PolarPoint, SvgThemeType,
@@ -212,6 +212,7 @@ pub fn to_core_eulumdat(ldt: &Eulumdat) -> CoreEulumdat {
         c_angles: ldt.c_angles.clone(),
         g_angles: ldt.g_angles.clone(),
         intensities: ldt.intensities.clone(),
+        photometric_center: None,
     }
 }
 
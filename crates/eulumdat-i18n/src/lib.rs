@@ -488,7 +488,7 @@ pub struct ValidationLevel {
     pub info: String,
 }
 
-/// Validation message translations keyed by code (W001–W046, E001–E006).
+/// Validation message translations keyed by code (W001–W051, E001–E006).
 /// Messages may contain `{0}`, `{1}`, … placeholders for dynamic values.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ValidationMessageLocale {
@@ -539,6 +539,10 @@ pub struct ValidationMessageLocale {
     pub w045: String,
     pub w046: String,
     pub w047: String,
+    pub w048: String,
+    pub w049: String,
+    pub w050: String,
+    pub w051: String,
     pub e001: String,
     pub e002: String,
     pub e003: String,
@@ -682,6 +686,8 @@ pub struct UiSubtabs {
     pub tm30_cvg: String,
     pub tm30_hue: String,
     pub metrics: String,
+    #[serde(default)]
+    pub chromaticity: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -866,6 +872,7 @@ pub struct UiFloodlight {
     pub area_size: String,
     pub nema_classification: String,
     pub show_contours: String,
+    pub datasheet_style: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -1734,6 +1741,10 @@ impl Locale {
             "W045" => &m.w045,
             "W046" => &m.w046,
             "W047" => &m.w047,
+            "W048" => &m.w048,
+            "W049" => &m.w049,
+            "W050" => &m.w050,
+            "W051" => &m.w051,
             "E001" => &m.e001,
             "E002" => &m.e002,
             "E003" => &m.e003,
@@ -0,0 +1,138 @@
+//! Peak and centroid direction analysis
+//!
+//! Mis-aimed or asymmetric optics show up as a peak or a flux centroid away
+//! from where the luminaire type expects it, e.g. a downlight whose beam axis
+//! leans a few degrees off the nadir. Directions are unit vectors in the
+//! system of [`direction_to_angles`]: Y up, the nadir at -Y.
+
+use crate::{angles_to_direction, direction_to_angles, PhotometricWeb, Zone};
+
+/// Step of the centroid quadrature in degrees
+const STEP: f64 = 1.0;
+
+impl PhotometricWeb {
+    /// C and gamma angle in degrees of the highest stored intensity.
+    ///
+    /// Of several equal peaks, the first in the stored order is returned;
+    /// with symmetry the mirrored directions have the same intensity.
+    pub fn peak_angles(&self) -> (f64, f64) {
+        let mut best = (f64::MIN, 0.0, 0.0);
+        for (row, &c) in self.intensities.iter().zip(&self.c_angles) {
+            for (&value, &g) in row.iter().zip(&self.g_angles) {
+                if value > best.0 {
+                    best = (value, c, g);
+                }
+            }
+        }
+        (best.1, best.2)
+    }
+
+    /// Unit vector towards the highest stored intensity.
+    pub fn peak_direction(&self) -> [f64; 3] {
+        let (c_angle, g_angle) = self.peak_angles();
+        angles_to_direction(c_angle, g_angle)
+    }
+
+    /// Flux-weighted mean of the emission directions, ∫ I ω dΩ / ∫ I dΩ.
+    ///
+    /// Its direction is the beam axis; its length, between 0 and 1, is how
+    /// concentrated the light is around it (1 for a single direction, 0 for
+    /// light spread evenly or symmetrically up and down). A web without light
+    /// gives the zero vector.
+    pub fn flux_centroid(&self) -> [f64; 3] {
+        let mut sum = [0.0; 3];
+        let mut flux = 0.0;
+        Zone::SPHERE.for_each_cell(STEP, STEP, |c, g, solid_angle| {
+            let weight = self.sample(c, g).max(0.0) * solid_angle;
+            let direction = angles_to_direction(c, g);
+            for axis in 0..3 {
+                sum[axis] += weight * direction[axis];
+            }
+            flux += weight;
+        });
+        if flux <= 0.0 {
+            return [0.0; 3];
+        }
+        sum.map(|s| s / flux)
+    }
+
+    /// Tilt of the beam axis from the nadir and the C-plane it leans into,
+    /// both in degrees.
+    ///
+    /// The beam axis is the [flux centroid](Self::flux_centroid); a tilt of
+    /// 0° points straight down, 90° horizontally into the returned C-plane.
+    pub fn beam_tilt(&self) -> (f64, f64) {
+        let (c_angle, g_angle) = direction_to_angles(self.flux_centroid());
+        (g_angle, c_angle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eulumdat::Symmetry;
+
+    /// Narrow beam of `width` degrees around (C, γ) = (`c`, `g`)
+    fn spot(c: f64, g: f64, width: f64) -> PhotometricWeb {
+        let axis = angles_to_direction(c, g);
+        let c_angles: Vec<f64> = (0..72).map(|i| i as f64 * 5.0).collect();
+        let g_angles: Vec<f64> = (0..=180).map(|i| i as f64).collect();
+        let intensities = c_angles
+            .iter()
+            .map(|&ci| {
+                g_angles
+                    .iter()
+                    .map(|&gi| {
+                        let d = angles_to_direction(ci, gi);
+                        let cos = d[0] * axis[0] + d[1] * axis[1] + d[2] * axis[2];
+                        let angle = cos.clamp(-1.0, 1.0).acos().to_degrees();
+                        (1000.0 * (1.0 - angle / width)).max(0.0)
+                    })
+                    .collect()
+            })
+            .collect();
+        PhotometricWeb::new(c_angles, g_angles, intensities, Symmetry::None)
+    }
+
+    #[test]
+    fn test_downlight_points_at_nadir() {
+        let web = spot(0.0, 0.0, 20.0);
+        assert_eq!(web.peak_angles().1, 0.0);
+        let centroid = web.flux_centroid();
+        assert!(centroid[1] < -0.9, "{centroid:?}");
+        assert!(web.beam_tilt().0 < 0.5);
+    }
+
+    #[test]
+    fn test_tilted_beam() {
+        let web = spot(90.0, 15.0, 10.0);
+        assert_eq!(web.peak_angles(), (90.0, 15.0));
+        let peak = web.peak_direction();
+        assert!(peak[0] > 0.0 && peak[1] < 0.0);
+
+        let (tilt, c_plane) = web.beam_tilt();
+        assert!((tilt - 15.0).abs() < 0.5, "tilt {tilt}");
+        assert!((c_plane - 90.0).abs() < 1.0, "C {c_plane}");
+    }
+
+    #[test]
+    fn test_uniform_and_dark() {
+        let uniform = PhotometricWeb::new(
+            vec![0.0],
+            vec![0.0, 180.0],
+            vec![vec![100.0, 100.0]],
+            Symmetry::VerticalAxis,
+        );
+        let centroid = uniform.flux_centroid();
+        assert!(centroid.iter().all(|v| v.abs() < 1e-9), "{centroid:?}");
+
+        let dark = PhotometricWeb::new(
+            vec![0.0],
+            vec![0.0, 180.0],
+            vec![vec![0.0, 0.0]],
+            Symmetry::VerticalAxis,
+        );
+        assert_eq!(dark.flux_centroid(), [0.0; 3]);
+        assert_eq!(dark.beam_tilt(), (0.0, 0.0));
+    }
+}
@@ -0,0 +1,202 @@
+//! Binary cache format of a prebaked [`PhotometricWeb`]
+//!
+//! Unlike [`PhotometricWeb::to_compact_bytes`], which stores the grid with
+//! `f32` precision for transfer, this format keeps the complete web with
+//! `f64` precision: the stored grid, the C-planes expanded over the full
+//! circle, the cached peak and minimum used for normalization and the
+//! interpolation. A loaded web samples exactly like the one that was
+//! written, without reparsing or recomputing anything.
+//!
+//! All numbers are little-endian; lists are prefixed with a `u32` length.
+//!
+//! ```text
+//! magic "PWEB" (4) | version (1) | symmetry (1) | interpolation (1)
+//! max (f64) | min (f64) | c_angles | g_angles | full_c_angles
+//! rows (u32) | per row: len (u32) | intensities (f64 ...)
+//! ```
+
+use eulumdat::{Error, Interpolation, Result, Symmetry};
+
+use crate::PhotometricWeb;
+
+/// Magic bytes of a cached web
+const MAGIC: &[u8; 4] = b"PWEB";
+/// Current format version
+const VERSION: u8 = 1;
+
+impl PhotometricWeb {
+    /// Encode the complete web for caching.
+    ///
+    /// Everything sampling needs is stored with full precision, including
+    /// the expanded C-planes and the normalization peak.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let values = self.c_angles.len()
+            + self.g_angles.len()
+            + self.full_c_angles.len()
+            + self.intensities.iter().map(Vec::len).sum::<usize>();
+        let mut out = Vec::with_capacity(23 + 8 * values + 4 * (self.intensities.len() + 4));
+        out.extend_from_slice(MAGIC);
+        out.push(VERSION);
+        out.push(self.symmetry.as_int() as u8);
+        out.push(match self.interpolation {
+            Interpolation::Nearest => 0,
+            Interpolation::Bilinear => 1,
+            Interpolation::Bicubic => 2,
+        });
+        out.extend_from_slice(&self.max_intensity.to_le_bytes());
+        out.extend_from_slice(&self.min_intensity.to_le_bytes());
+        for list in [&self.c_angles, &self.g_angles, &self.full_c_angles] {
+            write_list(&mut out, list);
+        }
+        out.extend_from_slice(&(self.intensities.len() as u32).to_le_bytes());
+        for row in &self.intensities {
+            write_list(&mut out, row);
+        }
+        out
+    }
+
+    /// Decode a web written by [`Self::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 7 || &bytes[..4] != MAGIC {
+            return Err(Error::msg("Not a cached photometric web"));
+        }
+        if bytes[4] != VERSION {
+            return Err(Error::msg(format!(
+                "Unsupported photometric web cache version {}",
+                bytes[4]
+            )));
+        }
+        let symmetry = Symmetry::from_int(bytes[5] as i32)?;
+        let interpolation = match bytes[6] {
+            0 => Interpolation::Nearest,
+            1 => Interpolation::Bilinear,
+            2 => Interpolation::Bicubic,
+            tag => return Err(Error::msg(format!("Unknown interpolation {tag}"))),
+        };
+
+        let mut r = Reader { bytes, pos: 7 };
+        let max_intensity = r.f64()?;
+        let min_intensity = r.f64()?;
+        let c_angles = r.list()?;
+        let g_angles = r.list()?;
+        let full_c_angles = r.list()?;
+        let rows = r.len(4)?;
+        let intensities = (0..rows).map(|_| r.list()).collect::<Result<_>>()?;
+        if r.pos != bytes.len() {
+            return Err(Error::msg(format!(
+                "Trailing data after photometric web at byte {}",
+                r.pos
+            )));
+        }
+
+        Ok(Self {
+            c_angles,
+            g_angles,
+            intensities,
+            symmetry,
+            max_intensity,
+            min_intensity,
+            interpolation,
+            full_c_angles,
+        })
+    }
+}
+
+fn write_list(out: &mut Vec<u8>, values: &[f64]) {
+    out.extend_from_slice(&(values.len() as u32).to_le_bytes());
+    for value in values {
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let chunk = self
+            .bytes
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| Error::msg(format!("Photometric web truncated at byte {}", self.pos)))?;
+        self.pos += N;
+        Ok(chunk.try_into().expect("slice has length N"))
+    }
+
+    fn f64(&mut self) -> Result<f64> {
+        Ok(f64::from_le_bytes(self.take()?))
+    }
+
+    /// A length prefix of items of `size` bytes, checked against the
+    /// remaining data
+    fn len(&mut self, size: usize) -> Result<usize> {
+        let n = u32::from_le_bytes(self.take()?) as usize;
+        if n.saturating_mul(size) > self.bytes.len() - self.pos {
+            return Err(Error::msg(format!(
+                "Photometric web has invalid length {n} at byte {}",
+                self.pos
+            )));
+        }
+        Ok(n)
+    }
+
+    fn list(&mut self) -> Result<Vec<f64>> {
+        let n = self.len(8)?;
+        (0..n).map(|_| self.f64()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn web() -> PhotometricWeb {
+        PhotometricWeb::new(
+            vec![0.0, 30.0, 90.0],
+            vec![0.0, 45.0, 90.0, 180.0],
+            vec![
+                vec![100.0, 80.5, 20.25, 0.0],
+                vec![95.0, 70.125, 15.0, 0.0],
+                vec![90.0, 60.0, 10.0, 1.0 / 3.0],
+            ],
+            Symmetry::BothPlanes,
+        )
+        .with_interpolation(Interpolation::Bicubic)
+    }
+
+    #[test]
+    fn test_roundtrip_is_exact() {
+        let web = web();
+        let restored = PhotometricWeb::from_bytes(&web.to_bytes()).unwrap();
+        assert_eq!(restored.symmetry(), Symmetry::BothPlanes);
+        assert_eq!(restored.interpolation(), Interpolation::Bicubic);
+        assert_eq!(restored.c_angles(), web.c_angles());
+        assert_eq!(restored.full_c_angles, web.full_c_angles);
+        assert_eq!(restored.intensities, web.intensities);
+        assert_eq!(restored.max_intensity(), web.max_intensity());
+        for (c, g) in [(0.0, 0.0), (200.0, 30.0), (315.0, 100.0)] {
+            assert_eq!(restored.sample(c, g), web.sample(c, g));
+            assert_eq!(
+                restored.sample_normalized(c, g),
+                web.sample_normalized(c, g)
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_data() {
+        let bytes = web().to_bytes();
+        assert!(PhotometricWeb::from_bytes(&bytes[..bytes.len() - 3]).is_err());
+        assert!(PhotometricWeb::from_bytes(b"LDTG\x01\x00").is_err());
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(PhotometricWeb::from_bytes(&trailing).is_err());
+
+        let mut huge = bytes;
+        // First list length claims more values than remain
+        huge[23..27].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(PhotometricWeb::from_bytes(&huge).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+//! Quantitative difference between two photometric webs
+//!
+//! Both webs are sampled on a common 1° × 1° grid over the full sphere, so
+//! webs with different angle sets and symmetries compare directly. Intensity
+//! differences are relative to the larger of the two peaks, which keeps them
+//! finite where either web is dark.
+
+use eulumdat::Symmetry;
+
+use crate::{PhotometricWeb, Zone};
+
+/// Step of the common comparison grid in degrees
+const GRID_STEP: f64 = 1.0;
+
+/// Difference between two photometric webs, see [`PhotometricWeb::compare`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WebDifference {
+    /// Solid-angle weighted RMS of the intensity difference, relative to the
+    /// larger peak (0.0 = identical)
+    pub rms_relative: f64,
+    /// Largest intensity difference relative to the larger peak
+    pub max_relative: f64,
+    /// C and gamma angle in degrees of the largest difference
+    pub max_at: (f64, f64),
+    /// Total flux of the first web
+    pub flux_a: f64,
+    /// Total flux of the second web
+    pub flux_b: f64,
+}
+
+impl WebDifference {
+    /// Flux of the second web minus the first.
+    pub fn flux_delta(&self) -> f64 {
+        self.flux_b - self.flux_a
+    }
+
+    /// Flux delta relative to the first web (0.0 if it has no flux).
+    pub fn flux_delta_relative(&self) -> f64 {
+        if self.flux_a > 0.0 {
+            self.flux_delta() / self.flux_a
+        } else {
+            0.0
+        }
+    }
+}
+
+impl PhotometricWeb {
+    /// Compare this web with `other` over a common resampled grid.
+    ///
+    /// Intensities are compared as stored, so both webs should be in the
+    /// same unit (cd/klm for webs from EULUMDAT files).
+    pub fn compare(&self, other: &PhotometricWeb) -> WebDifference {
+        let peak = self.max_intensity().max(other.max_intensity());
+        let rotational =
+            self.symmetry() == Symmetry::VerticalAxis && other.symmetry() == Symmetry::VerticalAxis;
+        let c_step = if rotational { 360.0 } else { GRID_STEP };
+
+        let mut sum_squares = 0.0;
+        let mut total_weight = 0.0;
+        let mut max_relative = 0.0;
+        let mut max_at = (0.0, 0.0);
+        Zone::SPHERE.for_each_cell(c_step, GRID_STEP, |c, g, weight| {
+            let delta = if peak > 0.0 {
+                (self.sample(c, g) - other.sample(c, g)).abs() / peak
+            } else {
+                0.0
+            };
+            sum_squares += delta * delta * weight;
+            total_weight += weight;
+            if delta > max_relative {
+                max_relative = delta;
+                max_at = (c, g);
+            }
+        });
+
+        WebDifference {
+            rms_relative: (sum_squares / total_weight).sqrt(),
+            max_relative,
+            max_at,
+            flux_a: self.total_flux(),
+            flux_b: other.total_flux(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn web(scale: f64, symmetry: Symmetry) -> PhotometricWeb {
+        let c_angles = match symmetry {
+            Symmetry::VerticalAxis => vec![0.0],
+            _ => vec![0.0, 90.0, 180.0, 270.0],
+        };
+        let rows = c_angles.len();
+        PhotometricWeb::new(
+            c_angles,
+            vec![0.0, 45.0, 90.0, 180.0],
+            vec![vec![100.0 * scale, 80.0 * scale, 20.0 * scale, 0.0]; rows],
+            symmetry,
+        )
+    }
+
+    #[test]
+    fn test_identical_across_symmetries() {
+        let a = web(1.0, Symmetry::VerticalAxis);
+        let b = web(1.0, Symmetry::None);
+        let diff = a.compare(&b);
+        assert!(diff.rms_relative < 1e-12);
+        assert!(diff.max_relative < 1e-12);
+        assert!(diff.flux_delta_relative().abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_scaled_web() {
+        let a = web(1.0, Symmetry::VerticalAxis);
+        let b = web(0.9, Symmetry::VerticalAxis);
+        let diff = a.compare(&b);
+        // Largest at the nadir: 10 of a 100 peak
+        assert!((diff.max_relative - 0.1).abs() < 0.01);
+        assert!(diff.max_at.1 < 1.0);
+        assert!(diff.rms_relative > 0.0 && diff.rms_relative < diff.max_relative);
+        assert!((diff.flux_delta_relative() + 0.1).abs() < 1e-9);
+    }
+}
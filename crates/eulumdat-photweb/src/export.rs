@@ -0,0 +1,302 @@
+//! Export of the LDC solid to 3D file formats
+//!
+//! glTF and OBJ keep the Y-up coordinate system of [`LdcMesh`], which is
+//! also the glTF convention; STL is rotated to Z up.
+
+use base64::Engine;
+
+use crate::{Color, LdcMesh, Vertex};
+
+const GLB_MAGIC: u32 = 0x4654_6C67; // "glTF"
+const GLB_CHUNK_JSON: u32 = 0x4E4F_534A;
+const GLB_CHUNK_BIN: u32 = 0x004E_4942;
+
+impl LdcMesh {
+    /// Binary glTF 2.0 (.glb) with positions, normals and heatmap vertex colors.
+    ///
+    /// The colors follow [`Color::from_heatmap`] with the distance from the
+    /// center relative to the farthest vertex, i.e. the normalized intensity.
+    /// The material is double-sided, so the file opens as-is in Blender,
+    /// three.js or any glTF viewer.
+    pub fn to_gltf(&self) -> Vec<u8> {
+        let mut buffer = self.gltf_buffer();
+        let mut json = self.gltf_json(buffer.len(), None).into_bytes();
+        // Chunks are 4-byte aligned: JSON with spaces, binary with zeros
+        json.resize((json.len() + 3) & !3, b' ');
+        buffer.resize((buffer.len() + 3) & !3, 0);
+
+        let total = 12 + 8 + json.len() + 8 + buffer.len();
+        let mut out = Vec::with_capacity(total);
+        for value in [GLB_MAGIC, 2, total as u32] {
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+        out.extend_from_slice(&(json.len() as u32).to_le_bytes());
+        out.extend_from_slice(&GLB_CHUNK_JSON.to_le_bytes());
+        out.extend_from_slice(&json);
+        out.extend_from_slice(&(buffer.len() as u32).to_le_bytes());
+        out.extend_from_slice(&GLB_CHUNK_BIN.to_le_bytes());
+        out.extend_from_slice(&buffer);
+        out
+    }
+
+    /// glTF 2.0 JSON (.gltf) with the same content as [`to_gltf`](Self::to_gltf),
+    /// the buffer embedded as a base64 data URI.
+    pub fn to_gltf_json(&self) -> String {
+        let buffer = self.gltf_buffer();
+        let uri = format!(
+            "data:application/octet-stream;base64,{}",
+            base64::engine::general_purpose::STANDARD.encode(&buffer)
+        );
+        self.gltf_json(buffer.len(), Some(&uri))
+    }
+
+    /// Wavefront OBJ with positions and normals, for CAD tools that don't
+    /// read glTF.
+    pub fn to_obj(&self) -> String {
+        let mut out = String::from("# Photometric solid exported by eulumdat\n");
+        for v in &self.vertices {
+            out.push_str(&format!("v {} {} {}\n", v.x, v.y, v.z));
+        }
+        for v in &self.vertices {
+            out.push_str(&format!("vn {} {} {}\n", v.nx, v.ny, v.nz));
+        }
+        // OBJ indices are 1-based
+        for tri in self.indices.chunks_exact(3) {
+            let (a, b, c) = (tri[0] + 1, tri[1] + 1, tri[2] + 1);
+            out.push_str(&format!("f {a}//{a} {b}//{b} {c}//{c}\n"));
+        }
+        out
+    }
+
+    /// Binary STL for 3D printing, rotated to Z up as CAD tools and slicers
+    /// expect.
+    ///
+    /// Degenerate triangles at the poles and in dark directions are left out
+    /// and facets are wound to face outward.
+    pub fn to_stl(&self) -> Vec<u8> {
+        let z_up = |i: u32| {
+            let v = &self.vertices[i as usize];
+            [v.x, -v.z, v.y]
+        };
+        let triangles: Vec<([f32; 3], [[f32; 3]; 3])> = self
+            .indices
+            .chunks_exact(3)
+            .filter_map(|tri| {
+                let mut corners = [z_up(tri[0]), z_up(tri[1]), z_up(tri[2])];
+                let mut normal = face_normal(&corners)?;
+                // The solid is star-shaped around the origin, so outward
+                // facets point away from it
+                let outward: f32 = (0..3)
+                    .map(|axis| {
+                        normal[axis] * (corners[0][axis] + corners[1][axis] + corners[2][axis])
+                    })
+                    .sum();
+                if outward < 0.0 {
+                    corners.swap(1, 2);
+                    normal = normal.map(|n| -n);
+                }
+                Some((normal, corners))
+            })
+            .collect();
+
+        let mut out = Vec::with_capacity(84 + triangles.len() * 50);
+        let mut header = [0u8; 80];
+        let title = b"Photometric solid exported by eulumdat";
+        header[..title.len()].copy_from_slice(title);
+        out.extend_from_slice(&header);
+        out.extend_from_slice(&(triangles.len() as u32).to_le_bytes());
+        for (normal, corners) in &triangles {
+            for value in normal.iter().chain(corners.iter().flatten()) {
+                out.extend_from_slice(&value.to_le_bytes());
+            }
+            out.extend_from_slice(&0u16.to_le_bytes());
+        }
+        out
+    }
+
+    /// Heatmap color of each vertex from its distance to the center
+    fn intensity_colors(&self) -> Vec<Color> {
+        let radius = |v: &Vertex| (v.x * v.x + v.y * v.y + v.z * v.z).sqrt();
+        let max = self.vertices.iter().map(radius).fold(0.0_f32, f32::max);
+        self.vertices
+            .iter()
+            .map(|v| Color::from_heatmap(if max > 0.0 { radius(v) / max } else { 0.0 }))
+            .collect()
+    }
+
+    /// Positions, normals, RGB colors and indices, one after the other
+    fn gltf_buffer(&self) -> Vec<u8> {
+        let colors: Vec<f32> = self
+            .intensity_colors()
+            .iter()
+            .flat_map(|c| [c.r, c.g, c.b])
+            .collect();
+        self.positions_flat()
+            .iter()
+            .chain(&self.normals_flat())
+            .chain(&colors)
+            .flat_map(|v| v.to_le_bytes())
+            .chain(self.indices.iter().flat_map(|i| i.to_le_bytes()))
+            .collect()
+    }
+
+    fn gltf_json(&self, buffer_length: usize, uri: Option<&str>) -> String {
+        const FLOAT: u32 = 5126;
+        const UNSIGNED_INT: u32 = 5125;
+        const ARRAY_BUFFER: u32 = 34962;
+        const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+        let count = self.vertex_count();
+        let vertex_bytes = count * 12;
+        let (mut min, mut max) = ([0.0_f32; 3], [0.0_f32; 3]);
+        for (i, v) in self.vertices.iter().enumerate() {
+            for (axis, value) in [v.x, v.y, v.z].into_iter().enumerate() {
+                if i == 0 || value < min[axis] {
+                    min[axis] = value;
+                }
+                if i == 0 || value > max[axis] {
+                    max[axis] = value;
+                }
+            }
+        }
+        let vec3 = |v: [f32; 3]| format!("[{},{},{}]", v[0], v[1], v[2]);
+        let uri = uri
+            .map(|uri| format!(",\"uri\":\"{uri}\""))
+            .unwrap_or_default();
+
+        format!(
+            concat!(
+                "{{\"asset\":{{\"version\":\"2.0\",\"generator\":\"eulumdat-photweb\"}},",
+                "\"scene\":0,\"scenes\":[{{\"nodes\":[0]}}],",
+                "\"nodes\":[{{\"mesh\":0,\"name\":\"Photometric solid\"}}],",
+                "\"materials\":[{{\"name\":\"Intensity\",\"doubleSided\":true,",
+                "\"pbrMetallicRoughness\":{{\"metallicFactor\":0,\"roughnessFactor\":1}}}}],",
+                "\"meshes\":[{{\"primitives\":[{{",
+                "\"attributes\":{{\"POSITION\":0,\"NORMAL\":1,\"COLOR_0\":2}},",
+                "\"indices\":3,\"material\":0}}]}}],",
+                "\"accessors\":[",
+                "{{\"bufferView\":0,\"componentType\":{float},\"count\":{count},\"type\":\"VEC3\",",
+                "\"min\":{min},\"max\":{max}}},",
+                "{{\"bufferView\":1,\"componentType\":{float},\"count\":{count},\"type\":\"VEC3\"}},",
+                "{{\"bufferView\":2,\"componentType\":{float},\"count\":{count},\"type\":\"VEC3\"}},",
+                "{{\"bufferView\":3,\"componentType\":{uint},\"count\":{indices},\"type\":\"SCALAR\"}}],",
+                "\"bufferViews\":[",
+                "{{\"buffer\":0,\"byteOffset\":0,\"byteLength\":{vb},\"target\":{ab}}},",
+                "{{\"buffer\":0,\"byteOffset\":{vb},\"byteLength\":{vb},\"target\":{ab}}},",
+                "{{\"buffer\":0,\"byteOffset\":{vb2},\"byteLength\":{vb},\"target\":{ab}}},",
+                "{{\"buffer\":0,\"byteOffset\":{vb3},\"byteLength\":{ib},\"target\":{eab}}}],",
+                "\"buffers\":[{{\"byteLength\":{length}{uri}}}]}}"
+            ),
+            float = FLOAT,
+            uint = UNSIGNED_INT,
+            count = count,
+            indices = self.indices.len(),
+            min = vec3(min),
+            max = vec3(max),
+            vb = vertex_bytes,
+            vb2 = 2 * vertex_bytes,
+            vb3 = 3 * vertex_bytes,
+            ib = self.indices.len() * 4,
+            ab = ARRAY_BUFFER,
+            eab = ELEMENT_ARRAY_BUFFER,
+            length = buffer_length,
+            uri = uri,
+        )
+    }
+}
+
+/// Unit normal of a counter-clockwise triangle, `None` if it has no area
+fn face_normal([a, b, c]: &[[f32; 3]; 3]) -> Option<[f32; 3]> {
+    let u = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    let v = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+    let n = [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ];
+    let len = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+    (len > 1e-12).then(|| [n[0] / len, n[1] / len, n[2] / len])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PhotometricWeb;
+    use eulumdat::Symmetry;
+
+    fn mesh() -> crate::LdcMesh {
+        let web = PhotometricWeb::new(
+            vec![0.0, 90.0, 180.0, 270.0],
+            vec![0.0, 45.0, 90.0, 135.0, 180.0],
+            vec![vec![100.0, 80.0, 50.0, 10.0, 0.0]; 4],
+            Symmetry::None,
+        );
+        web.generate_ldc_mesh(30.0, 30.0, 1.0)
+    }
+
+    fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_glb_layout() {
+        let mesh = mesh();
+        let glb = mesh.to_gltf();
+
+        assert_eq!(&glb[..4], b"glTF");
+        assert_eq!(u32_at(&glb, 4), 2);
+        assert_eq!(u32_at(&glb, 8) as usize, glb.len());
+
+        let json_len = u32_at(&glb, 12) as usize;
+        assert_eq!(json_len % 4, 0);
+        let json = std::str::from_utf8(&glb[20..20 + json_len]).unwrap();
+        assert!(json.contains("\"COLOR_0\":2"));
+        assert!(!json.contains("\"uri\""));
+
+        // Positions, normals and colors (3 floats each) plus the indices
+        let bin_len = u32_at(&glb, 20 + json_len) as usize;
+        assert_eq!(bin_len, mesh.vertex_count() * 36 + mesh.indices.len() * 4);
+        assert_eq!(20 + json_len + 8 + bin_len, glb.len());
+    }
+
+    #[test]
+    fn test_gltf_json_embeds_buffer() {
+        let json = mesh().to_gltf_json();
+        assert!(json.starts_with("{\"asset\""));
+        assert!(json.contains("\"uri\":\"data:application/octet-stream;base64,"));
+        // Balanced braces and brackets outside the base64 payload
+        assert_eq!(json.matches('{').count(), json.matches('}').count());
+        assert_eq!(json.matches('[').count(), json.matches(']').count());
+    }
+
+    #[test]
+    fn test_obj_counts() {
+        let mesh = mesh();
+        let obj = mesh.to_obj();
+        let count = |prefix: &str| obj.lines().filter(|l| l.starts_with(prefix)).count();
+        assert_eq!(count("v "), mesh.vertex_count());
+        assert_eq!(count("vn "), mesh.vertex_count());
+        assert_eq!(count("f "), mesh.triangle_count());
+        // 1-based indices
+        assert!(!obj.contains("f 0/"));
+    }
+
+    #[test]
+    fn test_stl_outward_facets() {
+        let stl = mesh().to_stl();
+        let count = u32_at(&stl, 80) as usize;
+        assert!(count > 0);
+        assert_eq!(stl.len(), 84 + count * 50);
+
+        let f32_at =
+            |offset: usize| f32::from_le_bytes(stl[offset..offset + 4].try_into().unwrap());
+        for i in 0..count {
+            let facet = 84 + i * 50;
+            let normal: Vec<f32> = (0..3).map(|k| f32_at(facet + 4 * k)).collect();
+            let centroid: Vec<f32> = (0..3)
+                .map(|k| (1..4).map(|c| f32_at(facet + 12 * c + 4 * k)).sum())
+                .collect();
+            let dot: f32 = normal.iter().zip(&centroid).map(|(n, c)| n * c).sum();
+            assert!(dot >= 0.0, "facet {i} faces inward");
+        }
+    }
+}
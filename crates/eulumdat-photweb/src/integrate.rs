@@ -0,0 +1,155 @@
+//! Solid-angle integration of the intensity distribution
+//!
+//! Zone flux, zonal flux and BUG zone lumens of a web use the quadrature of
+//! [`eulumdat::integration`], the same the `eulumdat` crate uses for the
+//! flux, zonal lumens and BUG rating of a file. The flux centroid and
+//! [`PhotometricWeb::compare`] weight their samples with the same cells.
+
+use eulumdat::{Zone, ZoneLumens};
+
+use crate::PhotometricWeb;
+
+impl PhotometricWeb {
+    /// Luminous flux emitted into `zone`.
+    ///
+    /// The result is in the intensity unit times steradians, i.e. lumens per
+    /// 1000 lamp lumens for a web in cd/klm.
+    pub fn integrate(&self, zone: &Zone) -> f64 {
+        zone.integrate(self.symmetry(), |c, g| self.sample(c, g))
+    }
+
+    /// Luminous flux over the whole sphere, see [`Self::integrate`].
+    pub fn total_flux(&self) -> f64 {
+        self.integrate(&Zone::SPHERE)
+    }
+
+    /// Luminous flux in gamma bands of `step` degrees from nadir to zenith.
+    ///
+    /// The last band is shorter when `step` does not divide 180°.
+    pub fn zonal_flux(&self, step: f64) -> Vec<f64> {
+        let step = step.max(eulumdat::integration::MAX_G_STEP);
+        let count = (180.0 / step).ceil() as usize;
+        (0..count)
+            .map(|i| {
+                let g_start = i as f64 * step;
+                self.integrate(&Zone::gamma(g_start, (g_start + step).min(180.0)))
+            })
+            .collect()
+    }
+
+    /// IES TM-15 BUG zone lumens, forward being C0–C180.
+    ///
+    /// Like [`Self::integrate`] the result is per 1000 lamp lumens for a web
+    /// in cd/klm; scale it by the lamp flux before rating it.
+    pub fn zone_lumens(&self) -> ZoneLumens {
+        ZoneLumens::from_zones(|zone| self.integrate(zone))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eulumdat::Symmetry;
+    use std::f64::consts::PI;
+
+    fn uniform(intensity: f64) -> PhotometricWeb {
+        PhotometricWeb::new(
+            vec![0.0],
+            vec![0.0, 180.0],
+            vec![vec![intensity, intensity]],
+            Symmetry::VerticalAxis,
+        )
+    }
+
+    #[test]
+    fn test_uniform_sphere() {
+        let web = uniform(100.0);
+        assert!((web.total_flux() - 400.0 * PI).abs() < 1e-6);
+        assert!((web.integrate(&Zone::DOWNWARD) - 200.0 * PI).abs() < 1e-6);
+        // A quarter of the lower hemisphere, wrapping through C0
+        let quarter = Zone::new(315.0, 45.0, 0.0, 90.0);
+        assert!((quarter.solid_angle() - PI / 2.0).abs() < 1e-9);
+        assert!((web.integrate(&quarter) - 50.0 * PI).abs() < 1e-6);
+
+        let zones = web.zonal_flux(30.0);
+        assert_eq!(zones.len(), 6);
+        assert!((zones.iter().sum::<f64>() - web.total_flux()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cosine_distribution() {
+        // Lambertian downlight: I = I0 cos γ, flux = π I0
+        let g_angles: Vec<f64> = (0..=36).map(|i| i as f64 * 5.0).collect();
+        let row = g_angles
+            .iter()
+            .map(|g| g.to_radians().cos().max(0.0) * 100.0)
+            .collect();
+        let web = PhotometricWeb::new(vec![0.0], g_angles, vec![row], Symmetry::VerticalAxis);
+        assert!((web.total_flux() - 100.0 * PI).abs() < 0.5);
+        assert!(web.integrate(&Zone::UPWARD) < 1e-9);
+    }
+
+    #[test]
+    fn test_zone_lumens() {
+        // Light only in the forward half
+        let web = PhotometricWeb::new(
+            vec![0.0, 180.0, 181.0, 360.0],
+            vec![0.0, 90.0, 91.0, 180.0],
+            vec![
+                vec![100.0, 100.0, 0.0, 0.0],
+                vec![100.0, 100.0, 0.0, 0.0],
+                vec![0.0; 4],
+                vec![0.0; 4],
+            ],
+            Symmetry::None,
+        );
+        let zones = web.zone_lumens();
+        let forward = zones.fl + zones.fm + zones.fh + zones.fvh;
+        assert!((forward - 100.0 * PI).abs() < 1.0, "forward {forward}");
+        assert!(zones.bl < 1.0 && zones.uh < 1e-9);
+    }
+
+    #[test]
+    fn test_matches_file_calculations() {
+        // The web of a file integrates like the file itself; C360 repeats C0
+        // as the web holds the last plane instead of wrapping
+        let c_angles: Vec<f64> = (0..=24).map(|i| i as f64 * 15.0).collect();
+        let g_angles: Vec<f64> = (0..=36).map(|i| i as f64 * 5.0).collect();
+        let intensities = c_angles
+            .iter()
+            .map(|&c| {
+                g_angles
+                    .iter()
+                    .map(|&g| 200.0 * (1.0 + 0.5 * c.to_radians().sin()) * (g / 120.0).cos())
+                    .collect()
+            })
+            .collect();
+        let ldt = eulumdat::Eulumdat {
+            symmetry: Symmetry::None,
+            num_c_planes: c_angles.len(),
+            num_g_planes: g_angles.len(),
+            c_angles,
+            g_angles,
+            intensities,
+            lamp_sets: vec![eulumdat::LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 1000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let web = PhotometricWeb::from(&ldt);
+
+        let flux = eulumdat::PhotometricCalculations::integrate_flux(&ldt);
+        assert!((web.total_flux() - flux.lor * 10.0).abs() < 1e-6 * web.total_flux());
+        let file = eulumdat::ZoneLumens::from_eulumdat(&ldt);
+        let zones = web.zone_lumens();
+        for (web_zone, file_zone) in [
+            (zones.fm, file.fm),
+            (zones.bh, file.bh),
+            (zones.uh, file.uh),
+        ] {
+            assert!((web_zone - file_zone).abs() < 1e-6 * file_zone.max(1.0));
+        }
+    }
+}
@@ -5,10 +5,22 @@
 //! This crate provides a `PhotometricWeb` structure that represents the full 3D
 //! luminous intensity distribution of a light source. It supports:
 //!
-//! - **Sampling**: Get intensity at any C/G angle with bilinear interpolation
+//! - **Sampling**: Get intensity at any C/G angle with bilinear interpolation,
+//!   or in the direction of a 3D vector
+//! - **Cross sections**: (γ, intensity) polylines through a C-plane pair
+//! - **Regular grids**: Uniform, symmetry-expanded resampling as flat `f32` arrays
 //! - **Normalization**: Sample normalized (0.0-1.0) intensity values
 //! - **Symmetry handling**: Automatic expansion based on symmetry type
-//! - **Mesh generation**: Generate 3D LDC solid geometry (coming soon)
+//! - **Direction analysis**: Peak direction, flux centroid and beam axis tilt
+//! - **Integration**: Flux of any C/gamma zone, zonal lumens and BUG zone lumens
+//! - **Comparison**: RMS and maximum intensity difference and flux delta of two webs
+//! - **Mesh generation**: Generate 3D LDC solid geometry, with LOD chains
+//! - **Sources**: Build webs and meshes from any [`PhotometricSource`], including
+//!   ATLA intensity distributions with the `atla` feature
+//! - **Caching**: Lossless binary serialization of prebaked webs
+//! - **Export**: Write the LDC solid as glTF 2.0 with intensity vertex colors, OBJ or STL
+//! - **Importance sampling**: Emission directions and their densities for path tracers
+//! - **Texture baking**: 1D profiles and equirectangular or octahedral maps for render engines
 //!
 //! ## Quick Start
 //!
@@ -28,8 +40,22 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
+mod analysis;
+mod cache;
+mod difference;
+mod export;
+mod integrate;
 mod mesh;
 mod photweb;
+mod sampling;
+mod source;
+mod texture;
 
+pub use difference::WebDifference;
+pub use eulumdat::Interpolation;
+pub use eulumdat::Zone;
 pub use mesh::{hsl_to_rgb, Color, ColorMode, ColoredLdcMesh, LdcMesh, Vertex};
-pub use photweb::PhotometricWeb;
+pub use photweb::{angles_to_direction, direction_to_angles, PhotometricWeb};
+pub use sampling::DirectionSampler;
+pub use source::PhotometricSource;
+pub use texture::{BakedTexture, TextureLayout};
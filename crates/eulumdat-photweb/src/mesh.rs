@@ -177,24 +177,97 @@ impl LdcMesh {
     /// - X-Z plane is horizontal
     /// - C=0° is along +Z axis, C=90° is along +X axis
     pub fn from_photweb(web: &PhotometricWeb, c_step: f64, g_step: f64, scale: f32) -> Self {
-        let mut vertices = Vec::new();
-        let mut indices = Vec::new();
-
         // Calculate grid dimensions
         let c_count = (360.0 / c_step).ceil() as usize + 1;
         let g_count = (180.0 / g_step).ceil() as usize + 1;
 
+        let c_angles: Vec<f64> = (0..c_count)
+            .map(|ci| (ci as f64 * c_step).min(360.0))
+            .collect();
+        let g_angles: Vec<f64> = (0..g_count)
+            .map(|gi| (gi as f64 * g_step).min(180.0))
+            .collect();
+        Self::from_angles(web, &c_angles, &g_angles, scale)
+    }
+
+    /// Generate an LDC solid mesh with adaptive angle steps.
+    ///
+    /// Instead of fixed steps, the C-planes and gamma angles are spread
+    /// according to the intensity gradient: narrow beams and cut-offs get
+    /// dense rows and columns, flat regions coarse ones. Half of the
+    /// samples are still spread evenly, so no region is left empty.
+    ///
+    /// # Arguments
+    /// * `web` - The photometric web to generate from
+    /// * `vertex_budget` - Maximum number of vertices (at least 15)
+    /// * `scale` - Scale factor for the mesh (1.0 = normalized intensity as radius)
+    pub fn from_photweb_adaptive(web: &PhotometricWeb, vertex_budget: usize, scale: f32) -> Self {
+        // Twice as many C-planes as gamma angles, like equal steps
+        let g_count = ((vertex_budget as f64 / 2.0).sqrt().floor() as usize).max(3);
+        let c_count = (vertex_budget / g_count).max(5);
+
+        // Gradients on a fine grid, the largest across the other angle
+        const FINE_G: usize = 180;
+        const FINE_C: usize = 180;
+        let fine = |i: usize, n: usize, range: f64| range * i as f64 / n as f64;
+        let intensity: Vec<Vec<f64>> = (0..=FINE_C)
+            .map(|ci| {
+                (0..=FINE_G)
+                    .map(|gi| {
+                        web.sample_normalized(fine(ci, FINE_C, 360.0), fine(gi, FINE_G, 180.0))
+                    })
+                    .collect()
+            })
+            .collect();
+        let g_weights: Vec<f64> = (0..FINE_G)
+            .map(|gi| {
+                intensity
+                    .iter()
+                    .map(|column| (column[gi + 1] - column[gi]).abs())
+                    .fold(0.0, f64::max)
+            })
+            .collect();
+        let c_weights: Vec<f64> = (0..FINE_C)
+            .map(|ci| {
+                (0..=FINE_G)
+                    .map(|gi| (intensity[ci + 1][gi] - intensity[ci][gi]).abs())
+                    .fold(0.0, f64::max)
+            })
+            .collect();
+
+        let c_angles = adaptive_angles(&c_weights, 360.0, c_count);
+        let g_angles = adaptive_angles(&g_weights, 180.0, g_count);
+        Self::from_angles(web, &c_angles, &g_angles, scale)
+    }
+
+    /// Mesh over a grid of C-plane and gamma angles, both ascending and
+    /// spanning 0–360° and 0–180°
+    fn from_angles(web: &PhotometricWeb, c_angles: &[f64], g_angles: &[f64], scale: f32) -> Self {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+        let c_count = c_angles.len();
+        let g_count = g_angles.len();
+
         // Generate vertices
-        for gi in 0..g_count {
-            let g_angle = (gi as f64 * g_step).min(180.0);
+        for (gi, &g_angle) in g_angles.iter().enumerate() {
             let g_rad = g_angle.to_radians();
 
-            for ci in 0..c_count {
-                let c_angle = (ci as f64 * c_step).min(360.0);
+            // All C-planes meet at the poles; one shared radius closes them
+            let is_pole = gi == 0 || gi == g_count - 1;
+            let pole_radius = is_pole.then(|| {
+                let sum: f64 = c_angles[..c_count - 1]
+                    .iter()
+                    .map(|&c_angle| web.sample_normalized(c_angle, g_angle))
+                    .sum();
+                (sum / (c_count - 1) as f64) as f32 * scale
+            });
+
+            for &c_angle in c_angles {
                 let c_rad = c_angle.to_radians();
 
                 // Get normalized intensity as radius
-                let radius = web.sample_normalized(c_angle, g_angle) as f32 * scale;
+                let radius = pole_radius
+                    .unwrap_or_else(|| web.sample_normalized(c_angle, g_angle) as f32 * scale);
 
                 // Spherical to Cartesian conversion
                 // gamma = 0 is nadir (-Y), gamma = 90 is horizontal, gamma = 180 is zenith (+Y)
@@ -220,7 +293,8 @@ impl LdcMesh {
         }
 
         // Generate triangle indices
-        // Connect vertices in a grid pattern
+        // Connect vertices in a grid pattern, counter-clockwise seen from
+        // outside (gamma grows upward, C grows toward +X at C=0)
         for gi in 0..g_count - 1 {
             for ci in 0..c_count - 1 {
                 let i00 = (gi * c_count + ci) as u32;
@@ -229,15 +303,15 @@ impl LdcMesh {
                 let i11 = ((gi + 1) * c_count + ci + 1) as u32;
 
                 // Two triangles per quad
-                // Triangle 1: i00, i10, i01
+                // Triangle 1: i00, i01, i10
                 indices.push(i00);
-                indices.push(i10);
                 indices.push(i01);
+                indices.push(i10);
 
-                // Triangle 2: i01, i10, i11
+                // Triangle 2: i01, i11, i10
                 indices.push(i01);
-                indices.push(i10);
                 indices.push(i11);
+                indices.push(i10);
             }
         }
 
@@ -249,6 +323,130 @@ impl LdcMesh {
         }
     }
 
+    /// Replace the radial normals by smoothed surface normals.
+    ///
+    /// Each vertex gets the area-weighted average of the normals of its
+    /// triangles. The duplicated C=0°/360° column and the pole rows share
+    /// their normals, so renderers show no seam at the C-plane wrap-around.
+    /// Vertices without any triangle area around them keep their normal.
+    pub fn with_smooth_normals(mut self) -> Self {
+        let mut sums = vec![[0.0_f32; 3]; self.vertices.len()];
+        for tri in self.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| {
+                let v = &self.vertices[i as usize];
+                [v.x, v.y, v.z]
+            });
+            let u = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+            let v = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+            // Not normalized: its length is twice the triangle area
+            let n = [
+                u[1] * v[2] - u[2] * v[1],
+                u[2] * v[0] - u[0] * v[2],
+                u[0] * v[1] - u[1] * v[0],
+            ];
+            for &i in tri {
+                for axis in 0..3 {
+                    sums[i as usize][axis] += n[axis];
+                }
+            }
+        }
+
+        let (c_count, g_count) = (self.c_divisions, self.g_divisions);
+        let add = |a: [f32; 3], b: [f32; 3]| [a[0] + b[0], a[1] + b[1], a[2] + b[2]];
+        for gi in 0..g_count {
+            let row = gi * c_count;
+            if gi == 0 || gi == g_count - 1 {
+                // One normal for the whole pole
+                let pole = sums[row..row + c_count]
+                    .iter()
+                    .fold([0.0; 3], |acc, &n| add(acc, n));
+                sums[row..row + c_count].fill(pole);
+            } else {
+                // Weld the seam
+                let seam = add(sums[row], sums[row + c_count - 1]);
+                sums[row] = seam;
+                sums[row + c_count - 1] = seam;
+            }
+        }
+
+        for (vertex, n) in self.vertices.iter_mut().zip(&sums) {
+            let len = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+            if len > 1e-12 {
+                vertex.nx = n[0] / len;
+                vertex.ny = n[1] / len;
+                vertex.nz = n[2] / len;
+            }
+        }
+        self
+    }
+
+    /// Simplified copies of the mesh, one per target triangle count.
+    ///
+    /// Each level keeps evenly spread rows and columns of this mesh's grid,
+    /// including the poles and the C=0°/360° seam, so vertices and normals
+    /// are reused as they are and adaptive spacing carries over. The levels
+    /// keep the C to gamma ratio of the grid and have at most the target
+    /// number of triangles, but never fewer than 16 (5 × 3 vertices); a
+    /// target at or above [`Self::triangle_count`] gives a plain copy.
+    pub fn generate_lods(&self, target_triangles: &[usize]) -> Vec<LdcMesh> {
+        target_triangles
+            .iter()
+            .map(|&target| self.decimated(target))
+            .collect()
+    }
+
+    /// Grid subset with at most `target` triangles
+    fn decimated(&self, target: usize) -> LdcMesh {
+        if target >= self.triangle_count() || self.c_divisions < 5 || self.g_divisions < 3 {
+            return self.clone();
+        }
+        let (c_quads, g_quads) = (self.c_divisions - 1, self.g_divisions - 1);
+        // Two triangles per quad; scale both directions alike
+        let factor = (target as f64 / (2 * c_quads * g_quads) as f64).sqrt();
+        let mut c_keep = ((c_quads as f64 * factor).floor() as usize).clamp(4, c_quads);
+        let mut g_keep = ((g_quads as f64 * factor).floor() as usize).clamp(2, g_quads);
+        // Flooring both can still overshoot at the minimum sizes
+        while 2 * c_keep * g_keep > target.max(16) {
+            if c_keep > 4 && c_keep * g_quads >= g_keep * c_quads {
+                c_keep -= 1;
+            } else if g_keep > 2 {
+                g_keep -= 1;
+            } else {
+                break;
+            }
+        }
+
+        let columns = spread(c_quads, c_keep);
+        let rows = spread(g_quads, g_keep);
+        let c_count = columns.len();
+        let vertices = rows
+            .iter()
+            .flat_map(|&gi| {
+                columns
+                    .iter()
+                    .map(move |&ci| self.vertices[gi * self.c_divisions + ci])
+            })
+            .collect();
+        let mut indices = Vec::with_capacity(6 * c_keep * g_keep);
+        for gi in 0..rows.len() - 1 {
+            for ci in 0..c_count - 1 {
+                let i00 = (gi * c_count + ci) as u32;
+                let i01 = (gi * c_count + ci + 1) as u32;
+                let i10 = ((gi + 1) * c_count + ci) as u32;
+                let i11 = ((gi + 1) * c_count + ci + 1) as u32;
+                // Same winding as the full mesh
+                indices.extend_from_slice(&[i00, i01, i10, i01, i11, i10]);
+            }
+        }
+
+        LdcMesh {
+            vertices,
+            indices,
+            c_divisions: c_count,
+            g_divisions: rows.len(),
+        }
+    }
+
     /// Get vertex positions as a flat array [x0, y0, z0, x1, y1, z1, ...].
     ///
     /// Useful for graphics APIs that expect interleaved or separate position data.
@@ -311,6 +509,46 @@ impl LdcMesh {
     }
 }
 
+/// `count` angles from 0 to `range`, denser where `weights` are high
+///
+/// `weights` holds one value per equal interval of `range`. Half of the
+/// angles follow the weights, half are spread evenly.
+fn adaptive_angles(weights: &[f64], range: f64, count: usize) -> Vec<f64> {
+    let total: f64 = weights.iter().sum();
+    let uniform = if total > 0.0 {
+        total / weights.len() as f64
+    } else {
+        1.0
+    };
+    let density: Vec<f64> = weights.iter().map(|w| w + uniform).collect();
+    let sum: f64 = density.iter().sum();
+    let width = range / weights.len() as f64;
+
+    // Invert the cumulative density at equal steps
+    let mut angles = Vec::with_capacity(count);
+    let mut cumulative = 0.0;
+    let mut interval = 0;
+    for k in 0..count {
+        let target = sum * k as f64 / (count - 1) as f64;
+        while interval < density.len() - 1 && cumulative + density[interval] < target {
+            cumulative += density[interval];
+            interval += 1;
+        }
+        let t = ((target - cumulative) / density[interval]).clamp(0.0, 1.0);
+        angles.push(((interval as f64 + t) * width).min(range));
+    }
+    angles[0] = 0.0;
+    angles[count - 1] = range;
+    angles
+}
+
+/// `intervals + 1` indices from 0 to `last` inclusive, evenly spread
+fn spread(last: usize, intervals: usize) -> Vec<usize> {
+    (0..=intervals)
+        .map(|k| (k * last + intervals / 2) / intervals)
+        .collect()
+}
+
 /// A colored 3D mesh with positions, normals, colors, and indices.
 ///
 /// This is a convenience wrapper that combines `LdcMesh` with per-vertex colors.
@@ -401,6 +639,20 @@ impl PhotometricWeb {
         ColoredLdcMesh::from_photweb(self, c_step, g_step, scale, color_mode)
     }
 
+    /// Generate an LDC solid mesh with adaptive angle steps.
+    ///
+    /// See [`LdcMesh::from_photweb_adaptive`].
+    pub fn generate_adaptive_ldc_mesh(&self, vertex_budget: usize, scale: f32) -> LdcMesh {
+        LdcMesh::from_photweb_adaptive(self, vertex_budget, scale)
+    }
+
+    /// Generate an LDC solid mesh with smoothed normals.
+    ///
+    /// See [`LdcMesh::with_smooth_normals`].
+    pub fn generate_smooth_ldc_mesh(&self, c_step: f64, g_step: f64, scale: f32) -> LdcMesh {
+        LdcMesh::from_photweb(self, c_step, g_step, scale).with_smooth_normals()
+    }
+
     /// Generate just the vertex positions for the LDC solid.
     ///
     /// Returns a vector of (x, y, z) tuples.
@@ -479,6 +731,161 @@ mod tests {
         assert!(zenith.is_some(), "Should have zenith vertex");
     }
 
+    #[test]
+    fn test_winding_faces_outward() {
+        let web = create_uniform_web();
+        let mesh = web.generate_ldc_mesh(30.0, 30.0, 1.0);
+
+        for tri in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| mesh.vertices[i as usize]);
+            let u = [b.x - a.x, b.y - a.y, b.z - a.z];
+            let v = [c.x - a.x, c.y - a.y, c.z - a.z];
+            let n = [
+                u[1] * v[2] - u[2] * v[1],
+                u[2] * v[0] - u[0] * v[2],
+                u[0] * v[1] - u[1] * v[0],
+            ];
+            let centroid = [a.x + b.x + c.x, a.y + b.y + c.y, a.z + b.z + c.z];
+            let dot: f32 = (0..3).map(|k| n[k] * centroid[k]).sum();
+            assert!(dot >= -1e-6, "Triangle {:?} faces inward", tri);
+        }
+    }
+
+    #[test]
+    fn test_smooth_normals_seam_and_poles() {
+        // Intensity varying with C, so radial and surface normals differ
+        let web = PhotometricWeb::new(
+            vec![0.0, 90.0, 180.0, 270.0],
+            vec![0.0, 45.0, 90.0, 135.0, 180.0],
+            vec![
+                vec![100.0, 90.0, 60.0, 20.0, 5.0],
+                vec![100.0, 60.0, 30.0, 10.0, 5.0],
+                vec![100.0, 90.0, 60.0, 20.0, 5.0],
+                vec![100.0, 60.0, 30.0, 10.0, 5.0],
+            ],
+            Symmetry::None,
+        );
+        let mesh = web.generate_smooth_ldc_mesh(15.0, 15.0, 1.0);
+        let (c_count, g_count) = (mesh.c_divisions, mesh.g_divisions);
+
+        for gi in 0..g_count {
+            let first = mesh.vertices[gi * c_count];
+            let last = mesh.vertices[gi * c_count + c_count - 1];
+            // Seam vertices share position and normal
+            assert!((first.x - last.x).abs() < 1e-5 && (first.z - last.z).abs() < 1e-5);
+            assert!((first.nx - last.nx).abs() < 1e-5);
+            assert!((first.ny - last.ny).abs() < 1e-5);
+            assert!((first.nz - last.nz).abs() < 1e-5);
+
+            let len = (first.nx * first.nx + first.ny * first.ny + first.nz * first.nz).sqrt();
+            assert!((len - 1.0).abs() < 1e-4);
+        }
+
+        // Closed poles: one point and one normal, facing away from the solid
+        let nadir = &mesh.vertices[..c_count];
+        assert!(nadir.iter().all(|v| v.y == nadir[0].y && v.x.abs() < 1e-6));
+        assert!(nadir
+            .iter()
+            .all(|v| v.nx == nadir[0].nx && v.ny == nadir[0].ny));
+        assert!(nadir[0].ny < -0.9);
+        let zenith = &mesh.vertices[(g_count - 1) * c_count..];
+        assert!(zenith.iter().all(|v| v.y == zenith[0].y));
+        assert!(zenith
+            .iter()
+            .all(|v| v.nx == zenith[0].nx && v.ny == zenith[0].ny));
+        assert!(zenith[0].ny > 0.9);
+    }
+
+    #[test]
+    fn test_adaptive_mesh_follows_beam() {
+        // Narrow beam: full intensity up to 10°, dark from 20°
+        let g_angles: Vec<f64> = (0..=36).map(|i| i as f64 * 5.0).collect();
+        let column: Vec<f64> = g_angles
+            .iter()
+            .map(|&g| {
+                if g <= 10.0 {
+                    1000.0
+                } else if g <= 20.0 {
+                    300.0
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        let web = PhotometricWeb::new(
+            vec![0.0, 90.0, 180.0, 270.0],
+            g_angles,
+            vec![column; 4],
+            Symmetry::None,
+        );
+
+        let mesh = web.generate_adaptive_ldc_mesh(800, 1.0);
+        assert!(mesh.vertex_count() <= 800);
+        assert!(mesh.vertex_count() > 600);
+        for &idx in &mesh.indices {
+            assert!((idx as usize) < mesh.vertex_count());
+        }
+
+        // Gamma rows from the vertices of the C=0° column
+        let rows: Vec<f64> = (0..mesh.g_divisions)
+            .map(|gi| {
+                let v = mesh.vertices[gi * mesh.c_divisions];
+                let r = (v.x * v.x + v.y * v.y + v.z * v.z).sqrt();
+                if r > 1e-6 {
+                    (-v.y / r).clamp(-1.0, 1.0).acos().to_degrees() as f64
+                } else {
+                    f64::NAN
+                }
+            })
+            .collect();
+        let in_beam = rows.iter().filter(|&&g| g > 5.0 && g < 25.0).count();
+        // Even steps would put about a ninth of the rows there
+        assert!(
+            in_beam * 9 > 2 * mesh.g_divisions,
+            "{in_beam} of {} rows in the beam edge",
+            mesh.g_divisions
+        );
+    }
+
+    #[test]
+    fn test_adaptive_angles_uniform() {
+        let angles = adaptive_angles(&[0.0; 10], 180.0, 7);
+        for (i, &angle) in angles.iter().enumerate() {
+            assert!((angle - i as f64 * 30.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_lod_chain() {
+        let web = create_uniform_web();
+        let mesh = web.generate_ldc_mesh(5.0, 5.0, 1.0);
+        let full = mesh.triangle_count();
+        let lods = mesh.generate_lods(&[full + 1, 2000, 500, 100, 1]);
+        assert_eq!(lods.len(), 5);
+        assert_eq!(lods[0].triangle_count(), full);
+
+        let mut previous = full;
+        for (lod, target) in lods[1..].iter().zip([2000, 500, 100, 16]) {
+            let count = lod.triangle_count();
+            assert!(count <= target && count <= previous, "{count} > {target}");
+            assert!(count * 2 > target / 2, "{count} far below {target}");
+            assert_eq!(lod.vertex_count(), lod.c_divisions * lod.g_divisions);
+            assert!(lod
+                .indices
+                .iter()
+                .all(|&i| (i as usize) < lod.vertex_count()));
+            // Seam and poles survive
+            let first = lod.vertices[0];
+            let last_row = &lod.vertices[(lod.g_divisions - 1) * lod.c_divisions..];
+            assert_eq!(first, mesh.vertices[0]);
+            assert_eq!(
+                last_row[lod.c_divisions - 1],
+                *mesh.vertices.last().unwrap()
+            );
+            previous = count;
+        }
+    }
+
     #[test]
     fn test_flat_arrays() {
         let web = create_uniform_web();
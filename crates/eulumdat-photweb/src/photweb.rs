@@ -1,6 +1,7 @@
 //! PhotometricWeb - Core representation of a luminous intensity distribution
 
-use eulumdat::{Eulumdat, Symmetry};
+use eulumdat::compact::{Compression, IntensityGrid};
+use eulumdat::{Eulumdat, Interpolation, Symmetry};
 
 /// A photometric web representing the full 3D luminous intensity distribution.
 ///
@@ -9,17 +10,21 @@ use eulumdat::{Eulumdat, Symmetry};
 #[derive(Debug, Clone)]
 pub struct PhotometricWeb {
     /// C-plane angles in degrees (0-360)
-    c_angles: Vec<f64>,
+    pub(crate) c_angles: Vec<f64>,
     /// Gamma angles in degrees (0-180)
-    g_angles: Vec<f64>,
+    pub(crate) g_angles: Vec<f64>,
     /// Intensity values in cd/klm, indexed as `[c_index][g_index]`
-    intensities: Vec<Vec<f64>>,
+    pub(crate) intensities: Vec<Vec<f64>>,
     /// Symmetry type
-    symmetry: Symmetry,
+    pub(crate) symmetry: Symmetry,
     /// Maximum intensity value (cached)
-    max_intensity: f64,
+    pub(crate) max_intensity: f64,
     /// Minimum intensity value (cached)
-    min_intensity: f64,
+    pub(crate) min_intensity: f64,
+    /// Interpolation used by [`Self::sample`]
+    pub(crate) interpolation: Interpolation,
+    /// C-plane angles expanded over the full circle (cached)
+    pub(crate) full_c_angles: Vec<f64>,
 }
 
 impl PhotometricWeb {
@@ -40,6 +45,7 @@ impl PhotometricWeb {
             .flat_map(|row| row.iter())
             .copied()
             .fold(f64::MAX, f64::min);
+        let full_c_angles = expand_c_angles(&c_angles, symmetry);
 
         Self {
             c_angles,
@@ -48,10 +54,26 @@ impl PhotometricWeb {
             symmetry,
             max_intensity,
             min_intensity,
+            interpolation: Interpolation::default(),
+            full_c_angles,
         }
     }
 
-    /// Sample intensity at any C and G angle using bilinear interpolation.
+    /// Use a different interpolation for [`Self::sample`] (default: bilinear).
+    ///
+    /// Bicubic sampling removes the faceting of bilinear interpolation on
+    /// coarse grids, e.g. in dense meshes and renders.
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Get the interpolation used by [`Self::sample`].
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Sample intensity at any C and G angle using the configured interpolation.
     ///
     /// Handles symmetry automatically - you can query any angle in the full
     /// 0-360° C range and 0-180° G range regardless of stored symmetry.
@@ -63,20 +85,33 @@ impl PhotometricWeb {
     /// # Returns
     /// Intensity in cd/klm
     pub fn sample(&self, c_angle: f64, g_angle: f64) -> f64 {
-        // Normalize C angle to 0-360 range
-        let c_normalized = c_angle.rem_euclid(360.0);
-        // Clamp G angle to 0-180 range
-        let g_clamped = g_angle.clamp(0.0, 180.0);
-
-        // Find the effective C based on symmetry
-        let effective_c = self.apply_symmetry(c_normalized);
+        self.sample_with(c_angle, g_angle, self.interpolation)
+    }
 
-        // Find interpolation indices
-        let (ci, cf) = self.find_interpolation_index(&self.c_angles, effective_c);
-        let (gi, gf) = self.find_interpolation_index(&self.g_angles, g_clamped);
+    /// Sample intensity with an explicit interpolation.
+    pub fn sample_with(&self, c_angle: f64, g_angle: f64, interpolation: Interpolation) -> f64 {
+        match interpolation {
+            Interpolation::Bilinear => self.sample_bilinear(c_angle, g_angle),
+            _ => interpolation.sample_grid(
+                &self.full_c_angles,
+                &self.g_angles,
+                c_angle,
+                g_angle,
+                |c, g| self.sample_bilinear(c, g),
+            ),
+        }
+    }
 
-        // Bilinear interpolation
-        self.bilinear_interpolate(ci, cf, gi, gf)
+    /// Bilinear interpolation between the stored planes.
+    fn sample_bilinear(&self, c_angle: f64, g_angle: f64) -> f64 {
+        sample_stored(
+            &self.c_angles,
+            &self.g_angles,
+            &self.intensities,
+            self.symmetry,
+            c_angle,
+            g_angle,
+        )
     }
 
     /// Sample normalized intensity (0.0 to 1.0) at any C and G angle.
@@ -90,6 +125,68 @@ impl PhotometricWeb {
         self.sample(c_angle, g_angle) / self.max_intensity
     }
 
+    /// Sample intensity in the direction of a vector.
+    ///
+    /// The vector is converted with [`direction_to_angles`], so symmetry is
+    /// handled as in [`Self::sample`].
+    ///
+    /// # Returns
+    /// Intensity in cd/klm
+    pub fn sample_dir(&self, direction: [f64; 3]) -> f64 {
+        let (c_angle, g_angle) = direction_to_angles(direction);
+        self.sample(c_angle, g_angle)
+    }
+
+    /// Sample normalized intensity (0.0 to 1.0) in the direction of a vector.
+    pub fn sample_dir_normalized(&self, direction: [f64; 3]) -> f64 {
+        let (c_angle, g_angle) = direction_to_angles(direction);
+        self.sample_normalized(c_angle, g_angle)
+    }
+
+    /// Polyline of `(gamma, intensity)` points through a C-plane pair.
+    ///
+    /// Gamma runs from -180° to 180° in steps of `step` degrees, at least
+    /// 0.1° (the steps next to the zenith may be shorter). Positive angles lie
+    /// in `c_plane`, negative ones in the opposite plane `c_plane + 180°`, as
+    /// on the two halves of a polar diagram.
+    pub fn cross_section(&self, c_plane: f64, step: f64) -> Vec<(f64, f64)> {
+        let opposite = c_plane + 180.0;
+        let step = step.max(0.1);
+        let count = (180.0 / step).ceil() as usize;
+        let gammas: Vec<f64> = (0..=count).map(|i| (i as f64 * step).min(180.0)).collect();
+
+        // The nadir is shared by both halves
+        let mut points: Vec<(f64, f64)> = gammas[1..]
+            .iter()
+            .rev()
+            .map(|&g| (-g, self.sample(opposite, g)))
+            .collect();
+        points.extend(gammas.iter().map(|&g| (g, self.sample(c_plane, g))));
+        points
+    }
+
+    /// Resample onto a uniform `n_c` × `n_g` grid over the full sphere.
+    ///
+    /// Symmetry is expanded: C runs over `n_c` planes `360° / n_c` apart from
+    /// C0 (C360 wraps to C0 and is left out), gamma over `n_g` angles from the
+    /// nadir to the zenith, both included. Intensities in cd/klm are stored
+    /// row by row, one gamma angle per row, so the value at C index `i` and
+    /// gamma index `j` is `grid[j * n_c + i]`. `n_c` is raised to at least 1
+    /// and `n_g` to at least 2.
+    pub fn to_grid(&self, n_c: usize, n_g: usize) -> Vec<f32> {
+        let n_c = n_c.max(1);
+        let n_g = n_g.max(2);
+        let mut grid = Vec::with_capacity(n_c * n_g);
+        for j in 0..n_g {
+            let g_angle = j as f64 * 180.0 / (n_g - 1) as f64;
+            for i in 0..n_c {
+                let c_angle = i as f64 * 360.0 / n_c as f64;
+                grid.push(self.sample(c_angle, g_angle) as f32);
+            }
+        }
+        grid
+    }
+
     /// Get the maximum intensity value.
     pub fn max_intensity(&self) -> f64 {
         self.max_intensity
@@ -115,89 +212,178 @@ impl PhotometricWeb {
         &self.g_angles
     }
 
-    /// Apply symmetry to map any C angle to the stored range.
-    fn apply_symmetry(&self, c_normalized: f64) -> f64 {
-        match self.symmetry {
-            Symmetry::None => c_normalized,
-            Symmetry::VerticalAxis => 0.0, // All C-planes are the same
-            Symmetry::PlaneC0C180 => {
-                if c_normalized <= 180.0 {
-                    c_normalized
-                } else {
-                    360.0 - c_normalized
-                }
-            }
-            Symmetry::PlaneC90C270 => {
-                // Mirror across the C90-C270 line (left-right symmetry)
-                // Data stored for C0-C180 (right half)
-                // C180-C360 mirrors to C180-C0
-                if c_normalized > 180.0 {
-                    360.0 - c_normalized // C225→C135, C270→C90, C315→C45
-                } else {
-                    c_normalized // C0-C180 stored as-is
-                }
-            }
-            Symmetry::BothPlanes => {
-                let in_first_half = c_normalized <= 180.0;
-                let c_in_half = if in_first_half {
-                    c_normalized
-                } else {
-                    360.0 - c_normalized
-                };
-                if c_in_half <= 90.0 {
-                    c_in_half
-                } else {
-                    180.0 - c_in_half
-                }
-            }
+    /// Encode to the compact binary format for caching or transfer.
+    ///
+    /// See [`eulumdat::compact`] for the layout and precision.
+    pub fn to_compact_bytes(&self, compression: Compression) -> Vec<u8> {
+        IntensityGrid {
+            symmetry: self.symmetry,
+            c_angles: self.c_angles.clone(),
+            g_angles: self.g_angles.clone(),
+            intensities: self.intensities.clone(),
         }
+        .to_bytes(compression)
     }
 
-    /// Find interpolation index and fraction for a target angle.
-    fn find_interpolation_index(&self, angles: &[f64], target: f64) -> (usize, f64) {
-        if angles.is_empty() {
-            return (0, 0.0);
-        }
+    /// Decode from the compact binary format.
+    pub fn from_compact_bytes(bytes: &[u8]) -> eulumdat::Result<Self> {
+        let grid = IntensityGrid::from_bytes(bytes)?;
+        Ok(Self::new(
+            grid.c_angles,
+            grid.g_angles,
+            grid.intensities,
+            grid.symmetry,
+        ))
+    }
+}
 
-        if target <= angles[0] {
-            return (0, 0.0);
-        }
+/// Bilinear interpolation on a stored grid `intensities[c][g]`.
+///
+/// Any C angle is first mapped into the stored range of `symmetry`; angles
+/// outside the stored planes are clamped to the nearest one.
+pub(crate) fn sample_stored(
+    c_angles: &[f64],
+    g_angles: &[f64],
+    intensities: &[Vec<f64>],
+    symmetry: Symmetry,
+    c_angle: f64,
+    g_angle: f64,
+) -> f64 {
+    // Normalize C angle to 0-360 range
+    let c_normalized = c_angle.rem_euclid(360.0);
+    // Clamp G angle to 0-180 range
+    let g_clamped = g_angle.clamp(0.0, 180.0);
+
+    // Find the effective C based on symmetry
+    let effective_c = apply_symmetry(symmetry, c_normalized);
+
+    // Find interpolation indices
+    let (ci, cf) = find_interpolation_index(c_angles, effective_c);
+    let (gi, gf) = find_interpolation_index(g_angles, g_clamped);
+
+    let get = |c: usize, g: usize| -> f64 {
+        intensities
+            .get(c)
+            .and_then(|row| row.get(g))
+            .copied()
+            .unwrap_or(0.0)
+    };
 
-        if target >= angles[angles.len() - 1] {
-            return (angles.len() - 1, 0.0);
-        }
+    let i00 = get(ci, gi);
+    let i01 = get(ci, gi + 1);
+    let i10 = get(ci + 1, gi);
+    let i11 = get(ci + 1, gi + 1);
+
+    // Bilinear interpolation
+    let i0 = i00 * (1.0 - gf) + i01 * gf;
+    let i1 = i10 * (1.0 - gf) + i11 * gf;
+
+    i0 * (1.0 - cf) + i1 * cf
+}
 
-        for i in 0..angles.len() - 1 {
-            if target >= angles[i] && target <= angles[i + 1] {
-                let fraction = (target - angles[i]) / (angles[i + 1] - angles[i]);
-                return (i, fraction);
+/// Map any C angle to the stored range of `symmetry`.
+fn apply_symmetry(symmetry: Symmetry, c_normalized: f64) -> f64 {
+    match symmetry {
+        Symmetry::None => c_normalized,
+        Symmetry::VerticalAxis => 0.0, // All C-planes are the same
+        Symmetry::PlaneC0C180 => {
+            if c_normalized <= 180.0 {
+                c_normalized
+            } else {
+                360.0 - c_normalized
+            }
+        }
+        Symmetry::PlaneC90C270 => {
+            // Mirror across the C90-C270 line (left-right symmetry)
+            // Data stored for C0-C180 (right half)
+            // C180-C360 mirrors to C180-C0
+            if c_normalized > 180.0 {
+                360.0 - c_normalized // C225→C135, C270→C90, C315→C45
+            } else {
+                c_normalized // C0-C180 stored as-is
             }
         }
+        Symmetry::BothPlanes => {
+            let in_first_half = c_normalized <= 180.0;
+            let c_in_half = if in_first_half {
+                c_normalized
+            } else {
+                360.0 - c_normalized
+            };
+            if c_in_half <= 90.0 {
+                c_in_half
+            } else {
+                180.0 - c_in_half
+            }
+        }
+    }
+}
 
-        (angles.len() - 1, 0.0)
+/// Find interpolation index and fraction for a target angle.
+fn find_interpolation_index(angles: &[f64], target: f64) -> (usize, f64) {
+    if angles.is_empty() {
+        return (0, 0.0);
     }
 
-    /// Perform bilinear interpolation.
-    fn bilinear_interpolate(&self, ci: usize, cf: f64, gi: usize, gf: f64) -> f64 {
-        let get = |c: usize, g: usize| -> f64 {
-            self.intensities
-                .get(c)
-                .and_then(|row| row.get(g))
-                .copied()
-                .unwrap_or(0.0)
-        };
+    if target <= angles[0] {
+        return (0, 0.0);
+    }
 
-        let i00 = get(ci, gi);
-        let i01 = get(ci, gi + 1);
-        let i10 = get(ci + 1, gi);
-        let i11 = get(ci + 1, gi + 1);
+    if target >= angles[angles.len() - 1] {
+        return (angles.len() - 1, 0.0);
+    }
+
+    for i in 0..angles.len() - 1 {
+        if target >= angles[i] && target <= angles[i + 1] {
+            let fraction = (target - angles[i]) / (angles[i + 1] - angles[i]);
+            return (i, fraction);
+        }
+    }
 
-        // Bilinear interpolation
-        let i0 = i00 * (1.0 - gf) + i01 * gf;
-        let i1 = i10 * (1.0 - gf) + i11 * gf;
+    (angles.len() - 1, 0.0)
+}
 
-        i0 * (1.0 - cf) + i1 * cf
+/// C-plane and gamma angles in degrees of a direction vector.
+///
+/// Directions follow the mesh coordinate system: Y up with the nadir
+/// (gamma 0°) at -Y, C=0° along +Z and C=90° along +X. The vector need not
+/// be normalized; a zero vector gives the nadir.
+pub fn direction_to_angles([x, y, z]: [f64; 3]) -> (f64, f64) {
+    let len = (x * x + y * y + z * z).sqrt();
+    if len <= 0.0 {
+        return (0.0, 0.0);
     }
+    let g = (-y / len).clamp(-1.0, 1.0).acos().to_degrees();
+    let c = x.atan2(z).to_degrees().rem_euclid(360.0);
+    (c, g)
+}
+
+/// Unit direction vector of C-plane and gamma angles in degrees.
+///
+/// The inverse of [`direction_to_angles`].
+pub fn angles_to_direction(c_angle: f64, g_angle: f64) -> [f64; 3] {
+    let (sin_c, cos_c) = c_angle.to_radians().sin_cos();
+    let (sin_g, cos_g) = g_angle.to_radians().sin_cos();
+    [sin_g * sin_c, -cos_g, sin_g * cos_c]
+}
+
+/// Stored C-plane angles mirrored over the full 0–360° circle.
+fn expand_c_angles(c_angles: &[f64], symmetry: Symmetry) -> Vec<f64> {
+    let mut full: Vec<f64> = match symmetry {
+        Symmetry::None => c_angles.to_vec(),
+        Symmetry::VerticalAxis => return vec![0.0],
+        Symmetry::PlaneC0C180 | Symmetry::PlaneC90C270 => {
+            c_angles.iter().flat_map(|&c| [c, 360.0 - c]).collect()
+        }
+        Symmetry::BothPlanes => c_angles
+            .iter()
+            .flat_map(|&c| [c, 180.0 - c, 180.0 + c, 360.0 - c])
+            .collect(),
+    };
+    full.retain(|c| (0.0..360.0).contains(c));
+    full.sort_by(|a, b| a.total_cmp(b));
+    full.dedup_by(|a, b| (*a - *b).abs() < 1e-9);
+    full
 }
 
 impl From<&Eulumdat> for PhotometricWeb {
@@ -261,6 +447,32 @@ mod tests {
         assert!((i - 50.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_with_interpolation() {
+        let web = create_test_web().with_interpolation(Interpolation::Nearest);
+        assert_eq!(web.interpolation(), Interpolation::Nearest);
+        // Snaps to C90 G45
+        assert!((web.sample(80.0, 50.0) - 70.0).abs() < 0.001);
+        // Explicit bilinear still interpolates
+        let bilinear = web.sample_with(45.0, 0.0, Interpolation::Bilinear);
+        assert!((bilinear - 95.0).abs() < 0.001);
+
+        let cubic = create_test_web().with_interpolation(Interpolation::Bicubic);
+        assert!((cubic.sample(90.0, 45.0) - 70.0).abs() < 0.001);
+        assert!((cubic.sample(180.0, 135.0) - 20.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_compact_roundtrip() {
+        let web = create_test_web();
+        let bytes = web.to_compact_bytes(Compression::None);
+        let restored = PhotometricWeb::from_compact_bytes(&bytes).unwrap();
+        assert_eq!(restored.symmetry(), web.symmetry());
+        assert_eq!(restored.c_angles(), web.c_angles());
+        assert!((restored.sample(45.0, 30.0) - web.sample(45.0, 30.0)).abs() < 1e-4);
+        assert_eq!(restored.max_intensity(), web.max_intensity());
+    }
+
     #[test]
     fn test_sample_normalized() {
         let web = create_test_web();
@@ -274,6 +486,94 @@ mod tests {
         assert!((n - 0.5).abs() < 0.001);
     }
 
+    #[test]
+    fn test_sample_dir() {
+        let web = create_test_web();
+
+        // Nadir, C0 and C90 at the horizon, zenith
+        assert!((web.sample_dir([0.0, -1.0, 0.0]) - 100.0).abs() < 0.001);
+        assert!((web.sample_dir([0.0, 0.0, 2.0]) - 50.0).abs() < 0.001);
+        assert!((web.sample_dir([3.0, 0.0, 0.0]) - 40.0).abs() < 0.001);
+        assert!((web.sample_dir([0.0, 1.0, 0.0]) - 10.0).abs() < 0.001);
+        // C270 at gamma 45°
+        let dir = angles_to_direction(270.0, 45.0);
+        assert!(dir[0] < 0.0 && dir[1] < 0.0);
+        assert!((web.sample_dir(dir) - 65.0).abs() < 0.001);
+
+        // Symmetric web: C270 mirrors C90
+        let symmetric = PhotometricWeb::new(
+            vec![0.0, 90.0, 180.0],
+            vec![0.0, 90.0, 180.0],
+            vec![
+                vec![100.0, 50.0, 0.0],
+                vec![80.0, 40.0, 0.0],
+                vec![60.0, 30.0, 0.0],
+            ],
+            Symmetry::PlaneC0C180,
+        );
+        assert!((symmetric.sample_dir([-1.0, 0.0, 0.0]) - 40.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_cross_section() {
+        let web = create_test_web();
+        let points = web.cross_section(90.0, 45.0);
+        let gammas: Vec<f64> = points.iter().map(|p| p.0).collect();
+        assert_eq!(
+            gammas,
+            vec![-180.0, -135.0, -90.0, -45.0, 0.0, 45.0, 90.0, 135.0, 180.0]
+        );
+        // C270 on the left, C90 on the right
+        assert!((points[1].1 - 22.0).abs() < 0.001);
+        assert!((points[6].1 - 40.0).abs() < 0.001);
+
+        // Shorter last step
+        let points = web.cross_section(0.0, 50.0);
+        assert_eq!(points.len(), 9);
+        assert_eq!(points.last().unwrap().0, 180.0);
+
+        // Zero, negative and tiny steps are raised to 0.1° and still reach
+        // both zeniths
+        for step in [0.0, -10.0, 0.05] {
+            let points = web.cross_section(0.0, step);
+            assert_eq!(points.len(), 3601, "step {step}");
+            assert_eq!(points[0].0, -180.0);
+            assert!((points[1].0 + 179.9).abs() < 1e-9);
+            assert_eq!(points[1800].0, 0.0);
+            assert_eq!(points[3600].0, 180.0);
+        }
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let web = create_test_web();
+        let grid = web.to_grid(4, 5);
+        assert_eq!(grid.len(), 20);
+        // Rows of gamma 0°, 45°, ... with C0, C90, C180, C270
+        assert_eq!(&grid[..4], &[100.0, 90.0, 80.0, 85.0]);
+        assert_eq!(grid[2 * 4 + 1], 40.0);
+        assert_eq!(&grid[16..], &[10.0, 8.0, 5.0, 6.0]);
+
+        // Symmetry is expanded
+        let symmetric = PhotometricWeb::new(
+            vec![0.0, 90.0],
+            vec![0.0, 180.0],
+            vec![vec![100.0, 0.0], vec![50.0, 0.0]],
+            Symmetry::BothPlanes,
+        );
+        assert_eq!(&symmetric.to_grid(4, 2)[..4], &[100.0, 50.0, 100.0, 50.0]);
+        assert_eq!(symmetric.to_grid(0, 0).len(), 2);
+    }
+
+    #[test]
+    fn test_direction_angles_roundtrip() {
+        for (c, g) in [(0.0, 30.0), (90.0, 90.0), (200.0, 120.0), (315.0, 10.0)] {
+            let (c2, g2) = direction_to_angles(angles_to_direction(c, g));
+            assert!((c - c2).abs() < 1e-9 && (g - g2).abs() < 1e-9);
+        }
+        assert_eq!(direction_to_angles([0.0, 0.0, 0.0]), (0.0, 0.0));
+    }
+
     #[test]
     fn test_symmetry_both_planes() {
         let web = PhotometricWeb::new(
@@ -0,0 +1,213 @@
+//! Importance sampling of emission directions for path tracers
+//!
+//! The sphere is divided into cells of equal C and gamma steps. Each cell is
+//! chosen with a probability proportional to its intensity times its solid
+//! angle, and directions are uniform in solid angle within the cell, so the
+//! density follows the intensity up to the cell resolution.
+
+use std::f64::consts::PI;
+
+use crate::{angles_to_direction, direction_to_angles, PhotometricWeb};
+
+/// Importance sampler of emission directions built from a [`PhotometricWeb`].
+///
+/// Directions are unit vectors as in [`direction_to_angles`]; densities are
+/// per steradian.
+#[derive(Debug, Clone)]
+pub struct DirectionSampler {
+    c_bins: usize,
+    g_bins: usize,
+    /// Cumulative weight of the gamma rows, ending at 1 up to rounding
+    row_cdf: Vec<f64>,
+    /// Cumulative weight of the cells within each row, each ending at 1
+    /// (or all zero for rows without light)
+    cell_cdf: Vec<Vec<f64>>,
+    /// Density per steradian of each cell, row by row
+    cell_pdf: Vec<f64>,
+}
+
+impl DirectionSampler {
+    /// Build the sampler with `c_bins` × `g_bins` cells (at least 1 × 1).
+    ///
+    /// Intensities are sampled at the cell centers. A web without any light
+    /// gives uniform sampling over the sphere.
+    pub fn new(web: &PhotometricWeb, c_bins: usize, g_bins: usize) -> Self {
+        let c_bins = c_bins.max(1);
+        let g_bins = g_bins.max(1);
+        let c_width = 2.0 * PI / c_bins as f64;
+
+        let mut weights = Vec::with_capacity(g_bins);
+        let mut solid_angles = Vec::with_capacity(g_bins);
+        for gi in 0..g_bins {
+            let (g0, g1) = (gi as f64 / g_bins as f64, (gi + 1) as f64 / g_bins as f64);
+            let solid_angle = c_width * ((g0 * PI).cos() - (g1 * PI).cos());
+            let g_center = (gi as f64 + 0.5) * 180.0 / g_bins as f64;
+            let row: Vec<f64> = (0..c_bins)
+                .map(|ci| {
+                    let c_center = (ci as f64 + 0.5) * 360.0 / c_bins as f64;
+                    web.sample(c_center, g_center).max(0.0) * solid_angle
+                })
+                .collect();
+            weights.push(row);
+            solid_angles.push(solid_angle);
+        }
+        let mut total: f64 = weights.iter().flatten().sum();
+        if total <= 0.0 {
+            // No light: uniform over the sphere
+            for (row, &solid_angle) in weights.iter_mut().zip(&solid_angles) {
+                row.fill(solid_angle);
+            }
+            total = 4.0 * PI;
+        }
+
+        let mut row_cdf = Vec::with_capacity(g_bins);
+        let mut cell_cdf = Vec::with_capacity(g_bins);
+        let mut cell_pdf = Vec::with_capacity(g_bins * c_bins);
+        let mut cumulative = 0.0;
+        for (row, &solid_angle) in weights.iter().zip(&solid_angles) {
+            let row_total: f64 = row.iter().sum();
+            cumulative += row_total / total;
+            row_cdf.push(cumulative);
+
+            let mut running = 0.0;
+            cell_cdf.push(
+                row.iter()
+                    .map(|w| {
+                        running += w;
+                        if row_total > 0.0 {
+                            running / row_total
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect(),
+            );
+            cell_pdf.extend(row.iter().map(|w| w / total / solid_angle));
+        }
+
+        Self {
+            c_bins,
+            g_bins,
+            row_cdf,
+            cell_cdf,
+            cell_pdf,
+        }
+    }
+
+    /// Sample a unit direction from two uniform random numbers in [0, 1).
+    ///
+    /// Returns the direction and its density per steradian.
+    pub fn sample_direction(&self, u1: f64, u2: f64) -> ([f64; 3], f64) {
+        let (gi, g_offset) = pick(&self.row_cdf, u1);
+        let (ci, c_offset) = pick(&self.cell_cdf[gi], u2);
+
+        // Uniform in cos(gamma) within the row, i.e. uniform in solid angle
+        let cos0 = (gi as f64 / self.g_bins as f64 * PI).cos();
+        let cos1 = ((gi + 1) as f64 / self.g_bins as f64 * PI).cos();
+        let g_angle = (cos0 + (cos1 - cos0) * g_offset)
+            .clamp(-1.0, 1.0)
+            .acos()
+            .to_degrees();
+        let c_angle = (ci as f64 + c_offset) * 360.0 / self.c_bins as f64;
+
+        let pdf = self.cell_pdf[gi * self.c_bins + ci];
+        (angles_to_direction(c_angle, g_angle), pdf)
+    }
+
+    /// Density per steradian of sampling `direction` (need not be normalized).
+    pub fn pdf(&self, direction: [f64; 3]) -> f64 {
+        let (c_angle, g_angle) = direction_to_angles(direction);
+        let ci = ((c_angle / 360.0 * self.c_bins as f64) as usize).min(self.c_bins - 1);
+        let gi = ((g_angle / 180.0 * self.g_bins as f64) as usize).min(self.g_bins - 1);
+        self.cell_pdf[gi * self.c_bins + ci]
+    }
+}
+
+/// Index of the `cdf` entry `u` falls into, and the offset of `u` within it
+///
+/// `u` is scaled to the last entry, so entries of zero weight are never
+/// picked.
+fn pick(cdf: &[f64], u: f64) -> (usize, f64) {
+    let u = u.clamp(0.0, 1.0 - f64::EPSILON) * cdf[cdf.len() - 1];
+    let i = cdf.partition_point(|&c| c <= u).min(cdf.len() - 1);
+    let start = if i == 0 { 0.0 } else { cdf[i - 1] };
+    let width = cdf[i] - start;
+    let offset = if width > 0.0 {
+        ((u - start) / width).clamp(0.0, 1.0)
+    } else {
+        0.5
+    };
+    (i, offset)
+}
+
+impl PhotometricWeb {
+    /// Importance sampler of emission directions with 1° cells.
+    ///
+    /// See [`DirectionSampler`]; use [`DirectionSampler::new`] for another
+    /// resolution.
+    pub fn direction_sampler(&self) -> DirectionSampler {
+        DirectionSampler::new(self, 360, 180)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eulumdat::Symmetry;
+
+    fn downlight() -> PhotometricWeb {
+        PhotometricWeb::new(
+            vec![0.0, 90.0, 180.0, 270.0],
+            vec![0.0, 30.0, 60.0, 90.0, 180.0],
+            vec![vec![100.0, 80.0, 20.0, 0.0, 0.0]; 4],
+            Symmetry::None,
+        )
+    }
+
+    #[test]
+    fn test_pdf_integrates_to_one() {
+        let sampler = DirectionSampler::new(&downlight(), 72, 36);
+        // Midpoint rule over 1° × 1° cells
+        let mut integral = 0.0;
+        for gi in 0..180 {
+            let g = gi as f64 + 0.5;
+            let solid_angle = (1.0f64).to_radians().powi(2) * g.to_radians().sin();
+            for ci in 0..360 {
+                let dir = angles_to_direction(ci as f64 + 0.5, g);
+                integral += sampler.pdf(dir) * solid_angle;
+            }
+        }
+        assert!((integral - 1.0).abs() < 0.01, "integral {integral}");
+    }
+
+    #[test]
+    fn test_samples_follow_distribution() {
+        let sampler = downlight().direction_sampler();
+        let n = 64;
+        for i in 0..n {
+            for j in 0..n {
+                let (u1, u2) = ((i as f64 + 0.5) / n as f64, (j as f64 + 0.5) / n as f64);
+                let (dir, pdf) = sampler.sample_direction(u1, u2);
+                let len = (dir[0] * dir[0] + dir[1] * dir[1] + dir[2] * dir[2]).sqrt();
+                assert!((len - 1.0).abs() < 1e-9);
+                // Nothing above the horizon
+                assert!(dir[1] < 1e-9, "sample {dir:?} above the horizon");
+                assert!(pdf > 0.0);
+                assert!((pdf - sampler.pdf(dir)).abs() < 1e-9 * pdf.max(1.0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_dark_web_samples_uniformly() {
+        let web = PhotometricWeb::new(
+            vec![0.0],
+            vec![0.0, 180.0],
+            vec![vec![0.0, 0.0]],
+            Symmetry::VerticalAxis,
+        );
+        let sampler = DirectionSampler::new(&web, 8, 8);
+        let (_, pdf) = sampler.sample_direction(0.3, 0.7);
+        assert!((pdf - 1.0 / (4.0 * PI)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,241 @@
+//! Sources of a luminous intensity distribution
+//!
+//! [`PhotometricSource`] abstracts over the formats a distribution is read
+//! from, so webs and meshes can be built from an EULUMDAT file or, with the
+//! `atla` feature, from an ATLA emitter without converting it to LDT first.
+
+use eulumdat::{Eulumdat, Symmetry};
+
+use crate::mesh::LdcMesh;
+use crate::PhotometricWeb;
+
+/// A luminous intensity distribution that can be sampled at any angle.
+///
+/// Angles are C-plane and gamma angles in degrees; intensities are in the
+/// units of the source (cd/klm for EULUMDAT).
+pub trait PhotometricSource {
+    /// Intensity at any C angle (wrapped to 0–360°) and gamma angle (0–180°).
+    fn sample(&self, c_angle: f64, g_angle: f64) -> f64;
+
+    /// Largest stored intensity.
+    fn max_intensity(&self) -> f64;
+
+    /// Symmetry of the stored data, which decides the C range
+    /// [`PhotometricWeb::from_source`] samples.
+    fn symmetry(&self) -> Symmetry;
+}
+
+impl PhotometricSource for PhotometricWeb {
+    fn sample(&self, c_angle: f64, g_angle: f64) -> f64 {
+        PhotometricWeb::sample(self, c_angle, g_angle)
+    }
+
+    fn max_intensity(&self) -> f64 {
+        PhotometricWeb::max_intensity(self)
+    }
+
+    fn symmetry(&self) -> Symmetry {
+        PhotometricWeb::symmetry(self)
+    }
+}
+
+impl PhotometricSource for Eulumdat {
+    fn sample(&self, c_angle: f64, g_angle: f64) -> f64 {
+        Eulumdat::sample(self, c_angle, g_angle)
+    }
+
+    fn max_intensity(&self) -> f64 {
+        Eulumdat::max_intensity(self)
+    }
+
+    fn symmetry(&self) -> Symmetry {
+        self.symmetry
+    }
+}
+
+#[cfg(feature = "atla")]
+mod atla_source {
+    use super::*;
+    use crate::photweb::sample_stored;
+    use atla::{IntensityDistribution, SymmetryType};
+
+    /// Intensities scaled by the TM-33 multiplier
+    fn scaled(dist: &IntensityDistribution) -> Vec<Vec<f64>> {
+        let multiplier = dist.multiplier.map_or(1.0, f64::from);
+        dist.intensities
+            .iter()
+            .map(|row| row.iter().map(|v| v * multiplier).collect())
+            .collect()
+    }
+
+    impl PhotometricSource for IntensityDistribution {
+        fn sample(&self, c_angle: f64, g_angle: f64) -> f64 {
+            let value = sample_stored(
+                &self.horizontal_angles,
+                &self.vertical_angles,
+                &self.intensities,
+                PhotometricSource::symmetry(self),
+                c_angle,
+                g_angle,
+            );
+            value * self.multiplier.map_or(1.0, f64::from)
+        }
+
+        fn max_intensity(&self) -> f64 {
+            let max = self
+                .intensities
+                .iter()
+                .flatten()
+                .copied()
+                .fold(0.0, f64::max);
+            max * self.multiplier.map_or(1.0, f64::from)
+        }
+
+        /// The declared TM-33 symmetry, or the one implied by the range of
+        /// horizontal angles when none or an arbitrary one is declared.
+        fn symmetry(&self) -> Symmetry {
+            match self.symmetry {
+                Some(SymmetryType::Full) => return Symmetry::VerticalAxis,
+                Some(SymmetryType::Bi0) => return Symmetry::PlaneC0C180,
+                Some(SymmetryType::Bi90) => return Symmetry::PlaneC90C270,
+                Some(SymmetryType::Quad) => return Symmetry::BothPlanes,
+                Some(SymmetryType::None) | Some(SymmetryType::Arbitrary) | None => {}
+            }
+            let angles = &self.horizontal_angles;
+            let max = angles.iter().copied().fold(f64::MIN, f64::max);
+            let min = angles.iter().copied().fold(f64::MAX, f64::min);
+            if angles.len() <= 1 || max - min < 1.0 {
+                Symmetry::VerticalAxis
+            } else if max <= 90.5 {
+                Symmetry::BothPlanes
+            } else if max <= 180.5 {
+                Symmetry::PlaneC0C180
+            } else {
+                Symmetry::None
+            }
+        }
+    }
+
+    /// The stored grid as is, without resampling.
+    impl From<&IntensityDistribution> for PhotometricWeb {
+        fn from(dist: &IntensityDistribution) -> Self {
+            Self::new(
+                dist.horizontal_angles.clone(),
+                dist.vertical_angles.clone(),
+                scaled(dist),
+                PhotometricSource::symmetry(dist),
+            )
+        }
+    }
+}
+
+impl PhotometricWeb {
+    /// Build a web by sampling `source` every `c_step` × `g_step` degrees.
+    ///
+    /// Only the C range its symmetry requires is sampled: one plane for
+    /// rotational symmetry, 0–90° for both planes, 0–180° for one plane and
+    /// the full circle otherwise. Gamma always spans 0–180°.
+    pub fn from_source(source: &impl PhotometricSource, c_step: f64, g_step: f64) -> Self {
+        let symmetry = source.symmetry();
+        let c_end = match symmetry {
+            Symmetry::VerticalAxis => 0.0,
+            Symmetry::BothPlanes => 90.0,
+            Symmetry::PlaneC0C180 | Symmetry::PlaneC90C270 => 180.0,
+            Symmetry::None => 360.0,
+        };
+        let c_angles = steps(c_end, c_step);
+        let g_angles = steps(180.0, g_step);
+        let intensities = c_angles
+            .iter()
+            .map(|&c| g_angles.iter().map(|&g| source.sample(c, g)).collect())
+            .collect();
+        Self::new(c_angles, g_angles, intensities, symmetry)
+    }
+}
+
+impl LdcMesh {
+    /// Generate the LDC solid of any [`PhotometricSource`].
+    ///
+    /// The source is sampled into a [`PhotometricWeb`] with the mesh steps,
+    /// so the vertices lie on the source itself.
+    pub fn from_source(
+        source: &impl PhotometricSource,
+        c_step: f64,
+        g_step: f64,
+        scale: f32,
+    ) -> Self {
+        let web = PhotometricWeb::from_source(source, c_step, g_step);
+        Self::from_photweb(&web, c_step, g_step, scale)
+    }
+}
+
+/// Angles from 0 to `end` inclusive, `step` apart (the last step may be
+/// shorter)
+fn steps(end: f64, step: f64) -> Vec<f64> {
+    if end <= 0.0 {
+        return vec![0.0];
+    }
+    let count = (end / step.max(0.1)).ceil() as usize;
+    (0..=count)
+        .map(|i| (i as f64 * end / count as f64).min(end))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_source_matches_web() {
+        let web = PhotometricWeb::new(
+            vec![0.0, 45.0, 90.0],
+            vec![0.0, 30.0, 60.0, 90.0, 180.0],
+            vec![
+                vec![100.0, 90.0, 50.0, 10.0, 0.0],
+                vec![100.0, 80.0, 40.0, 5.0, 0.0],
+                vec![100.0, 70.0, 30.0, 0.0, 0.0],
+            ],
+            Symmetry::BothPlanes,
+        );
+        let resampled = PhotometricWeb::from_source(&web, 15.0, 10.0);
+        assert_eq!(resampled.symmetry(), Symmetry::BothPlanes);
+        assert_eq!(resampled.c_angles().len(), 7);
+        assert_eq!(resampled.g_angles().len(), 19);
+        for (c, g) in [(0.0, 0.0), (45.0, 30.0), (200.0, 60.0), (300.0, 90.0)] {
+            assert!((resampled.sample(c, g) - web.sample(c, g)).abs() < 1e-9);
+        }
+
+        let mesh = LdcMesh::from_source(&web, 15.0, 10.0, 1.0);
+        assert_eq!(
+            mesh.vertex_count(),
+            web.generate_ldc_mesh(15.0, 10.0, 1.0).vertex_count()
+        );
+    }
+
+    #[cfg(feature = "atla")]
+    #[test]
+    fn test_atla_distribution() {
+        use atla::{IntensityDistribution, SymmetryType};
+
+        let dist = IntensityDistribution {
+            horizontal_angles: vec![0.0, 90.0, 180.0],
+            vertical_angles: vec![0.0, 90.0, 180.0],
+            intensities: vec![
+                vec![200.0, 100.0, 0.0],
+                vec![200.0, 50.0, 0.0],
+                vec![200.0, 20.0, 0.0],
+            ],
+            symmetry: Some(SymmetryType::Bi0),
+            multiplier: Some(0.5),
+            ..Default::default()
+        };
+        assert_eq!(PhotometricSource::symmetry(&dist), Symmetry::PlaneC0C180);
+        assert_eq!(PhotometricSource::max_intensity(&dist), 100.0);
+        // C270 mirrors onto C90
+        assert_eq!(PhotometricSource::sample(&dist, 270.0, 90.0), 25.0);
+
+        let web = PhotometricWeb::from(&dist);
+        assert_eq!(web.max_intensity(), 100.0);
+        assert_eq!(web.sample(90.0, 45.0), 62.5);
+    }
+}
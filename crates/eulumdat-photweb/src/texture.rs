@@ -0,0 +1,231 @@
+//! Texture baking of the intensity distribution for render engines
+//!
+//! Engines consume photometric profiles as textures ("IES profiles"): a 1D
+//! vertical profile, or a 2D map over all directions. Directions are those of
+//! [`direction_to_angles`](crate::direction_to_angles).
+
+use crate::{direction_to_angles, PhotometricWeb};
+
+/// Layout of a baked texture
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextureLayout {
+    /// `resolution` × 1 texels over gamma 0–180°, averaged over all C-planes
+    Profile1D,
+    /// `resolution` × `resolution / 2` texels: C 0–360° along x, gamma
+    /// 0–180° along y (row 0 at the nadir)
+    Equirect,
+    /// `resolution` × `resolution` octahedral map: the nadir in the center,
+    /// the zenith in the corners, +X to the right and +Z down
+    Octahedral,
+}
+
+/// Intensities baked into a single-channel float texture
+#[derive(Debug, Clone)]
+pub struct BakedTexture {
+    /// Width in texels
+    pub width: usize,
+    /// Height in texels
+    pub height: usize,
+    /// Layout the texels follow
+    pub layout: TextureLayout,
+    /// Intensities relative to the peak (0.0–1.0), row by row
+    pub data: Vec<f32>,
+    /// Peak intensity in cd/klm, the factor to absolute values
+    pub peak_intensity: f64,
+}
+
+impl BakedTexture {
+    /// Texels as little-endian `f32`, row by row, for GPU upload.
+    pub fn to_raw_bytes(&self) -> Vec<u8> {
+        self.data.iter().flat_map(|v| v.to_le_bytes()).collect()
+    }
+
+    /// OpenEXR image with a single uncompressed float channel `Y`.
+    pub fn to_exr(&self) -> Vec<u8> {
+        fn attribute(out: &mut Vec<u8>, name: &str, kind: &str, value: &[u8]) {
+            out.extend_from_slice(name.as_bytes());
+            out.push(0);
+            out.extend_from_slice(kind.as_bytes());
+            out.push(0);
+            out.extend_from_slice(&(value.len() as i32).to_le_bytes());
+            out.extend_from_slice(value);
+        }
+        let ints =
+            |values: &[i32]| -> Vec<u8> { values.iter().flat_map(|v| v.to_le_bytes()).collect() };
+        let (width, height) = (self.width as i32, self.height as i32);
+
+        // Magic number and version 2, single-part scanline image
+        let mut out = vec![0x76, 0x2f, 0x31, 0x01, 2, 0, 0, 0];
+        // Channel Y: FLOAT, not linear, sampling 1 × 1
+        let mut channels = b"Y\0".to_vec();
+        channels.extend_from_slice(&ints(&[2, 0, 1, 1]));
+        channels.push(0);
+        attribute(&mut out, "channels", "chlist", &channels);
+        attribute(&mut out, "compression", "compression", &[0]);
+        let window = ints(&[0, 0, width - 1, height - 1]);
+        attribute(&mut out, "dataWindow", "box2i", &window);
+        attribute(&mut out, "displayWindow", "box2i", &window);
+        attribute(&mut out, "lineOrder", "lineOrder", &[0]);
+        attribute(&mut out, "pixelAspectRatio", "float", &1.0f32.to_le_bytes());
+        attribute(&mut out, "screenWindowCenter", "v2f", &[0; 8]);
+        attribute(
+            &mut out,
+            "screenWindowWidth",
+            "float",
+            &1.0f32.to_le_bytes(),
+        );
+        out.push(0);
+
+        // Offset table, then one block per scanline
+        let row_bytes = self.width * 4;
+        let first_block = out.len() + self.height * 8;
+        for y in 0..self.height {
+            let offset = (first_block + y * (8 + row_bytes)) as u64;
+            out.extend_from_slice(&offset.to_le_bytes());
+        }
+        for (y, row) in self.data.chunks_exact(self.width.max(1)).enumerate() {
+            out.extend_from_slice(&(y as i32).to_le_bytes());
+            out.extend_from_slice(&(row_bytes as i32).to_le_bytes());
+            for value in row {
+                out.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        out
+    }
+}
+
+impl PhotometricWeb {
+    /// Bake the distribution into a texture, sampled at the texel centers.
+    ///
+    /// See [`TextureLayout`] for the size and mapping of each layout;
+    /// `resolution` is raised to at least 2.
+    pub fn bake_texture(&self, resolution: usize, layout: TextureLayout) -> BakedTexture {
+        let resolution = resolution.max(2);
+        let center = |i: usize, n: usize, range: f64| (i as f64 + 0.5) / n as f64 * range;
+
+        let (width, height, data) = match layout {
+            TextureLayout::Profile1D => {
+                // C-planes for the average, every 5°
+                let data = (0..resolution)
+                    .map(|i| {
+                        let g = center(i, resolution, 180.0);
+                        let sum: f64 = (0..72)
+                            .map(|c| self.sample_normalized(c as f64 * 5.0, g))
+                            .sum();
+                        (sum / 72.0) as f32
+                    })
+                    .collect();
+                (resolution, 1, data)
+            }
+            TextureLayout::Equirect => {
+                let height = resolution / 2;
+                let mut data = Vec::with_capacity(resolution * height);
+                for y in 0..height {
+                    let g = center(y, height, 180.0);
+                    for x in 0..resolution {
+                        let c = center(x, resolution, 360.0);
+                        data.push(self.sample_normalized(c, g) as f32);
+                    }
+                }
+                (resolution, height, data)
+            }
+            TextureLayout::Octahedral => {
+                let mut data = Vec::with_capacity(resolution * resolution);
+                for y in 0..resolution {
+                    let v = center(y, resolution, 2.0) - 1.0;
+                    for x in 0..resolution {
+                        let u = center(x, resolution, 2.0) - 1.0;
+                        let (c, g) = direction_to_angles(octahedral_direction(u, v));
+                        data.push(self.sample_normalized(c, g) as f32);
+                    }
+                }
+                (resolution, resolution, data)
+            }
+        };
+
+        BakedTexture {
+            width,
+            height,
+            layout,
+            data,
+            peak_intensity: self.max_intensity(),
+        }
+    }
+}
+
+/// Direction of octahedral coordinates `u`, `v` in -1..1, nadir at 0, 0
+fn octahedral_direction(u: f64, v: f64) -> [f64; 3] {
+    let h = 1.0 - u.abs() - v.abs();
+    // The lower hemisphere fills the inner diamond, the upper one folds
+    // into the corners
+    let (a, b) = if h >= 0.0 {
+        (u, v)
+    } else {
+        ((1.0 - v.abs()) * u.signum(), (1.0 - u.abs()) * v.signum())
+    };
+    [a, -h, b]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eulumdat::Symmetry;
+
+    /// Downlight: bright at the nadir, dark above the horizon, brighter toward C90
+    fn web() -> PhotometricWeb {
+        PhotometricWeb::new(
+            vec![0.0, 90.0, 180.0, 270.0],
+            vec![0.0, 45.0, 90.0, 135.0, 180.0],
+            vec![
+                vec![100.0, 50.0, 0.0, 0.0, 0.0],
+                vec![100.0, 80.0, 0.0, 0.0, 0.0],
+                vec![100.0, 50.0, 0.0, 0.0, 0.0],
+                vec![100.0, 80.0, 0.0, 0.0, 0.0],
+            ],
+            Symmetry::None,
+        )
+    }
+
+    #[test]
+    fn test_layout_sizes() {
+        let web = web();
+        let profile = web.bake_texture(64, TextureLayout::Profile1D);
+        assert_eq!((profile.width, profile.height), (64, 1));
+        assert!(profile.data[0] > 0.99);
+        assert!(profile.data[63].abs() < 1e-6);
+
+        let equirect = web.bake_texture(64, TextureLayout::Equirect);
+        assert_eq!((equirect.width, equirect.height), (64, 32));
+        assert_eq!(equirect.data.len(), 64 * 32);
+        assert_eq!(equirect.peak_intensity, 100.0);
+    }
+
+    #[test]
+    fn test_octahedral_mapping() {
+        let texture = web().bake_texture(32, TextureLayout::Octahedral);
+        let at = |x: usize, y: usize| texture.data[y * 32 + x];
+        // Nadir in the center, zenith in the corners
+        assert!(at(16, 16) > 0.95);
+        assert_eq!(at(0, 0), 0.0);
+        assert_eq!(at(31, 31), 0.0);
+        // Halfway to the horizon toward +X (C90) is brighter than toward +Z (C0)
+        assert!(at(24, 16) > at(16, 24));
+
+        let (c, g) = direction_to_angles(octahedral_direction(0.5, 0.0));
+        assert!((c - 90.0).abs() < 1e-9 && (g - 45.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_exr_layout() {
+        let texture = web().bake_texture(8, TextureLayout::Equirect);
+        let exr = texture.to_exr();
+        assert_eq!(&exr[..4], &[0x76, 0x2f, 0x31, 0x01]);
+
+        // Header, offset table and 4 scanlines of 8 floats
+        let header = exr.len() - 4 * 8 - 4 * (8 + 32);
+        let first = u64::from_le_bytes(exr[header..header + 8].try_into().unwrap());
+        assert_eq!(first as usize, header + 4 * 8);
+        assert_eq!(exr[header - 1], 0);
+        assert_eq!(texture.to_raw_bytes().len(), 8 * 4 * 4);
+    }
+}
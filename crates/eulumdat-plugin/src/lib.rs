@@ -557,6 +557,12 @@ struct CieFluxWrapper {
     n3: f64,
     n4: f64,
     n5: f64,
+    fc1: f64,
+    fc2: f64,
+    fc3: f64,
+    fc4: f64,
+    fc5: f64,
+    code: String,
 }
 
 impl From<&CieFluxCodes> for CieFluxWrapper {
@@ -567,6 +573,12 @@ impl From<&CieFluxCodes> for CieFluxWrapper {
             n3: c.n3,
             n4: c.n4,
             n5: c.n5,
+            fc1: c.fc1,
+            fc2: c.fc2,
+            fc3: c.fc3,
+            fc4: c.fc4,
+            fc5: c.fc5,
+            code: c.to_string(),
         }
     }
 }
@@ -18,6 +18,9 @@ use core::{
 /// - N3: % flux in 0-40° zone
 /// - N4: % flux in upper hemisphere (90-180°) - equivalent to ULOR
 /// - N5: % flux in 90-120° zone (near-horizontal uplight)
+///
+/// FC1-FC5 are the normative CIE 52 code: % of downward flux within
+/// 41.4°, 60° and 75.5°, % of flux downwards, and the light output ratio.
 #[pyclass]
 #[derive(Clone, Debug)]
 pub struct CieFluxCodes {
@@ -36,19 +39,34 @@ pub struct CieFluxCodes {
     /// N5: % flux in 90-120° zone
     #[pyo3(get)]
     pub n5: f64,
+    /// FC1: % of downward flux within 41.4°
+    #[pyo3(get)]
+    pub fc1: f64,
+    /// FC2: % of downward flux within 60°
+    #[pyo3(get)]
+    pub fc2: f64,
+    /// FC3: % of downward flux within 75.5°
+    #[pyo3(get)]
+    pub fc3: f64,
+    /// FC4: % of luminaire flux in the lower hemisphere
+    #[pyo3(get)]
+    pub fc4: f64,
+    /// FC5: flux fraction (light output ratio) in %
+    #[pyo3(get)]
+    pub fc5: f64,
 }
 
 #[pymethods]
 impl CieFluxCodes {
-    /// Format as standard CIE flux code string "N1 N2 N3 N4 N5"
+    /// Format as the normative CIE flux code string "FC1 FC2 FC3 FC4 FC5"
     fn __str__(&self) -> String {
         format!(
             "{:.0} {:.0} {:.0} {:.0} {:.0}",
-            self.n1.round(),
-            self.n2.round(),
-            self.n3.round(),
-            self.n4.round(),
-            self.n5.round()
+            self.fc1.round(),
+            self.fc2.round(),
+            self.fc3.round(),
+            self.fc4.round(),
+            self.fc5.round()
         )
     }
 
@@ -67,6 +85,11 @@ impl CieFluxCodes {
         dict.set_item("n3", self.n3).unwrap();
         dict.set_item("n4", self.n4).unwrap();
         dict.set_item("n5", self.n5).unwrap();
+        dict.set_item("fc1", self.fc1).unwrap();
+        dict.set_item("fc2", self.fc2).unwrap();
+        dict.set_item("fc3", self.fc3).unwrap();
+        dict.set_item("fc4", self.fc4).unwrap();
+        dict.set_item("fc5", self.fc5).unwrap();
         dict
     }
 }
@@ -79,6 +102,11 @@ impl From<CoreCieFluxCodes> for CieFluxCodes {
             n3: c.n3,
             n4: c.n4,
             n5: c.n5,
+            fc1: c.fc1,
+            fc2: c.fc2,
+            fc3: c.fc3,
+            fc4: c.fc4,
+            fc5: c.fc5,
         }
     }
 }
@@ -216,6 +244,13 @@ pub struct PhotometricSummary {
     /// S/H ratio for C90 plane
     #[pyo3(get)]
     pub spacing_c90: f64,
+    /// S/H ratio along the diagonal (C45 plane)
+    #[pyo3(get)]
+    pub spacing_diagonal: f64,
+    /// Maximum SHR of a square layout (IES uniformity method); None if not
+    /// achievable
+    #[pyo3(get)]
+    pub spacing_max: Option<f64>,
 
     // Internal storage for cie_flux_codes and zonal_lumens
     inner_cie_codes: CoreCieFluxCodes,
@@ -346,6 +381,9 @@ Zonal Lumens (%)
         dict.set_item("avg_intensity", self.avg_intensity).unwrap();
         dict.set_item("spacing_c0", self.spacing_c0).unwrap();
         dict.set_item("spacing_c90", self.spacing_c90).unwrap();
+        dict.set_item("spacing_diagonal", self.spacing_diagonal)
+            .unwrap();
+        dict.set_item("spacing_max", self.spacing_max).unwrap();
         dict.set_item(
             "cie_flux_code",
             format!("{}", self.inner_cie_codes).as_str(),
@@ -390,6 +428,8 @@ impl From<CorePhotoSummary> for PhotometricSummary {
             avg_intensity: s.avg_intensity,
             spacing_c0: s.spacing_c0,
             spacing_c90: s.spacing_c90,
+            spacing_diagonal: s.spacing_diagonal,
+            spacing_max: s.spacing_max,
             inner_cie_codes: s.cie_flux_codes,
             inner_zonal_lumens: s.zonal_lumens,
         }
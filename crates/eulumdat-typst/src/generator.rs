@@ -3,7 +3,8 @@
 use eulumdat::Eulumdat;
 
 use crate::template::{
-    generate_comparison_report, generate_typst_source, generate_typst_with_files,
+    generate_comparison_report, generate_datasheet_typst, generate_typst_source,
+    generate_typst_with_files,
 };
 
 /// Sections that can be included in the report.
@@ -23,6 +24,8 @@ pub enum ReportSection {
     PolarDiagram,
     /// Cartesian diagram (intensity vs gamma)
     CartesianDiagram,
+    /// Cone diagram (beam and field spread at mounting height)
+    ConeDiagram,
     /// 3D Butterfly diagram
     ButterflyDiagram,
     /// Intensity heatmap
@@ -54,6 +57,7 @@ impl ReportSection {
             Self::PhotometricData,
             Self::PolarDiagram,
             Self::CartesianDiagram,
+            Self::ConeDiagram,
             Self::ButterflyDiagram,
             Self::HeatmapDiagram,
             Self::BugRating,
@@ -157,52 +161,18 @@ impl<'a> ReportGenerator<'a> {
     /// invokes `typst compile`, and returns the PDF bytes.
     /// Requires `typst` to be installed and in PATH.
     pub fn generate_pdf(&self, options: &ReportOptions) -> crate::Result<Vec<u8>> {
-        use std::process::Command;
-
         // Generate Typst source with inline embedded SVGs
         let (source, _) = generate_typst_with_files(self.ldt, &options.sections);
+        compile_with_typst_cli(&source)
+    }
 
-        // Create temp files
-        let temp_dir = std::env::temp_dir();
-        let typ_path = temp_dir.join("eulumdat_report.typ");
-        let pdf_path = temp_dir.join("eulumdat_report.pdf");
-
-        // Write Typst source
-        std::fs::write(&typ_path, &source)?;
-
-        // Invoke typst CLI
-        let output = Command::new("typst")
-            .args([
-                "compile",
-                typ_path.to_str().unwrap(),
-                pdf_path.to_str().unwrap(),
-            ])
-            .output()
-            .map_err(|e| {
-                crate::error::ReportError::Compile(format!(
-                    "Failed to run typst CLI. Is typst installed? Error: {}",
-                    e
-                ))
-            })?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            // Clean up temp files
-            let _ = std::fs::remove_file(&typ_path);
-            return Err(crate::error::ReportError::Compile(format!(
-                "typst compile failed:\n{}",
-                stderr
-            )));
-        }
-
-        // Read PDF
-        let pdf_bytes = std::fs::read(&pdf_path)?;
-
-        // Clean up temp files
-        let _ = std::fs::remove_file(&typ_path);
-        let _ = std::fs::remove_file(&pdf_path);
-
-        Ok(pdf_bytes)
+    /// Generate a one-page PDF datasheet from a page-sized datasheet SVG
+    /// using the typst CLI.
+    ///
+    /// The SVG should have the proportions of `paper`, see
+    /// `eulumdat::diagram::DatasheetComposer`.
+    pub fn generate_datasheet_pdf(svg: &str, paper: PaperSize) -> crate::Result<Vec<u8>> {
+        compile_with_typst_cli(&generate_datasheet_typst(svg, paper))
     }
 
     /// Generate a Typst comparison report for two photometric files.
@@ -229,6 +199,56 @@ impl<'a> ReportGenerator<'a> {
     }
 }
 
+/// Compile Typst source (with inline SVGs) to PDF with the typst CLI.
+///
+/// Writes the source to a temp file, invokes `typst compile` and returns
+/// the PDF bytes.
+fn compile_with_typst_cli(source: &str) -> crate::Result<Vec<u8>> {
+    use std::process::Command;
+
+    // Create temp files
+    let temp_dir = std::env::temp_dir();
+    let typ_path = temp_dir.join("eulumdat_report.typ");
+    let pdf_path = temp_dir.join("eulumdat_report.pdf");
+
+    // Write Typst source
+    std::fs::write(&typ_path, source)?;
+
+    // Invoke typst CLI
+    let output = Command::new("typst")
+        .args([
+            "compile",
+            typ_path.to_str().unwrap(),
+            pdf_path.to_str().unwrap(),
+        ])
+        .output()
+        .map_err(|e| {
+            crate::error::ReportError::Compile(format!(
+                "Failed to run typst CLI. Is typst installed? Error: {}",
+                e
+            ))
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        // Clean up temp files
+        let _ = std::fs::remove_file(&typ_path);
+        return Err(crate::error::ReportError::Compile(format!(
+            "typst compile failed:\n{}",
+            stderr
+        )));
+    }
+
+    // Read PDF
+    let pdf_bytes = std::fs::read(&pdf_path)?;
+
+    // Clean up temp files
+    let _ = std::fs::remove_file(&typ_path);
+    let _ = std::fs::remove_file(&pdf_path);
+
+    Ok(pdf_bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -264,6 +284,29 @@ mod tests {
         assert!(source.contains("Test Manufacturer"));
     }
 
+    #[test]
+    fn test_pdf_source_embeds_diagrams() {
+        let ldt = create_test_ldt();
+        let (source, _) = crate::template::generate_typst_with_files(&ldt, &ReportSection::all());
+
+        assert!(source.contains("= Cone Diagram"));
+        assert!(source.contains("= BUG Rating"));
+        // Polar, cartesian, cone, butterfly, heatmap and BUG diagrams
+        assert_eq!(source.matches("#image(bytes(").count(), 6);
+    }
+
+    #[test]
+    fn test_datasheet_typst() {
+        let source = crate::template::generate_datasheet_typst(
+            r#"<svg width="216mm"><text>"A\B"</text></svg>"#,
+            PaperSize::Letter,
+        );
+        assert!(source.starts_with(r#"#set page(paper: "us-letter", margin: 0pt)"#));
+        assert!(
+            source.contains(r#"#image(bytes("<svg width=\"216mm\"><text>\"A\\B\"</text></svg>")"#)
+        );
+    }
+
     #[test]
     fn test_compact_sections() {
         let sections = ReportSection::compact();
@@ -36,4 +36,6 @@ mod world;
 
 pub use error::{ReportError, Result};
 pub use generator::{PaperSize, ReportGenerator, ReportOptions, ReportSection};
-pub use template::{generate_comparison_report, generate_typst_with_files};
+pub use template::{
+    generate_comparison_report, generate_datasheet_typst, generate_typst_with_files,
+};
@@ -2,11 +2,16 @@
 
 use eulumdat::{
     bug_rating::BugDiagram,
-    diagram::{ButterflyDiagram, CartesianDiagram, HeatmapDiagram, PolarDiagram, SvgTheme},
+    diagram::{
+        ButterflyDiagram, CartesianDiagram, ConeDiagram, HeatmapDiagram, PolarDiagram, SvgTheme,
+    },
     Eulumdat, PhotometricCalculations, PhotometricComparison, PhotometricSummary, Significance,
 };
 
-use crate::generator::ReportSection;
+use crate::generator::{PaperSize, ReportSection};
+
+/// Mounting height of the cone diagram in meters.
+const CONE_MOUNTING_HEIGHT: f64 = 3.0;
 
 /// Generate Typst source with inline embedded SVGs for PDF compilation.
 /// Returns the complete Typst source with SVGs embedded as bytes.
@@ -55,6 +60,11 @@ pub fn generate_typst_with_files(
                 let svg = diagram.to_svg(500.0, 300.0, &theme);
                 source.push_str(&generate_cartesian_diagram_section_inline(&svg));
             }
+            ReportSection::ConeDiagram => {
+                let diagram = ConeDiagram::from_eulumdat(ldt, CONE_MOUNTING_HEIGHT);
+                let svg = diagram.to_svg(500.0, 350.0, &theme);
+                source.push_str(&generate_cone_diagram_section_inline(&svg));
+            }
             ReportSection::ButterflyDiagram => {
                 let diagram = ButterflyDiagram::from_eulumdat(ldt, 450.0, 350.0, 60.0);
                 let svg = diagram.to_svg(450.0, 350.0, &theme);
@@ -66,7 +76,8 @@ pub fn generate_typst_with_files(
                 source.push_str(&generate_heatmap_diagram_section_inline(&svg));
             }
             ReportSection::BugRating => {
-                source.push_str(&generate_bug_rating_section(ldt));
+                let svg = BugDiagram::from_eulumdat(ldt).to_svg(500.0, 400.0, &theme);
+                source.push_str(&generate_bug_rating_section_inline(ldt, &svg));
             }
             ReportSection::IntensityTable => {
                 source.push_str(&generate_intensity_table_section(ldt));
@@ -137,6 +148,42 @@ The Cartesian diagram shows luminous intensity (cd/klm) versus gamma angle (°)
     )
 }
 
+fn generate_cone_diagram_section_inline(svg_content: &str) -> String {
+    let escaped_svg = escape_svg_for_typst(svg_content);
+    format!(
+        r##"
+= Cone Diagram
+
+The cone diagram shows the beam (50% of I_max) and field (10% of I_max) spread at a mounting height of {CONE_MOUNTING_HEIGHT} m.
+
+#align(center)[
+  #image(bytes("{}"), width: 85%)
+]
+
+#pagebreak()
+"##,
+        escaped_svg
+    )
+}
+
+fn generate_bug_rating_section_inline(ldt: &Eulumdat, svg_content: &str) -> String {
+    let section = generate_bug_rating_section(ldt);
+    let body = section.strip_suffix("#pagebreak()\n").unwrap_or(&section);
+    format!(
+        r##"{}
+== BUG Diagram
+
+#align(center)[
+  #image(bytes("{}"), width: 80%)
+]
+
+#pagebreak()
+"##,
+        body,
+        escape_svg_for_typst(svg_content)
+    )
+}
+
 fn generate_butterfly_diagram_section_inline(svg_content: &str) -> String {
     let escaped_svg = escape_svg_for_typst(svg_content);
     format!(
@@ -179,6 +226,20 @@ fn escape_svg_for_typst(svg: &str) -> String {
     svg.replace('\\', "\\\\").replace('"', "\\\"")
 }
 
+/// Generate Typst source for a one-page datasheet.
+///
+/// `svg` is a page-sized datasheet (see `eulumdat::diagram::DatasheetComposer`)
+/// that fills the page without margins.
+pub fn generate_datasheet_typst(svg: &str, paper: PaperSize) -> String {
+    format!(
+        r#"#set page(paper: "{}", margin: 0pt)
+#image(bytes("{}"), width: 100%, height: 100%)
+"#,
+        paper.typst_name(),
+        escape_svg_for_typst(svg)
+    )
+}
+
 /// Generate the complete Typst source for a photometric report.
 /// (For .typ file export - uses inline SVG placeholders, not suitable for PDF)
 pub fn generate_typst_source(
@@ -221,6 +282,9 @@ pub fn generate_typst_source(
             ReportSection::CartesianDiagram => {
                 source.push_str(&generate_cartesian_diagram_section(ldt, include_dark_theme));
             }
+            ReportSection::ConeDiagram => {
+                source.push_str(&generate_cone_diagram_section());
+            }
             ReportSection::ButterflyDiagram => {
                 source.push_str(&generate_butterfly_diagram_section(ldt, include_dark_theme));
             }
@@ -625,6 +689,21 @@ _Note: For PDF generation, use the CLI command `eulumdat report` which properly
 "##.to_string()
 }
 
+fn generate_cone_diagram_section() -> String {
+    // Note: For standalone .typ export, diagrams can't be embedded without external files
+    format!(
+        r##"
+= Cone Diagram
+
+The cone diagram shows the beam (50% of I_max) and field (10% of I_max) spread at a mounting height of {CONE_MOUNTING_HEIGHT} m.
+
+_Note: For PDF generation, use the CLI command `eulumdat report` which properly embeds diagrams._
+
+#pagebreak()
+"##
+    )
+}
+
 fn generate_butterfly_diagram_section(_ldt: &Eulumdat, _dark_theme: bool) -> String {
     // Note: For standalone .typ export, diagrams can't be embedded without external files
     r##"
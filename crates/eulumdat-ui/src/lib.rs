@@ -5,6 +5,12 @@
 //! - Desktop applications (via eframe)
 //! - Web applications (via eframe WASM or embedded in Leptos)
 //!
+//! Diagrams are drawn directly with egui painters from the same geometry the
+//! core SVG renderer uses, so they stay crisp at any size and support hover
+//! readouts without a texture round-trip. The `show_diagram` variants take a
+//! diagram prepared by the caller (cached, or for a selected C-plane) and
+//! return the plot's response; [`layout`] maps positions on it.
+//!
 //! # Features
 //!
 //! - `3d` - Enable 3D viewer using three-d
@@ -32,8 +38,8 @@ mod viewer_3d;
 
 pub use theme::Theme;
 pub use widgets::{
-    CartesianWidget, DiagramTab, EditorPanel, HeatmapWidget, InfoPanel, PolarWidget,
-    ValidationPanel,
+    layout, paint_primitives, CartesianWidget, DiagramTab, EditorPanel, HeatmapWidget, InfoPanel,
+    PolarWidget, ValidationPanel,
 };
 
 #[cfg(feature = "3d")]
@@ -1,7 +1,9 @@
 //! Cartesian diagram widget for egui
 
+use super::layout::{to_screen, CartesianLayout};
+use super::primitives::paint_primitives;
 use crate::Theme;
-use egui::{pos2, vec2, Pos2, Rect, Sense, Stroke};
+use egui::{pos2, vec2, Pos2, Rect, Response, Sense, Stroke, Vec2};
 use eulumdat::{diagram::CartesianDiagram, Eulumdat};
 
 /// Cartesian diagram widget
@@ -10,57 +12,97 @@ pub struct CartesianWidget;
 impl CartesianWidget {
     /// Show the cartesian diagram
     pub fn show(ui: &mut egui::Ui, ldt: &Eulumdat, theme: &Theme) {
-        let available_size = ui.available_size();
-        let width = available_size.x.min(800.0);
-        let height = (width * 0.6).min(available_size.y - 50.0);
+        let size = Self::size_for(ui.available_size());
+        // Max 8 curves, built at the widget size so the plot area and curve
+        // points share the SVG layout
+        let cartesian = CartesianDiagram::from_eulumdat(ldt, size.x as f64, size.y as f64, 8);
+        Self::show_diagram(ui, ldt, &cartesian, theme);
+    }
 
-        let (response, painter) = ui.allocate_painter(vec2(width, height), Sense::hover());
-        let rect = response.rect;
+    /// Widget size for the available space; build the diagram shown by
+    /// [`CartesianWidget::show_diagram`] at this size.
+    pub fn size_for(available_size: Vec2) -> Vec2 {
+        let width = available_size.x.max(200.0);
+        let height = (width * 0.6).min(available_size.y - 50.0).max(150.0);
+        vec2(width, height)
+    }
 
-        // Margins for axes
-        let margin = vec2(60.0, 40.0);
-        let plot_rect = Rect::from_min_max(rect.min + margin, rect.max - vec2(20.0, 30.0));
+    /// Show a prepared cartesian diagram of `ldt`, built at
+    /// [`CartesianWidget::size_for`] the available space, e.g. one kept by
+    /// the caller between frames or built for a single C-plane.
+    ///
+    /// Returns the response of the plot, which also senses clicks.
+    pub fn show_diagram(
+        ui: &mut egui::Ui,
+        ldt: &Eulumdat,
+        cartesian: &CartesianDiagram,
+        theme: &Theme,
+    ) -> Response {
+        let size = Self::size_for(ui.available_size());
+        let (response, painter) = ui.allocate_painter(size, Sense::click());
+        let rect = response.rect;
 
         // Background
         painter.rect_filled(rect, 0.0, theme.background);
 
-        // Generate diagram data (max 8 curves)
-        let cartesian = CartesianDiagram::from_eulumdat(ldt, width as f64, height as f64, 8);
+        let layout = CartesianLayout::new(rect, cartesian);
 
         // Draw grid
-        Self::draw_grid(&painter, plot_rect, &cartesian, theme);
+        Self::draw_grid(&painter, &layout, cartesian, theme);
 
         // Draw curves
         for curve in cartesian.curves.iter() {
             let color = theme.c_plane_color(curve.c_angle, cartesian.curves.len());
-            Self::draw_curve(&painter, plot_rect, curve, &cartesian, color);
+            Self::draw_curve(&painter, rect, curve, color);
         }
 
         // Draw axes labels
-        Self::draw_axes(&painter, plot_rect, &cartesian, theme);
+        Self::draw_axes(&painter, &layout, cartesian, theme);
+
+        // Batwing callouts, drawn from the same primitives as the SVG
+        paint_primitives(&painter, rect, &cartesian.batwing_primitives(), theme);
+
+        // Hover readout
+        if let Some(hover_pos) = response.hover_pos() {
+            if let Some((gamma, _)) = layout.invert(hover_pos) {
+                Self::draw_hover(&painter, &layout, ldt, cartesian, gamma, theme);
+
+                let mut tooltip = format!("γ: {:.1}°", gamma);
+                for curve in &cartesian.curves {
+                    tooltip.push_str(&format!(
+                        "\n{}: {:.1} cd/klm",
+                        curve.label,
+                        ldt.sample(curve.c_angle, gamma)
+                    ));
+                }
+                response.clone().on_hover_text_at_pointer(tooltip);
+            }
+        }
 
         // Legend
-        Self::draw_legend(ui, &cartesian, theme);
+        Self::draw_legend(ui, cartesian, theme);
+
+        response
     }
 
     fn draw_grid(
         painter: &egui::Painter,
-        rect: Rect,
-        _cartesian: &CartesianDiagram,
+        layout: &CartesianLayout,
+        cartesian: &CartesianDiagram,
         theme: &Theme,
     ) {
         let grid_stroke = Stroke::new(1.0, theme.grid);
+        let rect = layout.plot;
 
         // Vertical grid lines (gamma angles)
-        for gamma in (0..=180).step_by(30) {
-            let x = rect.left() + (gamma as f32 / 180.0) * rect.width();
+        for &gamma in &cartesian.x_ticks {
+            let x = layout.gamma_x(gamma);
             painter.line_segment([pos2(x, rect.top()), pos2(x, rect.bottom())], grid_stroke);
         }
 
         // Horizontal grid lines (intensity)
-        let num_lines = 5;
-        for i in 0..=num_lines {
-            let y = rect.bottom() - (i as f32 / num_lines as f32) * rect.height();
+        for &value in &cartesian.y_ticks {
+            let y = layout.intensity_y(value);
             painter.line_segment([pos2(rect.left(), y), pos2(rect.right(), y)], grid_stroke);
         }
     }
@@ -69,33 +111,30 @@ impl CartesianWidget {
         painter: &egui::Painter,
         rect: Rect,
         curve: &eulumdat::diagram::CartesianCurve,
-        cartesian: &CartesianDiagram,
         color: egui::Color32,
     ) {
-        if curve.points.is_empty() {
+        if curve.is_empty() {
             return;
         }
 
-        let stroke = Stroke::new(2.0, color);
-        let max_intensity = cartesian.scale.scale_max;
-
+        // Points are already in widget pixels (same as `CartesianCurve::to_svg_path`)
         let screen_points: Vec<Pos2> = curve
             .points
             .iter()
-            .map(|p| {
-                let x = rect.left() + (p.gamma as f32 / 180.0) * rect.width();
-                let y = rect.bottom() - (p.intensity as f32 / max_intensity as f32) * rect.height();
-                pos2(x, y.max(rect.top()))
-            })
+            .map(|p| to_screen(rect, p.x, p.y))
             .collect();
 
-        for pair in screen_points.windows(2) {
-            painter.line_segment([pair[0], pair[1]], stroke);
-        }
+        painter.add(egui::Shape::line(screen_points, Stroke::new(2.0, color)));
     }
 
-    fn draw_axes(painter: &egui::Painter, rect: Rect, cartesian: &CartesianDiagram, theme: &Theme) {
+    fn draw_axes(
+        painter: &egui::Painter,
+        layout: &CartesianLayout,
+        cartesian: &CartesianDiagram,
+        theme: &Theme,
+    ) {
         let axis_stroke = Stroke::new(1.5, theme.axis);
+        let rect = layout.plot;
 
         // X axis
         painter.line_segment(
@@ -116,24 +155,22 @@ impl CartesianWidget {
         );
 
         // X axis labels (gamma)
-        for gamma in (0..=180).step_by(30) {
-            let x = rect.left() + (gamma as f32 / 180.0) * rect.width();
+        for &gamma in &cartesian.x_ticks {
+            let x = layout.gamma_x(gamma);
             painter.text(
-                pos2(x, rect.bottom() + 15.0),
+                pos2(x, rect.bottom() + 5.0),
                 egui::Align2::CENTER_TOP,
-                format!("{}°", gamma),
+                format!("{:.0}°", gamma),
                 egui::FontId::proportional(10.0),
                 theme.text,
             );
         }
 
         // Y axis labels (intensity)
-        let num_labels = 5;
-        for i in 0..=num_labels {
-            let value = cartesian.scale.scale_max * (i as f64 / num_labels as f64);
-            let y = rect.bottom() - (i as f32 / num_labels as f32) * rect.height();
+        for &value in &cartesian.y_ticks {
+            let y = layout.intensity_y(value);
             painter.text(
-                pos2(rect.left() - 10.0, y),
+                pos2(rect.left() - 8.0, y),
                 egui::Align2::RIGHT_CENTER,
                 format!("{:.0}", value),
                 egui::FontId::proportional(10.0),
@@ -143,7 +180,7 @@ impl CartesianWidget {
 
         // Axis titles
         painter.text(
-            pos2(rect.center().x, rect.bottom() + 30.0),
+            pos2(rect.center().x, rect.bottom() + 22.0),
             egui::Align2::CENTER_TOP,
             "Gamma (°)",
             egui::FontId::proportional(12.0),
@@ -151,6 +188,29 @@ impl CartesianWidget {
         );
     }
 
+    fn draw_hover(
+        painter: &egui::Painter,
+        layout: &CartesianLayout,
+        ldt: &Eulumdat,
+        cartesian: &CartesianDiagram,
+        gamma: f64,
+        theme: &Theme,
+    ) {
+        let rect = layout.plot;
+        let x = layout.gamma_x(gamma);
+        painter.line_segment(
+            [pos2(x, rect.top()), pos2(x, rect.bottom())],
+            Stroke::new(1.0, theme.axis),
+        );
+
+        for curve in &cartesian.curves {
+            let value = ldt.sample(curve.c_angle, gamma);
+            let y = layout.intensity_y(value).max(rect.top());
+            let color = theme.c_plane_color(curve.c_angle, cartesian.curves.len());
+            painter.circle_filled(pos2(x, y), 3.5, color);
+        }
+    }
+
     fn draw_legend(ui: &mut egui::Ui, cartesian: &CartesianDiagram, theme: &Theme) {
         ui.horizontal_wrapped(|ui| {
             for curve in &cartesian.curves {
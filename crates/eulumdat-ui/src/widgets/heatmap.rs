@@ -1,7 +1,8 @@
 //! Heatmap diagram widget for egui
 
+use super::layout::{color32, heatmap_cell_at, to_screen};
 use crate::Theme;
-use egui::{pos2, vec2, Rect, Sense};
+use egui::{pos2, vec2, Rect, Response, Sense, Stroke, Vec2};
 use eulumdat::{diagram::HeatmapDiagram, Eulumdat};
 
 /// Heatmap diagram widget
@@ -10,31 +11,33 @@ pub struct HeatmapWidget;
 impl HeatmapWidget {
     /// Show the heatmap diagram
     pub fn show(ui: &mut egui::Ui, ldt: &Eulumdat, theme: &Theme) {
-        let available_size = ui.available_size();
-        let width = available_size.x.min(800.0);
-        let height = (width * 0.5).min(available_size.y - 80.0);
+        let size = Self::size_for(ui.available_size());
+        // Built at the widget size; cell rects share the SVG layout
+        let heatmap = HeatmapDiagram::from_eulumdat(ldt, size.x as f64, size.y as f64);
+        Self::show_diagram(ui, &heatmap, theme);
+    }
 
-        let (response, painter) = ui.allocate_painter(vec2(width, height), Sense::hover());
-        let rect = response.rect;
+    /// Widget size for the available space; build the diagram shown by
+    /// [`HeatmapWidget::show_diagram`] at this size.
+    pub fn size_for(available_size: Vec2) -> Vec2 {
+        let width = available_size.x.max(300.0);
+        let height = (width * 0.55).min(available_size.y - 20.0).max(200.0);
+        vec2(width, height)
+    }
 
-        // Margins for axes
-        let margin = vec2(60.0, 20.0);
-        let legend_width = 60.0;
-        let plot_rect = Rect::from_min_max(
-            rect.min + margin,
-            rect.max - vec2(legend_width + 20.0, 40.0),
-        );
+    /// Show a prepared heatmap, built at [`HeatmapWidget::size_for`] the
+    /// available space, e.g. one kept by the caller between frames.
+    ///
+    /// Returns the response of the plot, which also senses clicks.
+    pub fn show_diagram(ui: &mut egui::Ui, heatmap: &HeatmapDiagram, theme: &Theme) -> Response {
+        let size = Self::size_for(ui.available_size());
+        let (response, painter) = ui.allocate_painter(size, Sense::click());
+        let rect = response.rect;
 
         // Background
         painter.rect_filled(rect, 0.0, theme.background);
 
-        // Generate heatmap data
-        let heatmap = HeatmapDiagram::from_eulumdat(ldt, width as f64, height as f64);
-
-        let num_c = heatmap.c_angles.len();
-        let num_g = heatmap.g_angles.len();
-
-        if heatmap.cells.is_empty() || num_c == 0 || num_g == 0 {
+        if heatmap.is_empty() {
             painter.text(
                 rect.center(),
                 egui::Align2::CENTER_CENTER,
@@ -42,53 +45,60 @@ impl HeatmapWidget {
                 egui::FontId::proportional(14.0),
                 theme.text,
             );
-            return;
+            return response;
         }
 
-        // Draw cells
-        let cell_width = plot_rect.width() / num_c as f32;
-        let cell_height = plot_rect.height() / num_g as f32;
+        let plot_rect = Rect::from_min_size(
+            to_screen(rect, heatmap.margin_left, heatmap.margin_top),
+            vec2(heatmap.plot_width as f32, heatmap.plot_height as f32),
+        );
 
+        // Draw cells
         for cell in &heatmap.cells {
-            let x = plot_rect.left() + cell.c_index as f32 * cell_width;
-            let y = plot_rect.top() + cell.g_index as f32 * cell_height;
-            let cell_rect = Rect::from_min_size(pos2(x, y), vec2(cell_width, cell_height));
-
-            let color = theme.heatmap_color(cell.normalized);
-            painter.rect_filled(cell_rect, 0.0, color);
+            let cell_rect = Rect::from_min_size(
+                to_screen(rect, cell.x, cell.y),
+                vec2(cell.width as f32, cell.height as f32),
+            );
+            // Expand slightly so neighbouring cells don't show seams
+            painter.rect_filled(cell_rect.expand(0.5), 0.0, color32(cell.color));
         }
 
         // Draw axes
-        Self::draw_axes(&painter, plot_rect, &heatmap, theme);
+        Self::draw_axes(&painter, rect, plot_rect, heatmap, theme);
 
         // Draw color legend
-        Self::draw_legend(&painter, rect, &heatmap, theme);
+        Self::draw_legend(&painter, plot_rect, heatmap, theme);
 
         // Tooltip on hover
         if let Some(hover_pos) = response.hover_pos() {
-            if plot_rect.contains(hover_pos) {
-                let c_idx = ((hover_pos.x - plot_rect.left()) / cell_width) as usize;
-                let g_idx = ((hover_pos.y - plot_rect.top()) / cell_height) as usize;
-
-                if let Some(cell) = heatmap
-                    .cells
-                    .iter()
-                    .find(|c| c.c_index == c_idx && c.g_index == g_idx)
-                {
-                    let tooltip_text = format!(
-                        "C: {:.0}°, γ: {:.0}°\nIntensity: {:.1} cd/klm\nCandela: {:.1} cd",
-                        cell.c_angle, cell.g_angle, cell.intensity, cell.candela
-                    );
-                    response.clone().on_hover_text(tooltip_text);
-                }
+            if let Some(cell) = heatmap_cell_at(heatmap, rect, hover_pos) {
+                let cell_rect = Rect::from_min_size(
+                    to_screen(rect, cell.x, cell.y),
+                    vec2(cell.width as f32, cell.height as f32),
+                );
+                painter.rect_stroke(cell_rect, 0.0, Stroke::new(1.5, theme.text));
+
+                let tooltip_text = format!(
+                    "C: {:.0}°, γ: {:.0}°\nIntensity: {:.1} cd/klm\nCandela: {:.1} cd",
+                    cell.c_angle, cell.g_angle, cell.intensity, cell.candela
+                );
+                response.clone().on_hover_text_at_pointer(tooltip_text);
             }
         }
+
+        response
     }
 
-    fn draw_axes(painter: &egui::Painter, rect: Rect, _heatmap: &HeatmapDiagram, theme: &Theme) {
+    fn draw_axes(
+        painter: &egui::Painter,
+        rect: Rect,
+        plot_rect: Rect,
+        heatmap: &HeatmapDiagram,
+        theme: &Theme,
+    ) {
         // X axis label (C-planes)
         painter.text(
-            pos2(rect.center().x, rect.bottom() + 25.0),
+            pos2(plot_rect.center().x, plot_rect.bottom() + 25.0),
             egui::Align2::CENTER_TOP,
             "C-plane (°)",
             egui::FontId::proportional(11.0),
@@ -97,61 +107,64 @@ impl HeatmapWidget {
 
         // Y axis label (Gamma)
         painter.text(
-            pos2(rect.left() - 40.0, rect.center().y),
+            pos2(plot_rect.left() - 45.0, plot_rect.center().y),
             egui::Align2::CENTER_CENTER,
             "γ",
             egui::FontId::proportional(14.0),
             theme.text,
         );
 
+        // Thin out tick labels so they never overlap
+        let c_step = (heatmap.c_angles.len() as f64 * 30.0 / heatmap.plot_width)
+            .ceil()
+            .max(1.0) as usize;
+        let g_step = (heatmap.g_angles.len() as f64 * 14.0 / heatmap.plot_height)
+            .ceil()
+            .max(1.0) as usize;
+
         // X axis tick labels
-        let c_ticks = [0.0, 90.0, 180.0, 270.0, 360.0];
-        for &c in &c_ticks {
-            let x = rect.left() + (c as f32 / 360.0) * rect.width();
-            if x <= rect.right() {
-                painter.text(
-                    pos2(x, rect.bottom() + 5.0),
-                    egui::Align2::CENTER_TOP,
-                    format!("{:.0}", c),
-                    egui::FontId::proportional(9.0),
-                    theme.text,
-                );
-            }
+        for (x, _, label) in heatmap.x_labels(c_step) {
+            painter.text(
+                pos2(to_screen(rect, x, 0.0).x, plot_rect.bottom() + 5.0),
+                egui::Align2::CENTER_TOP,
+                label,
+                egui::FontId::proportional(9.0),
+                theme.text,
+            );
         }
 
         // Y axis tick labels
-        let g_ticks = [0.0, 45.0, 90.0, 135.0, 180.0];
-        for &g in &g_ticks {
-            let y = rect.top() + (g as f32 / 180.0) * rect.height();
-            if y <= rect.bottom() {
-                painter.text(
-                    pos2(rect.left() - 5.0, y),
-                    egui::Align2::RIGHT_CENTER,
-                    format!("{:.0}", g),
-                    egui::FontId::proportional(9.0),
-                    theme.text,
-                );
-            }
+        for (y, _, label) in heatmap.y_labels(g_step) {
+            painter.text(
+                pos2(plot_rect.left() - 5.0, to_screen(rect, 0.0, y).y),
+                egui::Align2::RIGHT_CENTER,
+                label,
+                egui::FontId::proportional(9.0),
+                theme.text,
+            );
         }
     }
 
-    fn draw_legend(painter: &egui::Painter, rect: Rect, heatmap: &HeatmapDiagram, theme: &Theme) {
-        let legend_x = rect.right() - 50.0;
-        let legend_top = rect.top() + 20.0;
-        let legend_height = rect.height() - 60.0;
+    fn draw_legend(
+        painter: &egui::Painter,
+        plot_rect: Rect,
+        heatmap: &HeatmapDiagram,
+        theme: &Theme,
+    ) {
+        let legend_x = plot_rect.right() + 20.0;
+        let legend_top = plot_rect.top();
+        let legend_height = plot_rect.height();
         let legend_width = 20.0;
 
-        // Draw gradient bar
-        let num_steps = 50;
+        // Draw gradient bar from the shared legend entries (top = max)
+        let num_steps = heatmap.legend_entries.len().max(1);
         let step_height = legend_height / num_steps as f32;
 
-        for i in 0..num_steps {
-            let normalized = 1.0 - (i as f64 / num_steps as f64);
-            let color = theme.heatmap_color(normalized);
+        for (i, (_, color, _)) in heatmap.legend_entries.iter().enumerate() {
             let y = legend_top + i as f32 * step_height;
             let step_rect =
                 Rect::from_min_size(pos2(legend_x, y), vec2(legend_width, step_height + 1.0));
-            painter.rect_filled(step_rect, 0.0, color);
+            painter.rect_filled(step_rect, 0.0, color32(*color));
         }
 
         // Border
@@ -161,14 +174,14 @@ impl HeatmapWidget {
                 vec2(legend_width, legend_height),
             ),
             0.0,
-            egui::Stroke::new(1.0, theme.axis),
+            Stroke::new(1.0, theme.axis),
         );
 
         // Labels
         painter.text(
             pos2(legend_x + legend_width + 5.0, legend_top),
             egui::Align2::LEFT_CENTER,
-            format!("{:.0}", heatmap.scale.max_intensity),
+            format!("{:.0}", heatmap.max_candela),
             egui::FontId::proportional(9.0),
             theme.text,
         );
@@ -187,7 +200,7 @@ impl HeatmapWidget {
                 legend_top + legend_height + 15.0,
             ),
             egui::Align2::CENTER_TOP,
-            "cd/klm",
+            "cd",
             egui::FontId::proportional(9.0),
             theme.text,
         );
@@ -0,0 +1,241 @@
+//! Screen layout shared by the native diagram widgets
+//!
+//! The core diagram builders already produce geometry in output pixels
+//! (cartesian points, heatmap cells) or in intensity units around a center
+//! (polar points). These helpers place that geometry inside an egui rect and
+//! invert it for hover readouts, using the same layout as the SVG renderer.
+//! They are public so applications can map pointer positions on a widget
+//! (e.g. for measurement markers) exactly as the widget draws.
+
+use egui::{pos2, Color32, Pos2, Rect};
+use eulumdat::diagram::{
+    CartesianDiagram, Color, HeatmapCell, HeatmapDiagram, PolarDiagram, RadialScale,
+};
+
+/// Outer margin of the polar layout (matches `PolarDiagram::to_svg`)
+const POLAR_MARGIN: f32 = 60.0;
+
+/// Convert a core diagram color to an egui color
+pub(crate) fn color32(color: Color) -> Color32 {
+    Color32::from_rgb(color.r, color.g, color.b)
+}
+
+/// Map a point in diagram pixels (relative to the widget origin) to the screen
+pub(crate) fn to_screen(rect: Rect, x: f64, y: f64) -> Pos2 {
+    pos2(rect.min.x + x as f32, rect.min.y + y as f32)
+}
+
+/// Map a screen position to diagram pixels (relative to the widget origin)
+pub(crate) fn to_local(rect: Rect, pos: Pos2) -> (f64, f64) {
+    ((pos.x - rect.min.x) as f64, (pos.y - rect.min.y) as f64)
+}
+
+/// Polar diagram placement inside a square widget
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolarLayout {
+    /// Center of the diagram on screen
+    pub center: Pos2,
+    /// Radius of the outermost grid circle in points
+    pub radius: f32,
+    /// Intensity at the outermost grid circle (cd/klm)
+    pub scale_max: f64,
+    /// Mapping of intensity to radius
+    pub radial_scale: RadialScale,
+}
+
+impl PolarLayout {
+    /// Lay out a linear polar diagram in `rect`
+    ///
+    /// The margin shrinks on small widgets so the plot stays usable.
+    pub fn new(rect: Rect, scale_max: f64) -> Self {
+        let size = rect.width().min(rect.height());
+        let margin = POLAR_MARGIN.min(size * 0.12);
+        Self {
+            center: rect.center(),
+            radius: (size / 2.0 - margin).max(1.0),
+            scale_max,
+            radial_scale: RadialScale::Linear,
+        }
+    }
+
+    /// Lay out `polar` in `rect`, on its scale and radial scale
+    pub fn for_diagram(rect: Rect, polar: &PolarDiagram) -> Self {
+        Self {
+            radial_scale: polar.radial_scale,
+            ..Self::new(rect, polar.scale.scale_max)
+        }
+    }
+
+    /// Radius in points for an intensity value
+    pub fn radius_for(&self, intensity: f64) -> f32 {
+        self.radius * self.radial_scale.fraction(intensity, self.scale_max) as f32
+    }
+
+    /// Screen position for a gamma angle and intensity on the right (C0/C90) half
+    pub fn point(&self, gamma: f64, intensity: f64) -> Pos2 {
+        let r = self.radius_for(intensity);
+        let g = gamma.to_radians() as f32;
+        pos2(self.center.x + r * g.sin(), self.center.y + r * g.cos())
+    }
+
+    /// Invert a screen position into `(gamma, intensity, right_half)`
+    ///
+    /// Returns `None` outside the outermost grid circle.
+    pub fn invert(&self, pos: Pos2) -> Option<(f64, f64, bool)> {
+        let dx = (pos.x - self.center.x) as f64;
+        let dy = (pos.y - self.center.y) as f64;
+        let r = dx.hypot(dy);
+        if r > self.radius as f64 {
+            return None;
+        }
+        // Gamma 0° points down; both halves share the gamma scale
+        let gamma = dx.abs().atan2(dy).to_degrees();
+        let intensity = self
+            .radial_scale
+            .value_at(r / self.radius as f64, self.scale_max);
+        Some((gamma, intensity, dx >= 0.0))
+    }
+}
+
+/// Cartesian plot area on screen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CartesianLayout {
+    /// Plot area (axes box) on screen
+    pub plot: Rect,
+    /// Gamma angle at the right edge of the plot
+    pub max_gamma: f64,
+    /// Intensity at the top of the plot (cd/klm)
+    pub scale_max: f64,
+}
+
+impl CartesianLayout {
+    /// Place `cartesian`, built at the widget size, in `rect`
+    pub fn new(rect: Rect, cartesian: &CartesianDiagram) -> Self {
+        Self {
+            plot: Rect::from_min_size(
+                to_screen(rect, cartesian.margin_left, cartesian.margin_top),
+                egui::vec2(cartesian.plot_width as f32, cartesian.plot_height as f32),
+            ),
+            max_gamma: cartesian.max_gamma,
+            scale_max: cartesian.scale.scale_max,
+        }
+    }
+
+    /// Screen x position of a gamma angle
+    pub fn gamma_x(&self, gamma: f64) -> f32 {
+        self.plot.left() + (gamma / self.max_gamma) as f32 * self.plot.width()
+    }
+
+    /// Screen y position of an intensity value
+    pub fn intensity_y(&self, intensity: f64) -> f32 {
+        self.plot.bottom() - (intensity / self.scale_max) as f32 * self.plot.height()
+    }
+
+    /// Screen position of a gamma angle and intensity
+    pub fn point(&self, gamma: f64, intensity: f64) -> Pos2 {
+        pos2(self.gamma_x(gamma), self.intensity_y(intensity))
+    }
+
+    /// Invert a screen position into `(gamma, intensity)`
+    ///
+    /// Returns `None` outside the plot area (with half a point of slack, so
+    /// the axes themselves still hit).
+    pub fn invert(&self, pos: Pos2) -> Option<(f64, f64)> {
+        if !self.plot.expand(0.5).contains(pos) || !self.plot.is_positive() {
+            return None;
+        }
+        let fx = ((pos.x - self.plot.left()) / self.plot.width()).clamp(0.0, 1.0) as f64;
+        let fy = ((self.plot.bottom() - pos.y) / self.plot.height()).clamp(0.0, 1.0) as f64;
+        Some((fx * self.max_gamma, fy * self.scale_max))
+    }
+}
+
+/// Heatmap cell under a screen position, for a heatmap built at the size of `rect`
+pub fn heatmap_cell_at(heatmap: &HeatmapDiagram, rect: Rect, pos: Pos2) -> Option<&HeatmapCell> {
+    let (x, y) = to_local(rect, pos);
+    heatmap.cells.iter().find(|cell| {
+        x >= cell.x && x < cell.x + cell.width && y >= cell.y && y < cell.y + cell.height
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use eulumdat::{Eulumdat, LampSet};
+
+    fn test_ldt() -> Eulumdat {
+        Eulumdat {
+            c_angles: vec![0.0, 90.0, 180.0, 270.0],
+            g_angles: vec![0.0, 45.0, 90.0],
+            intensities: vec![
+                vec![200.0, 120.0, 10.0],
+                vec![180.0, 100.0, 8.0],
+                vec![200.0, 120.0, 10.0],
+                vec![180.0, 100.0, 8.0],
+            ],
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 1000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_polar_roundtrip() {
+        let rect = Rect::from_min_size(pos2(10.0, 20.0), egui::vec2(400.0, 400.0));
+        let layout = PolarLayout::new(rect, 250.0);
+        let pos = layout.point(60.0, 100.0);
+        let (gamma, intensity, right) = layout.invert(pos).unwrap();
+        assert_relative_eq!(gamma, 60.0, epsilon = 1e-3);
+        assert_relative_eq!(intensity, 100.0, epsilon = 1e-3);
+        assert!(right);
+
+        let mirrored = pos2(2.0 * layout.center.x - pos.x, pos.y);
+        assert!(!layout.invert(mirrored).unwrap().2);
+        assert!(layout.invert(rect.min).is_none());
+    }
+
+    #[test]
+    fn test_polar_radial_scale_roundtrip() {
+        let rect = Rect::from_min_size(pos2(0.0, 0.0), egui::vec2(400.0, 400.0));
+        let mut polar = eulumdat::diagram::PolarDiagram::from_eulumdat(&test_ldt());
+        polar = polar.with_radial_scale(RadialScale::Logarithmic);
+        let layout = PolarLayout::for_diagram(rect, &polar);
+        let pos = layout.point(30.0, 20.0);
+        let (gamma, intensity, _) = layout.invert(pos).unwrap();
+        assert_relative_eq!(gamma, 30.0, epsilon = 1e-3);
+        assert_relative_eq!(intensity, 20.0, epsilon = 1e-2);
+        assert!(layout.radius_for(20.0) > PolarLayout::new(rect, 250.0).radius_for(20.0));
+    }
+
+    #[test]
+    fn test_cartesian_layout_matches_curve_points() {
+        let ldt = test_ldt();
+        let rect = Rect::from_min_size(pos2(10.0, 20.0), egui::vec2(500.0, 300.0));
+        let cartesian = CartesianDiagram::from_eulumdat(&ldt, 500.0, 300.0, 8);
+        let layout = CartesianLayout::new(rect, &cartesian);
+        for point in &cartesian.curves[0].points {
+            let pos = to_screen(rect, point.x, point.y);
+            let (gamma, intensity) = layout.invert(pos).unwrap();
+            assert_relative_eq!(gamma, point.gamma, epsilon = 1e-3);
+            assert_relative_eq!(intensity, point.intensity, epsilon = 1e-2);
+            assert_relative_eq!(layout.point(gamma, intensity).x, pos.x, epsilon = 1e-3);
+        }
+        assert!(layout.invert(rect.min).is_none());
+    }
+
+    #[test]
+    fn test_heatmap_cell_lookup() {
+        let ldt = test_ldt();
+        let heatmap = HeatmapDiagram::from_eulumdat(&ldt, 600.0, 400.0);
+        let cell = &heatmap.cells[5];
+        let rect = Rect::from_min_size(pos2(10.0, 20.0), egui::vec2(600.0, 400.0));
+        let center = to_screen(rect, cell.x + cell.width / 2.0, cell.y + cell.height / 2.0);
+        let found = heatmap_cell_at(&heatmap, rect, center).unwrap();
+        assert_eq!((found.c_index, found.g_index), (cell.c_index, cell.g_index));
+        assert!(heatmap_cell_at(&heatmap, rect, rect.min).is_none());
+    }
+}
@@ -3,13 +3,16 @@
 mod cartesian;
 mod heatmap;
 mod info;
+pub mod layout;
 mod polar;
+mod primitives;
 mod validation;
 
 pub use cartesian::CartesianWidget;
 pub use heatmap::HeatmapWidget;
 pub use info::InfoPanel;
 pub use polar::PolarWidget;
+pub use primitives::paint_primitives;
 pub use validation::ValidationPanel;
 
 /// Available diagram tabs
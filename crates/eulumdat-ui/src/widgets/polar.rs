@@ -1,8 +1,12 @@
 //! Polar diagram widget for egui
 
+use super::layout::PolarLayout;
 use crate::Theme;
-use egui::{vec2, Color32, Pos2, Sense, Stroke, Vec2};
-use eulumdat::{diagram::PolarDiagram, Eulumdat};
+use egui::{vec2, Color32, Pos2, Response, Sense, Shape, Stroke, Vec2};
+use eulumdat::{
+    diagram::{PolarCurve, PolarDiagram},
+    Eulumdat,
+};
 
 /// Polar diagram widget
 pub struct PolarWidget;
@@ -10,72 +14,107 @@ pub struct PolarWidget;
 impl PolarWidget {
     /// Show the polar diagram
     pub fn show(ui: &mut egui::Ui, ldt: &Eulumdat, theme: &Theme) {
+        let polar = PolarDiagram::from_eulumdat(ldt);
+        Self::show_diagram(ui, ldt, &polar, theme);
+    }
+
+    /// Show a prepared polar diagram of `ldt`, e.g. one kept by the caller
+    /// between frames or built for a single C-plane pair.
+    ///
+    /// Returns the response of the plot square, which also senses clicks.
+    pub fn show_diagram(
+        ui: &mut egui::Ui,
+        ldt: &Eulumdat,
+        polar: &PolarDiagram,
+        theme: &Theme,
+    ) -> Response {
         let available_size = ui.available_size();
-        let size = available_size.min_elem().min(600.0);
-        let (response, painter) = ui.allocate_painter(Vec2::splat(size), Sense::hover());
+        let size = available_size.x.min(available_size.y - 30.0).max(200.0);
+        let (response, painter) = ui.allocate_painter(Vec2::splat(size), Sense::click());
 
         let rect = response.rect;
-        let center = rect.center();
-        let radius = (size / 2.0) * 0.85;
 
         // Background
         painter.rect_filled(rect, 0.0, theme.background);
 
-        // Generate diagram data
-        let polar = PolarDiagram::from_eulumdat(ldt);
+        let layout = PolarLayout::for_diagram(rect, polar);
 
         // Draw grid circles
-        Self::draw_grid(&painter, center, radius, &polar, theme);
+        Self::draw_grid(&painter, &layout, polar, theme);
 
         // Draw angle labels
-        Self::draw_angle_labels(&painter, center, radius, theme);
+        Self::draw_angle_labels(&painter, &layout, theme);
 
         // Draw intensity curves
         Self::draw_curve(
             &painter,
-            center,
-            radius,
-            &polar.c0_c180_curve.points,
-            polar.scale.scale_max,
+            &layout,
+            polar,
+            &polar.c0_c180_curve,
             theme.primary_curve,
         );
 
         if polar.show_c90_c270() {
             Self::draw_curve(
                 &painter,
-                center,
-                radius,
-                &polar.c90_c270_curve.points,
-                polar.scale.scale_max,
+                &layout,
+                polar,
+                &polar.c90_c270_curve,
                 theme.secondary_curve,
             );
         }
 
+        // Hover readout
+        if let Some(hover_pos) = response.hover_pos() {
+            if let Some((gamma, _, right)) = layout.invert(hover_pos) {
+                Self::draw_hover(&painter, &layout, ldt, polar, gamma, right, theme);
+
+                let mut tooltip = format!("γ: {:.1}°", gamma);
+                for (c, _) in Self::planes(polar, right, theme) {
+                    tooltip.push_str(&format!("\nC{:.0}: {:.1} cd/klm", c, ldt.sample(c, gamma)));
+                }
+                response.clone().on_hover_text_at_pointer(tooltip);
+            }
+        }
+
         // Legend
-        Self::draw_legend(ui, &polar, theme);
+        Self::draw_legend(ui, polar, theme);
+
+        response
+    }
+
+    /// C-planes shown on one half with their curve colors: the planes of the
+    /// curves on the right half, the opposite planes on the left.
+    fn planes(polar: &PolarDiagram, right: bool, theme: &Theme) -> Vec<(f64, Color32)> {
+        let side = |c: f64| if right { c } else { (c + 180.0) % 360.0 };
+        let mut planes = vec![(side(polar.c0_c180_curve.c_angle), theme.primary_curve)];
+        if polar.show_c90_c270() {
+            planes.push((side(polar.c90_c270_curve.c_angle), theme.secondary_curve));
+        }
+        planes
     }
 
     fn draw_grid(
         painter: &egui::Painter,
-        center: Pos2,
-        radius: f32,
+        layout: &PolarLayout,
         polar: &PolarDiagram,
         theme: &Theme,
     ) {
         let grid_stroke = Stroke::new(1.0, theme.grid);
         let axis_stroke = Stroke::new(1.5, theme.axis);
+        let center = layout.center;
+        let radius = layout.radius;
 
         // Concentric circles for intensity scale
-        for &value in polar.scale.grid_values.iter() {
-            let r = radius * (value / polar.scale.scale_max) as f32;
+        for value in polar.radial_scale.ring_values(&polar.scale) {
+            let r = layout.radius_for(value);
             painter.circle_stroke(center, r, grid_stroke);
 
-            // Label on right side
-            let label_pos = center + vec2(r + 5.0, 0.0);
+            // Label below the center, like the SVG output
             painter.text(
-                label_pos,
-                egui::Align2::LEFT_CENTER,
-                format!("{:.0}", value),
+                center + vec2(5.0, r + 2.0),
+                egui::Align2::LEFT_TOP,
+                polar.radial_scale.format_value(value),
                 egui::FontId::proportional(10.0),
                 theme.text,
             );
@@ -99,69 +138,105 @@ impl PolarWidget {
         );
     }
 
-    fn draw_angle_labels(painter: &egui::Painter, center: Pos2, radius: f32, theme: &Theme) {
-        let labels = [
-            (0, "0°", egui::Align2::CENTER_BOTTOM),
-            (90, "90°", egui::Align2::LEFT_CENTER),
-            (180, "180°", egui::Align2::CENTER_TOP),
-            (270, "90°", egui::Align2::RIGHT_CENTER),
-        ];
-
-        for (angle_deg, label, align) in labels {
-            let angle_rad = (angle_deg as f32 - 90.0).to_radians();
-            let pos = center + (radius + 15.0) * vec2(angle_rad.cos(), angle_rad.sin());
-            painter.text(
-                pos,
-                align,
-                label,
-                egui::FontId::proportional(12.0),
-                theme.text,
-            );
+    fn draw_angle_labels(painter: &egui::Painter, layout: &PolarLayout, theme: &Theme) {
+        let label_offset = layout.radius + 14.0;
+
+        for gamma in (30..=150).step_by(30) {
+            let g = (gamma as f32).to_radians();
+            let dx = label_offset * g.sin();
+            let dy = label_offset * g.cos();
+            for x in [layout.center.x - dx, layout.center.x + dx] {
+                painter.text(
+                    Pos2::new(x, layout.center.y + dy),
+                    egui::Align2::CENTER_CENTER,
+                    format!("{}°", gamma),
+                    egui::FontId::proportional(11.0),
+                    theme.text,
+                );
+            }
         }
+
+        painter.text(
+            layout.center + vec2(0.0, label_offset),
+            egui::Align2::CENTER_CENTER,
+            "0°",
+            egui::FontId::proportional(11.0),
+            theme.text,
+        );
+        painter.text(
+            layout.center - vec2(0.0, label_offset),
+            egui::Align2::CENTER_CENTER,
+            "180°",
+            egui::FontId::proportional(11.0),
+            theme.text,
+        );
     }
 
     fn draw_curve(
         painter: &egui::Painter,
-        center: Pos2,
-        radius: f32,
-        points: &[eulumdat::diagram::PolarPoint],
-        scale_max: f64,
+        layout: &PolarLayout,
+        polar: &PolarDiagram,
+        curve: &PolarCurve,
         color: Color32,
     ) {
-        if points.is_empty() {
+        if curve.is_empty() {
             return;
         }
 
-        let stroke = Stroke::new(2.0, color);
-        let screen_points: Vec<Pos2> = points
-            .iter()
-            .map(|p| {
-                // Convert from diagram coordinates to screen coordinates
-                // In polar diagram: gamma 0° = down (nadir), gamma 180° = up (zenith)
-                let r = (p.intensity / scale_max) as f32 * radius;
-                let angle_rad = (p.gamma as f32 - 90.0).to_radians();
-                center + r * vec2(angle_rad.cos(), angle_rad.sin())
-            })
+        // Same geometry as the SVG output, on the diagram's radial scale
+        let screen_points: Vec<Pos2> = polar
+            .curve_screen_points(
+                curve,
+                layout.center.x as f64,
+                layout.center.y as f64,
+                layout.radius as f64,
+            )
+            .into_iter()
+            .map(|p| Pos2::new(p.x as f32, p.y as f32))
             .collect();
 
-        // Draw as connected line segments
-        for pair in screen_points.windows(2) {
-            painter.line_segment([pair[0], pair[1]], stroke);
+        painter.add(Shape::closed_line(screen_points, Stroke::new(2.0, color)));
+    }
+
+    fn draw_hover(
+        painter: &egui::Painter,
+        layout: &PolarLayout,
+        ldt: &Eulumdat,
+        polar: &PolarDiagram,
+        gamma: f64,
+        right: bool,
+        theme: &Theme,
+    ) {
+        let mirror = |p: Pos2| {
+            if right {
+                p
+            } else {
+                Pos2::new(2.0 * layout.center.x - p.x, p.y)
+            }
+        };
+
+        let edge = mirror(layout.point(gamma, layout.scale_max));
+        painter.line_segment([layout.center, edge], Stroke::new(1.0, theme.axis));
+
+        for (c, color) in Self::planes(polar, right, theme) {
+            let pos = mirror(layout.point(gamma, ldt.sample(c, gamma)));
+            painter.circle_filled(pos, 4.0, color);
+            painter.circle_stroke(pos, 4.0, Stroke::new(1.0, theme.background));
         }
     }
 
     fn draw_legend(ui: &mut egui::Ui, polar: &PolarDiagram, theme: &Theme) {
         ui.horizontal(|ui| {
-            // C0-C180 legend
+            // C0-C180 legend (or the selected plane pair)
             let (rect, _) = ui.allocate_exact_size(vec2(20.0, 3.0), Sense::hover());
             ui.painter().rect_filled(rect, 0.0, theme.primary_curve);
-            ui.label("C0-C180");
+            ui.label(&polar.c0_c180_curve.label);
 
             if polar.show_c90_c270() {
                 ui.add_space(20.0);
                 let (rect, _) = ui.allocate_exact_size(vec2(20.0, 3.0), Sense::hover());
                 ui.painter().rect_filled(rect, 0.0, theme.secondary_curve);
-                ui.label("C90-C270");
+                ui.label(&polar.c90_c270_curve.label);
             }
 
             ui.add_space(20.0);
@@ -0,0 +1,151 @@
+//! egui backend for the core diagram primitives
+//!
+//! Draws [`Primitive`]s of the core geometry model (e.g. the batwing callouts
+//! of the cartesian diagram) with an egui painter. Coordinates are diagram
+//! pixels relative to the widget origin, colors come from the [`Theme`].
+
+use super::layout::{color32, to_screen};
+use crate::Theme;
+use egui::emath::Rot2;
+use egui::epaint::{PathShape, TextShape};
+use egui::{vec2, Align2, Color32, FontId, Painter, Pos2, Rect, Shape, Stroke};
+use eulumdat::diagram::{LineStyle, Primitive, StyleRole, TextAnchor};
+
+/// Paint `primitives` laid out for a widget at `rect`
+pub fn paint_primitives(painter: &Painter, rect: Rect, primitives: &[Primitive], theme: &Theme) {
+    for primitive in primitives {
+        match primitive {
+            Primitive::Rect {
+                x,
+                y,
+                width,
+                height,
+                fill,
+                stroke,
+                corner_radius,
+            } => {
+                let shape_rect = Rect::from_min_size(
+                    to_screen(rect, *x, *y),
+                    vec2(*width as f32, *height as f32),
+                );
+                let rounding = *corner_radius as f32;
+                if let Some(fill) = fill {
+                    painter.rect_filled(shape_rect, rounding, fill_color(*fill, theme));
+                }
+                if let Some(style) = stroke {
+                    painter.rect_stroke(shape_rect, rounding, line_stroke(style, theme));
+                }
+            }
+            Primitive::Circle {
+                center,
+                radius,
+                fill,
+                stroke,
+            } => {
+                let center = to_screen(rect, center.x, center.y);
+                if let Some(fill) = fill {
+                    painter.circle_filled(center, *radius as f32, fill_color(*fill, theme));
+                }
+                if let Some(style) = stroke {
+                    painter.circle_stroke(center, *radius as f32, line_stroke(style, theme));
+                }
+            }
+            Primitive::Line { from, to, stroke } => {
+                let points = [to_screen(rect, from.x, from.y), to_screen(rect, to.x, to.y)];
+                paint_line(painter, &points, stroke, theme);
+            }
+            Primitive::Path {
+                points,
+                closed,
+                fill,
+                stroke,
+            } => {
+                let points: Vec<Pos2> = points.iter().map(|p| to_screen(rect, p.x, p.y)).collect();
+                if fill.is_some() || (*closed && stroke.dash.is_none()) {
+                    painter.add(PathShape {
+                        points,
+                        closed: *closed,
+                        fill: fill.map_or(Color32::TRANSPARENT, |f| fill_color(f, theme)),
+                        stroke: line_stroke(stroke, theme).into(),
+                    });
+                } else {
+                    paint_line(painter, &points, stroke, theme);
+                }
+            }
+            Primitive::Text {
+                position,
+                text,
+                size,
+                anchor,
+                middle,
+                rotation,
+                role,
+            } => {
+                let position = to_screen(rect, position.x, position.y);
+                let color = fill_color(*role, theme);
+                let font = FontId::proportional(*size as f32);
+                if *rotation == 0.0 {
+                    let align = match (anchor, middle) {
+                        (TextAnchor::Start, false) => Align2::LEFT_BOTTOM,
+                        (TextAnchor::Middle, false) => Align2::CENTER_BOTTOM,
+                        (TextAnchor::End, false) => Align2::RIGHT_BOTTOM,
+                        (TextAnchor::Start, true) => Align2::LEFT_CENTER,
+                        (TextAnchor::Middle, true) => Align2::CENTER_CENTER,
+                        (TextAnchor::End, true) => Align2::RIGHT_CENTER,
+                    };
+                    painter.text(position, align, text, font, color);
+                } else {
+                    // Rotate the anchored galley around the anchor point
+                    let galley = painter.layout_no_wrap(text.clone(), font, color);
+                    let size = galley.size();
+                    let dx = match anchor {
+                        TextAnchor::Start => 0.0,
+                        TextAnchor::Middle => -size.x / 2.0,
+                        TextAnchor::End => -size.x,
+                    };
+                    let dy = if *middle { -size.y / 2.0 } else { -size.y };
+                    let angle = (*rotation as f32).to_radians();
+                    let offset = Rot2::from_angle(angle) * vec2(dx, dy);
+                    painter.add(TextShape::new(position + offset, galley, color).with_angle(angle));
+                }
+            }
+        }
+    }
+}
+
+/// Solid or dashed polyline
+fn paint_line(painter: &Painter, points: &[Pos2], style: &LineStyle, theme: &Theme) {
+    let stroke = line_stroke(style, theme);
+    match style.dash {
+        Some((dash, gap)) => {
+            painter.extend(Shape::dashed_line(points, stroke, dash as f32, gap as f32));
+        }
+        None => {
+            painter.add(Shape::line(points.to_vec(), stroke));
+        }
+    }
+}
+
+fn line_stroke(style: &LineStyle, theme: &Theme) -> Stroke {
+    Stroke::new(style.width as f32, fill_color(style.role, theme))
+}
+
+/// Theme color of a style role
+fn fill_color(role: StyleRole, theme: &Theme) -> Color32 {
+    match role {
+        StyleRole::Background | StyleRole::Surface | StyleRole::LegendBackground => {
+            theme.background
+        }
+        StyleRole::Grid => theme.grid,
+        StyleRole::Axis => theme.axis,
+        StyleRole::Text | StyleRole::TextSecondary => theme.text,
+        StyleRole::CurvePrimary => theme.primary_curve,
+        StyleRole::CurvePrimaryFill => theme.primary_curve.gamma_multiply(0.15),
+        StyleRole::CurveSecondary => theme.secondary_curve,
+        StyleRole::CurveSecondaryFill => theme.secondary_curve.gamma_multiply(0.15),
+        StyleRole::Fixed(color) => color32(color),
+        StyleRole::Translucent(color, alpha) => {
+            color32(color).gamma_multiply(alpha.clamp(0.0, 1.0) as f32)
+        }
+    }
+}
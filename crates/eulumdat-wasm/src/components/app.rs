@@ -452,6 +452,7 @@ fn load_template(
                     } else {
                         0.0
                     },
+                    ..Default::default()
                 };
                 if let Ok(ldt) = IesParser::parse_with_options(&content, &opts) {
                     let doc = LuminaireOpticalData::from_eulumdat(&ldt);
@@ -669,6 +670,7 @@ pub fn App() -> impl IntoView {
                 } else {
                     0.0
                 },
+                ..Default::default()
             };
             match IesParser::parse_with_options(&content, &opts) {
                 Ok(ldt) => {
@@ -222,6 +222,11 @@ fn export_svg_as_raster(
         };
 
         if let Ok(data_url) = result {
+            let data_url = if mime_owned == "image/png" {
+                tag_png_data_url(&data_url, scale)
+            } else {
+                data_url
+            };
             trigger_download_from_url(&data_url, &filename_owned);
         }
     });
@@ -231,6 +236,23 @@ fn export_svg_as_raster(
     img.set_src(&svg_url);
 }
 
+/// Mark a canvas PNG data URL as sRGB at the given scale factor.
+///
+/// Canvas PNGs carry no color information, which some tools render washed
+/// out. Falls back to the original URL if it cannot be decoded.
+fn tag_png_data_url(data_url: &str, scale: f64) -> String {
+    use base64::Engine;
+
+    const PREFIX: &str = "data:image/png;base64,";
+    let engine = base64::engine::general_purpose::STANDARD;
+    data_url
+        .strip_prefix(PREFIX)
+        .and_then(|b64| engine.decode(b64).ok())
+        .and_then(|png| eulumdat::diagram::tag_srgb_png(&png, scale))
+        .map(|png| format!("{PREFIX}{}", engine.encode(png)))
+        .unwrap_or_else(|| data_url.to_string())
+}
+
 /// Export SVG as PNG (2x scale for crisp rendering).
 pub fn export_svg_as_png(filename: &str, svg_content: &str, scale: f64) {
     export_svg_as_raster(filename, svg_content, scale, "image/png", None);
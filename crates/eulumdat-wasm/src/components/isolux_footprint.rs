@@ -58,6 +58,7 @@ pub fn IsoluxFootprint(ldt: ReadSignal<Eulumdat>) -> impl IntoView {
     let (mounting_height, set_mounting_height) = signal(10.0_f64);
     let (tilt_angle, set_tilt_angle) = signal(0.0_f64);
     let (area_size, set_area_size) = signal(20.0_f64);
+    let (datasheet, set_datasheet) = signal(false);
 
     // Slider operates in the user's unit; convert to meters on change
     let on_height_change = move |ev: ev::Event| {
@@ -95,7 +96,11 @@ pub fn IsoluxFootprint(ldt: ReadSignal<Eulumdat>) -> impl IntoView {
         let theme = SvgTheme::css_variables_with_locale(&locale.get());
         let diagram =
             IsoluxDiagram::from_eulumdat_with_units(&ldt_val, 600.0, 500.0, params, units);
-        diagram.to_svg_with_units(600.0, 500.0, &theme, units)
+        if datasheet.get() {
+            diagram.to_svg_datasheet(600.0, 500.0, &theme, units)
+        } else {
+            diagram.to_svg_with_units(600.0, 500.0, &theme, units)
+        }
     };
 
     view! {
@@ -137,6 +142,13 @@ pub fn IsoluxFootprint(ldt: ReadSignal<Eulumdat>) -> impl IntoView {
                     />
                     <span class="control-value">{move || unit_system.get().format_distance(area_size.get())}</span>
                 </label>
+
+                <label class="control-group">
+                    <input type="checkbox"
+                        prop:checked=move || datasheet.get()
+                        on:change=move |ev| set_datasheet.set(event_target_checked(&ev))
+                    />{" "}{move || locale.get().ui.floodlight.datasheet_style.clone()}
+                </label>
             </div>
 
             <div class="isolux-diagram" inner_html=svg_content />
@@ -3,7 +3,7 @@
 
 use crate::i18n::use_locale;
 use eulumdat::{
-    diagram::{PolarDiagram as CorePolarDiagram, SvgTheme},
+    diagram::{PolarDiagram as CorePolarDiagram, SvgInteractivity, SvgTheme},
     Eulumdat,
 };
 use leptos::prelude::*;
@@ -79,8 +79,15 @@ pub fn PolarDiagram(ldt: ReadSignal<Eulumdat>) -> impl IntoView {
             }
         }}
         <div class="polar-diagram" inner_html=move || {
+            let ldt = ldt.get();
             let theme = SvgTheme::css_variables_with_locale(&locale.get());
-            CorePolarDiagram::render_svg(&ldt.get(), selected_plane.get(), 500.0, 500.0, &theme)
+            let svg = CorePolarDiagram::render_svg(&ldt, selected_plane.get(), 500.0, 500.0, &theme);
+            // Native tooltips with the exact value of every curve point
+            let polar = match selected_plane.get() {
+                Some(c_plane) => CorePolarDiagram::from_eulumdat_for_plane(&ldt, c_plane),
+                None => CorePolarDiagram::from_eulumdat(&ldt),
+            };
+            SvgInteractivity::Tooltips.apply(&svg, &polar.hover_targets(500.0), &theme)
         } />
     }
 }
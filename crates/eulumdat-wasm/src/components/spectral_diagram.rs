@@ -4,9 +4,11 @@
 //! Auto-detects horticultural lighting and shows PAR zones.
 //! Can synthesize spectrum from CCT/CRI when spectral data is not available.
 //! Calculates and displays TM-30 metrics when real spectral data is available.
+//! Plots the source on the CIE 1931 chromaticity diagram with CCT and Duv.
 //! Shows IR/UV content and thermal/hazard warnings.
 
 use crate::i18n::use_locale;
+use atla::chromaticity::ChromaticityDiagram;
 use atla::spectral::{synthesize_spectrum, SpectralDiagram, SpectralMetrics, SpectralTheme};
 use atla::tm30::{calculate_tm30, Tm30Theme};
 use atla::{LuminaireOpticalData, SpectralDistribution, SpectralUnits};
@@ -59,6 +61,7 @@ enum SpectralSubTab {
     #[default]
     Spd,
     Metrics,
+    Chromaticity,
     Tm30Cvg,
     Tm30Hue,
 }
@@ -183,6 +186,31 @@ pub fn SpectralDiagramView(
         tm30_result().map(|tm30| tm30.rf_hue_svg(700.0, 300.0, &theme))
     };
 
+    // CIE 1931 chromaticity diagram SVG
+    let chromaticity_svg = move || {
+        let doc = atla_doc.get();
+        let theme = if dark.get() {
+            SpectralTheme::dark()
+        } else {
+            SpectralTheme::light()
+        };
+        let diagram = match detect_spectral_source(&doc) {
+            SpectralSource::Direct => doc
+                .emitters
+                .iter()
+                .filter_map(|e| e.spectral_distribution.as_ref())
+                .next()
+                .map(ChromaticityDiagram::from_spectral),
+            SpectralSource::Synthesized => get_cct_cri(&doc).map(|(cct, cri)| {
+                ChromaticityDiagram::from_spectral(&synthesize_spectrum(cct, cri))
+            }),
+            SpectralSource::Sample => Some(ChromaticityDiagram::from_spectral(
+                &create_sample_led_spectrum(),
+            )),
+        };
+        diagram.map(|d| d.to_svg(600.0, 620.0, &theme))
+    };
+
     // Spectral metrics (IR/UV/PAR distribution)
     let spectral_metrics = move || -> Option<SpectralMetrics> {
         let doc = atla_doc.get();
@@ -230,6 +258,12 @@ pub fn SpectralDiagramView(
                 >
                     {move || locale.get().ui.subtabs.metrics.clone()}
                 </button>
+                <button
+                    class=move || if active_sub_tab.get() == SpectralSubTab::Chromaticity { "sub-tab active" } else { "sub-tab" }
+                    on:click=move |_| set_active_sub_tab.set(SpectralSubTab::Chromaticity)
+                >
+                    {move || locale.get().ui.subtabs.chromaticity.clone()}
+                </button>
                 {move || if has_tm30() {
                     view! {
                         <button
@@ -482,6 +516,16 @@ pub fn SpectralDiagramView(
                         </div>
                     }.into_any(),
 
+                    SpectralSubTab::Chromaticity => view! {
+                        <div class="chromaticity-container">
+                            {move || chromaticity_svg().map(|svg| view! {
+                                <div class="chromaticity-diagram">
+                                    <div class="diagram-svg" inner_html=svg />
+                                </div>
+                            })}
+                        </div>
+                    }.into_any(),
+
                     SpectralSubTab::Tm30Cvg => view! {
                         <div class="tm30-cvg-container">
                             {move || tm30_cvg_svg().map(|svg| view! {
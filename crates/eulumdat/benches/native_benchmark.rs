@@ -49,6 +49,8 @@ fn main() {
     run_challenging_benchmark();
     println!("\n{}\n", "=".repeat(60));
     run_cu_ugr_benchmark();
+    println!("\n{}\n", "=".repeat(60));
+    run_compact_benchmark();
 }
 
 fn run_simple_benchmark() {
@@ -509,3 +511,73 @@ fn run_cu_ugr_benchmark() {
         ugr_speedup
     );
 }
+
+/// Time `f` over `iterations` runs, returning µs per run
+fn time_per_iter(iterations: u32, mut f: impl FnMut()) -> f64 {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    start.elapsed().as_micros() as f64 / iterations as f64
+}
+
+fn run_compact_benchmark() {
+    use eulumdat::compact::Compression;
+
+    println!("--- Compact binary vs LDT/JSON (size and encode/decode time) ---\n");
+    let iterations = 1000;
+    let ldt = eulumdat::Eulumdat::parse(SAMPLE_LDT).expect("Parse failed");
+
+    let mut formats: Vec<(&str, usize, f64, f64)> = Vec::new();
+
+    let text = ldt.to_ldt();
+    formats.push((
+        "LDT text",
+        text.len(),
+        time_per_iter(iterations, || drop(ldt.to_ldt())),
+        time_per_iter(iterations, || drop(eulumdat::Eulumdat::parse(&text))),
+    ));
+
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_string(&ldt).expect("JSON encode failed");
+        formats.push((
+            "JSON",
+            json.len(),
+            time_per_iter(iterations, || drop(serde_json::to_string(&ldt))),
+            time_per_iter(iterations, || {
+                drop(serde_json::from_str::<eulumdat::Eulumdat>(&json))
+            }),
+        ));
+    }
+    #[cfg(not(feature = "serde"))]
+    println!("(JSON skipped: run with --features serde)");
+
+    #[allow(unused_mut)] // only extended with the zstd feature
+    let mut compressions = vec![("Compact", Compression::None)];
+    #[cfg(feature = "zstd")]
+    compressions.push(("Compact + zstd", Compression::Zstd(3)));
+    #[cfg(not(feature = "zstd"))]
+    println!("(zstd skipped: run with --features zstd)");
+
+    for (name, compression) in compressions {
+        let bytes = ldt.to_compact_bytes(compression);
+        formats.push((
+            name,
+            bytes.len(),
+            time_per_iter(iterations, || drop(ldt.to_compact_bytes(compression))),
+            time_per_iter(iterations, || {
+                drop(eulumdat::Eulumdat::from_compact_bytes(&bytes))
+            }),
+        ));
+    }
+
+    println!("\n=== Summary - Compact ===");
+    println!(
+        "{:<16} {:>8} {:>12} {:>12}",
+        "Format", "Bytes", "Encode µs", "Decode µs"
+    );
+    for (name, size, encode, decode) in formats {
+        println!("{:<16} {:>8} {:>12.2} {:>12.2}", name, size, encode, decode);
+    }
+}
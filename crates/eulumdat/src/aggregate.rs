@@ -0,0 +1,366 @@
+//! Combined distribution of several positioned luminaires.
+//!
+//! Continuous-row linear systems, multi-head spots and clustered floods are
+//! photometered one module at a time. [`Aggregate`] places several
+//! [`Eulumdat`] instances with their own position and aiming and combines
+//! them, either into a single far-field equivalent distribution or into an
+//! illuminance field that keeps the individual positions (near field).
+//!
+//! Positions and aiming follow the area calculations ([`LuminairePlace`]):
+//! metres in a right-handed frame with `z` up, `mounting_height` as the
+//! height of the luminaire, `rotation` turning C0 about the vertical axis
+//! and `tilt_angle` turning the nadir towards C0. The far-field
+//! distribution uses the same frame: C0 along +X, C90 along +Y.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use eulumdat::aggregate::Aggregate;
+//! use eulumdat::Eulumdat;
+//!
+//! let module = Eulumdat::from_file("linear_1500.ldt").unwrap();
+//! let row = Aggregate::continuous_row(&module, 4, 1.5);
+//!
+//! // One LDT describing the whole 6 m row
+//! let combined = row.to_eulumdat(5.0, 2.5);
+//! combined.save("row_6m.ldt").unwrap();
+//!
+//! // Illuminance 3 m below the row, close enough to see the modules
+//! let lux = row.illuminance_at((0.0, 0.0, -3.0), (0.0, 0.0, 1.0));
+//! println!("{lux:.0} lx");
+//! ```
+
+use crate::area::{compute_illuminance_at_point, LuminairePlace};
+use crate::calculations::PhotometricCalculations;
+use crate::eulumdat::{Eulumdat, LampSet, TypeIndicator};
+
+/// A luminaire of an [`Aggregate`] with its placement.
+#[derive(Debug, Clone)]
+pub struct AggregateMember<'a> {
+    /// Photometric data of the luminaire.
+    pub ldt: &'a Eulumdat,
+    /// Position and aiming.
+    pub place: LuminairePlace,
+}
+
+impl AggregateMember<'_> {
+    /// Lamp flux in klm, the scale of the cd/klm intensities.
+    fn flux_klm(&self) -> f64 {
+        self.ldt.total_luminous_flux() / 1000.0
+    }
+
+    /// Intensity in cd towards the direction `(C, γ)` of the aggregate frame.
+    fn intensity(&self, c: f64, g: f64) -> f64 {
+        let (sin_g, cos_g) = g.to_radians().sin_cos();
+        let (sin_c, cos_c) = c.to_radians().sin_cos();
+        let (dx, dy, dz) = (sin_g * cos_c, sin_g * sin_c, -cos_g);
+
+        // Into the luminaire frame: undo rotation, then tilt
+        let (sin_r, cos_r) = self.place.rotation.to_radians().sin_cos();
+        let (sin_t, cos_t) = self.place.tilt_angle.to_radians().sin_cos();
+        let dx_r = dx * cos_r + dy * sin_r;
+        let dy_r = -dx * sin_r + dy * cos_r;
+        let lx = dx_r * cos_t + dz * sin_t;
+        let lz = -dx_r * sin_t + dz * cos_t;
+
+        let local_g = (-lz).clamp(-1.0, 1.0).acos().to_degrees();
+        let local_c = dy_r.atan2(lx).to_degrees().rem_euclid(360.0);
+        self.ldt.sample(local_c, local_g) * self.flux_klm()
+    }
+}
+
+/// Several positioned and aimed luminaires treated as one system.
+#[derive(Debug, Clone, Default)]
+pub struct Aggregate<'a> {
+    members: Vec<AggregateMember<'a>>,
+}
+
+impl<'a> Aggregate<'a> {
+    /// Create an empty aggregate.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a luminaire at `place`.
+    pub fn add(&mut self, ldt: &'a Eulumdat, place: LuminairePlace) -> &mut Self {
+        self.members.push(AggregateMember { ldt, place });
+        self
+    }
+
+    /// Continuous row of `count` identical modules along C0 (+X).
+    ///
+    /// Modules are `spacing` metres apart, centred on the origin at height 0.
+    pub fn continuous_row(ldt: &'a Eulumdat, count: usize, spacing: f64) -> Self {
+        let mut row = Self::new();
+        let offset = (count.saturating_sub(1)) as f64 / 2.0;
+        for i in 0..count {
+            let x = (i as f64 - offset) * spacing;
+            row.add(ldt, LuminairePlace::simple(i, x, 0.0, 0.0));
+        }
+        row
+    }
+
+    /// Luminaires of the aggregate.
+    pub fn members(&self) -> &[AggregateMember<'a>] {
+        &self.members
+    }
+
+    /// Whether the aggregate has no luminaires.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Summed lamp flux of all members in lumens.
+    pub fn total_luminous_flux(&self) -> f64 {
+        self.members
+            .iter()
+            .map(|m| m.ldt.total_luminous_flux())
+            .sum()
+    }
+
+    /// Summed wattage of all members in watts.
+    pub fn total_wattage(&self) -> f64 {
+        self.members.iter().map(|m| m.ldt.total_wattage()).sum()
+    }
+
+    /// Far-field intensity in cd towards `(C, γ)`.
+    ///
+    /// Sum of the aimed member intensities; positions do not matter at
+    /// distances large against the extent of the aggregate.
+    pub fn intensity(&self, c: f64, g: f64) -> f64 {
+        self.members.iter().map(|m| m.intensity(c, g)).sum()
+    }
+
+    /// Illuminance in lux at `point` on a surface facing `normal`.
+    ///
+    /// Every member contributes from its own emission origin (inverse
+    /// square law and cosine of incidence), so this holds close to the
+    /// aggregate where the far-field equivalent does not.
+    pub fn illuminance_at(&self, point: (f64, f64, f64), normal: (f64, f64, f64)) -> f64 {
+        self.members
+            .iter()
+            .map(|m| compute_illuminance_at_point(m.ldt, &m.place, point, normal, m.flux_klm()))
+            .sum()
+    }
+
+    /// Horizontal illuminance in lux on an upward-facing plane at height `z`.
+    ///
+    /// Samples `resolution` × `resolution` points spanning `x_range` and
+    /// `y_range` inclusive; rows run along Y, columns along X.
+    pub fn illuminance_grid(
+        &self,
+        x_range: (f64, f64),
+        y_range: (f64, f64),
+        z: f64,
+        resolution: usize,
+    ) -> Vec<Vec<f64>> {
+        let n = resolution.max(2);
+        let step =
+            |(start, end): (f64, f64), i: usize| start + (end - start) * i as f64 / (n - 1) as f64;
+        (0..n)
+            .map(|row| {
+                let y = step(y_range, row);
+                (0..n)
+                    .map(|col| self.illuminance_at((step(x_range, col), y, z), (0.0, 0.0, 1.0)))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Far-field equivalent as a single distribution.
+    ///
+    /// Intensities are sampled on a full `c_step` × `g_step` grid
+    /// (0-360°, 0-180°) and stored in cd/klm of the summed lamp flux, with
+    /// symmetry re-detected. The result has one lamp set carrying the
+    /// summed flux and wattage, the flux-weighted light output ratio and
+    /// the bounding box of the members as luminaire dimensions. Header
+    /// texts are taken from the first member.
+    pub fn to_eulumdat(&self, c_step: f64, g_step: f64) -> Eulumdat {
+        let Some(first) = self.members.first() else {
+            return Eulumdat::default();
+        };
+        let flux = self.total_luminous_flux();
+        let klm = flux / 1000.0;
+
+        let (length, width, luminous_length, luminous_width) = self.extent_mm();
+        let max_of =
+            |f: fn(&Eulumdat) -> f64| self.members.iter().map(|m| f(m.ldt)).fold(0.0, f64::max);
+        let light_output_ratio = if flux > 0.0 {
+            self.members
+                .iter()
+                .map(|m| m.ldt.light_output_ratio * m.ldt.total_luminous_flux())
+                .sum::<f64>()
+                / flux
+        } else {
+            first.ldt.light_output_ratio
+        };
+        let first_lamp = first.ldt.lamp_sets.first().cloned().unwrap_or_default();
+        let num_lamps = self
+            .members
+            .iter()
+            .filter_map(|m| m.ldt.lamp_sets.first())
+            .map(|ls| ls.num_lamps)
+            .sum();
+
+        let mut ldt = Eulumdat {
+            identification: first.ldt.identification.clone(),
+            type_indicator: if length > 2.0 * width {
+                TypeIndicator::Linear
+            } else {
+                TypeIndicator::PointSourceOther
+            },
+            measurement_report_number: first.ldt.measurement_report_number.clone(),
+            luminaire_name: first.ldt.luminaire_name.clone(),
+            luminaire_number: first.ldt.luminaire_number.clone(),
+            file_name: first.ldt.file_name.clone(),
+            date_user: first.ldt.date_user.clone(),
+            length,
+            width,
+            height: max_of(|l| l.height),
+            luminous_area_length: luminous_length,
+            luminous_area_width: luminous_width,
+            conversion_factor: 1.0,
+            light_output_ratio,
+            lamp_sets: vec![LampSet {
+                num_lamps,
+                total_luminous_flux: flux,
+                wattage_with_ballast: self.total_wattage(),
+                ..first_lamp
+            }],
+            direct_ratios: first.ldt.direct_ratios,
+            intensities: vec![vec![0.0]],
+            ..Default::default()
+        };
+
+        let c_angles = angle_steps(360.0, c_step, false);
+        let g_angles = angle_steps(180.0, g_step, true);
+        ldt.resample_with(&c_angles, &g_angles, |_, c, g| {
+            if klm > 0.0 {
+                self.intensity(c, g) / klm
+            } else {
+                0.0
+            }
+        });
+        ldt.downward_flux_fraction = PhotometricCalculations::downward_flux(&ldt, 90.0);
+        ldt
+    }
+
+    /// Bounding box of the member bodies and luminous areas in mm:
+    /// `(length, width, luminous length, luminous width)`.
+    fn extent_mm(&self) -> (f64, f64, f64, f64) {
+        let span = |pos: fn(&LuminairePlace) -> f64, size: fn(&Eulumdat) -> f64| {
+            let (lo, hi) = self
+                .members
+                .iter()
+                .map(|m| {
+                    let half = size(m.ldt) / 2000.0;
+                    (pos(&m.place) - half, pos(&m.place) + half)
+                })
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (a, b)| {
+                    (lo.min(a), hi.max(b))
+                });
+            ((hi - lo) * 1000.0).max(0.0)
+        };
+        (
+            span(|p| p.effective_position().0, |l| l.length),
+            span(|p| p.effective_position().1, |l| l.width),
+            span(|p| p.effective_position().0, |l| l.luminous_area_length),
+            span(|p| p.effective_position().1, |l| l.luminous_area_width),
+        )
+    }
+}
+
+/// `0, step, 2·step, …` up to `end` (included when `inclusive`).
+fn angle_steps(end: f64, step: f64, inclusive: bool) -> Vec<f64> {
+    let step = if step > 0.0 { step } else { 5.0 };
+    let mut angles: Vec<f64> = (0..)
+        .map(|i| i as f64 * step)
+        .take_while(|a| *a < end - 1e-6)
+        .collect();
+    if inclusive {
+        angles.push(end);
+    }
+    angles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Symmetry;
+
+    /// Rotationally symmetric `300·cosⁿγ` module, 1000 lm, 5° steps.
+    fn module(n: i32) -> Eulumdat {
+        let g_angles: Vec<f64> = (0..=36).map(|i| i as f64 * 5.0).collect();
+        let row = g_angles
+            .iter()
+            .map(|g| 300.0 * g.to_radians().cos().max(0.0).powi(n))
+            .collect();
+        Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            num_c_planes: 1,
+            num_g_planes: g_angles.len(),
+            g_plane_distance: 5.0,
+            length: 1500.0,
+            width: 60.0,
+            light_output_ratio: 90.0,
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 1000.0,
+                wattage_with_ballast: 10.0,
+                ..Default::default()
+            }],
+            c_angles: vec![0.0],
+            g_angles,
+            intensities: vec![row],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_continuous_row_far_field() {
+        let ldt = module(1);
+        let row = Aggregate::continuous_row(&ldt, 4, 1.5);
+        assert_eq!(row.members().len(), 4);
+        assert_eq!(row.total_luminous_flux(), 4000.0);
+        assert!((row.intensity(0.0, 0.0) - 1200.0).abs() < 1e-9);
+
+        let combined = row.to_eulumdat(15.0, 5.0);
+        assert_eq!(combined.symmetry, Symmetry::VerticalAxis);
+        assert_eq!(combined.type_indicator, TypeIndicator::Linear);
+        assert!((combined.length - 6000.0).abs() < 1e-9);
+        assert!((combined.width - 60.0).abs() < 1e-9);
+        assert_eq!(combined.lamp_sets[0].num_lamps, 4);
+        assert!((combined.total_wattage() - 40.0).abs() < 1e-9);
+        assert!((combined.light_output_ratio - 90.0).abs() < 1e-9);
+        // Same cd/klm shape as a single module
+        assert!((combined.sample(0.0, 0.0) - 300.0).abs() < 1e-9);
+        assert!((combined.sample(90.0, 60.0) - 150.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_aimed_members_and_near_field() {
+        let ldt = module(8);
+        let mut pair = Aggregate::new();
+        let mut left = LuminairePlace::simple(0, -1.0, 0.0, 0.0);
+        left.tilt_angle = -30.0;
+        let mut right = LuminairePlace::simple(1, 1.0, 0.0, 0.0);
+        right.tilt_angle = 30.0;
+        pair.add(&ldt, left).add(&ldt, right);
+
+        // Both heads 30° off nadir: C0-C180 symmetric with the beams at ±30°
+        let combined = pair.to_eulumdat(5.0, 5.0);
+        assert_eq!(combined.symmetry, Symmetry::BothPlanes);
+        let peak = combined.sample(0.0, 30.0);
+        assert!(peak > combined.sample(0.0, 0.0));
+        assert!((combined.sample(180.0, 30.0) - peak).abs() < 1e-6);
+
+        // Near field: the heads aim outwards, away from the centre
+        let below_right = pair.illuminance_at((1.0, 0.0, -2.0), (0.0, 0.0, 1.0));
+        let below_left = pair.illuminance_at((-1.0, 0.0, -2.0), (0.0, 0.0, 1.0));
+        assert!((below_right - below_left).abs() < 1e-9);
+        let grid = pair.illuminance_grid((-3.0, 3.0), (-1.0, 1.0), -2.0, 7);
+        assert_eq!(grid.len(), 7);
+        assert!((grid[3][3] - pair.illuminance_at((0.0, 0.0, -2.0), (0.0, 0.0, 1.0))).abs() < 1e-9);
+        assert!(grid[3][0] > 10.0 * grid[3][3]);
+    }
+}
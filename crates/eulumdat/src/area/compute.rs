@@ -49,6 +49,27 @@ impl LuminairePlace {
             self.y + self.arm_length * dir_rad.cos(),
         )
     }
+
+    /// Emission origin `(x, y, height)` in meters.
+    ///
+    /// The effective position and mounting height locate the luminous area;
+    /// the photometric center offset of `ldt` is turned with the placement's
+    /// tilt and rotation and added on top.
+    pub fn emission_origin(&self, ldt: &Eulumdat) -> (f64, f64, f64) {
+        let (x, y) = self.effective_position();
+        let (ox, oy, oz) = ldt.photometric_center_offset().to_meters();
+
+        let (sin_t, cos_t) = self.tilt_angle.to_radians().sin_cos();
+        let (sin_r, cos_r) = self.rotation.to_radians().sin_cos();
+        let dx_r = ox * cos_t - oz * sin_t;
+        let dz = ox * sin_t + oz * cos_t;
+
+        (
+            x + dx_r * cos_r - oy * sin_r,
+            y + dx_r * sin_r + oy * cos_r,
+            self.mounting_height + dz,
+        )
+    }
 }
 
 /// Result of multi-luminaire illuminance calculation.
@@ -108,8 +129,7 @@ pub fn compute_area_illuminance(
     let mut lux_grid = vec![vec![0.0_f64; n]; n];
 
     for placement in placements {
-        let (lx, ly) = placement.effective_position();
-        let h = placement.mounting_height;
+        let (lx, ly, h) = placement.emission_origin(ldt);
         let tilt_rad = placement.tilt_angle.to_radians();
         let rot_rad = placement.rotation.to_radians();
 
@@ -176,8 +196,7 @@ pub fn compute_area_illuminance_mixed(
         let ldt = ldts[ldt_idx];
         let fs = flux_scales[ldt_idx];
 
-        let (lx, ly) = placement.effective_position();
-        let h = placement.mounting_height;
+        let (lx, ly, h) = placement.emission_origin(ldt);
         let tilt_rad = placement.tilt_angle.to_radians();
         let rot_rad = placement.rotation.to_radians();
 
@@ -348,8 +367,7 @@ pub fn compute_area_illuminance_polygon(
     let mut lux_grid = vec![vec![0.0_f64; n]; n];
 
     for placement in placements {
-        let (lx, ly) = placement.effective_position();
-        let h = placement.mounting_height;
+        let (lx, ly, h) = placement.emission_origin(ldt);
         let tilt_rad = placement.tilt_angle.to_radians();
         let rot_rad = placement.rotation.to_radians();
 
@@ -444,8 +462,7 @@ pub(crate) fn compute_illuminance_at_point(
     normal: (f64, f64, f64),
     flux_scale: f64,
 ) -> f64 {
-    let (lx, ly) = placement.effective_position();
-    let lz = placement.mounting_height;
+    let (lx, ly, lz) = placement.emission_origin(ldt);
 
     // Vector from luminaire to surface point
     let dx = point.0 - lx;
@@ -854,6 +871,29 @@ mod tests {
         assert!(r_poly.mask.is_some());
     }
 
+    #[test]
+    fn photometric_center_offset_shifts_origin() {
+        let mut ldt = test_ldt();
+        let mut placement = LuminairePlace::simple(0, 20.0, 20.0, 10.0);
+        assert_eq!(placement.emission_origin(&ldt), (20.0, 20.0, 10.0));
+
+        // 1 m towards C0 and 0.5 m up
+        ldt.photometric_center = Some(crate::PhotometricCenter::new(1000.0, 0.0, 500.0));
+        let (x, y, h) = placement.emission_origin(&ldt);
+        assert!((x - 21.0).abs() < 1e-9 && (y - 20.0).abs() < 1e-9);
+        assert!((h - 10.5).abs() < 1e-9);
+
+        placement.rotation = 90.0;
+        let (x, y, _) = placement.emission_origin(&ldt);
+        assert!((x - 20.0).abs() < 1e-9 && (y - 21.0).abs() < 1e-9);
+
+        // The brightest cell follows the emission origin
+        let result = compute_area_illuminance(&ldt, &[placement], 40.0, 40.0, 40, 1.0);
+        let brightest = result.lux_grid[21][20];
+        assert!(brightest >= result.lux_grid[19][20]);
+        assert!((brightest - result.max_lux).abs() < 1e-9);
+    }
+
     #[test]
     fn polygon_triangle_excludes_cells() {
         use crate::area::polygon::AreaPolygon;
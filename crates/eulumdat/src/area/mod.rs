@@ -6,14 +6,17 @@
 mod compute;
 pub mod layout;
 mod optimize;
+pub mod plane;
 mod polygon;
 mod svg;
 
+pub(crate) use compute::compute_illuminance_at_point;
 pub use compute::{
     compute_area_illuminance, compute_area_illuminance_mixed, compute_area_illuminance_polygon,
     compute_wall_illuminance, AreaResult, LuminairePlace,
 };
 pub use layout::{ArrangementType, GridPreset, PoleConfig};
 pub use optimize::{optimize_spacing, OptimizationCriteria, OptimizationRow};
+pub use plane::{CalculationPlane, IlluminanceGrid};
 pub use polygon::AreaPolygon;
 pub use svg::{AreaSvg, ContourOverlay};
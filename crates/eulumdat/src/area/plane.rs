@@ -0,0 +1,347 @@
+//! Illuminance grids on arbitrary calculation planes.
+//!
+//! A [`CalculationPlane`] is a rectangle in 3D space (floor, workplane, wall
+//! or any tilted surface) sampled at cell centers. Computing a plane yields an
+//! [`IlluminanceGrid`]: the raw lux values with their extents and statistics,
+//! independent of any rendering, so diagrams, reports and the CLI can share it.
+
+use super::compute::{compute_illuminance_at_point, LuminairePlace};
+use crate::Eulumdat;
+
+/// A rectangular calculation plane in 3D space (meters).
+///
+/// Cell `(row, col)` has its center at
+/// `origin + (col + 0.5) · du · u_axis + (row + 0.5) · dv · v_axis`
+/// and the plane receives light on the side of `u_axis × v_axis`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CalculationPlane {
+    /// Corner of the plane (x, y, z)
+    pub origin: (f64, f64, f64),
+    /// Unit vector along the plane width (columns)
+    pub u_axis: (f64, f64, f64),
+    /// Unit vector along the plane height (rows)
+    pub v_axis: (f64, f64, f64),
+    /// Extent along `u_axis` in meters
+    pub width: f64,
+    /// Extent along `v_axis` in meters
+    pub height: f64,
+    /// Number of grid columns
+    pub cols: usize,
+    /// Number of grid rows
+    pub rows: usize,
+}
+
+impl CalculationPlane {
+    /// Horizontal plane at height `z`, spanning `width` × `depth` from (x, y).
+    ///
+    /// Faces upward; rows run along +Y, columns along +X.
+    pub fn horizontal(
+        x: f64,
+        y: f64,
+        z: f64,
+        width: f64,
+        depth: f64,
+        cols: usize,
+        rows: usize,
+    ) -> Self {
+        Self {
+            origin: (x, y, z),
+            u_axis: (1.0, 0.0, 0.0),
+            v_axis: (0.0, 1.0, 0.0),
+            width,
+            height: depth,
+            cols,
+            rows,
+        }
+    }
+
+    /// Floor plane (z = 0) centered on the origin.
+    pub fn floor_centered(half_width: f64, half_depth: f64, resolution: usize) -> Self {
+        Self::horizontal(
+            -half_width,
+            -half_depth,
+            0.0,
+            2.0 * half_width,
+            2.0 * half_depth,
+            resolution,
+            resolution,
+        )
+    }
+
+    /// Vertical wall from `start` to `end` (ground coordinates), from `base_z` up `height` meters.
+    ///
+    /// Seen from above, the wall faces to the right of the `start → end` direction;
+    /// rows run upward.
+    pub fn vertical(
+        start: (f64, f64),
+        end: (f64, f64),
+        base_z: f64,
+        height: f64,
+        cols: usize,
+        rows: usize,
+    ) -> Self {
+        let dx = end.0 - start.0;
+        let dy = end.1 - start.1;
+        let len = (dx * dx + dy * dy).sqrt();
+        let u_axis = if len > 1e-9 {
+            (dx / len, dy / len, 0.0)
+        } else {
+            (1.0, 0.0, 0.0)
+        };
+        Self {
+            origin: (start.0, start.1, base_z),
+            u_axis,
+            v_axis: (0.0, 0.0, 1.0),
+            width: len,
+            height,
+            cols,
+            rows,
+        }
+    }
+
+    /// Surface normal (`u_axis × v_axis`, normalized).
+    pub fn normal(&self) -> (f64, f64, f64) {
+        let (ux, uy, uz) = self.u_axis;
+        let (vx, vy, vz) = self.v_axis;
+        let n = (uy * vz - uz * vy, uz * vx - ux * vz, ux * vy - uy * vx);
+        let len = (n.0 * n.0 + n.1 * n.1 + n.2 * n.2).sqrt();
+        if len > 1e-12 {
+            (n.0 / len, n.1 / len, n.2 / len)
+        } else {
+            (0.0, 0.0, 1.0)
+        }
+    }
+
+    /// Cell size along the `u_axis` and `v_axis` in meters.
+    pub fn cell_size(&self) -> (f64, f64) {
+        (
+            self.width / self.cols.max(1) as f64,
+            self.height / self.rows.max(1) as f64,
+        )
+    }
+
+    /// Plane-local coordinates (meters from origin) of a cell center.
+    pub fn local_point(&self, row: usize, col: usize) -> (f64, f64) {
+        let (du, dv) = self.cell_size();
+        ((col as f64 + 0.5) * du, (row as f64 + 0.5) * dv)
+    }
+
+    /// World coordinates of a cell center.
+    pub fn world_point(&self, row: usize, col: usize) -> (f64, f64, f64) {
+        let (u, v) = self.local_point(row, col);
+        (
+            self.origin.0 + u * self.u_axis.0 + v * self.v_axis.0,
+            self.origin.1 + u * self.u_axis.1 + v * self.v_axis.1,
+            self.origin.2 + u * self.u_axis.2 + v * self.v_axis.2,
+        )
+    }
+}
+
+/// Illuminance values on a calculation plane.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IlluminanceGrid {
+    /// The plane the grid was computed on
+    pub plane: CalculationPlane,
+    /// Illuminance in lux (row-major: `[row][col]`)
+    pub values: Vec<Vec<f64>>,
+    pub min_lux: f64,
+    pub avg_lux: f64,
+    pub max_lux: f64,
+}
+
+impl IlluminanceGrid {
+    /// Compute illuminance on `plane` from the given luminaire placements.
+    ///
+    /// Placements carry mounting height and aiming (tilt/rotation).
+    pub fn compute(
+        ldt: &Eulumdat,
+        placements: &[LuminairePlace],
+        plane: &CalculationPlane,
+        proration_factor: f64,
+    ) -> Self {
+        let total_flux: f64 = ldt
+            .lamp_sets
+            .iter()
+            .map(|ls| ls.total_luminous_flux * ls.num_lamps.unsigned_abs() as f64)
+            .sum();
+        let flux_scale = total_flux / 1000.0 * proration_factor;
+        let normal = plane.normal();
+
+        let values: Vec<Vec<f64>> = (0..plane.rows)
+            .map(|row| {
+                (0..plane.cols)
+                    .map(|col| {
+                        let point = plane.world_point(row, col);
+                        placements
+                            .iter()
+                            .map(|p| {
+                                compute_illuminance_at_point(ldt, p, point, normal, flux_scale)
+                            })
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self::from_values(plane.clone(), values)
+    }
+
+    /// Compute illuminance on `plane` for a single luminaire above the origin.
+    ///
+    /// # Arguments
+    /// * `mounting_height` - Luminaire height in meters
+    /// * `tilt_angle` - Tilt in degrees (0 = pointing down)
+    /// * `rotation` - C0 direction in degrees
+    pub fn for_luminaire(
+        ldt: &Eulumdat,
+        mounting_height: f64,
+        tilt_angle: f64,
+        rotation: f64,
+        plane: &CalculationPlane,
+    ) -> Self {
+        let placement = LuminairePlace {
+            tilt_angle,
+            rotation,
+            ..LuminairePlace::simple(0, 0.0, 0.0, mounting_height)
+        };
+        Self::compute(ldt, &[placement], plane, 1.0)
+    }
+
+    /// Build a grid from precomputed values, deriving the statistics.
+    pub fn from_values(plane: CalculationPlane, values: Vec<Vec<f64>>) -> Self {
+        let mut min_lux = f64::MAX;
+        let mut max_lux: f64 = 0.0;
+        let mut sum = 0.0;
+        let mut count = 0usize;
+        for &lux in values.iter().flatten() {
+            min_lux = min_lux.min(lux);
+            max_lux = max_lux.max(lux);
+            sum += lux;
+            count += 1;
+        }
+        if count == 0 {
+            min_lux = 0.0;
+        }
+        let avg_lux = if count > 0 { sum / count as f64 } else { 0.0 };
+
+        Self {
+            plane,
+            values,
+            min_lux,
+            avg_lux,
+            max_lux,
+        }
+    }
+
+    /// Plane extents as `(width, height)` in meters.
+    pub fn extents(&self) -> (f64, f64) {
+        (self.plane.width, self.plane.height)
+    }
+
+    /// Illuminance at a cell, if in range.
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        self.values.get(row).and_then(|r| r.get(col).copied())
+    }
+
+    /// Uniformity U₀ = min / avg.
+    pub fn uniformity_min_avg(&self) -> f64 {
+        if self.avg_lux > 0.0 {
+            self.min_lux / self.avg_lux
+        } else {
+            0.0
+        }
+    }
+
+    /// Uniformity Ud = min / max.
+    pub fn uniformity_min_max(&self) -> f64 {
+        if self.max_lux > 0.0 {
+            self.min_lux / self.max_lux
+        } else {
+            0.0
+        }
+    }
+
+    /// Cell-center coordinates along the plane axes (meters from origin).
+    ///
+    /// Returns `(u_coords, v_coords)` for columns and rows respectively.
+    pub fn axis_coords(&self) -> (Vec<f64>, Vec<f64>) {
+        let (du, dv) = self.plane.cell_size();
+        (
+            (0..self.plane.cols)
+                .map(|c| (c as f64 + 0.5) * du)
+                .collect(),
+            (0..self.plane.rows)
+                .map(|r| (r as f64 + 0.5) * dv)
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LampSet;
+
+    fn test_ldt() -> Eulumdat {
+        Eulumdat {
+            c_angles: vec![0.0, 90.0, 180.0, 270.0],
+            g_angles: vec![0.0, 15.0, 30.0, 45.0, 60.0, 75.0, 90.0],
+            intensities: vec![
+                vec![300.0, 280.0, 220.0, 140.0, 60.0, 15.0, 3.0],
+                vec![300.0, 270.0, 200.0, 120.0, 50.0, 12.0, 2.0],
+                vec![300.0, 280.0, 220.0, 140.0, 60.0, 15.0, 3.0],
+                vec![300.0, 270.0, 200.0, 120.0, 50.0, 12.0, 2.0],
+            ],
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 10000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn floor_grid_matches_inverse_square() {
+        let ldt = test_ldt();
+        // Single cell directly below the luminaire
+        let plane = CalculationPlane::horizontal(-0.05, -0.05, 0.0, 0.1, 0.1, 1, 1);
+        let grid = IlluminanceGrid::for_luminaire(&ldt, 5.0, 0.0, 0.0, &plane);
+        // E = 300 cd/klm × 10 klm / 25 m²
+        assert!((grid.values[0][0] - 120.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn wall_faces_luminaire() {
+        let ldt = test_ldt();
+        // Wall at x = 3 running from +Y to -Y faces -X (towards the luminaire)
+        let facing = CalculationPlane::vertical((3.0, 2.0), (3.0, -2.0), 0.0, 3.0, 8, 6);
+        let away = CalculationPlane::vertical((3.0, -2.0), (3.0, 2.0), 0.0, 3.0, 8, 6);
+        assert!((facing.normal().0 + 1.0).abs() < 1e-9);
+
+        let lit = IlluminanceGrid::for_luminaire(&ldt, 4.0, 0.0, 0.0, &facing);
+        let dark = IlluminanceGrid::for_luminaire(&ldt, 4.0, 0.0, 0.0, &away);
+        assert!(lit.max_lux > 0.0);
+        assert_eq!(dark.max_lux, 0.0);
+        assert_eq!(lit.values.len(), 6);
+        assert_eq!(lit.values[0].len(), 8);
+    }
+
+    #[test]
+    fn tilt_shifts_peak() {
+        let ldt = test_ldt();
+        let plane = CalculationPlane::floor_centered(20.0, 20.0, 41);
+        let grid = IlluminanceGrid::for_luminaire(&ldt, 8.0, 30.0, 0.0, &plane);
+        let (mut best_col, mut best) = (0, 0.0);
+        for (col, &v) in grid.values[20].iter().enumerate() {
+            if v > best {
+                best = v;
+                best_col = col;
+            }
+        }
+        assert_ne!(best_col, 20, "Tilted peak should move off-center");
+        assert!(grid.uniformity_min_max() <= 1.0);
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides efficient batch processing of LDT files, allowing
 //! conversion of multiple files in a single operation.
 
+use crate::metadata::{CatalogEntry, MetadataLookup};
 use crate::{Eulumdat, IesExporter};
 
 /// Input file for batch conversion
@@ -36,6 +37,8 @@ pub struct BatchOutput {
     pub content: Option<String>,
     /// Error message (if failed)
     pub error: Option<String>,
+    /// Catalog metadata applied during conversion (see [`batch_convert_with_lookup`])
+    pub metadata: Option<CatalogEntry>,
 }
 
 /// Target format for batch conversion
@@ -97,7 +100,31 @@ pub struct BatchStats {
 pub fn batch_convert(inputs: &[BatchInput], format: ConversionFormat) -> Vec<BatchOutput> {
     inputs
         .iter()
-        .map(|input| convert_single(input, format))
+        .map(|input| convert_single(input, format, None))
+        .collect()
+}
+
+/// Convert one file.
+///
+/// The building block of [`batch_convert`] for callers that schedule the
+/// files themselves, e.g. on a thread pool or with progress reporting.
+pub fn convert_one(input: &BatchInput, format: ConversionFormat) -> BatchOutput {
+    convert_single(input, format, None)
+}
+
+/// Batch convert, enriching each file with catalog metadata.
+///
+/// Every successfully parsed file is passed to `lookup`; matching entries
+/// are applied before export (see [`crate::metadata::enrich`]) and returned
+/// in [`BatchOutput::metadata`].
+pub fn batch_convert_with_lookup(
+    inputs: &[BatchInput],
+    format: ConversionFormat,
+    lookup: &dyn MetadataLookup,
+) -> Vec<BatchOutput> {
+    inputs
+        .iter()
+        .map(|input| convert_single(input, format, Some(lookup)))
         .collect()
 }
 
@@ -118,7 +145,11 @@ pub fn batch_convert_with_stats(
 }
 
 /// Convert a single file
-fn convert_single(input: &BatchInput, output_format: ConversionFormat) -> BatchOutput {
+fn convert_single(
+    input: &BatchInput,
+    output_format: ConversionFormat,
+    lookup: Option<&dyn MetadataLookup>,
+) -> BatchOutput {
     // Detect input format if not specified
     let input_format = input
         .format
@@ -131,7 +162,8 @@ fn convert_single(input: &BatchInput, output_format: ConversionFormat) -> BatchO
     };
 
     match parse_result {
-        Ok(ldt) => {
+        Ok(mut ldt) => {
+            let metadata = lookup.and_then(|lookup| crate::metadata::enrich(&mut ldt, lookup));
             let content = match output_format {
                 ConversionFormat::Ies => IesExporter::export(&ldt),
                 ConversionFormat::Ldt => ldt.to_ldt(),
@@ -157,6 +189,7 @@ fn convert_single(input: &BatchInput, output_format: ConversionFormat) -> BatchO
                 output_name,
                 content: Some(content),
                 error: None,
+                metadata,
             }
         }
         Err(e) => BatchOutput {
@@ -164,6 +197,7 @@ fn convert_single(input: &BatchInput, output_format: ConversionFormat) -> BatchO
             output_name: String::new(),
             content: None,
             error: Some(e.to_string()),
+            metadata: None,
         },
     }
 }
@@ -349,4 +383,45 @@ TILT=NONE
         assert!(outputs[0].content.is_some());
         assert!(outputs[1].error.is_some());
     }
+
+    #[test]
+    fn test_convert_one_matches_batch() {
+        let input = BatchInput {
+            name: "test.LDT".to_string(),
+            content: TEST_LDT.to_string(),
+            format: None,
+        };
+
+        let single = convert_one(&input, ConversionFormat::Ies);
+        let batch = batch_convert(std::slice::from_ref(&input), ConversionFormat::Ies);
+        assert_eq!(single.output_name, "test.ies");
+        assert_eq!(single.content, batch[0].content);
+    }
+
+    #[test]
+    fn test_batch_convert_with_lookup() {
+        let mut table = crate::CatalogTable::new();
+        table.insert(
+            "PRODUCT",
+            CatalogEntry {
+                manufacturer: Some("Canonical Lighting".to_string()),
+                product_family: Some("Downlights".to_string()),
+                luminaire_name: None,
+            },
+        );
+        let inputs = vec![BatchInput {
+            name: "good.ldt".to_string(),
+            content: TEST_LDT.to_string(),
+            format: None,
+        }];
+
+        let outputs = batch_convert_with_lookup(&inputs, ConversionFormat::Ies, &table);
+        let metadata = outputs[0].metadata.as_ref().unwrap();
+        assert_eq!(metadata.product_family.as_deref(), Some("Downlights"));
+        assert!(outputs[0]
+            .content
+            .as_ref()
+            .unwrap()
+            .contains("[MANUFAC] Canonical Lighting"));
+    }
 }
@@ -15,7 +15,7 @@
 //! println!("BUG Rating: {}", rating);
 //! ```
 
-use crate::Eulumdat;
+use crate::{Eulumdat, Zone};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -50,106 +50,36 @@ pub struct ZoneLumens {
 
 impl ZoneLumens {
     /// Calculate zone lumens from Eulumdat intensity data
+    ///
+    /// The zones are integrated with the shared quadrature of
+    /// [`crate::integration`] and scaled by the lamp flux.
     pub fn from_eulumdat(ldt: &Eulumdat) -> Self {
-        let mut zones = ZoneLumens::default();
-
-        if ldt.intensities.is_empty() || ldt.g_angles.is_empty() || ldt.c_angles.is_empty() {
-            return zones;
-        }
-
         let total_flux: f64 = ldt
             .lamp_sets
             .iter()
             .map(|ls| ls.total_luminous_flux * ls.num_lamps.unsigned_abs() as f64)
             .sum();
-
         let scale = total_flux / 1000.0;
+        Self::from_zones(|zone| zone.integrate_eulumdat(ldt) * scale)
+    }
 
-        for (c_idx, c_angle) in ldt.c_angles.iter().enumerate() {
-            if c_idx >= ldt.intensities.len() {
-                continue;
-            }
-
-            let intensities = &ldt.intensities[c_idx];
-            let is_forward = *c_angle <= 180.0;
-
-            let dc = if ldt.c_angles.len() > 1 {
-                let dc_before = if c_idx > 0 {
-                    c_angle - ldt.c_angles[c_idx - 1]
-                } else {
-                    ldt.c_angles[1] - ldt.c_angles[0]
-                };
-                let dc_after = if c_idx < ldt.c_angles.len() - 1 {
-                    ldt.c_angles[c_idx + 1] - c_angle
-                } else {
-                    ldt.c_angles[ldt.c_angles.len() - 1] - ldt.c_angles[ldt.c_angles.len() - 2]
-                };
-                (dc_before + dc_after) / 2.0
-            } else {
-                360.0
-            };
-
-            for (g_idx, &g_angle) in ldt.g_angles.iter().enumerate() {
-                if g_idx >= intensities.len() {
-                    continue;
-                }
-
-                let intensity = intensities[g_idx] * scale;
-
-                let dg = if ldt.g_angles.len() > 1 {
-                    let dg_before = if g_idx > 0 {
-                        g_angle - ldt.g_angles[g_idx - 1]
-                    } else {
-                        ldt.g_angles[1] - ldt.g_angles[0]
-                    };
-                    let dg_after = if g_idx < ldt.g_angles.len() - 1 {
-                        ldt.g_angles[g_idx + 1] - g_angle
-                    } else {
-                        ldt.g_angles[ldt.g_angles.len() - 1] - ldt.g_angles[ldt.g_angles.len() - 2]
-                    };
-                    (dg_before + dg_after) / 2.0
-                } else {
-                    5.0
-                };
-
-                let g_rad = g_angle.to_radians();
-                let dg_rad = dg.to_radians();
-                let dc_rad = dc.to_radians();
-                let lumens = intensity * g_rad.sin() * dg_rad * dc_rad;
-
-                if g_angle < 30.0 {
-                    if is_forward {
-                        zones.fl += lumens;
-                    } else {
-                        zones.bl += lumens;
-                    }
-                } else if g_angle < 60.0 {
-                    if is_forward {
-                        zones.fm += lumens;
-                    } else {
-                        zones.bm += lumens;
-                    }
-                } else if g_angle < 80.0 {
-                    if is_forward {
-                        zones.fh += lumens;
-                    } else {
-                        zones.bh += lumens;
-                    }
-                } else if g_angle <= 90.0 {
-                    if is_forward {
-                        zones.fvh += lumens;
-                    } else {
-                        zones.bvh += lumens;
-                    }
-                } else if g_angle <= 100.0 {
-                    zones.ul += lumens;
-                } else {
-                    zones.uh += lumens;
-                }
-            }
+    /// Zone lumens from a function integrating the flux of a [`Zone`],
+    /// forward being C0-C180
+    pub fn from_zones(integrate: impl Fn(&Zone) -> f64) -> Self {
+        let forward = |g_start, g_end| integrate(&Zone::new(0.0, 180.0, g_start, g_end));
+        let back = |g_start, g_end| integrate(&Zone::new(180.0, 360.0, g_start, g_end));
+        Self {
+            bl: back(0.0, 30.0),
+            bm: back(30.0, 60.0),
+            bh: back(60.0, 80.0),
+            bvh: back(80.0, 90.0),
+            fl: forward(0.0, 30.0),
+            fm: forward(30.0, 60.0),
+            fh: forward(60.0, 80.0),
+            fvh: forward(80.0, 90.0),
+            ul: integrate(&Zone::gamma(90.0, 100.0)),
+            uh: integrate(&Zone::gamma(100.0, 180.0)),
         }
-
-        zones
     }
 
     /// Total lumens across all zones
@@ -182,6 +112,118 @@ impl ZoneLumens {
     }
 }
 
+/// TM-15-11 backlight maxima per rating B0–B4: (BH, BM, BL)
+const B_THRESHOLDS: [[f64; 3]; 5] = [
+    [110.0, 220.0, 110.0],
+    [500.0, 1000.0, 500.0],
+    [1000.0, 2500.0, 1000.0],
+    [2500.0, 5000.0, 2500.0],
+    [5000.0, 8500.0, 5000.0],
+];
+
+/// TM-15-11 uplight maxima per rating U0–U4: (UL, UH)
+const U_THRESHOLDS: [[f64; 2]; 5] = [
+    [0.0, 0.0],
+    [10.0, 10.0],
+    [50.0, 50.0],
+    [500.0, 500.0],
+    [1000.0, 1000.0],
+];
+
+/// TM-15-11 glare maxima per rating G0–G4: (FH/BH, FVH/BVH)
+const G_THRESHOLDS: [[f64; 2]; 5] = [
+    [10.0, 10.0],
+    [180.0, 10.0],
+    [600.0, 50.0],
+    [2000.0, 200.0],
+    [4000.0, 400.0],
+];
+
+/// Why a BUG component got its rating: the deciding zone and the
+/// thresholds around its lumens
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct BugRatingReason {
+    /// Rating component ('B', 'U' or 'G')
+    pub component: char,
+    /// Assigned rating (0-5)
+    pub rating: u8,
+    /// Deciding zone (e.g., "FH")
+    pub zone: &'static str,
+    /// Lumens in the deciding zone
+    pub lumens: f64,
+    /// Maximum of the next lower rating, which the zone exceeds (None for 0)
+    pub exceeded: Option<f64>,
+    /// Maximum of the assigned rating for the zone (None for 5)
+    pub limit: Option<f64>,
+}
+
+impl BugRatingReason {
+    /// Rate one component: the lowest row of `thresholds` that all zones stay
+    /// within, decided by the zone furthest over the row below it.
+    fn from_thresholds<const N: usize>(
+        component: char,
+        zones: [(&'static str, f64); N],
+        thresholds: &[[f64; N]; 5],
+    ) -> Self {
+        let rating = thresholds
+            .iter()
+            .position(|row| zones.iter().zip(row).all(|((_, lm), max)| lm <= max))
+            .unwrap_or(5);
+
+        // The row that had to be exceeded, or B0/U0/G0 for the closest zone
+        let row = &thresholds[rating.saturating_sub(1)];
+        let ratio = |lumens: f64, max: f64| {
+            if max > 0.0 {
+                lumens / max
+            } else if lumens > 0.0 {
+                f64::INFINITY
+            } else {
+                0.0
+            }
+        };
+        let deciding = (0..N)
+            .reduce(|best, i| {
+                if ratio(zones[i].1, row[i]) > ratio(zones[best].1, row[best]) {
+                    i
+                } else {
+                    best
+                }
+            })
+            .unwrap_or(0);
+
+        Self {
+            component,
+            rating: rating as u8,
+            zone: zones[deciding].0,
+            lumens: zones[deciding].1,
+            exceeded: (rating > 0).then(|| thresholds[rating - 1][deciding]),
+            limit: thresholds.get(rating).map(|row| row[deciding]),
+        }
+    }
+}
+
+impl std::fmt::Display for BugRatingReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}: {} {:.0} lm",
+            self.component, self.rating, self.zone, self.lumens
+        )?;
+        match (self.exceeded, self.limit) {
+            (Some(exceeded), _) => write!(
+                f,
+                " > {:.0} lm ({}{} max)",
+                exceeded,
+                self.component,
+                self.rating - 1
+            ),
+            (None, Some(limit)) => write!(f, " ≤ {limit:.0} lm"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
 /// BUG rating values (0-5 scale for each component)
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -206,54 +248,44 @@ impl BugRating {
 
     /// Calculate BUG rating from zone lumens
     pub fn from_zone_lumens(zones: &ZoneLumens) -> Self {
-        // Backlight thresholds: (BH max, BM max, BL max)
-        let b_thresholds = [
-            (110.0, 220.0, 110.0),
-            (500.0, 1000.0, 500.0),
-            (1000.0, 2500.0, 1000.0),
-            (2500.0, 5000.0, 2500.0),
-            (5000.0, 8500.0, 5000.0),
-        ];
-
-        let b = b_thresholds
-            .iter()
-            .position(|(bh_max, bm_max, bl_max)| {
-                zones.bh <= *bh_max && zones.bm <= *bm_max && zones.bl <= *bl_max
-            })
-            .unwrap_or(5) as u8;
-
-        // Uplight thresholds: (UL max, UH max)
-        let u_thresholds = [
-            (0.0, 0.0),
-            (10.0, 10.0),
-            (50.0, 50.0),
-            (500.0, 500.0),
-            (1000.0, 1000.0),
-        ];
-
-        let u = u_thresholds
-            .iter()
-            .position(|(ul_max, uh_max)| zones.ul <= *ul_max && zones.uh <= *uh_max)
-            .unwrap_or(5) as u8;
-
-        // Glare thresholds: (FH/BH max, FVH/BVH max)
-        let g_thresholds = [
-            (10.0, 10.0),
-            (180.0, 10.0),
-            (600.0, 50.0),
-            (2000.0, 200.0),
-            (4000.0, 400.0),
-        ];
-
-        let max_fh = zones.fh.max(zones.bh);
-        let max_fvh = zones.fvh.max(zones.bvh);
+        let [b, u, g] = Self::reasons(zones).map(|reason| reason.rating);
+        Self { b, u, g }
+    }
 
-        let g = g_thresholds
-            .iter()
-            .position(|(fh_max, fvh_max)| max_fh <= *fh_max && max_fvh <= *fvh_max)
-            .unwrap_or(5) as u8;
+    /// The zone that decided each of the B, U and G ratings, with the
+    /// TM-15-11 thresholds around its lumens
+    pub fn reasons(zones: &ZoneLumens) -> [BugRatingReason; 3] {
+        let (glare_high, glare_high_lumens) = if zones.bh > zones.fh {
+            ("BH", zones.bh)
+        } else {
+            ("FH", zones.fh)
+        };
+        let (glare_very_high, glare_very_high_lumens) = if zones.bvh > zones.fvh {
+            ("BVH", zones.bvh)
+        } else {
+            ("FVH", zones.fvh)
+        };
 
-        Self { b, u, g }
+        [
+            BugRatingReason::from_thresholds(
+                'B',
+                [("BH", zones.bh), ("BM", zones.bm), ("BL", zones.bl)],
+                &B_THRESHOLDS,
+            ),
+            BugRatingReason::from_thresholds(
+                'U',
+                [("UL", zones.ul), ("UH", zones.uh)],
+                &U_THRESHOLDS,
+            ),
+            BugRatingReason::from_thresholds(
+                'G',
+                [
+                    (glare_high, glare_high_lumens),
+                    (glare_very_high, glare_very_high_lumens),
+                ],
+                &G_THRESHOLDS,
+            ),
+        ]
     }
 
     /// Calculate from Eulumdat directly
@@ -447,6 +479,28 @@ impl LcsZonePercentages {
     }
 }
 
+/// What the zones of the LCS diagram show
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LcsView {
+    /// Wedges scaled by zone lumens, labelled with lumens and share
+    #[default]
+    ZoneLumens,
+    /// Zones at their true angles, scaled by lumens per steradian and
+    /// labelled with their solid angle (TM-15 secondary view)
+    SolidAngle,
+}
+
+/// An LCS zone: label, nadir angle range (degrees), lumens and side
+/// (+1 forward, -1 back, 0 uplight over the full azimuth)
+type LcsZone = (&'static str, f64, f64, f64, f64);
+
+/// Solid angle in steradians between two nadir angles over `azimuth`
+/// radians, Ω = φ (cos θ₁ − cos θ₂)
+fn zone_solid_angle(from: f64, to: f64, azimuth: f64) -> f64 {
+    azimuth * (from.to_radians().cos() - to.to_radians().cos())
+}
+
 /// BUG diagram with SVG rendering support
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -534,7 +588,9 @@ impl BugDiagram {
 
     /// Generate SVG with detailed zone lumens breakdown.
     ///
-    /// Includes a table showing exact lumen values for each BUG zone.
+    /// Draws the TM-15-11 zone boundaries over a polar grid, outlines the
+    /// zone that decided each of B, U and G, and lists the zone lumens and
+    /// the thresholds that zone falls between.
     pub fn to_svg_with_details(
         &self,
         width: f64,
@@ -561,9 +617,11 @@ impl BugDiagram {
             cx, cy, radius, theme.grid
         ));
 
-        // Zone markers and fills
-        svg.push_str(&self.render_zone_markers(cx, cy, radius, theme));
+        // Polar background with the TM-15-11 zone boundaries, zone fills and
+        // the zones that decided the rating
+        svg.push_str(&self.render_zone_boundaries(cx, cy, radius, theme));
         svg.push_str(&self.render_zone_fills(cx, cy, radius, theme));
+        svg.push_str(&self.render_deciding_zones(cx, cy, radius));
 
         // Luminaire symbol
         svg.push_str(&format!(
@@ -667,7 +725,7 @@ impl BugDiagram {
 
         // Uplight total
         y += row_h + 5.0;
-        let uplight = self.zones.fvh + self.zones.bvh;
+        let uplight = self.zones.uplight_total();
         let uplight_pct = if self.total_lumens > 0.0 {
             uplight / self.total_lumens * 100.0
         } else {
@@ -678,30 +736,57 @@ impl BugDiagram {
             col1, y, theme.text_secondary, uplight, uplight_pct
         ));
 
-        // B, U, G breakdown
-        y += row_h;
-        svg.push_str(&format!(
-            r#"<text x="{}" y="{}" font-size="9" fill="{}">B{} (back: {:.0}lm)</text>"#,
-            col1, y, theme.text_secondary, self.rating.b, back_total
-        ));
-        y += row_h - 4.0;
-        svg.push_str(&format!(
-            r#"<text x="{}" y="{}" font-size="9" fill="{}">U{} (up: {:.0}lm)</text>"#,
-            col1, y, theme.text_secondary, self.rating.u, uplight
-        ));
-        y += row_h - 4.0;
-        let glare = self.zones.fh + self.zones.fm + self.zones.bh + self.zones.bm;
+        // Deciding zone and thresholds for each of B, U, G
+        y += row_h + 4.0;
         svg.push_str(&format!(
-            r#"<text x="{}" y="{}" font-size="9" fill="{}">G{} (glare: {:.0}lm)</text>"#,
-            col1, y, theme.text_secondary, self.rating.g, glare
+            r#"<text x="{}" y="{}" font-size="11" font-weight="bold" fill="{}">Thresholds (TM-15-11)</text>"#,
+            col1, y, theme.text
         ));
+        for reason in BugRating::reasons(&self.zones) {
+            y += row_h;
+            svg.push_str(&format!(
+                r#"<rect x="{}" y="{}" width="8" height="8" fill="{}" rx="1"/>
+<text x="{}" y="{}" font-size="9" fill="{}">{}</text>"#,
+                col1 - 5.0,
+                y - 8.0,
+                Self::component_color(reason.component),
+                col1 + 8.0,
+                y,
+                theme.text,
+                reason
+            ));
+            if let (Some(_), Some(limit)) = (reason.exceeded, reason.limit) {
+                y += row_h - 6.0;
+                svg.push_str(&format!(
+                    r#"<text x="{}" y="{}" font-size="8" fill="{}">≤ {:.0} lm ({}{} max)</text>"#,
+                    col1 + 8.0,
+                    y,
+                    theme.text_secondary,
+                    limit,
+                    reason.component,
+                    reason.rating
+                ));
+            }
+        }
 
         svg.push_str("</svg>");
         svg
     }
 
-    /// Generate SVG for TM-15-07 LCS view
+    /// Generate SVG for TM-15-07 LCS view, every zone labelled with its
+    /// lumens and share of the total
     pub fn to_lcs_svg(&self, width: f64, height: f64, theme: &crate::diagram::SvgTheme) -> String {
+        self.to_lcs_svg_view(width, height, theme, LcsView::ZoneLumens)
+    }
+
+    /// Generate SVG for the LCS diagram in the given view
+    pub fn to_lcs_svg_view(
+        &self,
+        width: f64,
+        height: f64,
+        theme: &crate::diagram::SvgTheme,
+        view: LcsView,
+    ) -> String {
         let cx = width * 0.3;
         let cy = height * 0.55;
         let radius = (width.min(height) * 0.4).max(100.0);
@@ -714,7 +799,12 @@ impl BugDiagram {
         );
 
         // Zone wedges
-        svg.push_str(&self.render_lcs_wedges(cx, cy, radius, theme));
+        match view {
+            LcsView::ZoneLumens => svg.push_str(&self.render_lcs_wedges(cx, cy, radius, theme)),
+            LcsView::SolidAngle => {
+                svg.push_str(&self.render_lcs_solid_angles(cx, cy, radius, theme))
+            }
+        }
 
         // Concentric circles
         for ratio in [1.0, 0.75, 0.5, 0.25] {
@@ -730,6 +820,9 @@ impl BugDiagram {
         // Radial lines
         svg.push_str(&self.render_lcs_radial_lines(cx, cy, radius, theme));
 
+        // Zone annotations
+        svg.push_str(&self.render_lcs_zone_labels(cx, cy, radius, theme, view));
+
         // Labels
         svg.push_str(&format!(
             r#"<text x="{}" y="{}" text-anchor="middle" font-size="9" fill="{}">BACK</text>
@@ -894,6 +987,147 @@ impl BugDiagram {
         result
     }
 
+    /// Highlight color of a rating component, matching the table rows
+    fn component_color(component: char) -> &'static str {
+        match component {
+            'U' => "#ef4444",
+            'G' => "#f59e0b",
+            _ => "#3b82f6",
+        }
+    }
+
+    /// Gamma range of a BUG zone and the sides (+1 forward, -1 back) it covers
+    fn zone_span(zone: &str) -> (f64, f64, &'static [f64]) {
+        let sides: &'static [f64] = match zone.as_bytes().first() {
+            Some(b'F') => &[1.0],
+            Some(b'B') => &[-1.0],
+            _ => &[1.0, -1.0],
+        };
+        let (start, end) = match zone {
+            "FL" | "BL" => (0.0, 30.0),
+            "FM" | "BM" => (30.0, 60.0),
+            "FH" | "BH" => (60.0, 80.0),
+            "FVH" | "BVH" => (80.0, 90.0),
+            "UL" => (90.0, 100.0),
+            _ => (100.0, 180.0),
+        };
+        (start, end, sides)
+    }
+
+    /// Polar grid with the radial zone boundaries at 30°, 60°, 80°, 90° and
+    /// 100° from nadir on both sides
+    fn render_zone_boundaries(
+        &self,
+        cx: f64,
+        cy: f64,
+        radius: f64,
+        theme: &crate::diagram::SvgTheme,
+    ) -> String {
+        let mut result = String::new();
+
+        for ratio in [0.25, 0.5, 0.75] {
+            result.push_str(&format!(
+                r#"<circle cx="{}" cy="{}" r="{}" fill="none" stroke="{}" stroke-width=".5" stroke-dasharray="2,2"/>"#,
+                cx,
+                cy,
+                radius * ratio,
+                theme.grid
+            ));
+        }
+
+        // Nadir-zenith axis
+        result.push_str(&format!(
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="1"/>
+<text x="{}" y="{}" text-anchor="middle" font-size="8" fill="{}">0°</text>
+<text x="{}" y="{}" text-anchor="middle" font-size="8" fill="{}">180°</text>"#,
+            cx,
+            cy - radius,
+            cx,
+            cy + radius,
+            theme.axis,
+            cx,
+            cy + radius + 11.0,
+            theme.text_secondary,
+            cx,
+            cy - radius - 4.0,
+            theme.text_secondary
+        ));
+
+        for angle in [30.0_f64, 60.0, 80.0, 90.0, 100.0] {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            for side in [1.0, -1.0] {
+                result.push_str(&format!(
+                    r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="1"/>"#,
+                    cx,
+                    cy,
+                    cx + side * radius * sin,
+                    cy + radius * cos,
+                    theme.axis
+                ));
+            }
+            result.push_str(&format!(
+                r#"<text x="{}" y="{}" font-size="8" fill="{}">{}°</text>"#,
+                cx + (radius + 4.0) * sin,
+                cy + (radius + 4.0) * cos + 3.0,
+                theme.text_secondary,
+                angle
+            ));
+        }
+
+        result.push_str(&format!(
+            r#"<text x="{}" y="{}" text-anchor="middle" font-size="9" fill="{}">BACK</text>
+<text x="{}" y="{}" text-anchor="middle" font-size="9" fill="{}">FRONT</text>"#,
+            cx - radius * 0.6,
+            cy - radius * 0.6,
+            theme.text_secondary,
+            cx + radius * 0.6,
+            cy - radius * 0.6,
+            theme.text_secondary
+        ));
+
+        result
+    }
+
+    /// Outline the zone that decided each rating component in its color
+    fn render_deciding_zones(&self, cx: f64, cy: f64, radius: f64) -> String {
+        let inner_r = radius * 0.3;
+        let outer_r = radius * 0.95;
+        let mut result = String::new();
+
+        for reason in BugRating::reasons(&self.zones) {
+            let (start, end, sides) = Self::zone_span(reason.zone);
+            let (start_rad, end_rad) = (start.to_radians(), end.to_radians());
+            let color = Self::component_color(reason.component);
+
+            for &sx in sides {
+                let sweep_outer = if sx > 0.0 { 0 } else { 1 };
+                result.push_str(&format!(
+                    r#"<path d="M {} {} L {} {} A {} {} 0 0 {} {} {} L {} {} A {} {} 0 0 {} {} {} Z" fill="{}" fill-opacity="0.15" stroke="{}" stroke-width="2"/>"#,
+                    cx + sx * inner_r * start_rad.sin(),
+                    cy + inner_r * start_rad.cos(),
+                    cx + sx * outer_r * start_rad.sin(),
+                    cy + outer_r * start_rad.cos(),
+                    outer_r,
+                    outer_r,
+                    sweep_outer,
+                    cx + sx * outer_r * end_rad.sin(),
+                    cy + outer_r * end_rad.cos(),
+                    cx + sx * inner_r * end_rad.sin(),
+                    cy + inner_r * end_rad.cos(),
+                    inner_r,
+                    inner_r,
+                    1 - sweep_outer,
+                    cx + sx * inner_r * start_rad.sin(),
+                    cy + inner_r * start_rad.cos(),
+                    color,
+                    color
+                ));
+            }
+        }
+
+        result
+    }
+
     fn render_zone_fills(
         &self,
         cx: f64,
@@ -944,8 +1178,8 @@ impl BugDiagram {
             let label_x = cx + sx * mid_r * mid_angle.to_radians().sin();
             let label_y = cy + mid_r * mid_angle.to_radians().cos();
 
-            let sweep_outer = if *is_forward { 1 } else { 0 };
-            let sweep_inner = if *is_forward { 0 } else { 1 };
+            let sweep_outer = if *is_forward { 0 } else { 1 };
+            let sweep_inner = if *is_forward { 1 } else { 0 };
 
             result.push_str(&format!(
                 r#"<path d="M {} {} L {} {} A {} {} 0 0 {} {} {} L {} {} A {} {} 0 0 {} {} {} Z" fill="{}" stroke="{}" stroke-width="0.5" opacity="{}"/>
@@ -1052,6 +1286,118 @@ impl BugDiagram {
         result
     }
 
+    /// Zones of the LCS, lower zones by side and uplight over the full azimuth
+    fn lcs_zones(&self) -> [LcsZone; 10] {
+        [
+            ("FL", 0.0, 30.0, self.zones.fl, 1.0),
+            ("FM", 30.0, 60.0, self.zones.fm, 1.0),
+            ("FH", 60.0, 80.0, self.zones.fh, 1.0),
+            ("FVH", 80.0, 90.0, self.zones.fvh, 1.0),
+            ("BL", 0.0, 30.0, self.zones.bl, -1.0),
+            ("BM", 30.0, 60.0, self.zones.bm, -1.0),
+            ("BH", 60.0, 80.0, self.zones.bh, -1.0),
+            ("BVH", 80.0, 90.0, self.zones.bvh, -1.0),
+            ("UL", 90.0, 100.0, self.zones.ul, 0.0),
+            ("UH", 100.0, 180.0, self.zones.uh, 0.0),
+        ]
+    }
+
+    /// Sectors at the true zone angles with radius proportional to the
+    /// zone's lumens per steradian (its mean intensity)
+    fn render_lcs_solid_angles(
+        &self,
+        cx: f64,
+        cy: f64,
+        max_radius: f64,
+        theme: &crate::diagram::SvgTheme,
+    ) -> String {
+        let zones = self.lcs_zones();
+        let density = |(_, from, to, lumens, side): &LcsZone| {
+            let azimuth = if *side == 0.0 { 2.0 } else { 1.0 } * std::f64::consts::PI;
+            lumens / zone_solid_angle(*from, *to, azimuth)
+        };
+        let max_density = zones.iter().map(density).fold(0.0, f64::max);
+        if max_density <= 0.0 {
+            return String::new();
+        }
+
+        let mut result = String::new();
+        for zone in &zones {
+            let zone_radius = density(zone) / max_density * max_radius;
+            if zone_radius < 0.5 {
+                continue;
+            }
+            let (_, from, to, _, side) = *zone;
+            let (color, opacity) = match side {
+                s if s > 0.0 => (&theme.curve_c0_c180, 0.7),
+                s if s < 0.0 => (&theme.curve_c90_c270, 0.7),
+                _ => (&theme.text_secondary, 0.4),
+            };
+            // Uplight spans the whole azimuth, so it is drawn on both sides
+            let sides: &[f64] = if side == 0.0 { &[1.0, -1.0] } else { &[side] };
+            for &dir in sides {
+                let point = |angle: f64| {
+                    let (sin, cos) = angle.to_radians().sin_cos();
+                    (cx + dir * zone_radius * sin, cy + zone_radius * cos)
+                };
+                let ((x1, y1), (x2, y2)) = (point(from), point(to));
+                let sweep = if dir > 0.0 { 0 } else { 1 };
+                result.push_str(&format!(
+                    r#"<path d="M {cx} {cy} L {x1:.2} {y1:.2} A {zone_radius:.2} {zone_radius:.2} 0 0 {sweep} {x2:.2} {y2:.2} Z" fill="{color}" fill-opacity="{opacity}" stroke="{}" stroke-width=".5"/>"#,
+                    theme.axis
+                ));
+            }
+        }
+
+        result
+    }
+
+    /// Zone name with lumens and share, or with solid angle and lm/sr
+    fn render_lcs_zone_labels(
+        &self,
+        cx: f64,
+        cy: f64,
+        radius: f64,
+        theme: &crate::diagram::SvgTheme,
+        view: LcsView,
+    ) -> String {
+        let mut result = String::new();
+        for (label, from, to, lumens, side) in self.lcs_zones() {
+            // Lower zones on their bisector, uplight stacked above the center
+            let (x, y) = match label {
+                "UL" => (cx, cy - radius * 0.3),
+                "UH" => (cx, cy - radius * 0.62),
+                _ => {
+                    let (sin, cos) = ((from + to) / 2.0).to_radians().sin_cos();
+                    (cx + side * radius * 0.62 * sin, cy + radius * 0.62 * cos)
+                }
+            };
+            let (first, second) = match view {
+                LcsView::ZoneLumens => {
+                    let share = if self.total_lumens > 0.0 {
+                        lumens / self.total_lumens * 100.0
+                    } else {
+                        0.0
+                    };
+                    (format!("{label}: {lumens:.0} lm"), format!("{share:.1}%"))
+                }
+                LcsView::SolidAngle => {
+                    let azimuth = if side == 0.0 { 2.0 } else { 1.0 } * std::f64::consts::PI;
+                    let omega = zone_solid_angle(from, to, azimuth);
+                    (
+                        format!("{label}: {omega:.2} sr"),
+                        format!("{:.0} lm/sr", lumens / omega),
+                    )
+                }
+            };
+            result.push_str(&format!(
+                r#"<text x="{x:.1}" y="{y:.1}" text-anchor="middle" font-size="8" fill="{}" paint-order="stroke" stroke="{}" stroke-width="2">{first}<tspan x="{x:.1}" dy="9">{second}</tspan></text>"#,
+                theme.text, theme.background
+            ));
+        }
+        result
+    }
+
     fn render_lcs_radial_lines(
         &self,
         cx: f64,
@@ -1252,6 +1598,7 @@ impl BugDiagram {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::f64::consts::PI;
 
     #[test]
     fn test_bug_rating_display() {
@@ -1306,6 +1653,67 @@ mod tests {
         assert_eq!(rating.g, 0);
     }
 
+    #[test]
+    fn test_bug_rating_reasons() {
+        let zones = ZoneLumens {
+            fl: 800.0,
+            fm: 1500.0,
+            fh: 450.0,
+            fvh: 30.0,
+            bl: 300.0,
+            bm: 200.0,
+            bh: 120.0,
+            bvh: 5.0,
+            ul: 0.0,
+            uh: 0.0,
+        };
+        let rating = BugRating::from_zone_lumens(&zones);
+        assert_eq!(rating, BugRating::new(1, 0, 2));
+
+        let [b, u, g] = BugRating::reasons(&zones);
+        // BL 300 > 110 is further over B0 than BH 120 > 110
+        assert_eq!(b.zone, "BL");
+        assert_eq!((b.exceeded, b.limit), (Some(110.0), Some(500.0)));
+        assert_eq!(u.to_string(), "U0: UL 0 lm ≤ 0 lm");
+        // FVH 30 > 10 is 3× over G1, FH 450 > 180 only 2.5×
+        assert_eq!(g.zone, "FVH");
+        assert_eq!(g.to_string(), "G2: FVH 30 lm > 10 lm (G1 max)");
+
+        let bright = ZoneLumens {
+            bh: 6000.0,
+            ..zones
+        };
+        let [b, ..] = BugRating::reasons(&bright);
+        assert_eq!((b.rating, b.zone, b.limit), (5, "BH", None));
+    }
+
+    #[test]
+    fn test_bug_details_svg_thresholds() {
+        let zones = ZoneLumens {
+            fh: 450.0,
+            fvh: 5.0,
+            ul: 20.0,
+            uh: 5.0,
+            ..Default::default()
+        };
+        let diagram = BugDiagram {
+            zones,
+            rating: BugRating::from_zone_lumens(&zones),
+            total_lumens: zones.total(),
+        };
+        let svg = diagram.to_svg_with_details(800.0, 560.0, &crate::diagram::SvgTheme::light());
+        assert!(svg.contains("Thresholds (TM-15-11)"));
+        assert!(svg.contains("U2: UL 20 lm > 10 lm (U1 max)"));
+        assert!(svg.contains("≤ 600 lm (G2 max)"));
+        // UL spans both sides, so the uplight outline is drawn twice
+        assert_eq!(
+            svg.matches(r##"stroke="#ef4444" stroke-width="2""##)
+                .count(),
+            2
+        );
+        assert!(svg.contains("Uplight: 25 lm"));
+    }
+
     #[test]
     fn test_lighting_zone_compliance() {
         // B0 U0 G0 — compliant with all zones
@@ -1350,6 +1758,55 @@ mod tests {
         assert!(pct.fb_ratio > 4.0); // 810/175 ≈ 4.6
     }
 
+    #[test]
+    fn test_lcs_zone_labels() {
+        let diagram = BugDiagram {
+            zones: ZoneLumens {
+                fl: 500.0,
+                fm: 300.0,
+                bl: 200.0,
+                ..Default::default()
+            },
+            rating: BugRating::new(0, 0, 0),
+            total_lumens: 1000.0,
+        };
+        let theme = crate::diagram::SvgTheme::light();
+
+        let svg = diagram.to_lcs_svg(510.0, 315.0, &theme);
+        assert!(svg.contains("FL: 500 lm"));
+        assert!(svg.contains(">50.0%<"));
+        assert!(svg.contains("BL: 200 lm"));
+        assert!(svg.contains("UH: 0 lm"));
+    }
+
+    #[test]
+    fn test_lcs_solid_angle_view() {
+        // FL spans π (1 - cos 30°) = 0.42 sr, UH 2π (1 + cos 100°) = 5.19 sr
+        assert!((zone_solid_angle(0.0, 30.0, PI) - 0.4209).abs() < 1e-4);
+        assert!((zone_solid_angle(100.0, 180.0, 2.0 * PI) - 5.1921).abs() < 1e-4);
+
+        let diagram = BugDiagram {
+            zones: ZoneLumens {
+                fl: 421.0,
+                uh: 100.0,
+                ..Default::default()
+            },
+            rating: BugRating::new(0, 1, 0),
+            total_lumens: 521.0,
+        };
+        let svg = diagram.to_lcs_svg_view(
+            510.0,
+            315.0,
+            &crate::diagram::SvgTheme::light(),
+            LcsView::SolidAngle,
+        );
+        assert!(svg.contains("FL: 0.42 sr"));
+        assert!(svg.contains(">1000 lm/sr<"));
+        assert!(svg.contains("UH: 5.19 sr"));
+        // FL sector plus UH drawn on both sides
+        assert_eq!(svg.matches("fill-opacity").count(), 3);
+    }
+
     #[test]
     fn test_lighting_zone_descriptions() {
         assert_eq!(LightingZone::LZ0.to_string(), "LZ0");
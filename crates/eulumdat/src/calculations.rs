@@ -5,10 +5,17 @@
 //! - Total luminous output
 //! - Utilization factors (direct ratios)
 
-use crate::eulumdat::{Eulumdat, Symmetry};
+use crate::eulumdat::Eulumdat;
 use crate::type_b_conversion::TypeBConversion;
 use std::f64::consts::PI;
 
+pub mod integration;
+pub mod leni;
+pub mod luminance;
+pub mod statistics;
+
+use integration::Zone;
+
 /// Photometric calculations on Eulumdat data.
 pub struct PhotometricCalculations;
 
@@ -25,157 +32,23 @@ impl PhotometricCalculations {
     /// # Returns
     /// The downward flux fraction as a percentage (0-100).
     pub fn downward_flux(ldt: &Eulumdat, arc: f64) -> f64 {
-        let total_output = Self::total_output(ldt);
-        if total_output <= 0.0 {
-            return 0.0;
-        }
-
-        let downward = match ldt.symmetry {
-            Symmetry::None => Self::downward_no_symmetry(ldt, arc),
-            Symmetry::VerticalAxis => Self::downward_for_plane(ldt, 0, arc),
-            Symmetry::PlaneC0C180 => Self::downward_c0_c180(ldt, arc),
-            Symmetry::PlaneC90C270 => Self::downward_c90_c270(ldt, arc),
-            Symmetry::BothPlanes => Self::downward_both_planes(ldt, arc),
-        };
-
-        100.0 * downward / total_output
-    }
-
-    /// Calculate downward flux for no symmetry case.
-    fn downward_no_symmetry(ldt: &Eulumdat, arc: f64) -> f64 {
-        let mc = ldt.actual_c_planes();
-        if mc == 0 || ldt.c_angles.is_empty() {
-            return 0.0;
-        }
-
-        let mut sum = 0.0;
-
-        for i in 1..mc {
-            let delta_c = ldt.c_angles[i] - ldt.c_angles[i - 1];
-            sum += delta_c * Self::downward_for_plane(ldt, i - 1, arc);
-        }
-
-        // Handle wrap-around from last plane to first
-        if mc > 1 {
-            let delta_c = 360.0 - ldt.c_angles[mc - 1];
-            sum += delta_c * Self::downward_for_plane(ldt, mc - 1, arc);
-        }
-
-        sum / 360.0
-    }
-
-    /// Calculate downward flux for C0-C180 symmetry.
-    fn downward_c0_c180(ldt: &Eulumdat, arc: f64) -> f64 {
-        let mc = ldt.actual_c_planes();
-        if mc == 0 || ldt.c_angles.is_empty() {
-            return 0.0;
-        }
-
-        let mut sum = 0.0;
-
-        for i in 1..mc {
-            let delta_c = ldt.c_angles[i] - ldt.c_angles[i - 1];
-            sum += 2.0 * delta_c * Self::downward_for_plane(ldt, i - 1, arc);
-        }
-
-        // Handle to 180°
-        if mc > 0 {
-            let delta_c = 180.0 - ldt.c_angles[mc - 1];
-            sum += 2.0 * delta_c * Self::downward_for_plane(ldt, mc - 1, arc);
-        }
-
-        sum / 360.0
-    }
-
-    /// Calculate downward flux for C90-C270 symmetry.
-    fn downward_c90_c270(ldt: &Eulumdat, arc: f64) -> f64 {
-        // Similar to C0-C180 but shifted
-        Self::downward_c0_c180(ldt, arc)
+        Self::flux_fraction(ldt, &Zone::gamma(0.0, arc), Self::total_output(ldt))
     }
 
-    /// Calculate downward flux for both planes symmetry.
-    fn downward_both_planes(ldt: &Eulumdat, arc: f64) -> f64 {
-        let mc = ldt.actual_c_planes();
-        if mc == 0 || ldt.c_angles.is_empty() {
-            return 0.0;
-        }
-
-        let mut sum = 0.0;
-
-        for i in 1..mc {
-            let delta_c = ldt.c_angles[i] - ldt.c_angles[i - 1];
-            sum += 4.0 * delta_c * Self::downward_for_plane(ldt, i - 1, arc);
-        }
-
-        // Handle to 90°
-        if mc > 0 {
-            let delta_c = 90.0 - ldt.c_angles[mc - 1];
-            sum += 4.0 * delta_c * Self::downward_for_plane(ldt, mc - 1, arc);
-        }
-
-        sum / 360.0
-    }
-
-    /// Calculate downward flux for a single C-plane up to arc angle.
-    fn downward_for_plane(ldt: &Eulumdat, c_index: usize, arc: f64) -> f64 {
-        if c_index >= ldt.intensities.len() || ldt.g_angles.is_empty() {
+    /// Percentage of `total` emitted into `zone`
+    fn flux_fraction(ldt: &Eulumdat, zone: &Zone, total: f64) -> f64 {
+        if total <= 0.0 {
             return 0.0;
         }
-
-        let intensities = &ldt.intensities[c_index];
-        let mut sum = 0.0;
-
-        for j in 1..ldt.g_angles.len() {
-            let g_prev = ldt.g_angles[j - 1];
-            let g_curr = ldt.g_angles[j];
-
-            // Only integrate up to arc angle
-            if g_prev >= arc {
-                break;
-            }
-
-            let g_end = g_curr.min(arc);
-            let delta_g = g_end - g_prev;
-
-            if delta_g <= 0.0 {
-                continue;
-            }
-
-            // Average intensity in this segment
-            let i_prev = intensities.get(j - 1).copied().unwrap_or(0.0);
-            let i_curr = intensities.get(j).copied().unwrap_or(0.0);
-            let avg_intensity = (i_prev + i_curr) / 2.0;
-
-            // Convert to radians for solid angle calculation
-            let g_prev_rad = g_prev * PI / 180.0;
-            let g_end_rad = g_end * PI / 180.0;
-
-            // Solid angle element: sin(g) * dg
-            let solid_angle = (g_prev_rad.cos() - g_end_rad.cos()).abs();
-
-            sum += avg_intensity * solid_angle;
-        }
-
-        sum * 2.0 * PI
+        100.0 * zone.integrate_eulumdat(ldt) / total
     }
 
     /// Calculate total luminous output.
     ///
-    /// Integrates the luminous intensity over the entire sphere.
+    /// Integrates the luminous intensity over the entire sphere, see
+    /// [`integration`].
     pub fn total_output(ldt: &Eulumdat) -> f64 {
-        // Use downward_flux with 180° to get full sphere
-        let mc = ldt.actual_c_planes();
-        if mc == 0 {
-            return 0.0;
-        }
-
-        match ldt.symmetry {
-            Symmetry::None => Self::downward_no_symmetry(ldt, 180.0),
-            Symmetry::VerticalAxis => Self::downward_for_plane(ldt, 0, 180.0),
-            Symmetry::PlaneC0C180 => Self::downward_c0_c180(ldt, 180.0),
-            Symmetry::PlaneC90C270 => Self::downward_c90_c270(ldt, 180.0),
-            Symmetry::BothPlanes => Self::downward_both_planes(ldt, 180.0),
-        }
+        Zone::SPHERE.integrate_eulumdat(ldt)
     }
 
     /// Calculate the luminous flux from the stored intensity distribution.
@@ -185,6 +58,38 @@ impl PhotometricCalculations {
         Self::total_output(ldt) * ldt.conversion_factor
     }
 
+    /// Integrate the intensity distribution into luminaire flux, DLOR and ULOR.
+    ///
+    /// Intensities are integrated over the full sphere, with the stored
+    /// C-planes weighted according to the symmetry. The result can be
+    /// compared with the declared LOR and DFF from the header via
+    /// [`FluxIntegration::lor_deviation`] and [`FluxIntegration::dff_deviation`].
+    pub fn integrate_flux(ldt: &Eulumdat) -> FluxIntegration {
+        let total = Self::total_output(ldt);
+        let downward = Zone::DOWNWARD.integrate_eulumdat(ldt);
+        let upward = (total - downward).max(0.0);
+
+        FluxIntegration {
+            luminaire_flux: total * ldt.total_luminous_flux() / 1000.0,
+            lor: total / 10.0,
+            dlor: downward / 10.0,
+            ulor: upward / 10.0,
+            dff: if total > 0.0 {
+                100.0 * downward / total
+            } else {
+                0.0
+            },
+        }
+    }
+
+    /// Mean/median intensity, per-hemisphere percentiles, peak direction and
+    /// average luminous exitance.
+    ///
+    /// See [`statistics`] for how the web is weighted.
+    pub fn intensity_statistics(ldt: &Eulumdat) -> statistics::IntensityStatistics {
+        statistics::IntensityStatistics::from_eulumdat(ldt)
+    }
+
     /// Calculate direct ratios (utilization factors) for standard room indices.
     ///
     /// Room indices k: 0.60, 0.80, 1.00, 1.25, 1.50, 2.00, 2.50, 3.00, 4.00, 5.00
@@ -387,6 +292,48 @@ impl PhotometricCalculations {
         }
     }
 
+    /// Peaks, valley and asymmetry of a batwing distribution.
+    ///
+    /// Looks at both halves of the C0-C180 plane. Returns `None` unless
+    /// [`beam_field_analysis`](Self::beam_field_analysis) flags the
+    /// distribution as batwing.
+    pub fn batwing_analysis(ldt: &Eulumdat) -> Option<BatwingAnalysis> {
+        if !Self::beam_field_analysis(ldt).is_batwing {
+            return None;
+        }
+
+        let peak = |c_angle: f64| {
+            ldt.g_angles
+                .iter()
+                .map(|&gamma| BatwingPeak {
+                    c_angle,
+                    gamma,
+                    intensity: ldt.sample(c_angle, gamma),
+                })
+                .fold(None, |best: Option<BatwingPeak>, p| match best {
+                    Some(b) if b.intensity >= p.intensity => Some(b),
+                    _ => Some(p),
+                })
+        };
+        let peak_c0 = peak(0.0)?;
+        let peak_c180 = peak(180.0)?;
+
+        let center_intensity = ldt.sample(0.0, 0.0);
+        let higher = peak_c0.intensity.max(peak_c180.intensity);
+        let lower = peak_c0.intensity.min(peak_c180.intensity);
+        if higher <= 0.0 {
+            return None;
+        }
+
+        Some(BatwingAnalysis {
+            peak_c0,
+            peak_c180,
+            center_intensity,
+            valley_depth: (1.0 - center_intensity / lower).clamp(0.0, 1.0),
+            asymmetry_ratio: lower / higher,
+        })
+    }
+
     /// Find the angle at which intensity drops to a given percentage of maximum.
     fn angle_at_percentage(ldt: &Eulumdat, percentage: f64) -> f64 {
         if ldt.intensities.is_empty() || ldt.g_angles.is_empty() {
@@ -411,7 +358,7 @@ impl PhotometricCalculations {
                 let prev_angle = ldt.g_angles[i - 1];
                 let curr_angle = ldt.g_angles[i];
 
-                if prev_intensity > threshold {
+                if prev_intensity >= threshold {
                     let ratio = (prev_intensity - threshold) / (prev_intensity - intensity);
                     return prev_angle + ratio * (curr_angle - prev_angle);
                 }
@@ -448,7 +395,7 @@ impl PhotometricCalculations {
                 let prev_angle = ldt.g_angles[i - 1];
                 let curr_angle = ldt.g_angles[i];
 
-                if prev_intensity > threshold {
+                if prev_intensity >= threshold {
                     let ratio = (prev_intensity - threshold) / (prev_intensity - intensity);
                     return prev_angle + ratio * (curr_angle - prev_angle);
                 }
@@ -727,16 +674,23 @@ impl PhotometricCalculations {
 
     /// Calculate CIE Flux Codes.
     ///
-    /// Returns a tuple of 5 values (N1, N2, N3, N4, N5) representing the
-    /// percentage of lamp flux in different angular zones:
+    /// The zonal values N1-N5 give the percentage of luminaire flux in
+    /// different angular zones:
     /// - N1: % in lower hemisphere (0-90°)
     /// - N2: % in 0-60° zone
     /// - N3: % in 0-40° zone
     /// - N4: % in upper hemisphere (90-180°)
     /// - N5: % in 90-120° zone (near-horizontal uplight)
     ///
-    /// The flux code is typically written as: N1 N2 N3 N4 N5
-    /// Example: "92 68 42 8 3" means 92% downward, 68% within 60°, etc.
+    /// The normative five-number code of CIE 52 (FC1-FC5) splits the lower
+    /// hemisphere into four cones of equal solid angle (π/2 sr each, ending
+    /// at 41.4°, 60°, 75.5° and 90°):
+    /// - FC1-FC3: % of the downward flux within 41.4°, 60° and 75.5°
+    /// - FC4: % of the luminaire flux in the lower hemisphere
+    /// - FC5: flux fraction of the luminaire, i.e. the light output ratio in %
+    ///
+    /// `Display` writes the normative code, e.g. "44 75 94 100 80" for a
+    /// cosine downlight with LOR 80%.
     pub fn cie_flux_codes(ldt: &Eulumdat) -> CieFluxCodes {
         let total = Self::total_output(ldt);
         if total <= 0.0 {
@@ -750,12 +704,26 @@ impl PhotometricCalculations {
         let flux_120 = Self::downward_flux(ldt, 120.0);
         let flux_180 = Self::downward_flux(ldt, 180.0);
 
+        // Normative code: cones of π/2 sr, relative to the downward flux
+        let of_downward = |arc: f64| {
+            if flux_90 > 0.0 {
+                100.0 * Self::downward_flux(ldt, arc) / flux_90
+            } else {
+                0.0
+            }
+        };
+
         CieFluxCodes {
             n1: flux_90,            // 0-90° (DLOR)
             n2: flux_60,            // 0-60°
             n3: flux_40,            // 0-40°
             n4: flux_180 - flux_90, // 90-180° (ULOR)
             n5: flux_120 - flux_90, // 90-120° (near-horizontal uplight)
+            fc1: of_downward(CIE_FLUX_CODE_CONES[0]),
+            fc2: of_downward(CIE_FLUX_CODE_CONES[1]),
+            fc3: of_downward(CIE_FLUX_CODE_CONES[2]),
+            fc4: flux_90,
+            fc5: ldt.light_output_ratio,
         }
     }
 
@@ -857,6 +825,8 @@ impl PhotometricCalculations {
 
     /// Calculate spacing criteria for both principal planes.
     ///
+    /// See [`Self::spacing_criteria_extended`] for the diagonal and maximum SHR.
+    ///
     /// # Returns
     /// (S/H parallel, S/H perpendicular) - spacing ratios for C0 and C90 planes
     pub fn spacing_criteria(ldt: &Eulumdat) -> (f64, f64) {
@@ -865,6 +835,99 @@ impl PhotometricCalculations {
         (s_h_parallel, s_h_perpendicular)
     }
 
+    /// Calculate spacing criteria for the principal planes, the diagonal and
+    /// the maximum SHR of a square layout.
+    ///
+    /// The diagonal S/H uses the C45 plane. The maximum SHR follows the IES
+    /// method: the largest spacing of a square array of luminaires for which
+    /// the work plane uniformity (Emin/Emax) inside one grid cell stays at or
+    /// above 0.87.
+    pub fn spacing_criteria_extended(ldt: &Eulumdat) -> SpacingCriteria {
+        let (c0, c90) = Self::spacing_criteria(ldt);
+        SpacingCriteria {
+            c0,
+            c90,
+            diagonal: Self::spacing_criterion(ldt, 45.0),
+            max_shr: Self::max_spacing_ratio_ies(ldt, 0.87),
+        }
+    }
+
+    /// Largest S/H of a square luminaire array that keeps the cell uniformity
+    /// at or above `uniformity_threshold`, or `None` if no S/H between 0.5
+    /// and 3.0 does.
+    ///
+    /// Uniformity does not fall steadily with spacing for batwing optics, so
+    /// the range is scanned in coarse steps first and only the interval after
+    /// the last passing step is refined by bisection.
+    fn max_spacing_ratio_ies(ldt: &Eulumdat, uniformity_threshold: f64) -> Option<f64> {
+        const MIN_S_H: f64 = 0.5;
+        const MAX_S_H: f64 = 3.0;
+        const SCAN_STEPS: usize = 50;
+
+        if ldt.intensities.is_empty() || ldt.g_angles.is_empty() {
+            return Some(1.0);
+        }
+
+        let passes = |s_h: f64| Self::square_array_uniformity(ldt, s_h) >= uniformity_threshold;
+        let step = (MAX_S_H - MIN_S_H) / SCAN_STEPS as f64;
+        let last_pass = (0..=SCAN_STEPS)
+            .rev()
+            .map(|i| MIN_S_H + i as f64 * step)
+            .find(|&s_h| passes(s_h))?;
+        if last_pass >= MAX_S_H {
+            return Some(MAX_S_H);
+        }
+
+        let mut low = last_pass;
+        let mut high = last_pass + step;
+        for _ in 0..12 {
+            let mid = (low + high) / 2.0;
+            if passes(mid) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Some(low)
+    }
+
+    /// Illuminance uniformity inside one cell of a square array (H = 1).
+    ///
+    /// The cell is lit by the luminaires on its four corners and the ring of
+    /// twelve around them, all oriented with C0 along +x.
+    fn square_array_uniformity(ldt: &Eulumdat, s_h: f64) -> f64 {
+        const NUM_POINTS: usize = 11;
+        let luminaires: Vec<(f64, f64)> = (-1..=2)
+            .flat_map(|i| (-1..=2).map(move |j| (i as f64 * s_h, j as f64 * s_h)))
+            .collect();
+
+        let mut e_max: f64 = 0.0;
+        let mut e_min = f64::MAX;
+        for i in 0..NUM_POINTS {
+            for j in 0..NUM_POINTS {
+                let x = i as f64 / (NUM_POINTS - 1) as f64 * s_h;
+                let y = j as f64 / (NUM_POINTS - 1) as f64 * s_h;
+                let e: f64 = luminaires
+                    .iter()
+                    .map(|&(lx, ly)| {
+                        let (dx, dy) = (x - lx, y - ly);
+                        let c = dy.atan2(dx).to_degrees().rem_euclid(360.0);
+                        let theta = dx.hypot(dy).atan();
+                        ldt.sample(c, theta.to_degrees()) * theta.cos().powi(3)
+                    })
+                    .sum();
+                e_max = e_max.max(e);
+                e_min = e_min.min(e);
+            }
+        }
+
+        if e_max > 0.0 {
+            e_min / e_max
+        } else {
+            0.0
+        }
+    }
+
     /// IES-style spacing criterion based on work plane illuminance uniformity.
     ///
     /// This method finds the maximum S/H ratio where illuminance uniformity
@@ -988,22 +1051,11 @@ impl PhotometricCalculations {
     /// # Returns
     /// Array of 18 values representing % flux in each 10° zone
     pub fn zonal_lumens_10deg(ldt: &Eulumdat) -> [f64; 18] {
-        let mut zones = [0.0; 18];
         let total = Self::total_output(ldt);
-
-        if total <= 0.0 {
-            return zones;
-        }
-
-        let mut prev_flux = 0.0;
-        for (i, zone) in zones.iter_mut().enumerate() {
-            let angle = ((i + 1) * 10) as f64;
-            let cumulative = Self::downward_flux(ldt, angle);
-            *zone = cumulative - prev_flux;
-            prev_flux = cumulative;
-        }
-
-        zones
+        std::array::from_fn(|i| {
+            let g_start = i as f64 * 10.0;
+            Self::flux_fraction(ldt, &Zone::gamma(g_start, g_start + 10.0), total)
+        })
     }
 
     /// Calculate luminous flux in standard 30° zones.
@@ -1012,25 +1064,16 @@ impl PhotometricCalculations {
     /// ZonalLumens30 struct with flux in each 30° zone
     pub fn zonal_lumens_30deg(ldt: &Eulumdat) -> ZonalLumens30 {
         let total = Self::total_output(ldt);
-
-        if total <= 0.0 {
-            return ZonalLumens30::default();
-        }
-
-        let f30 = Self::downward_flux(ldt, 30.0);
-        let f60 = Self::downward_flux(ldt, 60.0);
-        let f90 = Self::downward_flux(ldt, 90.0);
-        let f120 = Self::downward_flux(ldt, 120.0);
-        let f150 = Self::downward_flux(ldt, 150.0);
-        let f180 = Self::downward_flux(ldt, 180.0);
+        let zone =
+            |g_start: f64| Self::flux_fraction(ldt, &Zone::gamma(g_start, g_start + 30.0), total);
 
         ZonalLumens30 {
-            zone_0_30: f30,
-            zone_30_60: f60 - f30,
-            zone_60_90: f90 - f60,
-            zone_90_120: f120 - f90,
-            zone_120_150: f150 - f120,
-            zone_150_180: f180 - f150,
+            zone_0_30: zone(0.0),
+            zone_30_60: zone(30.0),
+            zone_60_90: zone(60.0),
+            zone_90_120: zone(90.0),
+            zone_120_150: zone(120.0),
+            zone_150_180: zone(150.0),
         }
     }
 
@@ -1185,6 +1228,25 @@ impl PhotometricCalculations {
         CuTable::calculate(ldt)
     }
 
+    /// Calculate the classic IES Coefficient of Utilization table (RCR method).
+    ///
+    /// Same layout as [`Self::cu_table`] (RCR 0-10, standard reflectances,
+    /// effective floor cavity reflectance 20%), but computed the way North
+    /// American layout tools do: zonal multipliers for the direct ratio and
+    /// inter-reflections between floor, ceiling and wall cavities. CU is
+    /// relative to lamp lumens, so the light output ratio is included.
+    pub fn cu_table_rcr(ldt: &Eulumdat) -> CuTable {
+        CuTable::calculate_rcr(ldt)
+    }
+
+    /// Calculate the utilization factor table by room index.
+    ///
+    /// UF values (0-1) for the standard room indices k = 0.6-5 and
+    /// reflectance combinations, see [`UfTable`].
+    pub fn uf_table(ldt: &Eulumdat) -> UfTable {
+        UfTable::calculate(ldt)
+    }
+
     // ========================================================================
     // Unified Glare Rating (UGR) Table - CIE 117:1995
     // ========================================================================
@@ -1258,6 +1320,9 @@ pub struct PhotometricSummary {
     pub field_angle_cie: f64,
     /// True if distribution is batwing (center < max, IES ≠ CIE)
     pub is_batwing: bool,
+    /// NEMA beam type, for floodlight distributions only
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub nema: Option<NemaClassification>,
 
     // Upward beam characteristics (for uplights and direct-indirect luminaires)
     /// Upward beam angle - 50% of upward peak (degrees)
@@ -1282,6 +1347,11 @@ pub struct PhotometricSummary {
     pub spacing_c0: f64,
     /// S/H ratio for C90 plane
     pub spacing_c90: f64,
+    /// S/H ratio along the diagonal (C45 plane)
+    pub spacing_diagonal: f64,
+    /// Maximum SHR of a square layout (IES uniformity method); `None` if not
+    /// achievable
+    pub spacing_max: Option<f64>,
 
     // Zonal lumens
     /// Zonal lumens in 30° zones
@@ -1292,7 +1362,7 @@ impl PhotometricSummary {
     /// Calculate complete photometric summary from Eulumdat data.
     pub fn from_eulumdat(ldt: &Eulumdat) -> Self {
         let cie_codes = PhotometricCalculations::cie_flux_codes(ldt);
-        let (s_c0, s_c90) = PhotometricCalculations::spacing_criteria(ldt);
+        let spacing = PhotometricCalculations::spacing_criteria_extended(ldt);
 
         Self {
             // Flux
@@ -1322,6 +1392,10 @@ impl PhotometricSummary {
                 analysis.is_batwing
             },
 
+            // NEMA beam type (floodlights)
+            nema: Some(PhotometricCalculations::nema_classification(ldt))
+                .filter(NemaClassification::is_floodlight),
+
             // Upward beam characteristics
             upward_beam_angle: PhotometricCalculations::upward_beam_angle(ldt),
             upward_field_angle: PhotometricCalculations::upward_field_angle(ldt),
@@ -1340,8 +1414,10 @@ impl PhotometricSummary {
             avg_intensity: ldt.avg_intensity(),
 
             // Spacing
-            spacing_c0: s_c0,
-            spacing_c90: s_c90,
+            spacing_c0: spacing.c0,
+            spacing_c90: spacing.c90,
+            spacing_diagonal: spacing.diagonal,
+            spacing_max: spacing.max_shr,
 
             // Zonal
             zonal_lumens: PhotometricCalculations::zonal_lumens_30deg(ldt),
@@ -1369,7 +1445,7 @@ CIE Flux Code:         {}
 
 Beam Characteristics
   Beam Angle (50%):    {:.1}°
-  Field Angle (10%):   {:.1}°
+  Field Angle (10%):   {:.1}°{}
 
 Intensity (cd/klm)
   Maximum:             {:.1}
@@ -1379,6 +1455,8 @@ Intensity (cd/klm)
 Spacing Criterion (S/H)
   C0 Plane:            {:.2}
   C90 Plane:           {:.2}
+  Diagonal:            {:.2}
+  Maximum SHR:         {}
 
 Zonal Lumens (%)
   0-30°:               {:.1}%
@@ -1399,11 +1477,22 @@ Zonal Lumens (%)
             self.cie_flux_codes,
             self.beam_angle,
             self.field_angle,
+            self.nema
+                .as_ref()
+                .map(|n| format!(
+                    "\n  NEMA Beam Type:      {} ({:.0}° × {:.0}°)",
+                    n.designation, n.horizontal_spread, n.vertical_spread
+                ))
+                .unwrap_or_default(),
             self.max_intensity,
             self.min_intensity,
             self.avg_intensity,
             self.spacing_c0,
             self.spacing_c90,
+            self.spacing_diagonal,
+            self.spacing_max
+                .map(|s| format!("{s:.2}"))
+                .unwrap_or_else(|| "not achievable".to_string()),
             self.zonal_lumens.zone_0_30,
             self.zonal_lumens.zone_30_60,
             self.zonal_lumens.zone_60_90,
@@ -1415,7 +1504,7 @@ Zonal Lumens (%)
 
     /// Format as single-line compact summary.
     pub fn to_compact(&self) -> String {
-        format!(
+        let mut s = format!(
             "CIE:{} Beam:{:.0}° Field:{:.0}° Eff:{:.0}lm/W S/H:{:.1}×{:.1}",
             self.cie_flux_codes,
             self.beam_angle,
@@ -1423,12 +1512,19 @@ Zonal Lumens (%)
             self.luminaire_efficacy,
             self.spacing_c0,
             self.spacing_c90,
-        )
+        );
+        if let Some(nema) = &self.nema {
+            s.push_str(&format!(
+                " NEMA:{}Hx{}V",
+                nema.horizontal_type, nema.vertical_type
+            ));
+        }
+        s
     }
 
     /// Format as key-value pairs for machine parsing.
     pub fn to_key_value(&self) -> Vec<(&'static str, String)> {
-        vec![
+        let mut kv = vec![
             ("total_lamp_flux_lm", format!("{:.1}", self.total_lamp_flux)),
             ("calculated_flux_lm", format!("{:.1}", self.calculated_flux)),
             ("lor_percent", format!("{:.1}", self.lor)),
@@ -1453,6 +1549,13 @@ Zonal Lumens (%)
             ("avg_intensity_cd_klm", format!("{:.1}", self.avg_intensity)),
             ("spacing_c0", format!("{:.2}", self.spacing_c0)),
             ("spacing_c90", format!("{:.2}", self.spacing_c90)),
+            ("spacing_diagonal", format!("{:.2}", self.spacing_diagonal)),
+            (
+                "spacing_max",
+                self.spacing_max
+                    .map(|s| format!("{s:.2}"))
+                    .unwrap_or_default(),
+            ),
             (
                 "zonal_0_30_percent",
                 format!("{:.1}", self.zonal_lumens.zone_0_30),
@@ -1477,10 +1580,159 @@ Zonal Lumens (%)
                 "zonal_150_180_percent",
                 format!("{:.1}", self.zonal_lumens.zone_150_180),
             ),
-        ]
+        ];
+        if let Some(nema) = &self.nema {
+            kv.push(("nema_class", nema.designation.clone()));
+        }
+        kv
+    }
+}
+
+impl PhotometricSummary {
+    /// Typed record for machine-readable output (JSON, dashboards).
+    ///
+    /// Units are part of the field names, as in [`Self::to_key_value`], but
+    /// values keep full precision.
+    pub fn to_record(&self) -> SummaryRecord {
+        SummaryRecord {
+            schema_version: SummaryRecord::SCHEMA_VERSION,
+            total_lamp_flux_lm: self.total_lamp_flux,
+            calculated_flux_lm: self.calculated_flux,
+            lor_percent: self.lor,
+            dlor_percent: self.dlor,
+            ulor_percent: self.ulor,
+            lamp_efficacy_lm_w: self.lamp_efficacy,
+            luminaire_efficacy_lm_w: self.luminaire_efficacy,
+            total_wattage_w: self.total_wattage,
+            cie_flux_code: self.cie_flux_codes.to_string(),
+            cie_n1: self.cie_flux_codes.n1,
+            cie_n2: self.cie_flux_codes.n2,
+            cie_n3: self.cie_flux_codes.n3,
+            cie_n4: self.cie_flux_codes.n4,
+            cie_n5: self.cie_flux_codes.n5,
+            beam_angle_deg: self.beam_angle,
+            field_angle_deg: self.field_angle,
+            beam_angle_cie_deg: self.beam_angle_cie,
+            field_angle_cie_deg: self.field_angle_cie,
+            is_batwing: self.is_batwing,
+            nema_class: self.nema.as_ref().map(|n| n.designation.clone()),
+            upward_beam_angle_deg: self.upward_beam_angle,
+            upward_field_angle_deg: self.upward_field_angle,
+            primary_direction: self.primary_direction,
+            distribution_type: self.distribution_type,
+            max_intensity_cd_klm: self.max_intensity,
+            min_intensity_cd_klm: self.min_intensity,
+            avg_intensity_cd_klm: self.avg_intensity,
+            spacing_c0: self.spacing_c0,
+            spacing_c90: self.spacing_c90,
+            spacing_diagonal: self.spacing_diagonal,
+            spacing_max: self.spacing_max,
+            zonal_0_30_percent: self.zonal_lumens.zone_0_30,
+            zonal_30_60_percent: self.zonal_lumens.zone_30_60,
+            zonal_60_90_percent: self.zonal_lumens.zone_60_90,
+            zonal_90_120_percent: self.zonal_lumens.zone_90_120,
+            zonal_120_150_percent: self.zonal_lumens.zone_120_150,
+            zonal_150_180_percent: self.zonal_lumens.zone_150_180,
+        }
     }
 }
 
+/// Machine-readable photometric summary with units in the field names.
+///
+/// Serializes (with the `serde` feature) to the flat JSON object described
+/// by [`SummaryRecord::JSON_SCHEMA`], which is what `eulumdat summary
+/// --format json` prints. Percentages are 0-100, angles in degrees,
+/// intensities in cd/klm.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SummaryRecord {
+    /// Version of the record layout
+    pub schema_version: u32,
+    /// Total lamp flux (lm)
+    pub total_lamp_flux_lm: f64,
+    /// Flux from intensity integration (lm)
+    pub calculated_flux_lm: f64,
+    /// Light Output Ratio (%)
+    pub lor_percent: f64,
+    /// Downward Light Output Ratio (%)
+    pub dlor_percent: f64,
+    /// Upward Light Output Ratio (%)
+    pub ulor_percent: f64,
+    /// Lamp efficacy (lm/W)
+    pub lamp_efficacy_lm_w: f64,
+    /// Luminaire efficacy (lm/W)
+    pub luminaire_efficacy_lm_w: f64,
+    /// Total system wattage (W)
+    pub total_wattage_w: f64,
+    /// Normative CIE flux code "FC1 FC2 FC3 FC4 FC5"
+    pub cie_flux_code: String,
+    /// Flux in 0-90° (% of luminaire flux)
+    pub cie_n1: f64,
+    /// Flux in 0-60° (% of luminaire flux)
+    pub cie_n2: f64,
+    /// Flux in 0-40° (% of luminaire flux)
+    pub cie_n3: f64,
+    /// Flux in 90-180° (% of luminaire flux)
+    pub cie_n4: f64,
+    /// Flux in 90-120° (% of luminaire flux)
+    pub cie_n5: f64,
+    /// Beam angle, IES definition (degrees)
+    pub beam_angle_deg: f64,
+    /// Field angle, IES definition (degrees)
+    pub field_angle_deg: f64,
+    /// Beam angle, CIE definition (degrees)
+    pub beam_angle_cie_deg: f64,
+    /// Field angle, CIE definition (degrees)
+    pub field_angle_cie_deg: f64,
+    /// Center intensity below the maximum
+    pub is_batwing: bool,
+    /// NEMA designation such as "NEMA 3H x 5V" (floodlights only)
+    pub nema_class: Option<String>,
+    /// Upward beam angle (degrees)
+    pub upward_beam_angle_deg: f64,
+    /// Upward field angle (degrees)
+    pub upward_field_angle_deg: f64,
+    /// Primary light direction
+    pub primary_direction: LightDirection,
+    /// Distribution type
+    pub distribution_type: DistributionType,
+    /// Maximum intensity (cd/klm)
+    pub max_intensity_cd_klm: f64,
+    /// Minimum intensity (cd/klm)
+    pub min_intensity_cd_klm: f64,
+    /// Average intensity (cd/klm)
+    pub avg_intensity_cd_klm: f64,
+    /// S/H ratio for C0 plane
+    pub spacing_c0: f64,
+    /// S/H ratio for C90 plane
+    pub spacing_c90: f64,
+    /// S/H ratio along the diagonal (C45 plane)
+    pub spacing_diagonal: f64,
+    /// Maximum SHR of a square layout (IES uniformity method); `None` if not
+    /// achievable
+    pub spacing_max: Option<f64>,
+    /// Flux in 0-30° (%)
+    pub zonal_0_30_percent: f64,
+    /// Flux in 30-60° (%)
+    pub zonal_30_60_percent: f64,
+    /// Flux in 60-90° (%)
+    pub zonal_60_90_percent: f64,
+    /// Flux in 90-120° (%)
+    pub zonal_90_120_percent: f64,
+    /// Flux in 120-150° (%)
+    pub zonal_120_150_percent: f64,
+    /// Flux in 150-180° (%)
+    pub zonal_150_180_percent: f64,
+}
+
+impl SummaryRecord {
+    /// Current value of `schema_version`.
+    pub const SCHEMA_VERSION: u32 = 1;
+
+    /// JSON Schema (draft 2020-12) of the serialized record.
+    pub const JSON_SCHEMA: &'static str = include_str!("../schema/photometric-summary.schema.json");
+}
+
 impl std::fmt::Display for PhotometricSummary {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.to_text())
@@ -1527,6 +1779,7 @@ pub struct GldfPhotometricData {
 
 /// UGR table values for GLDF export
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UgrTableValues {
     /// UGR crosswise (C90) looking direction
     pub crosswise: f64,
@@ -1935,18 +2188,89 @@ impl std::fmt::Display for GldfPhotometricData {
 impl PhotometricCalculations {
     /// Calculate beam angle (50% intensity) for a specific C-plane.
     ///
-    /// Returns the **full angle** per CIE S 017:2020 definition.
+    /// Returns the **full angle** per CIE S 017:2020 definition, measured
+    /// across the whole plane: the half angle in `c_plane` plus the half
+    /// angle in the opposite half-plane (`c_plane + 180°`). Any C angle is
+    /// accepted; intensities between stored planes are interpolated.
     pub fn beam_angle_for_plane(ldt: &Eulumdat, c_plane: f64) -> f64 {
-        // Return full angle (2× half angle) per CIE definition
-        Self::angle_at_percentage_for_plane(ldt, c_plane, 0.5) * 2.0
+        Self::angle_at_percentage_for_plane(ldt, c_plane, 0.5)
+            + Self::angle_at_percentage_for_plane(ldt, c_plane + 180.0, 0.5)
     }
 
     /// Calculate field angle (10% intensity) for a specific C-plane.
     ///
-    /// Returns the **full angle** per CIE S 017:2020 definition.
+    /// Returns the **full angle** per CIE S 017:2020 definition, measured
+    /// across the whole plane like [`Self::beam_angle_for_plane`].
     pub fn field_angle_for_plane(ldt: &Eulumdat, c_plane: f64) -> f64 {
-        // Return full angle (2× half angle) per CIE definition
-        Self::angle_at_percentage_for_plane(ldt, c_plane, 0.1) * 2.0
+        Self::angle_at_percentage_for_plane(ldt, c_plane, 0.1)
+            + Self::angle_at_percentage_for_plane(ldt, c_plane + 180.0, 0.1)
+    }
+
+    /// Describe the beam in a C-plane without assuming it is centered on nadir.
+    ///
+    /// Wall-washers and road optics peak off-axis, so half angles measured
+    /// from nadir are misleading. This finds the peak across the whole plane
+    /// (`c_plane` and `c_plane + 180°`) and measures the 50% and 10% points on
+    /// either side of it, relative to the peak intensity.
+    pub fn asymmetric_beam(ldt: &Eulumdat, c_plane: f64) -> AsymmetricBeam {
+        const STEP: f64 = 0.5;
+
+        // Signed gamma: positive towards `c_plane`, negative towards the opposite side
+        let profile = |s: f64| {
+            if s >= 0.0 {
+                ldt.sample(c_plane, s)
+            } else {
+                ldt.sample(c_plane + 180.0, -s)
+            }
+        };
+
+        let steps = (90.0 / STEP) as i32;
+        let (peak_gamma, peak_intensity) = (-steps..=steps)
+            .map(|i| {
+                let s = i as f64 * STEP;
+                (s, profile(s))
+            })
+            .fold((0.0f64, 0.0f64), |best, (s, v)| {
+                // Prefer the sample closest to nadir on ties
+                if v > best.1 + 1e-9 || ((v - best.1).abs() <= 1e-9 && s.abs() < best.0.abs()) {
+                    (s, v)
+                } else {
+                    best
+                }
+            });
+
+        let mut beam = AsymmetricBeam {
+            c_plane: c_plane.rem_euclid(360.0),
+            peak_gamma,
+            peak_intensity,
+            ..Default::default()
+        };
+        if peak_intensity <= 0.0 {
+            return beam;
+        }
+
+        // Walk from the peak until the profile drops below the threshold
+        let extent = |direction: f64, percentage: f64| {
+            let threshold = peak_intensity * percentage;
+            let mut prev = (peak_gamma, peak_intensity);
+            let mut s = peak_gamma;
+            while (s + direction * STEP).abs() <= 90.0 + 1e-9 {
+                s += direction * STEP;
+                let v = profile(s);
+                if v < threshold {
+                    let ratio = (prev.1 - threshold) / (prev.1 - v);
+                    return (prev.0 + direction * ratio * STEP - peak_gamma).abs();
+                }
+                prev = (s, v);
+            }
+            (s - peak_gamma).abs()
+        };
+
+        beam.beam_right = extent(1.0, 0.5);
+        beam.beam_left = extent(-1.0, 0.5);
+        beam.field_right = extent(1.0, 0.1);
+        beam.field_left = extent(-1.0, 0.1);
+        beam
     }
 
     /// Calculate half beam angle for a specific C-plane.
@@ -2281,6 +2605,127 @@ impl std::fmt::Display for BeamFieldAnalysis {
     }
 }
 
+/// Peak of one wing of a batwing distribution
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatwingPeak {
+    /// C-plane of the wing in degrees
+    pub c_angle: f64,
+    /// Gamma angle of the peak in degrees
+    pub gamma: f64,
+    /// Peak intensity (cd/klm)
+    pub intensity: f64,
+}
+
+/// Peaks and valley of a batwing distribution in the C0-C180 plane.
+///
+/// See [`PhotometricCalculations::batwing_analysis`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatwingAnalysis {
+    /// Peak of the C0 wing
+    pub peak_c0: BatwingPeak,
+    /// Peak of the C180 wing
+    pub peak_c180: BatwingPeak,
+    /// Intensity at nadir (cd/klm)
+    pub center_intensity: f64,
+    /// Dip at nadir below the lower peak (0 = none, 1 = dark center)
+    pub valley_depth: f64,
+    /// Lower peak over higher peak (1 = symmetric wings)
+    pub asymmetry_ratio: f64,
+}
+
+/// Luminaire flux obtained by integrating the intensity distribution.
+///
+/// Percentages are relative to the rated lamp flux (LOR, DLOR, ULOR) or to
+/// the luminaire flux (DFF), as in the EULUMDAT header.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FluxIntegration {
+    /// Luminaire flux in lumens
+    pub luminaire_flux: f64,
+    /// Light output ratio in %
+    pub lor: f64,
+    /// Downward light output ratio in %
+    pub dlor: f64,
+    /// Upward light output ratio in %
+    pub ulor: f64,
+    /// Downward flux fraction in %
+    pub dff: f64,
+}
+
+impl FluxIntegration {
+    /// Difference between the integrated and declared LOR in percentage points.
+    pub fn lor_deviation(&self, ldt: &Eulumdat) -> f64 {
+        self.lor - ldt.light_output_ratio
+    }
+
+    /// Difference between the integrated and declared DFF in percentage points.
+    pub fn dff_deviation(&self, ldt: &Eulumdat) -> f64 {
+        self.dff - ldt.downward_flux_fraction
+    }
+
+    /// Whether the declared LOR and DFF are within `tolerance` percentage points.
+    pub fn is_consistent_with(&self, ldt: &Eulumdat, tolerance: f64) -> bool {
+        self.lor_deviation(ldt).abs() <= tolerance && self.dff_deviation(ldt).abs() <= tolerance
+    }
+}
+
+/// Spacing-to-mounting-height ratios of a luminaire.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpacingCriteria {
+    /// S/H ratio for the C0 plane
+    pub c0: f64,
+    /// S/H ratio for the C90 plane
+    pub c90: f64,
+    /// S/H ratio along the diagonal (C45 plane)
+    pub diagonal: f64,
+    /// Maximum SHR of a square layout (IES uniformity method); `None` if the
+    /// uniformity target is not met at any S/H from 0.5 to 3.0
+    pub max_shr: Option<f64>,
+}
+
+/// Beam in one C-plane, measured around its peak rather than around nadir.
+///
+/// "Right" is towards `c_plane`, "left" towards `c_plane + 180°`, as in the
+/// polar diagram for C0-C180.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AsymmetricBeam {
+    /// C-plane the beam was measured in (degrees)
+    pub c_plane: f64,
+    /// Gamma of the peak, positive towards `c_plane` (degrees)
+    pub peak_gamma: f64,
+    /// Peak intensity (cd/klm)
+    pub peak_intensity: f64,
+    /// Angle from the peak to 50% of peak towards `c_plane + 180°` (degrees)
+    pub beam_left: f64,
+    /// Angle from the peak to 50% of peak towards `c_plane` (degrees)
+    pub beam_right: f64,
+    /// Angle from the peak to 10% of peak towards `c_plane + 180°` (degrees)
+    pub field_left: f64,
+    /// Angle from the peak to 10% of peak towards `c_plane` (degrees)
+    pub field_right: f64,
+}
+
+impl AsymmetricBeam {
+    /// Full beam angle (50% of peak) in degrees.
+    pub fn beam_angle(&self) -> f64 {
+        self.beam_left + self.beam_right
+    }
+
+    /// Full field angle (10% of peak) in degrees.
+    pub fn field_angle(&self) -> f64 {
+        self.field_left + self.field_right
+    }
+
+    /// Whether the beam is centered on nadir with equal halves, within `tolerance` degrees.
+    pub fn is_symmetric(&self, tolerance: f64) -> bool {
+        self.peak_gamma.abs() <= tolerance && (self.beam_left - self.beam_right).abs() <= tolerance
+    }
+}
+
 /// Primary direction of light output
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -2432,19 +2877,37 @@ pub struct CieFluxCodes {
     pub n4: f64,
     /// N5: % flux in 90-120° zone (near-horizontal uplight)
     pub n5: f64,
+    /// FC1: % of downward flux within 41.4° (CIE 52)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fc1: f64,
+    /// FC2: % of downward flux within 60° (CIE 52)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fc2: f64,
+    /// FC3: % of downward flux within 75.5° (CIE 52)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fc3: f64,
+    /// FC4: % of luminaire flux in the lower hemisphere (CIE 52)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fc4: f64,
+    /// FC5: flux fraction, luminaire flux as % of lamp flux (CIE 52)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fc5: f64,
+}
+
+/// Upper γ bounds of the CIE 52 flux code cones FC1-FC3 (π/2 sr each).
+const CIE_FLUX_CODE_CONES: [f64; 3] = [41.41, 60.0, 75.52];
+
+impl CieFluxCodes {
+    /// Normative five-number flux code "FC1 FC2 FC3 FC4 FC5" as integers.
+    pub fn code(&self) -> [u32; 5] {
+        [self.fc1, self.fc2, self.fc3, self.fc4, self.fc5].map(|v| v.round().max(0.0) as u32)
+    }
 }
 
 impl std::fmt::Display for CieFluxCodes {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{:.0} {:.0} {:.0} {:.0} {:.0}",
-            self.n1.round(),
-            self.n2.round(),
-            self.n3.round(),
-            self.n4.round(),
-            self.n5.round()
-        )
+        let [fc1, fc2, fc3, fc4, fc5] = self.code();
+        write!(f, "{fc1} {fc2} {fc3} {fc4} {fc5}")
     }
 }
 
@@ -2585,11 +3048,28 @@ pub const CU_REFLECTANCES: [(u8, u8, u8); 18] = [
 /// Standard Room Cavity Ratios for CU tables.
 pub const CU_RCR_VALUES: [u8; 11] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
 
+/// IES zonal multiplier constants `(A, B)` for the 10° zones from 0° to 90°.
+///
+/// The share of a zone's flux reaching the work plane directly is
+/// `K = exp(-A · RCR^B)`.
+const CU_ZONAL_MULTIPLIERS: [(f64, f64); 9] = [
+    (0.0, 0.0),
+    (0.041, 0.98),
+    (0.070, 1.05),
+    (0.100, 1.12),
+    (0.136, 1.16),
+    (0.190, 1.25),
+    (0.315, 1.25),
+    (0.640, 1.25),
+    (2.100, 0.80),
+];
+
 /// Coefficient of Utilization table.
 ///
 /// Contains CU values (as percentages) for standard room cavity ratios
 /// and reflectance combinations, following IES Zonal Cavity Method.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CuTable {
     /// Effective floor cavity reflectance used
     pub floor_reflectance: f64,
@@ -2641,6 +3121,100 @@ impl CuTable {
         table
     }
 
+    /// Calculate the CU table with the classic IES RCR method.
+    ///
+    /// The downward flux is split by the zonal multipliers into a direct
+    /// part on the work plane and a part on the walls; the upward flux goes
+    /// to the ceiling cavity. Inter-reflections between work plane, ceiling
+    /// and walls are then solved with the form factors of a square room of
+    /// the given RCR. CU values are percentages of lamp lumens.
+    pub fn calculate_rcr(ldt: &Eulumdat) -> Self {
+        let mut table = Self::default();
+        let zones = PhotometricCalculations::zonal_lumens_10deg(ldt);
+        let lor = ldt.light_output_ratio / 100.0;
+        let fractions = zones.map(|z| z / 100.0 * lor);
+
+        for &rcr in &CU_RCR_VALUES {
+            let row = CU_REFLECTANCES
+                .iter()
+                .map(|&(rc, rw, rf)| {
+                    Self::calculate_cu_rcr(
+                        &fractions,
+                        rcr as f64,
+                        rc as f64 / 100.0,
+                        rw as f64 / 100.0,
+                        rf as f64 / 100.0,
+                    )
+                })
+                .collect();
+            table.values.push(row);
+        }
+
+        table
+    }
+
+    /// CU in percent from zonal flux fractions of lamp lumens (10° zones).
+    fn calculate_cu_rcr(zones: &[f64; 18], rcr: f64, rho_c: f64, rho_w: f64, rho_f: f64) -> f64 {
+        let downward: f64 = zones[..9].iter().sum();
+        let upward: f64 = zones[9..].iter().sum();
+        let direct: f64 = zones[..9]
+            .iter()
+            .zip(CU_ZONAL_MULTIPLIERS)
+            .map(|(flux, (a, b))| flux * (-a * rcr.powf(b)).exp())
+            .sum();
+
+        // Initial flux on work plane, ceiling cavity and walls
+        let (floor_0, ceiling_0, wall_0) = (direct, upward, downward - direct);
+
+        // Form factors, floor and ceiling areas normalized to 1
+        let f = Self::floor_ceiling_form_factor(rcr);
+        let wall_area = 0.4 * rcr;
+        let (f_wf, f_ww) = if wall_area > 0.0 {
+            let f_wf = (1.0 - f) / wall_area;
+            (f_wf, 1.0 - 2.0 * f_wf)
+        } else {
+            (0.0, 0.0)
+        };
+
+        // Incident flux: Φ_i = Φ_i0 + Σ_j ρ_j Φ_j F_ji
+        let m = [
+            [1.0, -rho_c * f, -rho_w * f_wf],
+            [-rho_f * f, 1.0, -rho_w * f_wf],
+            [-rho_f * (1.0 - f), -rho_c * (1.0 - f), 1.0 - rho_w * f_ww],
+        ];
+        let det = |m: &[[f64; 3]; 3]| {
+            m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+                - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+                + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+        };
+        let d = det(&m);
+        if d.abs() < 1e-12 {
+            return 0.0;
+        }
+        let mut m_floor = m;
+        for (row, b) in m_floor.iter_mut().zip([floor_0, ceiling_0, wall_0]) {
+            row[0] = b;
+        }
+
+        (det(&m_floor) / d * 100.0).max(0.0)
+    }
+
+    /// Form factor between work plane and ceiling of a square room.
+    ///
+    /// For a square room RCR = 10·h/L; directly opposed parallel squares
+    /// of side L at distance h.
+    fn floor_ceiling_form_factor(rcr: f64) -> f64 {
+        if rcr <= 0.0 {
+            return 1.0;
+        }
+        let x = 10.0 / rcr;
+        let q = (1.0 + x * x).sqrt();
+        2.0 / (std::f64::consts::PI * x * x)
+            * (((1.0 + x * x) * (1.0 + x * x) / (1.0 + 2.0 * x * x)).ln() / 2.0
+                + 2.0 * x * q * (x / q).atan()
+                - 2.0 * x * x.atan())
+    }
+
     /// Calculate CU for specific conditions using zonal cavity method.
     ///
     /// This is the simple/fast version. Use `calculate_cu_ies` for IES-accurate values.
@@ -2946,6 +3520,136 @@ impl CuTable {
 
         s
     }
+
+    /// Format as CSV with one line per room cavity ratio and reflectance
+    /// combination (CU in percent).
+    pub fn to_csv(&self) -> String {
+        let mut s = String::from("rcr,ceiling,walls,floor,cu\n");
+        for (row, &rcr) in self.values.iter().zip(&self.rcr_values) {
+            for (cu, &(rc, rw, rf)) in row.iter().zip(&self.reflectances) {
+                s.push_str(&format!("{rcr},{rc},{rw},{rf},{cu:.1}\n"));
+            }
+        }
+        s
+    }
+}
+
+// ============================================================================
+// Utilization Factor (UF) Table - Room Index Method
+// ============================================================================
+
+/// Standard room indices k = L·W / (h·(L+W)) for UF tables.
+pub const UF_ROOM_INDICES: [f64; 10] = [0.6, 0.8, 1.0, 1.25, 1.5, 2.0, 2.5, 3.0, 4.0, 5.0];
+
+/// Standard reflectance combinations for UF tables.
+/// Format: (Ceiling%, Wall%, Floor%)
+pub const UF_REFLECTANCES: [(u8, u8, u8); 7] = [
+    (80, 50, 30),
+    (80, 50, 10),
+    (70, 50, 20),
+    (50, 50, 10),
+    (50, 30, 10),
+    (30, 30, 10),
+    (0, 0, 0),
+];
+
+/// Utilization factor table.
+///
+/// The European counterpart of [`CuTable`]: utilization factors (share of
+/// lamp lumens reaching the work plane, 0-1) by room index instead of room
+/// cavity ratio. Uses the inter-reflection model of [`CuTable::calculate_rcr`]
+/// with RCR = 5 / k.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UfTable {
+    /// Room indices k (rows)
+    pub room_indices: Vec<f64>,
+    /// Reflectance combinations (ceiling%, wall%, floor%) (columns)
+    pub reflectances: Vec<(u8, u8, u8)>,
+    /// Utilization factors indexed as \[room_index\]\[reflectance\]
+    pub values: Vec<Vec<f64>>,
+}
+
+impl UfTable {
+    /// Calculate the UF table for the standard room indices and reflectances.
+    pub fn calculate(ldt: &Eulumdat) -> Self {
+        Self::calculate_for(ldt, &UF_ROOM_INDICES, &UF_REFLECTANCES)
+    }
+
+    /// Calculate the UF table for the given room indices and reflectances.
+    ///
+    /// Non-positive room indices are skipped.
+    pub fn calculate_for(
+        ldt: &Eulumdat,
+        room_indices: &[f64],
+        reflectances: &[(u8, u8, u8)],
+    ) -> Self {
+        let zones = PhotometricCalculations::zonal_lumens_10deg(ldt);
+        let lor = ldt.light_output_ratio / 100.0;
+        let fractions = zones.map(|z| z / 100.0 * lor);
+
+        let room_indices: Vec<f64> = room_indices.iter().copied().filter(|&k| k > 0.0).collect();
+        let values = room_indices
+            .iter()
+            .map(|&k| {
+                reflectances
+                    .iter()
+                    .map(|&(rc, rw, rf)| {
+                        CuTable::calculate_cu_rcr(
+                            &fractions,
+                            5.0 / k,
+                            rc as f64 / 100.0,
+                            rw as f64 / 100.0,
+                            rf as f64 / 100.0,
+                        ) / 100.0
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            room_indices,
+            reflectances: reflectances.to_vec(),
+            values,
+        }
+    }
+
+    /// Format as text table, one column per reflectance combination.
+    pub fn to_text(&self) -> String {
+        let mut s = String::from("UTILIZATION FACTORS - ROOM INDEX METHOD\n\n");
+        let header = |label: &str, pick: fn(&(u8, u8, u8)) -> u8| {
+            let mut line = format!("{label:<8}");
+            for r in &self.reflectances {
+                line.push_str(&format!("{:>6}", pick(r)));
+            }
+            line.push('\n');
+            line
+        };
+        s.push_str(&header("Ceiling", |r| r.0));
+        s.push_str(&header("Walls", |r| r.1));
+        s.push_str(&header("Floor", |r| r.2));
+        s.push_str("k\n");
+
+        for (row, k) in self.values.iter().zip(&self.room_indices) {
+            s.push_str(&format!("{k:<8.2}"));
+            for uf in row {
+                s.push_str(&format!("{uf:>6.2}"));
+            }
+            s.push('\n');
+        }
+        s
+    }
+
+    /// Format as CSV with one line per room index and reflectance combination.
+    pub fn to_csv(&self) -> String {
+        let mut s = String::from("room_index,ceiling,walls,floor,uf\n");
+        for (row, k) in self.values.iter().zip(&self.room_indices) {
+            for (uf, &(rc, rw, rf)) in row.iter().zip(&self.reflectances) {
+                s.push_str(&format!("{k},{rc},{rw},{rf},{uf:.3}\n"));
+            }
+        }
+        s
+    }
 }
 
 // ============================================================================
@@ -2990,6 +3694,7 @@ pub const UGR_REFLECTANCES: [(u8, u8, u8); 5] = [
 /// Contains UGR values for standard room dimensions and reflectance combinations,
 /// following CIE 117:1995 tabular method.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UgrTable {
     /// UGR values for crosswise (C90) viewing - indexed as \[room_size\]\[reflectance\]
     pub crosswise: Vec<Vec<f64>>,
@@ -3016,6 +3721,34 @@ impl Default for UgrTable {
 }
 
 impl UgrTable {
+    /// Room size (X, Y in units of H) quoted as the single UGR value in GLDF
+    pub const STANDARD_ROOM: (f64, f64) = (4.0, 8.0);
+    /// Reflectances (ceiling%, wall%, floor%) of the quoted UGR value
+    pub const STANDARD_REFLECTANCES: (u8, u8, u8) = (70, 50, 20);
+
+    /// Crosswise and endwise UGR of the 4H×8H room at 70/50/20, if the
+    /// table contains it.
+    pub fn standard_room(&self) -> Option<UgrTableValues> {
+        let (i, j) = self.standard_room_index()?;
+        Some(UgrTableValues {
+            crosswise: *self.crosswise.get(i)?.get(j)?,
+            endwise: *self.endwise.get(i)?.get(j)?,
+        })
+    }
+
+    /// Row and column of the 4H×8H, 70/50/20 entry
+    fn standard_room_index(&self) -> Option<(usize, usize)> {
+        let i = self
+            .room_sizes
+            .iter()
+            .position(|&size| size == Self::STANDARD_ROOM)?;
+        let j = self
+            .reflectances
+            .iter()
+            .position(|&r| r == Self::STANDARD_REFLECTANCES)?;
+        Some((i, j))
+    }
+
     /// Calculate UGR table from Eulumdat data.
     pub fn calculate(ldt: &Eulumdat) -> Self {
         let mut table = Self::default();
@@ -3338,10 +4071,36 @@ impl UgrTable {
             for j in 0..5 {
                 s.push_str(&format!("{:5.1}", self.endwise[i][j]));
             }
+            if self.standard_room_index().map(|(row, _)| row) == Some(i) {
+                s.push_str("  *");
+            }
             s.push('\n');
         }
 
         s.push_str(&format!("\nMaximum UGR = {:.1}\n", self.max_ugr));
+        if let Some(standard) = self.standard_room() {
+            s.push_str(&format!(
+                "* 4H×8H, 70/50/20 (GLDF): C {:.1} / E {:.1}\n",
+                standard.crosswise, standard.endwise
+            ));
+        }
+        s
+    }
+
+    /// Format as CSV with one line per room size and reflectance combination.
+    pub fn to_csv(&self) -> String {
+        let mut s = String::from("x_h,y_h,ceiling,walls,floor,crosswise,endwise\n");
+        for (i, &(x, y)) in self.room_sizes.iter().enumerate() {
+            for (j, &(rc, rw, rf)) in self.reflectances.iter().enumerate() {
+                let (Some(cross), Some(end)) = (
+                    self.crosswise.get(i).and_then(|row| row.get(j)),
+                    self.endwise.get(i).and_then(|row| row.get(j)),
+                ) else {
+                    continue;
+                };
+                s.push_str(&format!("{x},{y},{rc},{rw},{rf},{cross:.1},{end:.1}\n"));
+            }
+        }
         s
     }
 }
@@ -3352,6 +4111,7 @@ impl UgrTable {
 
 /// Single entry in candela tabulation.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CandelaEntry {
     /// C-plane angle (degrees)
     pub c_plane: f64,
@@ -3366,6 +4126,7 @@ pub struct CandelaEntry {
 /// Contains absolute candela values at each measurement angle,
 /// formatted similar to Photometric Toolbox output.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CandelaTabulation {
     /// All candela entries
     pub entries: Vec<CandelaEntry>,
@@ -3515,6 +4276,17 @@ pub struct NemaClassification {
     pub designation: String,
 }
 
+impl NemaClassification {
+    /// Whether the distribution is a floodlight beam in the NEMA sense.
+    ///
+    /// NEMA types describe directed beams; when either spread reaches
+    /// type 7 (130° and more) the luminaire is a general-purpose wide
+    /// distribution and the classification carries no information.
+    pub fn is_floodlight(&self) -> bool {
+        self.i_max > 0.0 && self.horizontal_type < 7 && self.vertical_type < 7
+    }
+}
+
 impl std::fmt::Display for NemaClassification {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.designation)
@@ -3636,7 +4408,7 @@ impl PhotometricCalculations {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::eulumdat::LampSet;
+    use crate::eulumdat::{LampSet, Symmetry};
 
     fn create_test_ldt() -> Eulumdat {
         let mut ldt = Eulumdat::new();
@@ -3666,6 +4438,38 @@ mod tests {
         assert!(output > 0.0, "Total output should be positive");
     }
 
+    #[test]
+    fn test_integrate_flux_uniform_sphere() {
+        let mut ldt = create_test_ldt();
+        ldt.g_angles = (0..=36).map(|i| i as f64 * 5.0).collect();
+        ldt.num_g_planes = ldt.g_angles.len();
+        // 1000 lm / 4π sr = 79.58 cd/klm everywhere
+        ldt.intensities = vec![vec![1000.0 / (4.0 * PI); ldt.g_angles.len()]];
+
+        let flux = PhotometricCalculations::integrate_flux(&ldt);
+        assert!((flux.lor - 100.0).abs() < 0.1, "LOR was {}", flux.lor);
+        assert!((flux.dlor - 50.0).abs() < 0.1, "DLOR was {}", flux.dlor);
+        assert!((flux.ulor - 50.0).abs() < 0.1, "ULOR was {}", flux.ulor);
+        assert!((flux.dff - 50.0).abs() < 0.1);
+        assert!((flux.luminaire_flux - 1000.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_integrate_flux_matches_declared_header() {
+        let mut ldt = create_test_ldt();
+        let flux = PhotometricCalculations::integrate_flux(&ldt);
+        assert_eq!(flux.ulor, 0.0);
+        assert!((flux.dff - 100.0).abs() < 1e-9);
+
+        ldt.light_output_ratio = flux.lor;
+        ldt.downward_flux_fraction = 100.0;
+        assert!(flux.is_consistent_with(&ldt, 0.5));
+
+        ldt.light_output_ratio = flux.lor + 20.0;
+        assert!((flux.lor_deviation(&ldt) + 20.0).abs() < 1e-9);
+        assert!(!flux.is_consistent_with(&ldt, 5.0));
+    }
+
     #[test]
     fn test_downward_flux() {
         let ldt = create_test_ldt();
@@ -3694,6 +4498,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_beam_angle_threshold_on_grid_point() {
+        // Half of the maximum falls exactly on the 20° value
+        let mut ldt = create_test_ldt();
+        ldt.g_angles = vec![0.0, 10.0, 20.0, 30.0, 90.0];
+        ldt.intensities = vec![vec![400.0, 300.0, 200.0, 100.0, 0.0]];
+        assert!((PhotometricCalculations::beam_angle(&ldt) - 40.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_direct_ratios() {
         let ldt = create_test_ldt();
@@ -3738,6 +4551,66 @@ mod tests {
         assert!(!display.is_empty());
     }
 
+    /// Rotationally symmetric downlight, 0-180° in 0.5° steps.
+    fn downlight(intensity: impl Fn(f64) -> f64, lor: f64) -> Eulumdat {
+        let g_angles: Vec<f64> = (0..=360).map(|i| i as f64 * 0.5).collect();
+        let row = g_angles
+            .iter()
+            .map(|&g| if g <= 90.0 { intensity(g) } else { 0.0 })
+            .collect();
+        Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            num_c_planes: 1,
+            num_g_planes: g_angles.len(),
+            g_plane_distance: 0.5,
+            light_output_ratio: lor,
+            c_angles: vec![0.0],
+            g_angles,
+            intensities: vec![row],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_cie_flux_code_published_examples() {
+        // Uniform hemisphere: equal solid-angle cones get equal flux
+        let uniform = PhotometricCalculations::cie_flux_codes(&downlight(|_| 100.0, 100.0));
+        assert_eq!(uniform.code(), [25, 50, 75, 100, 100]);
+        assert!((uniform.fc1 - 25.0).abs() < 0.05);
+        assert!((uniform.fc3 - 75.0).abs() < 0.05);
+
+        // Cosine (Lambertian) downlight, CIE 52: "44 75 94 100"
+        let cosine = PhotometricCalculations::cie_flux_codes(&downlight(
+            |g| 100.0 * g.to_radians().cos(),
+            80.0,
+        ));
+        assert_eq!(cosine.to_string(), "44 75 94 100 80");
+        assert!((cosine.fc2 - 75.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_cie_flux_code_with_uplight() {
+        let mut ldt = downlight(|_| 100.0, 70.0);
+        // Same intensity upwards: half the luminaire flux goes up
+        for value in ldt.intensities[0].iter_mut() {
+            *value = 100.0;
+        }
+        let codes = PhotometricCalculations::cie_flux_codes(&ldt);
+        assert_eq!(codes.code(), [25, 50, 75, 50, 70]);
+        assert!((codes.fc4 - codes.n1).abs() < 1e-9);
+
+        // No downward light at all
+        let up_only = PhotometricCalculations::cie_flux_codes(&Eulumdat {
+            intensities: vec![ldt
+                .g_angles
+                .iter()
+                .map(|&g| if g > 90.0 { 100.0 } else { 0.0 })
+                .collect()],
+            ..ldt
+        });
+        assert_eq!(up_only.code()[..4], [0, 0, 0, 0]);
+    }
+
     #[test]
     fn test_luminaire_efficacy() {
         let mut ldt = create_test_ldt();
@@ -3766,6 +4639,141 @@ mod tests {
         assert!(s_h_perp > 0.0);
     }
 
+    #[test]
+    fn test_spacing_criteria_extended() {
+        let ldt = create_test_ldt();
+        let spacing = PhotometricCalculations::spacing_criteria_extended(&ldt);
+        let (c0, c90) = PhotometricCalculations::spacing_criteria(&ldt);
+        assert_eq!((spacing.c0, spacing.c90), (c0, c90));
+        // Rotationally symmetric: the diagonal matches the principal planes
+        assert!((spacing.diagonal - c0).abs() < 1e-9);
+        let max_shr = spacing.max_shr.expect("a downlight can be spaced evenly");
+        assert!(max_shr > 0.5 && max_shr < 3.0);
+
+        // A narrower beam has to be spaced closer
+        let mut narrow = ldt.clone();
+        narrow.intensities = vec![vec![1000.0, 700.0, 300.0, 100.0, 30.0, 10.0, 0.0]];
+        let narrow_spacing = PhotometricCalculations::spacing_criteria_extended(&narrow);
+        assert!(narrow_spacing.max_shr < spacing.max_shr);
+
+        let summary = PhotometricSummary::from_eulumdat(&ldt);
+        assert_eq!(summary.spacing_diagonal, spacing.diagonal);
+        assert_eq!(summary.spacing_max, spacing.max_shr);
+
+        // A spot cannot light the middle of a cell even at S/H 0.5
+        let mut spot = ldt.clone();
+        spot.intensities = vec![vec![1000.0, 100.0, 10.0, 0.0, 0.0, 0.0, 0.0]];
+        let spot_spacing = PhotometricCalculations::spacing_criteria_extended(&spot);
+        assert_eq!(spot_spacing.max_shr, None);
+        let summary = PhotometricSummary::from_eulumdat(&spot);
+        assert!(summary
+            .to_text()
+            .contains("Maximum SHR:         not achievable"));
+    }
+
+    #[test]
+    fn test_max_spacing_ratio_batwing() {
+        let mut ldt = create_test_ldt();
+        ldt.g_angles = (0..10).map(|i| i as f64 * 10.0).collect();
+        ldt.num_g_planes = ldt.g_angles.len();
+        ldt.intensities = vec![vec![
+            100.0, 150.0, 300.0, 600.0, 1000.0, 800.0, 300.0, 50.0, 10.0, 0.0,
+        ]];
+
+        // Close together, the dim centres show; spread out, the wings fill them
+        let at = |s_h| PhotometricCalculations::square_array_uniformity(&ldt, s_h);
+        assert!(at(0.5) < 0.9);
+        assert!(at(0.8) > 0.95);
+
+        let max_shr = PhotometricCalculations::max_spacing_ratio_ies(&ldt, 0.9)
+            .expect("the wings even out a wider spacing");
+        assert!(max_shr > 0.8, "max SHR was {max_shr}");
+        assert!(at(max_shr) >= 0.9);
+        assert!(at(max_shr + 0.01) < 0.9);
+    }
+
+    #[test]
+    fn test_batwing_analysis() {
+        let mut ldt = Eulumdat::new();
+        ldt.symmetry = Symmetry::PlaneC0C180;
+        ldt.num_c_planes = 3;
+        ldt.c_angles = vec![0.0, 90.0, 180.0];
+        ldt.g_angles = vec![0.0, 15.0, 30.0, 60.0, 90.0];
+        ldt.num_g_planes = ldt.g_angles.len();
+        ldt.intensities = vec![
+            vec![200.0, 300.0, 350.0, 100.0, 0.0],
+            vec![200.0, 250.0, 240.0, 80.0, 0.0],
+            vec![200.0, 280.0, 300.0, 80.0, 0.0],
+        ];
+
+        let batwing = PhotometricCalculations::batwing_analysis(&ldt).unwrap();
+        assert_eq!(
+            (batwing.peak_c0.gamma, batwing.peak_c0.intensity),
+            (30.0, 350.0)
+        );
+        assert_eq!(
+            (batwing.peak_c180.gamma, batwing.peak_c180.intensity),
+            (30.0, 300.0)
+        );
+        assert_eq!(batwing.center_intensity, 200.0);
+        assert!((batwing.valley_depth - 1.0 / 3.0).abs() < 1e-9);
+        assert!((batwing.asymmetry_ratio - 300.0 / 350.0).abs() < 1e-9);
+
+        // A center-peaked downlight has no wings
+        assert!(PhotometricCalculations::batwing_analysis(&create_test_ldt()).is_none());
+    }
+
+    #[test]
+    fn test_asymmetric_beam_wall_washer() {
+        // Peak at 30° towards C0, little light towards C180
+        let mut ldt = Eulumdat::new();
+        ldt.symmetry = Symmetry::PlaneC0C180;
+        ldt.num_c_planes = 2;
+        ldt.c_angles = vec![0.0, 90.0, 180.0];
+        ldt.g_angles = (0..=18).map(|i| i as f64 * 5.0).collect();
+        ldt.num_g_planes = ldt.g_angles.len();
+        let lobe = |g: f64, peak: f64, width: f64| 1000.0 * (-((g - peak) / width).powi(2)).exp();
+        ldt.intensities = vec![
+            ldt.g_angles.iter().map(|&g| lobe(g, 30.0, 15.0)).collect(),
+            ldt.g_angles.iter().map(|&g| lobe(g, 0.0, 15.0)).collect(),
+            ldt.g_angles.iter().map(|&g| lobe(-g, 30.0, 15.0)).collect(),
+        ];
+
+        let beam = PhotometricCalculations::asymmetric_beam(&ldt, 0.0);
+        assert!(
+            (beam.peak_gamma - 30.0).abs() <= 2.5,
+            "peak at {}",
+            beam.peak_gamma
+        );
+        assert!(!beam.is_symmetric(1.0));
+        // Symmetric lobe around the peak: 50% at ±15·√ln2 ≈ ±12.5°
+        assert!((beam.beam_left - 12.5).abs() < 1.5, "{:?}", beam);
+        assert!((beam.beam_right - 12.5).abs() < 1.5, "{:?}", beam);
+        assert!(beam.field_angle() > beam.beam_angle());
+
+        // Measured from nadir, the beam spans the C180 side only up to the
+        // 50% point of the (weak) nadir intensity
+        let from_nadir = PhotometricCalculations::beam_angle_for_plane(&ldt, 0.0);
+        assert!((from_nadir - beam.beam_angle()).abs() > 5.0);
+    }
+
+    #[test]
+    fn test_asymmetric_beam_matches_symmetric_plane() {
+        let ldt = create_test_ldt();
+        for c in [0.0, 37.5, 90.0] {
+            let beam = PhotometricCalculations::asymmetric_beam(&ldt, c);
+            assert_eq!(beam.peak_gamma, 0.0);
+            assert!(beam.is_symmetric(1e-6));
+            let full = PhotometricCalculations::beam_angle_for_plane(&ldt, c);
+            assert!(
+                (beam.beam_angle() - full).abs() < 2.5,
+                "{:?} vs {}",
+                beam,
+                full
+            );
+        }
+    }
+
     #[test]
     fn test_zonal_lumens() {
         let ldt = create_test_ldt();
@@ -3932,6 +4940,154 @@ mod tests {
         assert!(text.contains("COEFFICIENTS OF UTILIZATION"));
     }
 
+    /// Rotationally symmetric `1000·cosⁿγ` beam, 0-90° in 1° steps.
+    fn cosine_power_beam(n: i32) -> Eulumdat {
+        let mut ldt = create_test_ldt();
+        ldt.g_angles = (0..=90).map(f64::from).collect();
+        ldt.num_g_planes = ldt.g_angles.len();
+        ldt.intensities = vec![ldt
+            .g_angles
+            .iter()
+            .map(|g| 1000.0 * g.to_radians().cos().powi(n))
+            .collect()];
+        ldt
+    }
+
+    #[test]
+    fn test_summary_nema_for_floodlights() {
+        // 10% at cos⁴⁰γ = 0.1 → γ ≈ 19.3°, spread ≈ 38.6° → type 3
+        let summary = PhotometricSummary::from_eulumdat(&cosine_power_beam(40));
+        let nema = summary.nema.as_ref().expect("narrow beam is a floodlight");
+        assert_eq!(nema.designation, "NEMA 3H x 3V");
+        assert!((nema.horizontal_spread - 38.6).abs() < 1.0);
+        assert!(summary
+            .to_text()
+            .contains("NEMA Beam Type:      NEMA 3H x 3V"));
+        assert!(summary.to_compact().ends_with("NEMA:3Hx3V"));
+        assert!(summary
+            .to_key_value()
+            .contains(&("nema_class", "NEMA 3H x 3V".to_string())));
+        assert_eq!(
+            summary.to_record().nema_class.as_deref(),
+            Some("NEMA 3H x 3V")
+        );
+    }
+
+    #[test]
+    fn test_summary_no_nema_for_wide_distribution() {
+        // Cosine: 10% at 84°, spread 168° → type 7, not a floodlight
+        let ldt = cosine_power_beam(1);
+        let nema = PhotometricCalculations::nema_classification(&ldt);
+        assert_eq!(nema.horizontal_type, 7);
+        assert!(!nema.is_floodlight());
+
+        let summary = PhotometricSummary::from_eulumdat(&ldt);
+        assert!(summary.nema.is_none());
+        assert!(!summary.to_text().contains("NEMA"));
+        assert!(summary.to_record().nema_class.is_none());
+    }
+
+    #[test]
+    fn test_summary_record() {
+        let summary = PhotometricSummary::from_eulumdat(&create_test_ldt());
+        let record = summary.to_record();
+        assert_eq!(record.schema_version, SummaryRecord::SCHEMA_VERSION);
+        assert_eq!(record.beam_angle_deg, summary.beam_angle);
+        assert_eq!(record.cie_flux_code, summary.cie_flux_codes.to_string());
+
+        // Every key-value field has a typed counterpart in the schema
+        for (key, _) in summary.to_key_value() {
+            assert!(
+                SummaryRecord::JSON_SCHEMA.contains(&format!("\"{key}\"")),
+                "{key} missing from schema"
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_summary_record_matches_schema() {
+        let record = PhotometricSummary::from_eulumdat(&create_test_ldt()).to_record();
+        let json = serde_json::to_value(&record).unwrap();
+        let schema: serde_json::Value = serde_json::from_str(SummaryRecord::JSON_SCHEMA).unwrap();
+
+        let object = json.as_object().unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+        let required = schema["required"].as_array().unwrap();
+        assert_eq!(object.len(), properties.len());
+        assert_eq!(required.len(), properties.len());
+        for (key, value) in object {
+            let expected = match &properties[key]["type"] {
+                serde_json::Value::Array(types) if value.is_null() => {
+                    assert!(types.contains(&"null".into()), "{key}");
+                    continue;
+                }
+                serde_json::Value::Array(types) => types[0].as_str().unwrap(),
+                other => other.as_str().unwrap(),
+            };
+            let actual = match value {
+                serde_json::Value::Bool(_) => "boolean",
+                serde_json::Value::String(_) => "string",
+                serde_json::Value::Number(n) if n.is_u64() && expected == "integer" => "integer",
+                serde_json::Value::Number(_) => "number",
+                _ => "other",
+            };
+            assert_eq!(actual, expected, "{key}");
+            if let Some(allowed) = properties[key]["enum"].as_array() {
+                assert!(allowed.contains(value), "{key}: {value}");
+            }
+        }
+
+        let back: SummaryRecord = serde_json::from_value(json).unwrap();
+        assert_eq!(back, record);
+    }
+
+    #[test]
+    fn test_cu_table_rcr() {
+        // Narrow beam: all flux within 10°, so everything hits the work plane
+        let mut ldt = create_test_ldt();
+        ldt.g_angles = (0..=18).map(|i| i as f64 * 10.0).collect();
+        ldt.num_g_planes = ldt.g_angles.len();
+        ldt.intensities = vec![ldt
+            .g_angles
+            .iter()
+            .map(|&g| if g < 10.0 { 1000.0 } else { 0.0 })
+            .collect()];
+        ldt.light_output_ratio = 60.0;
+
+        let cu = PhotometricCalculations::cu_table_rcr(&ldt);
+        assert_eq!(cu.values.len(), 11);
+        assert_eq!(cu.values[0].len(), 18);
+        // Black ceiling and walls: CU is the direct downward flux
+        for row in &cu.values {
+            assert!((row[17] - 60.0).abs() < 1e-9, "{}", row[17]);
+        }
+        // RCR 0: floor and ceiling only, Φ / (1 - ρc·ρf)
+        assert!((cu.values[0][0] - 60.0 / (1.0 - 0.8 * 0.2)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cu_table_rcr_wide_distribution() {
+        let mut ldt = create_test_ldt();
+        ldt.light_output_ratio = 80.0;
+        let cu = PhotometricCalculations::cu_table_rcr(&ldt);
+        let zones = PhotometricCalculations::zonal_lumens_10deg(&ldt);
+        let downward: f64 = zones[..9].iter().sum::<f64>() * ldt.light_output_ratio / 100.0;
+
+        assert!(downward > 50.0);
+        assert!((cu.values[0][17] - downward).abs() < 1e-6);
+        for col in 0..18 {
+            for rcr in 1..11 {
+                // Smaller rooms lose more light to the walls
+                assert!(cu.values[rcr][col] <= cu.values[rcr - 1][col] + 1e-9);
+            }
+        }
+        // Brighter walls help in deep rooms
+        assert!(cu.values[10][0] > cu.values[10][3]);
+        // Form factor of a cube (RCR 10) is 0.1998
+        assert!((CuTable::floor_ceiling_form_factor(10.0) - 0.1998).abs() < 1e-4);
+    }
+
     #[test]
     fn test_ugr_table() {
         let mut ldt = create_test_ldt();
@@ -3960,6 +5116,61 @@ mod tests {
         assert!(text.contains("Maximum UGR"));
     }
 
+    #[test]
+    fn test_uf_table() {
+        let mut ldt = create_test_ldt();
+        ldt.light_output_ratio = 80.0;
+        let uf = PhotometricCalculations::uf_table(&ldt);
+        assert_eq!(uf.values.len(), UF_ROOM_INDICES.len());
+        assert_eq!(uf.values[0].len(), UF_REFLECTANCES.len());
+
+        // UF at room index k is the RCR-method CU at RCR = 5/k
+        let cu = PhotometricCalculations::cu_table_rcr(&ldt);
+        let k1 = uf.room_indices.iter().position(|&k| k == 1.0).unwrap();
+        let rcr5 = cu.rcr_values.iter().position(|&r| r == 5).unwrap();
+        let c705020 = cu
+            .reflectances
+            .iter()
+            .position(|&r| r == (70, 50, 20))
+            .unwrap();
+        assert!((uf.values[k1][2] * 100.0 - cu.values[rcr5][c705020]).abs() < 1e-9);
+
+        // Larger rooms and brighter surfaces use more of the light
+        for j in 0..UF_REFLECTANCES.len() {
+            for i in 1..uf.values.len() {
+                assert!(uf.values[i][j] >= uf.values[i - 1][j] - 1e-9);
+            }
+        }
+        assert!(uf.values[5][0] > uf.values[5][6]);
+
+        let custom = UfTable::calculate_for(&ldt, &[0.0, 1.0, 2.0], &[(70, 50, 20)]);
+        assert_eq!(custom.room_indices, vec![1.0, 2.0]);
+        assert_eq!(custom.to_csv().lines().count(), 3);
+        assert!(custom.to_text().contains("1.00"));
+    }
+
+    #[test]
+    fn test_ugr_table_standard_room() {
+        let mut ldt = create_test_ldt();
+        ldt.luminous_area_length = 600.0;
+        ldt.luminous_area_width = 600.0;
+        let ugr = PhotometricCalculations::ugr_table(&ldt);
+
+        // 4H×8H is the 11th room size, 70/50/20 the first reflectance column
+        let standard = ugr.standard_room().unwrap();
+        assert_eq!(standard.crosswise, ugr.crosswise[10][0]);
+        assert_eq!(standard.endwise, ugr.endwise[10][0]);
+        let text = ugr.to_text();
+        assert_eq!(text.matches("  *\n").count(), 1);
+        assert!(text.contains("* 4H×8H, 70/50/20 (GLDF)"));
+
+        let csv = ugr.to_csv();
+        assert_eq!(csv.lines().count(), 1 + 19 * 5);
+        assert!(csv.contains(&format!("\n4,8,70,50,20,{:.1},", standard.crosswise)));
+
+        assert!(UgrTable::default().standard_room().is_none());
+    }
+
     #[test]
     fn test_candela_tabulation() {
         let ldt = create_test_ldt();
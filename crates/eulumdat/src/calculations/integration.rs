@@ -0,0 +1,307 @@
+//! Solid-angle integration of the intensity distribution.
+//!
+//! All flux calculations share one quadrature: total and downward flux,
+//! [`PhotometricCalculations::integrate_flux`], the zonal lumens, the BUG
+//! zone lumens and the webs of `eulumdat-photweb`. A [`Zone`] is divided
+//! into cells of at most 1° in C and 0.5° in γ, and the intensity at each
+//! cell center is weighted by the exact solid angle of the cell:
+//!
+//! ```text
+//! Φ = Σ I(C, γ) · ΔC · (cos γ₁ − cos γ₂)
+//! ```
+//!
+//! Directions outside the measured γ range of a file count as dark.
+//!
+//! [`PhotometricCalculations::integrate_flux`]: crate::PhotometricCalculations::integrate_flux
+
+use crate::eulumdat::{Eulumdat, Symmetry};
+use crate::symmetry::SymmetryHandler;
+
+/// Largest C step of the quadrature in degrees.
+pub const MAX_C_STEP: f64 = 1.0;
+/// Largest γ step of the quadrature in degrees.
+pub const MAX_G_STEP: f64 = 0.5;
+
+/// A region of the sphere bounded by C-plane and γ angles in degrees.
+///
+/// The C range runs counter-clockwise from `c_start` to `c_end` and may wrap
+/// through C0 (e.g. 270° to 90°); equal bounds or a span of 360° or more
+/// cover the full circle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Zone {
+    /// First C-plane angle
+    pub c_start: f64,
+    /// Last C-plane angle
+    pub c_end: f64,
+    /// Smallest γ angle (0° = nadir)
+    pub g_start: f64,
+    /// Largest γ angle (180° = zenith)
+    pub g_end: f64,
+}
+
+impl Zone {
+    /// The whole sphere.
+    pub const SPHERE: Self = Self::gamma(0.0, 180.0);
+    /// The lower hemisphere, up to the horizon.
+    pub const DOWNWARD: Self = Self::gamma(0.0, 90.0);
+    /// The upper hemisphere, above the horizon.
+    pub const UPWARD: Self = Self::gamma(90.0, 180.0);
+
+    /// A zone bounded by C-plane and γ angles.
+    pub const fn new(c_start: f64, c_end: f64, g_start: f64, g_end: f64) -> Self {
+        Self {
+            c_start,
+            c_end,
+            g_start,
+            g_end,
+        }
+    }
+
+    /// A band between two γ angles over all C-planes.
+    pub const fn gamma(g_start: f64, g_end: f64) -> Self {
+        Self::new(0.0, 360.0, g_start, g_end)
+    }
+
+    /// C span in degrees, 360° for the full circle
+    fn c_span(&self) -> f64 {
+        let span = self.c_end - self.c_start;
+        if span.abs() >= 360.0 {
+            return 360.0;
+        }
+        let span = span.rem_euclid(360.0);
+        if span == 0.0 {
+            360.0
+        } else {
+            span
+        }
+    }
+
+    /// Solid angle of the zone in steradians.
+    pub fn solid_angle(&self) -> f64 {
+        let (g0, g1) = self.gamma_bounds();
+        self.c_span().to_radians() * (g0.to_radians().cos() - g1.to_radians().cos())
+    }
+
+    /// γ bounds clamped to 0–180° and in ascending order
+    fn gamma_bounds(&self) -> (f64, f64) {
+        let g0 = self.g_start.clamp(0.0, 180.0);
+        let g1 = self.g_end.clamp(0.0, 180.0);
+        (g0.min(g1), g0.max(g1))
+    }
+
+    /// Cell centers of the quadrature, at most `c_step` by `g_step` degrees:
+    /// the C angles of the columns, and the γ angle and solid angle of the
+    /// cells of each row
+    fn grid(&self, c_step: f64, g_step: f64) -> (Vec<f64>, Vec<(f64, f64)>) {
+        let (g0, g1) = self.gamma_bounds();
+        if g1 <= g0 {
+            return (Vec::new(), Vec::new());
+        }
+        let c_span = self.c_span();
+        let c_steps = (c_span / c_step).ceil().max(1.0) as usize;
+        let g_steps = ((g1 - g0) / g_step).ceil().max(1.0) as usize;
+        let dc = c_span / c_steps as f64;
+        let dg = (g1 - g0) / g_steps as f64;
+
+        let columns = (0..c_steps)
+            .map(|ci| self.c_start + (ci as f64 + 0.5) * dc)
+            .collect();
+        let rows = (0..g_steps)
+            .map(|gi| {
+                let g_low = g0 + gi as f64 * dg;
+                let solid_angle =
+                    dc.to_radians() * (g_low.to_radians().cos() - (g_low + dg).to_radians().cos());
+                (g_low + dg / 2.0, solid_angle)
+            })
+            .collect();
+        (columns, rows)
+    }
+
+    /// Visit the quadrature cells of the zone, at most `c_step` by `g_step`
+    /// degrees, with the C and γ angle of the cell center and the solid
+    /// angle of the cell.
+    pub fn for_each_cell(&self, c_step: f64, g_step: f64, mut visit: impl FnMut(f64, f64, f64)) {
+        let (columns, rows) = self.grid(c_step, g_step);
+        for (g, solid_angle) in rows {
+            for &c in &columns {
+                visit(c, g, solid_angle);
+            }
+        }
+    }
+
+    /// The part of the zone to integrate for a distribution with the given
+    /// symmetry, the factor for the parts left out, and the C step
+    fn reduced(&self, symmetry: Symmetry) -> (Self, f64, f64) {
+        // A full circle only needs the C range the symmetry does not repeat
+        let full_circle = self.c_span() >= 360.0;
+        let part = |c_start, c_end| Self::new(c_start, c_end, self.g_start, self.g_end);
+        match symmetry {
+            // Every C-plane is the same, so only γ needs to be resolved
+            Symmetry::VerticalAxis => (*self, 1.0, 360.0),
+            Symmetry::BothPlanes if full_circle => (part(0.0, 90.0), 4.0, MAX_C_STEP),
+            Symmetry::PlaneC0C180 if full_circle => (part(0.0, 180.0), 2.0, MAX_C_STEP),
+            Symmetry::PlaneC90C270 if full_circle => (part(90.0, 270.0), 2.0, MAX_C_STEP),
+            _ => (*self, 1.0, MAX_C_STEP),
+        }
+    }
+
+    /// Integrate `sample(c, γ)` over the zone, for a distribution with the
+    /// given symmetry.
+    ///
+    /// The result is in the intensity unit times steradians, i.e. lumens per
+    /// 1000 lamp lumens for intensities in cd/klm.
+    pub fn integrate(&self, symmetry: Symmetry, sample: impl Fn(f64, f64) -> f64) -> f64 {
+        let (zone, factor, c_step) = self.reduced(symmetry);
+        let mut flux = 0.0;
+        zone.for_each_cell(c_step, MAX_G_STEP, |c, g, solid_angle| {
+            flux += sample(c, g) * solid_angle;
+        });
+        flux * factor
+    }
+
+    /// Integrate the intensities of `ldt` over the zone, in lumens per 1000
+    /// lamp lumens.
+    ///
+    /// Equals [`Self::integrate`] with [`Eulumdat::sample`]. As the C
+    /// interpolation of a column is the same in every row, the columns are
+    /// first summed into a weight per stored C-plane.
+    pub fn integrate_eulumdat(&self, ldt: &Eulumdat) -> f64 {
+        let (Some(&g_first), Some(&g_last)) = (ldt.g_angles.first(), ldt.g_angles.last()) else {
+            return 0.0;
+        };
+        if ldt.intensities.is_empty() {
+            return 0.0;
+        }
+        let (zone, factor, c_step) = self.reduced(ldt.symmetry);
+        let (columns, rows) = zone.grid(c_step, MAX_G_STEP);
+        let mut weights = vec![0.0; ldt.intensities.len()];
+        for &c in &columns {
+            let (lower, upper, fraction) = SymmetryHandler::c_interpolation(ldt, c);
+            // Planes past the stored ones count as dark, like in sampling
+            if let Some(weight) = weights.get_mut(lower) {
+                *weight += 1.0 - fraction;
+            }
+            if let Some(weight) = weights.get_mut(upper) {
+                *weight += fraction;
+            }
+        }
+
+        let mut flux = 0.0;
+        for (g, solid_angle) in rows {
+            if g < g_first || g > g_last {
+                continue;
+            }
+            let (gi, gf) = SymmetryHandler::g_interpolation(ldt, g);
+            let row: f64 = ldt
+                .intensities
+                .iter()
+                .zip(&weights)
+                .map(|(plane, weight)| {
+                    let at = |i: usize| plane.get(i).copied().unwrap_or(0.0);
+                    weight * (at(gi) * (1.0 - gf) + at(gi + 1) * gf)
+                })
+                .sum();
+            flux += row * solid_angle;
+        }
+        flux * factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bug_rating::ZoneLumens;
+    use crate::eulumdat::LampSet;
+    use crate::PhotometricCalculations;
+    use std::f64::consts::PI;
+
+    /// Asymmetric street light: forward-throwing, some backlight and uplight
+    fn street_light() -> Eulumdat {
+        let c_angles: Vec<f64> = (0..24).map(|i| i as f64 * 15.0).collect();
+        let g_angles: Vec<f64> = (0..=36).map(|i| i as f64 * 5.0).collect();
+        let intensities = c_angles
+            .iter()
+            .map(|&c| {
+                let forward = 1.0 + 0.6 * c.to_radians().sin();
+                g_angles
+                    .iter()
+                    .map(|&g| {
+                        let lobe = (1.0 - ((g - 60.0) / 40.0).powi(2)).max(0.0);
+                        let uplight = if g > 90.0 { 5.0 } else { 0.0 };
+                        300.0 * forward * lobe + 40.0 * (g.to_radians().cos()).max(0.0) + uplight
+                    })
+                    .collect()
+            })
+            .collect();
+        Eulumdat {
+            symmetry: Symmetry::None,
+            num_c_planes: c_angles.len(),
+            num_g_planes: g_angles.len(),
+            c_angles,
+            g_angles,
+            intensities,
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 12000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_uniform_sphere() {
+        let flux = Zone::SPHERE.integrate(Symmetry::None, |_, _| 100.0);
+        assert!((flux - 400.0 * PI).abs() < 1e-6);
+        // A quarter of the lower hemisphere, wrapping through C0
+        let quarter = Zone::new(315.0, 45.0, 0.0, 90.0);
+        assert!((quarter.solid_angle() - PI / 2.0).abs() < 1e-9);
+        assert!((quarter.integrate(Symmetry::None, |_, _| 100.0) - 50.0 * PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_eulumdat_matches_sampling() {
+        let ldt = street_light();
+        let zone = Zone::new(300.0, 120.0, 20.0, 140.0);
+        let sampled = zone.integrate(ldt.symmetry, |c, g| ldt.sample(c, g));
+        assert!((zone.integrate_eulumdat(&ldt) - sampled).abs() < 1e-9 * sampled);
+    }
+
+    #[test]
+    fn test_symmetry_reduction() {
+        // Full circles are integrated over the non-repeating part only
+        let mut ldt = street_light();
+        ldt.symmetry = Symmetry::BothPlanes;
+        let sample = |c: f64, g: f64| ldt.sample(c, g);
+        let reduced = Zone::DOWNWARD.integrate(Symmetry::BothPlanes, sample);
+        let full = Zone::DOWNWARD.integrate(Symmetry::None, sample);
+        assert!((reduced - full).abs() < 1e-6 * full, "{reduced} vs {full}");
+    }
+
+    #[test]
+    fn test_flux_and_bug_zones_share_the_quadrature() {
+        let ldt = street_light();
+        let total = Zone::SPHERE.integrate_eulumdat(&ldt);
+        let downward = Zone::DOWNWARD.integrate_eulumdat(&ldt);
+
+        let flux = PhotometricCalculations::integrate_flux(&ldt);
+        assert!((flux.lor * 10.0 - total).abs() < 1e-9);
+        assert!((flux.dlor * 10.0 - downward).abs() < 1e-9);
+        assert!((flux.ulor * 10.0 - (total - downward)).abs() < 1e-9);
+
+        let zonal = PhotometricCalculations::zonal_lumens_30deg(&ldt);
+        let band = Zone::gamma(30.0, 60.0).integrate_eulumdat(&ldt);
+        assert!((zonal.zone_30_60 - 100.0 * band / total).abs() < 1e-9);
+
+        // BUG zones are in lumens, forward being C0-C180
+        let scale = ldt.total_luminous_flux() / 1000.0;
+        let zones = ZoneLumens::from_eulumdat(&ldt);
+        let fm = Zone::new(0.0, 180.0, 30.0, 60.0).integrate_eulumdat(&ldt);
+        let bl = Zone::new(180.0, 360.0, 0.0, 30.0).integrate_eulumdat(&ldt);
+        let uh = Zone::gamma(100.0, 180.0).integrate_eulumdat(&ldt);
+        assert!((zones.fm - fm * scale).abs() < 1e-9);
+        assert!((zones.bl - bl * scale).abs() < 1e-9);
+        assert!((zones.uh - uh * scale).abs() < 1e-9);
+        assert!((zones.total() - total * scale).abs() < 1e-6 * total * scale);
+    }
+}
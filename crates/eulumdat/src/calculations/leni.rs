@@ -0,0 +1,288 @@
+//! Lighting energy numeric indicator (LENI) estimation.
+//!
+//! Implements the quick method of EN 15193-1 for estimating the annual
+//! lighting energy of a room:
+//!
+//! ```text
+//! W_L = Σ(P_n × F_C) × [(t_D × F_O × F_D) + (t_N × F_O)] / 1000
+//! W_P = Σ P_pc × [t_y − (t_D + t_N)] / 1000 + Σ P_em × t_e / 1000
+//! LENI = (W_L + W_P) / A
+//! ```
+//!
+//! All energies are in kWh/year, LENI in kWh/(m²·year).
+
+use crate::eulumdat::Eulumdat;
+
+/// Hours in a standard year (t_y).
+pub const HOURS_PER_YEAR: f64 = 8760.0;
+
+/// Annual operating profile of a building zone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperatingProfile {
+    /// Annual operating hours during daylight time (t_D)
+    pub daylight_hours: f64,
+    /// Annual operating hours during non-daylight time (t_N)
+    pub non_daylight_hours: f64,
+    /// Occupancy dependency factor (F_O, 0-1); 1.0 without presence detection
+    pub occupancy_factor: f64,
+    /// Daylight dependency factor (F_D, 0-1); 1.0 without daylight control
+    pub daylight_factor: f64,
+}
+
+impl Default for OperatingProfile {
+    fn default() -> Self {
+        Self::office()
+    }
+}
+
+impl OperatingProfile {
+    /// Create a profile without occupancy or daylight control.
+    pub fn new(daylight_hours: f64, non_daylight_hours: f64) -> Self {
+        Self {
+            daylight_hours,
+            non_daylight_hours,
+            occupancy_factor: 1.0,
+            daylight_factor: 1.0,
+        }
+    }
+
+    /// Office buildings (EN 15193 default: t_D 2250 h, t_N 250 h).
+    pub fn office() -> Self {
+        Self::new(2250.0, 250.0)
+    }
+
+    /// Educational buildings (t_D 1800 h, t_N 200 h).
+    pub fn education() -> Self {
+        Self::new(1800.0, 200.0)
+    }
+
+    /// Hospitals (t_D 3000 h, t_N 2000 h).
+    pub fn hospital() -> Self {
+        Self::new(3000.0, 2000.0)
+    }
+
+    /// Hotels (t_D 3000 h, t_N 2000 h).
+    pub fn hotel() -> Self {
+        Self::new(3000.0, 2000.0)
+    }
+
+    /// Restaurants (t_D 1250 h, t_N 1250 h).
+    pub fn restaurant() -> Self {
+        Self::new(1250.0, 1250.0)
+    }
+
+    /// Sports facilities (t_D 2000 h, t_N 2000 h).
+    pub fn sports() -> Self {
+        Self::new(2000.0, 2000.0)
+    }
+
+    /// Wholesale and retail (t_D 3000 h, t_N 2000 h).
+    pub fn retail() -> Self {
+        Self::new(3000.0, 2000.0)
+    }
+
+    /// Manufacturing (t_D 2500 h, t_N 1500 h).
+    pub fn manufacturing() -> Self {
+        Self::new(2500.0, 1500.0)
+    }
+
+    /// Set the occupancy dependency factor (F_O).
+    pub fn with_occupancy_factor(mut self, factor: f64) -> Self {
+        self.occupancy_factor = factor.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the daylight dependency factor (F_D).
+    pub fn with_daylight_factor(mut self, factor: f64) -> Self {
+        self.daylight_factor = factor.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Total annual operating hours (t_D + t_N).
+    pub fn total_hours(&self) -> f64 {
+        self.daylight_hours + self.non_daylight_hours
+    }
+
+    /// Equivalent full-load hours after occupancy and daylight factors.
+    pub fn effective_hours(&self) -> f64 {
+        self.daylight_hours * self.occupancy_factor * self.daylight_factor
+            + self.non_daylight_hours * self.occupancy_factor
+    }
+}
+
+/// Constant illuminance factor (F_C) for maintained-illuminance control.
+///
+/// With constant illuminance control the luminaires are dimmed while new and
+/// run at full power only at the end of the maintenance cycle, giving
+/// `F_C = (1 + MF) / 2`. Without control F_C is 1.0.
+pub fn constant_illuminance_factor(maintenance_factor: f64) -> f64 {
+    (1.0 + maintenance_factor.clamp(0.0, 1.0)) / 2.0
+}
+
+/// Room configuration for a LENI estimate.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeniParams {
+    /// Floor area of the room or zone (m²)
+    pub floor_area: f64,
+    /// Number of installed luminaires
+    pub luminaire_count: u32,
+    /// Circuit power per luminaire including control gear (W)
+    pub luminaire_power: f64,
+    /// Operating profile
+    pub profile: OperatingProfile,
+    /// Constant illuminance factor (F_C, 0-1)
+    pub constant_illuminance_factor: f64,
+    /// Standby power of the control system per luminaire (P_pc, W)
+    pub parasitic_power: f64,
+    /// Charging power of emergency lighting per luminaire (P_em, W)
+    pub emergency_charging_power: f64,
+    /// Annual emergency battery charging hours (t_e)
+    pub emergency_charging_hours: f64,
+}
+
+impl Default for LeniParams {
+    fn default() -> Self {
+        Self {
+            floor_area: 24.0,
+            luminaire_count: 4,
+            luminaire_power: 30.0,
+            profile: OperatingProfile::office(),
+            constant_illuminance_factor: 1.0,
+            parasitic_power: 0.0,
+            emergency_charging_power: 0.0,
+            emergency_charging_hours: 0.0,
+        }
+    }
+}
+
+impl LeniParams {
+    /// Create params for `count` luminaires of the given LDT in a room of `floor_area` m².
+    ///
+    /// The luminaire power is taken from the lamp sets (wattage incl. ballast).
+    pub fn from_eulumdat(ldt: &Eulumdat, floor_area: f64, count: u32) -> Self {
+        Self {
+            floor_area,
+            luminaire_count: count,
+            luminaire_power: ldt.total_wattage(),
+            ..Default::default()
+        }
+    }
+
+    /// Installed lighting power density (W/m²).
+    pub fn power_density(&self) -> f64 {
+        if self.floor_area <= 0.0 {
+            return 0.0;
+        }
+        self.installed_power() / self.floor_area
+    }
+
+    /// Total installed luminaire power (W).
+    pub fn installed_power(&self) -> f64 {
+        self.luminaire_power * self.luminaire_count as f64
+    }
+
+    /// Calculate the annual energy and LENI.
+    pub fn calculate(&self) -> LeniResult {
+        let count = self.luminaire_count as f64;
+        let f_c = self.constant_illuminance_factor.clamp(0.0, 1.0);
+
+        let lighting_energy =
+            self.installed_power() * f_c * self.profile.effective_hours() / 1000.0;
+
+        let standby_hours = (HOURS_PER_YEAR - self.profile.total_hours()).max(0.0);
+        let parasitic_energy = (self.parasitic_power * count * standby_hours
+            + self.emergency_charging_power * count * self.emergency_charging_hours)
+            / 1000.0;
+
+        let total_energy = lighting_energy + parasitic_energy;
+        let leni = if self.floor_area > 0.0 {
+            total_energy / self.floor_area
+        } else {
+            0.0
+        };
+
+        LeniResult {
+            lighting_energy,
+            parasitic_energy,
+            total_energy,
+            leni,
+            power_density: self.power_density(),
+        }
+    }
+}
+
+/// Result of a LENI estimate.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeniResult {
+    /// Annual lighting energy W_L (kWh/year)
+    pub lighting_energy: f64,
+    /// Annual parasitic energy W_P (kWh/year)
+    pub parasitic_energy: f64,
+    /// Total annual energy W (kWh/year)
+    pub total_energy: f64,
+    /// Lighting energy numeric indicator (kWh/m²/year)
+    pub leni: f64,
+    /// Installed lighting power density (W/m²)
+    pub power_density: f64,
+}
+
+impl std::fmt::Display for LeniResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LENI {:.1} kWh/m²/year (W_L {:.0} kWh, W_P {:.0} kWh, {:.1} W/m²)",
+            self.leni, self.lighting_energy, self.parasitic_energy, self.power_density
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leni_office_without_controls() {
+        let params = LeniParams {
+            floor_area: 20.0,
+            luminaire_count: 4,
+            luminaire_power: 25.0,
+            ..Default::default()
+        };
+        let result = params.calculate();
+        // 100 W × 2500 h = 250 kWh over 20 m²
+        assert!((result.lighting_energy - 250.0).abs() < 1e-9);
+        assert!((result.leni - 12.5).abs() < 1e-9);
+        assert!((result.power_density - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_leni_controls_reduce_energy() {
+        let base = LeniParams::default();
+        let controlled = LeniParams {
+            profile: OperatingProfile::office()
+                .with_occupancy_factor(0.9)
+                .with_daylight_factor(0.7),
+            constant_illuminance_factor: constant_illuminance_factor(0.8),
+            ..base.clone()
+        };
+        assert!(controlled.calculate().leni < base.calculate().leni);
+        assert!((constant_illuminance_factor(0.8) - 0.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_leni_parasitic_energy() {
+        let params = LeniParams {
+            luminaire_count: 1,
+            parasitic_power: 1.0,
+            emergency_charging_power: 1.0,
+            emergency_charging_hours: 1000.0,
+            ..Default::default()
+        };
+        let result = params.calculate();
+        // (8760 − 2500) h standby + 1000 h charging at 1 W
+        assert!((result.parasitic_energy - 7.26).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,212 @@
+//! Luminaire luminance versus viewing angle.
+//!
+//! EN 12464-1 limits the average luminance of luminaires seen at elevation
+//! angles of 65° and above, to avoid reflections on display screens. The
+//! average luminance in a direction is the intensity divided by the luminous
+//! area projected towards the observer:
+//!
+//! ```text
+//! A_p(C, γ) = A_bottom · cos γ + A_side(C) · sin γ
+//! L(C, γ)   = I(C, γ) / A_p(C, γ)
+//! ```
+//!
+//! The side area uses the luminous heights of the C-planes (HC0…HC270), so
+//! luminaires with luminous sides are not overrated.
+
+use crate::eulumdat::Eulumdat;
+use std::f64::consts::PI;
+
+/// Viewing angles (γ) tabulated for EN 12464-1 luminance limits.
+pub const LUMINANCE_LIMIT_ANGLES: [f64; 5] = [65.0, 70.0, 75.0, 80.0, 85.0];
+
+/// EN 12464-1 limit for screens with high state luminance (> 200 cd/m²), case A.
+pub const LIMIT_HIGH_LUMINANCE_SCREEN: f64 = 3000.0;
+
+/// EN 12464-1 limit for screens with medium state luminance (≤ 200 cd/m²), case A.
+pub const LIMIT_MEDIUM_LUMINANCE_SCREEN: f64 = 1500.0;
+
+/// Luminous area of the luminaire projected towards `(c_angle, gamma)` in m².
+///
+/// The bottom face is `La × B1` (or a disc of diameter `La` when B1 is 0); the
+/// sides are the luminous heights of the four main C-planes, weighted by how
+/// much of each face is turned towards the observer.
+pub fn projected_luminous_area(ldt: &Eulumdat, c_angle: f64, gamma: f64) -> f64 {
+    let length = ldt.luminous_area_length / 1000.0;
+    let width = ldt.luminous_area_width / 1000.0;
+    if length <= 0.0 {
+        return 0.0;
+    }
+
+    let (c_sin, c_cos) = c_angle.to_radians().sin_cos();
+    let (g_sin, g_cos) = gamma.to_radians().sin_cos();
+    let [h0, h90, h180, h270] = [
+        ldt.height_c0,
+        ldt.height_c90,
+        ldt.height_c180,
+        ldt.height_c270,
+    ]
+    .map(|h| h.max(0.0) / 1000.0);
+
+    let (bottom, side) = if width <= 0.0 {
+        // Circular: a cylinder shows diameter × height from every azimuth
+        let height = c_cos * c_cos * if c_cos >= 0.0 { h0 } else { h180 }
+            + c_sin * c_sin * if c_sin >= 0.0 { h90 } else { h270 };
+        (PI * length * length / 4.0, length * height)
+    } else {
+        // Length runs along C0-C180, width along C90-C270
+        let c0_face = width * if c_cos >= 0.0 { h0 } else { h180 };
+        let c90_face = length * if c_sin >= 0.0 { h90 } else { h270 };
+        (
+            length * width,
+            c0_face * c_cos.abs() + c90_face * c_sin.abs(),
+        )
+    };
+
+    (bottom * g_cos.max(0.0) + side * g_sin.max(0.0)).max(0.0)
+}
+
+/// Average luminance of the luminaire seen from `(c_angle, gamma)` in cd/m².
+///
+/// Returns 0 when the luminaire has no luminous area in that direction.
+pub fn luminance_at(ldt: &Eulumdat, c_angle: f64, gamma: f64) -> f64 {
+    let area = projected_luminous_area(ldt, c_angle, gamma);
+    if area <= 1e-9 {
+        return 0.0;
+    }
+    let intensity = ldt.sample(c_angle, gamma) * ldt.total_luminous_flux() / 1000.0;
+    intensity / area
+}
+
+/// Luminance table for the EN 12464-1 viewing angles.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LuminanceTable {
+    /// C-plane angles (columns)
+    pub c_planes: Vec<f64>,
+    /// Viewing angles γ (rows)
+    pub viewing_angles: Vec<f64>,
+    /// Luminance in cd/m² (`[angle][c_plane]`)
+    pub values: Vec<Vec<f64>>,
+}
+
+impl LuminanceTable {
+    /// Compute the table at 65°–85° for C-planes every 15°.
+    pub fn from_eulumdat(ldt: &Eulumdat) -> Self {
+        let c_planes: Vec<f64> = (0..24).map(|i| i as f64 * 15.0).collect();
+        Self::with_angles(ldt, &c_planes, &LUMINANCE_LIMIT_ANGLES)
+    }
+
+    /// Compute the table for explicit C-planes and viewing angles.
+    pub fn with_angles(ldt: &Eulumdat, c_planes: &[f64], viewing_angles: &[f64]) -> Self {
+        let values = viewing_angles
+            .iter()
+            .map(|&gamma| {
+                c_planes
+                    .iter()
+                    .map(|&c| luminance_at(ldt, c, gamma))
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            c_planes: c_planes.to_vec(),
+            viewing_angles: viewing_angles.to_vec(),
+            values,
+        }
+    }
+
+    /// Highest luminance in the table (cd/m²).
+    pub fn max_luminance(&self) -> f64 {
+        self.values.iter().flatten().copied().fold(0.0, f64::max)
+    }
+
+    /// Highest luminance at one viewing angle across all C-planes (cd/m²).
+    pub fn max_at(&self, viewing_angle: f64) -> Option<f64> {
+        self.viewing_angles
+            .iter()
+            .position(|&g| (g - viewing_angle).abs() < 1e-6)
+            .map(|i| self.values[i].iter().copied().fold(0.0, f64::max))
+    }
+
+    /// Whether every tabulated luminance is at or below `limit` (cd/m²).
+    pub fn complies(&self, limit: f64) -> bool {
+        self.max_luminance() <= limit
+    }
+
+    /// Format as a text table (rows = γ, columns = C-planes).
+    pub fn to_text(&self) -> String {
+        let mut out = String::from("γ \\ C");
+        for c in &self.c_planes {
+            out.push_str(&format!("{:>7.0}", c));
+        }
+        out.push('\n');
+        for (gamma, row) in self.viewing_angles.iter().zip(&self.values) {
+            out.push_str(&format!("{:>4.0}°", gamma));
+            for value in row {
+                out.push_str(&format!("{:>7.0}", value));
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LampSet, Symmetry};
+
+    fn flat_panel() -> Eulumdat {
+        Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            c_angles: vec![0.0],
+            g_angles: (0..=18).map(|i| i as f64 * 5.0).collect(),
+            // Lambertian: I(γ) = 300 cos γ
+            intensities: vec![(0..=18)
+                .map(|i| 300.0 * (i as f64 * 5.0).to_radians().cos())
+                .collect()],
+            luminous_area_length: 600.0,
+            luminous_area_width: 600.0,
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 4000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_lambertian_panel_has_constant_luminance() {
+        let ldt = flat_panel();
+        // 1200 cd / 0.36 m²
+        let expected = 1200.0 / 0.36;
+        for gamma in [0.0, 30.0, 65.0] {
+            let l = luminance_at(&ldt, 0.0, gamma);
+            assert!((l - expected).abs() / expected < 1e-3, "γ={gamma}: {l}");
+        }
+    }
+
+    #[test]
+    fn test_luminous_sides_reduce_luminance() {
+        let mut ldt = flat_panel();
+        let flat = luminance_at(&ldt, 90.0, 75.0);
+        ldt.height_c90 = 50.0;
+        ldt.height_c270 = 50.0;
+        assert!(luminance_at(&ldt, 90.0, 75.0) < flat);
+        // The C0 face has no height, so C0 is unchanged
+        assert!((luminance_at(&ldt, 0.0, 75.0) - flat).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_table_layout_and_limits() {
+        let table = LuminanceTable::from_eulumdat(&flat_panel());
+        assert_eq!(table.viewing_angles, LUMINANCE_LIMIT_ANGLES.to_vec());
+        assert_eq!(table.c_planes.len(), 24);
+        assert_eq!(table.values.len(), 5);
+        assert!(table.complies(LIMIT_MEDIUM_LUMINANCE_SCREEN * 3.0));
+        assert!(!table.complies(LIMIT_HIGH_LUMINANCE_SCREEN));
+        assert!(table.max_at(65.0).is_some());
+        assert!(table.to_text().lines().count() == 6);
+    }
+}
@@ -0,0 +1,231 @@
+//! Intensity statistics and average luminous exitance.
+//!
+//! Dashboards comparing many luminaires want a few numbers per file rather
+//! than the whole web: mean and median intensity, percentiles per
+//! hemisphere and the direction of the peak. All averages are weighted by
+//! solid angle, so a value describes the share of directions (not of
+//! measured grid points) with at most that intensity:
+//!
+//! ```text
+//! Ī = ∫ I dΩ / ∫ dΩ      dΩ = sin γ dγ dC
+//! ```
+//!
+//! The web is sampled on a 1° × 1° grid of cell midpoints with symmetry-aware
+//! interpolation; directions outside the measured γ range count as dark.
+
+use crate::calculations::luminance::projected_luminous_area;
+use crate::calculations::PhotometricCalculations;
+use crate::eulumdat::Eulumdat;
+
+/// Sampling step for the statistics in degrees.
+const STEP: f64 = 1.0;
+
+/// Solid-angle weighted statistics of one hemisphere, in cd/klm.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HemisphereStatistics {
+    /// Mean intensity
+    pub mean: f64,
+    /// 10th percentile
+    pub p10: f64,
+    /// Median intensity
+    pub median: f64,
+    /// 90th percentile
+    pub p90: f64,
+    /// Maximum intensity
+    pub max: f64,
+}
+
+/// Intensity statistics of a luminaire.
+///
+/// Intensities are in cd/klm like the distribution; the exitance is in
+/// lm/m² of luminaire flux.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntensityStatistics {
+    /// Mean intensity over the sphere (luminaire flux / 4π)
+    pub mean_intensity: f64,
+    /// Median intensity over the sphere
+    pub median_intensity: f64,
+    /// Lower hemisphere (γ 0-90°)
+    pub lower: HemisphereStatistics,
+    /// Upper hemisphere (γ 90-180°)
+    pub upper: HemisphereStatistics,
+    /// Highest measured intensity
+    pub peak_intensity: f64,
+    /// C-angle of the peak (degrees)
+    pub peak_c: f64,
+    /// Gamma of the peak (degrees)
+    pub peak_gamma: f64,
+    /// Unit vector towards the peak: x towards C0, y towards C90, z up
+    pub peak_direction: [f64; 3],
+    /// Luminaire flux per luminous area in lm/m² (0 without luminous area)
+    pub average_exitance: f64,
+}
+
+impl IntensityStatistics {
+    /// Compute the statistics of a distribution.
+    pub fn from_eulumdat(ldt: &Eulumdat) -> Self {
+        let (Some(&g_first), Some(&g_last)) = (ldt.g_angles.first(), ldt.g_angles.last()) else {
+            return Self::default();
+        };
+        if ldt.intensities.is_empty() {
+            return Self::default();
+        }
+
+        // (intensity, solid angle) of every sampling cell
+        let steps = (180.0 / STEP) as usize;
+        let mut lower = Vec::with_capacity(steps * steps);
+        let mut upper = Vec::with_capacity(steps * steps);
+        let cell = STEP.to_radians() * STEP.to_radians();
+        for gi in 0..steps {
+            let g = (gi as f64 + 0.5) * STEP;
+            let weight = g.to_radians().sin() * cell;
+            let in_range = g >= g_first && g <= g_last;
+            for ci in 0..2 * steps {
+                let c = (ci as f64 + 0.5) * STEP;
+                let value = if in_range { ldt.sample(c, g) } else { 0.0 };
+                if g < 90.0 {
+                    lower.push((value, weight));
+                } else {
+                    upper.push((value, weight));
+                }
+            }
+        }
+
+        let mut all: Vec<(f64, f64)> = lower.iter().chain(&upper).copied().collect();
+        let (mean_intensity, _) = weighted_mean(&all);
+        let median_intensity = weighted_percentile(&mut all, 0.5);
+
+        let (peak_intensity, peak_c, peak_gamma) = peak(ldt);
+        let (sin_g, cos_g) = peak_gamma.to_radians().sin_cos();
+        let (sin_c, cos_c) = peak_c.to_radians().sin_cos();
+
+        let area = projected_luminous_area(ldt, 0.0, 0.0);
+        let average_exitance = if area > 0.0 {
+            PhotometricCalculations::integrate_flux(ldt).luminaire_flux / area
+        } else {
+            0.0
+        };
+
+        Self {
+            mean_intensity,
+            median_intensity,
+            lower: hemisphere(&mut lower),
+            upper: hemisphere(&mut upper),
+            peak_intensity,
+            peak_c,
+            peak_gamma,
+            peak_direction: [sin_g * cos_c, sin_g * sin_c, -cos_g],
+            average_exitance,
+        }
+    }
+}
+
+/// Highest stored intensity with its (C, γ).
+fn peak(ldt: &Eulumdat) -> (f64, f64, f64) {
+    let mut best = (0.0, 0.0, 0.0);
+    for (row, c) in ldt.intensities.iter().zip(&ldt.c_angles) {
+        for (&value, &g) in row.iter().zip(&ldt.g_angles) {
+            if value > best.0 {
+                best = (value, *c, g);
+            }
+        }
+    }
+    best
+}
+
+fn hemisphere(samples: &mut [(f64, f64)]) -> HemisphereStatistics {
+    let (mean, max) = weighted_mean(samples);
+    HemisphereStatistics {
+        mean,
+        p10: weighted_percentile(samples, 0.1),
+        median: weighted_percentile(samples, 0.5),
+        p90: weighted_percentile(samples, 0.9),
+        max,
+    }
+}
+
+/// Weighted mean and maximum of `(value, weight)` samples.
+fn weighted_mean(samples: &[(f64, f64)]) -> (f64, f64) {
+    let total: f64 = samples.iter().map(|(_, w)| w).sum();
+    if total <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let sum: f64 = samples.iter().map(|(v, w)| v * w).sum();
+    let max = samples.iter().map(|(v, _)| *v).fold(0.0, f64::max);
+    (sum / total, max)
+}
+
+/// Smallest value below which at least `fraction` of the weight lies.
+fn weighted_percentile(samples: &mut [(f64, f64)], fraction: f64) -> f64 {
+    samples.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let total: f64 = samples.iter().map(|(_, w)| w).sum();
+    let mut cumulative = 0.0;
+    for &(value, weight) in samples.iter() {
+        cumulative += weight;
+        if cumulative >= fraction * total {
+            return value;
+        }
+    }
+    samples.last().map_or(0.0, |(v, _)| *v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eulumdat::{LampSet, Symmetry};
+
+    fn rotational(g_angles: Vec<f64>, intensity: impl Fn(f64) -> f64) -> Eulumdat {
+        let row = g_angles.iter().map(|&g| intensity(g)).collect();
+        Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            num_c_planes: 1,
+            num_g_planes: g_angles.len(),
+            c_angles: vec![0.0],
+            g_angles,
+            intensities: vec![row],
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 1000.0,
+                ..Default::default()
+            }],
+            luminous_area_length: 500.0,
+            luminous_area_width: 200.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_uniform_sphere() {
+        let ldt = rotational(vec![0.0, 90.0, 180.0], |_| 100.0);
+        let stats = IntensityStatistics::from_eulumdat(&ldt);
+
+        assert!((stats.mean_intensity - 100.0).abs() < 1e-9);
+        assert_eq!(stats.median_intensity, 100.0);
+        assert_eq!(stats.lower.p10, 100.0);
+        assert_eq!(stats.upper.p90, 100.0);
+        // 4π · 100 cd/klm · 1 klm over 0.1 m²
+        let flux = 4.0 * std::f64::consts::PI * 100.0;
+        assert!((stats.average_exitance - flux / 0.1).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_cosine_downlight() {
+        let g_angles = (0..=90).map(f64::from).collect();
+        let ldt = rotational(g_angles, |g| 1000.0 * g.to_radians().cos());
+        let stats = IntensityStatistics::from_eulumdat(&ldt);
+
+        // cos γ is uniform in solid angle over the lower hemisphere
+        assert!((stats.lower.mean - 500.0).abs() < 1.0);
+        assert!((stats.lower.median - 500.0).abs() < 10.0);
+        assert!((stats.lower.p10 - 100.0).abs() < 10.0);
+        assert!((stats.lower.p90 - 900.0).abs() < 10.0);
+        assert!((stats.mean_intensity - 250.0).abs() < 1.0);
+        assert_eq!(stats.upper, HemisphereStatistics::default());
+
+        assert_eq!(stats.peak_intensity, 1000.0);
+        assert_eq!(stats.peak_gamma, 0.0);
+        assert_eq!(stats.peak_direction, [0.0, 0.0, -1.0]);
+    }
+}
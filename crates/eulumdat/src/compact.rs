@@ -0,0 +1,505 @@
+//! Compact binary serialization for caching and transfer.
+//!
+//! LDT text and JSON spend most of their bytes on the intensity matrix. The
+//! compact format stores header fields as-is and the matrix as `f32` deltas
+//! along each C-plane, which are small for smooth distributions and compress
+//! well. With the `zstd` feature the payload can additionally be compressed,
+//! e.g. for IndexedDB storage or server responses.
+//!
+//! Angles and intensities are stored with `f32` precision (about 7
+//! significant digits); all other numbers, including the photometric center,
+//! round-trip exactly.
+//!
+//! ```text
+//! magic (4) | version (1) | compression (1) | payload
+//! ```
+//!
+//! Version 2 added the photometric center. Version 1 data still decodes,
+//! without one.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use eulumdat::{compact::Compression, Eulumdat};
+//!
+//! let ldt = Eulumdat::from_file("luminaire.ldt").unwrap();
+//! let bytes = ldt.to_compact_bytes(Compression::None);
+//! let restored = Eulumdat::from_compact_bytes(&bytes).unwrap();
+//! assert_eq!(restored.luminaire_name, ldt.luminaire_name);
+//! ```
+
+use crate::error::{anyhow, Result};
+use crate::eulumdat::{Eulumdat, LampSet, PhotometricCenter, Symmetry, TypeIndicator};
+
+/// Magic bytes of a compact [`Eulumdat`].
+const MAGIC_EULUMDAT: &[u8; 4] = b"LDTC";
+/// Magic bytes of a compact [`IntensityGrid`].
+const MAGIC_GRID: &[u8; 4] = b"LDTG";
+/// Current format version.
+const VERSION: u8 = 2;
+/// Oldest version that can still be decoded.
+const MIN_VERSION: u8 = 1;
+
+/// Compression applied to the payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    /// Delta encoding only
+    #[default]
+    None,
+    /// Delta encoding followed by zstd at the given level (1–22)
+    #[cfg(feature = "zstd")]
+    Zstd(i32),
+}
+
+impl Compression {
+    fn tag(self) -> u8 {
+        match self {
+            Self::None => 0,
+            #[cfg(feature = "zstd")]
+            Self::Zstd(_) => 1,
+        }
+    }
+}
+
+/// Symmetry, angles and intensities without the descriptive header.
+///
+/// This is the part of the data needed for sampling and rendering, e.g. by
+/// `eulumdat_photweb::PhotometricWeb`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IntensityGrid {
+    /// Symmetry of the stored planes
+    pub symmetry: Symmetry,
+    /// C-plane angles in degrees
+    pub c_angles: Vec<f64>,
+    /// Gamma angles in degrees
+    pub g_angles: Vec<f64>,
+    /// Intensities in cd/klm, `[c_index][g_index]`
+    pub intensities: Vec<Vec<f64>>,
+}
+
+impl IntensityGrid {
+    /// Encode to the compact binary format.
+    pub fn to_bytes(&self, compression: Compression) -> Vec<u8> {
+        let mut w = ByteWriter::default();
+        write_grid(
+            &mut w,
+            self.symmetry,
+            &self.c_angles,
+            &self.g_angles,
+            &self.intensities,
+        );
+        finish(MAGIC_GRID, w.bytes, compression)
+    }
+
+    /// Decode from the compact binary format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (_, payload) = open(MAGIC_GRID, bytes)?;
+        let mut r = ByteReader::new(&payload);
+        read_grid(&mut r)
+    }
+}
+
+impl From<&Eulumdat> for IntensityGrid {
+    fn from(ldt: &Eulumdat) -> Self {
+        Self {
+            symmetry: ldt.symmetry,
+            c_angles: ldt.c_angles.clone(),
+            g_angles: ldt.g_angles.clone(),
+            intensities: ldt.intensities.clone(),
+        }
+    }
+}
+
+impl Eulumdat {
+    /// Encode to the compact binary format (see [`crate::compact`]).
+    pub fn to_compact_bytes(&self, compression: Compression) -> Vec<u8> {
+        let mut w = ByteWriter::default();
+        for text in [
+            &self.identification,
+            &self.measurement_report_number,
+            &self.luminaire_name,
+            &self.luminaire_number,
+            &self.file_name,
+            &self.date_user,
+        ] {
+            w.str(text);
+        }
+        w.u8(self.type_indicator.as_int() as u8);
+        w.u32(self.num_c_planes as u32);
+        w.f64(self.c_plane_distance);
+        w.u32(self.num_g_planes as u32);
+        w.f64(self.g_plane_distance);
+        for value in [
+            self.length,
+            self.width,
+            self.height,
+            self.luminous_area_length,
+            self.luminous_area_width,
+            self.height_c0,
+            self.height_c90,
+            self.height_c180,
+            self.height_c270,
+            self.downward_flux_fraction,
+            self.light_output_ratio,
+            self.conversion_factor,
+            self.tilt_angle,
+        ] {
+            w.f64(value);
+        }
+        w.u32(self.lamp_sets.len() as u32);
+        for lamp_set in &self.lamp_sets {
+            w.u32(lamp_set.num_lamps as u32);
+            w.str(&lamp_set.lamp_type);
+            w.f64(lamp_set.total_luminous_flux);
+            w.str(&lamp_set.color_appearance);
+            w.str(&lamp_set.color_rendering_group);
+            w.f64(lamp_set.wattage_with_ballast);
+        }
+        for ratio in self.direct_ratios {
+            w.f64(ratio);
+        }
+        match self.photometric_center {
+            Some(center) => {
+                w.u8(1);
+                for value in [center.x, center.y, center.z] {
+                    w.f64(value);
+                }
+            }
+            None => w.u8(0),
+        }
+        write_grid(
+            &mut w,
+            self.symmetry,
+            &self.c_angles,
+            &self.g_angles,
+            &self.intensities,
+        );
+        finish(MAGIC_EULUMDAT, w.bytes, compression)
+    }
+
+    /// Decode from the compact binary format (see [`crate::compact`]).
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self> {
+        let (version, payload) = open(MAGIC_EULUMDAT, bytes)?;
+        let mut r = ByteReader::new(&payload);
+
+        let mut ldt = Eulumdat {
+            identification: r.str()?,
+            measurement_report_number: r.str()?,
+            luminaire_name: r.str()?,
+            luminaire_number: r.str()?,
+            file_name: r.str()?,
+            date_user: r.str()?,
+            type_indicator: TypeIndicator::from_int(r.u8()? as i32)?,
+            num_c_planes: r.u32()? as usize,
+            c_plane_distance: r.f64()?,
+            num_g_planes: r.u32()? as usize,
+            g_plane_distance: r.f64()?,
+            ..Default::default()
+        };
+        for value in [
+            &mut ldt.length,
+            &mut ldt.width,
+            &mut ldt.height,
+            &mut ldt.luminous_area_length,
+            &mut ldt.luminous_area_width,
+            &mut ldt.height_c0,
+            &mut ldt.height_c90,
+            &mut ldt.height_c180,
+            &mut ldt.height_c270,
+            &mut ldt.downward_flux_fraction,
+            &mut ldt.light_output_ratio,
+            &mut ldt.conversion_factor,
+            &mut ldt.tilt_angle,
+        ] {
+            *value = r.f64()?;
+        }
+        let lamp_sets = r.u32()?;
+        for _ in 0..lamp_sets {
+            ldt.lamp_sets.push(LampSet {
+                num_lamps: r.u32()? as i32,
+                lamp_type: r.str()?,
+                total_luminous_flux: r.f64()?,
+                color_appearance: r.str()?,
+                color_rendering_group: r.str()?,
+                wattage_with_ballast: r.f64()?,
+            });
+        }
+        for ratio in &mut ldt.direct_ratios {
+            *ratio = r.f64()?;
+        }
+        if version >= 2 && r.u8()? != 0 {
+            ldt.photometric_center = Some(PhotometricCenter::new(r.f64()?, r.f64()?, r.f64()?));
+        }
+        let grid = read_grid(&mut r)?;
+        ldt.symmetry = grid.symmetry;
+        ldt.c_angles = grid.c_angles;
+        ldt.g_angles = grid.g_angles;
+        ldt.intensities = grid.intensities;
+        Ok(ldt)
+    }
+}
+
+fn write_grid(
+    w: &mut ByteWriter,
+    symmetry: Symmetry,
+    c_angles: &[f64],
+    g_angles: &[f64],
+    intensities: &[Vec<f64>],
+) {
+    w.u8(symmetry.as_int() as u8);
+    for angles in [c_angles, g_angles] {
+        w.u32(angles.len() as u32);
+        angles.iter().for_each(|&a| w.f32(a as f32));
+    }
+    w.u32(intensities.len() as u32);
+    for row in intensities {
+        w.u32(row.len() as u32);
+        // Deltas against the value the decoder will reconstruct, so f32
+        // rounding does not accumulate along the plane
+        let mut previous = 0.0f32;
+        for &value in row {
+            let delta = value as f32 - previous;
+            w.f32(delta);
+            previous += delta;
+        }
+    }
+}
+
+fn read_grid(r: &mut ByteReader) -> Result<IntensityGrid> {
+    let symmetry = Symmetry::from_int(r.u8()? as i32)?;
+    let mut angles = [Vec::new(), Vec::new()];
+    for list in &mut angles {
+        let n = r.len()?;
+        for _ in 0..n {
+            list.push(r.f32()? as f64);
+        }
+    }
+    let [c_angles, g_angles] = angles;
+
+    let rows = r.len()?;
+    let mut intensities = Vec::with_capacity(rows);
+    for _ in 0..rows {
+        let n = r.len()?;
+        let mut row = Vec::with_capacity(n);
+        let mut value = 0.0f32;
+        for _ in 0..n {
+            value += r.f32()?;
+            row.push(value as f64);
+        }
+        intensities.push(row);
+    }
+    Ok(IntensityGrid {
+        symmetry,
+        c_angles,
+        g_angles,
+        intensities,
+    })
+}
+
+/// Prefix the payload with the header and compress it.
+fn finish(magic: &[u8; 4], payload: Vec<u8>, compression: Compression) -> Vec<u8> {
+    let mut out = Vec::with_capacity(payload.len() + 6);
+    out.extend_from_slice(magic);
+    out.push(VERSION);
+    out.push(compression.tag());
+    match compression {
+        Compression::None => out.extend_from_slice(&payload),
+        #[cfg(feature = "zstd")]
+        Compression::Zstd(level) => out.extend_from_slice(
+            &zstd::bulk::compress(&payload, level).expect("zstd compression of in-memory data"),
+        ),
+    }
+    out
+}
+
+/// Check the header and return the format version and the decompressed
+/// payload.
+fn open(magic: &[u8; 4], bytes: &[u8]) -> Result<(u8, Vec<u8>)> {
+    if bytes.len() < 6 || &bytes[..4] != magic {
+        return Err(anyhow!("Not a compact photometric file"));
+    }
+    let version = bytes[4];
+    if !(MIN_VERSION..=VERSION).contains(&version) {
+        return Err(anyhow!("Unsupported compact format version {}", version));
+    }
+    let payload = &bytes[6..];
+    match bytes[5] {
+        0 => Ok((version, payload.to_vec())),
+        #[cfg(feature = "zstd")]
+        1 => Ok((version, zstd::stream::decode_all(payload)?)),
+        #[cfg(not(feature = "zstd"))]
+        1 => Err(anyhow!(
+            "Compact data is zstd-compressed; enable the `zstd` feature"
+        )),
+        tag => Err(anyhow!("Unknown compression {}", tag)),
+    }
+}
+
+#[derive(Default)]
+struct ByteWriter {
+    bytes: Vec<u8>,
+}
+
+impl ByteWriter {
+    fn u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn f64(&mut self, value: f64) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn str(&mut self, value: &str) {
+        self.u32(value.len() as u32);
+        self.bytes.extend_from_slice(value.as_bytes());
+    }
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let end = self.pos + N;
+        let chunk = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or_else(|| anyhow!("Compact data truncated at byte {}", self.pos))?;
+        self.pos = end;
+        Ok(chunk.try_into().expect("slice has length N"))
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+
+    /// A length prefix, checked against the remaining data.
+    fn len(&mut self) -> Result<usize> {
+        let n = self.u32()? as usize;
+        if n > self.bytes.len() - self.pos {
+            return Err(anyhow!("Compact data has invalid length {}", n));
+        }
+        Ok(n)
+    }
+
+    fn f32(&mut self) -> Result<f32> {
+        Ok(f32::from_le_bytes(self.take()?))
+    }
+
+    fn f64(&mut self) -> Result<f64> {
+        Ok(f64::from_le_bytes(self.take()?))
+    }
+
+    fn str(&mut self) -> Result<String> {
+        let n = self.len()?;
+        let text = std::str::from_utf8(&self.bytes[self.pos..self.pos + n])?.to_string();
+        self.pos += n;
+        Ok(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn road_luminaire() -> Eulumdat {
+        Eulumdat::parse(include_str!(
+            "../../eulumdat-wasm/templates/road_luminaire.ldt"
+        ))
+        .unwrap()
+    }
+
+    fn assert_close(a: &[Vec<f64>], b: &[Vec<f64>]) {
+        assert_eq!(a.len(), b.len());
+        for (ra, rb) in a.iter().zip(b) {
+            assert_eq!(ra.len(), rb.len());
+            for (x, y) in ra.iter().zip(rb) {
+                assert!((x - y).abs() <= 1e-5 * x.abs().max(1.0), "{x} vs {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_eulumdat_roundtrip() {
+        let ldt = road_luminaire();
+        let bytes = ldt.to_compact_bytes(Compression::None);
+        let restored = Eulumdat::from_compact_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.identification, ldt.identification);
+        assert_eq!(restored.lamp_sets, ldt.lamp_sets);
+        assert_eq!(restored.direct_ratios, ldt.direct_ratios);
+        assert_eq!(restored.symmetry, ldt.symmetry);
+        assert_eq!(restored.c_angles, ldt.c_angles);
+        assert_close(&restored.intensities, &ldt.intensities);
+        assert_eq!(restored.photometric_center, None);
+    }
+
+    #[test]
+    fn test_photometric_center_roundtrip() {
+        let mut ldt = road_luminaire();
+        ldt.photometric_center = Some(PhotometricCenter::new(120.5, -3.25, -35.0));
+        let bytes = ldt.to_compact_bytes(Compression::None);
+        assert_eq!(bytes[4], VERSION);
+        let restored = Eulumdat::from_compact_bytes(&bytes).unwrap();
+        assert_eq!(restored.photometric_center, ldt.photometric_center);
+        assert_eq!(restored.lamp_sets, ldt.lamp_sets);
+
+        // Version 1 data has no center: drop the flag and the three values
+        let mut v1 = road_luminaire().to_compact_bytes(Compression::None);
+        v1[4] = 1;
+        let flag = v1.len() - grid_len(&ldt) - 1;
+        assert_eq!(v1.remove(flag), 0);
+        let restored = Eulumdat::from_compact_bytes(&v1).unwrap();
+        assert_eq!(restored.photometric_center, None);
+        assert_eq!(restored.direct_ratios, ldt.direct_ratios);
+        assert_close(&restored.intensities, &ldt.intensities);
+    }
+
+    /// Encoded size of the intensity grid at the end of a compact file
+    fn grid_len(ldt: &Eulumdat) -> usize {
+        IntensityGrid::from(ldt).to_bytes(Compression::None).len() - 6
+    }
+
+    #[test]
+    fn test_grid_roundtrip_and_errors() {
+        let grid = IntensityGrid::from(&road_luminaire());
+        let bytes = grid.to_bytes(Compression::None);
+        let restored = IntensityGrid::from_bytes(&bytes).unwrap();
+        assert_close(&restored.intensities, &grid.intensities);
+
+        // Wrong magic, truncated data
+        assert!(Eulumdat::from_compact_bytes(&bytes).is_err());
+        assert!(IntensityGrid::from_bytes(&bytes[..bytes.len() / 2]).is_err());
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd_compression() {
+        let ldt = road_luminaire();
+        let plain = ldt.to_compact_bytes(Compression::None);
+        let packed = ldt.to_compact_bytes(Compression::Zstd(3));
+        assert!(packed.len() < plain.len());
+        assert!(packed.len() < ldt.to_ldt().len());
+
+        let restored = Eulumdat::from_compact_bytes(&packed).unwrap();
+        assert_close(&restored.intensities, &ldt.intensities);
+    }
+}
@@ -16,8 +16,8 @@
 //! println!("Similarity: {:.1}%", cmp.similarity_score * 100.0);
 //! ```
 
-use crate::bug_rating::BugDiagram;
-use crate::calculations::PhotometricSummary;
+use crate::bug_rating::{BugDiagram, BugRating};
+use crate::calculations::{PhotometricSummary, UgrTable};
 use crate::eulumdat::Eulumdat;
 use crate::units::UnitSystem;
 
@@ -376,6 +376,263 @@ impl PhotometricComparison {
         }
         out
     }
+
+    /// Format as a Markdown table.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "| Metric | {} | {} | Delta | % | Significance |\n|---|---:|---:|---:|---:|---|\n",
+            markdown_cell(&self.label_a),
+            markdown_cell(&self.label_b)
+        );
+        for m in &self.metrics {
+            let unit = if m.unit.is_empty() {
+                String::new()
+            } else {
+                format!(" {}", m.unit)
+            };
+            out.push_str(&format!(
+                "| {} | {:.1}{unit} | {:.1}{unit} | {:+.1} | {:+.1}% | {} |\n",
+                m.name, m.value_a, m.value_b, m.delta, m.delta_percent, m.significance,
+            ));
+        }
+        out
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Catalog overview
+// ---------------------------------------------------------------------------
+
+/// Column of a [`CatalogOverview`], used for sorting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OverviewColumn {
+    /// File label
+    Name,
+    /// Luminaire flux (lm)
+    Flux,
+    /// System power (W)
+    Wattage,
+    /// Luminaire efficacy (lm/W)
+    Efficacy,
+    /// Beam angle (degrees)
+    BeamAngle,
+    /// Field angle (degrees)
+    FieldAngle,
+    /// UGR 4H×8H, 70/50/20
+    Ugr,
+    /// BUG rating
+    Bug,
+}
+
+/// Key metrics of one file in a [`CatalogOverview`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverviewRow {
+    /// Label, usually the file name
+    pub label: String,
+    /// Luminaire name from the file
+    pub luminaire_name: String,
+    /// Luminaire flux: lamp flux × LOR (lm)
+    pub flux_lm: f64,
+    /// System power (W)
+    pub wattage_w: f64,
+    /// Luminaire efficacy (lm/W)
+    pub efficacy_lm_w: f64,
+    /// Beam angle, IES definition (degrees)
+    pub beam_angle_deg: f64,
+    /// Field angle, IES definition (degrees)
+    pub field_angle_deg: f64,
+    /// Higher of the crosswise and endwise UGR for the 4H×8H room at 70/50/20
+    pub ugr: Option<f64>,
+    /// BUG rating
+    pub bug_rating: BugRating,
+}
+
+impl OverviewRow {
+    /// Collect the overview metrics of one file.
+    pub fn from_eulumdat(ldt: &Eulumdat, label: &str) -> Self {
+        let summary = PhotometricSummary::from_eulumdat(ldt);
+        let ugr = UgrTable::calculate(ldt)
+            .standard_room()
+            .map(|ugr| ugr.crosswise.max(ugr.endwise));
+        Self {
+            label: label.to_string(),
+            luminaire_name: ldt.luminaire_name.trim().to_string(),
+            flux_lm: summary.total_lamp_flux * summary.lor / 100.0,
+            wattage_w: summary.total_wattage,
+            efficacy_lm_w: summary.luminaire_efficacy,
+            beam_angle_deg: summary.beam_angle,
+            field_angle_deg: summary.field_angle,
+            ugr,
+            bug_rating: BugRating::from_eulumdat(ldt),
+        }
+    }
+
+    fn cmp_by(&self, other: &Self, column: OverviewColumn) -> std::cmp::Ordering {
+        let bug = |r: &BugRating| (r.b, r.u, r.g);
+        match column {
+            OverviewColumn::Name => self.label.cmp(&other.label),
+            OverviewColumn::Flux => self.flux_lm.total_cmp(&other.flux_lm),
+            OverviewColumn::Wattage => self.wattage_w.total_cmp(&other.wattage_w),
+            OverviewColumn::Efficacy => self.efficacy_lm_w.total_cmp(&other.efficacy_lm_w),
+            OverviewColumn::BeamAngle => self.beam_angle_deg.total_cmp(&other.beam_angle_deg),
+            OverviewColumn::FieldAngle => self.field_angle_deg.total_cmp(&other.field_angle_deg),
+            // Files without a UGR value sort last
+            OverviewColumn::Ugr => match (self.ugr, other.ugr) {
+                (Some(a), Some(b)) => a.total_cmp(&b),
+                (a, b) => b.is_some().cmp(&a.is_some()),
+            },
+            OverviewColumn::Bug => bug(&self.bug_rating).cmp(&bug(&other.bug_rating)),
+        }
+    }
+
+    fn ugr_text(&self) -> String {
+        self.ugr.map(|ugr| format!("{ugr:.1}")).unwrap_or_default()
+    }
+}
+
+/// Overview table of many photometric files, e.g. a product catalog.
+///
+/// ```rust,no_run
+/// use eulumdat::{CatalogOverview, Eulumdat, OverviewColumn, OverviewRow};
+///
+/// let rows = ["a.ldt", "b.ldt"]
+///     .iter()
+///     .map(|path| Ok(OverviewRow::from_eulumdat(&Eulumdat::from_file(path)?, path)))
+///     .collect::<eulumdat::Result<Vec<_>>>()?;
+/// let mut overview = CatalogOverview::new(rows);
+/// overview.sort_by(OverviewColumn::Efficacy, true);
+/// println!("{}", overview.to_markdown());
+/// # Ok::<(), eulumdat::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CatalogOverview {
+    /// One row per file
+    pub rows: Vec<OverviewRow>,
+}
+
+impl CatalogOverview {
+    /// Column headers of the text, CSV and Markdown tables
+    const HEADERS: [&'static str; 9] = [
+        "File",
+        "Luminaire",
+        "Flux (lm)",
+        "Power (W)",
+        "Efficacy (lm/W)",
+        "Beam (°)",
+        "Field (°)",
+        "UGR",
+        "BUG",
+    ];
+
+    /// Overview of the given rows.
+    pub fn new(rows: Vec<OverviewRow>) -> Self {
+        Self { rows }
+    }
+
+    /// Sort the rows by `column`, largest first if `descending`.
+    ///
+    /// The sort is stable, so rows with equal values keep their order.
+    pub fn sort_by(&mut self, column: OverviewColumn, descending: bool) {
+        self.rows.sort_by(|a, b| {
+            let order = a.cmp_by(b, column);
+            if descending {
+                order.reverse()
+            } else {
+                order
+            }
+        });
+    }
+
+    fn cells(row: &OverviewRow) -> [String; 9] {
+        [
+            row.label.clone(),
+            row.luminaire_name.clone(),
+            format!("{:.0}", row.flux_lm),
+            format!("{:.1}", row.wattage_w),
+            format!("{:.1}", row.efficacy_lm_w),
+            format!("{:.1}", row.beam_angle_deg),
+            format!("{:.1}", row.field_angle_deg),
+            row.ugr_text(),
+            row.bug_rating.to_string(),
+        ]
+    }
+
+    /// Format as an aligned text table.
+    pub fn to_text(&self) -> String {
+        let rows: Vec<[String; 9]> = self.rows.iter().map(Self::cells).collect();
+        let widths: Vec<usize> = (0..9)
+            .map(|i| {
+                rows.iter()
+                    .map(|row| row[i].chars().count())
+                    .chain([Self::HEADERS[i].chars().count()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let line = |cells: &[String]| {
+            let mut out = String::new();
+            for (i, cell) in cells.iter().enumerate() {
+                let pad = widths[i] - cell.chars().count();
+                // Names left-aligned, numbers right-aligned
+                if i < 2 || i == 8 {
+                    out.push_str(cell);
+                    out.push_str(&" ".repeat(pad));
+                } else {
+                    out.push_str(&" ".repeat(pad));
+                    out.push_str(cell);
+                }
+                out.push_str("  ");
+            }
+            format!("{}\n", out.trim_end())
+        };
+
+        let mut out = line(&Self::HEADERS.map(String::from));
+        out.push_str(&line(
+            &widths.iter().map(|&w| "-".repeat(w)).collect::<Vec<_>>(),
+        ));
+        for row in &rows {
+            out.push_str(&line(row));
+        }
+        out
+    }
+
+    /// Format as CSV with a header row.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(
+            "file,luminaire,flux_lm,wattage_w,efficacy_lm_w,beam_angle_deg,field_angle_deg,ugr,bug\n",
+        );
+        for row in &self.rows {
+            out.push_str(&format!(
+                "\"{}\",\"{}\",{:.1},{:.2},{:.2},{:.2},{:.2},{},{}\n",
+                row.label.replace('"', "\"\""),
+                row.luminaire_name.replace('"', "\"\""),
+                row.flux_lm,
+                row.wattage_w,
+                row.efficacy_lm_w,
+                row.beam_angle_deg,
+                row.field_angle_deg,
+                row.ugr_text(),
+                row.bug_rating,
+            ));
+        }
+        out
+    }
+
+    /// Format as a Markdown table.
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "| {} |\n|---|---|---:|---:|---:|---:|---:|---:|---|\n",
+            Self::HEADERS.join(" | ")
+        );
+        for row in &self.rows {
+            let cells = Self::cells(row).map(|cell| markdown_cell(&cell));
+            out.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+        out
+    }
 }
 
 impl std::fmt::Display for PhotometricComparison {
@@ -388,6 +645,11 @@ impl std::fmt::Display for PhotometricComparison {
 // Internal helpers
 // ---------------------------------------------------------------------------
 
+/// Escape pipes so text stays in one Markdown table cell
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
 fn metric(name: &str, key: &str, unit: &str, a: f64, b: f64, weight: f64) -> ComparisonMetric {
     let delta = b - a;
     let delta_percent = if a.abs() > 1e-9 {
@@ -1060,4 +1322,68 @@ mod tests {
             assert_eq!(m.significance, Significance::Major);
         }
     }
+
+    fn overview_ldt(name: &str, flux: f64, wattage: f64, spread: f64) -> Eulumdat {
+        Eulumdat {
+            luminaire_name: name.to_string(),
+            symmetry: crate::Symmetry::VerticalAxis,
+            c_angles: vec![0.0],
+            g_angles: (0..=18).map(|i| i as f64 * 5.0).collect(),
+            intensities: vec![(0..=18)
+                .map(|i| 500.0 * (-(i as f64 * 5.0 / spread).powi(2)).exp())
+                .collect()],
+            light_output_ratio: 100.0,
+            luminous_area_length: 200.0,
+            luminous_area_width: 200.0,
+            lamp_sets: vec![crate::LampSet {
+                num_lamps: 1,
+                total_luminous_flux: flux,
+                wattage_with_ballast: wattage,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn catalog_overview_sorts_by_column() {
+        let mut overview = CatalogOverview::new(vec![
+            OverviewRow::from_eulumdat(&overview_ldt("Narrow", 2000.0, 20.0, 15.0), "a.ldt"),
+            OverviewRow::from_eulumdat(&overview_ldt("Wide", 3000.0, 20.0, 45.0), "b.ldt"),
+            OverviewRow::from_eulumdat(&overview_ldt("Small", 1000.0, 20.0, 30.0), "c.ldt"),
+        ]);
+        assert_eq!(overview.rows[1].flux_lm, 3000.0);
+        assert_eq!(overview.rows[1].efficacy_lm_w, 150.0);
+        assert!(overview.rows.iter().all(|row| row.ugr.is_some()));
+
+        overview.sort_by(OverviewColumn::Efficacy, true);
+        let labels: Vec<&str> = overview.rows.iter().map(|r| r.label.as_str()).collect();
+        assert_eq!(labels, ["b.ldt", "a.ldt", "c.ldt"]);
+
+        overview.sort_by(OverviewColumn::BeamAngle, false);
+        assert_eq!(overview.rows[0].luminaire_name, "Narrow");
+        assert_eq!(overview.rows[2].luminaire_name, "Wide");
+    }
+
+    #[test]
+    fn catalog_overview_tables() {
+        let mut row =
+            OverviewRow::from_eulumdat(&overview_ldt("A|B \"x\"", 2000.0, 20.0, 20.0), "a.ldt");
+        row.ugr = None;
+        let overview = CatalogOverview::new(vec![row]);
+
+        let csv = overview.to_csv();
+        assert!(csv.starts_with("file,luminaire,flux_lm,"));
+        assert!(csv.contains("\"a.ldt\",\"A|B \"\"x\"\"\",2000.0,20.00,100.00,"));
+        // A rotationally symmetric downlight sends half its light backwards
+        assert!(csv.trim_end().ends_with(",,B1 U0 G0"));
+
+        let markdown = overview.to_markdown();
+        assert_eq!(markdown.lines().count(), 3);
+        assert!(markdown.contains("| a.ldt | A\\|B \"x\" | 2000 |"));
+
+        let text = overview.to_text();
+        assert!(text.lines().next().unwrap().starts_with("File   Luminaire"));
+        assert_eq!(text.lines().count(), 3);
+    }
 }
@@ -0,0 +1,318 @@
+//! Measurement dates in the free-form date/user field.
+//!
+//! EULUMDAT line 12 and the IES `[ISSUEDATE]` keyword are free text, so
+//! dates appear as `2024-01-15`, `15.01.2024`, `15-JAN-2024`, `Jan 15, 2024`
+//! or `20240115`, often followed by a user name. [`MeasurementDate::find`]
+//! locates the first date in such a string so libraries can be sorted and
+//! filtered by measurement date.
+//!
+//! Numeric dates without a year first are read day-first (European order),
+//! unless the second number can only be a day.
+
+use std::ops::Range;
+
+/// Earliest year accepted as a plausible measurement date.
+pub const MIN_PLAUSIBLE_YEAR: u16 = 1950;
+
+/// Latest year accepted as a plausible measurement date.
+pub const MAX_PLAUSIBLE_YEAR: u16 = 2100;
+
+const MONTH_ABBREVIATIONS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+const MONTH_NAMES: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// A calendar date parsed from a date/user field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeasurementDate {
+    /// Year (four digits)
+    pub year: u16,
+    /// Month (1–12)
+    pub month: u8,
+    /// Day of month (1–31)
+    pub day: u8,
+}
+
+impl MeasurementDate {
+    /// Create a date, returning `None` if the day does not exist.
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// Parse the first date found in free-form text.
+    pub fn parse(text: &str) -> Option<Self> {
+        Self::find(text).map(|(date, _)| date)
+    }
+
+    /// Find the first date in free-form text and its byte range.
+    pub fn find(text: &str) -> Option<(Self, Range<usize>)> {
+        let tokens = tokenize(text);
+
+        for i in 0..tokens.len() {
+            // Compact YYYYMMDD
+            let t = &tokens[i];
+            if let Some(value) = t.number().filter(|_| t.text.len() == 8) {
+                let (year, month, day) = (value / 10000, value / 100 % 100, value % 100);
+                if let Some(date) = Self::new(year as u16, month as u8, day as u8) {
+                    return Some((date, t.span.clone()));
+                }
+            }
+
+            let Some(window) = tokens.get(i..i + 3) else {
+                continue;
+            };
+            if !separated_by_punctuation(text, window) {
+                continue;
+            }
+            if let Some(date) = Self::from_parts(&window[0], &window[1], &window[2]) {
+                return Some((date, window[0].span.start..window[2].span.end));
+            }
+        }
+        None
+    }
+
+    /// Interpret three adjacent tokens as a date.
+    fn from_parts(a: &Token, b: &Token, c: &Token) -> Option<Self> {
+        // YYYY-MM-DD, YYYY-MON-DD
+        if let Some(year) = a.number().filter(|_| a.text.len() == 4) {
+            let month = b.month().or_else(|| b.day())?;
+            return Self::new(year as u16, month, c.day()?);
+        }
+
+        let year = c.year()?;
+        // DD-MON-YYYY
+        if let Some(month) = b.month() {
+            return Self::new(year, month, a.day()?);
+        }
+        // MON DD, YYYY
+        if let Some(month) = a.month() {
+            return Self::new(year, month, b.day()?);
+        }
+        // DD.MM.YYYY, or MM/DD/YYYY when the second number cannot be a month
+        let (first, second) = (a.day()?, b.day()?);
+        if second > 12 {
+            Self::new(year, first, second)
+        } else {
+            Self::new(year, second, first)
+        }
+    }
+
+    /// Whether the year lies between [`MIN_PLAUSIBLE_YEAR`] and [`MAX_PLAUSIBLE_YEAR`].
+    pub fn is_plausible(&self) -> bool {
+        (MIN_PLAUSIBLE_YEAR..=MAX_PLAUSIBLE_YEAR).contains(&self.year)
+    }
+
+    /// ISO 8601 form (`2024-01-15`).
+    pub fn to_iso(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    /// IES `[ISSUEDATE]` form (`15-JAN-2024`).
+    pub fn to_ies(&self) -> String {
+        format!(
+            "{:02}-{}-{:04}",
+            self.day,
+            MONTH_ABBREVIATIONS[self.month as usize - 1],
+            self.year
+        )
+    }
+}
+
+impl std::fmt::Display for MeasurementDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_iso())
+    }
+}
+
+/// Replace the first date in `text` by its ISO form, keeping the rest.
+///
+/// Returns `None` when no date is found.
+pub fn normalize_date_text(text: &str) -> Option<String> {
+    let (date, span) = MeasurementDate::find(text)?;
+    Some(format!(
+        "{}{}{}",
+        &text[..span.start],
+        date.to_iso(),
+        &text[span.end..]
+    ))
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// An alphanumeric run in the input.
+struct Token<'a> {
+    text: &'a str,
+    span: Range<usize>,
+}
+
+impl Token<'_> {
+    fn number(&self) -> Option<u32> {
+        if self.text.bytes().all(|b| b.is_ascii_digit()) {
+            self.text.parse().ok()
+        } else {
+            None
+        }
+    }
+
+    fn day(&self) -> Option<u8> {
+        if self.text.len() > 2 {
+            return None;
+        }
+        self.number().map(|d| d as u8)
+    }
+
+    fn year(&self) -> Option<u16> {
+        let value = self.number()? as u16;
+        match self.text.len() {
+            4 => Some(value),
+            // Two-digit years: 70–99 → 19xx, 00–69 → 20xx
+            2 if value >= 70 => Some(1900 + value),
+            2 => Some(2000 + value),
+            _ => None,
+        }
+    }
+
+    /// English or German month name or abbreviation.
+    fn month(&self) -> Option<u8> {
+        let lower = self.text.to_lowercase();
+        let month = match lower.as_str() {
+            "mrz" | "märz" | "mär" => 3,
+            "mai" => 5,
+            "okt" | "oktober" => 10,
+            "dez" | "dezember" => 12,
+            "januar" => 1,
+            "februar" => 2,
+            "juni" => 6,
+            "juli" => 7,
+            // Any prefix of an English name from three letters ("Sept", "Jan")
+            _ if lower.len() >= 3 => {
+                MONTH_NAMES
+                    .iter()
+                    .position(|name| name.starts_with(&lower))? as u8
+                    + 1
+            }
+            _ => return None,
+        };
+        Some(month)
+    }
+}
+
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, ch) in text.char_indices() {
+        match (ch.is_alphanumeric(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                tokens.push(Token {
+                    text: &text[s..i],
+                    span: s..i,
+                });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        tokens.push(Token {
+            text: &text[s..],
+            span: s..text.len(),
+        });
+    }
+    tokens
+}
+
+/// Whether the tokens are only separated by short date punctuation.
+fn separated_by_punctuation(text: &str, window: &[Token]) -> bool {
+    window.windows(2).all(|pair| {
+        let gap = &text[pair[0].span.end..pair[1].span.start];
+        !gap.is_empty()
+            && gap.len() <= 3
+            && gap
+                .chars()
+                .all(|c| matches!(c, '.' | '-' | '/' | ',' | ' '))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: u16, month: u8, day: u8) -> Option<MeasurementDate> {
+        MeasurementDate::new(year, month, day)
+    }
+
+    #[test]
+    fn test_parse_common_formats() {
+        let expected = date(2024, 1, 15);
+        for text in [
+            "2024-01-15",
+            "2024/01/15",
+            "15.01.2024",
+            "15/01/2024",
+            "01/15/2024",
+            "15-JAN-2024",
+            "15 Januar 2024",
+            "Jan 15, 2024",
+            "20240115",
+            "15.01.24",
+        ] {
+            assert_eq!(MeasurementDate::parse(text), expected, "{text}");
+        }
+        assert_eq!(MeasurementDate::parse("03.Mrz.2023"), date(2023, 3, 3));
+    }
+
+    #[test]
+    fn test_find_in_free_text() {
+        let text = "Measured 14.02.2023 / M. Smith";
+        let (found, span) = MeasurementDate::find(text).unwrap();
+        assert_eq!(Some(found), date(2023, 2, 14));
+        assert_eq!(&text[span], "14.02.2023");
+        assert_eq!(
+            normalize_date_text(text).as_deref(),
+            Some("Measured 2023-02-14 / M. Smith")
+        );
+
+        assert_eq!(MeasurementDate::parse("J. Smith, marketing"), None);
+        assert_eq!(MeasurementDate::parse("31.02.2024"), None);
+        assert_eq!(MeasurementDate::parse("Report 12-345"), None);
+    }
+
+    #[test]
+    fn test_formatting_and_plausibility() {
+        let d = date(2024, 2, 29).unwrap();
+        assert_eq!(d.to_iso(), "2024-02-29");
+        assert_eq!(d.to_ies(), "29-FEB-2024");
+        assert!(d.is_plausible());
+        assert!(!date(1900, 1, 1).unwrap().is_plausible());
+        assert!(date(2023, 2, 29).is_none());
+        assert!(date(2023, 1, 1) < date(2023, 1, 2));
+    }
+}
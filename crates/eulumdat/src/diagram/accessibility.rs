@@ -0,0 +1,56 @@
+//! Accessible names for diagram SVGs
+//!
+//! Screen readers announce an inline SVG by the `<title>` and `<desc>`
+//! children of an element with `role="img"` (SVG-AAM), so a diagram embedded
+//! in a web page needs both to meet WCAG 1.1.1 (non-text content).
+//! Diagrams drawn through [`DiagramGeometry`](super::DiagramGeometry) carry
+//! them already; [`add_svg_title`] adds them to any other diagram SVG.
+//!
+//! Color alone should not tell curves apart (WCAG 1.4.1), see
+//! [`SvgTheme::curve_patterns`](super::SvgTheme::curve_patterns).
+
+use super::geometry::escape_text;
+
+/// Mark `svg` as an image with the accessible name `title` and, if not
+/// empty, the description `description`.
+///
+/// SVGs that already have `role="img"` are returned unchanged.
+pub fn add_svg_title(svg: &str, title: &str, description: &str) -> String {
+    let Some(start) = svg.find("<svg") else {
+        return svg.to_string();
+    };
+    let Some(end) = svg[start..].find('>').map(|i| start + i) else {
+        return svg.to_string();
+    };
+    if svg[start..end].contains("role=") {
+        return svg.to_string();
+    }
+
+    let mut out = String::with_capacity(svg.len() + title.len() + description.len() + 48);
+    out.push_str(&svg[..end]);
+    out.push_str(r#" role="img">"#);
+    out.push_str(&format!("<title>{}</title>", escape_text(title)));
+    if !description.is_empty() {
+        out.push_str(&format!("<desc>{}</desc>", escape_text(description)));
+    }
+    out.push_str(&svg[end + 1..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_svg_title() {
+        let svg = r#"<svg viewBox="0 0 10 10" xmlns="http://www.w3.org/2000/svg"><rect/></svg>"#;
+        let titled = add_svg_title(svg, "Polar <C0>", "Max & min");
+        assert_eq!(
+            titled,
+            r#"<svg viewBox="0 0 10 10" xmlns="http://www.w3.org/2000/svg" role="img"><title>Polar &lt;C0&gt;</title><desc>Max &amp; min</desc><rect/></svg>"#
+        );
+        // Titling twice keeps the first title
+        assert_eq!(add_svg_title(&titled, "Other", ""), titled);
+        assert!(!add_svg_title(svg, "Polar", "").contains("<desc>"));
+    }
+}
@@ -5,7 +5,7 @@
 
 use super::color::{Color, ColorPalette};
 use super::{DiagramScale, Point2D, SvgTheme};
-use crate::{Eulumdat, PhotometricSummary};
+use crate::{BatwingAnalysis, Eulumdat, PhotometricCalculations, PhotometricSummary};
 
 /// A point in a cartesian curve
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -80,6 +80,10 @@ pub struct CartesianDiagram {
     pub plot_height: f64,
     pub margin_left: f64,
     pub margin_top: f64,
+    /// Batwing peaks and valley drawn as callouts
+    /// (see [`CartesianDiagram::with_batwing_annotations`])
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub batwing: Option<BatwingAnalysis>,
 }
 
 impl CartesianDiagram {
@@ -91,6 +95,53 @@ impl CartesianDiagram {
     /// * `height` - Total output height in pixels
     /// * `max_curves` - Maximum number of curves to include (for readability)
     pub fn from_eulumdat(ldt: &Eulumdat, width: f64, height: f64, max_curves: usize) -> Self {
+        let max_gamma = ldt.g_angles.last().copied().unwrap_or(90.0);
+        Self::with_range(
+            ldt,
+            width,
+            height,
+            max_curves,
+            ldt.max_intensity(),
+            max_gamma,
+        )
+    }
+
+    /// Generate cartesian diagrams of several luminaires on common axes.
+    ///
+    /// All diagrams share the intensity and gamma range of the largest
+    /// file, so their curves can be drawn over each other, e.g. with
+    /// [`CartesianDiagram::to_overlay_svg_multi`].
+    pub fn from_eulumdat_multi(
+        ldts: &[&Eulumdat],
+        width: f64,
+        height: f64,
+        max_curves: usize,
+    ) -> Vec<Self> {
+        let max_intensity = ldts
+            .iter()
+            .map(|ldt| ldt.max_intensity())
+            .fold(0.0, f64::max);
+        let max_gamma = ldts
+            .iter()
+            .filter_map(|ldt| ldt.g_angles.last().copied())
+            .fold(None, |acc: Option<f64>, g| {
+                Some(acc.map_or(g, |a| a.max(g)))
+            })
+            .unwrap_or(90.0);
+        ldts.iter()
+            .map(|ldt| Self::with_range(ldt, width, height, max_curves, max_intensity, max_gamma))
+            .collect()
+    }
+
+    /// Cartesian diagram with the axes scaled to `max_intensity` and `max_gamma`.
+    fn with_range(
+        ldt: &Eulumdat,
+        width: f64,
+        height: f64,
+        max_curves: usize,
+        max_intensity: f64,
+        max_gamma: f64,
+    ) -> Self {
         let margin_left = 60.0;
         let margin_right = 25.0;
         let margin_top = 35.0;
@@ -99,9 +150,6 @@ impl CartesianDiagram {
         let plot_width = width - margin_left - margin_right;
         let plot_height = height - margin_top - margin_bottom;
 
-        let max_intensity = ldt.max_intensity();
-        let max_gamma = ldt.g_angles.last().copied().unwrap_or(90.0);
-
         // Calculate nice Y-axis ticks
         let y_ticks = if max_intensity > 0.0 {
             let step = DiagramScale::nice_step(max_intensity, 5);
@@ -160,6 +208,7 @@ impl CartesianDiagram {
             plot_height,
             margin_left,
             margin_top,
+            batwing: None,
         }
     }
 
@@ -247,9 +296,28 @@ impl CartesianDiagram {
             plot_height,
             margin_left,
             margin_top,
+            batwing: None,
         }
     }
 
+    /// Annotate the wing peaks, the valley depth at nadir and the
+    /// asymmetry of the wings.
+    ///
+    /// Leaves the diagram unchanged unless the distribution is a batwing,
+    /// see [`PhotometricCalculations::batwing_analysis`].
+    pub fn with_batwing_annotations(mut self, ldt: &Eulumdat) -> Self {
+        self.batwing = PhotometricCalculations::batwing_analysis(ldt);
+        self
+    }
+
+    /// Screen position of `intensity` at `gamma`.
+    pub fn to_screen(&self, gamma: f64, intensity: f64) -> Point2D {
+        Point2D::new(
+            self.margin_left + self.plot_width * (gamma / self.max_gamma),
+            self.margin_top + self.plot_height * (1.0 - intensity / self.scale.scale_max),
+        )
+    }
+
     /// Get data points for all curves (useful for non-SVG rendering)
     pub fn all_data_points(&self) -> Vec<(&CartesianCurve, Vec<Point2D>)> {
         self.curves
@@ -444,4 +512,40 @@ mod tests {
         assert!((DiagramScale::nice_step(47.0, 5) - 10.0).abs() < 0.01);
         assert!((DiagramScale::nice_step(1000.0, 5) - 200.0).abs() < 0.01);
     }
+
+    #[test]
+    fn test_batwing_callouts() {
+        let mut ldt = create_test_ldt();
+        ldt.symmetry = crate::Symmetry::None;
+        ldt.intensities[0] = vec![60.0, 90.0, 110.0, 95.0, 50.0, 25.0, 10.0];
+        ldt.intensities[2] = vec![60.0, 85.0, 100.0, 80.0, 40.0, 20.0, 8.0];
+
+        let diagram =
+            CartesianDiagram::from_eulumdat(&ldt, 500.0, 380.0, 8).with_batwing_annotations(&ldt);
+        let batwing = diagram.batwing.unwrap();
+        assert_eq!(batwing.peak_c0.gamma, 30.0);
+        let theme = SvgTheme::light();
+        let summary = PhotometricSummary::from_eulumdat(&ldt);
+        for svg in [
+            diagram.to_svg(500.0, 380.0, &theme),
+            diagram.to_svg_with_summary(500.0, 380.0, &theme, &summary),
+        ] {
+            assert!(svg.contains("C0 peak 110 @ 30°"));
+            assert!(svg.contains("C180 peak 100 @ 30°"));
+            assert!(svg.contains("Valley −40%"));
+            assert!(svg.contains("Asymmetry 0.91"));
+        }
+
+        // The peak marker sits on the C0 curve
+        let peak = diagram.to_screen(30.0, 110.0);
+        let on_curve = diagram.curves[0].points[2];
+        assert!((peak.x - on_curve.x).abs() < 1e-9 && (peak.y - on_curve.y).abs() < 1e-9);
+
+        // Nothing to annotate on a center-peaked distribution
+        let plain = create_test_ldt();
+        let diagram = CartesianDiagram::from_eulumdat(&plain, 500.0, 380.0, 8)
+            .with_batwing_annotations(&plain);
+        assert!(diagram.batwing.is_none());
+        assert!(!diagram.to_svg(500.0, 380.0, &theme).contains("Valley"));
+    }
 }
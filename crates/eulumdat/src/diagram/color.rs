@@ -31,6 +31,11 @@ impl Color {
         format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
     }
 
+    /// Relative luminance in 0-1 (Rec. 709 weights on the sRGB values)
+    pub fn luminance(&self) -> f64 {
+        (0.2126 * self.r as f64 + 0.7152 * self.g as f64 + 0.0722 * self.b as f64) / 255.0
+    }
+
     /// Lighten the color by a factor (0.0 = no change, 1.0 = white)
     pub fn lighten(&self, factor: f64) -> Self {
         let factor = factor.clamp(0.0, 1.0);
@@ -116,6 +121,102 @@ pub fn heatmap_color(value: f64) -> Color {
     }
 }
 
+/// Color map for heatmap-style diagrams
+///
+/// Viridis, Inferno and Grayscale are perceptually uniform and stay readable
+/// when printed in black and white, unlike the classic rainbow.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorMap {
+    /// Dark blue -> cyan -> green -> yellow -> red ([`heatmap_color`])
+    #[default]
+    Classic,
+    /// Matplotlib's viridis: dark purple -> teal -> yellow
+    Viridis,
+    /// Matplotlib's inferno: black -> purple -> orange -> pale yellow
+    Inferno,
+    /// Black to white
+    Grayscale,
+    /// Linear interpolation between `(position, color)` stops, positions 0-1
+    Custom(Vec<(f64, Color)>),
+}
+
+const VIRIDIS: [(u8, u8, u8); 9] = [
+    (68, 1, 84),
+    (72, 36, 117),
+    (59, 82, 139),
+    (44, 114, 142),
+    (33, 145, 140),
+    (40, 174, 128),
+    (94, 201, 98),
+    (173, 220, 48),
+    (253, 231, 37),
+];
+
+const INFERNO: [(u8, u8, u8); 9] = [
+    (0, 0, 4),
+    (31, 12, 72),
+    (85, 15, 109),
+    (136, 34, 106),
+    (186, 54, 85),
+    (227, 89, 51),
+    (249, 140, 10),
+    (249, 201, 50),
+    (252, 255, 164),
+];
+
+impl ColorMap {
+    /// Color for a value in 0-1 (clamped)
+    pub fn color(&self, value: f64) -> Color {
+        let v = value.clamp(0.0, 1.0);
+        match self {
+            Self::Classic => heatmap_color(v),
+            Self::Viridis => sample_table(&VIRIDIS, v),
+            Self::Inferno => sample_table(&INFERNO, v),
+            Self::Grayscale => {
+                let l = (v * 255.0).round() as u8;
+                Color::new(l, l, l)
+            }
+            Self::Custom(stops) => sample_stops(stops, v),
+        }
+    }
+}
+
+/// Interpolate an evenly spaced table of colors.
+fn sample_table(table: &[(u8, u8, u8)], v: f64) -> Color {
+    let pos = v * (table.len() - 1) as f64;
+    let i = (pos.floor() as usize).min(table.len() - 2);
+    let (a, b) = (table[i], table[i + 1]);
+    lerp(
+        Color::new(a.0, a.1, a.2),
+        Color::new(b.0, b.1, b.2),
+        pos - i as f64,
+    )
+}
+
+/// Interpolate between sorted `(position, color)` stops.
+fn sample_stops(stops: &[(f64, Color)], v: f64) -> Color {
+    let Some(&(first_pos, first)) = stops.first() else {
+        return Color::new(0, 0, 0);
+    };
+    if v <= first_pos {
+        return first;
+    }
+    for pair in stops.windows(2) {
+        let ((p0, c0), (p1, c1)) = (pair[0], pair[1]);
+        if v <= p1 {
+            let t = if p1 > p0 { (v - p0) / (p1 - p0) } else { 1.0 };
+            return lerp(c0, c1, t);
+        }
+    }
+    stops[stops.len() - 1].1
+}
+
+fn lerp(a: Color, b: Color, t: f64) -> Color {
+    let mix = |x: u8, y: u8| (x as f64 + (y as f64 - x as f64) * t).round() as u8;
+    Color::new(mix(a.r, b.r), mix(a.g, b.g), mix(a.b, b.b))
+}
+
 /// Color palette for diagrams
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -192,6 +293,25 @@ mod tests {
         assert!(high.r > high.b);
     }
 
+    #[test]
+    fn test_color_maps() {
+        assert_eq!(ColorMap::Viridis.color(0.0), Color::new(68, 1, 84));
+        assert_eq!(ColorMap::Viridis.color(1.0), Color::new(253, 231, 37));
+        assert_eq!(ColorMap::Inferno.color(0.0), Color::new(0, 0, 4));
+        assert_eq!(ColorMap::Grayscale.color(0.5), Color::new(128, 128, 128));
+        assert_eq!(ColorMap::Classic.color(0.3), heatmap_color(0.3));
+
+        let custom = ColorMap::Custom(vec![
+            (0.0, Color::new(255, 255, 255)),
+            (0.5, Color::new(0, 0, 255)),
+            (1.0, Color::new(0, 0, 0)),
+        ]);
+        assert_eq!(custom.color(0.25), Color::new(128, 128, 255));
+        assert_eq!(custom.color(0.75), Color::new(0, 0, 128));
+        assert_eq!(custom.color(2.0), Color::new(0, 0, 0));
+        assert_eq!(ColorMap::Custom(Vec::new()).color(0.5), Color::new(0, 0, 0));
+    }
+
     #[test]
     fn test_color_strings() {
         let c = Color::new(255, 128, 0);
@@ -48,6 +48,10 @@ pub struct ConeDiagram {
     pub beam_angle_c90: f64,
     /// Selected C-plane: None = overall (averaged), Some(angle) = specific plane
     pub selected_c_plane: Option<f64>,
+    /// Beam/field diameters and illuminance at several mounting heights,
+    /// drawn as levels across the cone (see [`ConeDiagram::with_heights`])
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub height_levels: Vec<ConeIlluminanceRow>,
 }
 
 impl ConeDiagram {
@@ -93,6 +97,7 @@ impl ConeDiagram {
             beam_angle_c0,
             beam_angle_c90,
             selected_c_plane: None,
+            height_levels: Vec::new(),
         }
     }
 
@@ -128,9 +133,59 @@ impl ConeDiagram {
             beam_angle_c0,
             beam_angle_c90,
             selected_c_plane: Some(c_plane),
+            height_levels: Vec::new(),
         }
     }
 
+    /// Draw several mounting heights (e.g. 2 m, 3 m, 4 m) in one diagram.
+    ///
+    /// Each height gets its beam/field diameter and the illuminance at the
+    /// center and at the beam edges. The cone is extended to the largest
+    /// height, which becomes the floor and the mounting height.
+    pub fn with_heights(mut self, ldt: &Eulumdat, heights: &[f64]) -> Self {
+        let mut heights: Vec<f64> = heights.iter().copied().filter(|&h| h > 0.0).collect();
+        heights.sort_by(f64::total_cmp);
+        heights.dedup();
+        let Some(&max_height) = heights.last() else {
+            return self;
+        };
+
+        let flux_scale = ldt
+            .lamp_sets
+            .iter()
+            .map(|ls| ls.total_luminous_flux * ls.num_lamps.unsigned_abs() as f64)
+            .sum::<f64>()
+            / 1000.0;
+        let c_plane = self.selected_c_plane.unwrap_or(0.0);
+        let perp_plane = (c_plane + 90.0) % 360.0;
+        let half_beam = PhotometricCalculations::half_beam_angle_for_plane(ldt, c_plane);
+        let half_beam_perp = PhotometricCalculations::half_beam_angle_for_plane(ldt, perp_plane);
+
+        self.height_levels = heights
+            .into_iter()
+            .map(|h| ConeIlluminanceRow {
+                height: h,
+                beam_diameter: self.beam_diameter_at(h),
+                field_diameter: self.field_diameter_at(h),
+                e_nadir: ldt.sample(c_plane, 0.0) * flux_scale / (h * h),
+                e_beam_c0: ConeIlluminanceTable::illuminance_at_angle(
+                    ldt, c_plane, half_beam, h, flux_scale,
+                ),
+                e_beam_c90: ConeIlluminanceTable::illuminance_at_angle(
+                    ldt,
+                    perp_plane,
+                    half_beam_perp,
+                    h,
+                    flux_scale,
+                ),
+            })
+            .collect();
+        self.mounting_height = max_height;
+        self.beam_diameter = self.beam_diameter_at(max_height);
+        self.field_diameter = self.field_diameter_at(max_height);
+        self
+    }
+
     /// Check whether the luminaire has variation across C-planes.
     ///
     /// Returns `false` for rotationally symmetric luminaires (where all
@@ -348,6 +403,7 @@ impl ConeIlluminanceTable {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::diagram::SvgTheme;
 
     fn create_test_ldt() -> Eulumdat {
         // Typical downlight distribution - 100 at nadir, drops off
@@ -378,6 +434,37 @@ mod tests {
         assert!(!cone.beam_classification().is_empty());
     }
 
+    #[test]
+    fn test_cone_with_heights() {
+        let mut ldt = create_test_ldt();
+        ldt.lamp_sets = vec![crate::LampSet {
+            num_lamps: 1,
+            total_luminous_flux: 1000.0,
+            ..Default::default()
+        }];
+        let cone = ConeDiagram::from_eulumdat(&ldt, 3.0).with_heights(&ldt, &[4.0, 2.0, 3.0, 2.0]);
+
+        let heights: Vec<f64> = cone.height_levels.iter().map(|l| l.height).collect();
+        assert_eq!(heights, vec![2.0, 3.0, 4.0]);
+        assert_eq!(cone.mounting_height, 4.0);
+        assert!((cone.beam_diameter - cone.height_levels[2].beam_diameter).abs() < 1e-9);
+        // 100 cd at nadir: 25 lx at 2 m, inverse square beyond
+        assert!((cone.height_levels[0].e_nadir - 25.0).abs() < 1e-9);
+        assert!((cone.height_levels[2].e_nadir - 6.25).abs() < 1e-9);
+        assert!(
+            (cone.height_levels[1].beam_diameter * 2.0 - cone.beam_diameter_at(6.0)).abs() < 1e-9
+        );
+
+        let svg = cone.to_svg(500.0, 500.0, &SvgTheme::light());
+        assert!(svg.contains("E₀ 25 lx"));
+        assert!(svg.contains(">2.0m</text>"));
+        assert_eq!(svg.matches(r#"class="cone-level""#).count(), 3);
+
+        // No heights keeps the single-height diagram
+        let single = ConeDiagram::from_eulumdat(&ldt, 3.0);
+        assert_eq!(single.clone().with_heights(&ldt, &[]), single);
+    }
+
     #[test]
     fn test_diameter_at_distance() {
         let ldt = create_test_ldt();
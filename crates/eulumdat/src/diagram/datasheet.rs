@@ -0,0 +1,339 @@
+//! One-page photometric datasheet
+//!
+//! Lays out existing diagrams (polar, cartesian, cone, BUG rating) and a
+//! photometric summary table on a single A4 page. The page is a grid of
+//! equal cells; each panel fills one or more columns of a row.
+//!
+//! ```rust,no_run
+//! use eulumdat::{Eulumdat, diagram::{DatasheetCell, DatasheetComposer, DatasheetPanel, SvgTheme}};
+//!
+//! let ldt = Eulumdat::from_file("luminaire.ldt")?;
+//! let composer = DatasheetComposer::new().with_grid(2, 2).with_cells(vec![
+//!     DatasheetCell::new(DatasheetPanel::Polar),
+//!     DatasheetCell::new(DatasheetPanel::Cone),
+//!     DatasheetCell::spanning(DatasheetPanel::Summary, 2),
+//! ]);
+//! std::fs::write("datasheet.svg", composer.to_svg(&ldt, &SvgTheme::light()))?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`DatasheetComposer::to_html`] wraps the page in a printable HTML document,
+//! and [`DatasheetBranding`] adds a company name, title and footer.
+
+use super::SvgTheme;
+use crate::Eulumdat;
+
+/// Content of a datasheet cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DatasheetPanel {
+    /// Polar intensity diagram
+    Polar,
+    /// Cartesian intensity diagram
+    Cartesian,
+    /// Beam/field cone diagram at the composer's mounting height
+    Cone,
+    /// Photometric summary table
+    Summary,
+    /// BUG rating diagram (with the zone lumens when spanning two columns)
+    Bug,
+}
+
+/// A panel and the number of grid columns it spans
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatasheetCell {
+    pub panel: DatasheetPanel,
+    pub column_span: usize,
+}
+
+impl DatasheetCell {
+    /// Panel in a single column
+    pub fn new(panel: DatasheetPanel) -> Self {
+        Self::spanning(panel, 1)
+    }
+
+    /// Panel spanning `column_span` columns
+    pub fn spanning(panel: DatasheetPanel, column_span: usize) -> Self {
+        Self {
+            panel,
+            column_span: column_span.max(1),
+        }
+    }
+}
+
+/// Page position of a panel
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatasheetSlot {
+    pub panel: DatasheetPanel,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Company details for the title block and footer
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DatasheetBranding {
+    /// Company name at the top right
+    pub company: String,
+    /// Document title (default "Photometric datasheet")
+    pub title: String,
+    /// Footer line, e.g. address or website
+    pub footer: String,
+    /// Color of the title rule (default: the theme's axis color)
+    pub accent: String,
+}
+
+impl DatasheetBranding {
+    /// Document title, or "Photometric datasheet"
+    pub fn title_or_default(&self) -> &str {
+        match self.title.trim() {
+            "" => "Photometric datasheet",
+            title => title,
+        }
+    }
+}
+
+/// Composes several diagrams into a single-page datasheet SVG
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatasheetComposer {
+    /// Page size in SVG units (A4 at 96 dpi by default)
+    pub page_width: f64,
+    pub page_height: f64,
+    /// Grid size
+    pub columns: usize,
+    pub rows: usize,
+    /// Page margin and spacing between cells
+    pub margin: f64,
+    pub gap: f64,
+    /// Height of the title block above the grid
+    pub header_height: f64,
+    /// Panels in row-major order
+    pub cells: Vec<DatasheetCell>,
+    /// Mounting height for the cone diagram in meters
+    pub mounting_height: f64,
+    /// Company name, title and footer
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub branding: DatasheetBranding,
+}
+
+impl Default for DatasheetComposer {
+    fn default() -> Self {
+        Self {
+            page_width: Self::A4_WIDTH,
+            page_height: Self::A4_HEIGHT,
+            columns: 2,
+            rows: 3,
+            margin: 36.0,
+            gap: 12.0,
+            header_height: 64.0,
+            cells: vec![
+                DatasheetCell::new(DatasheetPanel::Polar),
+                DatasheetCell::new(DatasheetPanel::Cartesian),
+                DatasheetCell::new(DatasheetPanel::Cone),
+                DatasheetCell::new(DatasheetPanel::Bug),
+                DatasheetCell::spanning(DatasheetPanel::Summary, 2),
+            ],
+            mounting_height: 3.0,
+            branding: DatasheetBranding::default(),
+        }
+    }
+}
+
+impl DatasheetComposer {
+    /// A4 width at 96 dpi (210 mm)
+    pub const A4_WIDTH: f64 = 793.7;
+    /// A4 height at 96 dpi (297 mm)
+    pub const A4_HEIGHT: f64 = 1122.5;
+
+    /// A4 portrait page: polar and cartesian, cone and BUG, then the summary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use a grid of `columns` × `rows` cells.
+    pub fn with_grid(mut self, columns: usize, rows: usize) -> Self {
+        self.columns = columns.max(1);
+        self.rows = rows.max(1);
+        self
+    }
+
+    /// Use these panels, in row-major order.
+    pub fn with_cells(mut self, cells: Vec<DatasheetCell>) -> Self {
+        self.cells = cells;
+        self
+    }
+
+    /// Mounting height for the cone diagram in meters.
+    pub fn with_mounting_height(mut self, mounting_height: f64) -> Self {
+        self.mounting_height = mounting_height;
+        self
+    }
+
+    /// Use a page of `width` × `height` SVG units (96 per inch).
+    pub fn with_page_size(mut self, width: f64, height: f64) -> Self {
+        self.page_width = width;
+        self.page_height = height;
+        self
+    }
+
+    /// Print a company name, title and footer on the page.
+    pub fn with_branding(mut self, branding: DatasheetBranding) -> Self {
+        self.branding = branding;
+        self
+    }
+
+    /// Luminaire name for the title block, falling back to the file name
+    pub(super) fn luminaire_title(ldt: &Eulumdat) -> &str {
+        [&ldt.luminaire_name, &ldt.file_name]
+            .into_iter()
+            .find(|s| !s.trim().is_empty())
+            .map_or("Luminaire", |s| s.trim())
+    }
+
+    /// Render as an HTML document holding the page, sized for printing
+    /// (print to PDF from a browser gives the same page).
+    pub fn to_html(&self, ldt: &Eulumdat, theme: &SvgTheme) -> String {
+        let (width_mm, height_mm) = (
+            self.page_width * 25.4 / 96.0,
+            self.page_height * 25.4 / 96.0,
+        );
+        let title = format!(
+            "{} – {}",
+            Self::luminaire_title(ldt),
+            self.branding.title_or_default()
+        );
+        format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n\
+             @page {{ size: {width_mm:.0}mm {height_mm:.0}mm; margin: 0; }}\n\
+             html, body {{ margin: 0; background: {}; }}\n\
+             body > svg {{ display: block; width: {width_mm:.0}mm; height: {height_mm:.0}mm; }}\n\
+             </style>\n</head>\n<body>\n{}\n</body>\n</html>\n",
+            super::geometry::escape_text(&title),
+            theme.background,
+            self.to_svg(ldt, theme)
+        )
+    }
+
+    /// Place the panels row by row; a panel that does not fit the rest of a
+    /// row starts the next one, and panels beyond the last row are dropped.
+    pub fn layout(&self) -> Vec<DatasheetSlot> {
+        let columns = self.columns.max(1);
+        let rows = self.rows.max(1);
+        let top = self.margin + self.header_height;
+        let cell_width = (self.page_width - 2.0 * self.margin - (columns - 1) as f64 * self.gap)
+            / columns as f64;
+        let cell_height =
+            (self.page_height - top - self.margin - (rows - 1) as f64 * self.gap) / rows as f64;
+
+        let mut slots = Vec::new();
+        let (mut row, mut column) = (0, 0);
+        for cell in &self.cells {
+            let span = cell.column_span.clamp(1, columns);
+            if column + span > columns {
+                row += 1;
+                column = 0;
+            }
+            if row >= rows {
+                break;
+            }
+            slots.push(DatasheetSlot {
+                panel: cell.panel,
+                x: self.margin + column as f64 * (cell_width + self.gap),
+                y: top + row as f64 * (cell_height + self.gap),
+                width: span as f64 * cell_width + (span - 1) as f64 * self.gap,
+                height: cell_height,
+            });
+            column += span;
+        }
+        slots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LampSet, Symmetry};
+
+    fn create_test_ldt() -> Eulumdat {
+        Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            luminaire_name: "Test Downlight".to_string(),
+            c_angles: vec![0.0],
+            g_angles: (0..=18).map(|i| i as f64 * 10.0).collect(),
+            intensities: vec![(0..=18)
+                .map(|i| 400.0 * (i as f64 * 10.0).to_radians().cos().max(0.0))
+                .collect()],
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 2000.0,
+                wattage_with_ballast: 20.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_datasheet_layout() {
+        let slots = DatasheetComposer::new().layout();
+        assert_eq!(slots.len(), 5);
+        assert_eq!(slots[1].y, slots[0].y);
+        assert!(slots[2].y > slots[0].y);
+        // The summary spans both columns
+        let summary = slots[4];
+        assert_eq!(summary.panel, DatasheetPanel::Summary);
+        assert!((summary.x + summary.width - (slots[1].x + slots[1].width)).abs() < 1e-9);
+
+        // A panel wider than the rest of the row wraps; overflow is dropped
+        let composer = DatasheetComposer::new().with_grid(2, 2).with_cells(vec![
+            DatasheetCell::new(DatasheetPanel::Polar),
+            DatasheetCell::spanning(DatasheetPanel::Summary, 2),
+            DatasheetCell::new(DatasheetPanel::Bug),
+        ]);
+        let slots = composer.layout();
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots[1].x, composer.margin);
+        assert!(slots[1].y > slots[0].y);
+    }
+
+    #[test]
+    fn test_datasheet_svg() {
+        let svg = DatasheetComposer::new().to_svg(&create_test_ldt(), &SvgTheme::light());
+        assert!(svg.starts_with(r#"<svg width="210mm" height="297mm""#));
+        assert!(svg.contains("Test Downlight"));
+        // Four nested diagrams plus the summary panel
+        assert_eq!(svg.matches("<svg ").count(), 6);
+        assert!(svg.contains("BUG Rating"));
+        assert!(svg.contains("2000 lm"));
+    }
+
+    #[test]
+    fn test_datasheet_branding_html() {
+        let composer = DatasheetComposer::new()
+            .with_page_size(816.0, 1056.0)
+            .with_branding(DatasheetBranding {
+                company: "ACME & Co".to_string(),
+                footer: "www.acme.example".to_string(),
+                accent: "#e30613".to_string(),
+                ..Default::default()
+            });
+        let svg = composer.to_svg(&create_test_ldt(), &SvgTheme::light());
+        assert!(svg.contains("ACME &amp; Co"));
+        assert!(svg.contains(">Photometric datasheet<"));
+        assert!(svg.contains("www.acme.example"));
+        assert!(svg.contains(r##"stroke="#e30613""##));
+
+        let html = composer.to_html(&create_test_ldt(), &SvgTheme::light());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Test Downlight – Photometric datasheet</title>"));
+        // US letter page
+        assert!(html.contains("size: 216mm 279mm"));
+        assert!(html.contains(&svg));
+    }
+}
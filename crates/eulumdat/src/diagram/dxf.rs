@@ -0,0 +1,433 @@
+//! DXF export for CAD workflows
+//!
+//! Writes the polar and isolux diagrams as ASCII DXF (AutoCAD R12) drawings,
+//! so lighting designers can drop the curves straight into a layout. The
+//! entities sit on separate layers that can be restyled or frozen per layer:
+//!
+//! - `GRID`: rings, radial lines, area outline and axes
+//! - `CURVES`: intensity curves and isolux contours
+//! - `TEXT`: scale values, angles and contour labels
+//!
+//! ```rust,no_run
+//! use eulumdat::{Eulumdat, diagram::PolarDiagram};
+//!
+//! let ldt = Eulumdat::from_file("luminaire.ldt")?;
+//! std::fs::write("polar.dxf", PolarDiagram::from_eulumdat(&ldt).to_dxf())?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::fmt::Display;
+
+use super::{IsoluxDiagram, PolarDiagram};
+
+/// Layer of the grid lines
+pub const DXF_LAYER_GRID: &str = "GRID";
+/// Layer of the curves and contours
+pub const DXF_LAYER_CURVES: &str = "CURVES";
+/// Layer of the labels
+pub const DXF_LAYER_TEXT: &str = "TEXT";
+
+// AutoCAD color index
+const ACI_RED: u8 = 1;
+const ACI_BLUE: u8 = 5;
+const ACI_WHITE: u8 = 7;
+const ACI_GRAY: u8 = 8;
+
+/// Minimal ASCII DXF R12 writer
+struct DxfWriter {
+    out: String,
+}
+
+impl DxfWriter {
+    /// Start a drawing with the header and the three layers.
+    fn new() -> Self {
+        let mut dxf = Self { out: String::new() };
+        dxf.pair(0, "SECTION");
+        dxf.pair(2, "HEADER");
+        dxf.pair(9, "$ACADVER");
+        dxf.pair(1, "AC1009");
+        dxf.pair(0, "ENDSEC");
+
+        dxf.pair(0, "SECTION");
+        dxf.pair(2, "TABLES");
+        dxf.pair(0, "TABLE");
+        dxf.pair(2, "LTYPE");
+        dxf.pair(70, 1);
+        dxf.pair(0, "LTYPE");
+        dxf.pair(2, "CONTINUOUS");
+        dxf.pair(70, 0);
+        dxf.pair(3, "Solid line");
+        dxf.pair(72, 65);
+        dxf.pair(73, 0);
+        dxf.pair(40, "0.0");
+        dxf.pair(0, "ENDTAB");
+        dxf.pair(0, "TABLE");
+        dxf.pair(2, "LAYER");
+        dxf.pair(70, 3);
+        for (name, color) in [
+            (DXF_LAYER_GRID, ACI_GRAY),
+            (DXF_LAYER_CURVES, ACI_BLUE),
+            (DXF_LAYER_TEXT, ACI_WHITE),
+        ] {
+            dxf.pair(0, "LAYER");
+            dxf.pair(2, name);
+            dxf.pair(70, 0);
+            dxf.pair(62, color);
+            dxf.pair(6, "CONTINUOUS");
+        }
+        dxf.pair(0, "ENDTAB");
+        dxf.pair(0, "ENDSEC");
+
+        dxf.pair(0, "SECTION");
+        dxf.pair(2, "ENTITIES");
+        dxf
+    }
+
+    fn pair(&mut self, code: u16, value: impl Display) {
+        self.out.push_str(&format!("{code:>3}\n{value}\n"));
+    }
+
+    fn point(&mut self, code: u16, (x, y): (f64, f64)) {
+        self.pair(code, format!("{x:.4}"));
+        self.pair(code + 10, format!("{y:.4}"));
+        self.pair(code + 20, "0.0");
+    }
+
+    fn line(&mut self, layer: &str, from: (f64, f64), to: (f64, f64)) {
+        self.pair(0, "LINE");
+        self.pair(8, layer);
+        self.point(10, from);
+        self.point(11, to);
+    }
+
+    fn circle(&mut self, layer: &str, center: (f64, f64), radius: f64) {
+        self.pair(0, "CIRCLE");
+        self.pair(8, layer);
+        self.point(10, center);
+        self.pair(40, format!("{radius:.4}"));
+    }
+
+    /// A 2D polyline, with its own color or the layer color (`None`).
+    fn polyline(&mut self, layer: &str, color: Option<u8>, points: &[(f64, f64)], closed: bool) {
+        if points.len() < 2 {
+            return;
+        }
+        self.pair(0, "POLYLINE");
+        self.pair(8, layer);
+        if let Some(color) = color {
+            self.pair(62, color);
+        }
+        self.pair(66, 1);
+        self.point(10, (0.0, 0.0));
+        self.pair(70, if closed { 1 } else { 0 });
+        for &point in points {
+            self.pair(0, "VERTEX");
+            self.pair(8, layer);
+            self.point(10, point);
+        }
+        self.pair(0, "SEQEND");
+        self.pair(8, layer);
+    }
+
+    /// Text centered on `position`.
+    fn text(&mut self, position: (f64, f64), height: f64, text: &str) {
+        self.pair(0, "TEXT");
+        self.pair(8, DXF_LAYER_TEXT);
+        self.point(10, position);
+        self.pair(40, format!("{height:.4}"));
+        // DXF escapes the degree sign
+        self.pair(1, text.replace('°', "%%d"));
+        self.pair(72, 4);
+        self.point(11, position);
+    }
+
+    fn finish(mut self) -> String {
+        self.pair(0, "ENDSEC");
+        self.pair(0, "EOF");
+        self.out
+    }
+}
+
+impl PolarDiagram {
+    /// Export the diagram as an ASCII DXF drawing.
+    ///
+    /// One drawing unit is 1 cd/klm on the linear radial scale, with the
+    /// center at the origin and nadir (γ = 0°) pointing down; other radial
+    /// scales keep the outer ring at the scale maximum. The C0-C180 curve
+    /// is blue, the C90-C270 curve red, both on the `CURVES` layer.
+    pub fn to_dxf(&self) -> String {
+        let mut dxf = DxfWriter::new();
+        let radius = self.scale.scale_max;
+        let text_height = radius * 0.03;
+
+        for value in self.radial_scale.ring_values(&self.scale) {
+            let r = self.radius_of(value, radius);
+            dxf.circle(DXF_LAYER_GRID, (0.0, 0.0), r);
+            dxf.text(
+                (text_height * 2.5, -r - text_height),
+                text_height,
+                &self.radial_scale.format_value(value),
+            );
+        }
+
+        for i in 0..=6 {
+            let angle_deg = i as f64 * 30.0;
+            let (sin, cos) = angle_deg.to_radians().sin_cos();
+            // Nadir and zenith lines only once
+            let sides: &[f64] = if i == 0 || i == 6 {
+                &[1.0]
+            } else {
+                &[-1.0, 1.0]
+            };
+            for &side in sides {
+                dxf.line(
+                    DXF_LAYER_GRID,
+                    (0.0, 0.0),
+                    (side * radius * sin, -radius * cos),
+                );
+                if i > 0 && i < 6 {
+                    let offset = radius + text_height * 2.0;
+                    dxf.text(
+                        (side * offset * sin, -offset * cos),
+                        text_height,
+                        &format!("{angle_deg:.0}°"),
+                    );
+                }
+            }
+        }
+        dxf.text((0.0, -radius - text_height * 2.0), text_height, "0°");
+        dxf.text((0.0, radius + text_height * 2.0), text_height, "180°");
+
+        let mut curves = vec![(&self.c0_c180_curve, ACI_BLUE)];
+        if self.show_c90_c270() {
+            curves.push((&self.c90_c270_curve, ACI_RED));
+        }
+        let legend_y = -radius - text_height * 6.0;
+        let legend_mid = (curves.len() - 1) as f64 / 2.0;
+        for (i, (curve, color)) in curves.iter().enumerate() {
+            let points: Vec<(f64, f64)> = self
+                .curve_screen_points(curve, 0.0, 0.0, radius)
+                .iter()
+                .map(|p| (p.x, -p.y))
+                .collect();
+            dxf.polyline(DXF_LAYER_CURVES, Some(*color), &points, true);
+            dxf.text(
+                ((i as f64 - legend_mid) * radius * 0.6, legend_y),
+                text_height,
+                &curve.label,
+            );
+        }
+        dxf.text(
+            (0.0, legend_y - text_height * 2.0),
+            text_height,
+            &format!("cd/klm{}", self.radial_scale.unit_suffix()),
+        );
+
+        dxf.finish()
+    }
+}
+
+impl IsoluxDiagram {
+    /// Export the diagram as an ASCII DXF drawing.
+    ///
+    /// One drawing unit is 1 m on the ground with the luminaire at the
+    /// origin. The contours go on the `CURVES` layer, the area outline,
+    /// the 1-2-5 grid and the axes on `GRID`, the levels on `TEXT`.
+    pub fn to_dxf(&self) -> String {
+        let mut dxf = DxfWriter::new();
+        let half_w = self.params.area_half_width;
+        let half_d = self.params.area_half_depth;
+        let text_height = half_w.max(half_d) * 0.03;
+
+        // Grid lines at a round spacing, about five per half
+        let step = [1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0]
+            .into_iter()
+            .find(|&s| half_w.max(half_d) / s <= 5.0)
+            .unwrap_or(200.0);
+        let mut x = -(half_w / step).floor() * step;
+        while x <= half_w + 1e-9 {
+            dxf.line(DXF_LAYER_GRID, (x, -half_d), (x, half_d));
+            dxf.text(
+                (x, -half_d - text_height * 1.5),
+                text_height,
+                &format!("{x:.0} m"),
+            );
+            x += step;
+        }
+        let mut y = -(half_d / step).floor() * step;
+        while y <= half_d + 1e-9 {
+            dxf.line(DXF_LAYER_GRID, (-half_w, y), (half_w, y));
+            dxf.text(
+                (-half_w - text_height * 3.0, y),
+                text_height,
+                &format!("{y:.0} m"),
+            );
+            y += step;
+        }
+        dxf.polyline(
+            DXF_LAYER_GRID,
+            None,
+            &[
+                (-half_w, -half_d),
+                (half_w, -half_d),
+                (half_w, half_d),
+                (-half_w, half_d),
+            ],
+            true,
+        );
+        dxf.circle(DXF_LAYER_GRID, (0.0, 0.0), text_height * 0.5);
+
+        for contour in &self.contours {
+            let chains: Vec<Vec<(f64, f64)>> = contour
+                .paths
+                .iter()
+                .flat_map(|path| svg_path_chains(path))
+                .map(|chain| chain.into_iter().map(|p| self.to_ground(p)).collect())
+                .collect();
+            for chain in &chains {
+                let closed = chain.len() > 2
+                    && chain
+                        .first()
+                        .zip(chain.last())
+                        .is_some_and(|(a, b)| (a.0 - b.0).abs() < 1e-6 && (a.1 - b.1).abs() < 1e-6);
+                dxf.polyline(DXF_LAYER_CURVES, None, chain, closed);
+            }
+            // Label the longest piece halfway along
+            if let Some(chain) = chains.iter().max_by_key(|c| c.len()) {
+                if let Some(&position) = chain.get(chain.len() / 2) {
+                    dxf.text(position, text_height, &contour.label);
+                }
+            }
+        }
+
+        dxf.finish()
+    }
+
+    /// Ground position in meters of a screen point.
+    fn to_ground(&self, (sx, sy): (f64, f64)) -> (f64, f64) {
+        let half_w = self.params.area_half_width;
+        let half_d = self.params.area_half_depth;
+        (
+            -half_w + (sx - self.margin_left) / self.plot_width * 2.0 * half_w,
+            -half_d + (sy - self.margin_top) / self.plot_height * 2.0 * half_d,
+        )
+    }
+}
+
+/// Polylines of an SVG path made of `M` and `L` commands.
+fn svg_path_chains(path: &str) -> Vec<Vec<(f64, f64)>> {
+    let mut chains: Vec<Vec<(f64, f64)>> = Vec::new();
+    let mut tokens = path.split_whitespace();
+    while let Some(token) = tokens.next() {
+        let (x, y) = match token {
+            "M" | "L" => {
+                let x = tokens.next().and_then(|t| t.parse().ok());
+                let y = tokens.next().and_then(|t| t.parse().ok());
+                match x.zip(y) {
+                    Some(point) => point,
+                    None => break,
+                }
+            }
+            _ => continue,
+        };
+        if token == "M" || chains.is_empty() {
+            chains.push(Vec::new());
+        }
+        if let Some(chain) = chains.last_mut() {
+            chain.push((x, y));
+        }
+    }
+    chains
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagram::IsoluxParams;
+    use crate::{Eulumdat, LampSet, Symmetry};
+
+    fn create_test_ldt() -> Eulumdat {
+        Eulumdat {
+            symmetry: Symmetry::None,
+            c_angles: vec![0.0, 90.0, 180.0, 270.0],
+            g_angles: vec![0.0, 30.0, 60.0, 90.0],
+            intensities: vec![
+                vec![300.0, 250.0, 100.0, 0.0],
+                vec![300.0, 200.0, 60.0, 0.0],
+                vec![300.0, 250.0, 100.0, 0.0],
+                vec![300.0, 200.0, 60.0, 0.0],
+            ],
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 1000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn entities(dxf: &str, kind: &str, layer: &str) -> usize {
+        let lines: Vec<&str> = dxf.lines().collect();
+        lines
+            .windows(4)
+            .filter(|w| w[0] == "  0" && w[1] == kind && w[2] == "  8" && w[3] == layer)
+            .count()
+    }
+
+    #[test]
+    fn test_polar_dxf_layers() {
+        let polar = PolarDiagram::from_eulumdat(&create_test_ldt());
+        let dxf = polar.to_dxf();
+
+        assert!(dxf.starts_with("  0\nSECTION\n"));
+        assert!(dxf.ends_with("  0\nEOF\n"));
+        for layer in [DXF_LAYER_GRID, DXF_LAYER_CURVES, DXF_LAYER_TEXT] {
+            assert!(dxf.contains(&format!("  0\nLAYER\n  2\n{layer}\n")));
+        }
+
+        assert_eq!(
+            entities(&dxf, "CIRCLE", DXF_LAYER_GRID),
+            polar.scale.grid_values.len()
+        );
+        assert_eq!(entities(&dxf, "LINE", DXF_LAYER_GRID), 12);
+        assert_eq!(entities(&dxf, "POLYLINE", DXF_LAYER_CURVES), 2);
+        assert_eq!(
+            entities(&dxf, "VERTEX", DXF_LAYER_CURVES),
+            polar.c0_c180_curve.points.len() + polar.c90_c270_curve.points.len()
+        );
+        assert!(dxf.contains("  1\n90%%d\n"));
+        assert!(!dxf.contains('°'));
+    }
+
+    #[test]
+    fn test_isolux_dxf_ground_coordinates() {
+        let params = IsoluxParams {
+            mounting_height: 3.0,
+            area_half_width: 10.0,
+            area_half_depth: 10.0,
+            grid_resolution: 40,
+            ..Default::default()
+        };
+        let isolux = IsoluxDiagram::from_eulumdat(&create_test_ldt(), 500.0, 500.0, params);
+        assert!(!isolux.contours.is_empty());
+        let dxf = isolux.to_dxf();
+
+        assert!(entities(&dxf, "POLYLINE", DXF_LAYER_CURVES) >= isolux.contours.len());
+        assert_eq!(entities(&dxf, "POLYLINE", DXF_LAYER_GRID), 1);
+        // Grid every 2 m from -10 to 10 in both directions
+        assert_eq!(entities(&dxf, "LINE", DXF_LAYER_GRID), 22);
+        for contour in &isolux.contours {
+            assert!(dxf.contains(&format!("  1\n{}\n", contour.label)));
+        }
+
+        // Contour vertices lie on the ground area, in meters
+        let lines: Vec<&str> = dxf.lines().collect();
+        let vertices: Vec<f64> = lines
+            .windows(5)
+            .filter(|w| w[0] == "VERTEX" && w[1] == "  8" && w[2] == DXF_LAYER_CURVES)
+            .map(|w| w[4].parse().unwrap())
+            .collect();
+        assert!(!vertices.is_empty());
+        assert!(vertices.iter().all(|x| x.abs() <= 10.0));
+    }
+}
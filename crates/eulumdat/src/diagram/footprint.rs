@@ -0,0 +1,274 @@
+//! Beam footprint on a target plane
+//!
+//! Projects the beam (50 % of I_max) and field (10 % of I_max) outlines onto
+//! a plane for a luminaire aimed at a tilt angle and throw distance: the
+//! ground for floodlights and sports lighting, the wall for facade lighting.
+//!
+//! ## Geometry
+//! ```text
+//! Luminaire at distance D = throw · cos(tilt) from the plane, aimed at
+//! (D · tan(tilt), 0). For plane point (x, y):
+//!   Rotate (x, y, -D) by -tilt around Y → local direction
+//!   I_rel = ldt.sample(C°, γ°) / I_max
+//! ```
+//!
+//! The outlines are marching-squares contours of `I_rel` at 0.5 and 0.1, so
+//! they stay correct for asymmetric beams.
+
+use super::contour::marching_squares;
+use crate::Eulumdat;
+
+/// Aiming of the luminaire towards the target plane
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FootprintParams {
+    /// Distance from the luminaire to the aiming point in meters
+    pub throw_distance: f64,
+    /// Aiming angle from the plane normal in degrees (0 = perpendicular)
+    pub tilt_angle: f64,
+    /// Number of grid cells per axis
+    pub grid_resolution: usize,
+}
+
+impl Default for FootprintParams {
+    fn default() -> Self {
+        Self {
+            throw_distance: 10.0,
+            tilt_angle: 0.0,
+            grid_resolution: 80,
+        }
+    }
+}
+
+/// Outline of the beam or field on the target plane
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FootprintContour {
+    /// Fraction of I_max (0.5 for the beam, 0.1 for the field)
+    pub fraction: f64,
+    /// Label (e.g., "Beam 50%")
+    pub label: String,
+    /// SVG path strings
+    pub paths: Vec<String>,
+    /// Extent along the aiming direction in meters
+    pub length: f64,
+    /// Extent across the aiming direction in meters
+    pub width: f64,
+}
+
+/// Plan view of the beam footprint on a target plane
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FootprintDiagram {
+    /// Beam and field outlines
+    pub contours: Vec<FootprintContour>,
+    /// Aiming used
+    pub params: FootprintParams,
+    /// Perpendicular distance from the luminaire to the plane in meters
+    pub plane_distance: f64,
+    /// Aiming point along the plane X axis in meters
+    pub aim_x: f64,
+    /// Plane area shown in meters
+    pub x_min: f64,
+    pub x_max: f64,
+    pub y_min: f64,
+    pub y_max: f64,
+    /// Pixels per meter (equal on both axes)
+    pub scale: f64,
+    /// Plot dimensions
+    pub plot_width: f64,
+    pub plot_height: f64,
+    pub margin_left: f64,
+    pub margin_top: f64,
+}
+
+impl FootprintDiagram {
+    /// Steepest view of the plane that still gets a finite footprint
+    const MAX_ANGLE: f64 = 80.0;
+
+    /// Project the beam and field of `ldt` onto the target plane.
+    pub fn from_eulumdat(ldt: &Eulumdat, width: f64, height: f64, params: FootprintParams) -> Self {
+        let margin_left = 60.0;
+        let margin_right = 30.0;
+        let margin_top = 50.0;
+        let margin_bottom = 75.0;
+
+        let plot_width = width - margin_left - margin_right;
+        let plot_height = height - margin_top - margin_bottom;
+
+        let tilt = params.tilt_angle.clamp(0.0, Self::MAX_ANGLE);
+        let throw = params.throw_distance.max(0.1);
+        let distance = throw * tilt.to_radians().cos();
+        let aim_x = throw * tilt.to_radians().sin();
+        let i_max = ldt.max_intensity();
+
+        // Half-angle of the field around the beam axis
+        let half_field = (0..=Self::MAX_ANGLE as usize)
+            .rev()
+            .find(|&g| (0..24).any(|i| ldt.sample(i as f64 * 15.0, g as f64) >= 0.1 * i_max))
+            .map_or(Self::MAX_ANGLE, |g| (g as f64 + 2.0).min(Self::MAX_ANGLE));
+
+        // Area covering the field cone, padded by 10%
+        let near = (tilt - half_field).max(-Self::MAX_ANGLE).to_radians();
+        let far = (tilt + half_field).min(Self::MAX_ANGLE).to_radians();
+        let (x0, x1) = (distance * near.tan(), distance * far.tan());
+        // Semi-minor axis of the conic section D · sin α / √(cos(t+α) · cos(t−α))
+        let y1 = (distance * half_field.to_radians().sin() / (far.cos() * near.cos()).sqrt())
+            .min(x1 - x0);
+        let pad_x = 0.1 * (x1 - x0);
+        let pad_y = 0.1 * y1;
+        let (x_min, x_max) = (x0 - pad_x, x1 + pad_x);
+        let (y_min, y_max) = (-y1 - pad_y, y1 + pad_y);
+
+        let scale = (plot_width / (x_max - x_min)).min(plot_height / (y_max - y_min));
+        let left = margin_left + (plot_width - scale * (x_max - x_min)) / 2.0;
+        let top = margin_top + (plot_height - scale * (y_max - y_min)) / 2.0;
+
+        // Relative intensity on the plane
+        let n = params.grid_resolution.max(2);
+        let xs: Vec<f64> = (0..n)
+            .map(|i| x_min + (i as f64 + 0.5) * (x_max - x_min) / n as f64)
+            .collect();
+        let ys: Vec<f64> = (0..n)
+            .map(|i| y_min + (i as f64 + 0.5) * (y_max - y_min) / n as f64)
+            .collect();
+        let (sin_t, cos_t) = tilt.to_radians().sin_cos();
+        let grid: Vec<Vec<f64>> = ys
+            .iter()
+            .map(|&y| {
+                xs.iter()
+                    .map(|&x| {
+                        let dz = -distance;
+                        let dx_rot = x * cos_t + dz * sin_t;
+                        let dz_rot = -x * sin_t + dz * cos_t;
+                        let r = (x * x + y * y + dz * dz).sqrt();
+                        let gamma = (-dz_rot / r).clamp(-1.0, 1.0).acos().to_degrees();
+                        let c = y.atan2(dx_rot).to_degrees().rem_euclid(360.0);
+                        if i_max > 0.0 {
+                            ldt.sample(c, gamma) / i_max
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let x_coords: Vec<f64> = xs.iter().map(|x| left + (x - x_min) * scale).collect();
+        let y_coords: Vec<f64> = ys.iter().map(|y| top + (y - y_min) * scale).collect();
+        let contours = [(0.5, "Beam 50%"), (0.1, "Field 10%")]
+            .into_iter()
+            .map(|(fraction, label)| {
+                let inside = grid.iter().zip(&ys).flat_map(|(row, &y)| {
+                    row.iter()
+                        .zip(&xs)
+                        .filter(move |(&v, _)| v >= fraction)
+                        .map(move |(_, &x)| (x, y))
+                });
+                let (mut lx, mut ly) = ((f64::MAX, f64::MIN), (f64::MAX, f64::MIN));
+                for (x, y) in inside {
+                    lx = (lx.0.min(x), lx.1.max(x));
+                    ly = (ly.0.min(y), ly.1.max(y));
+                }
+                FootprintContour {
+                    fraction,
+                    label: label.to_string(),
+                    paths: marching_squares(&grid, &x_coords, &y_coords, fraction).paths,
+                    length: (lx.1 - lx.0).max(0.0),
+                    width: (ly.1 - ly.0).max(0.0),
+                }
+            })
+            .collect();
+
+        Self {
+            contours,
+            params,
+            plane_distance: distance,
+            aim_x,
+            x_min,
+            x_max,
+            y_min,
+            y_max,
+            scale,
+            plot_width,
+            plot_height,
+            margin_left: left,
+            margin_top: top,
+        }
+    }
+
+    /// Screen position of a plane point in meters
+    pub fn to_screen(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            self.margin_left + (x - self.x_min) * self.scale,
+            self.margin_top + (y - self.y_min) * self.scale,
+        )
+    }
+
+    /// Whether no outline reaches the plane area
+    pub fn is_empty(&self) -> bool {
+        self.contours.iter().all(|c| c.paths.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagram::SvgTheme;
+    use crate::{LampSet, Symmetry};
+
+    /// Rotationally symmetric beam with a 30° half beam angle
+    fn spot() -> Eulumdat {
+        Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            c_angles: vec![0.0],
+            g_angles: (0..=18).map(|i| i as f64 * 5.0).collect(),
+            intensities: vec![(0..=18)
+                .map(|i| 1000.0 * (1.0 - i as f64 * 5.0 / 60.0).max(0.0))
+                .collect()],
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 1000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_footprint_perpendicular() {
+        let params = FootprintParams {
+            throw_distance: 10.0,
+            tilt_angle: 0.0,
+            grid_resolution: 120,
+        };
+        let diagram = FootprintDiagram::from_eulumdat(&spot(), 600.0, 600.0, params);
+        let beam = &diagram.contours[0];
+        let field = &diagram.contours[1];
+
+        // Beam edge at 30° off axis: 2 · 10 · tan 30° ≈ 11.5 m
+        assert!((beam.length - 11.5).abs() < 0.6, "{}", beam.length);
+        assert!((beam.length - beam.width).abs() < 0.6);
+        assert!(field.length > beam.length);
+        assert!(!diagram.is_empty());
+    }
+
+    #[test]
+    fn test_footprint_tilted() {
+        let params = FootprintParams {
+            throw_distance: 10.0,
+            tilt_angle: 40.0,
+            grid_resolution: 120,
+        };
+        let diagram = FootprintDiagram::from_eulumdat(&spot(), 600.0, 600.0, params);
+        let beam = &diagram.contours[0];
+
+        // Tilting stretches the footprint along the aiming direction
+        assert!((diagram.aim_x - 6.43).abs() < 0.01);
+        assert!(beam.length > beam.width * 1.3);
+
+        let svg = diagram.to_svg(600.0, 600.0, &SvgTheme::light());
+        assert!(svg.contains("Beam 50%"));
+        assert!(svg.contains("Field 10%"));
+    }
+}
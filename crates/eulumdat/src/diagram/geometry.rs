@@ -0,0 +1,1242 @@
+//! Renderer-independent diagram geometry
+//!
+//! Diagram builders lay out their content as a flat list of [`Primitive`]s in
+//! output pixels. Colors are expressed as [`StyleRole`]s so every backend can
+//! apply its own theme while drawing the exact same shapes. The SVG backend
+//! lives here ([`DiagramGeometry::to_svg`]); egui or canvas frontends walk the
+//! primitives directly.
+
+use super::accessibility::add_svg_title;
+use super::color::{Color, ColorPalette};
+use super::{CartesianDiagram, HeatmapDiagram, Point2D, PolarDiagram, SvgLabels, SvgTheme};
+
+/// Semantic color of a primitive, resolved by the backend's theme
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StyleRole {
+    Background,
+    Surface,
+    Grid,
+    Axis,
+    Text,
+    TextSecondary,
+    LegendBackground,
+    /// C0-C180 curve
+    CurvePrimary,
+    CurvePrimaryFill,
+    /// C90-C270 curve
+    CurveSecondary,
+    CurveSecondaryFill,
+    /// Data-driven color (heatmap cells, per-plane curves)
+    Fixed(Color),
+    /// Data-driven color with an opacity of 0-1 (faded background curves)
+    Translucent(Color, f64),
+}
+
+impl StyleRole {
+    /// Resolve to a CSS color using an SVG theme
+    pub fn resolve(&self, theme: &SvgTheme) -> String {
+        match self {
+            Self::Background => theme.background.clone(),
+            Self::Surface => theme.surface.clone(),
+            Self::Grid => theme.grid.clone(),
+            Self::Axis => theme.axis.clone(),
+            Self::Text => theme.text.clone(),
+            Self::TextSecondary => theme.text_secondary.clone(),
+            Self::LegendBackground => theme.legend_bg.clone(),
+            Self::CurvePrimary => theme.curve_c0_c180.clone(),
+            Self::CurvePrimaryFill => theme.curve_c0_c180_fill.clone(),
+            Self::CurveSecondary => theme.curve_c90_c270.clone(),
+            Self::CurveSecondaryFill => theme.curve_c90_c270_fill.clone(),
+            Self::Fixed(color) => color.to_rgb_string(),
+            Self::Translucent(color, alpha) => color.to_rgba_string(*alpha),
+        }
+    }
+}
+
+/// Line style
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineStyle {
+    pub role: StyleRole,
+    pub width: f64,
+    /// Dash and gap length, `None` for solid lines
+    pub dash: Option<(f64, f64)>,
+}
+
+impl LineStyle {
+    pub fn solid(role: StyleRole, width: f64) -> Self {
+        Self {
+            role,
+            width,
+            dash: None,
+        }
+    }
+
+    pub fn dashed(role: StyleRole, width: f64, dash: f64, gap: f64) -> Self {
+        Self {
+            role,
+            width,
+            dash: Some((dash, gap)),
+        }
+    }
+}
+
+/// Horizontal text alignment relative to the anchor point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextAnchor {
+    Start,
+    Middle,
+    End,
+}
+
+/// A drawing primitive in output pixels (origin top-left, y down)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Primitive {
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        fill: Option<StyleRole>,
+        stroke: Option<LineStyle>,
+        corner_radius: f64,
+    },
+    Circle {
+        center: Point2D,
+        radius: f64,
+        fill: Option<StyleRole>,
+        stroke: Option<LineStyle>,
+    },
+    Line {
+        from: Point2D,
+        to: Point2D,
+        stroke: LineStyle,
+    },
+    Path {
+        points: Vec<Point2D>,
+        closed: bool,
+        fill: Option<StyleRole>,
+        stroke: LineStyle,
+    },
+    Text {
+        position: Point2D,
+        text: String,
+        size: f64,
+        anchor: TextAnchor,
+        /// Vertically center on `position` instead of using it as baseline
+        middle: bool,
+        /// Rotation in degrees around `position`
+        rotation: f64,
+        role: StyleRole,
+    },
+}
+
+impl Primitive {
+    fn text(x: f64, y: f64, text: impl Into<String>, size: f64, anchor: TextAnchor) -> Self {
+        Self::Text {
+            position: Point2D::new(x, y),
+            text: text.into(),
+            size,
+            anchor,
+            middle: false,
+            rotation: 0.0,
+            role: StyleRole::TextSecondary,
+        }
+    }
+
+    fn with_role(mut self, new_role: StyleRole) -> Self {
+        if let Self::Text { role, .. } = &mut self {
+            *role = new_role;
+        }
+        self
+    }
+
+    fn centered(mut self) -> Self {
+        if let Self::Text { middle, .. } = &mut self {
+            *middle = true;
+        }
+        self
+    }
+
+    fn rotated(mut self, degrees: f64) -> Self {
+        if let Self::Text { rotation, .. } = &mut self {
+            *rotation = degrees;
+        }
+        self
+    }
+
+    fn with_fill(mut self, role: StyleRole) -> Self {
+        if let Self::Rect { fill, .. } | Self::Circle { fill, .. } = &mut self {
+            *fill = Some(role);
+        }
+        self
+    }
+
+    fn with_stroke(mut self, style: LineStyle) -> Self {
+        if let Self::Rect { stroke, .. } | Self::Circle { stroke, .. } = &mut self {
+            *stroke = Some(style);
+        }
+        self
+    }
+
+    fn line(x1: f64, y1: f64, x2: f64, y2: f64, stroke: LineStyle) -> Self {
+        Self::Line {
+            from: Point2D::new(x1, y1),
+            to: Point2D::new(x2, y2),
+            stroke,
+        }
+    }
+
+    fn rect(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self::Rect {
+            x,
+            y,
+            width,
+            height,
+            fill: None,
+            stroke: None,
+            corner_radius: 0.0,
+        }
+    }
+}
+
+/// Legend entry, for frontends that lay out their own legend
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LegendItem {
+    pub label: String,
+    pub stroke: LineStyle,
+    pub fill: Option<StyleRole>,
+}
+
+/// Complete diagram geometry
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiagramGeometry {
+    pub width: f64,
+    pub height: f64,
+    /// Accessible name of the diagram (SVG `<title>`)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub title: String,
+    /// What the diagram shows, for screen readers (SVG `<desc>`)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub description: String,
+    /// Primitives in painting order
+    pub primitives: Vec<Primitive>,
+    /// Legend entries (also present as primitives)
+    pub legend: Vec<LegendItem>,
+}
+
+impl DiagramGeometry {
+    /// Empty geometry with a background
+    pub fn new(width: f64, height: f64) -> Self {
+        let background = Primitive::rect(0.0, 0.0, width, height).with_fill(StyleRole::Background);
+        Self {
+            width,
+            height,
+            title: String::new(),
+            description: String::new(),
+            primitives: vec![background],
+            legend: Vec::new(),
+        }
+    }
+
+    /// Set the accessible name and description
+    fn describe(&mut self, title: impl Into<String>, description: impl Into<String>) {
+        self.title = title.into();
+        self.description = description.into();
+    }
+
+    fn push(&mut self, primitive: Primitive) {
+        self.primitives.push(primitive);
+    }
+
+    /// Render with the SVG backend, titled for screen readers
+    pub fn to_svg(&self, theme: &SvgTheme) -> String {
+        let mut svg = format!(
+            r#"<svg viewBox="0 0 {} {}" xmlns="http://www.w3.org/2000/svg">"#,
+            self.width, self.height
+        );
+
+        push_primitives_svg(&mut svg, &self.primitives, theme);
+        svg.push_str(&theme.logo_svg(self.width, self.height));
+        svg.push_str("</svg>");
+        if self.title.is_empty() {
+            svg
+        } else {
+            add_svg_title(&svg, &self.title, &self.description)
+        }
+    }
+}
+
+/// Append the SVG elements of `primitives`, e.g. as an overlay of a
+/// hand-written SVG.
+pub(super) fn push_primitives_svg(svg: &mut String, primitives: &[Primitive], theme: &SvgTheme) {
+    let mut patterns = theme.curve_patterns.then(CurvePatterns::default);
+    let hatch = patterns.is_some()
+        && primitives.iter().any(|p| match p {
+            Primitive::Rect { fill, .. }
+            | Primitive::Circle { fill, .. }
+            | Primitive::Path { fill, .. } => *fill == Some(StyleRole::CurveSecondaryFill),
+            _ => false,
+        });
+    if hatch {
+        // Diagonal hatch over the C90-C270 fill color
+        svg.push_str(&format!(
+            r#"<defs><pattern id="{SECONDARY_HATCH_ID}" width="6" height="6" patternUnits="userSpaceOnUse" patternTransform="rotate(45)"><rect width="6" height="6" fill="{}"/><line x1="0" y1="0" x2="0" y2="6" stroke="{}" stroke-width="1"/></pattern></defs>"#,
+            theme.curve_c90_c270_fill, theme.curve_c90_c270
+        ));
+    }
+    for primitive in primitives {
+        match primitive {
+            Primitive::Rect {
+                x,
+                y,
+                width,
+                height,
+                fill,
+                stroke,
+                corner_radius,
+            } => {
+                svg.push_str(&format!(
+                    r#"<rect x="{x:.1}" y="{y:.1}" width="{width:.1}" height="{height:.1}"{}{}"#,
+                    svg_fill(fill, theme, hatch),
+                    svg_stroke(stroke.as_ref(), theme, &mut patterns)
+                ));
+                if *corner_radius > 0.0 {
+                    svg.push_str(&format!(r#" rx="{corner_radius}""#));
+                }
+                svg.push_str("/>");
+            }
+            Primitive::Circle {
+                center,
+                radius,
+                fill,
+                stroke,
+            } => {
+                svg.push_str(&format!(
+                    r#"<circle cx="{:.1}" cy="{:.1}" r="{radius:.1}"{}{}/>"#,
+                    center.x,
+                    center.y,
+                    svg_fill(fill, theme, hatch),
+                    svg_stroke(stroke.as_ref(), theme, &mut patterns)
+                ));
+            }
+            Primitive::Line { from, to, stroke } => {
+                svg.push_str(&format!(
+                    r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}"{}/>"#,
+                    from.x,
+                    from.y,
+                    to.x,
+                    to.y,
+                    svg_stroke(Some(stroke), theme, &mut patterns)
+                ));
+            }
+            Primitive::Path {
+                points,
+                closed,
+                fill,
+                stroke,
+            } => {
+                if points.is_empty() {
+                    continue;
+                }
+                let mut d = String::new();
+                for (i, p) in points.iter().enumerate() {
+                    let cmd = if i == 0 { "M" } else { " L" };
+                    d.push_str(&format!("{cmd} {:.1} {:.1}", p.x, p.y));
+                }
+                if *closed {
+                    d.push_str(" Z");
+                }
+                svg.push_str(&format!(
+                    r#"<path d="{d}"{}{} stroke-linecap="round" stroke-linejoin="round"/>"#,
+                    svg_fill(fill, theme, hatch),
+                    svg_stroke(Some(stroke), theme, &mut patterns)
+                ));
+            }
+            Primitive::Text {
+                position,
+                text,
+                size,
+                anchor,
+                middle,
+                rotation,
+                role,
+            } => {
+                let anchor = match anchor {
+                    TextAnchor::Start => "start",
+                    TextAnchor::Middle => "middle",
+                    TextAnchor::End => "end",
+                };
+                svg.push_str(&format!(
+                    r#"<text x="{:.1}" y="{:.1}" text-anchor="{anchor}"{} font-size="{}" fill="{}" font-family="{}""#,
+                    position.x,
+                    position.y,
+                    if *middle {
+                        r#" dominant-baseline="middle""#
+                    } else {
+                        ""
+                    },
+                    size * theme.font_scale,
+                    role.resolve(theme),
+                    theme.font_family
+                ));
+                if *rotation != 0.0 {
+                    svg.push_str(&format!(
+                        r#" transform="rotate({rotation}, {:.1}, {:.1})""#,
+                        position.x, position.y
+                    ));
+                }
+                svg.push_str(&format!(">{}</text>", escape_text(text)));
+            }
+        }
+    }
+}
+
+/// Dash patterns for curves told apart without color, in order of first use
+const CURVE_DASHES: [&str; 6] = ["", "8,4", "2,3", "8,3,2,3", "12,4", "4,2,1,2"];
+
+/// Id of the hatch pattern filling the C90-C270 area in pattern mode
+const SECONDARY_HATCH_ID: &str = "eulumdat-hatch-secondary";
+
+/// Dash patterns of the [`SvgTheme::curve_patterns`] mode, assigned to the
+/// curve colors while writing primitives
+#[derive(Default)]
+struct CurvePatterns {
+    /// Curve colors seen so far; the index picks the dash pattern
+    curves: Vec<StyleRole>,
+}
+
+impl CurvePatterns {
+    /// Dash pattern of a curve stroke, `None` for non-curve roles
+    fn dash(&mut self, role: StyleRole) -> Option<&'static str> {
+        let key = match role {
+            StyleRole::CurvePrimary | StyleRole::CurveSecondary | StyleRole::Fixed(_) => role,
+            // Faded planes share the pattern of their color
+            StyleRole::Translucent(color, _) => StyleRole::Fixed(color),
+            _ => return None,
+        };
+        let index = match self.curves.iter().position(|r| *r == key) {
+            Some(index) => index,
+            None => {
+                self.curves.push(key);
+                self.curves.len() - 1
+            }
+        };
+        Some(CURVE_DASHES[index % CURVE_DASHES.len()])
+    }
+}
+
+fn svg_fill(fill: &Option<StyleRole>, theme: &SvgTheme, hatch: bool) -> String {
+    match fill {
+        Some(StyleRole::CurveSecondaryFill) if hatch => {
+            format!(r#" fill="url(#{SECONDARY_HATCH_ID})""#)
+        }
+        Some(role) => format!(r#" fill="{}""#, role.resolve(theme)),
+        None => r#" fill="none""#.to_string(),
+    }
+}
+
+fn svg_stroke(
+    stroke: Option<&LineStyle>,
+    theme: &SvgTheme,
+    patterns: &mut Option<CurvePatterns>,
+) -> String {
+    match stroke {
+        Some(style) => {
+            let mut s = format!(
+                r#" stroke="{}" stroke-width="{}""#,
+                style.role.resolve(theme),
+                style.width * theme.line_scale
+            );
+            // Curves keep an explicit dash but still take up a pattern
+            let pattern = patterns.as_mut().and_then(|p| p.dash(style.role));
+            if let Some((dash, gap)) = style.dash {
+                s.push_str(&format!(r#" stroke-dasharray="{dash},{gap}""#));
+            } else if let Some(pattern) = pattern.filter(|p| !p.is_empty()) {
+                s.push_str(&format!(r#" stroke-dasharray="{pattern}""#));
+            }
+            s
+        }
+        None => String::new(),
+    }
+}
+
+pub(super) fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+impl PolarDiagram {
+    /// Lay out the polar diagram in a `size` × `size` square
+    pub fn to_geometry(&self, size: f64, labels: &SvgLabels) -> DiagramGeometry {
+        let center = size / 2.0;
+        let radius = (size / 2.0) - 60.0;
+        let mut geo = DiagramGeometry::new(size, size);
+        geo.describe(
+            "Polar intensity diagram",
+            format!(
+                "{} and {} planes, maximum {:.0} {}",
+                self.c0_c180_curve.label,
+                self.c90_c270_curve.label,
+                self.scale.max_intensity,
+                labels.intensity_unit
+            ),
+        );
+
+        // Grid circles; the middle and outer circles are major
+        let rings = self.radial_scale.ring_values(&self.scale);
+        let num_circles = rings.len();
+        for (i, &value) in rings.iter().enumerate() {
+            let r = self.radius_of(value, radius);
+            let is_major = i == num_circles - 1 || i == num_circles / 2;
+            let stroke = if is_major {
+                LineStyle::solid(StyleRole::Axis, 1.5)
+            } else {
+                LineStyle::solid(StyleRole::Grid, 1.0)
+            };
+            geo.push(Primitive::Circle {
+                center: Point2D::new(center, center),
+                radius: r,
+                fill: None,
+                stroke: Some(stroke),
+            });
+            geo.push(Primitive::text(
+                center + 5.0,
+                center + r + 12.0,
+                self.radial_scale.format_value(value),
+                11.0,
+                TextAnchor::Start,
+            ));
+        }
+
+        // Radial lines every 30° with angle labels
+        let grid = LineStyle::solid(StyleRole::Grid, 1.0);
+        for i in (0..=6).filter(|&i| i != 3) {
+            let angle_deg = i as f64 * 30.0;
+            let (sin, cos) = angle_deg.to_radians().sin_cos();
+            for side in [-1.0, 1.0] {
+                geo.push(Primitive::line(
+                    center,
+                    center,
+                    center + side * radius * sin,
+                    center + radius * cos,
+                    grid,
+                ));
+            }
+            if angle_deg > 0.0 && angle_deg < 180.0 {
+                let offset = radius + 18.0;
+                for side in [-1.0, 1.0] {
+                    geo.push(
+                        Primitive::text(
+                            center + side * offset * sin,
+                            center + offset * cos,
+                            format!("{angle_deg:.0}°"),
+                            11.0,
+                            TextAnchor::Middle,
+                        )
+                        .centered(),
+                    );
+                }
+            }
+        }
+        geo.push(Primitive::text(
+            center,
+            center - radius - 20.0,
+            "180°",
+            11.0,
+            TextAnchor::Middle,
+        ));
+
+        // 90° axis and labels
+        geo.push(Primitive::line(
+            center - radius,
+            center,
+            center + radius,
+            center,
+            LineStyle::solid(StyleRole::Axis, 1.5),
+        ));
+        for x in [center - radius - 20.0, center + radius + 20.0] {
+            geo.push(Primitive::text(x, center, "90°", 11.0, TextAnchor::Middle).centered());
+        }
+
+        // All planes, faded by their peak relative to the scale
+        let palette = ColorPalette::default();
+        for curve in &self.all_planes {
+            let opacity = 0.1 + 0.5 * (curve.max_intensity() / self.scale.scale_max).min(1.0);
+            let color = palette.color_for_c_angle(curve.c_angle);
+            geo.push(Primitive::Path {
+                points: self.curve_screen_points(curve, center, center, radius),
+                closed: true,
+                fill: None,
+                stroke: LineStyle::solid(StyleRole::Translucent(color, opacity), 1.0),
+            });
+        }
+
+        // Curves; selected plane pairs are labelled with their own angles
+        let mut curves = vec![(
+            &self.c0_c180_curve,
+            LineStyle::solid(StyleRole::CurvePrimary, 2.5),
+            Some(StyleRole::CurvePrimaryFill),
+            if self.c0_c180_curve.c_angle == 0.0 {
+                labels.plane_c0_c180.clone()
+            } else {
+                self.c0_c180_curve.label.clone()
+            },
+        )];
+        if self.show_c90_c270() {
+            curves.push((
+                &self.c90_c270_curve,
+                LineStyle::dashed(StyleRole::CurveSecondary, 2.5, 6.0, 4.0),
+                Some(StyleRole::CurveSecondaryFill),
+                if self.c90_c270_curve.c_angle == 90.0 {
+                    labels.plane_c90_c270.clone()
+                } else {
+                    self.c90_c270_curve.label.clone()
+                },
+            ));
+        }
+        if let Some(curve) = &self.max_plane {
+            curves.push((
+                curve,
+                LineStyle::dashed(StyleRole::Fixed(Color::new(245, 158, 11)), 3.0, 2.0, 3.0),
+                None,
+                format!("{} {}", labels.max_label, curve.label),
+            ));
+        }
+        for (curve, stroke, fill, _) in &curves {
+            if !curve.is_empty() {
+                geo.push(Primitive::Path {
+                    points: self.curve_screen_points(curve, center, center, radius),
+                    closed: true,
+                    fill: *fill,
+                    stroke: *stroke,
+                });
+            }
+        }
+
+        // Center point
+        geo.push(Primitive::Circle {
+            center: Point2D::new(center, center),
+            radius: 3.0,
+            fill: Some(StyleRole::Text),
+            stroke: None,
+        });
+
+        // Legend, bottom left
+        let top = size - 55.0 - curves.len().saturating_sub(2) as f64 * 23.0;
+        for (i, (_, stroke, fill, label)) in curves.into_iter().enumerate() {
+            let y = top + i as f64 * 23.0;
+            geo.push(Primitive::Rect {
+                x: 15.0,
+                y,
+                width: 16.0,
+                height: 16.0,
+                fill,
+                stroke: Some(LineStyle {
+                    width: 2.0,
+                    dash: stroke.dash.map(|_| (4.0, 2.0)),
+                    ..stroke
+                }),
+                corner_radius: 2.0,
+            });
+            geo.push(
+                Primitive::text(37.0, y + 12.0, label.clone(), 12.0, TextAnchor::Start)
+                    .with_role(StyleRole::Text),
+            );
+            geo.legend.push(LegendItem {
+                label,
+                stroke,
+                fill,
+            });
+        }
+
+        geo.push(Primitive::text(
+            size - 15.0,
+            size - 15.0,
+            format!(
+                "{}{}",
+                labels.intensity_unit,
+                self.radial_scale.unit_suffix()
+            ),
+            11.0,
+            TextAnchor::End,
+        ));
+
+        geo
+    }
+
+    /// Lay out a label-free thumbnail of the polar curves
+    ///
+    /// Meant for catalog and library previews (32–128 px), where text would
+    /// be unreadable: only the outer circle, the nadir axis and the curves.
+    pub fn to_thumbnail_geometry(&self, size: f64) -> DiagramGeometry {
+        let center = size / 2.0;
+        let radius = size / 2.0 - (size * 0.06).max(1.0);
+        let scale = self.scale.scale_max / radius;
+        let line_width = (size / 64.0).clamp(1.0, 2.5);
+        let mut geo = DiagramGeometry::new(size, size);
+        geo.describe("Polar intensity diagram", "");
+
+        geo.push(
+            Primitive::Circle {
+                center: Point2D::new(center, center),
+                radius,
+                fill: None,
+                stroke: None,
+            }
+            .with_stroke(LineStyle::solid(StyleRole::Grid, 1.0)),
+        );
+        geo.push(Primitive::line(
+            center,
+            center - radius,
+            center,
+            center + radius,
+            LineStyle::solid(StyleRole::Grid, 1.0),
+        ));
+
+        let mut curves = vec![(
+            &self.c0_c180_curve,
+            StyleRole::CurvePrimary,
+            StyleRole::CurvePrimaryFill,
+        )];
+        if self.show_c90_c270() {
+            curves.push((
+                &self.c90_c270_curve,
+                StyleRole::CurveSecondary,
+                StyleRole::CurveSecondaryFill,
+            ));
+        }
+        for (curve, stroke, fill) in curves {
+            if !curve.is_empty() {
+                geo.push(Primitive::Path {
+                    points: curve.screen_points(center, center, scale),
+                    closed: true,
+                    fill: Some(fill),
+                    stroke: LineStyle::solid(stroke, line_width),
+                });
+            }
+        }
+
+        geo
+    }
+}
+
+impl CartesianDiagram {
+    /// Lay out the cartesian diagram (the size it was built for)
+    pub fn to_geometry(&self, width: f64, height: f64, labels: &SvgLabels) -> DiagramGeometry {
+        let left = self.margin_left;
+        let top = self.margin_top;
+        let plot_w = self.plot_width;
+        let plot_h = self.plot_height;
+        let y_max = self.scale.scale_max;
+        let mut geo = DiagramGeometry::new(width, height);
+        geo.describe(
+            "Cartesian intensity diagram",
+            format!(
+                "{} against {} for {}, maximum {:.0} {}",
+                labels.intensity_axis,
+                labels.gamma_axis,
+                self.curves
+                    .iter()
+                    .map(|c| c.label.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                self.scale.max_intensity,
+                labels.intensity_unit
+            ),
+        );
+
+        // Plot area
+        geo.push(
+            Primitive::rect(left, top, plot_w, plot_h)
+                .with_fill(StyleRole::Surface)
+                .with_stroke(LineStyle::solid(StyleRole::Axis, 1.0)),
+        );
+
+        let grid = LineStyle::solid(StyleRole::Grid, 1.0);
+        for &v in &self.y_ticks {
+            let y = top + plot_h * (1.0 - v / y_max);
+            geo.push(Primitive::line(left, y, left + plot_w, y, grid));
+            geo.push(
+                Primitive::text(left - 8.0, y, format!("{v:.0}"), 11.0, TextAnchor::End).centered(),
+            );
+        }
+        for &v in &self.x_ticks {
+            let x = left + plot_w * (v / self.max_gamma);
+            geo.push(Primitive::line(x, top, x, top + plot_h, grid));
+            geo.push(Primitive::text(
+                x,
+                top + plot_h + 18.0,
+                format!("{v:.0}°"),
+                11.0,
+                TextAnchor::Middle,
+            ));
+        }
+
+        // Curves (already in output pixels)
+        for curve in &self.curves {
+            let stroke = LineStyle::solid(StyleRole::Fixed(curve.color), 2.5);
+            geo.push(Primitive::Path {
+                points: curve
+                    .points
+                    .iter()
+                    .map(|p| Point2D::new(p.x, p.y))
+                    .collect(),
+                closed: false,
+                fill: None,
+                stroke,
+            });
+        }
+
+        for primitive in self.batwing_primitives() {
+            geo.push(primitive);
+        }
+
+        // Axis titles
+        geo.push(
+            Primitive::text(
+                left + plot_w / 2.0,
+                height - 8.0,
+                labels.gamma_axis.clone(),
+                12.0,
+                TextAnchor::Middle,
+            )
+            .with_role(StyleRole::Text),
+        );
+        geo.push(
+            Primitive::text(
+                18.0,
+                top + plot_h / 2.0,
+                labels.intensity_axis.clone(),
+                12.0,
+                TextAnchor::Middle,
+            )
+            .with_role(StyleRole::Text)
+            .rotated(-90.0),
+        );
+
+        // Legend box, top left of the plot
+        let lx = left + 10.0;
+        let ly = top + 10.0;
+        geo.push(Primitive::Rect {
+            x: lx - 5.0,
+            y: ly - 5.0,
+            width: 90.0,
+            height: self.curves.len() as f64 * 18.0 + 10.0,
+            fill: Some(StyleRole::LegendBackground),
+            stroke: Some(LineStyle::solid(StyleRole::Axis, 1.0)),
+            corner_radius: 4.0,
+        });
+        for (i, curve) in self.curves.iter().enumerate() {
+            let y = ly + i as f64 * 18.0 + 8.0;
+            let stroke = LineStyle::solid(StyleRole::Fixed(curve.color), 2.5);
+            geo.push(Primitive::line(lx, y, lx + 18.0, y, stroke));
+            geo.push(
+                Primitive::text(
+                    lx + 24.0,
+                    y + 4.0,
+                    curve.label.clone(),
+                    11.0,
+                    TextAnchor::Start,
+                )
+                .with_role(StyleRole::Text),
+            );
+            geo.legend.push(LegendItem {
+                label: curve.label.clone(),
+                stroke,
+                fill: None,
+            });
+        }
+
+        geo.push(Primitive::text(
+            width - 15.0,
+            20.0,
+            format!(
+                "{} {:.0} cd/klm",
+                labels.max_label, self.scale.max_intensity
+            ),
+            11.0,
+            TextAnchor::End,
+        ));
+
+        geo
+    }
+
+    /// Callouts of the batwing peaks, the valley at nadir and the wing
+    /// asymmetry; empty without [`CartesianDiagram::with_batwing_annotations`].
+    pub fn batwing_primitives(&self) -> Vec<Primitive> {
+        let Some(batwing) = &self.batwing else {
+            return Vec::new();
+        };
+        let accent = StyleRole::Fixed(Color::new(168, 85, 247));
+        let mut primitives = Vec::new();
+
+        // Peak markers, the second label moved down if both peaks coincide
+        let mut last_label: Option<Point2D> = None;
+        for peak in [&batwing.peak_c0, &batwing.peak_c180] {
+            if peak.gamma > self.max_gamma {
+                continue;
+            }
+            let at = self.to_screen(peak.gamma, peak.intensity);
+            primitives.push(Primitive::Circle {
+                center: at,
+                radius: 4.0,
+                fill: Some(accent),
+                stroke: None,
+            });
+            let mut label = Point2D::new(at.x + 8.0, at.y - 8.0);
+            if let Some(prev) = last_label {
+                if (prev.x - label.x).abs() < 90.0 && (prev.y - label.y).abs() < 14.0 {
+                    label.y = prev.y + 14.0;
+                }
+            }
+            primitives.push(
+                Primitive::text(
+                    label.x,
+                    label.y,
+                    format!(
+                        "C{:.0} peak {:.0} @ {:.0}°",
+                        peak.c_angle, peak.intensity, peak.gamma
+                    ),
+                    10.0,
+                    TextAnchor::Start,
+                )
+                .with_role(accent),
+            );
+            last_label = Some(label);
+        }
+
+        // Valley: dimension line at nadir from the center up to the lower peak
+        let lower = batwing.peak_c0.intensity.min(batwing.peak_c180.intensity);
+        let bottom = self.to_screen(0.0, batwing.center_intensity);
+        let top = self.to_screen(0.0, lower);
+        let x = bottom.x + 6.0;
+        let dimension = LineStyle::dashed(accent, 1.5, 4.0, 3.0);
+        primitives.push(Primitive::line(x, bottom.y, x, top.y, dimension));
+        for y in [bottom.y, top.y] {
+            primitives.push(Primitive::line(x - 4.0, y, x + 4.0, y, dimension));
+        }
+        // Valley depth with the wing asymmetry below
+        let mid = (bottom.y + top.y) / 2.0;
+        for (dy, text) in [
+            (
+                -6.0,
+                format!("Valley −{:.0}%", batwing.valley_depth * 100.0),
+            ),
+            (8.0, format!("Asymmetry {:.2}", batwing.asymmetry_ratio)),
+        ] {
+            primitives.push(
+                Primitive::text(x + 6.0, mid + dy, text, 10.0, TextAnchor::Start)
+                    .with_role(accent)
+                    .centered(),
+            );
+        }
+
+        primitives
+    }
+}
+
+impl HeatmapDiagram {
+    /// Lay out the heatmap (the size it was built for)
+    pub fn to_geometry(&self, width: f64, height: f64, labels: &SvgLabels) -> DiagramGeometry {
+        let mut geo = DiagramGeometry::new(width, height);
+        geo.describe(
+            labels.heatmap_title.clone(),
+            format!(
+                "{} C-planes by {} gamma angles, maximum {:.0} cd",
+                self.c_angles.len(),
+                self.g_angles.len(),
+                self.max_candela
+            ),
+        );
+        if self.is_empty() {
+            geo.push(Primitive::text(
+                width / 2.0,
+                height / 2.0,
+                labels.no_data.clone(),
+                14.0,
+                TextAnchor::Middle,
+            ));
+            return geo;
+        }
+
+        let left = self.margin_left;
+        let top = self.margin_top;
+
+        geo.push(
+            Primitive::text(
+                width / 2.0,
+                25.0,
+                labels.heatmap_title.clone(),
+                14.0,
+                TextAnchor::Middle,
+            )
+            .with_role(StyleRole::Text),
+        );
+
+        geo.push(
+            Primitive::rect(left, top, self.plot_width, self.plot_height)
+                .with_stroke(LineStyle::solid(StyleRole::Grid, 1.0)),
+        );
+
+        for cell in &self.cells {
+            geo.push(
+                Primitive::rect(cell.x, cell.y, cell.width, cell.height)
+                    .with_fill(StyleRole::Fixed(cell.color)),
+            );
+        }
+
+        // Axis tick labels, thinned out for dense data
+        let label_step = |n: usize| match n {
+            0..=10 => 1,
+            11..=20 => 2,
+            _ => 5,
+        };
+        for (x, _, label) in self.x_labels(label_step(self.c_angles.len())) {
+            geo.push(Primitive::text(
+                x,
+                top + self.plot_height + 15.0,
+                label,
+                9.0,
+                TextAnchor::Middle,
+            ));
+        }
+        for (y, _, label) in self.y_labels(label_step(self.g_angles.len())) {
+            geo.push(Primitive::text(left - 8.0, y, label, 9.0, TextAnchor::End).centered());
+        }
+
+        geo.push(
+            Primitive::text(
+                left + self.plot_width / 2.0,
+                height - 10.0,
+                labels.c_plane_axis.clone(),
+                12.0,
+                TextAnchor::Middle,
+            )
+            .with_role(StyleRole::Text),
+        );
+        geo.push(
+            Primitive::text(
+                18.0,
+                top + self.plot_height / 2.0,
+                labels.gamma_angle_axis.clone(),
+                12.0,
+                TextAnchor::Middle,
+            )
+            .with_role(StyleRole::Text)
+            .rotated(-90.0),
+        );
+
+        // Color legend
+        let legend_x = width - 80.0;
+        let legend_w = 20.0;
+        let segments = self.legend_entries.len().max(1);
+        let segment_h = self.plot_height / segments as f64;
+        for (i, (_, color, _)) in self.legend_entries.iter().enumerate() {
+            geo.push(
+                Primitive::rect(
+                    legend_x,
+                    top + i as f64 * segment_h,
+                    legend_w,
+                    segment_h + 0.5,
+                )
+                .with_fill(StyleRole::Fixed(*color)),
+            );
+        }
+        geo.push(
+            Primitive::rect(legend_x, top, legend_w, self.plot_height)
+                .with_stroke(LineStyle::solid(StyleRole::Grid, 1.0)),
+        );
+
+        for i in 0..=5 {
+            let frac = i as f64 / 5.0;
+            let y = top + frac * self.plot_height;
+            let value = self.candela_at_position(1.0 - frac);
+            geo.push(Primitive::line(
+                legend_x + legend_w,
+                y,
+                legend_x + legend_w + 5.0,
+                y,
+                LineStyle::solid(StyleRole::Grid, 1.0),
+            ));
+            geo.push(
+                Primitive::text(
+                    legend_x + legend_w + 8.0,
+                    y,
+                    if value >= 10.0 || value == 0.0 {
+                        format!("{value:.0}")
+                    } else {
+                        format!("{value:.2}")
+                    },
+                    9.0,
+                    TextAnchor::Start,
+                )
+                .centered(),
+            );
+        }
+        geo.push(
+            Primitive::text(
+                legend_x + legend_w / 2.0,
+                top - 8.0,
+                if self.log_scale { "cd (log)" } else { "cd" },
+                10.0,
+                TextAnchor::Middle,
+            )
+            .with_role(StyleRole::Text),
+        );
+        geo.push(Primitive::text(
+            width - 15.0,
+            25.0,
+            format!("Max: {:.0} cd", self.max_candela),
+            11.0,
+            TextAnchor::End,
+        ));
+
+        geo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Eulumdat, LampSet, Symmetry};
+
+    fn test_ldt() -> Eulumdat {
+        Eulumdat {
+            symmetry: Symmetry::BothPlanes,
+            c_angles: vec![0.0, 30.0, 60.0, 90.0],
+            g_angles: vec![0.0, 30.0, 60.0, 90.0],
+            intensities: vec![
+                vec![300.0, 250.0, 120.0, 10.0],
+                vec![290.0, 240.0, 110.0, 9.0],
+                vec![280.0, 220.0, 100.0, 8.0],
+                vec![270.0, 200.0, 90.0, 7.0],
+            ],
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 1000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_polar_geometry_matches_curve_points() {
+        let polar = PolarDiagram::from_eulumdat(&test_ldt());
+        let labels = SvgLabels::default();
+        let geo = polar.to_geometry(500.0, &labels);
+        let scale = polar.scale.scale_max / 190.0;
+        let expected = polar.c0_c180_curve.screen_points(250.0, 250.0, scale);
+
+        let path = geo.primitives.iter().find_map(|p| match p {
+            Primitive::Path { points, .. } => Some(points),
+            _ => None,
+        });
+        assert_eq!(path, Some(&expected));
+        assert_eq!(geo.legend.len(), 2);
+    }
+
+    #[test]
+    fn test_thumbnail_has_no_text() {
+        let polar = PolarDiagram::from_eulumdat(&test_ldt());
+        let geo = polar.to_thumbnail_geometry(64.0);
+        assert!(!geo
+            .primitives
+            .iter()
+            .any(|p| matches!(p, Primitive::Text { .. })));
+        let paths = geo
+            .primitives
+            .iter()
+            .filter(|p| matches!(p, Primitive::Path { .. }))
+            .count();
+        assert_eq!(paths, 2);
+
+        let svg = PolarDiagram::render_thumbnail_svg(&test_ldt(), 64.0, &SvgTheme::light());
+        assert!(svg.starts_with(r#"<svg viewBox="0 0 64 64""#));
+    }
+
+    #[test]
+    fn test_svg_backend_resolves_roles() {
+        let ldt = test_ldt();
+        let cartesian = CartesianDiagram::from_eulumdat(&ldt, 500.0, 380.0, 8);
+        let geo = cartesian.to_geometry(500.0, 380.0, &SvgLabels::default());
+        let theme = SvgTheme::dark();
+        let svg = geo.to_svg(&theme);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>"));
+        assert!(svg.contains(&theme.background));
+        assert!(svg.contains("rotate(-90"));
+    }
+
+    #[test]
+    fn test_svg_backend_applies_theme_scales_and_logo() {
+        let polar = PolarDiagram::from_eulumdat(&test_ldt());
+        let geo = polar.to_geometry(500.0, &SvgLabels::default());
+        let theme = SvgTheme::builder()
+            .font_scale(2.0)
+            .line_scale(0.5)
+            .logo(super::super::SvgLogo::new("logo.svg", 50.0, 20.0))
+            .build();
+        let svg = geo.to_svg(&theme);
+
+        // Angle labels are 11 px, curves 2.5 px wide
+        assert!(svg.contains(r#"font-size="22""#));
+        assert!(svg.contains(r#"stroke-width="1.25""#));
+        assert!(svg.contains(r#"<image href="logo.svg" x="442.0" y="472.0""#));
+        assert!(!geo.to_svg(&SvgTheme::light()).contains("<image"));
+    }
+
+    #[test]
+    fn test_svg_backend_titles_diagram() {
+        let polar = PolarDiagram::from_eulumdat(&test_ldt());
+        let svg = polar.to_svg(500.0, 500.0, &SvgTheme::light());
+        assert!(svg.contains(r#" role="img"><title>Polar intensity diagram</title><desc>"#));
+        assert!(svg.contains("planes, maximum"));
+    }
+
+    #[test]
+    fn test_svg_backend_curve_patterns() {
+        let ldt = test_ldt();
+        let cartesian = CartesianDiagram::from_eulumdat(&ldt, 500.0, 380.0, 8);
+        let geo = cartesian.to_geometry(500.0, 380.0, &SvgLabels::default());
+        let plain = geo.to_svg(&SvgTheme::light());
+        let patterned = geo.to_svg(&SvgTheme::builder().curve_patterns(true).build());
+        assert!(!plain.contains(r#"stroke-dasharray="8,4""#));
+        // The first curve stays solid, the second is dashed in plot and legend
+        assert_eq!(patterned.matches(r#"stroke-dasharray="8,4""#).count(), 2);
+
+        let polar = PolarDiagram::from_eulumdat(&ldt).to_geometry(500.0, &SvgLabels::default());
+        let hatched = polar.to_svg(&SvgTheme::builder().curve_patterns(true).build());
+        assert_eq!(hatched.matches("<pattern ").count(), 1);
+        assert!(hatched.contains(r#"fill="url(#eulumdat-hatch-secondary)""#));
+    }
+
+    #[test]
+    fn test_heatmap_geometry_has_cells_and_legend() {
+        let ldt = test_ldt();
+        let heatmap = HeatmapDiagram::from_eulumdat(&ldt, 600.0, 420.0);
+        let geo = heatmap.to_geometry(600.0, 420.0, &SvgLabels::default());
+        let filled = geo
+            .primitives
+            .iter()
+            .filter(|p| {
+                matches!(
+                    p,
+                    Primitive::Rect {
+                        fill: Some(StyleRole::Fixed(_)),
+                        ..
+                    }
+                )
+            })
+            .count();
+        // Cells plus the color legend segments
+        assert_eq!(filled, heatmap.cells.len() + heatmap.legend_entries.len());
+    }
+}
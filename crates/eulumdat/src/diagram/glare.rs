@@ -0,0 +1,208 @@
+//! Luminance versus viewing angle (glare) diagram
+//!
+//! Plots the average luminaire luminance over γ for the C0 and C90 planes on a
+//! quarter polar grid (nadir down, horizontal to the right), with the
+//! EN 12464-1 screen-work limits drawn from γ = 65° so a luminaire that
+//! exceeds them is visible at a glance.
+//!
+//! ```rust,no_run
+//! use eulumdat::{Eulumdat, diagram::{GlareDiagram, SvgTheme}};
+//!
+//! let ldt = Eulumdat::from_file("office.ldt")?;
+//! let svg = GlareDiagram::from_eulumdat(&ldt).to_svg(500.0, 500.0, &SvgTheme::light());
+//! std::fs::write("glare.svg", svg)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use super::{DiagramScale, RadialScale};
+use crate::calculations::luminance::{
+    luminance_at, LIMIT_HIGH_LUMINANCE_SCREEN, LIMIT_MEDIUM_LUMINANCE_SCREEN,
+};
+use crate::Eulumdat;
+
+/// A sample of a luminance curve
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlarePoint {
+    /// Viewing angle γ in degrees
+    pub gamma: f64,
+    /// Average luminance in cd/m²
+    pub luminance: f64,
+}
+
+/// Luminance over γ in one C-plane
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlareCurve {
+    /// C-plane angle
+    pub c_angle: f64,
+    /// Label (e.g., "C0")
+    pub label: String,
+    /// Samples from nadir to horizontal
+    pub points: Vec<GlarePoint>,
+}
+
+impl GlareCurve {
+    /// Highest luminance at or above `min_gamma` (cd/m²)
+    pub fn max_from(&self, min_gamma: f64) -> f64 {
+        self.points
+            .iter()
+            .filter(|p| p.gamma >= min_gamma - 1e-9)
+            .map(|p| p.luminance)
+            .fold(0.0, f64::max)
+    }
+}
+
+/// Luminance versus viewing angle with the EN 12464-1 limits
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlareDiagram {
+    /// C0 and C90 curves
+    pub curves: Vec<GlareCurve>,
+    /// Radial scale (max covers the curves and the upper limit)
+    pub scale: DiagramScale,
+    /// Radial mapping of luminance
+    pub radial_scale: RadialScale,
+    /// Luminance limits drawn from [`GlareDiagram::LIMIT_START_GAMMA`] (cd/m²)
+    pub limits: Vec<f64>,
+}
+
+impl GlareDiagram {
+    /// Viewing angle from which EN 12464-1 limits the luminance
+    pub const LIMIT_START_GAMMA: f64 = 65.0;
+
+    /// Sampling step along γ in degrees
+    const GAMMA_STEP: f64 = 2.5;
+
+    /// Compute the C0 and C90 luminance curves, on a logarithmic scale.
+    pub fn from_eulumdat(ldt: &Eulumdat) -> Self {
+        let steps = (90.0 / Self::GAMMA_STEP) as usize;
+        let curves: Vec<GlareCurve> = [0.0, 90.0]
+            .into_iter()
+            .map(|c_angle| GlareCurve {
+                c_angle,
+                label: format!("C{c_angle:.0}"),
+                points: (0..=steps)
+                    .map(|i| {
+                        let gamma = i as f64 * Self::GAMMA_STEP;
+                        GlarePoint {
+                            gamma,
+                            luminance: luminance_at(ldt, c_angle, gamma),
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let radial_scale = RadialScale::Logarithmic;
+        let max_luminance = curves.iter().map(|c| c.max_from(0.0)).fold(0.0, f64::max);
+        Self {
+            curves,
+            scale: Self::scale_for(radial_scale, max_luminance),
+            radial_scale,
+            limits: vec![LIMIT_MEDIUM_LUMINANCE_SCREEN, LIMIT_HIGH_LUMINANCE_SCREEN],
+        }
+    }
+
+    /// Use a different radial scale.
+    pub fn with_radial_scale(mut self, radial_scale: RadialScale) -> Self {
+        self.scale = Self::scale_for(radial_scale, self.scale.max_intensity);
+        self.radial_scale = radial_scale;
+        self
+    }
+
+    /// Scale up to the highest luminance or the upper limit; whole decades
+    /// on the logarithmic scale.
+    fn scale_for(radial_scale: RadialScale, max_luminance: f64) -> DiagramScale {
+        let peak = max_luminance.max(LIMIT_HIGH_LUMINANCE_SCREEN);
+        let mut scale = DiagramScale::from_max_intensity(peak, 5);
+        if radial_scale == RadialScale::Logarithmic {
+            scale.scale_max = 10f64.powf(peak.log10().ceil());
+        }
+        scale.max_intensity = max_luminance;
+        scale
+    }
+
+    /// Whether the luminaire has no luminous area to compute luminance from
+    pub fn is_empty(&self) -> bool {
+        self.scale.max_intensity <= 0.0
+    }
+
+    /// Highest luminance from [`GlareDiagram::LIMIT_START_GAMMA`] to 90° (cd/m²)
+    pub fn max_limit_zone_luminance(&self) -> f64 {
+        self.curves
+            .iter()
+            .map(|c| c.max_from(Self::LIMIT_START_GAMMA))
+            .fold(0.0, f64::max)
+    }
+
+    /// Whether both curves stay at or below `limit` from 65° on
+    pub fn complies(&self, limit: f64) -> bool {
+        self.max_limit_zone_luminance() <= limit
+    }
+
+    /// Distance from the center at which `luminance` is drawn for an outer `radius`
+    pub fn radius_of(&self, luminance: f64, radius: f64) -> f64 {
+        self.radial_scale.fraction(luminance, self.scale.scale_max) * radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagram::SvgTheme;
+    use crate::{LampSet, Symmetry};
+
+    fn panel(peak: f64) -> Eulumdat {
+        Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            c_angles: vec![0.0],
+            g_angles: (0..=18).map(|i| i as f64 * 5.0).collect(),
+            intensities: vec![(0..=18)
+                .map(|i| peak * (i as f64 * 5.0).to_radians().cos())
+                .collect()],
+            luminous_area_length: 600.0,
+            luminous_area_width: 600.0,
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 4000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_glare_compliance() {
+        // Lambertian panel: constant 300 × 4 / 0.36 ≈ 3333 cd/m²
+        let bright = GlareDiagram::from_eulumdat(&panel(300.0));
+        assert_eq!(bright.curves.len(), 2);
+        assert!((bright.max_limit_zone_luminance() - 3333.3).abs() < 1.0);
+        assert!(!bright.complies(LIMIT_HIGH_LUMINANCE_SCREEN));
+        assert_eq!(bright.scale.scale_max, 10_000.0);
+
+        let dim = GlareDiagram::from_eulumdat(&panel(100.0));
+        assert!(dim.complies(LIMIT_MEDIUM_LUMINANCE_SCREEN));
+
+        let linear = dim.with_radial_scale(RadialScale::Linear);
+        assert_eq!(linear.scale.scale_max, 3000.0);
+        assert!((linear.radius_of(1500.0, 100.0) - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_glare_svg() {
+        let svg =
+            GlareDiagram::from_eulumdat(&panel(300.0)).to_svg(500.0, 500.0, &SvgTheme::light());
+        assert!(svg.contains("EN 12464-1"));
+        assert!(svg.contains("≤ 3000 cd/m² ✗"));
+        assert!(svg.contains("stroke-dasharray"));
+
+        let mut no_area = panel(300.0);
+        no_area.luminous_area_length = 0.0;
+        let empty = GlareDiagram::from_eulumdat(&no_area);
+        assert!(empty.is_empty());
+        assert!(empty
+            .to_svg(500.0, 500.0, &SvgTheme::light())
+            .contains("No data"));
+    }
+}
@@ -3,7 +3,7 @@
 //! Generates the data needed for 2D intensity heatmaps showing
 //! candela values across C-planes (x-axis) and gamma angles (y-axis).
 
-use super::color::{heatmap_color, Color};
+use super::color::{Color, ColorMap};
 use super::{DiagramScale, SvgTheme};
 use crate::{Eulumdat, PhotometricSummary};
 
@@ -62,8 +62,20 @@ pub struct HeatmapDiagram {
     pub margin_top: f64,
     /// Whether to render numeric values on cells
     pub show_values: bool,
+    /// Color map of the cells and the legend
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub color_map: ColorMap,
+    /// Map colors logarithmically over the top three decades
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub log_scale: bool,
 }
 
+/// Intensity decades covered by the logarithmic color scale
+const LOG_DECADES: f64 = 3.0;
+
+/// Number of color legend segments
+const LEGEND_SEGMENTS: usize = 50;
+
 impl HeatmapDiagram {
     /// Generate heatmap diagram data from Eulumdat
     ///
@@ -114,25 +126,77 @@ impl HeatmapDiagram {
             scale_factor,
         );
 
-        // Generate legend entries
-        let legend_entries = generate_legend_entries(max_candela, 50);
-
         let scale = DiagramScale::from_max_intensity(max_intensity, 5);
 
-        Self {
+        let mut diagram = Self {
             cells,
             scale,
             max_candela,
             total_flux,
             c_angles: ldt.c_angles.clone(),
             g_angles: ldt.g_angles.clone(),
-            legend_entries,
+            legend_entries: Vec::new(),
             plot_width,
             plot_height,
             margin_left,
             margin_top,
             show_values: false,
+            color_map: ColorMap::default(),
+            log_scale: false,
+        };
+        diagram.apply_colors();
+        diagram
+    }
+
+    /// Use another color map for the cells and the legend.
+    pub fn with_color_map(mut self, color_map: ColorMap) -> Self {
+        self.color_map = color_map;
+        self.apply_colors();
+        self
+    }
+
+    /// Map colors logarithmically, spreading the top three decades of
+    /// intensity over the color map so weak side lobes stay visible.
+    pub fn with_log_scale(mut self, log_scale: bool) -> Self {
+        self.log_scale = log_scale;
+        self.apply_colors();
+        self
+    }
+
+    /// Position (0-1) on the color map of a normalized intensity
+    pub fn color_position(&self, normalized: f64) -> f64 {
+        color_position(normalized, self.log_scale)
+    }
+
+    /// Candela value at a position (0-1) on the color map
+    pub fn candela_at_position(&self, position: f64) -> f64 {
+        if self.log_scale {
+            self.max_candela * 10f64.powf((position - 1.0) * LOG_DECADES)
+        } else {
+            self.max_candela * position
+        }
+    }
+
+    /// Recolor the cells and rebuild the legend for the current color map.
+    fn apply_colors(&mut self) {
+        for cell in &mut self.cells {
+            cell.color = self
+                .color_map
+                .color(color_position(cell.normalized, self.log_scale));
         }
+        if self.cells.is_empty() {
+            return;
+        }
+        self.legend_entries = (0..LEGEND_SEGMENTS)
+            .map(|i| {
+                let position = (LEGEND_SEGMENTS - 1 - i) as f64 / (LEGEND_SEGMENTS - 1) as f64;
+                (
+                    position,
+                    self.color_map.color(position),
+                    self.candela_at_position(position),
+                )
+            })
+            .collect();
     }
 
     /// Create an empty heatmap (for when there's no data)
@@ -154,6 +218,8 @@ impl HeatmapDiagram {
             margin_left: 0.0,
             margin_top: 0.0,
             show_values: false,
+            color_map: ColorMap::default(),
+            log_scale: false,
         }
     }
 
@@ -247,6 +313,16 @@ impl HeatmapDiagram {
     }
 }
 
+fn color_position(normalized: f64, log_scale: bool) -> f64 {
+    if !log_scale {
+        return normalized.clamp(0.0, 1.0);
+    }
+    if normalized <= 0.0 {
+        return 0.0;
+    }
+    (1.0 + normalized.log10() / LOG_DECADES).clamp(0.0, 1.0)
+}
+
 /// Generate heatmap cells
 fn generate_cells(
     ldt: &Eulumdat,
@@ -279,7 +355,6 @@ fn generate_cells(
 
             let x = margin_left + c_idx as f64 * cell_width;
             let y = margin_top + g_idx as f64 * cell_height;
-            let color = heatmap_color(normalized);
 
             cells.push(HeatmapCell {
                 c_index: c_idx,
@@ -289,7 +364,7 @@ fn generate_cells(
                 intensity,
                 candela,
                 normalized,
-                color,
+                color: Color::new(0, 0, 0),
                 x,
                 y,
                 width: cell_width,
@@ -301,18 +376,6 @@ fn generate_cells(
     cells
 }
 
-/// Generate color legend entries
-fn generate_legend_entries(max_candela: f64, num_segments: usize) -> Vec<(f64, Color, f64)> {
-    (0..num_segments)
-        .map(|i| {
-            let normalized = (num_segments - 1 - i) as f64 / (num_segments - 1) as f64;
-            let candela = max_candela * normalized;
-            let color = heatmap_color(normalized);
-            (normalized, color, candela)
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -366,6 +429,26 @@ mod tests {
         assert!(low_cell.normalized < 0.2);
     }
 
+    #[test]
+    fn test_heatmap_color_map_and_log_scale() {
+        let ldt = create_test_ldt();
+        let heatmap =
+            HeatmapDiagram::from_eulumdat(&ldt, 700.0, 500.0).with_color_map(ColorMap::Grayscale);
+
+        let peak = heatmap.get_cell(0, 0).unwrap();
+        assert_eq!(peak.color, Color::new(255, 255, 255));
+        let (position, color, candela) = heatmap.legend_entries[0];
+        assert_eq!((position, color), (1.0, Color::new(255, 255, 255)));
+        assert!((candela - 100.0).abs() < 1e-9);
+
+        // 18/100 sits at 1 + log10(0.18) / 3 ≈ 0.75 instead of 0.18
+        let heatmap = heatmap.with_log_scale(true);
+        let weak = heatmap.get_cell(1, 3).unwrap();
+        assert_eq!(weak.color, ColorMap::Grayscale.color(0.7517));
+        assert!((heatmap.candela_at_position(0.0) - 0.1).abs() < 1e-9);
+        assert!((heatmap.candela_at_position(2.0 / 3.0) - 10.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_empty_heatmap() {
         let ldt = Eulumdat::default();
@@ -0,0 +1,316 @@
+//! Hover values for SVG diagrams
+//!
+//! Adds transparent hit targets carrying `data-c`, `data-gamma` and
+//! `data-intensity` attributes on top of a rendered diagram, so hovering a
+//! polar curve point or heatmap cell can show its exact value. Frontends that
+//! insert SVG markup themselves (where embedded scripts do not run) can attach
+//! their own handlers to the data attributes.
+//!
+//! ```rust,no_run
+//! use eulumdat::{Eulumdat, diagram::{PolarDiagram, SvgInteractivity, SvgTheme}};
+//!
+//! let ldt = Eulumdat::from_file("luminaire.ldt")?;
+//! let svg = PolarDiagram::from_eulumdat(&ldt).to_svg_interactive(
+//!     500.0,
+//!     500.0,
+//!     &SvgTheme::light(),
+//!     SvgInteractivity::Script,
+//! );
+//! std::fs::write("polar.svg", svg)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use super::{HeatmapDiagram, PolarDiagram, SvgTheme};
+
+/// How much interactivity to embed in an SVG
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SvgInteractivity {
+    /// Plain SVG
+    #[default]
+    None,
+    /// Hit targets with data attributes and native `<title>` tooltips,
+    /// script-free for sanitized or `innerHTML` embedding
+    Tooltips,
+    /// Hit targets with data attributes and a small script that shows a
+    /// themed value box on hover (standalone files and inline SVG)
+    Script,
+}
+
+/// Area of a diagram that reports a value on hover
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HoverShape {
+    /// Curve point, hit within a few pixels
+    Point { x: f64, y: f64 },
+    /// Heatmap cell
+    Rect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+}
+
+/// A hoverable value in diagram pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HoverTarget {
+    pub shape: HoverShape,
+    /// C-plane angle in degrees
+    pub c_angle: f64,
+    /// Gamma angle in degrees
+    pub gamma: f64,
+    /// Intensity in cd/klm
+    pub intensity: f64,
+    /// Absolute intensity in cd, where the lamp flux is known
+    pub candela: Option<f64>,
+}
+
+impl HoverTarget {
+    /// Tooltip text, e.g. `C90° γ30°: 215.0 cd/klm (431 cd)`
+    pub fn label(&self) -> String {
+        let mut label = format!(
+            "C{:.0}° γ{:.0}°: {:.1} cd/klm",
+            self.c_angle, self.gamma, self.intensity
+        );
+        if let Some(candela) = self.candela {
+            label.push_str(&format!(" ({candela:.0} cd)"));
+        }
+        label
+    }
+}
+
+/// Hit radius of curve points in pixels
+const POINT_RADIUS: f64 = 5.0;
+
+const HOVER_SCRIPT: &str = r#"<script><![CDATA[
+(function () {
+  var script = document.currentScript;
+  var svg = script ? script.parentNode : document.documentElement;
+  var tip = svg.querySelector('.eulumdat-tooltip');
+  var box = tip.querySelector('rect');
+  var text = tip.querySelector('text');
+  var view = svg.viewBox.baseVal;
+  svg.addEventListener('mouseover', function (e) {
+    var target = e.target.closest ? e.target.closest('[data-intensity]') : null;
+    if (!target) { tip.setAttribute('visibility', 'hidden'); return; }
+    text.textContent = target.getAttribute('data-label');
+    tip.setAttribute('visibility', 'visible');
+    var b = target.getBBox();
+    var w = text.getComputedTextLength() + 12;
+    var x = Math.min(Math.max(b.x + b.width / 2 - w / 2, view.x), view.x + view.width - w);
+    var y = Math.max(b.y - 26, view.y);
+    box.setAttribute('x', x); box.setAttribute('y', y); box.setAttribute('width', w);
+    text.setAttribute('x', x + 6); text.setAttribute('y', y + 14);
+  });
+  svg.addEventListener('mouseleave', function () { tip.setAttribute('visibility', 'hidden'); });
+})();
+]]></script>"#;
+
+impl SvgInteractivity {
+    /// Insert the hover targets (and the script) before the closing `</svg>`
+    pub fn apply(self, svg: &str, targets: &[HoverTarget], theme: &SvgTheme) -> String {
+        let Some(end) = svg.rfind("</svg>") else {
+            return svg.to_string();
+        };
+        if self == Self::None || targets.is_empty() {
+            return svg.to_string();
+        }
+
+        let mut layer = String::from(r#"<g class="eulumdat-hover">"#);
+        for target in targets {
+            let label = escape_attr(&target.label());
+            let shape = match target.shape {
+                HoverShape::Point { x, y } => {
+                    format!(r#"<circle cx="{x:.1}" cy="{y:.1}" r="{POINT_RADIUS}""#)
+                }
+                HoverShape::Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                } => format!(
+                    r#"<rect x="{x:.1}" y="{y:.1}" width="{width:.1}" height="{height:.1}""#
+                ),
+            };
+            layer.push_str(&format!(
+                r#"{shape} fill="transparent" data-c="{}" data-gamma="{}" data-intensity="{:.2}""#,
+                target.c_angle, target.gamma, target.intensity
+            ));
+            if let Some(candela) = target.candela {
+                layer.push_str(&format!(r#" data-candela="{candela:.1}""#));
+            }
+            match self {
+                Self::Tooltips => {
+                    layer.push_str(&format!("><title>{label}</title></{}>", tag(&target.shape)))
+                }
+                _ => layer.push_str(&format!(r#" data-label="{label}"/>"#)),
+            }
+        }
+        layer.push_str("</g>");
+
+        if self == Self::Script {
+            layer.push_str(&format!(
+                r#"<g class="eulumdat-tooltip" visibility="hidden" pointer-events="none"><rect height="20" rx="3" fill="{}" stroke="{}" stroke-width="0.5"/><text font-size="11" fill="{}" font-family="{}"></text></g>"#,
+                theme.legend_bg, theme.axis, theme.text, theme.font_family
+            ));
+            layer.push_str(HOVER_SCRIPT);
+        }
+
+        let mut result = String::with_capacity(svg.len() + layer.len());
+        result.push_str(&svg[..end]);
+        result.push_str(&layer);
+        result.push_str(&svg[end..]);
+        result
+    }
+}
+
+fn tag(shape: &HoverShape) -> &'static str {
+    match shape {
+        HoverShape::Point { .. } => "circle",
+        HoverShape::Rect { .. } => "rect",
+    }
+}
+
+fn escape_attr(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+}
+
+impl PolarDiagram {
+    /// Hover targets for the curve points, laid out like [`PolarDiagram::to_svg`]
+    /// in a `size` × `size` square
+    pub fn hover_targets(&self, size: f64) -> Vec<HoverTarget> {
+        let center = size / 2.0;
+        let radius = size / 2.0 - 60.0;
+        let scale = self.scale.scale_max / radius;
+
+        let mut curves = vec![&self.c0_c180_curve];
+        if self.show_c90_c270() {
+            curves.push(&self.c90_c270_curve);
+        }
+        curves
+            .into_iter()
+            .flat_map(|curve| {
+                curve.points.iter().map(move |p| HoverTarget {
+                    shape: HoverShape::Point {
+                        x: center + p.x / scale,
+                        y: center + p.y / scale,
+                    },
+                    // The left half of the curve is the opposite plane
+                    c_angle: if p.x < 0.0 {
+                        (curve.c_angle + 180.0) % 360.0
+                    } else {
+                        curve.c_angle
+                    },
+                    gamma: p.gamma,
+                    intensity: p.intensity,
+                    candela: None,
+                })
+            })
+            .collect()
+    }
+
+    /// Generate the polar SVG with hover values on the curve points
+    pub fn to_svg_interactive(
+        &self,
+        width: f64,
+        height: f64,
+        theme: &SvgTheme,
+        interactivity: SvgInteractivity,
+    ) -> String {
+        let svg = self.to_svg(width, height, theme);
+        interactivity.apply(&svg, &self.hover_targets(width.min(height)), theme)
+    }
+}
+
+impl HeatmapDiagram {
+    /// Hover targets for all cells
+    pub fn hover_targets(&self) -> Vec<HoverTarget> {
+        self.cells
+            .iter()
+            .map(|cell| HoverTarget {
+                shape: HoverShape::Rect {
+                    x: cell.x,
+                    y: cell.y,
+                    width: cell.width,
+                    height: cell.height,
+                },
+                c_angle: cell.c_angle,
+                gamma: cell.g_angle,
+                intensity: cell.intensity,
+                candela: (self.total_flux > 0.0).then_some(cell.candela),
+            })
+            .collect()
+    }
+
+    /// Generate the heatmap SVG with hover values on the cells
+    pub fn to_svg_interactive(
+        &self,
+        width: f64,
+        height: f64,
+        theme: &SvgTheme,
+        interactivity: SvgInteractivity,
+    ) -> String {
+        let svg = self.to_svg(width, height, theme);
+        interactivity.apply(&svg, &self.hover_targets(), theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Eulumdat, LampSet, Symmetry};
+
+    fn create_test_ldt() -> Eulumdat {
+        Eulumdat {
+            symmetry: Symmetry::BothPlanes,
+            c_angles: vec![0.0, 90.0],
+            g_angles: vec![0.0, 45.0, 90.0],
+            intensities: vec![vec![300.0, 200.0, 10.0], vec![300.0, 150.0, 5.0]],
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 2000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_polar_hover_targets() {
+        let ldt = create_test_ldt();
+        let polar = PolarDiagram::from_eulumdat(&ldt);
+        let targets = polar.hover_targets(500.0);
+
+        assert_eq!(targets.len(), polar.c0_c180_curve.points.len() * 2);
+        let peak = targets
+            .iter()
+            .find(|t| t.c_angle == 90.0 && t.gamma == 45.0)
+            .unwrap();
+        assert_eq!(peak.label(), "C90° γ45°: 150.0 cd/klm");
+
+        let svg =
+            polar.to_svg_interactive(500.0, 500.0, &SvgTheme::light(), SvgInteractivity::None);
+        assert_eq!(svg, polar.to_svg(500.0, 500.0, &SvgTheme::light()));
+    }
+
+    #[test]
+    fn test_heatmap_interactivity_modes() {
+        let ldt = create_test_ldt();
+        let heatmap = HeatmapDiagram::from_eulumdat(&ldt, 700.0, 500.0);
+        let theme = SvgTheme::light();
+
+        let svg = heatmap.to_svg_interactive(700.0, 500.0, &theme, SvgInteractivity::Tooltips);
+        assert_eq!(svg.matches("data-intensity=").count(), heatmap.cells.len());
+        assert!(svg.contains(r#"data-candela="600.0""#));
+        assert!(svg.contains("<title>C0° γ0°: 300.0 cd/klm (600 cd)</title>"));
+        assert!(!svg.contains("<script>"));
+        assert!(svg.ends_with("</svg>"));
+
+        let svg = heatmap.to_svg_interactive(700.0, 500.0, &theme, SvgInteractivity::Script);
+        assert!(svg.contains(r#"data-label="C90° γ90°: 5.0 cd/klm (10 cd)""#));
+        assert!(svg.contains(r#"class="eulumdat-tooltip""#));
+        assert!(svg.contains("<script>"));
+    }
+}
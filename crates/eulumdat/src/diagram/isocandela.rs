@@ -1,13 +1,30 @@
 //! Isocandela contour plot
 //!
-//! 2D heatmap + contour lines on Type B (H, V) axes showing
-//! equal-intensity lines at percentages of I_max.
+//! 2D heatmap + contour lines showing equal-intensity lines at percentages
+//! of I_max. The default layout uses Type B (H, V) angles on linear axes;
+//! road and flood datasheets usually show the photometric sphere in a map
+//! projection instead, see [`IsocandelaProjection`].
 
 use super::color::{heatmap_color, Color};
 use super::contour::{marching_squares, ContourLine};
 use crate::type_b_conversion::TypeBConversion;
 use crate::Eulumdat;
 
+/// How the photometric sphere is mapped onto the plot.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IsocandelaProjection {
+    /// Type B angles on linear H and V axes
+    #[default]
+    Rectangular,
+    /// Sinusoidal equal-area map of the lower hemisphere in Type B angles:
+    /// `x = H·cos V`, `y = V`, nadir in the center
+    Sinusoidal,
+    /// Azimuthal equidistant map centered on nadir: the radius is γ and
+    /// the polar angle is C (C0 to the right, C90 up)
+    Azimuthal,
+}
+
 /// A single cell in the isocandela grid
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -69,11 +86,31 @@ pub struct IsocandelaDiagram {
     pub plot_height: f64,
     pub margin_left: f64,
     pub margin_top: f64,
+    /// Sphere projection of the plot
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub projection: IsocandelaProjection,
+    /// Gamma at the rim of the azimuthal map (90° or 180°)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub gamma_max: f64,
 }
 
 impl IsocandelaDiagram {
     /// Generate isocandela diagram from Eulumdat data.
     pub fn from_eulumdat(ldt: &Eulumdat, width: f64, height: f64) -> Self {
+        Self::from_eulumdat_with_projection(ldt, width, height, IsocandelaProjection::Rectangular)
+    }
+
+    /// Generate isocandela diagram in a sphere projection.
+    ///
+    /// The sinusoidal and azimuthal maps fill a centered square of the plot
+    /// area; only cells inside the map are kept. The azimuthal map reaches
+    /// γ 180° when the data extends into the upper hemisphere, else 90°.
+    pub fn from_eulumdat_with_projection(
+        ldt: &Eulumdat,
+        width: f64,
+        height: f64,
+        projection: IsocandelaProjection,
+    ) -> Self {
         let margin_left = 60.0;
         let margin_right = 80.0;
         let margin_top = 40.0;
@@ -87,23 +124,77 @@ impl IsocandelaDiagram {
         let v_min = -90.0;
         let v_max = 90.0;
 
+        let gamma_max = if ldt.g_angles.last().is_some_and(|&g| g > 90.0) {
+            180.0
+        } else {
+            90.0
+        };
+
+        // The sphere projections use a square map with equal scales
+        let (map_left, map_top, map_width, map_height) = match projection {
+            IsocandelaProjection::Rectangular => (margin_left, margin_top, plot_width, plot_height),
+            _ => {
+                let size = plot_width.min(plot_height);
+                (
+                    margin_left + (plot_width - size) / 2.0,
+                    margin_top + (plot_height - size) / 2.0,
+                    size,
+                    size,
+                )
+            }
+        };
+
         let grid_size = 90_usize; // 2° resolution
         let h_step = (h_max - h_min) / grid_size as f64;
         let v_step = (v_max - v_min) / grid_size as f64;
-        let cell_w = plot_width / grid_size as f64;
-        let cell_h = plot_height / grid_size as f64;
+        let cell_w = map_width / grid_size as f64;
+        let cell_h = map_height / grid_size as f64;
 
-        // Build intensity grid
+        // Build intensity grid. Points outside a projected map take the
+        // value at its rim, so contours run on smoothly up to the outline.
         let mut intensity_grid: Vec<Vec<f64>> = vec![vec![0.0; grid_size]; grid_size];
+        let mut angle_grid: Vec<Vec<(f64, f64, bool)>> =
+            vec![vec![(0.0, 0.0, true); grid_size]; grid_size];
         let mut i_max: f64 = 0.0;
 
         for (row, grid_row) in intensity_grid.iter_mut().enumerate() {
+            // Map coordinates in [-1, 1], y up
+            let ny = 1.0 - (row as f64 + 0.5) / grid_size as f64 * 2.0;
             let v = v_max - (row as f64 + 0.5) * v_step; // top = +90°
             for (col, cell_val) in grid_row.iter_mut().enumerate() {
-                let h = h_min + (col as f64 + 0.5) * h_step;
-                let intensity = TypeBConversion::intensity_at_type_b(ldt, h, v);
+                let nx = (col as f64 + 0.5) / grid_size as f64 * 2.0 - 1.0;
+                let (h, v, intensity, inside) = match projection {
+                    IsocandelaProjection::Rectangular => {
+                        let h = h_min + (col as f64 + 0.5) * h_step;
+                        (h, v, TypeBConversion::intensity_at_type_b(ldt, h, v), true)
+                    }
+                    IsocandelaProjection::Sinusoidal => {
+                        let v = ny * 90.0;
+                        let half_width = v.to_radians().cos();
+                        let inside = nx.abs() <= half_width;
+                        let h = if half_width > 1e-9 {
+                            (nx / half_width).clamp(-1.0, 1.0) * 90.0
+                        } else {
+                            0.0
+                        };
+                        (
+                            h,
+                            v,
+                            TypeBConversion::intensity_at_type_b(ldt, h, v),
+                            inside,
+                        )
+                    }
+                    IsocandelaProjection::Azimuthal => {
+                        let rho = nx.hypot(ny);
+                        let c = ny.atan2(nx).to_degrees().rem_euclid(360.0);
+                        let g = rho.min(1.0) * gamma_max;
+                        let (h, v) = TypeBConversion::type_c_to_type_b(c, g);
+                        (h, v, ldt.sample(c, g), rho <= 1.0)
+                    }
+                };
                 *cell_val = intensity;
-                if intensity > i_max {
+                angle_grid[row][col] = (h, v, inside);
+                if inside && intensity > i_max {
                     i_max = intensity;
                 }
             }
@@ -112,16 +203,22 @@ impl IsocandelaDiagram {
         // Build cells
         let mut cells = Vec::with_capacity(grid_size * grid_size);
         for (row, grid_row) in intensity_grid.iter().enumerate() {
-            let v = v_max - (row as f64 + 0.5) * v_step;
             for (col, &intensity) in grid_row.iter().enumerate() {
-                let h = h_min + (col as f64 + 0.5) * h_step;
-                let normalized = if i_max > 0.0 { intensity / i_max } else { 0.0 };
+                let (h, v, inside) = angle_grid[row][col];
+                if !inside {
+                    continue;
+                }
+                let normalized = if i_max > 0.0 {
+                    (intensity / i_max).min(1.0)
+                } else {
+                    0.0
+                };
 
                 cells.push(IsocandelaCell {
                     h_angle: h,
                     v_angle: v,
-                    sx: margin_left + col as f64 * cell_w,
-                    sy: margin_top + row as f64 * cell_h,
+                    sx: map_left + col as f64 * cell_w,
+                    sy: map_top + row as f64 * cell_h,
                     width: cell_w,
                     height: cell_h,
                     intensity,
@@ -134,10 +231,10 @@ impl IsocandelaDiagram {
         // Generate contour lines at percentage levels
         let percentages = [0.10, 0.25, 0.50, 0.75, 0.90];
         let x_coords: Vec<f64> = (0..grid_size)
-            .map(|col| margin_left + (col as f64 + 0.5) * cell_w)
+            .map(|col| map_left + (col as f64 + 0.5) * cell_w)
             .collect();
         let y_coords: Vec<f64> = (0..grid_size)
-            .map(|row| margin_top + (row as f64 + 0.5) * cell_h)
+            .map(|row| map_top + (row as f64 + 0.5) * cell_h)
             .collect();
 
         let contours: Vec<IsocandelaContour> = percentages
@@ -174,6 +271,52 @@ impl IsocandelaDiagram {
             plot_height,
             margin_left,
             margin_top,
+            projection,
+            gamma_max,
+        }
+    }
+
+    /// Center and radius of the square map of the sphere projections.
+    pub(crate) fn map_circle(&self) -> (f64, f64, f64) {
+        (
+            self.margin_left + self.plot_width / 2.0,
+            self.margin_top + self.plot_height / 2.0,
+            self.plot_width.min(self.plot_height) / 2.0,
+        )
+    }
+
+    /// Screen position of the Type B direction (H, V) on the sinusoidal map.
+    pub(crate) fn sinusoidal_point(&self, h: f64, v: f64) -> (f64, f64) {
+        let (cx, cy, radius) = self.map_circle();
+        (
+            cx + h * v.to_radians().cos() / 90.0 * radius,
+            cy - v / 90.0 * radius,
+        )
+    }
+
+    /// Screen position of the Type C direction (C, γ) on the azimuthal map.
+    pub(crate) fn azimuthal_point(&self, c: f64, g: f64) -> (f64, f64) {
+        let (cx, cy, radius) = self.map_circle();
+        let r = g / self.gamma_max * radius;
+        let (sin_c, cos_c) = c.to_radians().sin_cos();
+        (cx + r * cos_c, cy - r * sin_c)
+    }
+
+    /// Whether a screen point lies on the map.
+    pub(crate) fn contains(&self, x: f64, y: f64) -> bool {
+        let (cx, cy, radius) = self.map_circle();
+        let (nx, ny) = ((x - cx) / radius, (cy - y) / radius);
+        match self.projection {
+            IsocandelaProjection::Rectangular => {
+                x >= self.margin_left
+                    && x <= self.margin_left + self.plot_width
+                    && y >= self.margin_top
+                    && y <= self.margin_top + self.plot_height
+            }
+            IsocandelaProjection::Sinusoidal => {
+                ny.abs() <= 1.0 && nx.abs() <= (ny * 90.0).to_radians().cos()
+            }
+            IsocandelaProjection::Azimuthal => nx.hypot(ny) <= 1.0,
         }
     }
 }
@@ -235,4 +378,60 @@ mod tests {
             .find(|c| c.h_angle.abs() < 2.0 && c.v_angle.abs() < 2.0);
         assert!(center_cell.is_some());
     }
+
+    #[test]
+    fn test_isocandela_sinusoidal_projection() {
+        let ldt = create_test_ldt();
+        let diagram = IsocandelaDiagram::from_eulumdat_with_projection(
+            &ldt,
+            600.0,
+            500.0,
+            IsocandelaProjection::Sinusoidal,
+        );
+
+        // The sinusoidal map covers 2/π of its bounding square
+        let share = diagram.cells.len() as f64 / (90.0 * 90.0);
+        assert!((share - 2.0 / std::f64::consts::PI).abs() < 0.02, "{share}");
+        assert!(diagram.cells.iter().all(|c| c.h_angle.abs() <= 90.0));
+        assert!((diagram.i_max - 300.0).abs() < 5.0);
+
+        let svg = diagram.to_svg(600.0, 500.0, &crate::diagram::SvgTheme::light());
+        assert!(svg.contains("Sinusoidal Projection"));
+        assert!(svg.contains("clip-path=\"url(#isocandela-map)\""));
+    }
+
+    #[test]
+    fn test_isocandela_azimuthal_projection() {
+        let ldt = create_test_ldt();
+        let diagram = IsocandelaDiagram::from_eulumdat_with_projection(
+            &ldt,
+            600.0,
+            500.0,
+            IsocandelaProjection::Azimuthal,
+        );
+        assert_eq!(diagram.gamma_max, 90.0);
+
+        // 50% of 300 cd/klm lies between γ 30° and 45° in every C-plane
+        let (cx, cy, radius) = diagram.map_circle();
+        let half = diagram
+            .contours
+            .iter()
+            .find(|c| c.percentage == 50.0)
+            .unwrap();
+        for path in &half.paths {
+            let numbers: Vec<f64> = path
+                .split_whitespace()
+                .filter_map(|t| t.parse().ok())
+                .collect();
+            for p in numbers.chunks_exact(2) {
+                let gamma = (p[0] - cx).hypot(p[1] - cy) / radius * 90.0;
+                assert!((30.0..=46.0).contains(&gamma), "{gamma}");
+            }
+        }
+
+        // C0 is to the right of nadir
+        let (x, y) = diagram.azimuthal_point(0.0, 45.0);
+        assert!((x - (cx + radius / 2.0)).abs() < 1e-9 && (y - cy).abs() < 1e-9);
+        assert!(diagram.contains(cx, cy) && !diagram.contains(cx + radius * 1.01, cy));
+    }
 }
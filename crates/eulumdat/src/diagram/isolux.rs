@@ -1,7 +1,8 @@
 //! Isolux ground footprint diagram
 //!
-//! Computes ground-plane illuminance for a tilted luminaire at a given height,
-//! then renders a heatmap with contour lines showing equal-lux isolines.
+//! Computes ground-plane illuminance for a tilted luminaire at a given height
+//! (via [`IlluminanceGrid`]), then renders a heatmap with contour lines showing
+//! equal-lux isolines.
 //!
 //! ## Formula (tilted luminaire at height H, tilt α):
 //! ```text
@@ -15,6 +16,7 @@
 
 use super::color::{heatmap_color, Color};
 use super::contour::{marching_squares, ContourLine};
+use crate::area::{CalculationPlane, IlluminanceGrid};
 use crate::units::UnitSystem;
 use crate::Eulumdat;
 
@@ -126,8 +128,6 @@ impl IsoluxDiagram {
         let plot_height = height - margin_top - margin_bottom;
 
         let n = params.grid_resolution;
-        let h = params.mounting_height;
-        let tilt_rad = params.tilt_angle.to_radians();
 
         // Use abs(num_lamps) because negative num_lamps signals absolute
         // photometry (IES), where total_luminous_flux is already the real total.
@@ -136,31 +136,25 @@ impl IsoluxDiagram {
             .iter()
             .map(|ls| ls.total_luminous_flux * ls.num_lamps.unsigned_abs() as f64)
             .sum();
-        let flux_scale = total_flux / 1000.0;
 
-        // Build grid
+        // Compute illuminance on the ground plane around the luminaire
+        let plane =
+            CalculationPlane::floor_centered(params.area_half_width, params.area_half_depth, n);
+        let grid = IlluminanceGrid::for_luminaire(
+            ldt,
+            params.mounting_height,
+            params.tilt_angle,
+            0.0,
+            &plane,
+        );
+        let max_lux = grid.max_lux;
+        let lux_grid = grid.values;
+
         let dx = 2.0 * params.area_half_width / n as f64;
         let dy = 2.0 * params.area_half_depth / n as f64;
         let cell_w = plot_width / n as f64;
         let cell_h = plot_height / n as f64;
 
-        let mut lux_grid: Vec<Vec<f64>> = vec![vec![0.0; n]; n];
-        let mut max_lux: f64 = 0.0;
-
-        // Compute illuminance at each grid point
-        for (row, grid_row) in lux_grid.iter_mut().enumerate() {
-            let gy = -params.area_half_depth + (row as f64 + 0.5) * dy;
-            for (col, cell_val) in grid_row.iter_mut().enumerate() {
-                let gx = -params.area_half_width + (col as f64 + 0.5) * dx;
-
-                let lux = Self::compute_illuminance(ldt, gx, gy, h, tilt_rad, flux_scale);
-                *cell_val = lux;
-                if lux > max_lux {
-                    max_lux = lux;
-                }
-            }
-        }
-
         // Build cells
         let mut cells = Vec::with_capacity(n * n);
         for (row, grid_row) in lux_grid.iter().enumerate() {
@@ -232,54 +226,6 @@ impl IsoluxDiagram {
             margin_top,
         }
     }
-
-    /// Compute illuminance at ground point (gx, gy) in lux
-    fn compute_illuminance(
-        ldt: &Eulumdat,
-        gx: f64,
-        gy: f64,
-        h: f64,
-        tilt_rad: f64,
-        flux_scale: f64,
-    ) -> f64 {
-        // Vector from luminaire to ground point
-        let dx = gx;
-        let dy = gy;
-        let dz = -h; // luminaire is at (0, 0, h), ground is at z=0
-
-        let r = (dx * dx + dy * dy + dz * dz).sqrt();
-        if r < 1e-6 {
-            return 0.0;
-        }
-
-        // Rotate direction by -tilt around Y axis (tilt rotates the luminaire)
-        let cos_t = tilt_rad.cos();
-        let sin_t = tilt_rad.sin();
-
-        let dx_rot = dx * cos_t + dz * sin_t;
-        let dy_rot = dy;
-        let dz_rot = -dx * sin_t + dz * cos_t;
-
-        // Convert to Type C angles
-        let gamma = (-dz_rot / r).acos(); // gamma from nadir
-        let c = dy_rot.atan2(dx_rot);
-
-        let mut c_deg = c.to_degrees();
-        if c_deg < 0.0 {
-            c_deg += 360.0;
-        }
-        let gamma_deg = gamma.to_degrees();
-
-        // Get intensity from photometric data (cd/klm)
-        let intensity = ldt.sample(c_deg, gamma_deg);
-
-        // E = I × cos(θ_incidence) / r²
-        // cos(θ_incidence) for a horizontal surface = h/r (approximately)
-        let cos_incidence = h / r;
-        let illuminance = intensity * flux_scale * cos_incidence / (r * r);
-
-        illuminance.max(0.0)
-    }
 }
 
 #[cfg(test)]
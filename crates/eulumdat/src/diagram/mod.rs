@@ -10,6 +10,32 @@
 //! - **Butterfly**: 3D butterfly diagram with isometric projection
 //! - **Cartesian**: X-Y plot of intensity vs gamma angle for each C-plane
 //! - **Heatmap**: 2D grid showing intensity distribution across all angles
+//! - **Footprint**: beam and field outlines on an aimed-at target plane
+//! - **Glare**: luminance versus γ against the EN 12464-1 limits
+//! - **Road**: EN 13201 calculation field as a false-color plan view
+//!
+//! Polar, cartesian and heatmap diagrams can also be laid out as a
+//! [`DiagramGeometry`]: styled primitives that any backend can draw, so
+//! frontends render the same diagram as the SVG output.
+//!
+//! These SVGs carry a `<title>` and `<desc>` for screen readers (use
+//! [`add_svg_title`] for the others), and [`SvgTheme::curve_patterns`]
+//! tells their curves apart by dash pattern as well as color.
+//!
+//! Polar and heatmap SVGs can carry hover values for the curve points and
+//! cells, see [`SvgInteractivity`].
+//!
+//! [`DatasheetComposer`] lays several diagrams and a summary table out on
+//! one A4 page.
+//!
+//! The raw C/γ matrix renders as a heat-colored table, as SVG pages or HTML,
+//! see [`IntensityTable`].
+//!
+//! Polar and isolux diagrams also export to DXF for CAD layouts via
+//! `to_dxf`, with curves, grid and text on separate layers.
+//!
+//! With the `raster` feature, the SVG diagrams also render to PNG via
+//! `to_png` or [`svg_to_png`].
 //!
 //! # Example
 //!
@@ -25,35 +51,63 @@
 //! }
 //! ```
 
+mod accessibility;
 mod butterfly;
 mod cartesian;
 pub(crate) mod color;
 mod cone;
 pub(crate) mod contour;
+mod datasheet;
+mod dxf;
 mod floodlight_cartesian;
+mod footprint;
+mod geometry;
+mod glare;
 mod heatmap;
+mod interactive;
 mod isocandela;
 mod isolux;
 mod labels;
+mod png;
 mod polar;
 mod projection;
+#[cfg(feature = "raster")]
+mod raster;
+mod road;
 mod svg;
+mod table;
+mod theme;
 mod watchface;
 
+pub use accessibility::add_svg_title;
 pub use butterfly::{ButterflyDiagram, ButterflyWing, CPlaneData};
 pub use cartesian::{CartesianCurve, CartesianDiagram, CartesianPoint};
-pub use color::{heatmap_color, hsl_to_rgb, Color, ColorPalette};
+pub use color::{heatmap_color, hsl_to_rgb, Color, ColorMap, ColorPalette};
 pub use cone::{ConeDiagram, ConeIlluminanceRow, ConeIlluminanceTable};
+pub use datasheet::{
+    DatasheetBranding, DatasheetCell, DatasheetComposer, DatasheetPanel, DatasheetSlot,
+};
+pub use dxf::{DXF_LAYER_CURVES, DXF_LAYER_GRID, DXF_LAYER_TEXT};
 pub use floodlight_cartesian::{
     FloodlightCartesianDiagram, FloodlightCurve, FloodlightPoint, YScale,
 };
+pub use footprint::{FootprintContour, FootprintDiagram, FootprintParams};
+pub use geometry::{DiagramGeometry, LegendItem, LineStyle, Primitive, StyleRole, TextAnchor};
+pub use glare::{GlareCurve, GlareDiagram, GlarePoint};
 pub use heatmap::{HeatmapCell, HeatmapDiagram};
-pub use isocandela::{IsocandelaCell, IsocandelaContour, IsocandelaDiagram};
+pub use interactive::{HoverShape, HoverTarget, SvgInteractivity};
+pub use isocandela::{IsocandelaCell, IsocandelaContour, IsocandelaDiagram, IsocandelaProjection};
 pub use isolux::{IsoluxCell, IsoluxContour, IsoluxDiagram, IsoluxParams};
 pub use labels::DiagramLabels;
-pub use polar::{PolarCurve, PolarDiagram, PolarPoint};
+pub use png::tag_srgb_png;
+pub use polar::{PolarCurve, PolarDiagram, PolarPoint, RadialScale};
 pub use projection::IsometricProjection;
+#[cfg(feature = "raster")]
+pub use raster::svg_to_png;
+pub use road::{RoadCell, RoadDiagram, RoadQuantity};
 pub use svg::{ConeDiagramLabels, DetailLevel, IsometricConfig, SvgLabels, SvgTheme};
+pub use table::IntensityTable;
+pub use theme::{LogoPosition, SvgLogo, SvgThemeBuilder};
 pub use watchface::WatchFaceStyle;
 
 /// Common 2D point used across diagram types
@@ -0,0 +1,136 @@
+//! Color tagging for PNG rasterizations of diagram SVGs.
+//!
+//! Diagram colors are sRGB, but rasterizers (resvg/tiny-skia, browser
+//! canvases) write PNGs without any color information. Some tools then
+//! apply their own gamma to untagged images, which makes exported diagrams
+//! look washed out next to native renders. [`tag_srgb_png`] marks a PNG as
+//! sRGB and records the pixel density of the render scale, so 2x/3x exports
+//! embed at their intended size in documents.
+
+/// PNG file signature.
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Chunks replaced by the tagging (color space and pixel density).
+const REPLACED_CHUNKS: [&[u8; 4]; 5] = [b"sRGB", b"gAMA", b"cHRM", b"iCCP", b"pHYs"];
+
+/// Pixels per meter at the CSS reference density of 96 dpi.
+const PIXELS_PER_METER_1X: f64 = 96.0 / 0.0254;
+
+/// Tag a PNG as sRGB rendered at `scale_factor` (1.0, 2.0, 3.0, ...).
+///
+/// Inserts an `sRGB` chunk (perceptual intent), the matching `gAMA` chunk
+/// for decoders without sRGB support, and a `pHYs` chunk of
+/// 96 dpi × `scale_factor`. Existing color space and density chunks are
+/// replaced, so tagging twice gives the same result.
+///
+/// Returns `None` if `png` is not a well-formed PNG stream.
+pub fn tag_srgb_png(png: &[u8], scale_factor: f64) -> Option<Vec<u8>> {
+    let mut rest = png.strip_prefix(&SIGNATURE)?;
+    let mut out = Vec::with_capacity(png.len() + 64);
+    out.extend_from_slice(&SIGNATURE);
+
+    let mut tagged = false;
+    while !rest.is_empty() {
+        let length = u32::from_be_bytes(rest.get(0..4)?.try_into().ok()?) as usize;
+        let chunk = rest.get(..12usize.checked_add(length)?)?;
+        let kind: &[u8; 4] = chunk[4..8].try_into().ok()?;
+        rest = &rest[chunk.len()..];
+
+        if !REPLACED_CHUNKS.contains(&kind) {
+            out.extend_from_slice(chunk);
+        }
+        if kind == b"IHDR" && !tagged {
+            let ppm = (PIXELS_PER_METER_1X * scale_factor.max(0.0)).round() as u32;
+            let mut phys = Vec::with_capacity(9);
+            phys.extend_from_slice(&ppm.to_be_bytes());
+            phys.extend_from_slice(&ppm.to_be_bytes());
+            phys.push(1); // unit: meter
+
+            write_chunk(&mut out, b"sRGB", &[0]);
+            write_chunk(&mut out, b"gAMA", &45455u32.to_be_bytes());
+            write_chunk(&mut out, b"pHYs", &phys);
+            tagged = true;
+        }
+        if kind == b"IEND" {
+            break;
+        }
+    }
+    tagged.then_some(out)
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    out.extend_from_slice(&crc32(kind.iter().chain(data)).to_be_bytes());
+}
+
+/// CRC-32 (ISO 3309) as used by PNG chunks.
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 1×1 PNG skeleton (no image data needed for chunk handling).
+    fn minimal_png(extra: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+        let mut png = SIGNATURE.to_vec();
+        write_chunk(&mut png, b"IHDR", &[0, 0, 0, 1, 0, 0, 0, 1, 8, 6, 0, 0, 0]);
+        for (kind, data) in extra {
+            write_chunk(&mut png, kind, data);
+        }
+        write_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    fn chunk_types(png: &[u8]) -> Vec<String> {
+        let mut rest = &png[8..];
+        let mut types = Vec::new();
+        while rest.len() >= 12 {
+            let length = u32::from_be_bytes(rest[0..4].try_into().unwrap()) as usize;
+            types.push(String::from_utf8_lossy(&rest[4..8]).into_owned());
+            rest = &rest[12 + length..];
+        }
+        types
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+    }
+
+    #[test]
+    fn test_tag_srgb_png() {
+        let png = minimal_png(&[(b"gAMA", &100_000u32.to_be_bytes())]);
+        let tagged = tag_srgb_png(&png, 2.0).unwrap();
+        assert_eq!(
+            chunk_types(&tagged),
+            vec!["IHDR", "sRGB", "gAMA", "pHYs", "IEND"]
+        );
+
+        // 192 dpi for a 2x render
+        let phys = tagged
+            .windows(4)
+            .position(|w| w == b"pHYs")
+            .map(|i| u32::from_be_bytes(tagged[i + 4..i + 8].try_into().unwrap()))
+            .unwrap();
+        assert_eq!(phys, 7559);
+
+        assert_eq!(tag_srgb_png(&tagged, 2.0).unwrap(), tagged);
+        assert!(tag_srgb_png(b"not a png", 1.0).is_none());
+        assert!(tag_srgb_png(&png[..20], 1.0).is_none());
+    }
+}
@@ -86,6 +86,95 @@ impl PolarCurve {
     }
 }
 
+/// Mapping of intensity to radius in the polar diagram
+///
+/// Spot and narrow-beam luminaires peak far above the rest of the curve, so
+/// on the linear scale everything but the peak collapses into the center.
+/// The square-root and logarithmic scales spread the low intensities out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RadialScale {
+    /// Radius proportional to intensity
+    #[default]
+    Linear,
+    /// Radius proportional to the square root of intensity
+    SquareRoot,
+    /// Radius proportional to the logarithm of intensity over
+    /// [`RadialScale::LOG_DECADES`] decades below the scale maximum
+    Logarithmic,
+}
+
+impl RadialScale {
+    /// Decades shown by the logarithmic scale
+    pub const LOG_DECADES: i32 = 3;
+
+    /// Fraction of the outer radius at which `value` is drawn on a scale up to `max`.
+    pub fn fraction(&self, value: f64, max: f64) -> f64 {
+        if max <= 0.0 || value <= 0.0 {
+            return 0.0;
+        }
+        match self {
+            Self::Linear => value / max,
+            Self::SquareRoot => (value / max).sqrt(),
+            Self::Logarithmic => {
+                let decades = Self::LOG_DECADES as f64;
+                ((value / max).log10() / decades + 1.0).max(0.0)
+            }
+        }
+    }
+
+    /// Value drawn at `fraction` of the outer radius on a scale up to `max`,
+    /// the inverse of [`RadialScale::fraction`].
+    pub fn value_at(&self, fraction: f64, max: f64) -> f64 {
+        if max <= 0.0 || fraction <= 0.0 {
+            return 0.0;
+        }
+        match self {
+            Self::Linear => fraction * max,
+            Self::SquareRoot => fraction * fraction * max,
+            Self::Logarithmic => {
+                let decades = Self::LOG_DECADES as f64;
+                max * 10f64.powf((fraction - 1.0) * decades)
+            }
+        }
+    }
+
+    /// Intensities of the grid rings for `scale`.
+    ///
+    /// The linear and square-root scales keep the rings of `scale`, the
+    /// logarithmic scale puts one ring per decade below the maximum.
+    pub fn ring_values(&self, scale: &DiagramScale) -> Vec<f64> {
+        match self {
+            Self::Linear | Self::SquareRoot => scale.grid_values.clone(),
+            Self::Logarithmic => (0..=Self::LOG_DECADES)
+                .rev()
+                .map(|k| scale.scale_max / 10f64.powi(k))
+                .collect(),
+        }
+    }
+
+    /// Ring label: whole numbers on the linear scale, the significant
+    /// digits of the small decades otherwise.
+    pub fn format_value(&self, value: f64) -> String {
+        match self {
+            Self::Linear => format!("{value:.0}"),
+            Self::SquareRoot | Self::Logarithmic => {
+                let text = format!("{value:.3}");
+                text.trim_end_matches('0').trim_end_matches('.').to_string()
+            }
+        }
+    }
+
+    /// Short marker appended to the unit label, empty for linear.
+    pub fn unit_suffix(&self) -> &'static str {
+        match self {
+            Self::Linear => "",
+            Self::SquareRoot => " (√)",
+            Self::Logarithmic => " (log)",
+        }
+    }
+}
+
 /// Complete polar diagram data
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -98,6 +187,17 @@ pub struct PolarDiagram {
     pub scale: DiagramScale,
     /// Symmetry type of the source data
     pub symmetry: Symmetry,
+    /// Every C-plane pair, drawn faded behind the main curves
+    /// (see [`PolarDiagram::with_all_planes`])
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub all_planes: Vec<PolarCurve>,
+    /// Plane pair containing the peak intensity, drawn highlighted
+    /// (see [`PolarDiagram::with_max_plane`])
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_plane: Option<PolarCurve>,
+    /// Mapping of intensity to radius (see [`PolarDiagram::with_radial_scale`])
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub radial_scale: RadialScale,
 }
 
 impl PolarDiagram {
@@ -124,7 +224,28 @@ impl PolarDiagram {
             c90_c270_curve,
             scale,
             symmetry: ldt.symmetry,
+            all_planes: Vec::new(),
+            max_plane: None,
+            radial_scale: RadialScale::Linear,
+        }
+    }
+
+    /// Generate polar diagrams of several luminaires on a common scale.
+    ///
+    /// Every diagram gets the scale of the highest intensity of all files,
+    /// so the curves can be drawn over each other, e.g. with
+    /// [`PolarDiagram::to_overlay_svg_multi`].
+    pub fn from_eulumdat_multi(ldts: &[&Eulumdat]) -> Vec<Self> {
+        let mut diagrams: Vec<Self> = ldts.iter().map(|ldt| Self::from_eulumdat(ldt)).collect();
+        let max_intensity = diagrams
+            .iter()
+            .map(|d| d.scale.max_intensity)
+            .fold(0.0, f64::max);
+        let scale = DiagramScale::from_max_intensity(max_intensity, 5);
+        for diagram in &mut diagrams {
+            diagram.scale = scale.clone();
         }
+        diagrams
     }
 
     /// Generate polar diagram data for a specific C-plane pair.
@@ -132,53 +253,8 @@ impl PolarDiagram {
     /// Builds a diagram showing C_x on the right half and C_{x+180} on the left half.
     /// Uses `ldt.sample()` which handles symmetry expansion and interpolation.
     pub fn from_eulumdat_for_plane(ldt: &Eulumdat, c_plane: f64) -> Self {
-        let opposite = (c_plane + 180.0) % 360.0;
-        let mut points = Vec::new();
-        let mut max_intensity: f64 = 0.0;
-
-        // Right half: C_x at gamma angles
-        for &g_angle in &ldt.g_angles {
-            let intensity = ldt.sample(c_plane, g_angle);
-            max_intensity = max_intensity.max(intensity);
-
-            let angle_rad = -g_angle.to_radians() + FRAC_PI_2;
-            let x = intensity * angle_rad.cos();
-            let y = intensity * angle_rad.sin();
-
-            points.push(PolarPoint {
-                x,
-                y,
-                gamma: g_angle,
-                intensity,
-            });
-        }
-
-        // Left half: C_{x+180} at gamma angles (reversed to close the path)
-        for j in (0..ldt.g_angles.len()).rev() {
-            let g_angle = ldt.g_angles[j];
-            let intensity = ldt.sample(opposite, g_angle);
-            max_intensity = max_intensity.max(intensity);
-
-            let angle_rad = -g_angle.to_radians() + FRAC_PI_2;
-            let x = intensity * angle_rad.cos();
-            let y = intensity * angle_rad.sin();
-
-            points.push(PolarPoint {
-                x: -x,
-                y,
-                gamma: g_angle,
-                intensity,
-            });
-        }
-
-        let label = format!("C{:.0}-C{:.0}", c_plane, opposite);
-        let curve = PolarCurve {
-            points,
-            c_angle: c_plane,
-            label,
-        };
-
-        let scale = DiagramScale::from_max_intensity(max_intensity, 5);
+        let curve = plane_pair_curve(ldt, c_plane);
+        let scale = DiagramScale::from_max_intensity(curve.max_intensity(), 5);
 
         Self {
             c0_c180_curve: curve,
@@ -189,6 +265,118 @@ impl PolarDiagram {
             },
             scale,
             symmetry: ldt.symmetry,
+            all_planes: Vec::new(),
+            max_plane: None,
+            radial_scale: RadialScale::Linear,
+        }
+    }
+
+    /// Generate polar diagram data for two arbitrary C-plane pairs.
+    ///
+    /// `c_first` takes the place of C0-C180 (solid) and `c_second` that of
+    /// C90-C270 (dashed), e.g. `(45.0, 135.0)` for the diagonals.
+    pub fn from_eulumdat_for_planes(ldt: &Eulumdat, c_first: f64, c_second: f64) -> Self {
+        let first = plane_pair_curve(ldt, c_first);
+        let second = plane_pair_curve(ldt, c_second);
+        let max_intensity = first.max_intensity().max(second.max_intensity());
+
+        Self {
+            c0_c180_curve: first,
+            c90_c270_curve: second,
+            scale: DiagramScale::from_max_intensity(max_intensity, 5),
+            symmetry: ldt.symmetry,
+            all_planes: Vec::new(),
+            max_plane: None,
+            radial_scale: RadialScale::Linear,
+        }
+    }
+
+    /// Add every measured C-plane pair as faded background curves.
+    ///
+    /// The opacity of each curve grows with its peak, so the dominant planes
+    /// stand out. The scale is widened if a plane exceeds it. Rotationally
+    /// symmetric luminaires have no other planes and are left unchanged.
+    pub fn with_all_planes(mut self, ldt: &Eulumdat) -> Self {
+        self.all_planes = Self::available_c_planes(ldt)
+            .into_iter()
+            .filter(|&c| c < 180.0)
+            .map(|c| plane_pair_curve(ldt, c))
+            .collect();
+        let maxima: Vec<f64> = self
+            .all_planes
+            .iter()
+            .map(PolarCurve::max_intensity)
+            .collect();
+        self.fit_scale(&maxima);
+        self
+    }
+
+    /// Highlight the C-plane pair containing the peak intensity.
+    pub fn with_max_plane(mut self, ldt: &Eulumdat) -> Self {
+        let mut peak: Option<(f64, f64)> = None;
+        for (row, &c) in ldt.intensities.iter().zip(&ldt.c_angles) {
+            for &value in row {
+                if peak.is_none_or(|(max, _)| value > max) {
+                    peak = Some((value, c));
+                }
+            }
+        }
+        self.max_plane = peak.map(|(_, c)| plane_pair_curve(ldt, c.rem_euclid(180.0)));
+        let maxima: Vec<f64> = self
+            .max_plane
+            .iter()
+            .map(PolarCurve::max_intensity)
+            .collect();
+        self.fit_scale(&maxima);
+        self
+    }
+
+    /// Draw the curves on a square-root or logarithmic radial scale.
+    ///
+    /// The grid rings are placed and labelled on the same scale, so
+    /// intensities can still be read off the diagram.
+    pub fn with_radial_scale(mut self, radial_scale: RadialScale) -> Self {
+        self.radial_scale = radial_scale;
+        self
+    }
+
+    /// Distance from the center of `intensity` in a diagram of `radius`.
+    pub fn radius_of(&self, intensity: f64, radius: f64) -> f64 {
+        radius * self.radial_scale.fraction(intensity, self.scale.scale_max)
+    }
+
+    /// Screen points of `curve` on the radial scale of this diagram.
+    pub fn curve_screen_points(
+        &self,
+        curve: &PolarCurve,
+        center_x: f64,
+        center_y: f64,
+        radius: f64,
+    ) -> Vec<Point2D> {
+        if self.radial_scale == RadialScale::Linear {
+            return curve.screen_points(center_x, center_y, self.scale.scale_max / radius);
+        }
+        curve
+            .points
+            .iter()
+            .map(|p| {
+                if p.intensity <= 0.0 {
+                    return Point2D::new(center_x, center_y);
+                }
+                let r = self.radius_of(p.intensity, radius) / p.intensity;
+                Point2D::new(center_x + p.x * r, center_y + p.y * r)
+            })
+            .collect()
+    }
+
+    /// Widen the scale to fit additional curves.
+    fn fit_scale(&mut self, maxima: &[f64]) {
+        let max_intensity = maxima
+            .iter()
+            .copied()
+            .fold(self.scale.max_intensity, f64::max);
+        if max_intensity > self.scale.max_intensity {
+            self.scale = DiagramScale::from_max_intensity(max_intensity, 5);
         }
     }
 
@@ -255,6 +443,43 @@ impl PolarDiagram {
         }
         polar.to_svg_with_summary(width, height, theme, &summary)
     }
+
+    /// Render a small label-free polar thumbnail SVG for catalog and library views.
+    pub fn render_thumbnail_svg(ldt: &Eulumdat, size: f64, theme: &SvgTheme) -> String {
+        Self::from_eulumdat(ldt)
+            .to_thumbnail_geometry(size)
+            .to_svg(theme)
+    }
+}
+
+/// Closed curve of the pair `c_plane` / `c_plane + 180°`: `c_plane` on the
+/// right half, the opposite plane mirrored to the left.
+fn plane_pair_curve(ldt: &Eulumdat, c_plane: f64) -> PolarCurve {
+    let opposite = (c_plane + 180.0) % 360.0;
+    let point = |c: f64, g_angle: f64, side: f64| {
+        let intensity = ldt.sample(c, g_angle);
+        let angle_rad = -g_angle.to_radians() + FRAC_PI_2;
+        PolarPoint {
+            x: side * intensity * angle_rad.cos(),
+            y: intensity * angle_rad.sin(),
+            gamma: g_angle,
+            intensity,
+        }
+    };
+
+    // Right half at increasing gamma, left half reversed to close the path
+    let mut points: Vec<PolarPoint> = ldt
+        .g_angles
+        .iter()
+        .map(|&g| point(c_plane, g, 1.0))
+        .collect();
+    points.extend(ldt.g_angles.iter().rev().map(|&g| point(opposite, g, -1.0)));
+
+    PolarCurve {
+        points,
+        c_angle: c_plane,
+        label: format!("C{:.0}-C{:.0}", c_plane, opposite),
+    }
 }
 
 /// Calculate the polar vectors for C0-C180 and C90-C270 planes
@@ -483,6 +708,7 @@ fn calculate_vectors(ldt: &Eulumdat) -> (Vec<PolarPoint>, Vec<PolarPoint>, f64)
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::diagram::{Primitive, StyleRole, SvgLabels};
 
     #[allow(clippy::field_reassign_with_default)]
     fn create_test_ldt() -> Eulumdat {
@@ -532,4 +758,126 @@ mod tests {
         // Should not show C90-C270 for vertical axis symmetry
         assert!(!polar.show_c90_c270());
     }
+
+    #[test]
+    fn test_selected_plane_pairs() {
+        let ldt = create_test_ldt();
+        let polar = PolarDiagram::from_eulumdat_for_planes(&ldt, 30.0, 60.0);
+
+        assert_eq!(polar.c0_c180_curve.label, "C30-C210");
+        assert_eq!(polar.c90_c270_curve.label, "C60-C240");
+        assert_eq!(polar.c0_c180_curve.points[0].intensity, 95.0);
+        assert_eq!(polar.scale.max_intensity, 95.0);
+
+        let svg = polar
+            .to_geometry(500.0, &SvgLabels::default())
+            .to_svg(&SvgTheme::light());
+        assert!(svg.contains("C30-C210"));
+        assert!(!svg.contains("C0-C180"));
+    }
+
+    #[test]
+    fn test_all_planes_and_max_plane() {
+        let ldt = create_test_ldt();
+        let polar = PolarDiagram::from_eulumdat(&ldt)
+            .with_all_planes(&ldt)
+            .with_max_plane(&ldt);
+
+        let angles: Vec<f64> = polar.all_planes.iter().map(|c| c.c_angle).collect();
+        assert_eq!(angles, vec![0.0, 30.0, 60.0, 90.0, 120.0, 150.0]);
+        assert_eq!(polar.max_plane.as_ref().unwrap().label, "C0-C180");
+
+        let geometry = polar.to_geometry(500.0, &SvgLabels::default());
+        let faded = geometry
+            .primitives
+            .iter()
+            .filter(|p| {
+                matches!(p, Primitive::Path { stroke, .. } if matches!(stroke.role, StyleRole::Translucent(..)))
+            })
+            .count();
+        assert_eq!(faded, 6);
+        assert_eq!(geometry.legend.len(), 3);
+        assert_eq!(geometry.legend[2].label, "Max: C0-C180");
+
+        // Nothing to fan out for rotationally symmetric luminaires
+        let mut ldt = create_test_ldt();
+        ldt.symmetry = Symmetry::VerticalAxis;
+        assert!(PolarDiagram::from_eulumdat(&ldt)
+            .with_all_planes(&ldt)
+            .all_planes
+            .is_empty());
+    }
+
+    #[test]
+    fn test_radial_scale_mapping() {
+        assert_eq!(RadialScale::Linear.fraction(25.0, 100.0), 0.25);
+        assert_eq!(RadialScale::SquareRoot.fraction(25.0, 100.0), 0.5);
+        assert!((RadialScale::Logarithmic.fraction(1.0, 100.0) - 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(RadialScale::Logarithmic.fraction(0.01, 100.0), 0.0);
+        assert_eq!(RadialScale::Logarithmic.fraction(0.0, 100.0), 0.0);
+
+        let scale = DiagramScale::from_max_intensity(480.0, 5);
+        let rings = RadialScale::Logarithmic.ring_values(&scale);
+        assert_eq!(rings.len(), 4);
+        assert_eq!(rings[3], scale.scale_max);
+        // One ring per decade, evenly spaced
+        for (i, &value) in rings.iter().enumerate() {
+            let fraction = RadialScale::Logarithmic.fraction(value, scale.scale_max);
+            assert!((fraction - i as f64 / 3.0).abs() < 1e-9);
+        }
+        assert_eq!(
+            RadialScale::SquareRoot.ring_values(&scale),
+            scale.grid_values
+        );
+        assert_eq!(RadialScale::Logarithmic.format_value(0.5), "0.5");
+        assert_eq!(RadialScale::Logarithmic.format_value(500.0), "500");
+
+        // value_at inverts fraction inside the scale
+        for scale in [
+            RadialScale::Linear,
+            RadialScale::SquareRoot,
+            RadialScale::Logarithmic,
+        ] {
+            for value in [0.5, 12.0, 80.0, 100.0] {
+                let fraction = scale.fraction(value, 100.0);
+                assert!((scale.value_at(fraction, 100.0) - value).abs() < 1e-9);
+            }
+            assert_eq!(scale.value_at(0.0, 100.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_log_radial_scale_spreads_spot_curve() {
+        let mut ldt = create_test_ldt();
+        ldt.symmetry = Symmetry::VerticalAxis;
+        ldt.c_angles = vec![0.0];
+        ldt.intensities = vec![vec![10000.0, 100.0, 10.0, 0.0]];
+
+        let linear = PolarDiagram::from_eulumdat(&ldt);
+        let log = linear.clone().with_radial_scale(RadialScale::Logarithmic);
+        let curve = &linear.c0_c180_curve;
+        assert_eq!(
+            linear.curve_screen_points(curve, 250.0, 250.0, 190.0),
+            curve.screen_points(250.0, 250.0, linear.scale.scale_max / 190.0)
+        );
+
+        // γ = 30° at 1 % of the peak: next to the center on the linear
+        // scale, a third of the way out on the logarithmic one
+        let radius_at = |polar: &PolarDiagram| {
+            let index = curve.points.iter().position(|p| p.gamma == 30.0).unwrap();
+            let p = polar.curve_screen_points(curve, 250.0, 250.0, 190.0)[index];
+            (p.x - 250.0).hypot(p.y - 250.0)
+        };
+        let expected = 190.0 * RadialScale::Logarithmic.fraction(100.0, log.scale.scale_max);
+        assert!(radius_at(&linear) < 2.0);
+        assert!((radius_at(&log) - expected).abs() < 1e-9);
+        assert!(expected > 50.0);
+
+        let svg = log
+            .to_geometry(500.0, &SvgLabels::default())
+            .to_svg(&SvgTheme::light());
+        assert!(svg.contains("cd/1000lm (log)"));
+        let ring_label = log.radial_scale.format_value(log.scale.scale_max / 1000.0);
+        assert!(svg.contains(&format!(">{ring_label}</text>")));
+    }
 }
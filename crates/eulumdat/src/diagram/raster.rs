@@ -0,0 +1,140 @@
+//! PNG rasterization of diagram SVGs (feature `raster`).
+//!
+//! Renders the SVG of any diagram with resvg and the system fonts, so the
+//! CLI, FFI and GUIs get the same bitmaps without their own rasterizer
+//! setup. The PNGs are tagged as sRGB with [`tag_srgb_png`].
+//!
+//! ```rust,no_run
+//! use eulumdat::{Eulumdat, diagram::{PolarDiagram, SvgTheme}};
+//!
+//! let ldt = Eulumdat::from_file("luminaire.ldt")?;
+//! let png = PolarDiagram::from_eulumdat(&ldt).to_png(600.0, 600.0, &SvgTheme::light())?;
+//! std::fs::write("polar.png", png)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::sync::{Arc, OnceLock};
+
+use resvg::tiny_skia::{Pixmap, Transform};
+use resvg::usvg::{fontdb, Options, Tree};
+
+use super::{
+    tag_srgb_png, ButterflyDiagram, CartesianDiagram, ConeDiagram, FloodlightCartesianDiagram,
+    HeatmapDiagram, IsocandelaDiagram, IsoluxDiagram, PolarDiagram, RoadDiagram, SvgTheme,
+};
+use crate::bug_rating::BugDiagram;
+use crate::error::{anyhow, Result};
+
+/// System fonts, loaded once per process.
+fn font_database() -> Arc<fontdb::Database> {
+    static FONTS: OnceLock<Arc<fontdb::Database>> = OnceLock::new();
+    FONTS
+        .get_or_init(|| {
+            let mut db = fontdb::Database::new();
+            db.load_system_fonts();
+            Arc::new(db)
+        })
+        .clone()
+}
+
+/// Render an SVG to an sRGB-tagged PNG.
+///
+/// One SVG unit becomes `scale_factor` pixels (clamped to 0.25-4); use 2.0
+/// or 3.0 for HiDPI embedding. The SVG's own background is kept and
+/// uncovered pixels stay transparent.
+pub fn svg_to_png(svg: &str, scale_factor: f32) -> Result<Vec<u8>> {
+    let options = Options {
+        fontdb: font_database(),
+        ..Default::default()
+    };
+    let tree = Tree::from_str(svg, &options)?;
+
+    let scale = scale_factor.clamp(0.25, 4.0);
+    let size = tree.size();
+    let (width, height) = (
+        (size.width() * scale).ceil() as u32,
+        (size.height() * scale).ceil() as u32,
+    );
+    let mut pixmap =
+        Pixmap::new(width, height).ok_or_else(|| anyhow!("Invalid PNG size {width}x{height}"))?;
+    resvg::render(
+        &tree,
+        Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    let png = pixmap.encode_png()?;
+    tag_srgb_png(&png, scale as f64).ok_or_else(|| anyhow!("Failed to tag PNG"))
+}
+
+macro_rules! impl_to_png {
+    ($($diagram:ty),* $(,)?) => {
+        $(
+            impl $diagram {
+                /// Render the diagram as a PNG, one pixel per SVG unit.
+                pub fn to_png(&self, width: f64, height: f64, theme: &SvgTheme) -> Result<Vec<u8>> {
+                    svg_to_png(&self.to_svg(width, height, theme), 1.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_png!(
+    PolarDiagram,
+    CartesianDiagram,
+    HeatmapDiagram,
+    ButterflyDiagram,
+    ConeDiagram,
+    IsoluxDiagram,
+    IsocandelaDiagram,
+    FloodlightCartesianDiagram,
+    RoadDiagram,
+    BugDiagram,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Eulumdat, LampSet, Symmetry};
+
+    fn header(png: &[u8]) -> (u32, u32) {
+        let ihdr = &png[16..24];
+        (
+            u32::from_be_bytes(ihdr[0..4].try_into().unwrap()),
+            u32::from_be_bytes(ihdr[4..8].try_into().unwrap()),
+        )
+    }
+
+    #[test]
+    fn test_polar_to_png() {
+        let ldt = Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            c_angles: vec![0.0],
+            g_angles: vec![0.0, 45.0, 90.0],
+            intensities: vec![vec![300.0, 200.0, 0.0]],
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 1000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let png = PolarDiagram::from_eulumdat(&ldt)
+            .to_png(300.0, 300.0, &SvgTheme::light())
+            .unwrap();
+
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        assert_eq!(header(&png), (300, 300));
+        assert!(png.windows(4).any(|w| w == b"sRGB"));
+    }
+
+    #[test]
+    fn test_svg_to_png_scale_and_errors() {
+        let svg = r#"<svg viewBox="0 0 40 30" xmlns="http://www.w3.org/2000/svg"><rect width="40" height="30" fill="red"/></svg>"#;
+        let png = svg_to_png(svg, 2.0).unwrap();
+        assert_eq!(header(&png), (80, 60));
+
+        assert!(svg_to_png("not an svg", 1.0).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+//! Road lighting plan view
+//!
+//! Draws the EN 13201-3 calculation field of a [`RoadGrid`] as false-color
+//! cells, with the luminaires, the pole spacing and the observer positions.
+//!
+//! ```rust,no_run
+//! use eulumdat::{Eulumdat, diagram::{RoadDiagram, RoadQuantity, SvgTheme}, road::{RoadGrid, RoadLayout}};
+//!
+//! let ldt = Eulumdat::from_file("road.ldt")?;
+//! let grid = RoadGrid::compute(&ldt, &RoadLayout::default());
+//! let svg = RoadDiagram::from_grid(&grid, 800.0, 400.0, RoadQuantity::Luminance)
+//!     .to_svg(800.0, 400.0, &SvgTheme::light());
+//! std::fs::write("road.svg", svg)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use super::color::{Color, ColorMap};
+use crate::road::{RoadGrid, RoadLayout};
+
+/// Value shown in the road plan view
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoadQuantity {
+    /// Road luminance in cd/m²
+    #[default]
+    Luminance,
+    /// Horizontal illuminance in lux
+    Illuminance,
+}
+
+impl RoadQuantity {
+    /// Unit label
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Self::Luminance => "cd/m²",
+            Self::Illuminance => "lx",
+        }
+    }
+}
+
+/// A grid point of the road plan view
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoadCell {
+    /// Position along the road in meters
+    pub x_m: f64,
+    /// Position across the road in meters
+    pub y_m: f64,
+    /// Screen X of the cell's left edge
+    pub sx: f64,
+    /// Screen Y of the cell's top edge
+    pub sy: f64,
+    /// Cell width in pixels
+    pub width: f64,
+    /// Cell height in pixels
+    pub height: f64,
+    /// Luminance or illuminance at the grid point
+    pub value: f64,
+    /// Cell color
+    pub color: Color,
+}
+
+/// False-color plan view of the road calculation field
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoadDiagram {
+    /// Grid cells
+    pub cells: Vec<RoadCell>,
+    /// Shown quantity
+    pub quantity: RoadQuantity,
+    /// Road geometry
+    pub layout: RoadLayout,
+    /// Minimum value on the field
+    pub min_value: f64,
+    /// Average value on the field
+    pub avg_value: f64,
+    /// Maximum value on the field
+    pub max_value: f64,
+    /// Overall uniformity min / average
+    pub uniformity: f64,
+    /// Longitudinal uniformity Ul (luminance only)
+    pub longitudinal_uniformity: Option<f64>,
+    /// Color map of the cells and the legend
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub color_map: ColorMap,
+    /// Pixels per meter
+    pub scale: f64,
+    /// Plot dimensions (the calculation field)
+    pub plot_width: f64,
+    pub plot_height: f64,
+    pub margin_left: f64,
+    pub margin_top: f64,
+}
+
+impl RoadDiagram {
+    /// Lay out the calculation field of `grid` in a `width` × `height` view.
+    pub fn from_grid(grid: &RoadGrid, width: f64, height: f64, quantity: RoadQuantity) -> Self {
+        let layout = grid.layout;
+        let road_width = layout.road_width();
+
+        // Room for the observers and labels left, the legend right, the
+        // title and far poles above, the spacing and statistics below
+        let available_width = (width - 160.0).max(1.0);
+        let available_height = (height - 130.0).max(1.0);
+        let scale = (available_width / layout.pole_spacing).min(available_height / road_width);
+        let plot_width = layout.pole_spacing * scale;
+        let plot_height = road_width * scale;
+        let margin_left = 70.0 + (available_width - plot_width) / 2.0;
+        let margin_top = 60.0 + (available_height - plot_height) / 2.0;
+
+        let (values, uniformity, longitudinal_uniformity) = match quantity {
+            RoadQuantity::Luminance => (
+                &grid.luminance,
+                grid.luminance_uniformity(),
+                Some(grid.longitudinal_uniformity()),
+            ),
+            RoadQuantity::Illuminance => (&grid.illuminance, grid.illuminance_uniformity(), None),
+        };
+
+        let dx = layout.pole_spacing / grid.x.len().max(1) as f64;
+        let dy = layout.lane_width / 3.0;
+        let mut cells = Vec::with_capacity(grid.x.len() * grid.y.len());
+        for (row, &y_m) in values.iter().zip(&grid.y) {
+            for (&value, &x_m) in row.iter().zip(&grid.x) {
+                cells.push(RoadCell {
+                    x_m,
+                    y_m,
+                    sx: margin_left + (x_m - dx / 2.0) * scale,
+                    sy: margin_top + (road_width - y_m - dy / 2.0) * scale,
+                    width: dx * scale,
+                    height: dy * scale,
+                    value,
+                    color: Color::new(0, 0, 0),
+                });
+            }
+        }
+
+        let count = cells.len().max(1) as f64;
+        let min_value = cells.iter().map(|c| c.value).fold(f64::INFINITY, f64::min);
+        let mut diagram = Self {
+            min_value: if min_value.is_finite() {
+                min_value
+            } else {
+                0.0
+            },
+            avg_value: cells.iter().map(|c| c.value).sum::<f64>() / count,
+            max_value: cells.iter().map(|c| c.value).fold(0.0, f64::max),
+            cells,
+            quantity,
+            layout,
+            uniformity,
+            longitudinal_uniformity,
+            color_map: ColorMap::default(),
+            scale,
+            plot_width,
+            plot_height,
+            margin_left,
+            margin_top,
+        };
+        diagram.apply_colors();
+        diagram
+    }
+
+    /// Use another color map for the cells and the legend.
+    pub fn with_color_map(mut self, color_map: ColorMap) -> Self {
+        self.color_map = color_map;
+        self.apply_colors();
+        self
+    }
+
+    /// Screen position of a road point in meters.
+    pub fn to_screen(&self, x_m: f64, y_m: f64) -> (f64, f64) {
+        (
+            self.margin_left + x_m * self.scale,
+            self.margin_top + (self.layout.road_width() - y_m) * self.scale,
+        )
+    }
+
+    fn apply_colors(&mut self) {
+        let max = self.max_value;
+        for cell in &mut self.cells {
+            let normalized = if max > 0.0 { cell.value / max } else { 0.0 };
+            cell.color = self.color_map.color(normalized);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagram::SvgTheme;
+    use crate::road::RoadArrangement;
+    use crate::{Eulumdat, LampSet, Symmetry};
+
+    fn create_test_grid(arrangement: RoadArrangement) -> RoadGrid {
+        let ldt = Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            c_angles: vec![0.0],
+            g_angles: vec![0.0, 30.0, 60.0, 90.0],
+            intensities: vec![vec![300.0, 320.0, 150.0, 0.0]],
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 10000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        RoadGrid::compute(
+            &ldt,
+            &RoadLayout {
+                arrangement,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_road_diagram_layout() {
+        let grid = create_test_grid(RoadArrangement::SingleSided);
+        let diagram = RoadDiagram::from_grid(&grid, 800.0, 400.0, RoadQuantity::Luminance);
+
+        assert_eq!(diagram.cells.len(), 60);
+        assert!((diagram.avg_value - grid.average_luminance()).abs() < 1e-9);
+        assert!(diagram.longitudinal_uniformity.is_some());
+
+        // The field fills the width; cells tile it without gaps
+        assert!((diagram.plot_width - 640.0).abs() < 1e-9);
+        let (x0, y0) = diagram.to_screen(0.0, diagram.layout.road_width());
+        assert!((diagram.cells[50].sx - x0).abs() < 1e-9);
+        assert!((diagram.cells[50].sy - y0).abs() < 1e-9);
+
+        let lux = RoadDiagram::from_grid(&grid, 800.0, 400.0, RoadQuantity::Illuminance)
+            .with_color_map(ColorMap::Grayscale);
+        assert!(lux.longitudinal_uniformity.is_none());
+        assert!(lux.max_value > diagram.max_value);
+        let peak = lux.cells.iter().find(|c| c.value == lux.max_value).unwrap();
+        assert_eq!(peak.color, Color::new(255, 255, 255));
+    }
+
+    #[test]
+    fn test_road_svg_annotations() {
+        let grid = create_test_grid(RoadArrangement::Staggered);
+        let svg = RoadDiagram::from_grid(&grid, 800.0, 400.0, RoadQuantity::Luminance).to_svg(
+            800.0,
+            400.0,
+            &SvgTheme::light(),
+        );
+
+        assert!(svg.contains("Road Luminance (EN 13201)"));
+        assert!(svg.contains("S = 30.0 m"));
+        assert_eq!(svg.matches(">-60 m</text>").count(), 2);
+        assert!(svg.contains("Ul = "));
+        // Near poles at both field ends, the far pole halfway
+        assert_eq!(svg.matches(r#"r="4" fill="white""#).count(), 3);
+        // 60 cells of about 64 × 31 px carry their values
+        assert_eq!(svg.matches(r#"font-family="monospace""#).count(), 60);
+    }
+}
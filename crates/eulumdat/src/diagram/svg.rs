@@ -29,11 +29,16 @@
 //! let svg = polar.to_svg_responsive(300.0, 300.0, &SvgTheme::light(), DetailLevel::Minimal);
 //! ```
 
+use super::geometry::escape_text;
 use super::{
-    ButterflyDiagram, CartesianCurve, CartesianDiagram, ConeDiagram, DiagramScale,
-    FloodlightCartesianDiagram, HeatmapDiagram, IsocandelaDiagram, IsoluxDiagram, PolarDiagram,
-    YScale,
+    ButterflyDiagram, CartesianCurve, CartesianDiagram, ColorPalette, ConeDiagram,
+    DatasheetComposer, DatasheetPanel, DiagramScale, FloodlightCartesianDiagram, FootprintDiagram,
+    GlareDiagram, HeatmapDiagram, IntensityTable, IsocandelaDiagram, IsocandelaProjection,
+    IsoluxDiagram, PolarDiagram, RoadDiagram, RoadQuantity, SvgLogo, YScale,
 };
+use crate::bug_rating::{BugDiagram, BugRating};
+use crate::calculations::PhotometricSummary;
+use crate::road::{OBSERVER_DISTANCE, OBSERVER_HEIGHT};
 use crate::units::UnitSystem;
 
 /// Format an illuminance value with appropriate precision.
@@ -50,6 +55,15 @@ fn fmt_lux(val: f64) -> String {
     }
 }
 
+/// Points of an SVG polyline path ("M x y L x y …").
+fn path_points(path: &str) -> Vec<(f64, f64)> {
+    let numbers: Vec<f64> = path
+        .split_whitespace()
+        .filter_map(|token| token.parse().ok())
+        .collect();
+    numbers.chunks_exact(2).map(|p| (p[0], p[1])).collect()
+}
+
 /// Detail level for SVG rendering
 ///
 /// Controls what elements are rendered based on display size.
@@ -265,8 +279,12 @@ impl SvgLabels {
 }
 
 /// Theme configuration for SVG diagrams
+///
+/// Use [`SvgTheme::builder`] for custom styling. Deserialized themes take
+/// missing fields from the light theme.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct SvgTheme {
     /// Background color
     pub background: String,
@@ -294,6 +312,19 @@ pub struct SvgTheme {
     pub font_family: String,
     /// Localized labels for diagram text
     pub labels: SvgLabels,
+    /// Colors for multi-curve diagrams (empty for the built-in palette)
+    pub palette: Vec<String>,
+    /// Multiplier for font sizes
+    pub font_scale: f64,
+    /// Multiplier for line widths
+    pub line_scale: f64,
+    /// Optional logo, e.g. the manufacturer's
+    pub logo: Option<SvgLogo>,
+    /// Units for distances and illuminance in annotations (m/lx or ft/fc)
+    pub units: UnitSystem,
+    /// Tell curves apart without color: a dash pattern per curve and a
+    /// hatched C90-C270 fill (diagrams drawn through `DiagramGeometry`)
+    pub curve_patterns: bool,
 }
 
 impl Default for SvgTheme {
@@ -319,6 +350,12 @@ impl SvgTheme {
             curve_c90_c270_fill: "rgba(239,68,68,0.15)".to_string(),
             font_family: "system-ui, -apple-system, sans-serif".to_string(),
             labels: SvgLabels::default(),
+            palette: Vec::new(),
+            font_scale: 1.0,
+            line_scale: 1.0,
+            logo: None,
+            units: UnitSystem::Metric,
+            curve_patterns: false,
         }
     }
 
@@ -338,6 +375,12 @@ impl SvgTheme {
             curve_c90_c270_fill: "rgba(248,113,113,0.2)".to_string(),
             font_family: "system-ui, -apple-system, sans-serif".to_string(),
             labels: SvgLabels::default(),
+            palette: Vec::new(),
+            font_scale: 1.0,
+            line_scale: 1.0,
+            logo: None,
+            units: UnitSystem::Metric,
+            curve_patterns: false,
         }
     }
 
@@ -357,6 +400,12 @@ impl SvgTheme {
             curve_c90_c270_fill: "var(--diagram-c0-fill, rgba(239,68,68,0.15))".to_string(),
             font_family: "system-ui, -apple-system, sans-serif".to_string(),
             labels: SvgLabels::default(),
+            palette: Vec::new(),
+            font_scale: 1.0,
+            line_scale: 1.0,
+            logo: None,
+            units: UnitSystem::Metric,
+            curve_patterns: false,
         }
     }
 
@@ -366,6 +415,12 @@ impl SvgTheme {
         self
     }
 
+    /// Set the units of distance and illuminance annotations
+    pub fn with_units(mut self, units: UnitSystem) -> Self {
+        self.units = units;
+        self
+    }
+
     /// Create theme with locale labels
     #[cfg(feature = "i18n")]
     pub fn light_with_locale(locale: &eulumdat_i18n::Locale) -> Self {
@@ -386,6 +441,9 @@ impl SvgTheme {
 
     /// Get a color for a C-plane index
     pub fn c_plane_color(&self, index: usize) -> &str {
+        if !self.palette.is_empty() {
+            return &self.palette[index % self.palette.len()];
+        }
         const COLORS: &[&str] = &[
             "#3b82f6", // blue
             "#ef4444", // red
@@ -525,192 +583,8 @@ impl Default for IsometricConfig {
 impl PolarDiagram {
     /// Generate complete SVG string for the polar diagram
     pub fn to_svg(&self, width: f64, height: f64, theme: &SvgTheme) -> String {
-        let size = width.min(height);
-        let center = size / 2.0;
-        let margin = 60.0;
-        let radius = (size / 2.0) - margin;
-        let scale = self.scale.scale_max / radius;
-
-        let mut svg = String::new();
-
-        // SVG header
-        svg.push_str(&format!(
-            r#"<svg viewBox="0 0 {size} {size}" xmlns="http://www.w3.org/2000/svg">"#
-        ));
-
-        // Background
-        svg.push_str(&format!(
-            r#"<rect x="0" y="0" width="{size}" height="{size}" fill="{}"/>"#,
-            theme.background
-        ));
-
-        // Grid circles
-        let num_circles = self.scale.grid_values.len();
-        for (i, &value) in self.scale.grid_values.iter().enumerate() {
-            let r = value / scale;
-            let is_major = i == num_circles - 1 || i == num_circles / 2;
-            let stroke_color = if is_major { &theme.axis } else { &theme.grid };
-            let stroke_width = if is_major { "1.5" } else { "1" };
-
-            svg.push_str(&format!(
-                r#"<circle cx="{center}" cy="{center}" r="{r:.1}" fill="none" stroke="{stroke_color}" stroke-width="{stroke_width}"/>"#
-            ));
-
-            // Intensity label
-            svg.push_str(&format!(
-                r#"<text x="{:.1}" y="{:.1}" font-size="11" fill="{}" font-family="{}">{:.0}</text>"#,
-                center + 5.0,
-                center + r + 12.0,
-                theme.text_secondary,
-                theme.font_family,
-                value
-            ));
-        }
-
-        // Radial lines every 30°
-        for i in 0..=6 {
-            if i == 3 {
-                continue; // Skip 90° (drawn separately)
-            }
-            let angle_deg = i as f64 * 30.0;
-            let angle_rad = angle_deg.to_radians();
-
-            let x1_left = center - radius * angle_rad.sin();
-            let y1_left = center + radius * angle_rad.cos();
-            let x1_right = center + radius * angle_rad.sin();
-            let y1_right = center + radius * angle_rad.cos();
-
-            svg.push_str(&format!(
-                r#"<line x1="{center}" y1="{center}" x2="{x1_left:.1}" y2="{y1_left:.1}" stroke="{}" stroke-width="1"/>"#,
-                theme.grid
-            ));
-            svg.push_str(&format!(
-                r#"<line x1="{center}" y1="{center}" x2="{x1_right:.1}" y2="{y1_right:.1}" stroke="{}" stroke-width="1"/>"#,
-                theme.grid
-            ));
-
-            // Angle labels
-            if angle_deg > 0.0 && angle_deg < 180.0 {
-                let label_offset = radius + 18.0;
-                let label_x_left = center - label_offset * angle_rad.sin();
-                let label_y_left = center + label_offset * angle_rad.cos();
-                let label_x_right = center + label_offset * angle_rad.sin();
-                let label_y_right = center + label_offset * angle_rad.cos();
-
-                svg.push_str(&format!(
-                    r#"<text x="{label_x_left:.1}" y="{label_y_left:.1}" text-anchor="middle" dominant-baseline="middle" font-size="11" fill="{}" font-family="{}">{angle_deg:.0}°</text>"#,
-                    theme.text_secondary, theme.font_family
-                ));
-                svg.push_str(&format!(
-                    r#"<text x="{label_x_right:.1}" y="{label_y_right:.1}" text-anchor="middle" dominant-baseline="middle" font-size="11" fill="{}" font-family="{}">{angle_deg:.0}°</text>"#,
-                    theme.text_secondary, theme.font_family
-                ));
-            }
-        }
-
-        // 180° label at top
-        svg.push_str(&format!(
-            r#"<text x="{center}" y="{:.1}" text-anchor="middle" font-size="11" fill="{}" font-family="{}">180°</text>"#,
-            center - radius - 20.0,
-            theme.text_secondary,
-            theme.font_family
-        ));
-
-        // 90° horizontal line
-        svg.push_str(&format!(
-            r#"<line x1="{:.1}" y1="{center}" x2="{:.1}" y2="{center}" stroke="{}" stroke-width="1.5"/>"#,
-            center - radius,
-            center + radius,
-            theme.axis
-        ));
-
-        // 90° labels
-        svg.push_str(&format!(
-            r#"<text x="{:.1}" y="{center}" text-anchor="middle" dominant-baseline="middle" font-size="11" fill="{}" font-family="{}">90°</text>"#,
-            center - radius - 20.0,
-            theme.text_secondary,
-            theme.font_family
-        ));
-        svg.push_str(&format!(
-            r#"<text x="{:.1}" y="{center}" text-anchor="middle" dominant-baseline="middle" font-size="11" fill="{}" font-family="{}">90°</text>"#,
-            center + radius + 20.0,
-            theme.text_secondary,
-            theme.font_family
-        ));
-
-        // C0-C180 curve
-        let path_c0_c180 = self.c0_c180_curve.to_svg_path(center, center, scale);
-        if !path_c0_c180.is_empty() {
-            svg.push_str(&format!(
-                r#"<path d="{}" fill="{}" stroke="{}" stroke-width="2.5"/>"#,
-                path_c0_c180, theme.curve_c0_c180_fill, theme.curve_c0_c180
-            ));
-        }
-
-        // C90-C270 curve
-        if self.show_c90_c270() {
-            let path_c90_c270 = self.c90_c270_curve.to_svg_path(center, center, scale);
-            if !path_c90_c270.is_empty() {
-                svg.push_str(&format!(
-                    r#"<path d="{}" fill="{}" stroke="{}" stroke-width="2.5" stroke-dasharray="6,4"/>"#,
-                    path_c90_c270,
-                    theme.curve_c90_c270_fill,
-                    theme.curve_c90_c270
-                ));
-            }
-        }
-
-        // Center point
-        svg.push_str(&format!(
-            r#"<circle cx="{center}" cy="{center}" r="3" fill="{}"/>"#,
-            theme.text
-        ));
-
-        // Legend
-        svg.push_str(&format!(
-            r#"<g transform="translate(15, {:.1})">"#,
-            size - 55.0
-        ));
-        svg.push_str(&format!(
-            r#"<rect x="0" y="0" width="16" height="16" fill="{}" stroke="{}" stroke-width="2" rx="2"/>"#,
-            theme.curve_c0_c180_fill,
-            theme.curve_c0_c180
-        ));
-        svg.push_str(&format!(
-            r#"<text x="22" y="12" font-size="12" fill="{}" font-family="{}">{}</text>"#,
-            theme.text, theme.font_family, theme.labels.plane_c0_c180
-        ));
-        svg.push_str("</g>");
-
-        if self.show_c90_c270() {
-            svg.push_str(&format!(
-                r#"<g transform="translate(15, {:.1})">"#,
-                size - 32.0
-            ));
-            svg.push_str(&format!(
-                r#"<rect x="0" y="0" width="16" height="16" fill="{}" stroke="{}" stroke-width="2" stroke-dasharray="4,2" rx="2"/>"#,
-                theme.curve_c90_c270_fill,
-                theme.curve_c90_c270
-            ));
-            svg.push_str(&format!(
-                r#"<text x="22" y="12" font-size="12" fill="{}" font-family="{}">{}</text>"#,
-                theme.text, theme.font_family, theme.labels.plane_c90_c270
-            ));
-            svg.push_str("</g>");
-        }
-
-        // Unit label
-        svg.push_str(&format!(
-            r#"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="11" fill="{}" font-family="{}">{}</text>"#,
-            size - 15.0,
-            size - 15.0,
-            theme.text_secondary,
-            theme.font_family,
-            theme.labels.intensity_unit
-        ));
-
-        svg.push_str("</svg>");
-        svg
+        self.to_geometry(width.min(height), &theme.labels)
+            .to_svg(theme)
     }
 
     /// Generate responsive SVG string for the polar diagram
@@ -982,7 +856,6 @@ impl PolarDiagram {
         let center = size / 2.0;
         let margin = 60.0;
         let radius = (size / 2.0) - margin;
-        let scale = self.scale.scale_max / radius;
 
         let mut svg = String::new();
 
@@ -998,9 +871,10 @@ impl PolarDiagram {
         ));
 
         // Grid circles
-        let num_circles = self.scale.grid_values.len();
-        for (i, &value) in self.scale.grid_values.iter().enumerate() {
-            let r = value / scale;
+        let rings = self.radial_scale.ring_values(&self.scale);
+        let num_circles = rings.len();
+        for (i, &value) in rings.iter().enumerate() {
+            let r = self.radius_of(value, radius);
             let is_major = i == num_circles - 1 || i == num_circles / 2;
             let stroke_color = if is_major { &theme.axis } else { &theme.grid };
             let stroke_width = if is_major { "1.5" } else { "1" };
@@ -1011,12 +885,12 @@ impl PolarDiagram {
 
             // Intensity label
             svg.push_str(&format!(
-                r#"<text x="{:.1}" y="{:.1}" font-size="11" fill="{}" font-family="{}">{:.0}</text>"#,
+                r#"<text x="{:.1}" y="{:.1}" font-size="11" fill="{}" font-family="{}">{}</text>"#,
                 center + 5.0,
                 center + r + 12.0,
                 theme.text_secondary,
                 theme.font_family,
-                value
+                self.radial_scale.format_value(value)
             ));
         }
 
@@ -1262,7 +1136,19 @@ impl PolarDiagram {
         }
 
         // C0-C180 curve
-        let path_c0_c180 = self.c0_c180_curve.to_svg_path(center, center, scale);
+        let curve_path = |curve| {
+            let points: Vec<(f64, f64)> = self
+                .curve_screen_points(curve, center, center, radius)
+                .iter()
+                .map(|p| (p.x, p.y))
+                .collect();
+            if points.is_empty() {
+                String::new()
+            } else {
+                polyline_path(&points, true)
+            }
+        };
+        let path_c0_c180 = curve_path(&self.c0_c180_curve);
         if !path_c0_c180.is_empty() {
             svg.push_str(&format!(
                 r#"<path d="{}" fill="{}" stroke="{}" stroke-width="2.5"/>"#,
@@ -1272,7 +1158,7 @@ impl PolarDiagram {
 
         // C90-C270 curve
         if self.show_c90_c270() {
-            let path_c90_c270 = self.c90_c270_curve.to_svg_path(center, center, scale);
+            let path_c90_c270 = curve_path(&self.c90_c270_curve);
             if !path_c90_c270.is_empty() {
                 svg.push_str(&format!(
                     r#"<path d="{}" fill="{}" stroke="{}" stroke-width="2.5" stroke-dasharray="6,4"/>"#,
@@ -1292,7 +1178,7 @@ impl PolarDiagram {
         // Max intensity marker at peak
         if summary.max_intensity > 0.0 {
             // Find approximate peak position (nadir, 0°)
-            let _peak_y = center + (summary.max_intensity / scale).min(radius);
+            let _peak_y = center + self.radius_of(summary.max_intensity, radius).min(radius);
             svg.push_str(&format!(
                 r#"<text x="{:.1}" y="{:.1}" text-anchor="start" font-size="10" fill="{}" font-family="{}" font-weight="bold">↑ {:.0}</text>"#,
                 center + 8.0,
@@ -1418,12 +1304,13 @@ impl PolarDiagram {
 
         // Unit label
         svg.push_str(&format!(
-            r#"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="11" fill="{}" font-family="{}">{}</text>"#,
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="11" fill="{}" font-family="{}">{}{}</text>"#,
             size - 15.0,
             size - 15.0,
             theme.text_secondary,
             theme.font_family,
-            theme.labels.intensity_unit
+            theme.labels.intensity_unit,
+            self.radial_scale.unit_suffix()
         ));
 
         svg.push_str("</svg>");
@@ -1747,6 +1634,19 @@ impl PolarDiagram {
 impl CartesianDiagram {
     /// Generate complete SVG string for the cartesian diagram
     pub fn to_svg(&self, width: f64, height: f64, theme: &SvgTheme) -> String {
+        self.to_geometry(width, height, &theme.labels).to_svg(theme)
+    }
+
+    /// Generate SVG with beam/field angle markers.
+    ///
+    /// Adds vertical lines at beam (50%) and field (10%) angles.
+    pub fn to_svg_with_summary(
+        &self,
+        width: f64,
+        height: f64,
+        theme: &SvgTheme,
+        summary: &crate::calculations::PhotometricSummary,
+    ) -> String {
         let margin_left = self.margin_left;
         let margin_top = self.margin_top;
         let plot_width = self.plot_width;
@@ -1805,167 +1705,27 @@ impl CartesianDiagram {
             ));
         }
 
-        // Intensity curves
-        for curve in &self.curves {
-            let path = curve.to_svg_path();
+        // === BEAM ANGLE MARKER (50%) ===
+        // Note: beam_angle is now the full angle per CIE S 017:2020
+        // For the Cartesian diagram, we plot gamma (half angle) on x-axis
+        let green = "#22c55e";
+        let orange = "#f97316";
+
+        let half_beam = summary.beam_angle / 2.0;
+        if half_beam > 0.0 && half_beam < self.max_gamma {
+            let beam_x = margin_left + plot_width * (half_beam / self.max_gamma);
             svg.push_str(&format!(
-                r#"<path d="{}" fill="none" stroke="{}" stroke-width="2.5" stroke-linecap="round" stroke-linejoin="round"/>"#,
-                path,
-                curve.color.to_rgb_string()
-            ));
-        }
-
-        // Axis labels
-        svg.push_str(&format!(
-            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="12" fill="{}" font-family="{}">{}</text>"#,
-            margin_left + plot_width / 2.0,
-            height - 8.0,
-            theme.text,
-            theme.font_family,
-            theme.labels.gamma_axis
-        ));
-
-        svg.push_str(&format!(
-            r#"<text x="18" y="{:.1}" text-anchor="middle" font-size="12" fill="{}" font-family="{}" transform="rotate(-90, 18, {:.1})">{}</text>"#,
-            margin_top + plot_height / 2.0,
-            theme.text,
-            theme.font_family,
-            margin_top + plot_height / 2.0,
-            theme.labels.intensity_axis
-        ));
-
-        // Legend
-        let legend_height = self.curves.len() as f64 * 18.0 + 10.0;
-        svg.push_str(&format!(
-            r#"<g transform="translate({:.1}, {:.1})">"#,
-            margin_left + 10.0,
-            margin_top + 10.0
-        ));
-        svg.push_str(&format!(
-            r#"<rect x="-5" y="-5" width="90" height="{legend_height:.1}" fill="{}" stroke="{}" stroke-width="1" rx="4"/>"#,
-            theme.legend_bg,
-            theme.axis
-        ));
-
-        for (i, curve) in self.curves.iter().enumerate() {
-            let y = i as f64 * 18.0 + 8.0;
-            svg.push_str(&format!(
-                r#"<line x1="0" y1="{y:.1}" x2="18" y2="{y:.1}" stroke="{}" stroke-width="2.5"/>"#,
-                curve.color.to_rgb_string()
-            ));
-            svg.push_str(&format!(
-                r#"<text x="24" y="{:.1}" font-size="11" fill="{}" font-family="{}">{}</text>"#,
-                y + 4.0,
-                theme.text,
-                theme.font_family,
-                curve.label
-            ));
-        }
-        svg.push_str("</g>");
-
-        // Max intensity label
-        svg.push_str(&format!(
-            r#"<text x="{:.1}" y="20" text-anchor="end" font-size="11" fill="{}" font-family="{}">{} {:.0} cd/klm</text>"#,
-            width - 15.0,
-            theme.text_secondary,
-            theme.font_family,
-            theme.labels.max_label,
-            self.scale.max_intensity
-        ));
-
-        svg.push_str("</svg>");
-        svg
-    }
-
-    /// Generate SVG with beam/field angle markers.
-    ///
-    /// Adds vertical lines at beam (50%) and field (10%) angles.
-    pub fn to_svg_with_summary(
-        &self,
-        width: f64,
-        height: f64,
-        theme: &SvgTheme,
-        summary: &crate::calculations::PhotometricSummary,
-    ) -> String {
-        let margin_left = self.margin_left;
-        let margin_top = self.margin_top;
-        let plot_width = self.plot_width;
-        let plot_height = self.plot_height;
-        let y_max = self.scale.scale_max;
-
-        let mut svg = String::new();
-
-        // SVG header
-        svg.push_str(&format!(
-            r#"<svg viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg">"#
-        ));
-
-        // Background
-        svg.push_str(&format!(
-            r#"<rect x="0" y="0" width="{width}" height="{height}" fill="{}"/>"#,
-            theme.background
-        ));
-
-        // Plot area background
-        svg.push_str(&format!(
-            r#"<rect x="{margin_left}" y="{margin_top}" width="{plot_width}" height="{plot_height}" fill="{}" stroke="{}" stroke-width="1"/>"#,
-            theme.surface,
-            theme.axis
-        ));
-
-        // Y-axis grid lines and labels
-        for &v in &self.y_ticks {
-            let y = margin_top + plot_height * (1.0 - v / y_max);
-            svg.push_str(&format!(
-                r#"<line x1="{margin_left}" y1="{y:.1}" x2="{:.1}" y2="{y:.1}" stroke="{}" stroke-width="1"/>"#,
-                margin_left + plot_width,
-                theme.grid
-            ));
-            svg.push_str(&format!(
-                r#"<text x="{:.1}" y="{y:.1}" text-anchor="end" dominant-baseline="middle" font-size="11" fill="{}" font-family="{}">{v:.0}</text>"#,
-                margin_left - 8.0,
-                theme.text_secondary,
-                theme.font_family
-            ));
-        }
-
-        // X-axis grid lines and labels
-        for &v in &self.x_ticks {
-            let x = margin_left + plot_width * (v / self.max_gamma);
-            svg.push_str(&format!(
-                r#"<line x1="{x:.1}" y1="{margin_top}" x2="{x:.1}" y2="{:.1}" stroke="{}" stroke-width="1"/>"#,
-                margin_top + plot_height,
-                theme.grid
-            ));
-            svg.push_str(&format!(
-                r#"<text x="{x:.1}" y="{:.1}" text-anchor="middle" font-size="11" fill="{}" font-family="{}">{v:.0}°</text>"#,
-                margin_top + plot_height + 18.0,
-                theme.text_secondary,
-                theme.font_family
-            ));
-        }
-
-        // === BEAM ANGLE MARKER (50%) ===
-        // Note: beam_angle is now the full angle per CIE S 017:2020
-        // For the Cartesian diagram, we plot gamma (half angle) on x-axis
-        let green = "#22c55e";
-        let orange = "#f97316";
-
-        let half_beam = summary.beam_angle / 2.0;
-        if half_beam > 0.0 && half_beam < self.max_gamma {
-            let beam_x = margin_left + plot_width * (half_beam / self.max_gamma);
-            svg.push_str(&format!(
-                r#"<line x1="{beam_x:.1}" y1="{margin_top}" x2="{beam_x:.1}" y2="{:.1}" stroke="{}" stroke-width="2" stroke-dasharray="6,4" opacity="0.8"/>"#,
-                margin_top + plot_height,
-                green
-            ));
-            // Display full angle in label per CIE definition
-            svg.push_str(&format!(
-                r#"<text x="{beam_x:.1}" y="{:.1}" text-anchor="middle" font-size="9" fill="{}" font-weight="bold">{} {:.0}°</text>"#,
-                margin_top - 5.0,
-                green,
-                theme.labels.beam,
-                summary.beam_angle
+                r#"<line x1="{beam_x:.1}" y1="{margin_top}" x2="{beam_x:.1}" y2="{:.1}" stroke="{}" stroke-width="2" stroke-dasharray="6,4" opacity="0.8"/>"#,
+                margin_top + plot_height,
+                green
+            ));
+            // Display full angle in label per CIE definition
+            svg.push_str(&format!(
+                r#"<text x="{beam_x:.1}" y="{:.1}" text-anchor="middle" font-size="9" fill="{}" font-weight="bold">{} {:.0}°</text>"#,
+                margin_top - 5.0,
+                green,
+                theme.labels.beam,
+                summary.beam_angle
             ));
         }
 
@@ -1998,6 +1758,7 @@ impl CartesianDiagram {
                 curve.color.to_rgb_string()
             ));
         }
+        super::geometry::push_primitives_svg(&mut svg, &self.batwing_primitives(), theme);
 
         // Axis labels
         svg.push_str(&format!(
@@ -2114,15 +1875,21 @@ impl CartesianDiagram {
 impl HeatmapDiagram {
     /// Generate complete SVG string for the heatmap diagram
     pub fn to_svg(&self, width: f64, height: f64, theme: &SvgTheme) -> String {
+        self.to_geometry(width, height, &theme.labels).to_svg(theme)
+    }
+
+    /// Generate SVG with zonal lumens breakdown overlay.
+    ///
+    /// Adds horizontal zone boundary lines and a zonal breakdown panel.
+    pub fn to_svg_with_summary(
+        &self,
+        width: f64,
+        height: f64,
+        theme: &SvgTheme,
+        summary: &crate::calculations::PhotometricSummary,
+    ) -> String {
         if self.is_empty() {
-            return format!(
-                r#"<svg viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg"><rect width="{width}" height="{height}" fill="{}"/><text x="{:.1}" y="{:.1}" text-anchor="middle" fill="{}">{}</text></svg>"#,
-                theme.background,
-                width / 2.0,
-                height / 2.0,
-                theme.text_secondary,
-                theme.labels.no_data
-            );
+            return self.to_svg(width, height, theme);
         }
 
         let mut svg = String::new();
@@ -2158,6 +1925,13 @@ impl HeatmapDiagram {
         ));
 
         // Heatmap cells
+        let num_c = self.c_angles.len().max(1);
+        let num_g = self.g_angles.len().max(1);
+        let val_step = ((num_c.max(num_g) as f64 / 8.0).ceil() as usize).max(1);
+        let val_font = (self.plot_width / num_c as f64 * val_step as f64 * 0.3)
+            .min(self.plot_height / num_g as f64 * val_step as f64 * 0.35)
+            .clamp(5.0, 11.0);
+
         for cell in &self.cells {
             svg.push_str(&format!(
                 r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="{}"><title>C{:.0}° γ{:.0}°: {:.1} cd ({:.1} cd/klm)</title></rect>"#,
@@ -2171,6 +1945,55 @@ impl HeatmapDiagram {
                 cell.candela,
                 cell.intensity
             ));
+
+            if self.show_values
+                && cell.c_index % val_step == val_step / 2
+                && cell.g_index % val_step == val_step / 2
+            {
+                let text_color = if cell.color.luminance() < 0.5 {
+                    "#ffffff"
+                } else {
+                    "#1a1a1a"
+                };
+                let cx = cell.x + cell.width * val_step as f64 / 2.0;
+                let cy = cell.y + cell.height * val_step as f64 / 2.0;
+                svg.push_str(&format!(
+                    r#"<text x="{cx:.1}" y="{cy:.1}" fill="{text_color}" font-size="{val_font:.1}" text-anchor="middle" dominant-baseline="central" font-family="monospace">{:.0}</text>"#,
+                    cell.intensity
+                ));
+            }
+        }
+
+        // === ZONE BOUNDARY LINES ===
+        let zone_angles = [30.0, 60.0, 90.0, 120.0, 150.0];
+        let num_g = self.g_angles.len();
+        let cell_height = self.plot_height / num_g as f64;
+
+        for &angle in &zone_angles {
+            // Find the Y position for this gamma angle
+            if let Some(idx) = self.g_angles.iter().position(|&g| (g - angle).abs() < 1.0) {
+                let y = self.margin_top + idx as f64 * cell_height;
+                let white = "#ffffff";
+                let black = "#000000";
+
+                // Draw a dashed line
+                svg.push_str(&format!(
+                    r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="2" stroke-dasharray="4,2" opacity="0.7"/>"#,
+                    self.margin_left, y,
+                    self.margin_left + self.plot_width, y,
+                    if angle == 90.0 { white } else { black }
+                ));
+
+                // Label
+                svg.push_str(&format!(
+                    r#"<text x="{:.1}" y="{:.1}" font-size="9" fill="{}" font-family="{}" font-weight="bold">{:.0}°</text>"#,
+                    self.margin_left + 4.0,
+                    y - 3.0,
+                    theme.text,
+                    theme.font_family,
+                    angle
+                ));
+            }
         }
 
         // X-axis labels (C-angles)
@@ -2196,7 +2019,6 @@ impl HeatmapDiagram {
         }
 
         // Y-axis labels (G-angles)
-        let num_g = self.g_angles.len();
         let step = if num_g <= 10 {
             1
         } else if num_g <= 20 {
@@ -2204,7 +2026,6 @@ impl HeatmapDiagram {
         } else {
             5
         };
-        let cell_height = self.plot_height / num_g as f64;
         for (i, &g) in self.g_angles.iter().enumerate() {
             if i % step == 0 {
                 let y = self.margin_top + (i as f64 + 0.5) * cell_height;
@@ -2236,269 +2057,23 @@ impl HeatmapDiagram {
             theme.labels.gamma_angle_axis
         ));
 
-        // Color legend
-        let legend_x = width - 80.0;
-        let legend_width = 20.0;
-        let num_segments = 50;
-        let segment_height = self.plot_height / num_segments as f64;
-
-        for (normalized, color, _) in &self.legend_entries {
-            let i = ((1.0 - normalized) * (num_segments as f64 - 1.0)) as usize;
-            let sy = self.margin_top + i as f64 * segment_height;
-            svg.push_str(&format!(
-                r#"<rect x="{legend_x:.1}" y="{sy:.1}" width="{legend_width:.1}" height="{:.1}" fill="{}"/>"#,
-                segment_height + 0.5,
-                color.to_rgb_string()
-            ));
-        }
+        // === ZONAL LUMENS BREAKDOWN PANEL ===
+        let panel_x = width - 135.0;
+        let panel_y = self.margin_top;
+        let panel_w = 125.0;
+        let panel_h = 125.0;
 
-        // Legend border
         svg.push_str(&format!(
-            r#"<rect x="{legend_x:.1}" y="{:.1}" width="{legend_width:.1}" height="{:.1}" fill="none" stroke="{}" stroke-width="1"/>"#,
-            self.margin_top,
-            self.plot_height,
-            theme.grid
+            r#"<rect x="{panel_x}" y="{panel_y}" width="{panel_w}" height="{panel_h}" fill="{}" stroke="{}" stroke-width="1" rx="4" opacity="0.95"/>"#,
+            theme.legend_bg,
+            theme.axis
         ));
 
-        // Legend labels
-        let num_labels = 5;
-        for i in 0..=num_labels {
-            let frac = i as f64 / num_labels as f64;
-            let value = self.max_candela * (1.0 - frac);
-            let ly = self.margin_top + frac * self.plot_height;
-
-            svg.push_str(&format!(
-                r#"<line x1="{:.1}" y1="{ly:.1}" x2="{:.1}" y2="{ly:.1}" stroke="{}" stroke-width="1"/>"#,
-                legend_x + legend_width,
-                legend_x + legend_width + 5.0,
-                theme.grid
-            ));
-            svg.push_str(&format!(
-                r#"<text x="{:.1}" y="{ly:.1}" dominant-baseline="middle" font-size="9" fill="{}" font-family="{}">{value:.0}</text>"#,
-                legend_x + legend_width + 8.0,
-                theme.text_secondary,
-                theme.font_family
-            ));
-        }
-
-        // Legend title
+        // Panel title
         svg.push_str(&format!(
-            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="10" fill="{}" font-family="{}">cd</text>"#,
-            legend_x + legend_width / 2.0,
-            self.margin_top - 8.0,
-            theme.text,
-            theme.font_family
-        ));
-
-        // Max value indicator
-        svg.push_str(&format!(
-            r#"<text x="{:.1}" y="25" text-anchor="end" font-size="11" fill="{}" font-family="{}">Max: {:.0} cd</text>"#,
-            width - 15.0,
-            theme.text_secondary,
-            theme.font_family,
-            self.max_candela
-        ));
-
-        svg.push_str("</svg>");
-        svg
-    }
-
-    /// Generate SVG with zonal lumens breakdown overlay.
-    ///
-    /// Adds horizontal zone boundary lines and a zonal breakdown panel.
-    pub fn to_svg_with_summary(
-        &self,
-        width: f64,
-        height: f64,
-        theme: &SvgTheme,
-        summary: &crate::calculations::PhotometricSummary,
-    ) -> String {
-        if self.is_empty() {
-            return self.to_svg(width, height, theme);
-        }
-
-        let mut svg = String::new();
-
-        // SVG header
-        svg.push_str(&format!(
-            r#"<svg viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg">"#
-        ));
-
-        // Background
-        svg.push_str(&format!(
-            r#"<rect x="0" y="0" width="{width}" height="{height}" fill="{}"/>"#,
-            theme.background
-        ));
-
-        // Title
-        svg.push_str(&format!(
-            r#"<text x="{:.1}" y="25" text-anchor="middle" font-size="14" fill="{}" font-weight="600" font-family="{}">{}</text>"#,
-            width / 2.0,
-            theme.text,
-            theme.font_family,
-            theme.labels.heatmap_title
-        ));
-
-        // Plot area border
-        svg.push_str(&format!(
-            r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="none" stroke="{}" stroke-width="1"/>"#,
-            self.margin_left,
-            self.margin_top,
-            self.plot_width,
-            self.plot_height,
-            theme.grid
-        ));
-
-        // Heatmap cells
-        let num_c = self.c_angles.len().max(1);
-        let num_g = self.g_angles.len().max(1);
-        let val_step = ((num_c.max(num_g) as f64 / 8.0).ceil() as usize).max(1);
-        let val_font = (self.plot_width / num_c as f64 * val_step as f64 * 0.3)
-            .min(self.plot_height / num_g as f64 * val_step as f64 * 0.35)
-            .clamp(5.0, 11.0);
-
-        for cell in &self.cells {
-            svg.push_str(&format!(
-                r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="{}"><title>C{:.0}° γ{:.0}°: {:.1} cd ({:.1} cd/klm)</title></rect>"#,
-                cell.x,
-                cell.y,
-                cell.width,
-                cell.height,
-                cell.color.to_rgb_string(),
-                cell.c_angle,
-                cell.g_angle,
-                cell.candela,
-                cell.intensity
-            ));
-
-            if self.show_values
-                && cell.c_index % val_step == val_step / 2
-                && cell.g_index % val_step == val_step / 2
-            {
-                let text_color = if cell.normalized < 0.45 {
-                    "#ffffff"
-                } else {
-                    "#1a1a1a"
-                };
-                let cx = cell.x + cell.width * val_step as f64 / 2.0;
-                let cy = cell.y + cell.height * val_step as f64 / 2.0;
-                svg.push_str(&format!(
-                    r#"<text x="{cx:.1}" y="{cy:.1}" fill="{text_color}" font-size="{val_font:.1}" text-anchor="middle" dominant-baseline="central" font-family="monospace">{:.0}</text>"#,
-                    cell.intensity
-                ));
-            }
-        }
-
-        // === ZONE BOUNDARY LINES ===
-        let zone_angles = [30.0, 60.0, 90.0, 120.0, 150.0];
-        let num_g = self.g_angles.len();
-        let cell_height = self.plot_height / num_g as f64;
-
-        for &angle in &zone_angles {
-            // Find the Y position for this gamma angle
-            if let Some(idx) = self.g_angles.iter().position(|&g| (g - angle).abs() < 1.0) {
-                let y = self.margin_top + idx as f64 * cell_height;
-                let white = "#ffffff";
-                let black = "#000000";
-
-                // Draw a dashed line
-                svg.push_str(&format!(
-                    r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="2" stroke-dasharray="4,2" opacity="0.7"/>"#,
-                    self.margin_left, y,
-                    self.margin_left + self.plot_width, y,
-                    if angle == 90.0 { white } else { black }
-                ));
-
-                // Label
-                svg.push_str(&format!(
-                    r#"<text x="{:.1}" y="{:.1}" font-size="9" fill="{}" font-family="{}" font-weight="bold">{:.0}°</text>"#,
-                    self.margin_left + 4.0,
-                    y - 3.0,
-                    theme.text,
-                    theme.font_family,
-                    angle
-                ));
-            }
-        }
-
-        // X-axis labels (C-angles)
-        let num_c = self.c_angles.len();
-        let step = if num_c <= 10 {
-            1
-        } else if num_c <= 20 {
-            2
-        } else {
-            5
-        };
-        let cell_width = self.plot_width / num_c as f64;
-        for (i, &c) in self.c_angles.iter().enumerate() {
-            if i % step == 0 {
-                let x = self.margin_left + (i as f64 + 0.5) * cell_width;
-                svg.push_str(&format!(
-                    r#"<text x="{x:.1}" y="{:.1}" text-anchor="middle" font-size="9" fill="{}" font-family="{}">{c:.0}</text>"#,
-                    self.margin_top + self.plot_height + 15.0,
-                    theme.text_secondary,
-                    theme.font_family
-                ));
-            }
-        }
-
-        // Y-axis labels (G-angles)
-        let step = if num_g <= 10 {
-            1
-        } else if num_g <= 20 {
-            2
-        } else {
-            5
-        };
-        for (i, &g) in self.g_angles.iter().enumerate() {
-            if i % step == 0 {
-                let y = self.margin_top + (i as f64 + 0.5) * cell_height;
-                svg.push_str(&format!(
-                    r#"<text x="{:.1}" y="{y:.1}" text-anchor="end" dominant-baseline="middle" font-size="9" fill="{}" font-family="{}">{g:.0}</text>"#,
-                    self.margin_left - 8.0,
-                    theme.text_secondary,
-                    theme.font_family
-                ));
-            }
-        }
-
-        // Axis titles
-        svg.push_str(&format!(
-            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="12" fill="{}" font-family="{}">{}</text>"#,
-            self.margin_left + self.plot_width / 2.0,
-            height - 10.0,
-            theme.text,
-            theme.font_family,
-            theme.labels.c_plane_axis
-        ));
-
-        svg.push_str(&format!(
-            r#"<text x="18" y="{:.1}" text-anchor="middle" font-size="12" fill="{}" font-family="{}" transform="rotate(-90, 18, {:.1})">{}</text>"#,
-            self.margin_top + self.plot_height / 2.0,
-            theme.text,
-            theme.font_family,
-            self.margin_top + self.plot_height / 2.0,
-            theme.labels.gamma_angle_axis
-        ));
-
-        // === ZONAL LUMENS BREAKDOWN PANEL ===
-        let panel_x = width - 135.0;
-        let panel_y = self.margin_top;
-        let panel_w = 125.0;
-        let panel_h = 125.0;
-
-        svg.push_str(&format!(
-            r#"<rect x="{panel_x}" y="{panel_y}" width="{panel_w}" height="{panel_h}" fill="{}" stroke="{}" stroke-width="1" rx="4" opacity="0.95"/>"#,
-            theme.legend_bg,
-            theme.axis
-        ));
-
-        // Panel title
-        svg.push_str(&format!(
-            r#"<text x="{:.1}" y="{:.1}" font-size="10" fill="{}" font-family="{}" font-weight="bold">Zonal Lumens</text>"#,
-            panel_x + 8.0,
-            panel_y + 15.0,
+            r#"<text x="{:.1}" y="{:.1}" font-size="10" fill="{}" font-family="{}" font-weight="bold">Zonal Lumens</text>"#,
+            panel_x + 8.0,
+            panel_y + 15.0,
             theme.text,
             theme.font_family
         ));
@@ -2896,16 +2471,38 @@ impl ConeDiagram {
         converted.mounting_height = units.convert_meters(self.mounting_height);
         converted.beam_diameter = units.convert_meters(self.beam_diameter);
         converted.field_diameter = units.convert_meters(self.field_diameter);
-        converted.to_svg_with_labels(width, height, theme, &labels)
+        for level in &mut converted.height_levels {
+            level.height = units.convert_meters(level.height);
+            level.beam_diameter = units.convert_meters(level.beam_diameter);
+            level.field_diameter = units.convert_meters(level.field_diameter);
+            level.e_nadir = units.convert_lux(level.e_nadir);
+        }
+        converted.render_svg(width, height, theme, &labels, units.illuminance_label())
     }
 
     /// Generate SVG string with custom labels (for i18n)
+    ///
+    /// Distances and illuminance use the theme's units.
     pub fn to_svg_with_labels(
         &self,
         width: f64,
         height: f64,
         theme: &SvgTheme,
         labels: &ConeDiagramLabels,
+    ) -> String {
+        match theme.units {
+            UnitSystem::Metric => self.render_svg(width, height, theme, labels, "lx"),
+            units => self.to_svg_with_units(width, height, theme, labels, units),
+        }
+    }
+
+    fn render_svg(
+        &self,
+        width: f64,
+        height: f64,
+        theme: &SvgTheme,
+        labels: &ConeDiagramLabels,
+        illuminance_label: &str,
     ) -> String {
         let margin_top = 60.0;
         let margin_bottom = 80.0;
@@ -2986,6 +2583,45 @@ impl ConeDiagram {
             theme.text_secondary
         ));
 
+        // Mounting height levels: height on the left, beam diameter and
+        // center illuminance right of the axis
+        for level in &self.height_levels {
+            let t = level.height / self.mounting_height.max(f64::EPSILON);
+            let y = luminaire_y + cone_height * t;
+            let (beam_half, field_half) = (beam_x_offset * t, field_x_offset * t);
+            svg.push_str(&format!(
+                r#"<g class="cone-level"><line x1="{:.1}" y1="{y:.1}" x2="{:.1}" y2="{y:.1}" stroke="{}" stroke-width="1" stroke-dasharray="2,3"/><circle cx="{:.1}" cy="{y:.1}" r="2.5" fill="{}"/><circle cx="{:.1}" cy="{y:.1}" r="2.5" fill="{}"/>"#,
+                cx - field_half,
+                cx + field_half,
+                theme.text_secondary,
+                cx - beam_half,
+                "#fbbf24",
+                cx + beam_half,
+                "#fbbf24"
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="10" fill="{}" font-family="{}">{:.1}{}</text>"#,
+                cx - field_half - 6.0,
+                y + 3.5,
+                theme.text_secondary,
+                theme.font_family,
+                level.height,
+                labels.meter
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{:.1}" font-size="10" fill="{}" font-family="{}" paint-order="stroke" stroke="{}" stroke-width="3">{} {:.2}{} · E₀ {} {illuminance_label}</text></g>"#,
+                cx + 6.0,
+                y - 4.0,
+                theme.text,
+                theme.font_family,
+                theme.background,
+                labels.beam_diameter,
+                level.beam_diameter,
+                labels.meter,
+                fmt_lux(level.e_nadir)
+            ));
+        }
+
         // Luminaire symbol (rectangle at top)
         let lum_width = 40.0;
         let lum_height = 12.0;
@@ -3489,8 +3125,10 @@ impl ConeDiagram {
 
 impl IsoluxDiagram {
     /// Generate complete SVG string for the isolux ground footprint diagram
+    ///
+    /// Labels use the theme's units.
     pub fn to_svg(&self, width: f64, height: f64, theme: &SvgTheme) -> String {
-        self.to_svg_with_units(width, height, theme, UnitSystem::default())
+        self.to_svg_with_units(width, height, theme, theme.units)
     }
 
     /// Generate SVG with unit system for labels (lx/fc, m/ft).
@@ -3694,14 +3332,13 @@ impl IsoluxDiagram {
         svg
     }
 
-    /// Generate SVG with AEC-style discrete colored contour bands.
+    /// Generate SVG in the style of luminaire datasheets (DIALux, Relux).
     ///
-    /// Instead of a continuous heatmap, renders filled contour zones in distinct
-    /// colors (red → orange → yellow → green → cyan → purple) with a stepped
-    /// legend showing percentage and lux values — matching common AEC photometric
-    /// data sheets.
-    #[allow(clippy::needless_range_loop)]
-    pub fn to_svg_aec(
+    /// Contour lines only, each labelled with its level along the line,
+    /// plus a scale bar, the luminaire position with its C0 direction and
+    /// the list of levels next to the plot. Uses the same ground coordinates
+    /// as [`Self::to_svg_with_units`].
+    pub fn to_svg_datasheet(
         &self,
         width: f64,
         height: f64,
@@ -3712,33 +3349,205 @@ impl IsoluxDiagram {
         let margin_top = self.margin_top;
         let plot_width = self.plot_width;
         let plot_height = self.plot_height;
+        let dist_label = units.distance_label();
+        let illu_label = units.illuminance_label();
 
         let mut svg = String::new();
-
-        // SVG header
         svg.push_str(&format!(
             r#"<svg viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg">"#
         ));
-
-        // Background
         svg.push_str(&format!(
             r#"<rect x="0" y="0" width="{width}" height="{height}" fill="{}"/>"#,
             theme.background
         ));
 
-        // AEC contour band definitions: (percentage of max, color, label)
-        let bands: &[(f64, &str, &str)] = &[
-            (0.50, "rgba(220,50,50,0.55)", "50.0 %"),
-            (0.30, "rgba(240,140,40,0.55)", "30.0 %"),
-            (0.10, "rgba(240,220,60,0.55)", "10.0 %"),
-            (0.06, "rgba(80,200,80,0.55)", "6.0 %"),
-            (0.03, "rgba(80,180,220,0.55)", "3.0 %"),
-            (0.01, "rgba(160,80,200,0.45)", "1.0 %"),
-        ];
-
-        // Build lux grid from cells
-        let n = self.params.grid_resolution;
-        let hw = self.params.area_half_width;
+        // Title
+        let h_display = units.convert_meters(self.params.mounting_height);
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="22" text-anchor="middle" font-size="14" font-weight="bold" fill="{}" font-family="{}">Isolux Diagram (h={h_display:.1}{dist_label}, tilt={:.0}°)</text>"#,
+            width / 2.0,
+            theme.text,
+            theme.font_family,
+            self.params.tilt_angle
+        ));
+
+        // Plot surface and grid at the tick positions
+        svg.push_str(&format!(
+            r#"<rect x="{margin_left}" y="{margin_top}" width="{plot_width}" height="{plot_height}" fill="{}"/>"#,
+            theme.surface
+        ));
+        let hw = self.params.area_half_width;
+        let hd = self.params.area_half_depth;
+        let tick_positions = [-1.0, -0.5, 0.0, 0.5, 1.0];
+        for &frac in &tick_positions {
+            let x = margin_left + plot_width * ((frac + 1.0) / 2.0);
+            let y = margin_top + plot_height * ((1.0 - frac) / 2.0);
+            svg.push_str(&format!(
+                r#"<line x1="{x:.1}" y1="{margin_top:.1}" x2="{x:.1}" y2="{:.1}" stroke="{}" stroke-width="0.5"/>"#,
+                margin_top + plot_height,
+                theme.grid
+            ));
+            svg.push_str(&format!(
+                r#"<line x1="{margin_left:.1}" y1="{y:.1}" x2="{:.1}" y2="{y:.1}" stroke="{}" stroke-width="0.5"/>"#,
+                margin_left + plot_width,
+                theme.grid
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{x:.1}" y="{:.1}" text-anchor="middle" font-size="10" fill="{}" font-family="{}">{:.0}{dist_label}</text>"#,
+                margin_top + plot_height + 16.0,
+                theme.text_secondary,
+                theme.font_family,
+                units.convert_meters(hw * frac)
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{y:.1}" text-anchor="end" dominant-baseline="middle" font-size="10" fill="{}" font-family="{}">{:.0}{dist_label}</text>"#,
+                margin_left - 6.0,
+                theme.text_secondary,
+                theme.font_family,
+                units.convert_meters(hd * frac)
+            ));
+        }
+
+        // Contour lines, labelled halfway along their longest segment
+        for contour in &self.contours {
+            for path in &contour.paths {
+                svg.push_str(&format!(
+                    r#"<path d="{path}" fill="none" stroke="{}" stroke-width="1.2"/>"#,
+                    theme.curve_c0_c180
+                ));
+            }
+            let longest = contour
+                .paths
+                .iter()
+                .map(|p| path_points(p))
+                .max_by_key(|points| points.len());
+            if let Some((x, y)) = longest.and_then(|points| points.get(points.len() / 2).copied()) {
+                svg.push_str(&format!(
+                    r#"<text x="{x:.1}" y="{y:.1}" text-anchor="middle" dominant-baseline="middle" font-size="9" fill="{}" font-family="{}" paint-order="stroke" stroke="{}" stroke-width="3">{}</text>"#,
+                    theme.text,
+                    theme.font_family,
+                    theme.surface,
+                    fmt_lux(units.convert_lux(contour.lux_value))
+                ));
+            }
+        }
+
+        // Luminaire position and C0 direction (+X)
+        let cx = margin_left + plot_width / 2.0;
+        let cy = margin_top + plot_height / 2.0;
+        svg.push_str(&format!(
+            r#"<line x1="{cx:.1}" y1="{cy:.1}" x2="{:.1}" y2="{cy:.1}" stroke="{}" stroke-width="1.5"/>"#,
+            cx + 14.0,
+            theme.text
+        ));
+        svg.push_str(&format!(
+            r#"<circle cx="{cx:.1}" cy="{cy:.1}" r="4" fill="{}" stroke="{}" stroke-width="1.5"/>"#,
+            theme.background, theme.text
+        ));
+
+        svg.push_str(&format!(
+            r#"<rect x="{margin_left}" y="{margin_top}" width="{plot_width}" height="{plot_height}" fill="none" stroke="{}" stroke-width="1"/>"#,
+            theme.axis
+        ));
+
+        // Scale bar, about a quarter of the plot width in round display units
+        let meters_per_px = 2.0 * hw / plot_width;
+        let unit_per_meter = units.convert_meters(1.0);
+        let bar_length = DiagramScale::nice_step(2.0 * hw * unit_per_meter, 4);
+        let bar_px = bar_length / unit_per_meter / meters_per_px;
+        let bar_y = margin_top + plot_height + 34.0;
+        svg.push_str(&format!(
+            r#"<path d="M {margin_left:.1} {:.1} V {bar_y:.1} H {:.1} V {:.1}" fill="none" stroke="{}" stroke-width="1.5"/>"#,
+            bar_y - 4.0,
+            margin_left + bar_px,
+            bar_y - 4.0,
+            theme.text
+        ));
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" font-size="10" fill="{}" font-family="{}">{} {dist_label}</text>"#,
+            margin_left + bar_px + 6.0,
+            bar_y,
+            theme.text_secondary,
+            theme.font_family,
+            bar_length
+        ));
+
+        // Level list
+        let legend_x = margin_left + plot_width + 10.0;
+        svg.push_str(&format!(
+            r#"<text x="{legend_x:.1}" y="{:.1}" font-size="10" font-weight="bold" fill="{}" font-family="{}">{illu_label}</text>"#,
+            margin_top + 10.0,
+            theme.text,
+            theme.font_family
+        ));
+        for (i, contour) in self.contours.iter().rev().enumerate() {
+            svg.push_str(&format!(
+                r#"<text x="{legend_x:.1}" y="{:.1}" font-size="10" fill="{}" font-family="{}">{}</text>"#,
+                margin_top + 26.0 + i as f64 * 14.0,
+                theme.text_secondary,
+                theme.font_family,
+                fmt_lux(units.convert_lux(contour.lux_value))
+            ));
+        }
+
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="10" fill="{}" font-family="{}">E_max = {} {illu_label}</text>"#,
+            margin_left + plot_width,
+            bar_y,
+            theme.text_secondary,
+            theme.font_family,
+            fmt_lux(units.convert_lux(self.max_lux))
+        ));
+
+        svg.push_str("</svg>");
+        svg
+    }
+
+    /// Generate SVG with AEC-style discrete colored contour bands.
+    ///
+    /// Instead of a continuous heatmap, renders filled contour zones in distinct
+    /// colors (red → orange → yellow → green → cyan → purple) with a stepped
+    /// legend showing percentage and lux values — matching common AEC photometric
+    /// data sheets.
+    #[allow(clippy::needless_range_loop)]
+    pub fn to_svg_aec(
+        &self,
+        width: f64,
+        height: f64,
+        theme: &SvgTheme,
+        units: UnitSystem,
+    ) -> String {
+        let margin_left = self.margin_left;
+        let margin_top = self.margin_top;
+        let plot_width = self.plot_width;
+        let plot_height = self.plot_height;
+
+        let mut svg = String::new();
+
+        // SVG header
+        svg.push_str(&format!(
+            r#"<svg viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg">"#
+        ));
+
+        // Background
+        svg.push_str(&format!(
+            r#"<rect x="0" y="0" width="{width}" height="{height}" fill="{}"/>"#,
+            theme.background
+        ));
+
+        // AEC contour band definitions: (percentage of max, color, label)
+        let bands: &[(f64, &str, &str)] = &[
+            (0.50, "rgba(220,50,50,0.55)", "50.0 %"),
+            (0.30, "rgba(240,140,40,0.55)", "30.0 %"),
+            (0.10, "rgba(240,220,60,0.55)", "10.0 %"),
+            (0.06, "rgba(80,200,80,0.55)", "6.0 %"),
+            (0.03, "rgba(80,180,220,0.55)", "3.0 %"),
+            (0.01, "rgba(160,80,200,0.45)", "1.0 %"),
+        ];
+
+        // Build lux grid from cells
+        let n = self.params.grid_resolution;
+        let hw = self.params.area_half_width;
         let hd = self.params.area_half_depth;
         let mut lux_grid: Vec<Vec<f64>> = vec![vec![0.0; n]; n];
         for cell in &self.cells {
@@ -4355,13 +4164,26 @@ impl IsocandelaDiagram {
         ));
 
         // Title
+        let title = match self.projection {
+            IsocandelaProjection::Rectangular => "Isocandela Contour Plot",
+            IsocandelaProjection::Sinusoidal => "Isocandela Diagram (Sinusoidal Projection)",
+            IsocandelaProjection::Azimuthal => "Isocandela Diagram (Azimuthal Projection)",
+        };
         svg.push_str(&format!(
-            r#"<text x="{:.1}" y="22" text-anchor="middle" font-size="14" font-weight="bold" fill="{}" font-family="{}">Isocandela Contour Plot</text>"#,
+            r#"<text x="{:.1}" y="22" text-anchor="middle" font-size="14" font-weight="bold" fill="{}" font-family="{}">{title}</text>"#,
             width / 2.0,
             theme.text,
             theme.font_family
         ));
 
+        // Sphere projections clip the heatmap and contours to the map outline
+        let outline = self.outline_path();
+        if let Some(outline) = &outline {
+            svg.push_str(&format!(
+                r#"<defs><clipPath id="isocandela-map"><path d="{outline}"/></clipPath></defs><g clip-path="url(#isocandela-map)">"#
+            ));
+        }
+
         // Heatmap cells
         for cell in &self.cells {
             svg.push_str(&format!(
@@ -4391,28 +4213,100 @@ impl IsocandelaDiagram {
                     path, color
                 ));
             }
-            // Label
-            if let Some(first_path) = contour.paths.first() {
-                if let Some(coords) = first_path.strip_prefix("M ") {
-                    if let Some(space_idx) = coords.find(' ') {
-                        let x_str = &coords[..space_idx];
-                        if let Ok(x) = x_str.parse::<f64>() {
-                            let y_str = coords[space_idx + 1..].split(' ').next().unwrap_or("0");
-                            if let Ok(y) = y_str.parse::<f64>() {
-                                svg.push_str(&format!(
-                                    r#"<text x="{:.1}" y="{:.1}" font-size="9" fill="white" font-family="{}" font-weight="bold" paint-order="stroke" stroke="{}" stroke-width="2">{}</text>"#,
-                                    x + 3.0, y - 3.0,
-                                    theme.font_family,
-                                    "rgba(0,0,0,0.5)",
-                                    contour.label
-                                ));
-                            }
-                        }
-                    }
-                }
+            // Label at the first contour point on the map
+            let anchor = contour
+                .paths
+                .iter()
+                .flat_map(|path| path_points(path))
+                .find(|&(x, y)| self.contains(x, y));
+            if let Some((x, y)) = anchor {
+                svg.push_str(&format!(
+                    r#"<text x="{:.1}" y="{:.1}" font-size="9" fill="white" font-family="{}" font-weight="bold" paint-order="stroke" stroke="{}" stroke-width="2">{}</text>"#,
+                    x + 3.0, y - 3.0,
+                    theme.font_family,
+                    "rgba(0,0,0,0.5)",
+                    contour.label
+                ));
+            }
+        }
+        if outline.is_some() {
+            svg.push_str("</g>");
+        }
+
+        match self.projection {
+            IsocandelaProjection::Rectangular => {
+                self.push_rectangular_axes(&mut svg, height, theme)
             }
+            IsocandelaProjection::Sinusoidal => self.push_sinusoidal_graticule(&mut svg, theme),
+            IsocandelaProjection::Azimuthal => self.push_azimuthal_graticule(&mut svg, theme),
+        }
+
+        // Color legend
+        let legend_x = margin_left + plot_width + 10.0;
+        let legend_h = plot_height;
+        let num_segments = 50;
+        let seg_h = legend_h / num_segments as f64;
+        for i in 0..num_segments {
+            let normalized = 1.0 - i as f64 / num_segments as f64;
+            let color = super::color::heatmap_color(normalized);
+            let y = margin_top + i as f64 * seg_h;
+            svg.push_str(&format!(
+                r#"<rect x="{legend_x:.1}" y="{y:.1}" width="15" height="{seg_h:.1}" fill="{}"/>"#,
+                color.to_rgb_string()
+            ));
+        }
+        // Legend labels
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" font-size="9" fill="{}" font-family="{}" dominant-baseline="middle">{:.0}</text>"#,
+            legend_x + 20.0, margin_top,
+            theme.text_secondary, theme.font_family,
+            self.i_max
+        ));
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" font-size="9" fill="{}" font-family="{}" dominant-baseline="middle">cd/klm</text>"#,
+            legend_x + 20.0, margin_top + 12.0,
+            theme.text_secondary, theme.font_family
+        ));
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" font-size="9" fill="{}" font-family="{}" dominant-baseline="middle">0</text>"#,
+            legend_x + 20.0, margin_top + legend_h,
+            theme.text_secondary, theme.font_family
+        ));
+
+        // Border
+        if let Some(outline) = &outline {
+            svg.push_str(&format!(
+                r#"<path d="{outline}" fill="none" stroke="{}" stroke-width="1"/>"#,
+                theme.axis
+            ));
+        } else {
+            svg.push_str(&format!(
+                r#"<rect x="{margin_left}" y="{margin_top}" width="{plot_width}" height="{plot_height}" fill="none" stroke="{}" stroke-width="1"/>"#,
+                theme.axis
+            ));
         }
 
+        // I_max annotation
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="10" fill="{}" font-family="{}">I_max = {:.0} cd/klm</text>"#,
+            margin_left + plot_width,
+            margin_top + plot_height + 38.0,
+            theme.text_secondary,
+            theme.font_family,
+            self.i_max
+        ));
+
+        svg.push_str("</svg>");
+        svg
+    }
+
+    /// Linear H/V grid, tick labels and axis titles.
+    fn push_rectangular_axes(&self, svg: &mut String, height: f64, theme: &SvgTheme) {
+        let margin_left = self.margin_left;
+        let margin_top = self.margin_top;
+        let plot_width = self.plot_width;
+        let plot_height = self.plot_height;
+
         // Axis grid lines and labels
         let h_ticks = [-90.0, -60.0, -30.0, 0.0, 30.0, 60.0, 90.0];
         let v_ticks = [-90.0, -60.0, -30.0, 0.0, 30.0, 60.0, 90.0];
@@ -4460,53 +4354,1034 @@ impl IsocandelaDiagram {
             theme.font_family,
             margin_top + plot_height / 2.0
         ));
+    }
 
-        // Color legend
-        let legend_x = margin_left + plot_width + 10.0;
-        let legend_h = plot_height;
-        let num_segments = 50;
-        let seg_h = legend_h / num_segments as f64;
-        for i in 0..num_segments {
-            let normalized = 1.0 - i as f64 / num_segments as f64;
-            let color = super::color::heatmap_color(normalized);
-            let y = margin_top + i as f64 * seg_h;
+    /// Outline of the projected map, `None` for the rectangular plot.
+    fn outline_path(&self) -> Option<String> {
+        match self.projection {
+            IsocandelaProjection::Rectangular => None,
+            IsocandelaProjection::Sinusoidal => {
+                // Down the H = -90° meridian, up the H = +90° meridian
+                let mut points: Vec<(f64, f64)> = (-18..=18)
+                    .rev()
+                    .map(|i| self.sinusoidal_point(-90.0, i as f64 * 5.0))
+                    .collect();
+                points.extend((-18..=18).map(|i| self.sinusoidal_point(90.0, i as f64 * 5.0)));
+                Some(polyline_path(&points, true))
+            }
+            IsocandelaProjection::Azimuthal => {
+                let (cx, cy, r) = self.map_circle();
+                Some(format!(
+                    "M {:.1} {cy:.1} A {r:.1} {r:.1} 0 1 0 {:.1} {cy:.1} A {r:.1} {r:.1} 0 1 0 {:.1} {cy:.1} Z",
+                    cx - r,
+                    cx + r,
+                    cx - r
+                ))
+            }
+        }
+    }
+
+    /// Parallels and meridians every 30° with H/V labels.
+    fn push_sinusoidal_graticule(&self, svg: &mut String, theme: &SvgTheme) {
+        let grid_style = r#"fill="none" stroke="rgba(255,255,255,0.25)" stroke-width="0.5""#;
+        let (_, cy, radius) = self.map_circle();
+
+        for v in [-60.0, -30.0, 0.0, 30.0, 60.0] {
+            let (x1, y) = self.sinusoidal_point(-90.0, v);
+            let (x2, _) = self.sinusoidal_point(90.0, v);
+            svg.push_str(&format!(
+                r#"<line x1="{x1:.1}" y1="{y:.1}" x2="{x2:.1}" y2="{y:.1}" {grid_style}/>"#
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{y:.1}" text-anchor="end" dominant-baseline="middle" font-size="10" fill="{}" font-family="{}">{v:.0}°</text>"#,
+                x1 - 6.0,
+                theme.text_secondary,
+                theme.font_family
+            ));
+        }
+        for h in [-60.0, -30.0, 0.0, 30.0, 60.0] {
+            let points: Vec<(f64, f64)> = (-18..=18)
+                .map(|i| self.sinusoidal_point(h, i as f64 * 5.0))
+                .collect();
+            svg.push_str(&format!(
+                r#"<path d="{}" {grid_style}/>"#,
+                polyline_path(&points, false)
+            ));
+        }
+        for h in [-90.0, -60.0, -30.0, 0.0, 30.0, 60.0, 90.0] {
+            let (x, _) = self.sinusoidal_point(h, 0.0);
+            svg.push_str(&format!(
+                r#"<text x="{x:.1}" y="{:.1}" text-anchor="middle" font-size="9" fill="white" font-family="{}" paint-order="stroke" stroke="rgba(0,0,0,0.5)" stroke-width="2">{h:.0}°</text>"#,
+                cy + 12.0,
+                theme.font_family
+            ));
+        }
+
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="12" fill="{}" font-family="{}">H (°) along the equator, V (°) at the left</text>"#,
+            self.margin_left + self.plot_width / 2.0,
+            cy + radius + 20.0,
+            theme.text,
+            theme.font_family
+        ));
+    }
+
+    /// Rings every 30° γ and C-plane rays every 30° with labels.
+    fn push_azimuthal_graticule(&self, svg: &mut String, theme: &SvgTheme) {
+        let grid_style = r#"fill="none" stroke="rgba(255,255,255,0.25)" stroke-width="0.5""#;
+        let (cx, cy, radius) = self.map_circle();
+
+        let mut g = 30.0;
+        while g < self.gamma_max - 1e-6 {
+            let r = g / self.gamma_max * radius;
+            svg.push_str(&format!(
+                r#"<circle cx="{cx:.1}" cy="{cy:.1}" r="{r:.1}" {grid_style}/>"#
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{:.1}" font-size="9" fill="white" font-family="{}" paint-order="stroke" stroke="rgba(0,0,0,0.5)" stroke-width="2">{g:.0}°</text>"#,
+                cx + r * std::f64::consts::FRAC_1_SQRT_2 + 2.0,
+                cy - r * std::f64::consts::FRAC_1_SQRT_2 - 2.0,
+                theme.font_family
+            ));
+            g += 30.0;
+        }
+        for i in 0..12 {
+            let c = i as f64 * 30.0;
+            let (x, y) = self.azimuthal_point(c, self.gamma_max);
+            svg.push_str(&format!(
+                r#"<line x1="{cx:.1}" y1="{cy:.1}" x2="{x:.1}" y2="{y:.1}" {grid_style}/>"#
+            ));
+            let (sin_c, cos_c) = c.to_radians().sin_cos();
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" dominant-baseline="middle" font-size="10" fill="{}" font-family="{}">C{c:.0}</text>"#,
+                cx + (radius + 16.0) * cos_c,
+                cy - (radius + 16.0) * sin_c,
+                theme.text_secondary,
+                theme.font_family
+            ));
+        }
+
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="12" fill="{}" font-family="{}">γ from nadir (rings), C-plane (rays)</text>"#,
+            self.margin_left + self.plot_width / 2.0,
+            cy + radius + 34.0,
+            theme.text,
+            theme.font_family
+        ));
+    }
+}
+
+/// SVG path through `points`, optionally closed.
+fn polyline_path(points: &[(f64, f64)], close: bool) -> String {
+    let mut path = String::new();
+    for (i, (x, y)) in points.iter().enumerate() {
+        let cmd = if i == 0 { "M" } else { "L" };
+        path.push_str(&format!("{cmd} {x:.1} {y:.1} "));
+    }
+    if close {
+        path.push('Z');
+    }
+    path.trim_end().to_string()
+}
+
+impl IntensityTable {
+    /// Generate SVG pages of the given size
+    ///
+    /// Each page holds as many C-planes and γ rows as fit; pages run down
+    /// the rows of a block of C-planes before moving to the next block.
+    pub fn to_svg_pages(&self, width: f64, height: f64, theme: &SvgTheme) -> Vec<String> {
+        let margin = 20.0;
+        let label_width = 50.0;
+        let top = 40.0;
+        let cell_w = Self::CELL_WIDTH;
+        let row_h = Self::ROW_HEIGHT;
+        let columns = ((width - 2.0 * margin - label_width) / cell_w)
+            .floor()
+            .max(1.0) as usize;
+        let rows = ((height - top - row_h - 30.0) / row_h).floor().max(1.0) as usize;
+
+        let pages = self.pages(columns, rows);
+        let page_count = pages.len();
+        let mut svgs = Vec::with_capacity(page_count.max(1));
+        for (index, (c_range, g_range)) in pages.into_iter().enumerate() {
+            let mut svg = String::new();
+            svg.push_str(&format!(
+                r#"<svg viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg">"#
+            ));
+            svg.push_str(&format!(
+                r#"<rect x="0" y="0" width="{width}" height="{height}" fill="{}"/>"#,
+                theme.background
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{margin}" y="24" font-size="14" font-weight="bold" fill="{}" font-family="{}">Luminous intensity (cd/klm)</text>"#,
+                theme.text,
+                theme.font_family
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="24" text-anchor="end" font-size="10" fill="{}" font-family="{}">{}/{page_count}</text>"#,
+                width - margin,
+                theme.text_secondary,
+                theme.font_family,
+                index + 1
+            ));
+
+            // Header row and column
+            let table_width = label_width + cell_w * c_range.len() as f64;
+            let table_height = row_h * (g_range.len() + 1) as f64;
+            svg.push_str(&format!(
+                r#"<rect x="{margin}" y="{top}" width="{table_width:.1}" height="{row_h}" fill="{}"/>"#,
+                theme.surface
+            ));
+            svg.push_str(&format!(
+                r#"<rect x="{margin}" y="{top}" width="{label_width}" height="{table_height:.1}" fill="{}"/>"#,
+                theme.surface
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="9" font-weight="bold" fill="{}" font-family="{}" dominant-baseline="middle">γ \ C</text>"#,
+                margin + label_width / 2.0,
+                top + row_h / 2.0,
+                theme.text,
+                theme.font_family
+            ));
+            for (i, c) in c_range.clone().enumerate() {
+                svg.push_str(&format!(
+                    r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="9" font-weight="bold" fill="{}" font-family="{}" dominant-baseline="middle">{}°</text>"#,
+                    margin + label_width + (i as f64 + 0.5) * cell_w,
+                    top + row_h / 2.0,
+                    theme.text,
+                    theme.font_family,
+                    self.c_angles[c]
+                ));
+            }
+
+            // Value cells
+            for (j, g) in g_range.clone().enumerate() {
+                let y = top + (j + 1) as f64 * row_h;
+                svg.push_str(&format!(
+                    r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="9" font-weight="bold" fill="{}" font-family="{}" dominant-baseline="middle">{}°</text>"#,
+                    margin + label_width / 2.0,
+                    y + row_h / 2.0,
+                    theme.text,
+                    theme.font_family,
+                    self.g_angles[g]
+                ));
+                for (i, c) in c_range.clone().enumerate() {
+                    let x = margin + label_width + i as f64 * cell_w;
+                    let value = self.values[c][g];
+                    let (color, text) = self.cell_colors(value);
+                    svg.push_str(&format!(
+                        r#"<rect x="{x:.1}" y="{y:.1}" width="{cell_w}" height="{row_h}" fill="{}"/>"#,
+                        color.to_hex_string()
+                    ));
+                    svg.push_str(&format!(
+                        r#"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="9" fill="{text}" font-family="{}" dominant-baseline="middle">{}</text>"#,
+                        x + cell_w - 4.0,
+                        y + row_h / 2.0,
+                        theme.font_family,
+                        Self::format_value(value)
+                    ));
+                }
+            }
+            svg.push_str(&format!(
+                r#"<rect x="{margin}" y="{top}" width="{table_width:.1}" height="{table_height:.1}" fill="none" stroke="{}"/>"#,
+                theme.axis
+            ));
+
+            svg.push_str(&format!(
+                r#"<text x="{margin}" y="{:.1}" font-size="10" fill="{}" font-family="{}">I max = {} cd/klm</text>"#,
+                height - 12.0,
+                theme.text_secondary,
+                theme.font_family,
+                Self::format_value(self.max_value)
+            ));
+            svg.push_str("</svg>");
+            svgs.push(svg);
+        }
+
+        if svgs.is_empty() {
+            svgs.push(format!(
+                r#"<svg viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg"><rect x="0" y="0" width="{width}" height="{height}" fill="{}"/><text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="14" fill="{}" font-family="{}">{}</text></svg>"#,
+                theme.background,
+                width / 2.0,
+                height / 2.0,
+                theme.text_secondary,
+                theme.font_family,
+                theme.labels.no_data
+            ));
+        }
+        svgs
+    }
+}
+
+impl DatasheetComposer {
+    /// Generate the datasheet as one A4 SVG
+    ///
+    /// Each panel is rendered at its cell size and nested as its own `<svg>`,
+    /// below a title block with the luminaire name and manufacturer.
+    pub fn to_svg(&self, ldt: &crate::Eulumdat, theme: &SvgTheme) -> String {
+        let (width, height) = (self.page_width, self.page_height);
+        let mut svg = format!(
+            r#"<svg width="{:.0}mm" height="{:.0}mm" viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg">
+<rect width="{width}" height="{height}" fill="{}"/>
+"#,
+            width * 25.4 / 96.0,
+            height * 25.4 / 96.0,
+            theme.background
+        );
+
+        // Title block
+        let title = Self::luminaire_title(ldt);
+        let subtitle = [ldt.identification.trim(), ldt.luminaire_number.trim()]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(" · ");
+        let branding = &self.branding;
+        // With a company name the document title moves to the second line
+        let (heading, note) = if branding.company.trim().is_empty() {
+            (
+                branding.title_or_default(),
+                ldt.date_user.trim().to_string(),
+            )
+        } else {
+            (
+                branding.company.trim(),
+                [branding.title_or_default(), ldt.date_user.trim()]
+                    .into_iter()
+                    .filter(|s| !s.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" · "),
+            )
+        };
+        let (rule_color, rule_width) = match branding.accent.trim() {
+            "" => (theme.axis.as_str(), 1.0),
+            accent => (accent, 2.0),
+        };
+        let baseline = self.margin + 24.0;
+        svg.push_str(&format!(
+            r#"<text x="{}" y="{baseline}" font-size="20" font-weight="bold" fill="{}" font-family="{}">{}</text>
+<text x="{}" y="{}" font-size="11" fill="{}" font-family="{}">{}</text>
+<text x="{}" y="{baseline}" text-anchor="end" font-size="11" fill="{}" font-family="{}">{}</text>
+<text x="{}" y="{}" text-anchor="end" font-size="10" fill="{}" font-family="{}">{}</text>
+<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="{rule_width}"/>
+"#,
+            self.margin,
+            theme.text,
+            theme.font_family,
+            escape_text(title),
+            self.margin,
+            baseline + 18.0,
+            theme.text_secondary,
+            theme.font_family,
+            escape_text(&subtitle),
+            width - self.margin,
+            theme.text_secondary,
+            theme.font_family,
+            escape_text(heading),
+            width - self.margin,
+            baseline + 18.0,
+            theme.text_secondary,
+            theme.font_family,
+            escape_text(&note),
+            self.margin,
+            self.margin + self.header_height - 12.0,
+            width - self.margin,
+            self.margin + self.header_height - 12.0,
+            escape_text(rule_color)
+        ));
+        if !branding.footer.trim().is_empty() {
+            svg.push_str(&format!(
+                r#"<text x="{}" y="{:.1}" text-anchor="middle" font-size="9" fill="{}" font-family="{}">{}</text>
+"#,
+                width / 2.0,
+                height - self.margin / 2.0 + 3.0,
+                theme.text_secondary,
+                theme.font_family,
+                escape_text(branding.footer.trim())
+            ));
+        }
+
+        for slot in self.layout() {
+            let (w, h) = (slot.width, slot.height);
+            let panel = match slot.panel {
+                DatasheetPanel::Polar => PolarDiagram::from_eulumdat(ldt).to_svg(w, h, theme),
+                DatasheetPanel::Cartesian => {
+                    CartesianDiagram::from_eulumdat(ldt, w, h, 8).to_svg(w, h, theme)
+                }
+                DatasheetPanel::Cone => {
+                    ConeDiagram::from_eulumdat(ldt, self.mounting_height).to_svg(w, h, theme)
+                }
+                DatasheetPanel::Bug => {
+                    let bug = BugDiagram::from_eulumdat(ldt);
+                    if w > 1.2 * h {
+                        bug.to_svg_with_details(w, h, theme)
+                    } else {
+                        bug.to_svg(w, h, theme)
+                    }
+                }
+                DatasheetPanel::Summary => Self::summary_svg(ldt, w, h, theme),
+            };
+            svg.push_str(&panel.replacen(
+                "<svg ",
+                &format!(
+                    r#"<svg x="{:.1}" y="{:.1}" width="{w:.1}" height="{h:.1}" "#,
+                    slot.x, slot.y
+                ),
+                1,
+            ));
+            svg.push_str(&format!(
+                r#"
+<rect x="{:.1}" y="{:.1}" width="{w:.1}" height="{h:.1}" fill="none" stroke="{}" stroke-width="1"/>
+"#,
+                slot.x, slot.y, theme.grid
+            ));
+        }
+
+        svg.push_str("</svg>");
+        svg
+    }
+
+    /// Key photometric values as a label/value table, in two columns when
+    /// the panel is wide enough
+    fn summary_svg(ldt: &crate::Eulumdat, width: f64, height: f64, theme: &SvgTheme) -> String {
+        let summary = PhotometricSummary::from_eulumdat(ldt);
+        let rows = [
+            ("Lamp flux", format!("{:.0} lm", summary.total_lamp_flux)),
+            ("System power", format!("{:.1} W", summary.total_wattage)),
+            (
+                "Luminaire efficacy",
+                format!("{:.1} lm/W", summary.luminaire_efficacy),
+            ),
+            ("LOR", format!("{:.1} %", summary.lor)),
+            (
+                "DLOR / ULOR",
+                format!("{:.1} / {:.1} %", summary.dlor, summary.ulor),
+            ),
+            (
+                "Max intensity",
+                format!("{:.1} cd/klm", summary.max_intensity),
+            ),
+            (
+                "Beam / field angle",
+                format!("{:.1}° / {:.1}°", summary.beam_angle, summary.field_angle),
+            ),
+            ("CIE flux code", summary.cie_flux_codes.to_string()),
+            (
+                "S/H C0 / C90",
+                format!("{:.2} / {:.2}", summary.spacing_c0, summary.spacing_c90),
+            ),
+            ("BUG rating", BugRating::from_eulumdat(ldt).to_string()),
+        ];
+
+        let columns = if width > 450.0 { 2 } else { 1 };
+        let per_column = rows.len().div_ceil(columns);
+        let column_width = (width - 30.0) / columns as f64;
+        let row_height = ((height - 50.0) / per_column as f64).min(22.0);
+
+        let mut svg = format!(
+            r#"<svg viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg">
+<rect width="{width}" height="{height}" fill="{}"/>
+<text x="15" y="26" font-size="13" font-weight="bold" fill="{}" font-family="{}">Photometric summary</text>
+"#,
+            theme.background, theme.text, theme.font_family
+        );
+        for (i, (label, value)) in rows.iter().enumerate() {
+            let x = 15.0 + (i / per_column) as f64 * column_width;
+            let y = 50.0 + (i % per_column) as f64 * row_height;
+            if (i % per_column) % 2 == 0 {
+                svg.push_str(&format!(
+                    r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{row_height:.1}" fill="{}"/>
+"#,
+                    x - 5.0,
+                    y - row_height * 0.7,
+                    column_width - 10.0,
+                    theme.surface
+                ));
+            }
+            svg.push_str(&format!(
+                r#"<text x="{x:.1}" y="{y:.1}" font-size="11" fill="{}" font-family="{}">{label}</text>
+<text x="{:.1}" y="{y:.1}" text-anchor="end" font-size="11" fill="{}" font-family="{}">{}</text>
+"#,
+                theme.text_secondary,
+                theme.font_family,
+                x + column_width - 20.0,
+                theme.text,
+                theme.font_family,
+                value
+            ));
+        }
+        svg.push_str("</svg>");
+        svg
+    }
+}
+
+impl FootprintDiagram {
+    /// Generate the SVG plan view of the beam footprint
+    ///
+    /// Distances use the theme's units. The luminaire's foot point and the
+    /// aiming point are marked on the plane.
+    pub fn to_svg(&self, width: f64, height: f64, theme: &SvgTheme) -> String {
+        let units = theme.units;
+        let m = units.distance_label();
+
+        let mut svg = String::new();
+        svg.push_str(&format!(
+            r#"<svg viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg">"#
+        ));
+        svg.push_str(&format!(
+            r#"<rect x="0" y="0" width="{width}" height="{height}" fill="{}"/>"#,
+            theme.background
+        ));
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="20" text-anchor="middle" font-size="14" font-weight="bold" fill="{}" font-family="{}">Beam Footprint</text>"#,
+            width / 2.0,
+            theme.text,
+            theme.font_family
+        ));
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="36" text-anchor="middle" font-size="10" fill="{}" font-family="{}">Throw {:.1} {m}, tilt {:.0}°, {:.1} {m} from the plane</text>"#,
+            width / 2.0,
+            theme.text_secondary,
+            theme.font_family,
+            units.convert_meters(self.params.throw_distance),
+            self.params.tilt_angle,
+            units.convert_meters(self.plane_distance)
+        ));
+
+        // Plane with a grid at round distances in the display unit
+        let (left, top) = self.to_screen(self.x_min, self.y_min);
+        let (right, bottom) = self.to_screen(self.x_max, self.y_max);
+        svg.push_str(&format!(
+            r#"<rect x="{left:.1}" y="{top:.1}" width="{:.1}" height="{:.1}" fill="{}" stroke="{}"/>"#,
+            right - left,
+            bottom - top,
+            theme.surface,
+            theme.axis
+        ));
+        let per_meter = units.convert_meters(1.0);
+        let span = (self.x_max - self.x_min).max(self.y_max - self.y_min) * per_meter;
+        let step = DiagramScale::nice_step(span, 8) / per_meter;
+        let mut x = (self.x_min / step).ceil() * step;
+        while x <= self.x_max {
+            let (sx, _) = self.to_screen(x, 0.0);
+            svg.push_str(&format!(
+                r#"<line x1="{sx:.1}" y1="{top:.1}" x2="{sx:.1}" y2="{bottom:.1}" stroke="{}" stroke-width="0.5"/>"#,
+                theme.grid
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{sx:.1}" y="{:.1}" text-anchor="middle" font-size="9" fill="{}" font-family="{}">{:.0}</text>"#,
+                bottom + 13.0,
+                theme.text_secondary,
+                theme.font_family,
+                x * per_meter
+            ));
+            x += step;
+        }
+        let mut y = (self.y_min / step).ceil() * step;
+        while y <= self.y_max {
+            let (_, sy) = self.to_screen(0.0, y);
+            svg.push_str(&format!(
+                r#"<line x1="{left:.1}" y1="{sy:.1}" x2="{right:.1}" y2="{sy:.1}" stroke="{}" stroke-width="0.5"/>"#,
+                theme.grid
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{sy:.1}" text-anchor="end" font-size="9" fill="{}" font-family="{}" dominant-baseline="middle">{:.0}</text>"#,
+                left - 5.0,
+                theme.text_secondary,
+                theme.font_family,
+                y * per_meter
+            ));
+            y += step;
+        }
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="10" fill="{}" font-family="{}">Along aiming direction ({m})</text>"#,
+            (left + right) / 2.0,
+            bottom + 27.0,
+            theme.text,
+            theme.font_family
+        ));
+
+        // Outlines: field dashed behind the beam
+        for contour in self.contours.iter().rev() {
+            let (color, dash) = if contour.fraction >= 0.5 {
+                (&theme.curve_c0_c180, "")
+            } else {
+                (&theme.curve_c90_c270, r#" stroke-dasharray="6,4""#)
+            };
+            for path in &contour.paths {
+                svg.push_str(&format!(
+                    r#"<path d="{path}" fill="none" stroke="{color}" stroke-width="2"{dash}/>"#
+                ));
+            }
+        }
+
+        // Foot point of the luminaire, aiming line and aiming point
+        let (fx, fy) = self.to_screen(0.0, 0.0);
+        let (ax, ay) = self.to_screen(self.aim_x, 0.0);
+        svg.push_str(&format!(
+            r#"<line x1="{fx:.1}" y1="{fy:.1}" x2="{ax:.1}" y2="{ay:.1}" stroke="{}" stroke-width="1" stroke-dasharray="3,3"/>"#,
+            theme.text_secondary
+        ));
+        svg.push_str(&format!(
+            r#"<circle cx="{fx:.1}" cy="{fy:.1}" r="4" fill="{}" stroke="{}" stroke-width="1.5"/>"#,
+            theme.background, theme.text
+        ));
+        svg.push_str(&format!(
+            r#"<path d="M {:.1} {ay:.1} L {:.1} {ay:.1} M {ax:.1} {:.1} L {ax:.1} {:.1}" stroke="{}" stroke-width="1.5"/>"#,
+            ax - 6.0,
+            ax + 6.0,
+            ay - 6.0,
+            ay + 6.0,
+            theme.text
+        ));
+
+        // Legend with the footprint sizes
+        let mut ly = height - 30.0;
+        for contour in &self.contours {
+            let (color, dash) = if contour.fraction >= 0.5 {
+                (&theme.curve_c0_c180, "")
+            } else {
+                (&theme.curve_c90_c270, r#" stroke-dasharray="6,4""#)
+            };
+            svg.push_str(&format!(
+                r#"<line x1="{:.1}" y1="{ly:.1}" x2="{:.1}" y2="{ly:.1}" stroke="{color}" stroke-width="2"{dash}/>"#,
+                width / 2.0 - 120.0,
+                width / 2.0 - 96.0
+            ));
+            let size = if contour.paths.is_empty() {
+                "outside the plane".to_string()
+            } else {
+                format!(
+                    "{:.1} × {:.1} {m}",
+                    units.convert_meters(contour.length),
+                    units.convert_meters(contour.width)
+                )
+            };
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{ly:.1}" font-size="10" fill="{}" font-family="{}" dominant-baseline="middle">{}: {size}</text>"#,
+                width / 2.0 - 90.0,
+                theme.text,
+                theme.font_family,
+                contour.label
+            ));
+            ly += 14.0;
+        }
+
+        svg.push_str("</svg>");
+        svg
+    }
+}
+
+impl GlareDiagram {
+    /// Generate the SVG quarter polar plot of luminance versus γ
+    ///
+    /// Nadir points down and the horizontal to the right. The zone above the
+    /// EN 12464-1 limits from 65° to 90° is shaded, the limits are dashed.
+    pub fn to_svg(&self, width: f64, height: f64, theme: &SvgTheme) -> String {
+        let mut svg = String::new();
+        svg.push_str(&format!(
+            r#"<svg viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg">"#
+        ));
+        svg.push_str(&format!(
+            r#"<rect x="0" y="0" width="{width}" height="{height}" fill="{}"/>"#,
+            theme.background
+        ));
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="20" text-anchor="middle" font-size="14" font-weight="bold" fill="{}" font-family="{}">Luminance (EN 12464-1)</text>"#,
+            width / 2.0,
+            theme.text,
+            theme.font_family
+        ));
+
+        if self.is_empty() {
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="14" fill="{}" font-family="{}">{}</text>"#,
+                width / 2.0,
+                height / 2.0,
+                theme.text_secondary,
+                theme.font_family,
+                theme.labels.no_data
+            ));
+            svg.push_str("</svg>");
+            return svg;
+        }
+
+        let cx = 55.0;
+        let cy = 45.0;
+        let radius = (width - cx - 35.0).min(height - cy - 35.0).max(10.0);
+        let point = |gamma: f64, r: f64| {
+            let (sin, cos) = gamma.to_radians().sin_cos();
+            (cx + r * sin, cy + r * cos)
+        };
+        let arc = |from: f64, to: f64, r: f64| {
+            let points: Vec<(f64, f64)> = (0..=30)
+                .map(|i| point(from + (to - from) * i as f64 / 30.0, r))
+                .collect();
+            polyline_path(&points, false)
+        };
+        let start = Self::LIMIT_START_GAMMA;
+
+        // Zone above the lowest limit, where luminance is not allowed
+        if let Some(&lowest) = self.limits.iter().min_by(|a, b| a.total_cmp(b)) {
+            let r_limit = self.radius_of(lowest, radius);
+            let mut zone: Vec<(f64, f64)> = (0..=30)
+                .map(|i| point(start + (90.0 - start) * i as f64 / 30.0, radius))
+                .collect();
+            zone.extend((0..=30).map(|i| point(90.0 - (90.0 - start) * i as f64 / 30.0, r_limit)));
+            svg.push_str(&format!(
+                r##"<path d="{}" fill="#ef4444" fill-opacity="0.12" stroke="none"/>"##,
+                polyline_path(&zone, true)
+            ));
+        }
+
+        // Rings with their values along the nadir axis
+        for value in self.radial_scale.ring_values(&self.scale) {
+            let r = self.radius_of(value, radius);
+            if r <= 0.0 {
+                continue;
+            }
+            svg.push_str(&format!(
+                r#"<path d="{}" fill="none" stroke="{}" stroke-width="1"/>"#,
+                arc(0.0, 90.0, r),
+                theme.grid
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="9" fill="{}" font-family="{}" dominant-baseline="middle">{}</text>"#,
+                cx - 4.0,
+                cy + r,
+                theme.text_secondary,
+                theme.font_family,
+                self.radial_scale.format_value(value)
+            ));
+        }
+
+        // Radial lines every 15°, the limit start emphasized
+        for gamma in (0..=6).map(|i| i as f64 * 15.0).chain([start]) {
+            let (x, y) = point(gamma, radius);
+            let (lx, ly) = point(gamma, radius + 12.0);
+            let (stroke, stroke_width) = if gamma == start {
+                ("#ef4444", 1.5)
+            } else {
+                (theme.axis.as_str(), 1.0)
+            };
+            svg.push_str(&format!(
+                r#"<line x1="{cx:.1}" y1="{cy:.1}" x2="{x:.1}" y2="{y:.1}" stroke="{stroke}" stroke-width="{stroke_width}"/>"#
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{lx:.1}" y="{ly:.1}" text-anchor="middle" font-size="9" fill="{}" font-family="{}" dominant-baseline="middle">{gamma:.0}°</text>"#,
+                theme.text_secondary,
+                theme.font_family
+            ));
+        }
+
+        // Limits
+        for &limit in &self.limits {
+            let r = self.radius_of(limit, radius);
+            svg.push_str(&format!(
+                r##"<path d="{}" fill="none" stroke="#ef4444" stroke-width="1.5" stroke-dasharray="6,4"/>"##,
+                arc(start, 90.0, r)
+            ));
+            let (x, y) = point(start, r);
+            svg.push_str(&format!(
+                r##"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="9" fill="#ef4444" font-family="{}">{limit:.0}</text>"##,
+                x - 3.0,
+                y - 3.0,
+                theme.font_family
+            ));
+        }
+
+        // Curves
+        let colors = [&theme.curve_c0_c180, &theme.curve_c90_c270];
+        for (curve, color) in self.curves.iter().zip(colors) {
+            let points: Vec<(f64, f64)> = curve
+                .points
+                .iter()
+                .map(|p| point(p.gamma, self.radius_of(p.luminance, radius)))
+                .collect();
+            if points.is_empty() {
+                continue;
+            }
+            svg.push_str(&format!(
+                r#"<path d="{}" fill="none" stroke="{color}" stroke-width="2"/>"#,
+                polyline_path(&points, false)
+            ));
+        }
+
+        // Legend and compliance in the free corner
+        let box_w = 170.0;
+        let box_h = 30.0 + 14.0 * (self.curves.len() + self.limits.len()) as f64;
+        let bx = cx + radius - box_w;
+        let by = cy + radius - box_h;
+        svg.push_str(&format!(
+            r#"<rect x="{bx:.1}" y="{by:.1}" width="{box_w}" height="{box_h:.1}" fill="{}" stroke="{}" rx="4"/>"#,
+            theme.legend_bg,
+            theme.grid
+        ));
+        let mut y = by + 14.0;
+        for (curve, color) in self.curves.iter().zip(colors) {
+            svg.push_str(&format!(
+                r#"<line x1="{:.1}" y1="{y:.1}" x2="{:.1}" y2="{y:.1}" stroke="{color}" stroke-width="2"/>"#,
+                bx + 8.0,
+                bx + 26.0
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{y:.1}" font-size="10" fill="{}" font-family="{}" dominant-baseline="middle">{}</text>"#,
+                bx + 32.0,
+                theme.text,
+                theme.font_family,
+                curve.label
+            ));
+            y += 14.0;
+        }
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{y:.1}" font-size="10" fill="{}" font-family="{}" dominant-baseline="middle">Max L(γ ≥ {start:.0}°) = {:.0} cd/m²</text>"#,
+            bx + 8.0,
+            theme.text,
+            theme.font_family,
+            self.max_limit_zone_luminance()
+        ));
+        y += 14.0;
+        for &limit in &self.limits {
+            let (mark, color) = if self.complies(limit) {
+                ("✓", "#22c55e")
+            } else {
+                ("✗", "#ef4444")
+            };
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{y:.1}" font-size="10" fill="{color}" font-family="{}" dominant-baseline="middle">≤ {limit:.0} cd/m² {mark}</text>"#,
+                bx + 8.0,
+                theme.font_family
+            ));
+            y += 14.0;
+        }
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="9" fill="{}" font-family="{}">cd/m²{}</text>"#,
+            width - 10.0,
+            height - 8.0,
+            theme.text_secondary,
+            theme.font_family,
+            self.radial_scale.unit_suffix()
+        ));
+
+        svg.push_str("</svg>");
+        svg
+    }
+}
+
+impl RoadDiagram {
+    /// Generate the SVG plan view of the road calculation field
+    ///
+    /// Distances and illuminance use the theme's units; luminance stays in
+    /// cd/m².
+    pub fn to_svg(&self, width: f64, height: f64, theme: &SvgTheme) -> String {
+        let layout = &self.layout;
+        let margin_left = self.margin_left;
+        let margin_top = self.margin_top;
+        let plot_width = self.plot_width;
+        let plot_height = self.plot_height;
+        let units = theme.units;
+        let m = units.distance_label();
+        let unit = match self.quantity {
+            RoadQuantity::Luminance => self.quantity.unit(),
+            RoadQuantity::Illuminance => units.illuminance_label(),
+        };
+        let fmt_value = |value: f64| match self.quantity {
+            RoadQuantity::Luminance => format!("{value:.2}"),
+            RoadQuantity::Illuminance => fmt_lux(units.convert_lux(value)),
+        };
+
+        let mut svg = String::new();
+        svg.push_str(&format!(
+            r#"<svg viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg">"#
+        ));
+        svg.push_str(&format!(
+            r#"<rect x="0" y="0" width="{width}" height="{height}" fill="{}"/>"#,
+            theme.background
+        ));
+
+        // Title
+        let title = match self.quantity {
+            RoadQuantity::Luminance => "Road Luminance (EN 13201)",
+            RoadQuantity::Illuminance => "Road Illuminance (EN 13201)",
+        };
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="20" text-anchor="middle" font-size="14" font-weight="bold" fill="{}" font-family="{}">{title}</text>"#,
+            width / 2.0,
+            theme.text,
+            theme.font_family
+        ));
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="36" text-anchor="middle" font-size="10" fill="{}" font-family="{}">{} × {:.2} {m} lanes, H = {:.1} {m}, overhang {:.1} {m}</text>"#,
+            width / 2.0,
+            theme.text_secondary,
+            theme.font_family,
+            layout.lanes,
+            units.convert_meters(layout.lane_width),
+            units.convert_meters(layout.mounting_height),
+            units.convert_meters(layout.overhang)
+        ));
+
+        // Grid cells with their values where they fit
+        let show_values = self
+            .cells
+            .first()
+            .is_some_and(|c| c.width >= 30.0 && c.height >= 14.0);
+        for cell in &self.cells {
+            svg.push_str(&format!(
+                r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="{}"/>"#,
+                cell.sx,
+                cell.sy,
+                cell.width,
+                cell.height,
+                cell.color.to_rgb_string()
+            ));
+            if show_values {
+                let text_color = if cell.color.luminance() < 0.5 {
+                    "#ffffff"
+                } else {
+                    "#1a1a1a"
+                };
+                svg.push_str(&format!(
+                    r#"<text x="{:.1}" y="{:.1}" fill="{text_color}" font-size="9" text-anchor="middle" dominant-baseline="central" font-family="monospace">{}</text>"#,
+                    cell.sx + cell.width / 2.0,
+                    cell.sy + cell.height / 2.0,
+                    fmt_value(cell.value)
+                ));
+            }
+        }
+
+        // Lane markings and road edges
+        for lane in 1..layout.lanes {
+            let (x1, y) = self.to_screen(0.0, lane as f64 * layout.lane_width);
+            svg.push_str(&format!(
+                r#"<line x1="{x1:.1}" y1="{y:.1}" x2="{:.1}" y2="{y:.1}" stroke="white" stroke-width="1.5" stroke-dasharray="8,6"/>"#,
+                x1 + plot_width
+            ));
+        }
+        svg.push_str(&format!(
+            r#"<rect x="{margin_left:.1}" y="{margin_top:.1}" width="{plot_width:.1}" height="{plot_height:.1}" fill="none" stroke="{}" stroke-width="1.5"/>"#,
+            theme.axis
+        ));
+
+        // Luminaires on the field boundary: pole at the road edge, arm to the head
+        for place in layout.luminaires() {
+            if place.x < -1e-6 || place.x > layout.pole_spacing + 1e-6 {
+                continue;
+            }
+            let edge = if place.rotation == 0.0 {
+                0.0
+            } else {
+                layout.road_width()
+            };
+            let (px, py) = self.to_screen(place.x, edge);
+            let (hx, hy) = self.to_screen(place.x, place.y);
+            svg.push_str(&format!(
+                r#"<line x1="{px:.1}" y1="{py:.1}" x2="{hx:.1}" y2="{hy:.1}" stroke="{}" stroke-width="2"/><circle cx="{px:.1}" cy="{py:.1}" r="4" fill="{}"/><circle cx="{hx:.1}" cy="{hy:.1}" r="4" fill="white" stroke="black" stroke-width="1.5"/>"#,
+                theme.axis, theme.axis
+            ));
+        }
+
+        // Pole spacing dimension below the field
+        let dim_y = margin_top + plot_height + 20.0;
+        let right = margin_left + plot_width;
+        svg.push_str(&format!(
+            r#"<g stroke="{}" stroke-width="1"><line x1="{margin_left:.1}" y1="{dim_y:.1}" x2="{right:.1}" y2="{dim_y:.1}"/><line x1="{margin_left:.1}" y1="{:.1}" x2="{margin_left:.1}" y2="{:.1}"/><line x1="{right:.1}" y1="{:.1}" x2="{right:.1}" y2="{:.1}"/></g>"#,
+            theme.text_secondary,
+            dim_y - 5.0,
+            dim_y + 5.0,
+            dim_y - 5.0,
+            dim_y + 5.0
+        ));
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="10" fill="{}" font-family="{}" paint-order="stroke" stroke="{}" stroke-width="4">S = {:.1} {m}</text>"#,
+            margin_left + plot_width / 2.0,
+            dim_y + 4.0,
+            theme.text,
+            theme.font_family,
+            theme.background,
+            units.convert_meters(layout.pole_spacing)
+        ));
+
+        // Observers, one per lane, looking along the road into the field
+        for (x_m, y_m) in layout.observers() {
+            let (_, y) = self.to_screen(0.0, y_m);
+            let tip = margin_left - 6.0;
+            svg.push_str(&format!(
+                r#"<line x1="{:.1}" y1="{y:.1}" x2="{tip:.1}" y2="{y:.1}" stroke="{}" stroke-width="1.5"/><path d="M {tip:.1} {y:.1} L {:.1} {:.1} L {:.1} {:.1} Z" fill="{}"/><circle cx="{:.1}" cy="{y:.1}" r="3" fill="{}"/>"#,
+                tip - 40.0,
+                theme.text,
+                tip - 7.0,
+                y - 4.0,
+                tip - 7.0,
+                y + 4.0,
+                theme.text,
+                tip - 44.0,
+                theme.text
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="8" fill="{}" font-family="{}">{:.0} {m}</text>"#,
+                tip - 22.0,
+                y - 6.0,
+                theme.text_secondary,
+                theme.font_family,
+                units.convert_meters(x_m)
+            ));
+        }
+
+        // Color legend
+        let legend_x = right + 14.0;
+        let num_segments = 50;
+        let seg_h = plot_height / num_segments as f64;
+        for i in 0..num_segments {
+            let color = self.color_map.color(1.0 - i as f64 / num_segments as f64);
+            svg.push_str(&format!(
+                r#"<rect x="{legend_x:.1}" y="{:.1}" width="15" height="{seg_h:.2}" fill="{}"/>"#,
+                margin_top + i as f64 * seg_h,
+                color.to_rgb_string()
+            ));
+        }
+        for (frac, value) in [(0.0, self.max_value), (1.0, 0.0)] {
             svg.push_str(&format!(
-                r#"<rect x="{legend_x:.1}" y="{y:.1}" width="15" height="{seg_h:.1}" fill="{}"/>"#,
-                color.to_rgb_string()
+                r#"<text x="{:.1}" y="{:.1}" font-size="9" fill="{}" font-family="{}" dominant-baseline="middle">{} {unit}</text>"#,
+                legend_x + 20.0,
+                margin_top + frac * plot_height,
+                theme.text_secondary,
+                theme.font_family,
+                fmt_value(value)
             ));
         }
-        // Legend labels
-        svg.push_str(&format!(
-            r#"<text x="{:.1}" y="{:.1}" font-size="9" fill="{}" font-family="{}" dominant-baseline="middle">{:.0}</text>"#,
-            legend_x + 20.0, margin_top,
-            theme.text_secondary, theme.font_family,
-            self.i_max
-        ));
-        svg.push_str(&format!(
-            r#"<text x="{:.1}" y="{:.1}" font-size="9" fill="{}" font-family="{}" dominant-baseline="middle">cd/klm</text>"#,
-            legend_x + 20.0, margin_top + 12.0,
-            theme.text_secondary, theme.font_family
-        ));
-        svg.push_str(&format!(
-            r#"<text x="{:.1}" y="{:.1}" font-size="9" fill="{}" font-family="{}" dominant-baseline="middle">0</text>"#,
-            legend_x + 20.0, margin_top + legend_h,
-            theme.text_secondary, theme.font_family
-        ));
 
-        // Border
+        // Results
+        let results = match self.quantity {
+            RoadQuantity::Luminance => format!(
+                "Lav = {} cd/m²   Uo = {:.2}   Ul = {:.2}   q0 = {:.3}",
+                fmt_value(self.avg_value),
+                self.uniformity,
+                self.longitudinal_uniformity.unwrap_or(0.0),
+                layout.q0
+            ),
+            RoadQuantity::Illuminance => format!(
+                "Eav = {} {unit}   Emin = {} {unit}   Uo = {:.2}",
+                fmt_value(self.avg_value),
+                fmt_value(self.min_value),
+                self.uniformity
+            ),
+        };
         svg.push_str(&format!(
-            r#"<rect x="{margin_left}" y="{margin_top}" width="{plot_width}" height="{plot_height}" fill="none" stroke="{}" stroke-width="1"/>"#,
-            theme.axis
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="11" fill="{}" font-family="{}">{results}</text>"#,
+            width / 2.0,
+            height - 26.0,
+            theme.text,
+            theme.font_family
         ));
-
-        // I_max annotation
         svg.push_str(&format!(
-            r#"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="10" fill="{}" font-family="{}">I_max = {:.0} cd/klm</text>"#,
-            margin_left + plot_width,
-            margin_top + plot_height + 38.0,
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="9" fill="{}" font-family="{}">Observers {:.0} {m} before the field at {:.1} {m} eye height</text>"#,
+            width / 2.0,
+            height - 10.0,
             theme.text_secondary,
             theme.font_family,
-            self.i_max
+            units.convert_meters(OBSERVER_DISTANCE),
+            units.convert_meters(OBSERVER_HEIGHT)
         ));
 
         svg.push_str("</svg>");
@@ -4748,56 +5623,7 @@ impl PolarDiagram {
             theme.background
         ));
 
-        // Grid circles
-        for (i, &value) in unified_scale.grid_values.iter().enumerate() {
-            let r = value / scale;
-            let is_major = i == unified_scale.grid_values.len() - 1;
-            let stroke_color = if is_major { &theme.axis } else { &theme.grid };
-            let stroke_width = if is_major { "1.5" } else { "1" };
-
-            svg.push_str(&format!(
-                r#"<circle cx="{center}" cy="{center}" r="{r:.1}" fill="none" stroke="{stroke_color}" stroke-width="{stroke_width}"/>"#
-            ));
-
-            svg.push_str(&format!(
-                r#"<text x="{:.1}" y="{:.1}" font-size="11" fill="{}" font-family="{}">{:.0}</text>"#,
-                center + 5.0,
-                center + r + 12.0,
-                theme.text_secondary,
-                theme.font_family,
-                value
-            ));
-        }
-
-        // Radial lines every 30°
-        for i in 0..=6 {
-            if i == 3 {
-                continue;
-            }
-            let angle_deg = i as f64 * 30.0;
-            let angle_rad = angle_deg.to_radians();
-            let x_left = center - radius * angle_rad.sin();
-            let y_left = center + radius * angle_rad.cos();
-            let x_right = center + radius * angle_rad.sin();
-            let y_right = center + radius * angle_rad.cos();
-
-            svg.push_str(&format!(
-                r#"<line x1="{center}" y1="{center}" x2="{x_left:.1}" y2="{y_left:.1}" stroke="{}" stroke-width="1"/>"#,
-                theme.grid
-            ));
-            svg.push_str(&format!(
-                r#"<line x1="{center}" y1="{center}" x2="{x_right:.1}" y2="{y_right:.1}" stroke="{}" stroke-width="1"/>"#,
-                theme.grid
-            ));
-        }
-
-        // 90° horizontal axis
-        svg.push_str(&format!(
-            r#"<line x1="{:.1}" y1="{center}" x2="{:.1}" y2="{center}" stroke="{}" stroke-width="1.5"/>"#,
-            center - radius,
-            center + radius,
-            theme.axis
-        ));
+        push_polar_overlay_grid(&mut svg, center, radius, &unified_scale, theme);
 
         // Colors: A = theme defaults (blue/red), B = green/orange
         let color_a_c0 = &theme.curve_c0_c180;
@@ -4941,6 +5767,117 @@ impl PolarDiagram {
         svg.push_str("</svg>");
         svg
     }
+
+    /// Render any number of polar diagrams overlaid on a single SVG.
+    ///
+    /// Each luminaire gets its own color from the default palette; C0-C180
+    /// is drawn solid and C90-C270 dashed. The diagrams are drawn on the
+    /// scale of the largest one, see [`PolarDiagram::from_eulumdat_multi`].
+    /// `labels` name the luminaires in the legend.
+    pub fn to_overlay_svg_multi(
+        diagrams: &[PolarDiagram],
+        labels: &[&str],
+        width: f64,
+        height: f64,
+        theme: &SvgTheme,
+    ) -> String {
+        let size = width.min(height);
+        let center = size / 2.0;
+        let margin = 60.0;
+        let radius = (size / 2.0) - margin;
+
+        let max_val = diagrams
+            .iter()
+            .map(|d| d.scale.scale_max)
+            .fold(0.0, f64::max);
+        let unified_scale = DiagramScale::from_max_intensity(max_val, 5);
+        let scale = unified_scale.scale_max / radius;
+
+        let mut svg = String::new();
+        svg.push_str(&format!(
+            r#"<svg viewBox="0 0 {size} {size}" xmlns="http://www.w3.org/2000/svg">"#
+        ));
+        svg.push_str(&format!(
+            r#"<rect x="0" y="0" width="{size}" height="{size}" fill="{}"/>"#,
+            theme.background
+        ));
+
+        push_polar_overlay_grid(&mut svg, center, radius, &unified_scale, theme);
+
+        let palette = ColorPalette::default();
+        for (i, diagram) in diagrams.iter().enumerate() {
+            let color = palette.color_at(i).to_rgb_string();
+            let path = diagram.c0_c180_curve.to_svg_path(center, center, scale);
+            if !path.is_empty() {
+                svg.push_str(&format!(
+                    r#"<path d="{path}" fill="none" stroke="{color}" stroke-width="2.5"/>"#
+                ));
+            }
+            if diagram.show_c90_c270() {
+                let path = diagram.c90_c270_curve.to_svg_path(center, center, scale);
+                if !path.is_empty() {
+                    svg.push_str(&format!(
+                        r#"<path d="{path}" fill="none" stroke="{color}" stroke-width="2.5" stroke-dasharray="6,4"/>"#
+                    ));
+                }
+            }
+        }
+
+        // Center point
+        svg.push_str(&format!(
+            r#"<circle cx="{center}" cy="{center}" r="3" fill="{}"/>"#,
+            theme.text
+        ));
+
+        // Legend: one row per luminaire, then the line styles of the planes
+        let mut entries: Vec<(String, String, &str)> = diagrams
+            .iter()
+            .enumerate()
+            .map(|(i, _)| {
+                let label = labels.get(i).copied().unwrap_or("");
+                (
+                    palette.color_at(i).to_rgb_string(),
+                    label.to_string(),
+                    "none",
+                )
+            })
+            .collect();
+        if let Some(first) = diagrams.first() {
+            entries.push((
+                theme.text.clone(),
+                first.c0_c180_curve.label.clone(),
+                "none",
+            ));
+            if diagrams.iter().any(PolarDiagram::show_c90_c270) {
+                entries.push((
+                    theme.text.clone(),
+                    first.c90_c270_curve.label.clone(),
+                    "4,2",
+                ));
+            }
+        }
+        push_overlay_legend(
+            &mut svg,
+            15.0,
+            size - entries.len() as f64 * 18.0 - 25.0,
+            170.0,
+            &entries,
+            theme,
+        );
+
+        // Unit label
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="end" font-size="11" fill="{}" font-family="{}">{}</text>"#,
+            size - 15.0,
+            size - 15.0,
+            theme.text_secondary,
+            theme.font_family,
+            theme.labels.intensity_unit
+        ));
+
+        svg.push_str("</svg>");
+        svg
+    }
 }
 
 impl CartesianDiagram {
@@ -5114,6 +6051,137 @@ impl CartesianDiagram {
         svg
     }
 
+    /// Render any number of cartesian diagrams overlaid on a single SVG.
+    ///
+    /// Each luminaire gets its own color from the default palette, and the
+    /// C-planes are told apart by line style (solid, dashed, dotted, ...)
+    /// in the order of the curves. Build the diagrams with
+    /// [`CartesianDiagram::from_eulumdat_multi`] so they share their axes.
+    /// `labels` name the luminaires in the legend.
+    pub fn to_overlay_svg_multi(
+        diagrams: &[CartesianDiagram],
+        labels: &[&str],
+        width: f64,
+        height: f64,
+        theme: &SvgTheme,
+    ) -> String {
+        const DASHES: [&str; 4] = ["none", "8,4", "2,3", "12,3,2,3"];
+
+        let Some(first) = diagrams.first() else {
+            return format!(
+                r#"<svg viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg"></svg>"#
+            );
+        };
+        let margin_left = first.margin_left;
+        let margin_top = first.margin_top;
+        let plot_width = first.plot_width;
+        let plot_height = first.plot_height;
+        let y_max = diagrams
+            .iter()
+            .map(|d| d.scale.scale_max)
+            .fold(0.0, f64::max);
+        let max_gamma = diagrams.iter().map(|d| d.max_gamma).fold(0.0, f64::max);
+
+        let mut svg = String::new();
+        svg.push_str(&format!(
+            r#"<svg viewBox="0 0 {width} {height}" xmlns="http://www.w3.org/2000/svg">"#
+        ));
+        svg.push_str(&format!(
+            r#"<rect x="0" y="0" width="{width}" height="{height}" fill="{}"/>"#,
+            theme.background
+        ));
+        svg.push_str(&format!(
+            r#"<rect x="{margin_left}" y="{margin_top}" width="{plot_width}" height="{plot_height}" fill="{}" stroke="{}" stroke-width="1"/>"#,
+            theme.surface, theme.axis
+        ));
+
+        // Grid of the diagram with the largest range
+        let widest = diagrams
+            .iter()
+            .max_by(|a, b| a.scale.scale_max.total_cmp(&b.scale.scale_max))
+            .unwrap_or(first);
+        for &v in &widest.y_ticks {
+            let y = margin_top + plot_height * (1.0 - v / y_max);
+            svg.push_str(&format!(
+                r#"<line x1="{margin_left}" y1="{y:.1}" x2="{:.1}" y2="{y:.1}" stroke="{}" stroke-width="1"/>"#,
+                margin_left + plot_width, theme.grid
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{:.1}" y="{y:.1}" text-anchor="end" dominant-baseline="middle" font-size="11" fill="{}" font-family="{}">{v:.0}</text>"#,
+                margin_left - 8.0, theme.text_secondary, theme.font_family
+            ));
+        }
+        let x_step = if max_gamma <= 90.0 { 15.0 } else { 30.0 };
+        let mut gamma = 0.0;
+        while gamma <= max_gamma {
+            let x = margin_left + plot_width * (gamma / max_gamma);
+            svg.push_str(&format!(
+                r#"<line x1="{x:.1}" y1="{margin_top}" x2="{x:.1}" y2="{:.1}" stroke="{}" stroke-width="1"/>"#,
+                margin_top + plot_height, theme.grid
+            ));
+            svg.push_str(&format!(
+                r#"<text x="{x:.1}" y="{:.1}" text-anchor="middle" font-size="11" fill="{}" font-family="{}">{gamma:.0}°</text>"#,
+                margin_top + plot_height + 18.0, theme.text_secondary, theme.font_family
+            ));
+            gamma += x_step;
+        }
+
+        let palette = ColorPalette::default();
+        for (i, diagram) in diagrams.iter().enumerate() {
+            let color = palette.color_at(i).to_rgb_string();
+            for (j, curve) in diagram.curves.iter().enumerate() {
+                let path = Self::rescale_curve_path(
+                    curve,
+                    margin_left,
+                    margin_top,
+                    plot_width,
+                    plot_height,
+                    y_max,
+                    max_gamma,
+                );
+                svg.push_str(&format!(
+                    r#"<path d="{path}" fill="none" stroke="{color}" stroke-width="2.5" stroke-dasharray="{}" stroke-linecap="round" stroke-linejoin="round"/>"#,
+                    DASHES[j % DASHES.len()]
+                ));
+            }
+        }
+
+        // Axis label
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="12" fill="{}" font-family="{}">{}</text>"#,
+            margin_left + plot_width / 2.0, height - 8.0,
+            theme.text, theme.font_family, theme.labels.gamma_axis
+        ));
+
+        // Legend: one row per luminaire, then the line style of each curve
+        let mut entries: Vec<(String, String, &str)> = (0..diagrams.len())
+            .map(|i| {
+                let label = labels.get(i).copied().unwrap_or("");
+                (
+                    palette.color_at(i).to_rgb_string(),
+                    label.to_string(),
+                    "none",
+                )
+            })
+            .collect();
+        for (j, dash) in DASHES.iter().enumerate() {
+            if let Some(curve) = diagrams.iter().find_map(|d| d.curves.get(j)) {
+                entries.push((theme.text.clone(), curve.label.clone(), dash));
+            }
+        }
+        push_overlay_legend(
+            &mut svg,
+            margin_left + 10.0,
+            margin_top + 10.0,
+            145.0,
+            &entries,
+            theme,
+        );
+
+        svg.push_str("</svg>");
+        svg
+    }
+
     /// Re-render a CartesianCurve path with a different Y scale and gamma range.
     fn rescale_curve_path(
         curve: &CartesianCurve,
@@ -5138,6 +6206,99 @@ impl CartesianDiagram {
     }
 }
 
+/// Grid circles with intensity labels, 30° rays and the horizontal axis of
+/// the polar overlays.
+fn push_polar_overlay_grid(
+    svg: &mut String,
+    center: f64,
+    radius: f64,
+    unified_scale: &DiagramScale,
+    theme: &SvgTheme,
+) {
+    let scale = unified_scale.scale_max / radius;
+
+    // Grid circles
+    for (i, &value) in unified_scale.grid_values.iter().enumerate() {
+        let r = value / scale;
+        let is_major = i == unified_scale.grid_values.len() - 1;
+        let stroke_color = if is_major { &theme.axis } else { &theme.grid };
+        let stroke_width = if is_major { "1.5" } else { "1" };
+
+        svg.push_str(&format!(
+            r#"<circle cx="{center}" cy="{center}" r="{r:.1}" fill="none" stroke="{stroke_color}" stroke-width="{stroke_width}"/>"#
+        ));
+
+        svg.push_str(&format!(
+            r#"<text x="{:.1}" y="{:.1}" font-size="11" fill="{}" font-family="{}">{:.0}</text>"#,
+            center + 5.0,
+            center + r + 12.0,
+            theme.text_secondary,
+            theme.font_family,
+            value
+        ));
+    }
+
+    // Radial lines every 30°
+    for i in 0..=6 {
+        if i == 3 {
+            continue;
+        }
+        let angle_deg = i as f64 * 30.0;
+        let angle_rad = angle_deg.to_radians();
+        let x_left = center - radius * angle_rad.sin();
+        let y_left = center + radius * angle_rad.cos();
+        let x_right = center + radius * angle_rad.sin();
+        let y_right = center + radius * angle_rad.cos();
+
+        svg.push_str(&format!(
+            r#"<line x1="{center}" y1="{center}" x2="{x_left:.1}" y2="{y_left:.1}" stroke="{}" stroke-width="1"/>"#,
+            theme.grid
+        ));
+        svg.push_str(&format!(
+            r#"<line x1="{center}" y1="{center}" x2="{x_right:.1}" y2="{y_right:.1}" stroke="{}" stroke-width="1"/>"#,
+            theme.grid
+        ));
+    }
+
+    // 90° horizontal axis
+    svg.push_str(&format!(
+        r#"<line x1="{:.1}" y1="{center}" x2="{:.1}" y2="{center}" stroke="{}" stroke-width="1.5"/>"#,
+        center - radius,
+        center + radius,
+        theme.axis
+    ));
+}
+
+/// Legend box of `(color, label, dash pattern)` rows for the overlay SVGs.
+fn push_overlay_legend(
+    svg: &mut String,
+    x: f64,
+    y: f64,
+    width: f64,
+    entries: &[(String, String, &str)],
+    theme: &SvgTheme,
+) {
+    let legend_height = entries.len() as f64 * 18.0 + 10.0;
+    svg.push_str(&format!(r#"<g transform="translate({x:.1}, {y:.1})">"#));
+    svg.push_str(&format!(
+        r#"<rect x="-5" y="-5" width="{width}" height="{legend_height:.1}" fill="{}" stroke="{}" stroke-width="1" rx="4"/>"#,
+        theme.legend_bg, theme.axis
+    ));
+    for (row, (color, label, dash)) in entries.iter().enumerate() {
+        let y = row as f64 * 18.0 + 8.0;
+        svg.push_str(&format!(
+            r#"<line x1="0" y1="{y:.1}" x2="18" y2="{y:.1}" stroke="{color}" stroke-width="2.5" stroke-dasharray="{dash}"/>"#
+        ));
+        svg.push_str(&format!(
+            r#"<text x="24" y="{:.1}" font-size="11" fill="{}" font-family="{}">{label}</text>"#,
+            y + 4.0,
+            theme.text,
+            theme.font_family
+        ));
+    }
+    svg.push_str("</g>");
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5170,6 +6331,51 @@ mod tests {
         assert!(svg.contains("cd/1000lm"));
     }
 
+    #[test]
+    fn test_multi_overlay_svg() {
+        let a = create_test_ldt();
+        let mut b = create_test_ldt();
+        for row in &mut b.intensities {
+            for v in row {
+                *v *= 2.0;
+            }
+        }
+        let c = create_test_ldt();
+        let ldts = [&a, &b, &c];
+        let labels = ["A", "B", "C"];
+
+        let polars = PolarDiagram::from_eulumdat_multi(&ldts);
+        assert_eq!(polars.len(), 3);
+        assert!(polars.iter().all(|p| p.scale == polars[1].scale));
+        let svg =
+            PolarDiagram::to_overlay_svg_multi(&polars, &labels, 500.0, 500.0, &SvgTheme::light());
+        let palette = ColorPalette::default();
+        for i in 0..3 {
+            assert!(svg.contains(&palette.color_at(i).to_rgb_string()));
+        }
+        assert!(svg.contains(">C</text>") && svg.contains(">C90-C270</text>"));
+
+        let cartesians = CartesianDiagram::from_eulumdat_multi(&ldts, 500.0, 380.0, 2);
+        assert!(cartesians
+            .iter()
+            .all(|d| d.y_ticks == cartesians[1].y_ticks));
+        // Same data, same axes: same screen points
+        assert_eq!(cartesians[0].curves, cartesians[2].curves);
+        let svg = CartesianDiagram::to_overlay_svg_multi(
+            &cartesians,
+            &labels,
+            500.0,
+            380.0,
+            &SvgTheme::light(),
+        );
+        assert_eq!(
+            svg.matches("stroke-dasharray=\"8,4\" stroke-linecap")
+                .count(),
+            3
+        );
+        assert!(svg.contains(">B</text>"));
+    }
+
     #[test]
     fn test_cartesian_to_svg() {
         let ldt = create_test_ldt();
@@ -5181,6 +6387,45 @@ mod tests {
         assert!(svg.contains("Gamma"));
     }
 
+    #[test]
+    fn test_isolux_datasheet_svg() {
+        let mut ldt = create_test_ldt();
+        ldt.lamp_sets = vec![crate::LampSet {
+            num_lamps: 1,
+            total_luminous_flux: 20000.0,
+            ..Default::default()
+        }];
+        let params = super::super::IsoluxParams {
+            mounting_height: 4.0,
+            area_half_width: 10.0,
+            area_half_depth: 10.0,
+            grid_resolution: 40,
+            ..Default::default()
+        };
+        let diagram = IsoluxDiagram::from_eulumdat(&ldt, 600.0, 500.0, params);
+        assert!(!diagram.contours.is_empty());
+
+        let svg = diagram.to_svg_datasheet(600.0, 500.0, &SvgTheme::light(), UnitSystem::Metric);
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>"));
+        assert!(svg.contains("Isolux Diagram (h=4.0m"));
+        // One label per contour on the line plus one in the level list
+        for contour in &diagram.contours {
+            let label = format!(">{}</text>", fmt_lux(contour.lux_value));
+            assert!(svg.matches(&label).count() >= 2, "{label}");
+        }
+        // 20 m wide plot → 5 m scale bar
+        assert!(svg.contains(">5 m</text>"));
+        assert!(!svg.contains("rgb("), "no heatmap cells");
+    }
+
+    #[test]
+    fn test_path_points() {
+        assert_eq!(
+            path_points("M 1.0 2.0 L 3.5 4.0 L 5.0 6.0"),
+            vec![(1.0, 2.0), (3.5, 4.0), (5.0, 6.0)]
+        );
+    }
+
     #[test]
     fn test_theme_css_variables() {
         let theme = SvgTheme::css_variables();
@@ -0,0 +1,230 @@
+//! Intensity table rendering
+//!
+//! Lays out the stored C/γ intensity matrix as a heat-colored table, either
+//! as fixed-size SVG pages (for datasheets and bitmap previews) or as one
+//! HTML document with a page break after each block of C-planes.
+//!
+//! ```rust,no_run
+//! use eulumdat::{Eulumdat, diagram::{IntensityTable, SvgTheme}};
+//!
+//! let ldt = Eulumdat::from_file("luminaire.ldt")?;
+//! let table = IntensityTable::from_eulumdat(&ldt);
+//! for (i, page) in table.to_svg_pages(800.0, 1100.0, &SvgTheme::light()).iter().enumerate() {
+//!     std::fs::write(format!("intensities-{}.svg", i + 1), page)?;
+//! }
+//! std::fs::write("intensities.html", table.to_html(&SvgTheme::light(), 12))?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use super::color::{Color, ColorMap};
+use super::SvgTheme;
+use crate::Eulumdat;
+use std::ops::Range;
+
+/// Heat-colored C/γ intensity matrix
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntensityTable {
+    /// C-plane angles (columns)
+    pub c_angles: Vec<f64>,
+    /// Gamma angles (rows)
+    pub g_angles: Vec<f64>,
+    /// Intensity in cd/klm (`[c][γ]`)
+    pub values: Vec<Vec<f64>>,
+    /// Highest intensity in the table
+    pub max_value: f64,
+    /// Cell coloring
+    pub color_map: ColorMap,
+}
+
+impl IntensityTable {
+    /// Width of a value cell in SVG pages
+    pub const CELL_WIDTH: f64 = 46.0;
+    /// Height of a row in SVG pages
+    pub const ROW_HEIGHT: f64 = 15.0;
+
+    /// Table of the stored C-planes (symmetric files keep their reduced set).
+    pub fn from_eulumdat(ldt: &Eulumdat) -> Self {
+        let c_angles: Vec<f64> = ldt
+            .c_angles
+            .iter()
+            .take(ldt.intensities.len())
+            .copied()
+            .collect();
+        let values: Vec<Vec<f64>> = ldt.intensities[..c_angles.len()]
+            .iter()
+            .map(|row| {
+                (0..ldt.g_angles.len())
+                    .map(|g| row.get(g).copied().unwrap_or(0.0))
+                    .collect()
+            })
+            .collect();
+        let max_value = values.iter().flatten().copied().fold(0.0, f64::max);
+
+        Self {
+            c_angles,
+            g_angles: ldt.g_angles.clone(),
+            values,
+            max_value,
+            color_map: ColorMap::default(),
+        }
+    }
+
+    /// Use a different color map for the cells.
+    pub fn with_color_map(mut self, color_map: ColorMap) -> Self {
+        self.color_map = color_map;
+        self
+    }
+
+    /// Whether the table has no values
+    pub fn is_empty(&self) -> bool {
+        self.c_angles.is_empty() || self.g_angles.is_empty()
+    }
+
+    /// Split the table into pages of at most `columns` C-planes and `rows`
+    /// gamma angles; all rows of a column block come before the next block.
+    pub fn pages(&self, columns: usize, rows: usize) -> Vec<(Range<usize>, Range<usize>)> {
+        let (columns, rows) = (columns.max(1), rows.max(1));
+        let mut pages = Vec::new();
+        for c in (0..self.c_angles.len()).step_by(columns) {
+            for g in (0..self.g_angles.len()).step_by(rows) {
+                pages.push((
+                    c..(c + columns).min(self.c_angles.len()),
+                    g..(g + rows).min(self.g_angles.len()),
+                ));
+            }
+        }
+        pages
+    }
+
+    /// Cell color and a readable text color on it
+    pub fn cell_colors(&self, value: f64) -> (Color, &'static str) {
+        let fraction = if self.max_value > 0.0 {
+            value / self.max_value
+        } else {
+            0.0
+        };
+        let color = self.color_map.color(fraction);
+        let text = if color.luminance() > 0.5 {
+            "#000000"
+        } else {
+            "#ffffff"
+        };
+        (color, text)
+    }
+
+    /// Cell text: one decimal below 100 cd/klm, whole numbers above
+    pub fn format_value(value: f64) -> String {
+        if value < 100.0 {
+            format!("{value:.1}")
+        } else {
+            format!("{value:.0}")
+        }
+    }
+
+    /// Render as a standalone HTML document with one table per block of
+    /// `columns_per_page` C-planes and a page break after each block.
+    pub fn to_html(&self, theme: &SvgTheme, columns_per_page: usize) -> String {
+        let mut html = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str("<title>Luminous intensity (cd/klm)</title>\n<style>\n");
+        html.push_str(&format!(
+            "body {{ background: {}; color: {}; font-family: {}; }}\n",
+            theme.background, theme.text, theme.font_family
+        ));
+        html.push_str(
+            "table { border-collapse: collapse; font-size: 11px; margin-bottom: 16px; }\n\
+             table.page { break-after: page; }\n\
+             thead { display: table-header-group; }\n\
+             td { padding: 2px 6px; text-align: right; }\n",
+        );
+        html.push_str(&format!(
+            "th {{ padding: 2px 6px; background: {}; border: 1px solid {}; }}\n",
+            theme.surface, theme.grid
+        ));
+        html.push_str("</style>\n</head>\n<body>\n");
+        html.push_str(&format!(
+            "<h1>Luminous intensity (cd/klm)</h1>\n<p>I<sub>max</sub> = {}</p>\n",
+            Self::format_value(self.max_value)
+        ));
+
+        let column_blocks = self.pages(columns_per_page, self.g_angles.len().max(1));
+        let block_count = column_blocks.len();
+        for (i, (columns, rows)) in column_blocks.into_iter().enumerate() {
+            let class = if i + 1 < block_count {
+                " class=\"page\""
+            } else {
+                ""
+            };
+            html.push_str(&format!("<table{class}>\n<thead>\n<tr><th>γ \\ C</th>"));
+            for c in &self.c_angles[columns.clone()] {
+                html.push_str(&format!("<th>{c}°</th>"));
+            }
+            html.push_str("</tr>\n</thead>\n<tbody>\n");
+            for g in rows {
+                html.push_str(&format!("<tr><th>{}°</th>", self.g_angles[g]));
+                for c in columns.clone() {
+                    let value = self.values[c][g];
+                    let (color, text) = self.cell_colors(value);
+                    html.push_str(&format!(
+                        "<td style=\"background: {}; color: {text}\">{}</td>",
+                        color.to_hex_string(),
+                        Self::format_value(value)
+                    ));
+                }
+                html.push_str("</tr>\n");
+            }
+            html.push_str("</tbody>\n</table>\n");
+        }
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_ldt() -> Eulumdat {
+        Eulumdat {
+            c_angles: (0..24).map(|i| i as f64 * 15.0).collect(),
+            g_angles: (0..=36).map(|i| i as f64 * 5.0).collect(),
+            intensities: (0..24)
+                .map(|c| {
+                    (0..=36)
+                        .map(|g| 300.0 - g as f64 * 8.0 + c as f64)
+                        .collect()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_intensity_table_pages() {
+        let table = IntensityTable::from_eulumdat(&create_test_ldt());
+        assert_eq!(table.max_value, 323.0);
+
+        let pages = table.pages(10, 20);
+        // 3 column blocks × 2 row blocks
+        assert_eq!(pages.len(), 6);
+        assert_eq!(pages[1], (0..10, 20..37));
+        assert_eq!(pages[5], (20..24, 20..37));
+
+        let svgs = table.to_svg_pages(600.0, 420.0, &SvgTheme::light());
+        assert!(svgs.len() > 1);
+        assert!(svgs[0].contains("1/"));
+        assert!(svgs.iter().any(|svg| svg.contains(">345°<")));
+    }
+
+    #[test]
+    fn test_intensity_table_html() {
+        let table = IntensityTable::from_eulumdat(&create_test_ldt());
+        let html = table.to_html(&SvgTheme::light(), 12);
+        assert_eq!(html.matches("<table").count(), 2);
+        assert_eq!(html.matches("class=\"page\"").count(), 1);
+        assert!(html.contains(">323</td>"));
+        // Low values are dark on the classic map, so their text is white
+        assert!(html.contains("color: #ffffff\">12.0</td>"));
+    }
+}
@@ -0,0 +1,420 @@
+//! Custom SVG themes for corporate datasheet styling
+//!
+//! [`SvgThemeBuilder`] starts from the light (or any other) theme and
+//! overrides colors, fonts, line weights and an optional logo:
+//!
+//! ```rust
+//! use eulumdat::diagram::{LogoPosition, SvgLogo, SvgTheme};
+//!
+//! let theme = SvgTheme::builder()
+//!     .curve_colors("#00594f", "#e5007d")
+//!     .font_family("Helvetica Neue, Arial, sans-serif")
+//!     .font_scale(1.2)
+//!     .line_scale(0.8)
+//!     .logo(SvgLogo::new("https://example.com/logo.svg", 80.0, 24.0)
+//!         .at(LogoPosition::TopLeft))
+//!     .build();
+//! ```
+//!
+//! The polar, cartesian and heatmap diagrams (rendered through
+//! [`DiagramGeometry`](super::DiagramGeometry)) apply the font scale, line
+//! scale and logo themselves; for the other diagrams pass the finished SVG
+//! through [`SvgTheme::apply_styling`]. Colors and the font family apply to
+//! all diagrams.
+
+use super::{SvgLabels, SvgTheme};
+use crate::units::UnitSystem;
+
+/// Corner of the diagram holding the logo
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LogoPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+/// Logo image drawn on every themed diagram
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SvgLogo {
+    /// Image URL or `data:` URI (SVG or PNG)
+    pub href: String,
+    /// Width in diagram pixels
+    pub width: f64,
+    /// Height in diagram pixels
+    pub height: f64,
+    /// Corner of the diagram
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub position: LogoPosition,
+    /// Distance from the diagram edges in pixels
+    #[cfg_attr(feature = "serde", serde(default = "default_logo_margin"))]
+    pub margin: f64,
+    /// Opacity 0-1
+    #[cfg_attr(feature = "serde", serde(default = "default_logo_opacity"))]
+    pub opacity: f64,
+}
+
+#[cfg(feature = "serde")]
+fn default_logo_margin() -> f64 {
+    8.0
+}
+
+#[cfg(feature = "serde")]
+fn default_logo_opacity() -> f64 {
+    1.0
+}
+
+impl SvgLogo {
+    /// Logo in the bottom right corner
+    pub fn new(href: impl Into<String>, width: f64, height: f64) -> Self {
+        Self {
+            href: href.into(),
+            width,
+            height,
+            position: LogoPosition::default(),
+            margin: 8.0,
+            opacity: 1.0,
+        }
+    }
+
+    /// Move the logo to another corner
+    pub fn at(mut self, position: LogoPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Set the opacity (0-1)
+    pub fn opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// `<image>` element for a `width` × `height` diagram
+    fn to_svg(&self, width: f64, height: f64) -> String {
+        let x = match self.position {
+            LogoPosition::TopLeft | LogoPosition::BottomLeft => self.margin,
+            LogoPosition::TopRight | LogoPosition::BottomRight => width - self.margin - self.width,
+        };
+        let y = match self.position {
+            LogoPosition::TopLeft | LogoPosition::TopRight => self.margin,
+            LogoPosition::BottomLeft | LogoPosition::BottomRight => {
+                height - self.margin - self.height
+            }
+        };
+        let href = self.href.replace('&', "&amp;").replace('"', "&quot;");
+        format!(
+            r#"<image href="{href}" x="{x:.1}" y="{y:.1}" width="{}" height="{}" opacity="{}" preserveAspectRatio="xMidYMid meet"/>"#,
+            self.width, self.height, self.opacity
+        )
+    }
+}
+
+/// Builder for custom [`SvgTheme`]s
+#[derive(Debug, Clone)]
+pub struct SvgThemeBuilder {
+    theme: SvgTheme,
+}
+
+impl SvgThemeBuilder {
+    /// Start from an existing theme
+    pub fn new(base: SvgTheme) -> Self {
+        Self { theme: base }
+    }
+
+    /// Page background
+    pub fn background(mut self, color: impl Into<String>) -> Self {
+        self.theme.background = color.into();
+        self
+    }
+
+    /// Plot surface
+    pub fn surface(mut self, color: impl Into<String>) -> Self {
+        self.theme.surface = color.into();
+        self
+    }
+
+    /// Grid and axis lines
+    pub fn grid_colors(mut self, grid: impl Into<String>, axis: impl Into<String>) -> Self {
+        self.theme.grid = grid.into();
+        self.theme.axis = axis.into();
+        self
+    }
+
+    /// Primary and secondary text
+    pub fn text_colors(mut self, text: impl Into<String>, secondary: impl Into<String>) -> Self {
+        self.theme.text = text.into();
+        self.theme.text_secondary = secondary.into();
+        self
+    }
+
+    /// Legend box background
+    pub fn legend_background(mut self, color: impl Into<String>) -> Self {
+        self.theme.legend_bg = color.into();
+        self
+    }
+
+    /// C0-C180 and C90-C270 curve colors
+    ///
+    /// Hex colors (`#rrggbb`) also set matching translucent fills.
+    pub fn curve_colors(
+        mut self,
+        primary: impl Into<String>,
+        secondary: impl Into<String>,
+    ) -> Self {
+        let (primary, secondary) = (primary.into(), secondary.into());
+        if let Some(fill) = translucent(&primary) {
+            self.theme.curve_c0_c180_fill = fill;
+        }
+        if let Some(fill) = translucent(&secondary) {
+            self.theme.curve_c90_c270_fill = fill;
+        }
+        self.theme.curve_c0_c180 = primary;
+        self.theme.curve_c90_c270 = secondary;
+        self
+    }
+
+    /// C0-C180 and C90-C270 fill colors
+    pub fn curve_fills(mut self, primary: impl Into<String>, secondary: impl Into<String>) -> Self {
+        self.theme.curve_c0_c180_fill = primary.into();
+        self.theme.curve_c90_c270_fill = secondary.into();
+        self
+    }
+
+    /// Colors for multi-curve diagrams, in order (see [`SvgTheme::c_plane_color`])
+    pub fn palette<S: Into<String>>(mut self, colors: impl IntoIterator<Item = S>) -> Self {
+        self.theme.palette = colors.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// CSS font family
+    pub fn font_family(mut self, family: impl Into<String>) -> Self {
+        self.theme.font_family = family.into();
+        self
+    }
+
+    /// Multiplier for all font sizes
+    pub fn font_scale(mut self, scale: f64) -> Self {
+        self.theme.font_scale = scale.max(0.1);
+        self
+    }
+
+    /// Multiplier for all line widths
+    pub fn line_scale(mut self, scale: f64) -> Self {
+        self.theme.line_scale = scale.max(0.1);
+        self
+    }
+
+    /// Dash patterns and hatching so curves differ without color
+    pub fn curve_patterns(mut self, enabled: bool) -> Self {
+        self.theme.curve_patterns = enabled;
+        self
+    }
+
+    /// Logo drawn on every diagram
+    pub fn logo(mut self, logo: SvgLogo) -> Self {
+        self.theme.logo = Some(logo);
+        self
+    }
+
+    /// Localized labels
+    pub fn labels(mut self, labels: SvgLabels) -> Self {
+        self.theme.labels = labels;
+        self
+    }
+
+    /// Metric (m, lx) or imperial (ft, fc) annotations
+    pub fn units(mut self, units: UnitSystem) -> Self {
+        self.theme.units = units;
+        self
+    }
+
+    pub fn build(self) -> SvgTheme {
+        self.theme
+    }
+}
+
+/// `rgba()` fill at 15 % opacity for a `#rrggbb` color
+fn translucent(color: &str) -> Option<String> {
+    let hex = color.strip_prefix('#').filter(|h| h.len() == 6)?;
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some(format!(
+        "rgba({},{},{},0.15)",
+        channel(0)?,
+        channel(2)?,
+        channel(4)?
+    ))
+}
+
+impl SvgTheme {
+    /// Builder starting from the light theme
+    pub fn builder() -> SvgThemeBuilder {
+        SvgThemeBuilder::new(Self::light())
+    }
+
+    /// Builder starting from this theme
+    pub fn to_builder(&self) -> SvgThemeBuilder {
+        SvgThemeBuilder::new(self.clone())
+    }
+
+    /// Logo element for a `width` × `height` diagram (empty without logo)
+    pub(crate) fn logo_svg(&self, width: f64, height: f64) -> String {
+        self.logo
+            .as_ref()
+            .map(|logo| logo.to_svg(width, height))
+            .unwrap_or_default()
+    }
+
+    /// Apply the font scale, line scale and logo to a finished SVG.
+    ///
+    /// For diagrams not drawn through `DiagramGeometry`; rescales the
+    /// numeric `font-size` and `stroke-width` attributes and places the logo
+    /// using the `viewBox`.
+    pub fn apply_styling(&self, svg: &str) -> String {
+        let mut out = scale_attribute(svg, "font-size", self.font_scale);
+        out = scale_attribute(&out, "stroke-width", self.line_scale);
+
+        if self.logo.is_some() {
+            let view_box = out
+                .split_once("viewBox=\"")
+                .and_then(|(_, rest)| rest.split_once('"'))
+                .map(|(view_box, _)| {
+                    view_box
+                        .split([' ', ','])
+                        .filter_map(|v| v.parse::<f64>().ok())
+                        .collect::<Vec<_>>()
+                });
+            if let (Some([_, _, width, height]), Some(end)) =
+                (view_box.as_deref(), out.rfind("</svg>"))
+            {
+                out.insert_str(end, &self.logo_svg(*width, *height));
+            }
+        }
+        out
+    }
+}
+
+/// Multiply every numeric `name="…"` attribute by `scale`.
+fn scale_attribute(svg: &str, name: &str, scale: f64) -> String {
+    if (scale - 1.0).abs() < 1e-9 {
+        return svg.to_string();
+    }
+    let pattern = format!(" {name}=\"");
+    let mut out = String::with_capacity(svg.len());
+    let mut rest = svg;
+    while let Some(start) = rest.find(&pattern) {
+        let value_start = start + pattern.len();
+        out.push_str(&rest[..value_start]);
+        rest = &rest[value_start..];
+        let Some(end) = rest.find('"') else {
+            break;
+        };
+        match rest[..end].parse::<f64>() {
+            Ok(value) => out.push_str(&format!("{}", (value * scale * 100.0).round() / 100.0)),
+            Err(_) => out.push_str(&rest[..end]),
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_overrides() {
+        let theme = SvgTheme::builder()
+            .curve_colors("#00594f", "var(--brand-red)")
+            .font_family("Arial")
+            .palette(["#111111", "#222222"])
+            .build();
+
+        assert_eq!(theme.curve_c0_c180, "#00594f");
+        assert_eq!(theme.curve_c0_c180_fill, "rgba(0,89,79,0.15)");
+        // Non-hex colors keep the base fill
+        assert_eq!(
+            theme.curve_c90_c270_fill,
+            SvgTheme::light().curve_c90_c270_fill
+        );
+        assert_eq!(theme.font_family, "Arial");
+        assert_eq!(theme.c_plane_color(3), "#222222");
+        assert_eq!(theme.background, SvgTheme::light().background);
+    }
+
+    #[test]
+    fn test_apply_styling() {
+        let theme = SvgTheme::builder()
+            .font_scale(1.5)
+            .line_scale(2.0)
+            .logo(SvgLogo::new("logo.png?a=1&b=2", 40.0, 20.0).at(LogoPosition::TopRight))
+            .build();
+        let svg = r#"<svg viewBox="0 0 400 300" xmlns="http://www.w3.org/2000/svg"><text font-size="10">A</text><line stroke-width="1.5"/><text font-size="{x}">B</text></svg>"#;
+
+        let styled = theme.apply_styling(svg);
+        assert!(styled.contains(r#"font-size="15""#));
+        assert!(styled.contains(r#"stroke-width="3""#));
+        assert!(styled.contains(r#"font-size="{x}""#));
+        assert!(styled.contains(
+            r#"<image href="logo.png?a=1&amp;b=2" x="352.0" y="8.0" width="40" height="20""#
+        ));
+        assert!(styled.ends_with("</svg>"));
+
+        assert_eq!(SvgTheme::light().apply_styling(svg), svg);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_partial_theme_json() {
+        let theme: SvgTheme = serde_json::from_str(
+            r##"{"curve_c0_c180": "#00594f", "font_scale": 1.25,
+                 "logo": {"href": "logo.svg", "width": 60, "height": 20}}"##,
+        )
+        .unwrap();
+
+        assert_eq!(theme.curve_c0_c180, "#00594f");
+        assert_eq!(theme.font_scale, 1.25);
+        assert_eq!(theme.line_scale, 1.0);
+        assert_eq!(theme.text, SvgTheme::light().text);
+        assert_eq!(theme.logo.unwrap().margin, 8.0);
+    }
+
+    #[test]
+    fn test_theme_units() {
+        use crate::diagram::{ConeDiagram, IsoluxDiagram, IsoluxParams};
+        use crate::{Eulumdat, LampSet, Symmetry};
+
+        let ldt = Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            c_angles: vec![0.0],
+            g_angles: vec![0.0, 30.0, 60.0, 90.0],
+            intensities: vec![vec![400.0, 300.0, 100.0, 0.0]],
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 2000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let imperial = SvgTheme::builder().units(UnitSystem::Imperial).build();
+
+        let cone = ConeDiagram::from_eulumdat(&ldt, 3.048).with_heights(&ldt, &[3.048]);
+        let svg = cone.to_svg(600.0, 500.0, &imperial);
+        assert!(svg.contains("10.0ft"));
+        assert!(svg.contains(" fc</text>"));
+        assert!(!cone.to_svg(600.0, 500.0, &SvgTheme::light()).contains("ft"));
+
+        let isolux = IsoluxDiagram::from_eulumdat(&ldt, 600.0, 500.0, IsoluxParams::default());
+        assert_eq!(
+            isolux.to_svg(600.0, 500.0, &imperial),
+            isolux.to_svg_with_units(600.0, 500.0, &imperial, UnitSystem::Imperial)
+        );
+        assert_eq!(
+            SvgTheme::dark().with_units(UnitSystem::Imperial).units,
+            imperial.units
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! Flux scaling and dimmed variants.
+//!
+//! Intensities are stored relative to the lamp flux (cd/klm), so scaling the
+//! lamp lumens scales the absolute intensities with them. Wattage follows
+//! either linearly or through a [`DriverCurve`] that models the driver's
+//! losses at partial load.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use eulumdat::{DriverCurve, Eulumdat};
+//!
+//! let ldt = Eulumdat::from_file("luminaire.ldt").unwrap();
+//! let dimmed = ldt.with_dimming_curve(50.0, &DriverCurve::with_standby(0.05));
+//! println!("{:.0} lm at {:.1} W", dimmed.total_luminous_flux(), dimmed.total_wattage());
+//! ```
+
+use crate::eulumdat::Eulumdat;
+
+/// Input power versus light output of a dimmable driver.
+///
+/// Points are `(light_fraction, power_fraction)` relative to full output,
+/// interpolated linearly. Outside the tabulated range the power is
+/// extrapolated from the nearest segment.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DriverCurve {
+    /// `(light_fraction, power_fraction)` pairs sorted by light fraction
+    pub points: Vec<(f64, f64)>,
+}
+
+impl Default for DriverCurve {
+    fn default() -> Self {
+        Self::linear()
+    }
+}
+
+impl DriverCurve {
+    /// Curve through the given points (sorted by light fraction).
+    pub fn new(mut points: Vec<(f64, f64)>) -> Self {
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { points }
+    }
+
+    /// Power proportional to light output.
+    pub fn linear() -> Self {
+        Self::new(vec![(0.0, 0.0), (1.0, 1.0)])
+    }
+
+    /// Constant standby share plus a part proportional to light output.
+    ///
+    /// `standby_fraction` is the power drawn at zero light relative to full
+    /// power, e.g. 0.05 for a driver that still draws 5 % when dimmed off.
+    pub fn with_standby(standby_fraction: f64) -> Self {
+        Self::new(vec![(0.0, standby_fraction), (1.0, 1.0)])
+    }
+
+    /// Power fraction at `light_fraction` of full output.
+    pub fn power_fraction(&self, light_fraction: f64) -> f64 {
+        match self.points.as_slice() {
+            [] => light_fraction,
+            [(x, y)] if *x > 0.0 => y * light_fraction / x,
+            [(_, y)] => *y,
+            points => {
+                let i = points
+                    .windows(2)
+                    .position(|w| light_fraction <= w[1].0)
+                    .unwrap_or(points.len() - 2);
+                let ((x0, y0), (x1, y1)) = (points[i], points[i + 1]);
+                if x1 > x0 {
+                    y0 + (y1 - y0) * (light_fraction - x0) / (x1 - x0)
+                } else {
+                    y1
+                }
+            }
+        }
+        .max(0.0)
+    }
+}
+
+impl Eulumdat {
+    /// Scale lamp lumens and wattage by `factor`.
+    ///
+    /// Relative intensities (cd/klm) are unchanged, so absolute intensities
+    /// scale by the same factor. Wattage scales linearly; use
+    /// [`Self::scale_flux_with_driver`] for drivers with partial-load losses.
+    pub fn scale_flux(&mut self, factor: f64) {
+        self.scale_flux_with_driver(factor, &DriverCurve::linear());
+    }
+
+    /// Scale lamp lumens by `factor` and wattage along a driver curve.
+    pub fn scale_flux_with_driver(&mut self, factor: f64, curve: &DriverCurve) {
+        let factor = factor.max(0.0);
+        // Curves are relative to full output, which the current data represents
+        let power = curve.power_fraction(factor) / curve.power_fraction(1.0).max(f64::EPSILON);
+        for lamp_set in &mut self.lamp_sets {
+            lamp_set.total_luminous_flux *= factor;
+            lamp_set.wattage_with_ballast *= power;
+        }
+    }
+
+    /// Dimmed copy at `percent` of the current light output, with linear wattage.
+    pub fn with_dimming(&self, percent: f64) -> Self {
+        self.with_dimming_curve(percent, &DriverCurve::linear())
+    }
+
+    /// Dimmed copy at `percent` of the current light output, with wattage
+    /// from a driver curve.
+    pub fn with_dimming_curve(&self, percent: f64, curve: &DriverCurve) -> Self {
+        let mut dimmed = self.clone();
+        dimmed.scale_flux_with_driver(percent / 100.0, curve);
+        dimmed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LampSet;
+
+    fn test_ldt() -> Eulumdat {
+        Eulumdat {
+            c_angles: vec![0.0],
+            g_angles: vec![0.0, 90.0],
+            intensities: vec![vec![300.0, 100.0]],
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 4000.0,
+                wattage_with_ballast: 40.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_scale_flux_scales_absolute_intensity() {
+        let ldt = test_ldt();
+        let candela = |ldt: &Eulumdat| ldt.sample(0.0, 0.0) * ldt.total_luminous_flux() / 1000.0;
+
+        let dimmed = ldt.with_dimming(25.0);
+        assert_eq!(dimmed.total_luminous_flux(), 1000.0);
+        assert_eq!(dimmed.total_wattage(), 10.0);
+        assert_eq!(dimmed.intensities, ldt.intensities);
+        assert!((candela(&dimmed) - candela(&ldt) * 0.25).abs() < 1e-9);
+        assert!((dimmed.luminous_efficacy() - ldt.luminous_efficacy()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_driver_curve() {
+        let curve = DriverCurve::with_standby(0.1);
+        assert!((curve.power_fraction(0.0) - 0.1).abs() < 1e-9);
+        assert!((curve.power_fraction(0.5) - 0.55).abs() < 1e-9);
+        assert!((curve.power_fraction(1.0) - 1.0).abs() < 1e-9);
+
+        let dimmed = test_ldt().with_dimming_curve(50.0, &curve);
+        assert_eq!(dimmed.total_luminous_flux(), 2000.0);
+        assert!((dimmed.total_wattage() - 22.0).abs() < 1e-9);
+        // Efficacy drops at partial load
+        assert!(dimmed.luminous_efficacy() < test_ldt().luminous_efficacy());
+
+        let tabulated = DriverCurve::new(vec![(1.0, 1.0), (0.2, 0.3), (0.0, 0.05)]);
+        assert!((tabulated.power_fraction(0.6) - 0.65).abs() < 1e-9);
+    }
+}
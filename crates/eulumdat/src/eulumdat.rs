@@ -2,8 +2,10 @@
 
 use std::path::Path;
 
+use crate::date::MeasurementDate;
 use crate::error::{anyhow, invalid_value, Result};
 use crate::parser::Parser;
+use crate::symmetry::Interpolation;
 use crate::validation::{ValidationError, ValidationWarning};
 use crate::writer::Writer;
 
@@ -133,6 +135,33 @@ impl Symmetry {
     }
 }
 
+/// How far one distribution is from another at the other's stored angles
+/// (see [`Eulumdat::deviation_from`]).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IntensityDeviation {
+    /// Largest absolute difference in cd/klm
+    pub max: f64,
+    /// Largest difference in % of the reference maximum intensity
+    pub max_percent: f64,
+    /// Root mean square difference in cd/klm
+    pub rms: f64,
+    /// C-angle of the largest difference
+    pub c_angle: f64,
+    /// γ-angle of the largest difference
+    pub g_angle: f64,
+}
+
+/// Vertical plane to mirror the distribution across (see [`Eulumdat::mirror`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MirrorPlane {
+    /// Plane through C0 and C180 (swaps C90 and C270)
+    C0C180,
+    /// Plane through C90 and C270 (swaps C0 and C180)
+    C90C270,
+}
+
 /// Lamp set configuration.
 ///
 /// A luminaire can have up to 20 lamp sets, each describing a group of lamps.
@@ -153,6 +182,34 @@ pub struct LampSet {
     pub wattage_with_ballast: f64,
 }
 
+/// Photometric center position within the luminaire.
+///
+/// Coordinates are in mm relative to the geometric center of the luminaire
+/// body, in the photometric frame: `x` towards C0, `y` towards C90 and `z`
+/// up (towards γ 180°).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PhotometricCenter {
+    /// Offset towards C0 in mm.
+    pub x: f64,
+    /// Offset towards C90 in mm.
+    pub y: f64,
+    /// Offset upwards in mm.
+    pub z: f64,
+}
+
+impl PhotometricCenter {
+    /// Create a photometric center from offsets in mm.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Offsets in meters.
+    pub fn to_meters(&self) -> (f64, f64, f64) {
+        (self.x / 1000.0, self.y / 1000.0, self.z / 1000.0)
+    }
+}
+
 /// Main Eulumdat data structure.
 ///
 /// This struct contains all data from an Eulumdat (LDT) file.
@@ -238,6 +295,18 @@ pub struct Eulumdat {
     /// Luminous intensity distribution in cd/klm.
     /// Indexed as `intensities[c_plane_index][g_plane_index]`.
     pub intensities: Vec<Vec<f64>>,
+
+    // === Geometry (not stored in LDT files) ===
+    /// Photometric center, if it differs from the center of the luminous
+    /// area (e.g. from GLDF/ATLA luminous opening positions).
+    ///
+    /// `None` means the LDT convention applies, see
+    /// [`photometric_center`](Self::photometric_center).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub photometric_center: Option<PhotometricCenter>,
 }
 
 impl Default for Eulumdat {
@@ -273,6 +342,7 @@ impl Default for Eulumdat {
             c_angles: Vec::new(),
             g_angles: Vec::new(),
             intensities: Vec::new(),
+            photometric_center: None,
         }
     }
 }
@@ -310,6 +380,11 @@ impl Eulumdat {
         Writer::write(self)
     }
 
+    /// Convert to LDT format string with the given precision per field.
+    pub fn to_ldt_with_rounding(&self, policy: &crate::RoundingPolicy) -> String {
+        Writer::write_with_rounding(self, policy)
+    }
+
     /// Validate the data and return any warnings.
     pub fn validate(&self) -> Vec<ValidationWarning> {
         crate::validation::validate(self)
@@ -320,6 +395,25 @@ impl Eulumdat {
         crate::validation::validate_strict(self)
     }
 
+    /// Measurement date parsed from the free-form date/user field.
+    pub fn measurement_date(&self) -> Option<MeasurementDate> {
+        MeasurementDate::parse(&self.date_user)
+    }
+
+    /// Rewrite the date in the date/user field as ISO 8601 (`YYYY-MM-DD`).
+    ///
+    /// Text around the date (e.g. a user name) is kept. Returns `false` and
+    /// leaves the field unchanged when no date is found.
+    pub fn normalize_date_user(&mut self) -> bool {
+        match crate::date::normalize_date_text(&self.date_user) {
+            Some(normalized) => {
+                self.date_user = normalized;
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Get the actual number of C-planes based on symmetry (Mc).
     pub fn actual_c_planes(&self) -> usize {
         self.symmetry.calc_mc(self.num_c_planes)
@@ -348,6 +442,37 @@ impl Eulumdat {
         }
     }
 
+    /// Center of the luminous area relative to the geometric center.
+    ///
+    /// LDT files place the luminous area on the bottom face of the
+    /// luminaire, extending upwards by the mean of the luminous heights
+    /// (HC0...HC270).
+    pub fn luminous_area_center(&self) -> PhotometricCenter {
+        let luminous_height =
+            (self.height_c0 + self.height_c90 + self.height_c180 + self.height_c270) / 4.0;
+        PhotometricCenter::new(0.0, 0.0, (luminous_height - self.height) / 2.0)
+    }
+
+    /// Photometric center relative to the geometric center.
+    ///
+    /// Uses [`photometric_center`](Self::photometric_center) if set and the
+    /// [luminous area center](Self::luminous_area_center) otherwise.
+    pub fn photometric_center(&self) -> PhotometricCenter {
+        self.photometric_center
+            .unwrap_or_else(|| self.luminous_area_center())
+    }
+
+    /// Offset of the photometric center from the luminous area center.
+    ///
+    /// Mounting heights and placements refer to the luminous area, so this
+    /// is the shift illuminance calculations apply to the emission origin.
+    /// Zero unless the photometric center is set explicitly.
+    pub fn photometric_center_offset(&self) -> PhotometricCenter {
+        let center = self.photometric_center();
+        let area = self.luminous_area_center();
+        PhotometricCenter::new(center.x - area.x, center.y - area.y, center.z - area.z)
+    }
+
     /// Get intensity at a specific C and G angle.
     ///
     /// Returns None if the indices are out of bounds.
@@ -392,10 +517,14 @@ impl Eulumdat {
     /// on goniophotometers, so C0 may point along the length or width axis.
     ///
     /// The method:
-    /// 1. Expands symmetric data to full 360°
-    /// 2. Resamples all intensities at shifted C-angles
-    /// 3. Sets symmetry to None (full data)
-    /// 4. For exact 90° multiples, rotates height_c0/c90/c180/c270 and swaps length/width
+    /// 1. Resamples all intensities at shifted C-angles over the full 360°
+    /// 2. Keeps the symmetry where the rotated data still has it (C0-C180 and
+    ///    both planes after 180°, both planes after 90°, vertical axis always);
+    ///    otherwise sets symmetry to None (full data)
+    /// 3. For exact 90° multiples, rotates height_c0/c90/c180/c270 and swaps length/width
+    ///
+    /// Rotations by a multiple of the C-plane distance only permute planes;
+    /// other angles interpolate between them.
     ///
     /// # Arguments
     /// * `degrees` - Rotation angle in degrees (positive = counter-clockwise when viewed from above)
@@ -406,38 +535,275 @@ impl Eulumdat {
             return;
         }
 
-        // Expand to full 360° data
-        let full_intensities = crate::symmetry::SymmetryHandler::expand_to_full(self);
-        let full_c_angles = crate::symmetry::SymmetryHandler::expand_c_angles(self);
+        let steps = (rotation / 90.0).round() as i32;
+        let quarter_turns =
+            ((rotation - steps as f64 * 90.0).abs() < 0.001).then_some(steps.rem_euclid(4));
+        let symmetry = match (self.symmetry, quarter_turns) {
+            (Symmetry::VerticalAxis, _) => Symmetry::VerticalAxis,
+            (Symmetry::BothPlanes, Some(_)) => Symmetry::BothPlanes,
+            (Symmetry::PlaneC0C180, Some(2)) => Symmetry::PlaneC0C180,
+            _ => Symmetry::None,
+        };
+
+        // Each new C-angle takes the intensity from c - rotation
+        self.remap_c_planes(symmetry, |c| c - rotation);
 
-        if full_intensities.is_empty() || full_c_angles.is_empty() {
-            return;
+        // An explicit photometric center turns with the distribution
+        if let Some(center) = self.photometric_center.as_mut() {
+            let (sin, cos) = rotation.to_radians().sin_cos();
+            let (x, y) = (center.x, center.y);
+            center.x = x * cos - y * sin;
+            center.y = x * sin + y * cos;
         }
 
-        // Build a temporary Eulumdat with full (Isym=0) data for sampling
-        let temp = Eulumdat {
-            symmetry: Symmetry::None,
-            c_angles: full_c_angles.clone(),
-            g_angles: self.g_angles.clone(),
-            intensities: full_intensities,
-            num_c_planes: full_c_angles.len(),
-            ..self.clone()
+        // For exact 90° multiples, rotate height values and swap dimensions
+        let [h0, h90, h180, h270] = [
+            self.height_c0,
+            self.height_c90,
+            self.height_c180,
+            self.height_c270,
+        ];
+        match quarter_turns {
+            Some(1) => {
+                // 90° CCW: C0→C90, C90→C180, C180→C270, C270→C0
+                self.height_c0 = h270;
+                self.height_c90 = h0;
+                self.height_c180 = h90;
+                self.height_c270 = h180;
+                std::mem::swap(&mut self.length, &mut self.width);
+                std::mem::swap(
+                    &mut self.luminous_area_length,
+                    &mut self.luminous_area_width,
+                );
+            }
+            Some(2) => {
+                // 180°: swap opposite pairs
+                self.height_c0 = h180;
+                self.height_c90 = h270;
+                self.height_c180 = h0;
+                self.height_c270 = h90;
+            }
+            Some(3) => {
+                // 270° CCW (= 90° CW)
+                self.height_c0 = h90;
+                self.height_c90 = h180;
+                self.height_c180 = h270;
+                self.height_c270 = h0;
+                std::mem::swap(&mut self.length, &mut self.width);
+                std::mem::swap(
+                    &mut self.luminous_area_length,
+                    &mut self.luminous_area_width,
+                );
+            }
+            _ => {} // 0 or 360 — already handled by early return
+        }
+    }
+
+    /// Mirror the distribution across a vertical plane.
+    ///
+    /// Fixes data measured with the C-plane direction reversed (C90 and C270
+    /// swapped, or C0 and C180). The symmetry is kept, since mirroring
+    /// preserves both plane symmetries; C90-C270 symmetric data is stored as
+    /// full data afterwards. The luminous heights of the swapped planes and
+    /// an explicit photometric center are mirrored as well.
+    pub fn mirror(&mut self, plane: MirrorPlane) {
+        let (unchanged_by, source): (Symmetry, fn(f64) -> f64) = match plane {
+            MirrorPlane::C0C180 => {
+                std::mem::swap(&mut self.height_c90, &mut self.height_c270);
+                if let Some(center) = self.photometric_center.as_mut() {
+                    center.y = -center.y;
+                }
+                (Symmetry::PlaneC0C180, |c| -c)
+            }
+            MirrorPlane::C90C270 => {
+                std::mem::swap(&mut self.height_c0, &mut self.height_c180);
+                if let Some(center) = self.photometric_center.as_mut() {
+                    center.x = -center.x;
+                }
+                (Symmetry::PlaneC90C270, |c| 180.0 - c)
+            }
         };
+        match self.symmetry {
+            // Already symmetric to the mirror plane
+            s if s == unchanged_by || s == Symmetry::BothPlanes => {}
+            Symmetry::PlaneC90C270 => self.remap_c_planes(Symmetry::None, source),
+            s => self.remap_c_planes(s, source),
+        }
+    }
+
+    /// Resample the distribution onto a regular C/γ grid.
+    ///
+    /// C-planes run from 0° in steps of `c_step` around the full circle, γ
+    /// angles from the first to the last current angle in steps of `g_step`
+    /// (the last angle is kept even if the step does not divide the range).
+    /// See [`Self::resample_to`] for how values and symmetry are derived.
+    ///
+    /// Non-positive steps leave the data unchanged.
+    pub fn resample(&mut self, c_step: f64, g_step: f64) {
+        let (Some(&g_first), Some(&g_last)) = (self.g_angles.first(), self.g_angles.last()) else {
+            return;
+        };
+        if c_step <= 0.0 || g_step <= 0.0 {
+            return;
+        }
+
+        let c_angles: Vec<f64> = (0..)
+            .map(|i| i as f64 * c_step)
+            .take_while(|c| *c < 360.0 - ANGLE_EPSILON)
+            .collect();
+        let mut g_angles: Vec<f64> = (0..)
+            .map(|i| g_first + i as f64 * g_step)
+            .take_while(|g| *g <= g_last + ANGLE_EPSILON)
+            .collect();
+        if g_angles.last().is_some_and(|g| *g < g_last - ANGLE_EPSILON) {
+            g_angles.push(g_last);
+        }
+        self.resample_to(&c_angles, &g_angles);
+    }
+
+    /// Resample the distribution onto explicit C and γ angles.
+    ///
+    /// `c_angles` lists the full 360° set of C-planes; both lists are
+    /// normalized, sorted and deduplicated. Intensities come from
+    /// [`Self::sample`], so the current symmetry is honoured. Afterwards the
+    /// symmetry is re-detected from the resampled values and only the planes
+    /// it requires are stored; grids that are not equidistant from C0 keep
+    /// full data. Plane counts and distances are updated (distance 0 for
+    /// non-equidistant angles, as in the file format).
+    pub fn resample_to(&mut self, c_angles: &[f64], g_angles: &[f64]) {
+        self.resample_with(c_angles, g_angles, Self::sample);
+    }
+
+    /// Resample onto explicit C and γ angles with a custom sampler.
+    ///
+    /// Like [`Self::resample_to`], but the value at (C, γ) is
+    /// `sample(self, c, γ)` on the data before resampling.
+    pub(crate) fn resample_with(
+        &mut self,
+        c_angles: &[f64],
+        g_angles: &[f64],
+        sample: impl Fn(&Self, f64, f64) -> f64,
+    ) {
+        let c_angles = sorted_angles(c_angles.iter().map(|c| c.rem_euclid(360.0)));
+        let g_angles = sorted_angles(g_angles.iter().map(|g| g.clamp(0.0, 180.0)));
+        if c_angles.is_empty() || g_angles.is_empty() || self.intensities.is_empty() {
+            return;
+        }
 
-        // Resample: for each output C-angle, sample at (c - rotation) from temp
-        let num_g = self.g_angles.len();
-        let mut new_intensities = Vec::with_capacity(full_c_angles.len());
-        for &c in &full_c_angles {
-            let source_c = (c - rotation).rem_euclid(360.0);
-            let mut row = Vec::with_capacity(num_g);
-            for &g in &self.g_angles {
-                row.push(temp.sample(source_c, g));
+        let mut intensities: Vec<Vec<f64>> = c_angles
+            .iter()
+            .map(|&c| g_angles.iter().map(|&g| sample(self, c, g)).collect())
+            .collect();
+        let symmetry = detect_grid_symmetry(&c_angles, &intensities);
+        intensities.truncate(symmetry.calc_mc(c_angles.len()));
+
+        self.symmetry = symmetry;
+        self.num_c_planes = c_angles.len();
+        self.c_plane_distance = uniform_step(&c_angles).unwrap_or(0.0);
+        self.num_g_planes = g_angles.len();
+        self.g_plane_distance = uniform_step(&g_angles).unwrap_or(0.0);
+        self.c_angles = c_angles;
+        self.g_angles = g_angles;
+        self.intensities = intensities;
+    }
+
+    /// Widen (`factor` > 1) or narrow (`factor` < 1) the beam about the nadir.
+    ///
+    /// The intensity at γ becomes the former intensity at γ / `factor`, so
+    /// beam and field angles scale by `factor`; light mapped past the last γ
+    /// angle is dropped. Intensities are then rescaled to keep the integrated
+    /// light output ratio. Non-positive factors leave the data unchanged.
+    pub fn scale_beam(&mut self, factor: f64) {
+        if factor <= 0.0 || !factor.is_finite() || self.g_angles.is_empty() {
+            return;
+        }
+        let lor_before = crate::PhotometricCalculations::integrate_flux(self).lor;
+        let g_last = self.g_angles[self.g_angles.len() - 1];
+
+        let source = self.clone();
+        for (row, &c) in self.intensities.iter_mut().zip(&source.c_angles) {
+            for (value, &g) in row.iter_mut().zip(&source.g_angles) {
+                let source_g = g / factor;
+                *value = if source_g > g_last + ANGLE_EPSILON {
+                    0.0
+                } else {
+                    source.sample(c, source_g)
+                };
             }
-            new_intensities.push(row);
         }
 
-        // Update self with full data
-        self.symmetry = Symmetry::None;
+        let lor_after = crate::PhotometricCalculations::integrate_flux(self).lor;
+        if lor_before > 0.0 && lor_after > 0.0 {
+            let scale = lor_before / lor_after;
+            for value in self.intensities.iter_mut().flatten() {
+                *value *= scale;
+            }
+        }
+    }
+
+    /// Compare with `reference` at each of its stored C/γ angles.
+    ///
+    /// After resampling a copy, `resampled.deviation_from(&original)` is the
+    /// interpolation error: how well the new grid reproduces the measured
+    /// values.
+    pub fn deviation_from(&self, reference: &Eulumdat) -> IntensityDeviation {
+        let mut deviation = IntensityDeviation::default();
+        let mut sum_sq = 0.0;
+        let mut count = 0usize;
+        for (plane, &c) in reference.intensities.iter().zip(&reference.c_angles) {
+            for (&value, &g) in plane.iter().zip(&reference.g_angles) {
+                let diff = (self.sample(c, g) - value).abs();
+                sum_sq += diff * diff;
+                count += 1;
+                if diff > deviation.max {
+                    deviation.max = diff;
+                    deviation.c_angle = c;
+                    deviation.g_angle = g;
+                }
+            }
+        }
+        if count > 0 {
+            deviation.rms = (sum_sq / count as f64).sqrt();
+        }
+        let i_max = reference.max_intensity();
+        if i_max > 0.0 {
+            deviation.max_percent = deviation.max / i_max * 100.0;
+        }
+        deviation
+    }
+
+    /// Resample the C-planes so that the plane at `c` takes the current
+    /// intensity at `source(c)`, storing the result with `symmetry`.
+    ///
+    /// `symmetry` must be a symmetry of the remapped distribution.
+    fn remap_c_planes(&mut self, symmetry: Symmetry, source: impl Fn(f64) -> f64) {
+        if self.symmetry == Symmetry::VerticalAxis {
+            // Rotationally symmetric: every remap leaves the data unchanged
+            return;
+        }
+
+        let full_c_angles = self.full_c_angles();
+        if full_c_angles.is_empty() || self.intensities.is_empty() {
+            return;
+        }
+
+        let mut new_intensities: Vec<Vec<f64>> = full_c_angles
+            .iter()
+            .map(|&c| {
+                let source_c = source(c).rem_euclid(360.0);
+                self.g_angles
+                    .iter()
+                    .map(|&g| self.sample(source_c, g))
+                    .collect()
+            })
+            .collect();
+
+        // Symmetric storage keeps the first Mc planes (C0 onwards)
+        if symmetry != Symmetry::None {
+            new_intensities.truncate(symmetry.calc_mc(full_c_angles.len()));
+        }
+
+        self.symmetry = symmetry;
         self.c_angles = full_c_angles;
         self.intensities = new_intensities;
         self.num_c_planes = self.c_angles.len();
@@ -446,51 +812,18 @@ impl Eulumdat {
         } else {
             0.0
         };
+    }
 
-        // For exact 90° multiples, rotate height values and swap dimensions
-        let steps = (rotation / 90.0).round() as i32;
-        if (rotation - steps as f64 * 90.0).abs() < 0.001 {
-            let steps_mod = steps.rem_euclid(4);
-            let [h0, h90, h180, h270] = [
-                self.height_c0,
-                self.height_c90,
-                self.height_c180,
-                self.height_c270,
-            ];
-            match steps_mod {
-                1 => {
-                    // 90° CCW: C0→C90, C90→C180, C180→C270, C270→C0
-                    self.height_c0 = h270;
-                    self.height_c90 = h0;
-                    self.height_c180 = h90;
-                    self.height_c270 = h180;
-                    std::mem::swap(&mut self.length, &mut self.width);
-                    std::mem::swap(
-                        &mut self.luminous_area_length,
-                        &mut self.luminous_area_width,
-                    );
-                }
-                2 => {
-                    // 180°: swap opposite pairs
-                    self.height_c0 = h180;
-                    self.height_c90 = h270;
-                    self.height_c180 = h0;
-                    self.height_c270 = h90;
-                }
-                3 => {
-                    // 270° CCW (= 90° CW)
-                    self.height_c0 = h90;
-                    self.height_c90 = h180;
-                    self.height_c180 = h270;
-                    self.height_c270 = h0;
-                    std::mem::swap(&mut self.length, &mut self.width);
-                    std::mem::swap(
-                        &mut self.luminous_area_length,
-                        &mut self.luminous_area_width,
-                    );
-                }
-                _ => {} // 0 or 360 — already handled by early return
-            }
+    /// Full 360° C-angles of the current data.
+    pub(crate) fn full_c_angles(&self) -> Vec<f64> {
+        if self.symmetry == Symmetry::None {
+            self.c_angles.clone()
+        } else if self.num_c_planes > 0 && self.c_plane_distance > 0.0 {
+            (0..self.num_c_planes)
+                .map(|i| i as f64 * self.c_plane_distance)
+                .collect()
+        } else {
+            crate::symmetry::SymmetryHandler::expand_c_angles(self)
         }
     }
 
@@ -530,6 +863,72 @@ impl Eulumdat {
     pub fn sample(&self, c_angle: f64, g_angle: f64) -> f64 {
         crate::symmetry::SymmetryHandler::get_intensity_at(self, c_angle, g_angle)
     }
+
+    /// Sample intensity with a chosen interpolation between grid nodes.
+    ///
+    /// [`Interpolation::Bicubic`] avoids the faceting of bilinear sampling in
+    /// dense renders; [`Interpolation::Nearest`] shows the raw measurement
+    /// grid. `sample_with(c, g, Interpolation::Bilinear)` equals `sample(c, g)`.
+    pub fn sample_with(&self, c_angle: f64, g_angle: f64, interpolation: Interpolation) -> f64 {
+        crate::symmetry::SymmetryHandler::get_intensity_with(self, c_angle, g_angle, interpolation)
+    }
+}
+
+/// Tolerance for comparing resampled angles in degrees.
+const ANGLE_EPSILON: f64 = 1e-6;
+
+/// Relative tolerance for detecting symmetry in resampled intensities.
+const SYMMETRY_TOLERANCE: f64 = 1e-9;
+
+/// Finite angles sorted ascending with near-duplicates removed.
+fn sorted_angles(angles: impl Iterator<Item = f64>) -> Vec<f64> {
+    let mut angles: Vec<f64> = angles.filter(|a| a.is_finite()).collect();
+    angles.sort_by(f64::total_cmp);
+    angles.dedup_by(|a, b| (*a - *b).abs() < ANGLE_EPSILON);
+    angles
+}
+
+/// Common step of equidistant angles, if there are at least two.
+fn uniform_step(angles: &[f64]) -> Option<f64> {
+    let step = angles.get(1)? - angles[0];
+    angles
+        .windows(2)
+        .all(|w| (w[1] - w[0] - step).abs() < ANGLE_EPSILON)
+        .then_some(step)
+}
+
+/// Strongest symmetry of full 360° data on the given C-planes.
+///
+/// Plane symmetries need an equidistant grid starting at C0 so that the
+/// mirrored planes are on the grid. Data symmetric to C90-C270 only is
+/// reported as [`Symmetry::None`], since reduced storage starts at C0.
+fn detect_grid_symmetry(c_angles: &[f64], rows: &[Vec<f64>]) -> Symmetry {
+    let max = rows.iter().flatten().fold(0.0f64, |m, v| m.max(v.abs()));
+    let tolerance = SYMMETRY_TOLERANCE * max.max(1.0);
+    let same = |a: &[f64], b: &[f64]| a.iter().zip(b).all(|(x, y)| (x - y).abs() <= tolerance);
+
+    if rows.iter().all(|row| same(row, &rows[0])) {
+        return Symmetry::VerticalAxis;
+    }
+
+    let nc = c_angles.len();
+    let equidistant = c_angles[0].abs() < ANGLE_EPSILON
+        && uniform_step(c_angles)
+            .is_some_and(|step| (step * nc as f64 - 360.0).abs() < ANGLE_EPSILON);
+    if !equidistant || !nc.is_multiple_of(2) {
+        return Symmetry::None;
+    }
+
+    // Plane i mirrors to -C (C0-C180) or 180° - C (C90-C270)
+    let mirrored =
+        |mirror: &dyn Fn(usize) -> usize| (0..nc).all(|i| same(&rows[i], &rows[mirror(i)]));
+    let c0_c180 = mirrored(&|i| (nc - i) % nc);
+    let c90_c270 = mirrored(&|i| (nc / 2 + nc - i) % nc);
+    match (c0_c180, c90_c270) {
+        (true, true) if nc.is_multiple_of(4) => Symmetry::BothPlanes,
+        (true, _) => Symmetry::PlaneC0C180,
+        _ => Symmetry::None,
+    }
 }
 
 #[cfg(test)]
@@ -670,6 +1069,85 @@ mod rotation_tests {
         assert!((ldt.luminous_area_width - 800.0).abs() < 0.01);
     }
 
+    fn create_c0_c180_ldt() -> Eulumdat {
+        // Isym=2, Nc=4: stored C0, C90, C180; C270 mirrors C90
+        Eulumdat {
+            symmetry: Symmetry::PlaneC0C180,
+            num_c_planes: 4,
+            c_plane_distance: 90.0,
+            num_g_planes: 3,
+            g_plane_distance: 45.0,
+            c_angles: vec![0.0, 90.0, 180.0, 270.0],
+            g_angles: vec![0.0, 45.0, 90.0],
+            intensities: vec![
+                vec![100.0, 80.0, 50.0],
+                vec![200.0, 160.0, 100.0],
+                vec![300.0, 240.0, 150.0],
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rotate_keeps_valid_symmetry() {
+        let mut ldt = create_c0_c180_ldt();
+        ldt.rotate_c_planes(180.0);
+        assert_eq!(ldt.symmetry, Symmetry::PlaneC0C180);
+        assert_eq!(ldt.intensities.len(), 3);
+        assert!((ldt.sample(0.0, 0.0) - 300.0).abs() < 0.01);
+        assert!((ldt.sample(90.0, 0.0) - 200.0).abs() < 0.01);
+        assert!((ldt.sample(180.0, 0.0) - 100.0).abs() < 0.01);
+
+        // A quarter turn moves the mirror plane to C90-C270
+        let mut ldt = create_c0_c180_ldt();
+        ldt.rotate_c_planes(90.0);
+        assert_eq!(ldt.symmetry, Symmetry::None);
+        assert!((ldt.sample(90.0, 0.0) - 100.0).abs() < 0.01);
+        assert!((ldt.sample(0.0, 0.0) - 200.0).abs() < 0.01);
+
+        // Rotationally symmetric data is unchanged
+        let mut ldt = Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            num_c_planes: 1,
+            c_angles: vec![0.0],
+            g_angles: vec![0.0, 90.0],
+            intensities: vec![vec![100.0, 50.0]],
+            ..Default::default()
+        };
+        ldt.rotate_c_planes(30.0);
+        assert_eq!(ldt.symmetry, Symmetry::VerticalAxis);
+        assert_eq!(ldt.intensities, vec![vec![100.0, 50.0]]);
+    }
+
+    #[test]
+    fn test_mirror() {
+        let original = create_asymmetric_ldt();
+        let mut ldt = original.clone();
+        ldt.mirror(MirrorPlane::C0C180);
+        assert!((ldt.sample(90.0, 0.0) - 400.0).abs() < 0.01);
+        assert!((ldt.sample(270.0, 45.0) - 160.0).abs() < 0.01);
+        assert!((ldt.sample(0.0, 0.0) - 100.0).abs() < 0.01);
+        assert_eq!((ldt.height_c90, ldt.height_c270), (40.0, 20.0));
+
+        ldt.mirror(MirrorPlane::C0C180);
+        assert_eq!(ldt.intensities, original.intensities);
+
+        ldt.mirror(MirrorPlane::C90C270);
+        assert!((ldt.sample(0.0, 0.0) - 300.0).abs() < 0.01);
+        assert!((ldt.sample(90.0, 0.0) - 200.0).abs() < 0.01);
+        assert_eq!((ldt.height_c0, ldt.height_c180), (30.0, 10.0));
+
+        // Mirroring C0-C180 data across its own plane is a no-op,
+        // across C90-C270 it stays C0-C180 symmetric
+        let mut sym = create_c0_c180_ldt();
+        sym.mirror(MirrorPlane::C0C180);
+        assert_eq!(sym.intensities, create_c0_c180_ldt().intensities);
+        sym.mirror(MirrorPlane::C90C270);
+        assert_eq!(sym.symmetry, Symmetry::PlaneC0C180);
+        assert!((sym.sample(0.0, 0.0) - 300.0).abs() < 0.01);
+        assert!((sym.sample(270.0, 0.0) - 200.0).abs() < 0.01);
+    }
+
     #[test]
     fn test_rotate_zero_is_noop() {
         let original = create_asymmetric_ldt();
@@ -682,4 +1160,165 @@ mod rotation_tests {
         assert_eq!(ldt.c_angles, original.c_angles);
         assert_eq!(ldt.intensities, original.intensities);
     }
+
+    #[test]
+    fn test_photometric_center() {
+        let mut ldt = create_asymmetric_ldt();
+        ldt.height = 100.0;
+        ldt.height_c0 = 20.0;
+        ldt.height_c90 = 20.0;
+        ldt.height_c180 = 20.0;
+        ldt.height_c270 = 20.0;
+
+        // Luminous area on the bottom face, 20 mm high
+        assert_eq!(
+            ldt.photometric_center(),
+            PhotometricCenter::new(0.0, 0.0, -40.0)
+        );
+        assert_eq!(
+            ldt.photometric_center_offset(),
+            PhotometricCenter::default()
+        );
+
+        ldt.photometric_center = Some(PhotometricCenter::new(150.0, 0.0, -40.0));
+        assert_eq!(
+            ldt.photometric_center_offset(),
+            PhotometricCenter::new(150.0, 0.0, 0.0)
+        );
+
+        // The explicit center turns and mirrors with the distribution
+        ldt.rotate_c_planes(90.0);
+        let center = ldt.photometric_center();
+        assert!(center.x.abs() < 1e-9 && (center.y - 150.0).abs() < 1e-9);
+        ldt.mirror(MirrorPlane::C0C180);
+        assert!((ldt.photometric_center().y + 150.0).abs() < 1e-9);
+    }
+}
+
+#[cfg(test)]
+mod resample_tests {
+    use super::*;
+    use crate::PhotometricCalculations;
+
+    #[test]
+    fn test_resample_to_regular_grid() {
+        let ldt = Eulumdat::parse(include_str!(
+            "../../eulumdat-wasm/templates/fluorescent_luminaire.ldt"
+        ))
+        .unwrap();
+        let mut dense = ldt.clone();
+        dense.resample(1.0, 1.0);
+
+        assert_eq!(dense.num_c_planes, 360);
+        assert_eq!(dense.c_plane_distance, 1.0);
+        assert_eq!(dense.num_g_planes, dense.g_angles.len());
+        assert_eq!(dense.g_angles.first(), ldt.g_angles.first());
+        assert_eq!(dense.g_angles.last(), ldt.g_angles.last());
+        assert_eq!(dense.symmetry, Symmetry::BothPlanes);
+        assert_eq!(dense.intensities.len(), 91);
+
+        // Original nodes on the new grid are preserved and the flux barely changes
+        for &c in ldt.c_angles.iter().filter(|c| c.fract() == 0.0) {
+            for &g in ldt.g_angles.iter().filter(|g| g.fract() == 0.0) {
+                assert!((dense.sample(c, g) - ldt.sample(c, g)).abs() < 1e-6);
+            }
+        }
+        let before = PhotometricCalculations::integrate_flux(&ldt).luminaire_flux;
+        let after = PhotometricCalculations::integrate_flux(&dense).luminaire_flux;
+        assert!(
+            (after - before).abs() / before < 0.01,
+            "{after} vs {before}"
+        );
+    }
+
+    #[test]
+    fn test_resample_deviation() {
+        let ldt = Eulumdat::parse(include_str!(
+            "../../eulumdat-wasm/templates/fluorescent_luminaire.ldt"
+        ))
+        .unwrap();
+        assert_eq!(ldt.deviation_from(&ldt), IntensityDeviation::default());
+
+        // A coarse grid misses intermediate nodes, a grid through them doesn't
+        let mut fine = ldt.clone();
+        fine.resample(1.0, 1.0);
+        let mut coarse = ldt.clone();
+        coarse.resample(30.0, 10.0);
+        let fine_error = fine.deviation_from(&ldt);
+        let coarse_error = coarse.deviation_from(&ldt);
+        assert!(coarse_error.max > fine_error.max);
+        assert!(coarse_error.rms > 0.0 && coarse_error.rms <= coarse_error.max);
+        assert!(
+            (coarse_error.max_percent - coarse_error.max / ldt.max_intensity() * 100.0).abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn test_resample_re_reduces_symmetry() {
+        // Full data that is symmetric to both planes
+        let c_angles: Vec<f64> = (0..8).map(|i| i as f64 * 45.0).collect();
+        let mut ldt = Eulumdat {
+            symmetry: Symmetry::None,
+            num_c_planes: 8,
+            c_plane_distance: 45.0,
+            num_g_planes: 3,
+            g_plane_distance: 45.0,
+            intensities: c_angles
+                .iter()
+                .map(|c: &f64| {
+                    let k = 100.0 + 50.0 * (2.0 * c.to_radians()).cos();
+                    vec![k, k * 0.5, 0.0]
+                })
+                .collect(),
+            c_angles,
+            g_angles: vec![0.0, 45.0, 90.0],
+            ..Default::default()
+        };
+        ldt.resample(15.0, 10.0);
+        assert_eq!(ldt.symmetry, Symmetry::BothPlanes);
+        assert_eq!(ldt.intensities.len(), 7);
+        assert_eq!(ldt.g_angles.len(), 10);
+        assert!((ldt.sample(270.0, 0.0) - 50.0).abs() < 1e-9);
+
+        // Uneven C-planes keep full data
+        ldt.resample_to(&[0.0, 10.0, 90.0, 180.0, 300.0], &[0.0, 90.0, 45.0]);
+        assert_eq!(ldt.symmetry, Symmetry::None);
+        assert_eq!(ldt.intensities.len(), 5);
+        assert_eq!(ldt.c_plane_distance, 0.0);
+        assert_eq!(ldt.g_angles, vec![0.0, 45.0, 90.0]);
+        assert_eq!(ldt.g_plane_distance, 45.0);
+
+        // Equal planes collapse to a single one
+        ldt.resample_to(&[0.0, 90.0], &[0.0, 90.0]);
+        assert_eq!(ldt.symmetry, Symmetry::None);
+        let mut round = Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            num_c_planes: 1,
+            c_angles: vec![0.0],
+            g_angles: vec![0.0, 90.0],
+            intensities: vec![vec![100.0, 50.0]],
+            ..Default::default()
+        };
+        round.resample(30.0, 5.0);
+        assert_eq!(round.symmetry, Symmetry::VerticalAxis);
+        assert_eq!(round.num_c_planes, 12);
+        assert_eq!(round.intensities.len(), 1);
+        assert_eq!(round.intensities[0].len(), 19);
+    }
+
+    #[test]
+    fn test_scale_beam_keeps_lor() {
+        let mut ldt = Eulumdat::parse(include_str!(
+            "../../eulumdat-wasm/templates/wiki-spotlight.ldt"
+        ))
+        .unwrap();
+        let beam = PhotometricCalculations::beam_angle(&ldt);
+        let lor = PhotometricCalculations::integrate_flux(&ldt).lor;
+
+        ldt.scale_beam(2.0);
+        let widened = PhotometricCalculations::beam_angle(&ldt);
+        assert!((widened / beam - 2.0).abs() < 0.1, "{beam} -> {widened}");
+        assert!((PhotometricCalculations::integrate_flux(&ldt).lor - lor).abs() < 1e-6);
+    }
 }
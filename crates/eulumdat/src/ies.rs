@@ -59,7 +59,9 @@ use std::path::Path;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{anyhow, Result};
-use crate::eulumdat::{Eulumdat, LampSet, Symmetry, TypeIndicator};
+use crate::eulumdat::{Eulumdat, LampSet, PhotometricCenter, Symmetry, TypeIndicator};
+use crate::repair::RepairReport;
+use crate::rounding::{NumericField, RoundingPolicy};
 use crate::symmetry::SymmetryHandler;
 
 /// IES file format parser.
@@ -399,6 +401,44 @@ pub struct TiltData {
     pub factors: Vec<f64>,
 }
 
+impl TiltData {
+    /// Multiplying factor at a luminaire tilt of `angle` degrees.
+    ///
+    /// Interpolates linearly between the tabulated angles and holds the
+    /// first/last factor outside them. Returns 1.0 without data.
+    pub fn multiplier_at(&self, angle: f64) -> f64 {
+        let mut points: Vec<(f64, f64)> = self
+            .angles
+            .iter()
+            .copied()
+            .zip(self.factors.iter().copied())
+            .collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let (Some(first), Some(last)) = (points.first(), points.last()) else {
+            return 1.0;
+        };
+        if angle <= first.0 {
+            return first.1;
+        }
+        if angle >= last.0 {
+            return last.1;
+        }
+        points
+            .windows(2)
+            .find(|w| angle <= w[1].0)
+            .map(|w| {
+                let ((a0, f0), (a1, f1)) = (w[0], w[1]);
+                if a1 > a0 {
+                    f0 + (f1 - f0) * (angle - a0) / (a1 - a0)
+                } else {
+                    f1
+                }
+            })
+            .unwrap_or(last.1)
+    }
+}
+
 /// Lamp position within luminaire (LM-63-2019 Annex E).
 #[derive(Debug, Clone, Copy, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -602,10 +642,17 @@ impl IesParser {
     /// Parse IES content with import options (e.g. C-plane rotation).
     pub fn parse_with_options(content: &str, options: &IesImportOptions) -> Result<Eulumdat> {
         let ies_data = Self::parse_ies_data(content)?;
+        let tilt_data = ies_data.tilt_data.clone();
         let mut ldt = Self::convert_to_eulumdat(ies_data)?;
         if options.rotate_c_planes.abs() > 0.001 {
             ldt.rotate_c_planes(options.rotate_c_planes);
         }
+        if let Some(tilt) = options.installed_tilt {
+            if let Some(tilt_data) = &tilt_data {
+                ldt.apply_tilt_multiplier(tilt_data, tilt);
+            }
+            ldt.tilt_distribution(tilt);
+        }
         Ok(ldt)
     }
 
@@ -993,6 +1040,14 @@ impl IesParser {
             })
             .collect();
 
+        // IES places the photometric center at the middle of the luminous
+        // opening; an offset from it is only carried by our user keyword,
+        // in the units of the file's dimensions
+        ldt.photometric_center = ies
+            .keywords
+            .get("_PHOTOMETRICCENTER")
+            .and_then(|value| Self::parse_photometric_center(value, mm_factor));
+
         // Photometric parameters
         ldt.conversion_factor = ies.multiplier;
         ldt.downward_flux_fraction =
@@ -1002,6 +1057,22 @@ impl IesParser {
         Ok(ldt)
     }
 
+    /// Parse the `x y z` value of a `[_PHOTOMETRICCENTER]` keyword into mm.
+    fn parse_photometric_center(value: &str, mm_factor: f64) -> Option<PhotometricCenter> {
+        let values: Vec<f64> = value
+            .split_whitespace()
+            .map(|v| v.parse().ok())
+            .collect::<Option<_>>()?;
+        match values[..] {
+            [x, y, z] => Some(PhotometricCenter::new(
+                x * mm_factor,
+                y * mm_factor,
+                z * mm_factor,
+            )),
+            _ => None,
+        }
+    }
+
     /// Detect symmetry type from horizontal angles.
     fn detect_symmetry(h_angles: &[f64]) -> Symmetry {
         if h_angles.is_empty() {
@@ -1091,12 +1162,17 @@ pub struct IesImportOptions {
     /// Rotate C-planes by this many degrees after import (default: 0.0).
     /// Set to 90.0 to convert IES C0 orientation → EULUMDAT C0 orientation.
     pub rotate_c_planes: f64,
+    /// Installed tilt of the luminaire in degrees (default: None).
+    /// Applies the `TILT` multipliers for this angle and tilts the
+    /// distribution, see [`Eulumdat::tilt_distribution`].
+    pub installed_tilt: Option<f64>,
 }
 
 impl Default for IesImportOptions {
     fn default() -> Self {
         Self {
             rotate_c_planes: 0.0,
+            installed_tilt: None,
         }
     }
 }
@@ -1117,6 +1193,11 @@ pub struct IesExportOptions {
     /// Rotate C-planes by this many degrees before export (default: 0.0).
     /// Set to -90.0 to convert EULUMDAT C0 orientation → IES C0 orientation.
     pub rotate_c_planes: f64,
+    /// Repairs to embed as a `[_REPAIRS]` keyword (default: None)
+    pub repair_report: Option<RepairReport>,
+    /// Precision of the numeric values (default: None, the LM-63 layout of
+    /// 2 decimals for angles and candela)
+    pub rounding: Option<RoundingPolicy>,
 }
 
 impl Default for IesExportOptions {
@@ -1128,6 +1209,8 @@ impl Default for IesExportOptions {
             file_gen_info: None,
             test_lab: None,
             rotate_c_planes: 0.0,
+            repair_report: None,
+            rounding: None,
         }
     }
 }
@@ -1185,15 +1268,18 @@ impl IesExporter {
                 .issue_date
                 .as_deref()
                 .filter(|s| !s.is_empty())
+                .map(str::to_string)
                 .unwrap_or_else(|| {
-                    if !ldt.date_user.is_empty() {
-                        &ldt.date_user
+                    if let Some(date) = ldt.measurement_date() {
+                        date.to_ies()
+                    } else if !ldt.date_user.is_empty() {
+                        ldt.date_user.clone()
                     } else {
                         // Default to current date if not provided
-                        "01-JAN-2025"
+                        "01-JAN-2025".to_string()
                     }
                 });
-            Self::write_keyword(&mut output, "ISSUEDATE", issue_date);
+            Self::write_keyword(&mut output, "ISSUEDATE", &issue_date);
         }
 
         // MANUFAC - required
@@ -1223,6 +1309,32 @@ impl IesExporter {
             }
         }
 
+        // Photometric center away from the middle of the luminous opening,
+        // in meters like the dimensions below
+        if let Some(center) = ldt.photometric_center {
+            Self::write_keyword(
+                &mut output,
+                "_PHOTOMETRICCENTER",
+                &format!(
+                    "{} {} {}",
+                    center.x / 1000.0,
+                    center.y / 1000.0,
+                    center.z / 1000.0
+                ),
+            );
+        }
+
+        // Repairs made by this tool, as a user keyword with [MORE] continuation
+        if let Some(report) = options.repair_report.as_ref().filter(|r| !r.is_empty()) {
+            let mut lines = report.comment_lines().into_iter();
+            if let Some(summary) = lines.next() {
+                Self::write_keyword(&mut output, "_REPAIRS", &summary);
+            }
+            for line in lines {
+                Self::write_keyword(&mut output, "MORE", &line);
+            }
+        }
+
         // TILT=NONE (most common)
         output.push_str("TILT=NONE\n");
 
@@ -1261,18 +1373,29 @@ impl IesExporter {
         // For IES output, num_lamps should always be positive (1 for absolute mode)
         let ies_num_lamps = num_lamps.abs().max(1);
 
+        // Numbers use the rounding policy if given, else the default LM-63 layout
+        let number =
+            |field: NumericField, legacy_decimals: usize, value: f64| match &options.rounding {
+                Some(policy) => policy.format_fixed(field, value),
+                None => format!("{:.*}", legacy_decimals, value),
+            };
+
         output.push_str(&format!(
-            "{} {:.1} {:.6} {} {} {} {} {:.4} {:.4} {:.4}\n",
+            "{} {} {} {} {} {} {} {} {} {}\n",
             ies_num_lamps,
-            lumens_per_lamp,
-            ldt.conversion_factor.max(1.0),
+            number(NumericField::Flux, 1, lumens_per_lamp),
+            number(
+                NumericField::ConversionFactor,
+                6,
+                ldt.conversion_factor.max(1.0)
+            ),
             v_angles.len(),
             h_angles.len(),
             photometric_type,
             units_type,
-            width,
-            length,
-            height
+            number(NumericField::Dimension, 4, width),
+            number(NumericField::Dimension, 4, length),
+            number(NumericField::Dimension, 4, height)
         ));
 
         // Line 2: Ballast factor, file generation type (LM-63-2019) or ballast-lamp factor, input watts
@@ -1282,14 +1405,20 @@ impl IesExporter {
         } else {
             1.0 // Legacy ballast-lamp photometric factor
         };
-        output.push_str(&format!("1.0 {:.5} {:.1}\n", file_gen_value, total_watts));
+        output.push_str(&format!(
+            "1.0 {:.5} {}\n",
+            file_gen_value,
+            number(NumericField::Wattage, 1, total_watts)
+        ));
+
+        let angle = |v: f64| number(NumericField::Angle, 2, v);
 
         // Vertical angles
-        output.push_str(&Self::format_values_multiline(&v_angles, 10));
+        output.push_str(&Self::format_values_multiline(&v_angles, 10, angle));
         output.push('\n');
 
         // Horizontal angles
-        output.push_str(&Self::format_values_multiline(&h_angles, 10));
+        output.push_str(&Self::format_values_multiline(&h_angles, 10, angle));
         output.push('\n');
 
         // Candela values for each horizontal angle
@@ -1297,7 +1426,9 @@ impl IesExporter {
         let cdklm_to_cd = total_flux / 1000.0;
         for row in &intensities {
             let absolute_candela: Vec<f64> = row.iter().map(|&v| v * cdklm_to_cd).collect();
-            output.push_str(&Self::format_values_multiline(&absolute_candela, 10));
+            output.push_str(&Self::format_values_multiline(&absolute_candela, 10, |v| {
+                number(NumericField::Intensity, 2, v)
+            }));
             output.push('\n');
         }
 
@@ -1371,13 +1502,17 @@ impl IesExporter {
     }
 
     /// Format values with line wrapping.
-    fn format_values_multiline(values: &[f64], per_line: usize) -> String {
+    fn format_values_multiline(
+        values: &[f64],
+        per_line: usize,
+        format: impl Fn(f64) -> String,
+    ) -> String {
         values
             .chunks(per_line)
             .map(|chunk| {
                 chunk
                     .iter()
-                    .map(|&v| format!("{:.2}", v))
+                    .map(|&v| format(v))
                     .collect::<Vec<_>>()
                     .join(" ")
             })
@@ -1425,6 +1560,10 @@ mod tests {
         assert!(ies.contains("[ISSUEDATE]")); // Required in 2019
         assert!(ies.contains("TILT=NONE"));
 
+        ldt.date_user = "2024-01-15".to_string();
+        let ies = IesExporter::export(&ldt);
+        assert!(ies.contains("[ISSUEDATE] 15-JAN-2024"));
+
         // Test legacy 2002 export
         let ies_2002 = IesExporter::export_2002(&ldt);
         assert!(ies_2002.contains("IESNA:LM-63-2002"));
@@ -1486,6 +1625,45 @@ TILT=NONE
         assert_eq!(parsed.luminaire_name, ldt.luminaire_name);
         assert_eq!(parsed.g_angles.len(), ldt.g_angles.len());
         assert_eq!(parsed.symmetry, Symmetry::VerticalAxis);
+        assert_eq!(parsed.photometric_center, None);
+        assert!(!ies.contains("[_PHOTOMETRICCENTER]"));
+    }
+
+    #[test]
+    fn test_photometric_center_roundtrip() {
+        let mut ldt = Eulumdat::new();
+        ldt.symmetry = Symmetry::VerticalAxis;
+        ldt.c_angles = vec![0.0];
+        ldt.g_angles = vec![0.0, 90.0];
+        ldt.intensities = vec![vec![500.0, 200.0]];
+        ldt.lamp_sets.push(LampSet {
+            num_lamps: 1,
+            total_luminous_flux: 1000.0,
+            ..Default::default()
+        });
+        ldt.photometric_center = Some(PhotometricCenter::new(150.0, 0.0, -40.0));
+
+        let ies = IesExporter::export(&ldt);
+        assert!(ies.contains("[_PHOTOMETRICCENTER] 0.15 0 -0.04"));
+        let parsed = IesParser::parse(&ies).unwrap();
+        let center = parsed.photometric_center.unwrap();
+        assert!((center.x - 150.0).abs() < 1e-9);
+        assert!(center.y.abs() < 1e-9);
+        assert!((center.z + 40.0).abs() < 1e-9);
+
+        // In feet, like the dimensions of the file
+        let feet = ies
+            .replace(
+                "[_PHOTOMETRICCENTER] 0.15 0 -0.04",
+                "[_PHOTOMETRICCENTER] 1 0 0",
+            )
+            .replacen(" 1 2 0.0000", " 1 1 0.0000", 1); // units: type C, feet
+        let parsed = IesParser::parse(&feet).unwrap();
+        let center = parsed.photometric_center.unwrap();
+        assert!((center.x - 304.8).abs() < 1e-9, "{center:?}");
+
+        let broken = ies.replace("0.15 0 -0.04", "0.15 0");
+        assert_eq!(IesParser::parse(&broken).unwrap().photometric_center, None);
     }
 
     #[test]
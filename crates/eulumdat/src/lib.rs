@@ -33,8 +33,16 @@
 //! - **Symmetry handling** - 5 symmetry types with automatic data expansion
 //! - **Photometric calculations** - Downward flux, beam angles, utilization factors
 //! - **BUG Rating** - IESNA TM-15-11 Backlight-Uplight-Glare calculations
+//! - **Road lighting** - EN 13201-3 calculation grid with luminance and uniformities
 //! - **Diagram generation** - Platform-independent data for visualizations
 //!
+//! ## API Stability
+//!
+//! [`prelude`] re-exports the stable subset of the API (data model, IES
+//! import/export, validation, summary calculations and the main diagrams),
+//! which follows semver. Integrations that only need these should import
+//! `eulumdat::prelude::*`; everything else may change in minor releases.
+//!
 //! ## EULUMDAT File Structure
 //!
 //! The EULUMDAT format is a plain ASCII text file with the following structure:
@@ -166,51 +174,81 @@
 //! - [IESNA LM-63-2002 Standard](https://docs.agi32.com/PhotometricToolbox/Content/Open_Tool/iesna_lm-63_format.htm)
 //! - [IES TM-15-11 BUG Ratings](https://www.ies.org/wp-content/uploads/2017/03/TM-15-11BUGRatingsAddendum.pdf)
 
+pub mod aggregate;
 pub mod area;
 pub mod batch;
 pub mod bug_rating;
 mod calculations;
+pub mod compact;
 pub mod compare;
+pub mod date;
 pub mod diagram;
+pub mod dimming;
 mod error;
 mod eulumdat;
 mod ies;
 pub mod iesna_classification;
 pub mod interpolate;
+pub mod metadata;
 mod parser;
+pub mod prelude;
+pub mod repair;
+pub mod road;
+pub mod rounding;
 pub mod scene3d;
 mod symmetry;
+mod tilt;
 pub mod type_b_conversion;
 pub mod units;
 mod validation;
 mod writer;
 pub mod zonal;
 
+pub use aggregate::{Aggregate, AggregateMember};
 pub use batch::{BatchInput, BatchOutput, BatchStats, ConversionFormat, InputFormat};
-pub use bug_rating::{BugDiagram, BugRating, LcsZonePercentages, LightingZone, ZoneLumens};
+pub use bug_rating::{
+    BugDiagram, BugRating, BugRatingReason, LcsView, LcsZonePercentages, LightingZone, ZoneLumens,
+};
+pub use calculations::integration::{self, Zone};
+pub use calculations::leni::{self, LeniParams, LeniResult, OperatingProfile};
+pub use calculations::luminance::{self, LuminanceTable};
+pub use calculations::statistics::{HemisphereStatistics, IntensityStatistics};
 pub use calculations::{
-    BeamFieldAnalysis, CandelaEntry, CandelaTabulation, CieFluxCodes, ComprehensiveBeamAnalysis,
-    CuTable, DistributionType, GldfPhotometricData, IesMetadata, LightDirection,
-    NemaClassification, PhotometricCalculations, PhotometricSummary, UgrParams, UgrTable,
-    UgrTableValues, ZonalLumens30, CU_RCR_VALUES, CU_REFLECTANCES, UGR_REFLECTANCES,
-    UGR_ROOM_SIZES,
+    AsymmetricBeam, BatwingAnalysis, BatwingPeak, BeamFieldAnalysis, CandelaEntry,
+    CandelaTabulation, CieFluxCodes, ComprehensiveBeamAnalysis, CuTable, DistributionType,
+    FluxIntegration, GldfPhotometricData, IesMetadata, LightDirection, NemaClassification,
+    PhotometricCalculations, PhotometricSummary, SpacingCriteria, SummaryRecord, UfTable,
+    UgrParams, UgrTable, UgrTableValues, ZonalLumens30, CU_RCR_VALUES, CU_REFLECTANCES,
+    UF_REFLECTANCES, UF_ROOM_INDICES, UGR_REFLECTANCES, UGR_ROOM_SIZES,
 };
-pub use compare::{ComparisonMetric, PhotometricComparison, Significance};
+pub use compare::{
+    CatalogOverview, ComparisonMetric, OverviewColumn, OverviewRow, PhotometricComparison,
+    Significance,
+};
+pub use date::MeasurementDate;
+pub use dimming::DriverCurve;
 pub use iesna_classification::{
     classify as iesna_classify, Applicability as IesnaApplicability, CutoffClass,
     IesnaClassification, LateralType, LongitudinalClass,
 };
 // i18n re-exports for comparison are available via PhotometricComparison methods
 pub use error::{Error, Result};
-pub use eulumdat::{Eulumdat, LampSet, Symmetry, TypeIndicator};
+pub use eulumdat::{
+    Eulumdat, IntensityDeviation, LampSet, MirrorPlane, PhotometricCenter, Symmetry, TypeIndicator,
+};
 pub use ies::{
     validate_ies, validate_ies_strict, FileGenerationType, IesData, IesExportOptions, IesExporter,
     IesImportOptions, IesParser, IesValidationSeverity, IesValidationWarning, IesVersion,
     LampPosition, LuminousShape, PhotometricType, TiltData, UnitType,
 };
-pub use symmetry::SymmetryHandler;
+pub use metadata::{CatalogEntry, CatalogTable, MetadataLookup};
+pub use repair::{Repair, RepairReport};
+pub use rounding::{NumericField, RoundingPolicy};
+pub use symmetry::{Interpolation, SymmetryHandler};
 pub use type_b_conversion::TypeBConversion;
 pub use units::UnitSystem;
-pub use validation::{validate, validate_strict, ValidationError, ValidationWarning};
+pub use validation::{
+    validate, validate_strict, ValidationError, ValidationWarning, FLUX_CONSISTENCY_TOLERANCE,
+};
 #[cfg(feature = "i18n")]
 pub use validation::{validate_strict_with_locale, validate_with_locale};
@@ -0,0 +1,272 @@
+//! Catalog metadata enrichment.
+//!
+//! Files from different sources spell the same manufacturer in many ways
+//! ("ACME", "Acme Lighting GmbH", "acme lighting") and rarely name the
+//! product family. A [`MetadataLookup`] maps a parsed file to a canonical
+//! [`CatalogEntry`], usually by its catalog number, and [`enrich`] writes
+//! the result back into the file.
+//!
+//! [`CatalogTable`] loads the mapping from a CSV export; other sources
+//! (SQLite, a web service) can implement the trait directly or be passed
+//! as a closure.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use eulumdat::{metadata, CatalogTable, Eulumdat};
+//!
+//! let table = CatalogTable::from_csv(&std::fs::read_to_string("catalog.csv").unwrap()).unwrap();
+//! let mut ldt = Eulumdat::from_file("luminaire.ldt").unwrap();
+//! if let Some(entry) = metadata::enrich(&mut ldt, &table) {
+//!     println!("{}: {:?}", ldt.identification, entry.product_family);
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::error::{parse_error, Result};
+use crate::eulumdat::Eulumdat;
+
+/// Canonical metadata for one catalog number.
+///
+/// `None` fields leave the file unchanged.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CatalogEntry {
+    /// Canonical manufacturer name (written to the identification line)
+    pub manufacturer: Option<String>,
+    /// Product family the luminaire belongs to
+    pub product_family: Option<String>,
+    /// Canonical luminaire name
+    pub luminaire_name: Option<String>,
+}
+
+impl CatalogEntry {
+    /// Write the manufacturer and luminaire name into `ldt`.
+    ///
+    /// Returns the names of the fields that changed.
+    pub fn apply(&self, ldt: &mut Eulumdat) -> Vec<&'static str> {
+        let mut changed = Vec::new();
+        for (field, target, value) in [
+            (
+                "identification",
+                &mut ldt.identification,
+                &self.manufacturer,
+            ),
+            (
+                "luminaire_name",
+                &mut ldt.luminaire_name,
+                &self.luminaire_name,
+            ),
+        ] {
+            if let Some(value) = value.as_deref().filter(|v| *v != target.as_str()) {
+                *target = value.to_string();
+                changed.push(field);
+            }
+        }
+        changed
+    }
+}
+
+/// Source of canonical metadata for parsed files.
+pub trait MetadataLookup {
+    /// Metadata for `ldt`, or `None` if the file is unknown.
+    fn lookup(&self, ldt: &Eulumdat) -> Option<CatalogEntry>;
+}
+
+impl<F> MetadataLookup for F
+where
+    F: Fn(&Eulumdat) -> Option<CatalogEntry>,
+{
+    fn lookup(&self, ldt: &Eulumdat) -> Option<CatalogEntry> {
+        self(ldt)
+    }
+}
+
+/// Look up `ldt` and apply the result.
+///
+/// Returns the entry that was found, so callers can use fields that have no
+/// place in the file (such as the product family).
+pub fn enrich(ldt: &mut Eulumdat, lookup: &dyn MetadataLookup) -> Option<CatalogEntry> {
+    let entry = lookup.lookup(ldt)?;
+    entry.apply(ldt);
+    Some(entry)
+}
+
+/// In-memory mapping from catalog numbers to metadata.
+///
+/// Catalog numbers are compared case-insensitively and ignoring whitespace,
+/// so `"ab 123-x"` matches `"AB123-X"`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CatalogTable {
+    entries: HashMap<String, CatalogEntry>,
+}
+
+impl CatalogTable {
+    /// Create an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace the entry for a catalog number.
+    pub fn insert(&mut self, catalog_number: &str, entry: CatalogEntry) {
+        self.entries.insert(normalize_key(catalog_number), entry);
+    }
+
+    /// Entry for a catalog number.
+    pub fn get(&self, catalog_number: &str) -> Option<&CatalogEntry> {
+        self.entries.get(&normalize_key(catalog_number))
+    }
+
+    /// Number of catalog numbers in the table.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the table is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Load a table from CSV.
+    ///
+    /// Columns: `catalog_number, manufacturer, product_family[, luminaire_name]`,
+    /// separated by `,` or `;`. Fields may be double-quoted; empty fields
+    /// become `None`. A first line starting with `catalog` is treated as a
+    /// header, blank lines and lines starting with `#` are skipped.
+    pub fn from_csv(content: &str) -> Result<Self> {
+        let mut table = Self::new();
+        for (i, line) in content.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            if i == 0 && trimmed.to_lowercase().starts_with("catalog") {
+                continue;
+            }
+
+            let fields = split_csv_line(trimmed);
+            if fields.len() < 3 {
+                return Err(parse_error(
+                    i + 1,
+                    format!("expected at least 3 columns, found {}", fields.len()),
+                ));
+            }
+            if fields[0].is_empty() {
+                return Err(parse_error(i + 1, "empty catalog number"));
+            }
+            let field = |n: usize| fields.get(n).filter(|f| !f.is_empty()).cloned();
+            table.insert(
+                &fields[0],
+                CatalogEntry {
+                    manufacturer: field(1),
+                    product_family: field(2),
+                    luminaire_name: field(3),
+                },
+            );
+        }
+        Ok(table)
+    }
+}
+
+impl MetadataLookup for CatalogTable {
+    /// Look up the file by its luminaire (catalog) number.
+    fn lookup(&self, ldt: &Eulumdat) -> Option<CatalogEntry> {
+        self.get(&ldt.luminaire_number).cloned()
+    }
+}
+
+fn normalize_key(catalog_number: &str) -> String {
+    catalog_number
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// Split one CSV line on `,` or `;`, honouring double quotes.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                current.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' | ';' if !quoted => fields.push(std::mem::take(&mut current).trim().to_string()),
+            _ => current.push(ch),
+        }
+    }
+    fields.push(current.trim().to_string());
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CSV: &str = "catalog_number,manufacturer,product_family,luminaire_name
+AB-123,ACME Lighting GmbH,Downlights,\"ACME Down 123, white\"
+# discontinued
+CD 456;ACME Lighting GmbH;Panels;
+";
+
+    #[test]
+    fn test_csv_table() {
+        let table = CatalogTable::from_csv(CSV).unwrap();
+        assert_eq!(table.len(), 2);
+
+        let entry = table.get("ab-123").unwrap();
+        assert_eq!(entry.product_family.as_deref(), Some("Downlights"));
+        assert_eq!(
+            entry.luminaire_name.as_deref(),
+            Some("ACME Down 123, white")
+        );
+        assert_eq!(table.get("CD456").unwrap().luminaire_name, None);
+
+        assert!(CatalogTable::from_csv("AB-123,ACME").is_err());
+    }
+
+    #[test]
+    fn test_enrich_applies_canonical_names() {
+        let table = CatalogTable::from_csv(CSV).unwrap();
+        let mut ldt = Eulumdat {
+            identification: "acme".to_string(),
+            luminaire_name: "Panel".to_string(),
+            luminaire_number: "cd 456".to_string(),
+            ..Default::default()
+        };
+
+        let entry = enrich(&mut ldt, &table).unwrap();
+        assert_eq!(entry.product_family.as_deref(), Some("Panels"));
+        assert_eq!(ldt.identification, "ACME Lighting GmbH");
+        // No canonical name in the table: the file keeps its own
+        assert_eq!(ldt.luminaire_name, "Panel");
+
+        ldt.luminaire_number = "unknown".to_string();
+        assert!(enrich(&mut ldt, &table).is_none());
+    }
+
+    #[test]
+    fn test_closure_lookup() {
+        let lookup = |ldt: &Eulumdat| {
+            ldt.luminaire_name
+                .starts_with("Street")
+                .then(|| CatalogEntry {
+                    product_family: Some("Outdoor".to_string()),
+                    ..Default::default()
+                })
+        };
+        let mut ldt = Eulumdat {
+            luminaire_name: "Street 40W".to_string(),
+            ..Default::default()
+        };
+        let entry = enrich(&mut ldt, &lookup).unwrap();
+        assert_eq!(entry.product_family.as_deref(), Some("Outdoor"));
+        assert!(entry.apply(&mut ldt).is_empty());
+    }
+}
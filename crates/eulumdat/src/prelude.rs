@@ -0,0 +1,39 @@
+//! Stable API for downstream integrations.
+//!
+//! The crate root exports everything the workspace tools use, and much of
+//! it changes between minor releases. The prelude is the subset covered by
+//! semver: items re-exported here keep their names, paths and signatures
+//! until the next major version. CI diffs the public API against the latest
+//! published release with `cargo-public-api` and fails on removed or changed
+//! prelude items (see `scripts/check-public-api.sh`).
+//!
+//! Options structs in the prelude may gain fields in minor releases, so
+//! construct them with `..Default::default()`.
+//!
+//! ```rust,no_run
+//! use eulumdat::prelude::*;
+//!
+//! let ldt = Eulumdat::from_file("luminaire.ldt")?;
+//! let summary = PhotometricSummary::from_eulumdat(&ldt);
+//! println!("{} lm, beam {:.0}°", summary.total_lamp_flux, summary.beam_angle);
+//!
+//! let ies = IesExporter::export_with_options(
+//!     &ldt,
+//!     &IesExportOptions {
+//!         version: IesVersion::Lm63_2002,
+//!         ..Default::default()
+//!     },
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+pub use crate::bug_rating::{BugDiagram, BugRating};
+pub use crate::calculations::{GldfPhotometricData, PhotometricCalculations, PhotometricSummary};
+pub use crate::diagram::{
+    ButterflyDiagram, CartesianDiagram, HeatmapDiagram, PolarDiagram, SvgTheme,
+};
+pub use crate::error::{Error, Result};
+pub use crate::eulumdat::{Eulumdat, LampSet, Symmetry, TypeIndicator};
+pub use crate::ies::{IesExportOptions, IesExporter, IesImportOptions, IesParser, IesVersion};
+pub use crate::symmetry::Interpolation;
+pub use crate::validation::{ValidationError, ValidationWarning};
@@ -0,0 +1,431 @@
+//! Repair reports for auto-fixed photometric data.
+//!
+//! When data is repaired before export, recipients should be able to tell
+//! that the file no longer matches the original measurement. A
+//! [`RepairReport`] records every change and can be embedded in the output:
+//!
+//! - LDT: a short note is appended to the measurement report field
+//!   ([`RepairReport::annotate_ldt`]), keeping the 80 character line limit.
+//! - IES: a `[_REPAIRS]` user keyword with one `[MORE]` line per repair
+//!   (set [`IesExportOptions::repair_report`](crate::IesExportOptions)).
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use eulumdat::{Eulumdat, RepairReport};
+//!
+//! let mut ldt = Eulumdat::from_file("broken.ldt").unwrap();
+//! let report = RepairReport::auto_fix(&mut ldt);
+//! if !report.is_empty() {
+//!     report.annotate_ldt(&mut ldt);
+//! }
+//! ldt.save("fixed.ldt").unwrap();
+//! ```
+
+use crate::calculations::PhotometricCalculations;
+use crate::eulumdat::Eulumdat;
+
+/// Maximum length of an LDT text line (see validation W007–W011).
+const MAX_LDT_LINE_LENGTH: usize = 80;
+
+/// Maximum length of an LDT lamp text field (see validation W028–W030).
+const MAX_LAMP_TEXT_LENGTH: usize = 40;
+
+/// A single change made to the photometric data.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Repair {
+    /// Name of the repaired field (e.g. `num_g_planes`, `intensities`)
+    pub field: String,
+    /// What was changed
+    pub description: String,
+}
+
+impl std::fmt::Display for Repair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.description)
+    }
+}
+
+/// All changes made to a file by lenient parsing or auto-fixing.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RepairReport {
+    /// Repairs in the order they were applied
+    pub repairs: Vec<Repair>,
+}
+
+impl RepairReport {
+    /// Create an empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a repair.
+    pub fn push(&mut self, field: impl Into<String>, description: impl Into<String>) {
+        self.repairs.push(Repair {
+            field: field.into(),
+            description: description.into(),
+        });
+    }
+
+    /// Whether no repairs were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.repairs.is_empty()
+    }
+
+    /// Number of recorded repairs.
+    pub fn len(&self) -> usize {
+        self.repairs.len()
+    }
+
+    /// Fix common structural problems in place and report what was changed.
+    ///
+    /// - plane counts that disagree with the stored angles
+    /// - intensity rows with missing or extra values (padded with 0 / truncated)
+    /// - negative intensities (set to 0)
+    /// - DFF and LOR outside 0–100 % (clamped)
+    /// - a non-positive conversion factor (set to 1)
+    pub fn auto_fix(ldt: &mut Eulumdat) -> Self {
+        let mut report = Self::new();
+
+        if !ldt.c_angles.is_empty() && ldt.num_c_planes != ldt.c_angles.len() {
+            report.push(
+                "num_c_planes",
+                format!(
+                    "{} -> {} (C-angle count)",
+                    ldt.num_c_planes,
+                    ldt.c_angles.len()
+                ),
+            );
+            ldt.num_c_planes = ldt.c_angles.len();
+        }
+
+        if !ldt.g_angles.is_empty() && ldt.num_g_planes != ldt.g_angles.len() {
+            report.push(
+                "num_g_planes",
+                format!(
+                    "{} -> {} (G-angle count)",
+                    ldt.num_g_planes,
+                    ldt.g_angles.len()
+                ),
+            );
+            ldt.num_g_planes = ldt.g_angles.len();
+        }
+
+        let ng = ldt.g_angles.len();
+        let mut resized = 0;
+        let mut negative = 0;
+        for row in &mut ldt.intensities {
+            if ng > 0 && row.len() != ng {
+                row.resize(ng, 0.0);
+                resized += 1;
+            }
+            for value in row.iter_mut().filter(|v| **v < 0.0) {
+                *value = 0.0;
+                negative += 1;
+            }
+        }
+        if resized > 0 {
+            report.push(
+                "intensities",
+                format!("{} rows resized to {} values", resized, ng),
+            );
+        }
+        if negative > 0 {
+            report.push(
+                "intensities",
+                format!("{} negative values set to 0", negative),
+            );
+        }
+
+        for (field, value) in [
+            ("downward_flux_fraction", &mut ldt.downward_flux_fraction),
+            ("light_output_ratio", &mut ldt.light_output_ratio),
+        ] {
+            let clamped = value.clamp(0.0, 100.0);
+            if clamped != *value {
+                report.push(field, format!("{} -> {}", value, clamped));
+                *value = clamped;
+            }
+        }
+
+        if ldt.conversion_factor <= 0.0 {
+            report.push(
+                "conversion_factor",
+                format!("{} -> 1", ldt.conversion_factor),
+            );
+            ldt.conversion_factor = 1.0;
+        }
+
+        report
+    }
+
+    /// Apply the safe corrections for validation warnings and report them.
+    ///
+    /// Runs [`auto_fix`](Self::auto_fix) first, then fixes what
+    /// [`Eulumdat::validate`] still reports:
+    ///
+    /// - W048/W049: LOR and DFF recomputed from the intensities, when the
+    ///   integrated value is a valid percentage
+    /// - W031: direct ratios clamped to 0–1
+    /// - W007–W011, W028–W030: text fields truncated to their LDT limit
+    ///
+    /// Warnings without a safe correction (e.g. unsorted angles) are left
+    /// alone; validate again to list them.
+    pub fn fix_validation(ldt: &mut Eulumdat) -> Self {
+        let mut report = Self::auto_fix(ldt);
+        let codes: Vec<&str> = ldt.validate().iter().map(|w| w.code).collect();
+
+        if codes.iter().any(|code| matches!(*code, "W048" | "W049")) {
+            let flux = PhotometricCalculations::integrate_flux(ldt);
+            for (code, field, value, integrated) in [
+                (
+                    "W048",
+                    "light_output_ratio",
+                    &mut ldt.light_output_ratio,
+                    flux.lor,
+                ),
+                (
+                    "W049",
+                    "downward_flux_fraction",
+                    &mut ldt.downward_flux_fraction,
+                    flux.dff,
+                ),
+            ] {
+                // Above 100 % the intensities themselves are off, not the header
+                if codes.contains(&code) && integrated <= 100.0 {
+                    let integrated = (integrated * 10.0).round() / 10.0;
+                    report.push(
+                        field,
+                        format!(
+                            "{} -> {} (integrated from intensities, {})",
+                            value, integrated, code
+                        ),
+                    );
+                    *value = integrated;
+                }
+            }
+        }
+
+        if codes.contains(&"W031") {
+            for (i, ratio) in ldt.direct_ratios.iter_mut().enumerate() {
+                let clamped = ratio.clamp(0.0, 1.0);
+                if clamped != *ratio {
+                    report.push(
+                        "direct_ratios",
+                        format!("ratio {}: {} -> {} (W031)", i + 1, ratio, clamped),
+                    );
+                    *ratio = clamped;
+                }
+            }
+        }
+
+        for (field, value) in [
+            (
+                "measurement_report_number",
+                &mut ldt.measurement_report_number,
+            ),
+            ("luminaire_name", &mut ldt.luminaire_name),
+            ("luminaire_number", &mut ldt.luminaire_number),
+            ("file_name", &mut ldt.file_name),
+            ("date_user", &mut ldt.date_user),
+        ] {
+            truncate_text(&mut report, field, value, MAX_LDT_LINE_LENGTH);
+        }
+        for (i, lamp_set) in ldt.lamp_sets.iter_mut().enumerate() {
+            for (field, value) in [
+                ("lamp_type", &mut lamp_set.lamp_type),
+                ("color_appearance", &mut lamp_set.color_appearance),
+                ("color_rendering_group", &mut lamp_set.color_rendering_group),
+            ] {
+                let field = format!("lamp_sets[{}].{}", i, field);
+                truncate_text(&mut report, &field, value, MAX_LAMP_TEXT_LENGTH);
+            }
+        }
+
+        report
+    }
+
+    /// One-line summary naming the tool and the repaired fields.
+    pub fn summary(&self) -> String {
+        let mut fields: Vec<&str> = Vec::new();
+        for repair in &self.repairs {
+            if !fields.contains(&repair.field.as_str()) {
+                fields.push(&repair.field);
+            }
+        }
+        format!(
+            "Modified by eulumdat-rs {}: {} repair(s) ({})",
+            env!("CARGO_PKG_VERSION"),
+            self.len(),
+            fields.join(", ")
+        )
+    }
+
+    /// Summary followed by one line per repair.
+    pub fn comment_lines(&self) -> Vec<String> {
+        std::iter::once(self.summary())
+            .chain(self.repairs.iter().map(|r| r.to_string()))
+            .collect()
+    }
+
+    /// Append the summary to the measurement report field of an LDT.
+    ///
+    /// The field is truncated to the 80 character LDT line limit. Does
+    /// nothing when the report is empty.
+    pub fn annotate_ldt(&self, ldt: &mut Eulumdat) {
+        if self.is_empty() {
+            return;
+        }
+        let note = if ldt.measurement_report_number.trim().is_empty() {
+            self.summary()
+        } else {
+            format!(
+                "{}; {}",
+                ldt.measurement_report_number.trim(),
+                self.summary()
+            )
+        };
+        ldt.measurement_report_number = note.chars().take(MAX_LDT_LINE_LENGTH).collect();
+    }
+}
+
+/// Truncate `value` to at most `max` bytes (the limit validation checks),
+/// keeping whole characters.
+fn truncate_text(report: &mut RepairReport, field: &str, value: &mut String, max: usize) {
+    if value.len() <= max {
+        return;
+    }
+    let end = value
+        .char_indices()
+        .map(|(i, c)| i + c.len_utf8())
+        .take_while(|&end| end <= max)
+        .last()
+        .unwrap_or(0);
+    report.push(
+        field,
+        format!(
+            "truncated from {} to {} characters",
+            value.chars().count(),
+            value[..end].chars().count()
+        ),
+    );
+    value.truncate(end);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IesExportOptions, IesExporter, IesParser, LampSet, Symmetry};
+
+    fn broken_ldt() -> Eulumdat {
+        Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            num_c_planes: 1,
+            num_g_planes: 5,
+            c_angles: vec![0.0],
+            g_angles: vec![0.0, 45.0, 90.0],
+            intensities: vec![vec![100.0, -5.0]],
+            light_output_ratio: 120.0,
+            downward_flux_fraction: 100.0,
+            conversion_factor: 0.0,
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 1000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_auto_fix_records_changes() {
+        let mut ldt = broken_ldt();
+        let report = RepairReport::auto_fix(&mut ldt);
+
+        assert_eq!(ldt.num_g_planes, 3);
+        assert_eq!(ldt.intensities[0], vec![100.0, 0.0, 0.0]);
+        assert_eq!(ldt.light_output_ratio, 100.0);
+        assert_eq!(ldt.conversion_factor, 1.0);
+        let fields: Vec<&str> = report.repairs.iter().map(|r| r.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "num_g_planes",
+                "intensities",
+                "intensities",
+                "light_output_ratio",
+                "conversion_factor"
+            ]
+        );
+
+        // A second pass has nothing left to fix
+        assert!(RepairReport::auto_fix(&mut ldt).is_empty());
+    }
+
+    #[test]
+    fn test_annotate_ldt_respects_line_limit() {
+        let mut ldt = broken_ldt();
+        ldt.measurement_report_number = "LAB-2024-0815".to_string();
+        let report = RepairReport::auto_fix(&mut ldt);
+        report.annotate_ldt(&mut ldt);
+
+        assert!(ldt
+            .measurement_report_number
+            .starts_with("LAB-2024-0815; Modified by"));
+        assert!(ldt.measurement_report_number.chars().count() <= MAX_LDT_LINE_LENGTH);
+
+        let reparsed = Eulumdat::parse(&ldt.to_ldt()).unwrap();
+        assert_eq!(
+            reparsed.measurement_report_number,
+            ldt.measurement_report_number
+        );
+    }
+
+    #[test]
+    fn test_ies_export_embeds_repairs() {
+        let mut ldt = broken_ldt();
+        let report = RepairReport::auto_fix(&mut ldt);
+        let options = IesExportOptions {
+            repair_report: Some(report.clone()),
+            ..Default::default()
+        };
+        let ies = IesExporter::export_with_options(&ldt, &options);
+        assert_eq!(ies.matches("[MORE] ").count(), report.len());
+
+        let data = IesParser::parse_to_ies_data(&ies).unwrap();
+        let value = &data.keywords["_REPAIRS"];
+        assert!(value.starts_with("Modified by eulumdat-rs"));
+        assert!(value.contains("conversion_factor: 0 -> 1"));
+    }
+
+    #[test]
+    fn test_fix_validation_clears_fixable_warnings() {
+        let mut ldt = Eulumdat::parse(include_str!(
+            "../../eulumdat-wasm/templates/fluorescent_luminaire.ldt"
+        ))
+        .unwrap();
+        ldt.light_output_ratio = 80.0;
+        ldt.direct_ratios[2] = 1.5;
+        ldt.luminaire_name = "x".repeat(90);
+        ldt.lamp_sets[0].lamp_type = "Lämp".repeat(12);
+
+        let report = RepairReport::fix_validation(&mut ldt);
+        let fields: Vec<&str> = report.repairs.iter().map(|r| r.field.as_str()).collect();
+        assert!(fields.contains(&"light_output_ratio"));
+        assert!(fields.contains(&"direct_ratios"));
+        assert!(fields.contains(&"luminaire_name"));
+        assert!(fields.contains(&"lamp_sets[0].lamp_type"));
+
+        let codes: Vec<&str> = ldt.validate().iter().map(|w| w.code).collect();
+        for code in ["W008", "W028", "W031", "W048"] {
+            assert!(!codes.contains(&code), "{} still reported", code);
+        }
+        assert_eq!(ldt.direct_ratios[2], 1.0);
+        assert!(ldt.lamp_sets[0].lamp_type.len() <= MAX_LAMP_TEXT_LENGTH);
+
+        // A second pass has nothing left to fix
+        assert!(RepairReport::fix_validation(&mut ldt).is_empty());
+    }
+}
@@ -0,0 +1,622 @@
+//! Road lighting calculation grid (EN 13201-3)
+//!
+//! Places luminaires along a straight road and evaluates the horizontal
+//! illuminance and the road luminance on the calculation field between two
+//! consecutive luminaires of the same row:
+//!
+//! - longitudinal spacing D = S / N with N = 10 for S ≤ 30 m, otherwise the
+//!   smallest N giving D ≤ 3 m
+//! - three points across each lane, spaced a third of the lane width
+//! - luminaires up to 5 · H before and behind the field contribute
+//! - one observer per lane, 60 m before the field on the lane centre line
+//!
+//! Luminance uses the diffuse approximation L = q0 · E with the average
+//! luminance coefficient q0 of the surface (0.07 for CIE R3 asphalt) instead
+//! of the full r-tables, so it follows the illuminance pattern and is the same
+//! for every observer.
+//!
+//! ```rust,no_run
+//! use eulumdat::{Eulumdat, road::{RoadGrid, RoadLayout}};
+//!
+//! let ldt = Eulumdat::from_file("road.ldt")?;
+//! let grid = RoadGrid::compute(&ldt, &RoadLayout::default());
+//! println!(
+//!     "Lav = {:.2} cd/m², Uo = {:.2}, Ul = {:.2}",
+//!     grid.average_luminance(),
+//!     grid.luminance_uniformity(),
+//!     grid.longitudinal_uniformity()
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::area::{compute_illuminance_at_point, LuminairePlace};
+use crate::Eulumdat;
+
+/// Distance of the observers before the calculation field in meters
+pub const OBSERVER_DISTANCE: f64 = 60.0;
+
+/// Eye height of the observers in meters
+pub const OBSERVER_HEIGHT: f64 = 1.5;
+
+/// Average luminance coefficient q0 of CIE R3 road surfaces
+pub const Q0_R3: f64 = 0.07;
+
+/// Arrangement of the luminaire rows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoadArrangement {
+    /// One row along the near road edge
+    #[default]
+    SingleSided,
+    /// Two rows facing each other
+    Opposite,
+    /// Two rows, the far row offset by half the spacing
+    Staggered,
+}
+
+/// Geometry of a straight road installation
+///
+/// The road runs along +X. Y runs across it from the near edge (0) to the
+/// far edge ([`RoadLayout::road_width`]); the first row of poles stands at
+/// the near edge and the field starts at one of its luminaires.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoadLayout {
+    /// Number of lanes
+    pub lanes: usize,
+    /// Lane width in meters
+    pub lane_width: f64,
+    /// Spacing between consecutive luminaires of a row in meters
+    pub pole_spacing: f64,
+    /// Mounting height in meters
+    pub mounting_height: f64,
+    /// Horizontal distance from the road edge to the luminaire in meters,
+    /// positive over the road
+    pub overhang: f64,
+    /// Row arrangement
+    pub arrangement: RoadArrangement,
+    /// Average luminance coefficient q0 of the surface in 1/sr
+    pub q0: f64,
+}
+
+impl Default for RoadLayout {
+    fn default() -> Self {
+        Self {
+            lanes: 2,
+            lane_width: 3.5,
+            pole_spacing: 30.0,
+            mounting_height: 8.0,
+            overhang: 1.0,
+            arrangement: RoadArrangement::SingleSided,
+            q0: Q0_R3,
+        }
+    }
+}
+
+impl RoadLayout {
+    /// Total carriageway width in meters
+    pub fn road_width(&self) -> f64 {
+        self.lanes as f64 * self.lane_width
+    }
+
+    /// Longitudinal grid positions within the field (EN 13201-3)
+    pub fn grid_x(&self) -> Vec<f64> {
+        let n = if self.pole_spacing <= 30.0 {
+            10
+        } else {
+            (self.pole_spacing / 3.0).ceil() as usize
+        };
+        let d = self.pole_spacing / n as f64;
+        (0..n).map(|i| (i as f64 + 0.5) * d).collect()
+    }
+
+    /// Transverse grid positions, three per lane (EN 13201-3)
+    pub fn grid_y(&self) -> Vec<f64> {
+        let d = self.lane_width / 3.0;
+        (0..self.lanes * 3).map(|i| (i as f64 + 0.5) * d).collect()
+    }
+
+    /// Luminaires contributing to the field
+    ///
+    /// C0 points along the road and C90 across it, towards the far edge for
+    /// the near row and towards the near edge for the far row.
+    pub fn luminaires(&self) -> Vec<LuminairePlace> {
+        let mut rows = vec![(0.0, self.overhang, 0.0)];
+        match self.arrangement {
+            RoadArrangement::SingleSided => {}
+            RoadArrangement::Opposite => {
+                rows.push((0.0, self.road_width() - self.overhang, 180.0));
+            }
+            RoadArrangement::Staggered => rows.push((
+                self.pole_spacing / 2.0,
+                self.road_width() - self.overhang,
+                180.0,
+            )),
+        }
+
+        let reach = 5.0 * self.mounting_height;
+        let first = -(reach / self.pole_spacing).ceil() as i64 - 1;
+        let last = ((self.pole_spacing + reach) / self.pole_spacing).ceil() as i64;
+
+        let mut luminaires = Vec::new();
+        for (offset, y, rotation) in rows {
+            for k in first..=last {
+                let x = k as f64 * self.pole_spacing + offset;
+                if x < -reach || x > self.pole_spacing + reach {
+                    continue;
+                }
+                luminaires.push(LuminairePlace {
+                    rotation,
+                    ..LuminairePlace::simple(luminaires.len(), x, y, self.mounting_height)
+                });
+            }
+        }
+        luminaires
+    }
+
+    /// Observer positions `(x, y)` at eye height [`OBSERVER_HEIGHT`], one per lane
+    pub fn observers(&self) -> Vec<(f64, f64)> {
+        (0..self.lanes)
+            .map(|lane| (-OBSERVER_DISTANCE, (lane as f64 + 0.5) * self.lane_width))
+            .collect()
+    }
+}
+
+/// Illuminance and luminance on the EN 13201-3 calculation field
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoadGrid {
+    /// Layout the grid was computed for
+    pub layout: RoadLayout,
+    /// Longitudinal grid positions in meters
+    pub x: Vec<f64>,
+    /// Transverse grid positions in meters
+    pub y: Vec<f64>,
+    /// Horizontal illuminance in lux (`[row][col]`, rows along Y)
+    pub illuminance: Vec<Vec<f64>>,
+    /// Road luminance in cd/m² (`[row][col]`, rows along Y)
+    pub luminance: Vec<Vec<f64>>,
+}
+
+impl RoadGrid {
+    /// Compute the grid for `ldt` installed as described by `layout`.
+    pub fn compute(ldt: &Eulumdat, layout: &RoadLayout) -> Self {
+        // Use abs(num_lamps): negative values flag absolute photometry
+        let total_flux: f64 = ldt
+            .lamp_sets
+            .iter()
+            .map(|ls| ls.total_luminous_flux * ls.num_lamps.unsigned_abs() as f64)
+            .sum();
+        let flux_scale = total_flux / 1000.0;
+
+        let x = layout.grid_x();
+        let y = layout.grid_y();
+        let luminaires = layout.luminaires();
+
+        let illuminance: Vec<Vec<f64>> = y
+            .iter()
+            .map(|&gy| {
+                x.iter()
+                    .map(|&gx| {
+                        luminaires
+                            .iter()
+                            .map(|place| {
+                                compute_illuminance_at_point(
+                                    ldt,
+                                    place,
+                                    (gx, gy, 0.0),
+                                    (0.0, 0.0, 1.0),
+                                    flux_scale,
+                                )
+                            })
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect();
+        let luminance = illuminance
+            .iter()
+            .map(|row| row.iter().map(|e| layout.q0 * e).collect())
+            .collect();
+
+        Self {
+            layout: *layout,
+            x,
+            y,
+            illuminance,
+            luminance,
+        }
+    }
+
+    /// Average horizontal illuminance in lux
+    pub fn average_illuminance(&self) -> f64 {
+        average(&self.illuminance)
+    }
+
+    /// Minimum horizontal illuminance in lux
+    pub fn min_illuminance(&self) -> f64 {
+        minimum(&self.illuminance)
+    }
+
+    /// Illuminance uniformity Emin / Eav
+    pub fn illuminance_uniformity(&self) -> f64 {
+        ratio(self.min_illuminance(), self.average_illuminance())
+    }
+
+    /// Average road luminance in cd/m²
+    pub fn average_luminance(&self) -> f64 {
+        average(&self.luminance)
+    }
+
+    /// Overall luminance uniformity Uo = Lmin / Lav
+    pub fn luminance_uniformity(&self) -> f64 {
+        ratio(minimum(&self.luminance), self.average_luminance())
+    }
+
+    /// Longitudinal uniformity Ul: the worst Lmin / Lmax along a lane
+    /// centre line
+    pub fn longitudinal_uniformity(&self) -> f64 {
+        let uniformity = (0..self.layout.lanes)
+            .filter_map(|lane| self.luminance.get(lane * 3 + 1))
+            .map(|row| ratio(minimum(std::slice::from_ref(row)), maximum(row)))
+            .fold(f64::INFINITY, f64::min);
+        if uniformity.is_finite() {
+            uniformity
+        } else {
+            0.0
+        }
+    }
+
+    /// Most demanding class of the series of `class` (M, C or P) that the
+    /// grid meets
+    pub fn achieved_class(&self, class: RoadClass) -> Option<RoadClass> {
+        class
+            .series()
+            .iter()
+            .copied()
+            .find(|candidate| candidate.is_met_by(self))
+    }
+
+    /// Scale all values by a maintenance factor
+    pub fn maintained(mut self, maintenance_factor: f64) -> Self {
+        for value in self
+            .illuminance
+            .iter_mut()
+            .chain(self.luminance.iter_mut())
+            .flatten()
+        {
+            *value *= maintenance_factor;
+        }
+        self
+    }
+}
+
+/// Lighting class of EN 13201-2
+///
+/// M classes rate the road luminance for motorised traffic, C classes the
+/// illuminance of conflict areas and P classes the illuminance of pedestrian
+/// and low speed areas. Threshold increment and edge illuminance ratio are
+/// not computed and therefore not checked, so M classes can only be
+/// estimated, see [`RoadClass::is_approximate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoadClass {
+    /// Lav ≥ 2.0 cd/m²
+    M1,
+    /// Lav ≥ 1.5 cd/m²
+    M2,
+    /// Lav ≥ 1.0 cd/m²
+    M3,
+    /// Lav ≥ 0.75 cd/m²
+    M4,
+    /// Lav ≥ 0.5 cd/m²
+    M5,
+    /// Lav ≥ 0.3 cd/m²
+    M6,
+    /// Eav ≥ 50 lx
+    C0,
+    /// Eav ≥ 30 lx
+    C1,
+    /// Eav ≥ 20 lx
+    C2,
+    /// Eav ≥ 15 lx
+    C3,
+    /// Eav ≥ 10 lx
+    C4,
+    /// Eav ≥ 7.5 lx
+    C5,
+    /// Eav ≥ 15 lx
+    P1,
+    /// Eav ≥ 10 lx
+    P2,
+    /// Eav ≥ 7.5 lx
+    P3,
+    /// Eav ≥ 5 lx
+    P4,
+    /// Eav ≥ 3 lx
+    P5,
+    /// Eav ≥ 2 lx
+    P6,
+}
+
+/// One requirement of a class against the computed value
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClassCheck {
+    /// Quantity, e.g. "Lav" or "Uo"
+    pub quantity: &'static str,
+    /// Unit, empty for ratios
+    pub unit: &'static str,
+    /// Minimum required value
+    pub required: f64,
+    /// Computed value
+    pub achieved: f64,
+}
+
+impl ClassCheck {
+    /// Whether the computed value reaches the requirement
+    pub fn passed(&self) -> bool {
+        self.achieved >= self.required
+    }
+}
+
+impl RoadClass {
+    /// All classes, most demanding first within each series
+    pub const ALL: [Self; 18] = [
+        Self::M1,
+        Self::M2,
+        Self::M3,
+        Self::M4,
+        Self::M5,
+        Self::M6,
+        Self::C0,
+        Self::C1,
+        Self::C2,
+        Self::C3,
+        Self::C4,
+        Self::C5,
+        Self::P1,
+        Self::P2,
+        Self::P3,
+        Self::P4,
+        Self::P5,
+        Self::P6,
+    ];
+
+    /// Classes of the same series (M, C or P), most demanding first
+    pub fn series(&self) -> &'static [Self] {
+        match self {
+            Self::M1 | Self::M2 | Self::M3 | Self::M4 | Self::M5 | Self::M6 => &Self::ALL[0..6],
+            Self::C0 | Self::C1 | Self::C2 | Self::C3 | Self::C4 | Self::C5 => &Self::ALL[6..12],
+            _ => &Self::ALL[12..18],
+        }
+    }
+
+    /// Whether the check of this class is only an estimate.
+    ///
+    /// True for the M classes: the luminance of [`RoadGrid`] follows the
+    /// illuminance (L = q0 · E, so its Uo equals the illuminance Uo), and
+    /// their threshold increment TI and edge ratio R_EI are not checked.
+    pub fn is_approximate(&self) -> bool {
+        self.series()[0] == Self::M1
+    }
+
+    /// Class name, e.g. "M3"
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::M1 => "M1",
+            Self::M2 => "M2",
+            Self::M3 => "M3",
+            Self::M4 => "M4",
+            Self::M5 => "M5",
+            Self::M6 => "M6",
+            Self::C0 => "C0",
+            Self::C1 => "C1",
+            Self::C2 => "C2",
+            Self::C3 => "C3",
+            Self::C4 => "C4",
+            Self::C5 => "C5",
+            Self::P1 => "P1",
+            Self::P2 => "P2",
+            Self::P3 => "P3",
+            Self::P4 => "P4",
+            Self::P5 => "P5",
+            Self::P6 => "P6",
+        }
+    }
+
+    /// Check the maintained values of `grid` against the class requirements
+    pub fn check(&self, grid: &RoadGrid) -> Vec<ClassCheck> {
+        let check = |quantity, unit, required, achieved| ClassCheck {
+            quantity,
+            unit,
+            required,
+            achieved,
+        };
+        // (Lav, Uo, Ul) of table 1 of EN 13201-2
+        let m = |lav, uo, ul| {
+            vec![
+                check("Lav", "cd/m²", lav, grid.average_luminance()),
+                check("Uo", "", uo, grid.luminance_uniformity()),
+                check("Ul", "", ul, grid.longitudinal_uniformity()),
+            ]
+        };
+        // (Eav, Uo) of table 2
+        let c = |eav| {
+            vec![
+                check("Eav", "lx", eav, grid.average_illuminance()),
+                check("Uo", "", 0.4, grid.illuminance_uniformity()),
+            ]
+        };
+        // (Eav, Emin) of table 3
+        let p = |eav, emin| {
+            vec![
+                check("Eav", "lx", eav, grid.average_illuminance()),
+                check("Emin", "lx", emin, grid.min_illuminance()),
+            ]
+        };
+        match self {
+            Self::M1 => m(2.0, 0.4, 0.7),
+            Self::M2 => m(1.5, 0.4, 0.7),
+            Self::M3 => m(1.0, 0.4, 0.6),
+            Self::M4 => m(0.75, 0.4, 0.6),
+            Self::M5 => m(0.5, 0.35, 0.4),
+            Self::M6 => m(0.3, 0.35, 0.4),
+            Self::C0 => c(50.0),
+            Self::C1 => c(30.0),
+            Self::C2 => c(20.0),
+            Self::C3 => c(15.0),
+            Self::C4 => c(10.0),
+            Self::C5 => c(7.5),
+            Self::P1 => p(15.0, 3.0),
+            Self::P2 => p(10.0, 2.0),
+            Self::P3 => p(7.5, 1.5),
+            Self::P4 => p(5.0, 1.0),
+            Self::P5 => p(3.0, 0.6),
+            Self::P6 => p(2.0, 0.4),
+        }
+    }
+
+    /// Whether `grid` meets every checked requirement of the class
+    pub fn is_met_by(&self, grid: &RoadGrid) -> bool {
+        self.check(grid).iter().all(ClassCheck::passed)
+    }
+}
+
+impl std::fmt::Display for RoadClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for RoadClass {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|class| class.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| {
+                crate::error::invalid_value("road class", format!("{s} (M1–M6, C0–C5 or P1–P6)"))
+            })
+    }
+}
+
+fn average(values: &[Vec<f64>]) -> f64 {
+    let count: usize = values.iter().map(Vec::len).sum();
+    if count == 0 {
+        return 0.0;
+    }
+    values.iter().flatten().sum::<f64>() / count as f64
+}
+
+fn minimum(values: &[Vec<f64>]) -> f64 {
+    let min = values
+        .iter()
+        .flatten()
+        .copied()
+        .fold(f64::INFINITY, f64::min);
+    if min.is_finite() {
+        min
+    } else {
+        0.0
+    }
+}
+
+fn maximum(values: &[f64]) -> f64 {
+    values.iter().copied().fold(0.0, f64::max)
+}
+
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator > 0.0 {
+        numerator / denominator
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LampSet, Symmetry};
+
+    fn create_test_ldt() -> Eulumdat {
+        Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            c_angles: vec![0.0],
+            g_angles: vec![0.0, 15.0, 30.0, 45.0, 60.0, 75.0, 90.0],
+            intensities: vec![vec![300.0, 310.0, 320.0, 280.0, 150.0, 30.0, 0.0]],
+            lamp_sets: vec![LampSet {
+                num_lamps: 1,
+                total_luminous_flux: 10000.0,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_road_grid_layout() {
+        let layout = RoadLayout::default();
+        assert_eq!(layout.grid_x().len(), 10);
+        assert!((layout.grid_x()[0] - 1.5).abs() < 1e-9);
+        assert_eq!(layout.grid_y().len(), 6);
+        assert_eq!(layout.observers(), vec![(-60.0, 1.75), (-60.0, 5.25)]);
+
+        // 5 · H = 40 m around the 30 m field: poles at -30, 0, 30, 60
+        let xs: Vec<f64> = layout.luminaires().iter().map(|l| l.x).collect();
+        assert_eq!(xs, vec![-30.0, 0.0, 30.0, 60.0]);
+
+        let long = RoadLayout {
+            pole_spacing: 40.0,
+            arrangement: RoadArrangement::Staggered,
+            ..layout
+        };
+        assert_eq!(long.grid_x().len(), 14);
+        let far: Vec<f64> = long
+            .luminaires()
+            .iter()
+            .filter(|l| l.rotation == 180.0)
+            .map(|l| l.x)
+            .collect();
+        assert_eq!(far, vec![-20.0, 20.0, 60.0]);
+    }
+
+    #[test]
+    fn test_road_grid_values() {
+        let ldt = create_test_ldt();
+        let grid = RoadGrid::compute(&ldt, &RoadLayout::default());
+
+        assert_eq!(grid.illuminance.len(), 6);
+        assert_eq!(grid.illuminance[0].len(), 10);
+        assert!(grid.average_illuminance() > grid.min_illuminance());
+        assert!(grid.min_illuminance() > 0.0);
+        assert!((grid.average_luminance() - Q0_R3 * grid.average_illuminance()).abs() < 1e-9);
+        assert!(grid.luminance_uniformity() > 0.0 && grid.luminance_uniformity() < 1.0);
+        assert!(grid.longitudinal_uniformity() > 0.0 && grid.longitudinal_uniformity() < 1.0);
+
+        // A second row raises the level and evens out the far lane
+        let opposite = RoadGrid::compute(
+            &ldt,
+            &RoadLayout {
+                arrangement: RoadArrangement::Opposite,
+                ..Default::default()
+            },
+        );
+        assert!(opposite.average_illuminance() > grid.average_illuminance() * 1.5);
+        assert!(opposite.illuminance_uniformity() >= grid.illuminance_uniformity());
+    }
+
+    #[test]
+    fn test_approximate_classes() {
+        // The diffuse luminance carries no information beyond the illuminance
+        let grid = RoadGrid::compute(&create_test_ldt(), &RoadLayout::default());
+        assert!((grid.luminance_uniformity() - grid.illuminance_uniformity()).abs() < 1e-9);
+
+        let approximate: Vec<_> = RoadClass::ALL
+            .into_iter()
+            .filter(RoadClass::is_approximate)
+            .collect();
+        assert_eq!(approximate, RoadClass::M1.series());
+    }
+}
@@ -0,0 +1,232 @@
+//! Rounding policy for exported numeric fields.
+//!
+//! Receiving tools differ in how much precision they accept or need: some
+//! choke on six-decimal intensities, others lose accuracy on integers. A
+//! [`RoundingPolicy`] sets the number of decimals per [`NumericField`] and is
+//! shared by the writers:
+//!
+//! - LDT: [`Eulumdat::to_ldt_with_rounding`]
+//! - IES: [`IesExportOptions::rounding`](crate::IesExportOptions)
+//! - ATLA: `LuminaireOpticalData::apply_rounding` (in the `atla` crate)
+//!
+//! Each writer rounds the values in its own units, so the IES intensity
+//! precision applies to candela and the LDT precision to cd/klm.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use eulumdat::{Eulumdat, NumericField, RoundingPolicy};
+//!
+//! let ldt = Eulumdat::from_file("luminaire.ldt").unwrap();
+//! let policy = RoundingPolicy::new(3).with_precision(NumericField::Intensity, 1);
+//! std::fs::write("rounded.ldt", ldt.to_ldt_with_rounding(&policy)).unwrap();
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::eulumdat::Eulumdat;
+
+/// Category of an exported numeric value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NumericField {
+    /// Luminous intensities (cd/klm in LDT, cd in IES)
+    Intensity,
+    /// C and γ angles, plane distances and tilt
+    Angle,
+    /// Lamp luminous flux (lm)
+    Flux,
+    /// Luminaire and luminous area dimensions
+    Dimension,
+    /// DFF, LOR and direct ratios
+    Ratio,
+    /// Lamp wattage (W)
+    Wattage,
+    /// Conversion factor for intensities
+    ConversionFactor,
+}
+
+/// Number of decimals written for each [`NumericField`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoundingPolicy {
+    /// Decimals for fields without an override
+    pub default_decimals: u8,
+    /// Per-field decimals
+    pub overrides: BTreeMap<NumericField, u8>,
+}
+
+impl Default for RoundingPolicy {
+    /// Six decimals everywhere, the precision of the plain LDT writer.
+    fn default() -> Self {
+        Self::new(6)
+    }
+}
+
+impl RoundingPolicy {
+    /// Use `default_decimals` for every field.
+    pub fn new(default_decimals: u8) -> Self {
+        Self {
+            default_decimals,
+            overrides: BTreeMap::new(),
+        }
+    }
+
+    /// Override the decimals for one field.
+    pub fn with_precision(mut self, field: NumericField, decimals: u8) -> Self {
+        self.overrides.insert(field, decimals);
+        self
+    }
+
+    /// Decimals used for `field`.
+    pub fn decimals(&self, field: NumericField) -> u8 {
+        self.overrides
+            .get(&field)
+            .copied()
+            .unwrap_or(self.default_decimals)
+    }
+
+    /// Round `value` to the precision of `field`.
+    pub fn round(&self, field: NumericField, value: f64) -> f64 {
+        round_to(value, self.decimals(field))
+    }
+
+    /// Format `value` rounded to the precision of `field`, without trailing zeros.
+    pub fn format(&self, field: NumericField, value: f64) -> String {
+        let decimals = self.decimals(field);
+        let rounded = round_to(value, decimals);
+        if rounded == rounded.trunc() {
+            format!("{}", rounded as i64)
+        } else {
+            let s = format!("{:.*}", decimals as usize, rounded);
+            s.trim_end_matches('0').trim_end_matches('.').to_string()
+        }
+    }
+
+    /// Format `value` with exactly the number of decimals of `field`.
+    pub fn format_fixed(&self, field: NumericField, value: f64) -> String {
+        format!("{:.*}", self.decimals(field) as usize, value)
+    }
+
+    /// Round every numeric field of `ldt` in place.
+    pub fn apply(&self, ldt: &mut Eulumdat) {
+        use NumericField::*;
+
+        for value in [
+            &mut ldt.c_plane_distance,
+            &mut ldt.g_plane_distance,
+            &mut ldt.tilt_angle,
+        ] {
+            *value = self.round(Angle, *value);
+        }
+        for value in [
+            &mut ldt.length,
+            &mut ldt.width,
+            &mut ldt.height,
+            &mut ldt.luminous_area_length,
+            &mut ldt.luminous_area_width,
+            &mut ldt.height_c0,
+            &mut ldt.height_c90,
+            &mut ldt.height_c180,
+            &mut ldt.height_c270,
+        ] {
+            *value = self.round(Dimension, *value);
+        }
+        for value in [&mut ldt.downward_flux_fraction, &mut ldt.light_output_ratio]
+            .into_iter()
+            .chain(ldt.direct_ratios.iter_mut())
+        {
+            *value = self.round(Ratio, *value);
+        }
+        ldt.conversion_factor = self.round(ConversionFactor, ldt.conversion_factor);
+
+        for lamp_set in &mut ldt.lamp_sets {
+            lamp_set.total_luminous_flux = self.round(Flux, lamp_set.total_luminous_flux);
+            lamp_set.wattage_with_ballast = self.round(Wattage, lamp_set.wattage_with_ballast);
+        }
+        for angle in ldt.c_angles.iter_mut().chain(ldt.g_angles.iter_mut()) {
+            *angle = self.round(Angle, *angle);
+        }
+        for value in ldt.intensities.iter_mut().flatten() {
+            *value = self.round(Intensity, *value);
+        }
+    }
+}
+
+fn round_to(value: f64, decimals: u8) -> f64 {
+    let scale = 10f64.powi(decimals as i32);
+    (value * scale).round() / scale
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IesExportOptions, IesExporter, IesParser, PhotometricCalculations};
+
+    fn road_luminaire() -> Eulumdat {
+        Eulumdat::parse(include_str!(
+            "../../eulumdat-wasm/templates/road_luminaire.ldt"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_precision_overrides() {
+        let policy = RoundingPolicy::new(3).with_precision(NumericField::Intensity, 1);
+        assert_eq!(policy.decimals(NumericField::Intensity), 1);
+        assert_eq!(policy.decimals(NumericField::Angle), 3);
+        assert_eq!(policy.format(NumericField::Intensity, 123.456), "123.5");
+        assert_eq!(policy.format(NumericField::Angle, 2.5), "2.5");
+        assert_eq!(policy.format(NumericField::Angle, 100.0), "100");
+        assert_eq!(policy.format_fixed(NumericField::Intensity, 2.0), "2.0");
+
+        let integer = RoundingPolicy::new(0);
+        assert_eq!(integer.format(NumericField::Flux, 1499.6), "1500");
+        assert_eq!(integer.format(NumericField::Flux, -0.2), "0");
+    }
+
+    #[test]
+    fn test_ldt_rounding_conserves_flux() {
+        let ldt = road_luminaire();
+        let before = PhotometricCalculations::integrate_flux(&ldt).luminaire_flux;
+
+        for decimals in [0, 1, 3] {
+            let policy = RoundingPolicy::new(3).with_precision(NumericField::Intensity, decimals);
+            let rounded = Eulumdat::parse(&ldt.to_ldt_with_rounding(&policy)).unwrap();
+            let after = PhotometricCalculations::integrate_flux(&rounded).luminaire_flux;
+            assert!(
+                (after - before).abs() / before < 0.005,
+                "{decimals} decimals: {after} vs {before}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ies_rounding_conserves_flux() {
+        let ldt = road_luminaire();
+        let flux = |options: &IesExportOptions| {
+            let ies = IesExporter::export_with_options(&ldt, options);
+            let reparsed = IesParser::parse(&ies).unwrap();
+            (
+                ies,
+                PhotometricCalculations::integrate_flux(&reparsed).luminaire_flux,
+            )
+        };
+
+        let (_, before) = flux(&IesExportOptions::default());
+        let (ies, after) = flux(&IesExportOptions {
+            rounding: Some(RoundingPolicy::new(2).with_precision(NumericField::Intensity, 0)),
+            ..Default::default()
+        });
+        let data = IesParser::parse_to_ies_data(&ies).unwrap();
+        assert!(data
+            .candela_values
+            .iter()
+            .flatten()
+            .all(|v| *v == v.round()));
+        assert!(
+            (after - before).abs() / before < 0.005,
+            "{after} vs {before}"
+        );
+    }
+}
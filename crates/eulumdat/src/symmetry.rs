@@ -161,12 +161,17 @@ impl SymmetryHandler {
     ///
     /// This handles symmetry automatically, interpolating between stored data points.
     pub fn get_intensity_at(eulumdat: &Eulumdat, c_angle: f64, g_angle: f64) -> f64 {
+        let planes = Self::c_interpolation(eulumdat, c_angle);
+        let g_idx = Self::g_interpolation(eulumdat, g_angle);
+        Self::interpolate_planes(eulumdat, planes, g_idx)
+    }
+
+    /// Stored C-planes around `c_angle` after applying the symmetry: lower
+    /// index, upper index and the fraction towards the upper one.
+    pub(crate) fn c_interpolation(eulumdat: &Eulumdat, c_angle: f64) -> (usize, usize, f64) {
         // Normalize C angle to 0-360 range
         let c_normalized = c_angle.rem_euclid(360.0);
 
-        // Clamp G angle to 0-180 range
-        let g_clamped = g_angle.clamp(0.0, 180.0);
-
         // Find the effective C index based on symmetry
         let effective_c = match eulumdat.symmetry {
             Symmetry::None => c_normalized,
@@ -201,34 +206,34 @@ impl SymmetryHandler {
             }
         };
 
-        let g_idx = Self::find_interpolation_indices(&eulumdat.g_angles, g_clamped);
-
         // For Symmetry::None, C-planes are cyclic (360° wraps to 0°).
         // We need special handling when effective_c falls between the last
         // C-angle and 360° (which equals the first C-angle).
-        if eulumdat.symmetry == Symmetry::None && !eulumdat.c_angles.is_empty() {
-            let last_c = *eulumdat.c_angles.last().unwrap();
+        if eulumdat.symmetry == Symmetry::None && eulumdat.c_angles.len() > 1 {
+            let last_idx = eulumdat.c_angles.len() - 1;
+            let last_c = eulumdat.c_angles[last_idx];
             let first_c = eulumdat.c_angles[0];
 
             // Check if the angle is beyond the last stored C-plane
-            if effective_c > last_c && eulumdat.c_angles.len() > 1 {
+            if effective_c > last_c {
                 // Wrap: interpolate between last C-plane and first C-plane
                 let span = (360.0 - last_c) + first_c; // gap across the wrap
                 if span > 0.0 {
                     let fraction = (effective_c - last_c) / span;
-                    let last_idx = eulumdat.c_angles.len() - 1;
-                    return Self::bilinear_interpolate_wrap(
-                        eulumdat, last_idx, 0, fraction, g_idx, g_clamped,
-                    );
+                    return (last_idx, 0, fraction);
                 }
             }
         }
 
         // Find surrounding C indices (non-wrapping)
-        let c_idx = Self::find_interpolation_indices(&eulumdat.c_angles, effective_c);
+        let (ci, cf) = Self::find_interpolation_indices(&eulumdat.c_angles, effective_c);
+        (ci, ci + 1, cf)
+    }
 
-        // Bilinear interpolation
-        Self::bilinear_interpolate(eulumdat, c_idx, g_idx, effective_c, g_clamped)
+    /// Stored G angle at or below `g_angle` and the fraction towards the next one.
+    pub(crate) fn g_interpolation(eulumdat: &Eulumdat, g_angle: f64) -> (usize, f64) {
+        // Clamp G angle to 0-180 range
+        Self::find_interpolation_indices(&eulumdat.g_angles, g_angle.clamp(0.0, 180.0))
     }
 
     /// Find indices for interpolation (lower index and fraction).
@@ -255,17 +260,13 @@ impl SymmetryHandler {
         (angles.len() - 1, 0.0)
     }
 
-    /// Perform bilinear interpolation on intensity data.
-    fn bilinear_interpolate(
+    /// Bilinear interpolation between two C-planes, see [`Self::c_interpolation`]
+    /// and [`Self::g_interpolation`].
+    fn interpolate_planes(
         eulumdat: &Eulumdat,
-        c_idx: (usize, f64),
-        g_idx: (usize, f64),
-        _c_angle: f64,
-        _g_angle: f64,
+        (ci_lo, ci_hi, cf): (usize, usize, f64),
+        (gi, gf): (usize, f64),
     ) -> f64 {
-        let (ci, cf) = c_idx;
-        let (gi, gf) = g_idx;
-
         // Get the four surrounding intensity values
         let get = |c: usize, g: usize| -> f64 {
             eulumdat
@@ -276,10 +277,10 @@ impl SymmetryHandler {
                 .unwrap_or(0.0)
         };
 
-        let i00 = get(ci, gi);
-        let i01 = get(ci, gi + 1);
-        let i10 = get(ci + 1, gi);
-        let i11 = get(ci + 1, gi + 1);
+        let i00 = get(ci_lo, gi);
+        let i01 = get(ci_lo, gi + 1);
+        let i10 = get(ci_hi, gi);
+        let i11 = get(ci_hi, gi + 1);
 
         // Bilinear interpolation
         let i0 = i00 * (1.0 - gf) + i01 * gf;
@@ -288,36 +289,25 @@ impl SymmetryHandler {
         i0 * (1.0 - cf) + i1 * cf
     }
 
-    /// Bilinear interpolation wrapping between two explicit C-plane indices.
-    /// Used when the C angle wraps from the last stored plane back to the first (360°→0°).
-    fn bilinear_interpolate_wrap(
+    /// Get intensity at any C and G angle with the chosen interpolation.
+    ///
+    /// [`Interpolation::Bilinear`] is identical to [`Self::get_intensity_at`].
+    pub fn get_intensity_with(
         eulumdat: &Eulumdat,
-        ci_lo: usize,
-        ci_hi: usize,
-        cf: f64,
-        g_idx: (usize, f64),
-        _g_angle: f64,
+        c_angle: f64,
+        g_angle: f64,
+        interpolation: Interpolation,
     ) -> f64 {
-        let (gi, gf) = g_idx;
-
-        let get = |c: usize, g: usize| -> f64 {
-            eulumdat
-                .intensities
-                .get(c)
-                .and_then(|row| row.get(g))
-                .copied()
-                .unwrap_or(0.0)
+        if interpolation == Interpolation::Bilinear {
+            return Self::get_intensity_at(eulumdat, c_angle, g_angle);
+        }
+        let c_angles = match eulumdat.symmetry {
+            Symmetry::VerticalAxis => vec![0.0],
+            _ => Self::expand_c_angles(eulumdat),
         };
-
-        let i00 = get(ci_lo, gi);
-        let i01 = get(ci_lo, gi + 1);
-        let i10 = get(ci_hi, gi);
-        let i11 = get(ci_hi, gi + 1);
-
-        let i0 = i00 * (1.0 - gf) + i01 * gf;
-        let i1 = i10 * (1.0 - gf) + i11 * gf;
-
-        i0 * (1.0 - cf) + i1 * cf
+        interpolation.sample_grid(&c_angles, &eulumdat.g_angles, c_angle, g_angle, |c, g| {
+            Self::get_intensity_at(eulumdat, c, g)
+        })
     }
 
     /// Convert polar coordinates (C, G, intensity) to Cartesian for visualization.
@@ -373,6 +363,143 @@ impl SymmetryHandler {
     }
 }
 
+/// Interpolation between the stored C/γ grid nodes when sampling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interpolation {
+    /// Value of the closest grid node (shows the raw measurement grid)
+    Nearest,
+    /// Linear in C and γ (default, never overshoots)
+    #[default]
+    Bilinear,
+    /// Catmull-Rom spline in C and γ (smooth, may overshoot slightly)
+    Bicubic,
+}
+
+impl Interpolation {
+    /// Interpolate on a C/γ grid.
+    ///
+    /// `c_angles` are the plane angles over the full 0–360° circle (a single
+    /// angle for rotationally symmetric data) and `g_angles` the gamma angles.
+    /// `node(c, g)` returns the intensity at a grid node, with `c` in 0–360°
+    /// and `g` in 0–180°; symmetry mapping is up to the caller. Neighbours
+    /// beyond γ 0°/180° are taken from the opposite C-plane, and C wraps
+    /// around the circle.
+    pub fn sample_grid(
+        self,
+        c_angles: &[f64],
+        g_angles: &[f64],
+        c_angle: f64,
+        g_angle: f64,
+        node: impl Fn(f64, f64) -> f64,
+    ) -> f64 {
+        if g_angles.is_empty() {
+            return 0.0;
+        }
+        let c_grid = CyclicGrid::new(c_angles);
+        let (ck, ct) = c_grid.locate(c_angle.rem_euclid(360.0));
+        let (gk, gt) = locate_mirrored(g_angles, g_angle.clamp(0.0, 180.0));
+
+        let at = |i: isize, j: isize| {
+            let c = c_grid.angle(ck + i);
+            let g = mirrored_angle(g_angles, gk + j);
+            // Across nadir or zenith the neighbour lies in the opposite half-plane
+            if g < 0.0 {
+                node((c + 180.0).rem_euclid(360.0), -g)
+            } else if g > 180.0 {
+                node((c + 180.0).rem_euclid(360.0), 360.0 - g)
+            } else {
+                node(c.rem_euclid(360.0), g)
+            }
+        };
+
+        match self {
+            Self::Nearest => at((ct >= 0.5) as isize, (gt >= 0.5) as isize),
+            Self::Bilinear => {
+                let i0 = at(0, 0) * (1.0 - gt) + at(0, 1) * gt;
+                let i1 = at(1, 0) * (1.0 - gt) + at(1, 1) * gt;
+                i0 * (1.0 - ct) + i1 * ct
+            }
+            Self::Bicubic => {
+                let column = |i: isize| catmull_rom([at(i, -1), at(i, 0), at(i, 1), at(i, 2)], gt);
+                catmull_rom([column(-1), column(0), column(1), column(2)], ct).max(0.0)
+            }
+        }
+    }
+}
+
+/// Catmull-Rom spline through `p[1]` (t = 0) and `p[2]` (t = 1).
+fn catmull_rom(p: [f64; 4], t: f64) -> f64 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    0.5 * (2.0 * p[1]
+        + (p[2] - p[0]) * t
+        + (2.0 * p[0] - 5.0 * p[1] + 4.0 * p[2] - p[3]) * t2
+        + (3.0 * p[1] - p[0] - 3.0 * p[2] + p[3]) * t3)
+}
+
+/// C-plane angles repeated every 360°.
+struct CyclicGrid<'a> {
+    angles: &'a [f64],
+}
+
+impl<'a> CyclicGrid<'a> {
+    fn new(angles: &'a [f64]) -> Self {
+        const SINGLE: &[f64] = &[0.0];
+        Self {
+            angles: if angles.is_empty() { SINGLE } else { angles },
+        }
+    }
+
+    /// Angle of node `k`, where indices outside the list continue around the circle.
+    fn angle(&self, k: isize) -> f64 {
+        let n = self.angles.len() as isize;
+        self.angles[k.rem_euclid(n) as usize] + 360.0 * k.div_euclid(n) as f64
+    }
+
+    /// Index of the node at or before `c` and the fraction towards the next one.
+    fn locate(&self, c: f64) -> (isize, f64) {
+        let k = self
+            .angles
+            .iter()
+            .rposition(|&a| a <= c)
+            .map_or(-1, |k| k as isize);
+        let (lo, hi) = (self.angle(k), self.angle(k + 1));
+        let t = if hi > lo { (c - lo) / (hi - lo) } else { 0.0 };
+        (k, t.clamp(0.0, 1.0))
+    }
+}
+
+/// Index of the gamma node at or before `g` and the fraction towards the next one.
+fn locate_mirrored(angles: &[f64], g: f64) -> (isize, f64) {
+    if angles.len() < 2 || g <= angles[0] {
+        return (0, 0.0);
+    }
+    let last = angles.len() - 1;
+    if g >= angles[last] {
+        return (last as isize, 0.0);
+    }
+    let k = angles
+        .iter()
+        .rposition(|&a| a <= g)
+        .unwrap_or(0)
+        .min(last - 1);
+    let t = (g - angles[k]) / (angles[k + 1] - angles[k]);
+    (k as isize, t)
+}
+
+/// Gamma angle of node `j`, mirrored about the first and last stored angle.
+fn mirrored_angle(angles: &[f64], j: isize) -> f64 {
+    let last = angles.len() as isize - 1;
+    if j < 0 {
+        2.0 * angles[0] - angles[(-j).min(last) as usize]
+    } else if j > last {
+        2.0 * angles[last as usize] - angles[(2 * last - j).max(0) as usize]
+    } else {
+        angles[j as usize]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -386,6 +513,70 @@ mod tests {
         assert_eq!(Symmetry::BothPlanes.calc_mc(36), 10);
     }
 
+    fn smooth_ldt() -> Eulumdat {
+        // I = 100 + 50 cos(2C) cos γ on a coarse 30° grid
+        let c_angles: Vec<f64> = (0..12).map(|i| i as f64 * 30.0).collect();
+        let g_angles: Vec<f64> = (0..=6).map(|i| i as f64 * 30.0).collect();
+        let intensities = c_angles
+            .iter()
+            .map(|c: &f64| {
+                g_angles
+                    .iter()
+                    .map(|g: &f64| {
+                        100.0 + 50.0 * (2.0 * c.to_radians()).cos() * g.to_radians().cos()
+                    })
+                    .collect()
+            })
+            .collect();
+        Eulumdat {
+            symmetry: Symmetry::None,
+            num_c_planes: 12,
+            c_plane_distance: 30.0,
+            c_angles,
+            g_angles,
+            intensities,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_interpolation_modes_agree_on_nodes() {
+        let ldt = smooth_ldt();
+        for (c, g) in [(0.0, 0.0), (90.0, 60.0), (330.0, 180.0), (150.0, 90.0)] {
+            let exact = SymmetryHandler::get_intensity_at(&ldt, c, g);
+            for mode in [
+                Interpolation::Nearest,
+                Interpolation::Bilinear,
+                Interpolation::Bicubic,
+            ] {
+                let v = SymmetryHandler::get_intensity_with(&ldt, c, g, mode);
+                assert!(
+                    (v - exact).abs() < 1e-9,
+                    "{mode:?} at C{c} G{g}: {v} vs {exact}"
+                );
+            }
+        }
+        // Nearest snaps to the closest node
+        let v = SymmetryHandler::get_intensity_with(&ldt, 40.0, 50.0, Interpolation::Nearest);
+        assert!((v - SymmetryHandler::get_intensity_at(&ldt, 30.0, 60.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bicubic_is_closer_to_smooth_distribution() {
+        let ldt = smooth_ldt();
+        let truth =
+            |c: f64, g: f64| 100.0 + 50.0 * (2.0 * c.to_radians()).cos() * g.to_radians().cos();
+        let mut err_linear = 0.0;
+        let mut err_cubic = 0.0;
+        for (c, g) in [(15.0, 15.0), (105.0, 45.0), (350.0, 10.0), (200.0, 130.0)] {
+            let linear = SymmetryHandler::get_intensity_with(&ldt, c, g, Interpolation::Bilinear);
+            let cubic = SymmetryHandler::get_intensity_with(&ldt, c, g, Interpolation::Bicubic);
+            err_linear += (linear - truth(c, g)).abs();
+            err_cubic += (cubic - truth(c, g)).abs();
+        }
+        assert!(err_cubic < err_linear * 0.5, "{err_cubic} vs {err_linear}");
+    }
+
     #[test]
     fn test_polar_to_cartesian() {
         let (x, y) = SymmetryHandler::polar_to_cartesian(0.0, 90.0, 1.0);
@@ -0,0 +1,215 @@
+//! Installed tilt of the luminaire.
+//!
+//! Photometric data describes the luminaire in its measurement position.
+//! Road and flood luminaires are often installed tilted, which turns the
+//! whole photometric web about the C90-C270 axis. [`Eulumdat::tilt_distribution`]
+//! resamples the data in the installed orientation, so calculations and
+//! diagrams see the distribution as it reaches the ground. For IES files,
+//! the `TILT` multipliers (lamp output versus tilt angle) can be applied on
+//! top with [`Eulumdat::apply_tilt_multiplier`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use eulumdat::Eulumdat;
+//!
+//! let ldt = Eulumdat::from_file("road.ldt").unwrap();
+//! let installed = ldt.with_tilt(15.0);
+//! println!("DFF installed: {:.1} %", installed.downward_flux_fraction);
+//! ```
+
+use crate::calculations::PhotometricCalculations;
+use crate::eulumdat::Eulumdat;
+use crate::ies::TiltData;
+
+/// C-plane step for data stored without plane spacing (rotational symmetry).
+const DEFAULT_C_STEP: f64 = 5.0;
+
+/// γ step used to extend data that ends before 180°.
+const DEFAULT_G_STEP: f64 = 5.0;
+
+impl Eulumdat {
+    /// Tilt the distribution by `degrees` about the C90-C270 axis.
+    ///
+    /// Positive angles turn the nadir (γ 0°) towards C0, the convention of
+    /// the area calculations. The data is resampled on the full C-plane
+    /// grid and the γ range is extended to 180°, since a tilted luminaire
+    /// emits above its original γ range. Directions outside the measured γ
+    /// range get zero intensity. Symmetry is re-detected (rotationally
+    /// symmetric data becomes C0-C180 symmetric) and the downward flux
+    /// fraction is recomputed. An explicit photometric center turns with
+    /// the luminaire; `tilt_angle` is left unchanged.
+    pub fn tilt_distribution(&mut self, degrees: f64) {
+        if degrees.abs() < 1e-9 || self.intensities.is_empty() || self.g_angles.is_empty() {
+            return;
+        }
+
+        let mut c_angles = self.full_c_angles();
+        if c_angles.len() < 4 {
+            let step = if self.c_plane_distance > 0.0 {
+                self.c_plane_distance
+            } else {
+                DEFAULT_C_STEP
+            };
+            c_angles = (0..)
+                .map(|i| i as f64 * step)
+                .take_while(|c| *c < 360.0 - 1e-6)
+                .collect();
+        }
+
+        let g_step = if self.g_plane_distance > 0.0 {
+            self.g_plane_distance
+        } else {
+            DEFAULT_G_STEP
+        };
+        let mut g_angles = self.g_angles.clone();
+        let g_first = g_angles[0];
+        let g_last = g_angles[g_angles.len() - 1];
+        let mut g = g_last + g_step;
+        while g < 180.0 - 1e-6 {
+            g_angles.push(g);
+            g += g_step;
+        }
+        g_angles.push(180.0);
+
+        let (sin_t, cos_t) = degrees.to_radians().sin_cos();
+        self.resample_with(&c_angles, &g_angles, |ldt, c, g| {
+            // Installed direction, then back into the measurement frame
+            let (sin_g, cos_g) = g.to_radians().sin_cos();
+            let (sin_c, cos_c) = c.to_radians().sin_cos();
+            let (dx, dy, dz) = (sin_g * cos_c, sin_g * sin_c, -cos_g);
+            let lx = dx * cos_t + dz * sin_t;
+            let lz = -dx * sin_t + dz * cos_t;
+
+            let source_g = (-lz).clamp(-1.0, 1.0).acos().to_degrees();
+            if source_g < g_first - 1e-6 || source_g > g_last + 1e-6 {
+                return 0.0;
+            }
+            let source_c = dy.atan2(lx).to_degrees().rem_euclid(360.0);
+            ldt.sample(source_c, source_g)
+        });
+
+        if let Some(center) = self.photometric_center.as_mut() {
+            let (x, z) = (center.x, center.z);
+            center.x = x * cos_t - z * sin_t;
+            center.z = x * sin_t + z * cos_t;
+        }
+        self.downward_flux_fraction = PhotometricCalculations::downward_flux(self, 90.0);
+    }
+
+    /// Tilt the distribution by its `tilt_angle` and reset the angle to 0.
+    ///
+    /// Turns the stored data into the installed distribution; applying it
+    /// twice has no further effect.
+    pub fn apply_tilt(&mut self) {
+        self.tilt_distribution(self.tilt_angle);
+        self.tilt_angle = 0.0;
+    }
+
+    /// Copy of the distribution tilted by `degrees`.
+    pub fn with_tilt(&self, degrees: f64) -> Self {
+        let mut tilted = self.clone();
+        tilted.tilt_distribution(degrees);
+        tilted
+    }
+
+    /// Scale intensities and light output ratio by the IES `TILT`
+    /// multiplier at a luminaire tilt of `degrees`.
+    ///
+    /// The multiplier describes how the lamp output changes with the
+    /// burning position, so lamp lumens and wattage are kept.
+    pub fn apply_tilt_multiplier(&mut self, tilt: &TiltData, degrees: f64) {
+        let factor = tilt.multiplier_at(degrees);
+        for value in self.intensities.iter_mut().flatten() {
+            *value *= factor;
+        }
+        self.light_output_ratio *= factor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Symmetry;
+
+    /// Rotationally symmetric beam, 0-90° in 1° steps, dark at 90°.
+    fn spot() -> Eulumdat {
+        let g_angles: Vec<f64> = (0..=90).map(f64::from).collect();
+        let row = g_angles
+            .iter()
+            .map(|g| 1000.0 * g.to_radians().cos().powi(8))
+            .collect();
+        Eulumdat {
+            symmetry: Symmetry::VerticalAxis,
+            num_c_planes: 1,
+            num_g_planes: g_angles.len(),
+            g_plane_distance: 1.0,
+            light_output_ratio: 100.0,
+            c_angles: vec![0.0],
+            g_angles,
+            intensities: vec![row],
+            ..Default::default()
+        }
+    }
+
+    /// Flux in klm by midpoint integration of `sample` over the sphere.
+    fn sphere_flux(ldt: &Eulumdat) -> f64 {
+        let step = 1.0f64.to_radians();
+        let mut flux = 0.0;
+        for c in 0..360 {
+            for g in 0..180 {
+                let (c, g) = (c as f64 + 0.5, g as f64 + 0.5);
+                flux += ldt.sample(c, g) * g.to_radians().sin() * step * step;
+            }
+        }
+        flux / 1000.0
+    }
+
+    #[test]
+    fn test_tilt_moves_peak_towards_c0() {
+        let mut ldt = spot();
+        let flux = sphere_flux(&ldt);
+        ldt.tilt_angle = 20.0;
+        ldt.apply_tilt();
+
+        assert_eq!(ldt.symmetry, Symmetry::PlaneC0C180);
+        assert_eq!(ldt.tilt_angle, 0.0);
+        assert_eq!(ldt.g_angles.last(), Some(&180.0));
+        assert!((ldt.sample(0.0, 20.0) - 1000.0).abs() < 1.0);
+        assert!(ldt.sample(180.0, 20.0) < ldt.sample(0.0, 20.0) / 2.0);
+        // Nadir and C0 γ 40° are both 20° off the beam axis
+        assert!((ldt.sample(0.0, 0.0) - ldt.sample(0.0, 40.0)).abs() < 5.0);
+
+        // Turning the web keeps the flux
+        let tilted_flux = sphere_flux(&ldt);
+        assert!(
+            (tilted_flux - flux).abs() / flux < 0.01,
+            "{tilted_flux} vs {flux}"
+        );
+
+        // Light beyond the original 90° now reaches the upper hemisphere
+        let mut wide = spot();
+        wide.tilt_distribution(80.0);
+        assert!(wide.sample(0.0, 100.0) > 0.0);
+        assert!(wide.downward_flux_fraction < 100.0);
+    }
+
+    #[test]
+    fn test_tilt_multiplier() {
+        let tilt = TiltData {
+            lamp_geometry: 1,
+            angles: vec![0.0, 30.0, 90.0],
+            factors: vec![1.0, 0.9, 0.6],
+        };
+        assert_eq!(tilt.multiplier_at(-10.0), 1.0);
+        assert!((tilt.multiplier_at(15.0) - 0.95).abs() < 1e-12);
+        assert!((tilt.multiplier_at(60.0) - 0.75).abs() < 1e-12);
+        assert_eq!(tilt.multiplier_at(120.0), 0.6);
+        assert_eq!(TiltData::default().multiplier_at(45.0), 1.0);
+
+        let mut ldt = spot();
+        ldt.apply_tilt_multiplier(&tilt, 30.0);
+        assert!((ldt.intensities[0][0] - 900.0).abs() < 1e-9);
+        assert!((ldt.light_output_ratio - 90.0).abs() < 1e-9);
+    }
+}
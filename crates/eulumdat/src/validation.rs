@@ -2,10 +2,16 @@
 //!
 //! Implements comprehensive validation based on the 41 constraints from the Eulumdat specification.
 
+use crate::calculations::PhotometricCalculations;
 use crate::eulumdat::{Eulumdat, Symmetry};
 
+/// Allowed difference between declared and integrated LOR/DFF in percentage points.
+pub const FLUX_CONSISTENCY_TOLERANCE: f64 = 5.0;
+
 /// A validation warning (non-fatal issue).
 #[derive(Debug, Clone, PartialEq)]
+// Serialize only: `code` is `&'static str`
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ValidationWarning {
     /// Warning code for programmatic handling.
     pub code: &'static str,
@@ -21,6 +27,7 @@ impl std::fmt::Display for ValidationWarning {
 
 /// A validation error (fatal issue).
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ValidationError {
     /// Error code for programmatic handling.
     pub code: &'static str,
@@ -131,6 +138,16 @@ fn english_message(code: &str, args: &[&dyn std::fmt::Display]) -> String {
             "Declared symmetry Isym={} may not match actual intensity data (RMS difference: {}% between {} planes)",
             args[0], args[1], args[2]
         ),
+        "W048" => format!(
+            "Declared LOR ({}%) differs from the integrated intensity distribution ({}%)",
+            args[0], args[1]
+        ),
+        "W049" => format!(
+            "Declared DFF ({}%) differs from the integrated intensity distribution ({}%)",
+            args[0], args[1]
+        ),
+        "W050" => format!("Date/user field '{}' contains no recognizable date", args[0]),
+        "W051" => format!("Measurement date {} is implausible", args[0]),
         "E001" => "No intensity data".to_string(),
         "E002" => "No G-planes defined".to_string(),
         "E003" => "No lamp sets defined".to_string(),
@@ -325,6 +342,20 @@ fn validate_inner(
         });
     }
 
+    if !ldt.date_user.trim().is_empty() {
+        match ldt.measurement_date() {
+            None => warnings.push(ValidationWarning {
+                code: "W050",
+                message: msg("W050", &[&ldt.date_user]),
+            }),
+            Some(date) if !date.is_plausible() => warnings.push(ValidationWarning {
+                code: "W051",
+                message: msg("W051", &[&date]),
+            }),
+            Some(_) => {}
+        }
+    }
+
     // === Physical Dimension Validation ===
 
     if ldt.length < 0.0 {
@@ -408,6 +439,35 @@ fn validate_inner(
         });
     }
 
+    // Declared values are checked against the integrated distribution when set;
+    // the integration needs a C-angle and intensity row for every stored plane
+    let mc = ldt.actual_c_planes();
+    let flux = if ldt.c_angles.len() >= mc && ldt.intensities.len() >= mc {
+        PhotometricCalculations::integrate_flux(ldt)
+    } else {
+        Default::default()
+    };
+    if flux.lor > 0.0 {
+        if ldt.light_output_ratio > 0.0
+            && flux.lor_deviation(ldt).abs() > FLUX_CONSISTENCY_TOLERANCE
+        {
+            let lor_str = format!("{:.1}", flux.lor);
+            warnings.push(ValidationWarning {
+                code: "W048",
+                message: msg("W048", &[&ldt.light_output_ratio, &lor_str]),
+            });
+        }
+        if ldt.downward_flux_fraction > 0.0
+            && flux.dff_deviation(ldt).abs() > FLUX_CONSISTENCY_TOLERANCE
+        {
+            let dff_str = format!("{:.1}", flux.dff);
+            warnings.push(ValidationWarning {
+                code: "W049",
+                message: msg("W049", &[&ldt.downward_flux_fraction, &dff_str]),
+            });
+        }
+    }
+
     if ldt.tilt_angle < -90.0 || ldt.tilt_angle > 90.0 {
         warnings.push(ValidationWarning {
             code: "W022",
@@ -763,6 +823,43 @@ mod tests {
         assert!(warnings.iter().all(|w| !w.code.starts_with('E')));
     }
 
+    #[test]
+    fn test_declared_flux_consistency() {
+        let mut ldt = create_valid_ldt();
+        let flux = PhotometricCalculations::integrate_flux(&ldt);
+        ldt.light_output_ratio = flux.lor;
+        ldt.downward_flux_fraction = flux.dff;
+        let warnings = validate(&ldt);
+        assert!(!warnings
+            .iter()
+            .any(|w| w.code == "W048" || w.code == "W049"));
+
+        ldt.light_output_ratio = flux.lor + 2.0 * FLUX_CONSISTENCY_TOLERANCE;
+        ldt.downward_flux_fraction = flux.dff / 2.0;
+        let warnings = validate(&ldt);
+        assert!(warnings.iter().any(|w| w.code == "W048"));
+        assert!(warnings.iter().any(|w| w.code == "W049"));
+    }
+
+    #[test]
+    fn test_date_user_validation() {
+        let mut ldt = create_valid_ldt();
+        let codes = |ldt: &Eulumdat| -> Vec<&'static str> {
+            validate(ldt)
+                .into_iter()
+                .map(|w| w.code)
+                .filter(|c| *c == "W050" || *c == "W051")
+                .collect()
+        };
+
+        ldt.date_user = "2024-03-01 / lab".to_string();
+        assert!(codes(&ldt).is_empty());
+        ldt.date_user = "last spring".to_string();
+        assert_eq!(codes(&ldt), ["W050"]);
+        ldt.date_user = "01.01.1901".to_string();
+        assert_eq!(codes(&ldt), ["W051"]);
+    }
+
     #[test]
     fn test_strict_validation() {
         let ldt = create_valid_ldt();
@@ -3,6 +3,7 @@
 //! Writes Eulumdat format files according to the official specification.
 
 use crate::eulumdat::{Eulumdat, Symmetry, TypeIndicator};
+use crate::rounding::{NumericField::*, RoundingPolicy};
 
 /// Writer for Eulumdat (LDT) files.
 pub struct Writer;
@@ -10,6 +11,11 @@ pub struct Writer;
 impl Writer {
     /// Write an Eulumdat structure to LDT format string.
     pub fn write(ldt: &Eulumdat) -> String {
+        Self::write_with_rounding(ldt, &RoundingPolicy::default())
+    }
+
+    /// Write an Eulumdat structure with the given precision per field.
+    pub fn write_with_rounding(ldt: &Eulumdat, policy: &RoundingPolicy) -> String {
         let mut output = String::new();
 
         // Line 1: Identification
@@ -39,7 +45,7 @@ impl Writer {
         output.push('\n');
 
         // Line 5: Distance between C-planes (Dc)
-        output.push_str(&Self::format_float(ldt.c_plane_distance));
+        output.push_str(&policy.format(Angle, ldt.c_plane_distance));
         output.push('\n');
 
         // Line 6: Number of G-planes (Ng)
@@ -47,7 +53,7 @@ impl Writer {
         output.push('\n');
 
         // Line 7: Distance between G-planes (Dg)
-        output.push_str(&Self::format_float(ldt.g_plane_distance));
+        output.push_str(&policy.format(Angle, ldt.g_plane_distance));
         output.push('\n');
 
         // Line 8: Measurement report number
@@ -71,55 +77,55 @@ impl Writer {
         output.push('\n');
 
         // Line 13: Length/diameter of luminaire (mm)
-        output.push_str(&Self::format_float(ldt.length));
+        output.push_str(&policy.format(Dimension, ldt.length));
         output.push('\n');
 
         // Line 14: Width of luminaire (mm)
-        output.push_str(&Self::format_float(ldt.width));
+        output.push_str(&policy.format(Dimension, ldt.width));
         output.push('\n');
 
         // Line 15: Height of luminaire (mm)
-        output.push_str(&Self::format_float(ldt.height));
+        output.push_str(&policy.format(Dimension, ldt.height));
         output.push('\n');
 
         // Line 16: Length/diameter of luminous area (mm)
-        output.push_str(&Self::format_float(ldt.luminous_area_length));
+        output.push_str(&policy.format(Dimension, ldt.luminous_area_length));
         output.push('\n');
 
         // Line 17: Width of luminous area (mm)
-        output.push_str(&Self::format_float(ldt.luminous_area_width));
+        output.push_str(&policy.format(Dimension, ldt.luminous_area_width));
         output.push('\n');
 
         // Line 18: Height at C0 plane (mm)
-        output.push_str(&Self::format_float(ldt.height_c0));
+        output.push_str(&policy.format(Dimension, ldt.height_c0));
         output.push('\n');
 
         // Line 19: Height at C90 plane (mm)
-        output.push_str(&Self::format_float(ldt.height_c90));
+        output.push_str(&policy.format(Dimension, ldt.height_c90));
         output.push('\n');
 
         // Line 20: Height at C180 plane (mm)
-        output.push_str(&Self::format_float(ldt.height_c180));
+        output.push_str(&policy.format(Dimension, ldt.height_c180));
         output.push('\n');
 
         // Line 21: Height at C270 plane (mm)
-        output.push_str(&Self::format_float(ldt.height_c270));
+        output.push_str(&policy.format(Dimension, ldt.height_c270));
         output.push('\n');
 
         // Line 22: Downward flux fraction (DFF) %
-        output.push_str(&Self::format_float(ldt.downward_flux_fraction));
+        output.push_str(&policy.format(Ratio, ldt.downward_flux_fraction));
         output.push('\n');
 
         // Line 23: Light output ratio of luminaire (LORL) %
-        output.push_str(&Self::format_float(ldt.light_output_ratio));
+        output.push_str(&policy.format(Ratio, ldt.light_output_ratio));
         output.push('\n');
 
         // Line 24: Conversion factor for luminous intensities (CFLI)
-        output.push_str(&Self::format_float(ldt.conversion_factor));
+        output.push_str(&policy.format(ConversionFactor, ldt.conversion_factor));
         output.push('\n');
 
         // Line 25: Tilt angle during measurement
-        output.push_str(&Self::format_float(ldt.tilt_angle));
+        output.push_str(&policy.format(Angle, ldt.tilt_angle));
         output.push('\n');
 
         // Line 26: Number of standard lamp sets
@@ -137,7 +143,7 @@ impl Writer {
             output.push('\n');
 
             // 26c: Total luminous flux
-            output.push_str(&Self::format_float(lamp_set.total_luminous_flux));
+            output.push_str(&policy.format(Flux, lamp_set.total_luminous_flux));
             output.push('\n');
 
             // 26d: Color appearance
@@ -149,32 +155,32 @@ impl Writer {
             output.push('\n');
 
             // 26f: Wattage including ballast
-            output.push_str(&Self::format_float(lamp_set.wattage_with_ballast));
+            output.push_str(&policy.format(Wattage, lamp_set.wattage_with_ballast));
             output.push('\n');
         }
 
         // Lines 27a-27j: Direct ratios
         for ratio in &ldt.direct_ratios {
-            output.push_str(&Self::format_float(*ratio));
+            output.push_str(&policy.format(Ratio, *ratio));
             output.push('\n');
         }
 
         // Lines 28: C-plane angles
         for angle in &ldt.c_angles {
-            output.push_str(&Self::format_float(*angle));
+            output.push_str(&policy.format(Angle, *angle));
             output.push('\n');
         }
 
         // Lines 29: G-plane angles
         for angle in &ldt.g_angles {
-            output.push_str(&Self::format_float(*angle));
+            output.push_str(&policy.format(Angle, *angle));
             output.push('\n');
         }
 
         // Lines 30+: Luminous intensities
         for row in &ldt.intensities {
             for intensity in row {
-                output.push_str(&Self::format_float(*intensity));
+                output.push_str(&policy.format(Intensity, *intensity));
                 output.push('\n');
             }
         }
@@ -184,18 +190,10 @@ impl Writer {
 
     /// Format a float value for output.
     ///
-    /// Uses a reasonable precision and removes trailing zeros.
+    /// Uses up to 6 decimal places and removes trailing zeros.
+    #[cfg(test)]
     fn format_float(value: f64) -> String {
-        if value == value.trunc() {
-            // Integer value
-            format!("{}", value as i64)
-        } else {
-            // Format with up to 6 decimal places, remove trailing zeros
-            let s = format!("{:.6}", value);
-            let s = s.trim_end_matches('0');
-            let s = s.trim_end_matches('.');
-            s.to_string()
-        }
+        RoundingPolicy::default().format(Intensity, value)
     }
 }
 
@@ -0,0 +1,36 @@
+#!/usr/bin/env bash
+# File: scripts/check-public-api.sh
+#
+# Semver check for the stable `eulumdat::prelude`.
+#
+# Diffs the public API of the eulumdat crate against a published release
+# (default: the latest on crates.io) with cargo-public-api and fails if any
+# item reachable through the prelude was removed or changed. The rest of the
+# crate may change freely between minor releases.
+#
+# Requires: cargo install --locked cargo-public-api, plus a nightly toolchain
+# (rustup toolchain install nightly) for rustdoc JSON.
+#
+# Usage: scripts/check-public-api.sh [VERSION]
+
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+BASELINE="${1:-latest}"
+
+echo "=== Diffing eulumdat public API against $BASELINE ==="
+diff=$(cargo public-api --package eulumdat --simplified diff "$BASELINE")
+
+# Removed and changed items are listed as "-pub ..." lines
+broken=$(grep -E '^-.*eulumdat::prelude::' <<<"$diff" || true)
+
+if [[ -n "$broken" ]]; then
+    echo "✗ Breaking changes to eulumdat::prelude since $BASELINE:"
+    echo "$broken"
+    echo ""
+    echo "Restore the items above or bump the major version."
+    exit 1
+fi
+
+echo "✓ eulumdat::prelude is compatible with $BASELINE"